@@ -0,0 +1,133 @@
+//! Config Files
+//!
+//! `--config FILE` loads validation settings from TOML instead of flags:
+//!
+//! ```toml
+//! level = "temporal"
+//! strict = true
+//! deny-warnings = false
+//! meta-key-order = ["domain", "version"]
+//! profile = "minimal"
+//! max-document-size = 65536
+//! z3-timeout = 10
+//!
+//! [thresholds]
+//! min-tier = "gold"
+//! max-size = 32768
+//! fail-on-ambiguity = 0.02
+//! ```
+//!
+//! Every key is optional. Unknown keys and out-of-range values are errors
+//! naming the key; a flag given on the command line overrides the file.
+
+use super::{TierArg, ValidationLevel};
+use aisp_core::validator::Profile;
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+/// Settings read from a config file
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ConfigFile {
+    pub level: Option<ValidationLevel>,
+    pub strict: Option<bool>,
+    pub deny_warnings: Option<bool>,
+    pub meta_key_order: Option<Vec<String>>,
+    /// Name of the language profile documents are validated against
+    pub profile: Option<String>,
+    pub max_document_size: Option<usize>,
+    /// Z3 timeout in seconds
+    pub z3_timeout: Option<u64>,
+    #[serde(default)]
+    pub thresholds: Thresholds,
+}
+
+/// Gates of the `validate` command
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Thresholds {
+    pub min_tier: Option<TierArg>,
+    pub max_size: Option<usize>,
+    pub fail_on_ambiguity: Option<f64>,
+}
+
+impl ConfigFile {
+    /// Read and check the config file at `path`
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+        Self::parse(&text).with_context(|| format!("Invalid config file {}", path.display()))
+    }
+
+    /// Parse and check config file contents
+    pub fn parse(text: &str) -> Result<Self> {
+        let config: Self = toml::from_str(text)?;
+        config.check()?;
+        Ok(config)
+    }
+
+    /// Language profile named by the file, if any
+    pub fn language_profile(&self) -> Option<Profile> {
+        self.profile.as_deref().and_then(Profile::named)
+    }
+
+    /// Reject values the schema types admit but validation cannot use
+    fn check(&self) -> Result<()> {
+        if let Some(name) = &self.profile {
+            if Profile::named(name).is_none() {
+                bail!("unknown profile `{}` for key `profile`", name);
+            }
+        }
+        if self.max_document_size == Some(0) {
+            bail!("key `max-document-size` must be positive");
+        }
+        if self.z3_timeout == Some(0) {
+            bail!("key `z3-timeout` must be positive");
+        }
+        if let Some(bound) = self.thresholds.fail_on_ambiguity {
+            if !(0.0..=1.0).contains(&bound) {
+                bail!("key `thresholds.fail-on-ambiguity` must be between 0 and 1, got {}", bound);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_config_parses_every_key() {
+        let config = ConfigFile::parse(
+            "level = \"temporal\"\nstrict = true\nprofile = \"minimal\"\nz3-timeout = 5\n\n\
+             [thresholds]\nmin-tier = \"gold\"\nfail-on-ambiguity = 0.02\n",
+        )
+        .unwrap();
+
+        assert!(matches!(config.level, Some(ValidationLevel::Temporal)));
+        assert_eq!(config.strict, Some(true));
+        assert_eq!(config.language_profile().unwrap().name, "minimal");
+        assert_eq!(config.z3_timeout, Some(5));
+        assert!(matches!(config.thresholds.min_tier, Some(TierArg::Gold)));
+        assert_eq!(config.thresholds.fail_on_ambiguity, Some(0.02));
+        assert_eq!(config.deny_warnings, None);
+    }
+
+    #[test]
+    fn test_unknown_and_invalid_keys_are_rejected() {
+        let error = ConfigFile::parse("strict = true\nstrictness = 2\n").unwrap_err().to_string();
+        assert!(error.contains("unknown field `strictness`"), "{}", error);
+
+        let error = ConfigFile::parse("[thresholds]\nmin-teir = \"gold\"\n").unwrap_err().to_string();
+        assert!(error.contains("unknown field `min-teir`"), "{}", error);
+
+        let error = ConfigFile::parse("level = \"deep\"\n").unwrap_err().to_string();
+        assert!(error.contains("unknown variant `deep`"), "{}", error);
+
+        let error = ConfigFile::parse("profile = \"tiny\"\n").unwrap_err().to_string();
+        assert_eq!(error, "unknown profile `tiny` for key `profile`");
+    }
+}
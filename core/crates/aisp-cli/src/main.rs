@@ -5,15 +5,28 @@
 //! and formal verification using Z3.
 
 use aisp_core::*;
+use aisp_core::mathematical_evaluator::meets_threshold;
+use aisp_core::property_types::PropertyType;
+use aisp_core::temporal_logic_solver::{AnalyzedFormula, FormulaType};
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::parser::ValueSource;
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
 use colored::*;
 use indicatif::{ProgressBar, ProgressStyle};
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::io::{self, Write};
-use std::path::{Path, PathBuf};
-use std::time::Duration;
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+mod config_file;
+mod report_template;
+use config_file::ConfigFile;
+use report_template::ReportTemplate;
 
 #[derive(Parser)]
 #[command(name = "aisp")]
@@ -27,11 +40,11 @@ Features:
   • Pure Rust implementation for maximum performance
   • Multi-level validation (Syntax, Semantic, Relational, Temporal)
   • Z3-based formal verification (optional)
-  • Rich output formats (human, JSON, detailed)
+  • Rich output formats (human, JSON, TOML, YAML, detailed)
   • Interactive mode for rapid development
 ")]
 struct Cli {
-    /// Input file(s) or directory to validate
+    /// Input file(s) or directory to validate (`-` reads stdin)
     #[arg(value_name = "PATH")]
     input: Vec<PathBuf>,
 
@@ -43,6 +56,11 @@ struct Cli {
     #[arg(short = 'o', long = "output")]
     output: Option<PathBuf>,
 
+    /// Also write one report per validated file into this directory,
+    /// mirroring input paths with an extension matching the format
+    #[arg(long = "output-dir", value_name = "DIR")]
+    output_dir: Option<PathBuf>,
+
     /// Validation level
     #[arg(short = 'l', long = "level", default_value = "semantic")]
     level: ValidationLevel,
@@ -51,14 +69,61 @@ struct Cli {
     #[arg(short = 's', long = "strict")]
     strict: bool,
 
+    /// Fail validation when any warning is reported
+    #[arg(long = "deny-warnings")]
+    deny_warnings: bool,
+
+    /// At the formal level, fail validation when fewer than this many
+    /// properties are proven
+    #[arg(long = "min-verified-properties", value_name = "N", default_value = "0")]
+    min_verified_properties: usize,
+
+    /// Canonical meta key order, comma-separated: validation warns when meta
+    /// entries deviate from it and formatting sorts them into it
+    #[arg(long = "meta-key-order", value_name = "KEYS", value_delimiter = ',')]
+    meta_key_order: Vec<String>,
+
+    /// Warn that a document may be prose rather than AISP when its symbol
+    /// density falls below this floor [default: 0.02]
+    #[arg(long = "min-symbol-density", value_name = "DENSITY")]
+    min_symbol_density: Option<f64>,
+
     /// Include timing information
     #[arg(short = 't', long = "timing")]
     timing: bool,
 
+    /// Write a per-stage timing breakdown as JSON to this file
+    #[arg(long = "profile", value_name = "FILE")]
+    profile: Option<PathBuf>,
+
+    /// After the run, write aggregate validation metrics in this format to
+    /// `--metrics-file`
+    #[arg(long = "emit-metrics", value_name = "FORMAT", requires = "metrics_file")]
+    emit_metrics: Option<MetricsFormat>,
+
+    /// File written by `--emit-metrics`
+    #[arg(long = "metrics-file", value_name = "FILE")]
+    metrics_file: Option<PathBuf>,
+
     /// Include detailed AST in output
     #[arg(long = "include-ast")]
     include_ast: bool,
 
+    /// Fail when JSON output has a null where a value is expected or does
+    /// not read back as the same result
+    #[arg(long = "strict-json")]
+    strict_json: bool,
+
+    /// Write JSON output on a single line instead of pretty-printed
+    #[arg(long = "json-compact")]
+    json_compact: bool,
+
+    /// Render human-readable output through this Handlebars-style template
+    /// instead of the built-in layout; only valid with the human and
+    /// detailed formats
+    #[arg(long = "report-template", value_name = "FILE")]
+    report_template: Option<PathBuf>,
+
     /// Enable formal verification with Z3
     #[cfg(feature = "z3-verification")]
     #[arg(long = "formal-verification")]
@@ -69,18 +134,44 @@ struct Cli {
     #[arg(long = "z3-timeout", default_value = "30")]
     z3_timeout: u64,
 
+    /// Stop validating a file after this many seconds and report the
+    /// stages that finished, marked as timed out
+    #[arg(long = "timeout", value_name = "SECONDS", value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+
     /// Verbose output
     #[arg(short = 'v', long = "verbose")]
     verbose: bool,
 
-    /// Quiet mode (errors only)
+    /// Quiet mode: print nothing and report only through the exit code
     #[arg(short = 'q', long = "quiet")]
     quiet: bool,
 
+    /// When to color output
+    #[arg(long = "color", value_name = "WHEN", default_value = "auto")]
+    color: ColorChoice,
+
     /// Number of parallel workers for batch processing
     #[arg(short = 'j', long = "jobs", default_value = "1")]
     jobs: usize,
 
+    /// How `--jobs` workers share a batch of files
+    #[arg(long = "parallel-strategy", value_name = "STRATEGY", default_value = "auto")]
+    parallel_strategy: ParallelStrategy,
+
+    /// Read validation settings from this TOML file; options given on the
+    /// command line take precedence over it
+    #[arg(long = "config", value_name = "FILE", global = true)]
+    config: Option<PathBuf>,
+
+    /// Language profile set by the config file
+    #[arg(skip)]
+    language_profile: Option<Profile>,
+
+    /// Document size limit set by the config file
+    #[arg(skip)]
+    max_document_size: Option<usize>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -89,16 +180,49 @@ struct Cli {
 enum Commands {
     /// Validate AISP document(s)
     Validate {
-        /// Files to validate
+        /// Files to validate (`-` reads stdin)
         files: Vec<PathBuf>,
         
         /// Stop on first error
         #[arg(long = "fail-fast")]
         fail_fast: bool,
+
+        /// Fail if a document's quality tier is below this tier.
+        /// Applied on top of validity: with --strict a document must
+        /// both pass strict validation and meet the tier.
+        #[arg(long = "min-tier", value_name = "TIER")]
+        min_tier: Option<TierArg>,
+
+        /// Fail if a document exceeds this many bytes, even when valid
+        #[arg(long = "max-size", value_name = "BYTES")]
+        max_size: Option<usize>,
+
+        /// Fail if a document's ambiguity meets or exceeds this bound.
+        /// Applied on top of validity, like --min-tier: with --strict a
+        /// document must both pass strict validation and stay below it.
+        #[arg(long = "fail-on-ambiguity", value_name = "THRESHOLD")]
+        fail_on_ambiguity: Option<f64>,
+
+        /// Only validate `.aisp` files under the given paths that changed
+        /// since this git ref
+        #[arg(long = "since", value_name = "REF")]
+        since: Option<String>,
+
+        /// Rewrite files with the suggested remediations applied
+        /// (missing blocks inserted, look-alike glyphs replaced) and
+        /// report on the result
+        #[arg(long = "fix")]
+        fix: bool,
+
+        /// Explain each document's density tier: δ, the threshold it fell
+        /// short of, the counts and per-block densities behind it, and
+        /// suggestions for reaching the next tier
+        #[arg(long = "explain-tier")]
+        explain_tier: bool,
     },
     /// Analyze document structure and metrics
     Analyze {
-        /// File to analyze
+        /// File to analyze (`-` reads stdin)
         file: PathBuf,
         
         /// Include symbol statistics
@@ -111,7 +235,7 @@ enum Commands {
     },
     /// Check syntax only (fast)
     Check {
-        /// Files to check
+        /// Files to check (`-` reads stdin)
         files: Vec<PathBuf>,
     },
     /// Format/prettify AISP document
@@ -123,10 +247,71 @@ enum Commands {
         #[arg(short = 'i', long = "in-place")]
         in_place: bool,
     },
+    /// Report style lints, optionally fixing the deterministic ones
+    Lint {
+        /// Files to lint
+        files: Vec<PathBuf>,
+
+        /// Rewrite files with fixable lints applied (type name case,
+        /// block order, whitespace) and report only what remains
+        #[arg(long = "fix")]
+        fix: bool,
+    },
+    /// Report corpus-wide statistics over a directory of documents
+    Stats {
+        /// Directory to scan recursively for `.aisp` files, or a single file
+        path: PathBuf,
+
+        /// Number of most-used symbols to report
+        #[arg(long = "top", default_value = "10")]
+        top: usize,
+    },
+    /// Measure validation latency and throughput for one document
+    Bench {
+        /// File to validate repeatedly (`-` reads stdin)
+        file: PathBuf,
+
+        /// Number of timed validations
+        #[arg(long = "iterations", default_value = "100")]
+        iterations: usize,
+
+        /// Untimed validations run first to warm caches
+        #[arg(long = "warmup", default_value = "10")]
+        warmup: usize,
+    },
+    /// Run only formal verification and report each property's outcome
+    Verify {
+        /// File to verify (`-` reads stdin)
+        file: PathBuf,
+
+        /// Seconds each property proof may take
+        #[arg(long = "timeout", default_value = "30")]
+        timeout: u64,
+
+        /// Property kinds to check, comma-separated (e.g. type-safety,liveness);
+        /// all kinds when omitted
+        #[arg(long = "properties", value_name = "KINDS", value_delimiter = ',')]
+        properties: Vec<String>,
+    },
+    /// Write a minimal valid AISP 5.1 document to start from
+    Init {
+        /// File to create
+        file: PathBuf,
+
+        /// Document name in the header (defaults to the file stem)
+        #[arg(long = "name")]
+        name: Option<String>,
+
+        /// Overwrite the file if it already exists
+        #[arg(long = "force")]
+        force: bool,
+    },
     /// Interactive validation mode
     Interactive,
     /// Show detailed information about validation levels
     Levels,
+    /// List every recognized AISP symbol with its name and category
+    ListSymbols,
 }
 
 #[derive(ValueEnum, Clone, Debug)]
@@ -135,13 +320,166 @@ enum OutputFormat {
     Human,
     /// JSON output for programmatic use
     Json,
+    /// One JSON object per line, written as each file completes
+    Jsonl,
+    /// TOML output for configuration-style pipelines
+    Toml,
+    /// YAML output for configuration-style pipelines
+    Yaml,
     /// Detailed human format with full analysis
     Detailed,
     /// Minimal output (validation result only)
     Minimal,
 }
 
-#[derive(ValueEnum, Clone, Debug)]
+/// Exposition format for `--emit-metrics`
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+enum MetricsFormat {
+    /// Prometheus text format, suitable for a pushgateway
+    Prometheus,
+}
+
+/// How batch validation is scheduled across workers, set by
+/// `--parallel-strategy`
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+enum ParallelStrategy {
+    /// Pick a strategy from the spread of file sizes
+    Auto,
+    /// Each worker takes the next file when it finishes one
+    WorkStealing,
+    /// Each worker takes a fixed, contiguous share of the files
+    Chunked,
+}
+
+/// A batch whose largest file exceeds this multiple of the mean size is
+/// skewed, and a fixed share could leave one worker with most of the work
+const SKEWED_SIZE_RATIO: u64 = 4;
+
+/// Mean file size below which files count as tiny
+const TINY_FILE_BYTES: u64 = 16 * 1024;
+
+impl ParallelStrategy {
+    /// The concrete strategy for files of the given sizes: work stealing
+    /// for skewed sizes or tiny files, chunking for evenly sized large ones
+    fn resolve(self, sizes: &[u64]) -> Self {
+        if self != Self::Auto {
+            return self;
+        }
+        let total: u64 = sizes.iter().sum();
+        let largest = sizes.iter().copied().max().unwrap_or(0);
+        let mean = total / sizes.len().max(1) as u64;
+        if largest > SKEWED_SIZE_RATIO * mean || mean < TINY_FILE_BYTES {
+            Self::WorkStealing
+        } else {
+            Self::Chunked
+        }
+    }
+}
+
+/// When to emit ANSI colors, set by `--color`
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+enum ColorChoice {
+    /// Color only when stdout is a terminal
+    Auto,
+    /// Always color
+    Always,
+    /// Never color
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether output should be colored, given whether stdout is a terminal
+    fn enabled(self, terminal: bool) -> bool {
+        match self {
+            ColorChoice::Auto => terminal,
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+
+    /// Apply the choice to all `colored` output in this process
+    ///
+    /// `auto` on a terminal keeps `colored`'s own `NO_COLOR` and `CLICOLOR`
+    /// handling.
+    fn apply(self) {
+        let terminal = io::stdout().is_terminal();
+        if self == ColorChoice::Auto && terminal {
+            colored::control::unset_override();
+        } else {
+            colored::control::set_override(self.enabled(terminal));
+        }
+    }
+}
+
+/// Quality tier accepted by `--min-tier`
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum TierArg {
+    Reject,
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+}
+
+impl From<TierArg> for QualityTier {
+    fn from(tier: TierArg) -> Self {
+        match tier {
+            TierArg::Reject => QualityTier::Reject,
+            TierArg::Bronze => QualityTier::Bronze,
+            TierArg::Silver => QualityTier::Silver,
+            TierArg::Gold => QualityTier::Gold,
+            TierArg::Platinum => QualityTier::Platinum,
+        }
+    }
+}
+
+/// CI gates checked independently of intrinsic validity
+#[derive(Debug, Clone, Copy, Default)]
+struct ValidationGates {
+    min_tier: Option<QualityTier>,
+    max_size: Option<usize>,
+    max_ambiguity: Option<f64>,
+}
+
+impl ValidationGates {
+    /// Describe every gate the document fails
+    fn check(&self, tier: QualityTier, document_size: usize, ambiguity: f64) -> Vec<String> {
+        let mut failures = Vec::new();
+
+        if let Some(min_tier) = self.min_tier {
+            if tier < min_tier {
+                failures.push(format!(
+                    "Quality tier {} {} is below required {} {}",
+                    tier.symbol(), tier.name(), min_tier.symbol(), min_tier.name()
+                ));
+            }
+        }
+
+        if let Some(max_size) = self.max_size {
+            if document_size > max_size {
+                failures.push(format!(
+                    "Document size {} bytes exceeds limit of {} bytes",
+                    document_size, max_size
+                ));
+            }
+        }
+
+        if let Some(max_ambiguity) = self.max_ambiguity {
+            if meets_threshold(ambiguity, max_ambiguity) {
+                failures.push(format!(
+                    "Ambiguity {:.3} meets or exceeds limit of {:.3}",
+                    ambiguity, max_ambiguity
+                ));
+            }
+        }
+
+        failures
+    }
+}
+
+#[derive(ValueEnum, Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
 enum ValidationLevel {
     /// Syntax checking only
     Syntax,
@@ -156,7 +494,7 @@ enum ValidationLevel {
 }
 
 /// Validation result for output
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct CliValidationResult {
     file: PathBuf,
     valid: bool,
@@ -171,31 +509,182 @@ struct CliValidationResult {
     total_time_ms: Option<u64>,
     warnings: Vec<String>,
     errors: Vec<String>,
+    /// Suggested fixes for the warnings and errors, applied by `--fix`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    remediations: Vec<String>,
+    /// Lines of the `--explain-tier` explanation
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tier_explanation: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    gate_failures: Vec<String>,
+    /// Validation ran out of `--timeout` and only covers the stages that
+    /// finished
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    timed_out: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     relational_analysis: Option<RelationalSummary>,
     #[serde(skip_serializing_if = "Option::is_none")]
     temporal_analysis: Option<TemporalSummary>,
     #[serde(skip_serializing_if = "Option::is_none")]
     formal_verification: Option<FormalSummary>,
+    /// Analysis stages and whether they ran, filled in under `--verbose`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    capabilities: Vec<StageCapability>,
+    /// Written separately by `--profile`, never part of the report itself
+    #[serde(skip)]
+    profile: Option<ProfileNode>,
+}
+
+/// Timing tree node written by `--profile`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ProfileNode {
+    stage: String,
+    duration_ms: f64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    children: Vec<ProfileNode>,
+}
+
+impl From<&StageProfile> for ProfileNode {
+    fn from(profile: &StageProfile) -> Self {
+        Self {
+            stage: profile.stage.clone(),
+            duration_ms: profile.duration.as_secs_f64() * 1000.0,
+            children: profile.children.iter().map(ProfileNode::from).collect(),
+        }
+    }
+}
+
+/// Profile of one validated file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct FileProfile {
+    file: PathBuf,
+    profile: ProfileNode,
+}
+
+/// Wrapper for formats that cannot hold a top-level array (TOML)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct CliValidationReport {
+    results: Vec<CliValidationResult>,
+}
+
+/// Corpus-wide statistics reported by `aisp stats`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct CorpusStats {
+    documents: usize,
+    valid: usize,
+    tier_distribution: Vec<TierCount>,
+    average_density: f64,
+    top_symbols: Vec<SymbolCount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    largest: Option<DocumentSize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    smallest: Option<DocumentSize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct TierCount {
+    tier: String,
+    tier_symbol: String,
+    count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SymbolCount {
+    symbol: String,
+    count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct DocumentSize {
+    file: PathBuf,
+    bytes: usize,
+}
+
+/// One row of the symbol table printed by `aisp list-symbols`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SymbolEntry {
+    symbol: String,
+    name: String,
+    category: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ascii: Option<String>,
+}
+
+/// Latency percentiles and throughput reported by `aisp bench`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct BenchReport {
+    file: PathBuf,
+    iterations: usize,
+    warmup: usize,
+    mean_ms: f64,
+    median_ms: f64,
+    p95_ms: f64,
+    p99_ms: f64,
+    documents_per_second: f64,
+}
+
+/// Per-property outcomes reported by `aisp verify`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct VerifyReport {
+    file: PathBuf,
+    properties: Vec<PropertyReport>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct PropertyReport {
+    name: String,
+    kind: String,
+    /// `proven`, `disproven` or `unknown`
+    status: String,
+    counterexample: Option<BTreeMap<String, String>>,
+}
+
+impl From<&PropertyOutcome> for PropertyReport {
+    fn from(outcome: &PropertyOutcome) -> Self {
+        Self {
+            name: outcome.name.clone(),
+            kind: outcome.property_type.name().to_string(),
+            status: outcome.status.name().to_string(),
+            counterexample: outcome.counterexample.clone(),
+        }
+    }
+}
+
+/// Lint results for one file reported by `aisp lint`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct LintReport {
+    file: PathBuf,
+    /// Findings resolved by `--fix`, at their original lines
+    fixed: Vec<String>,
+    findings: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct RelationalSummary {
     consistency_score: f64,
     constraints_total: usize,
     constraints_satisfied: usize,
     conflicts: usize,
+    /// Constraints that cannot hold together, when the solver proved so
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    unsat_core: Vec<String>,
+    /// Assignment satisfying every constraint, when the solver found one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    witness: Option<BTreeMap<String, String>>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct TemporalSummary {
     consistency_score: f64,
     ltl_formulas: usize,
     ctl_formulas: usize,
     patterns_detected: usize,
+    /// Deepest nesting of temporal operators within one formula
+    #[serde(default)]
+    max_temporal_depth: usize,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct FormalSummary {
     status: String,
     properties_checked: usize,
@@ -205,42 +694,148 @@ struct FormalSummary {
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let cli = Cli::parse();
+    let cli = cli_from_matches(&Cli::command().get_matches())?;
+    cli.color.apply();
 
     // Setup logging based on verbosity
     setup_logging(&cli)?;
 
+    match run(&cli).await {
+        // Quiet mode reports errors through the exit code alone
+        Err(_) if cli.quiet => std::process::exit(1),
+        outcome => outcome,
+    }
+}
+
+/// Build the CLI from parsed arguments, filling in settings from
+/// `--config` that the command line leaves unset
+fn cli_from_matches(matches: &ArgMatches) -> Result<Cli> {
+    let mut cli = Cli::from_arg_matches(matches)?;
+    if let Some(path) = &cli.config {
+        let file = ConfigFile::load(path)?;
+        cli.apply_config(&file, matches);
+    }
+    Ok(cli)
+}
+
+impl Cli {
+    /// Take each setting from `file` unless it was given on the command line
+    fn apply_config(&mut self, file: &ConfigFile, matches: &ArgMatches) {
+        let on_command_line = |id: &str| matches.value_source(id) == Some(ValueSource::CommandLine);
+
+        if let (Some(level), false) = (&file.level, on_command_line("level")) {
+            self.level = level.clone();
+        }
+        // Switches can only be turned on from the command line
+        self.strict |= file.strict.unwrap_or(false);
+        self.deny_warnings |= file.deny_warnings.unwrap_or(false);
+        if let (Some(keys), false) = (&file.meta_key_order, on_command_line("meta_key_order")) {
+            self.meta_key_order = keys.clone();
+        }
+        #[cfg(feature = "z3-verification")]
+        {
+            if let (Some(timeout), false) = (file.z3_timeout, on_command_line("z3_timeout")) {
+                self.z3_timeout = timeout;
+            }
+        }
+        self.language_profile = file.language_profile();
+        self.max_document_size = file.max_document_size;
+
+        if let Some(Commands::Validate { min_tier, max_size, fail_on_ambiguity, .. }) = &mut self.command {
+            *min_tier = min_tier.or(file.thresholds.min_tier);
+            *max_size = max_size.or(file.thresholds.max_size);
+            *fail_on_ambiguity = fail_on_ambiguity.or(file.thresholds.fail_on_ambiguity);
+        }
+    }
+}
+
+/// Run the requested command
+async fn run(cli: &Cli) -> Result<()> {
     match cli.command {
-        Some(Commands::Validate { ref files, fail_fast }) => {
-            validate_files(&cli, files.clone(), fail_fast).await
+        Some(Commands::Validate {
+            ref files, fail_fast, min_tier, max_size, fail_on_ambiguity, ref since, fix, explain_tier,
+        }) => {
+            let gates = ValidationGates {
+                min_tier: min_tier.map(QualityTier::from),
+                max_size,
+                max_ambiguity: fail_on_ambiguity,
+            };
+            let files = match since {
+                Some(git_ref) => {
+                    let changed = filter_changed_files(&git_changed_files(git_ref)?, files);
+                    if changed.is_empty() {
+                        if !cli.quiet {
+                            eprintln!("{} No .aisp files changed since {}", "✓".green().bold(), git_ref);
+                        }
+                        return Ok(());
+                    }
+                    changed
+                }
+                None => files.clone(),
+            };
+            validate_files(cli, files, fail_fast, fix, explain_tier, &gates).await
         }
         Some(Commands::Analyze { ref file, symbols, complexity }) => {
-            analyze_file(&cli, file.clone(), symbols, complexity).await
+            analyze_file(cli, file.clone(), symbols, complexity).await
         }
         Some(Commands::Check { ref files }) => {
-            check_files(&cli, files.clone()).await
+            check_files(cli, files.clone()).await
         }
         Some(Commands::Format { ref file, in_place }) => {
-            format_file(&cli, file.clone(), in_place).await
+            format_file(cli, file.clone(), in_place).await
+        }
+        Some(Commands::Lint { ref files, fix }) => {
+            lint_files(cli, files, fix)
+        }
+        Some(Commands::Stats { ref path, top }) => {
+            corpus_stats(cli, path, top)
+        }
+        Some(Commands::Bench { ref file, iterations, warmup }) => {
+            bench_document(cli, file, iterations, warmup)
+        }
+        Some(Commands::Verify { ref file, timeout, ref properties }) => {
+            verify_document(cli, file, timeout, properties)
+        }
+        Some(Commands::Init { ref file, ref name, force }) => {
+            init_document(cli, file, name.as_deref(), force)
         }
         Some(Commands::Interactive) => {
-            interactive_mode(&cli).await
+            interactive_mode(cli).await
         }
         Some(Commands::Levels) => {
             show_validation_levels()
         }
+        Some(Commands::ListSymbols) => {
+            list_symbols(cli)
+        }
         None => {
             // Default behavior: validate input files
             if cli.input.is_empty() {
-                eprintln!("{}", "Error: No input files specified".red());
-                eprintln!("Use --help for usage information");
+                if !cli.quiet {
+                    eprintln!("{}", "Error: No input files specified".red());
+                    eprintln!("Use --help for usage information");
+                }
                 std::process::exit(1);
             }
-            validate_files(&cli, cli.input.clone(), false).await
+            validate_files(cli, cli.input.clone(), false, false, false, &ValidationGates::default()).await
         }
     }
 }
 
+/// Progress bar message naming the current file and the throughput so far
+fn progress_message(file: &Path, completed: u64, elapsed: Duration) -> String {
+    match throughput(completed, elapsed) {
+        Some(rate) => format!("{:.1} files/s, validating {}", rate, file.display()),
+        None => format!("Validating {}", file.display()),
+    }
+}
+
+/// Files validated per second, once any file has completed
+fn throughput(completed: u64, elapsed: Duration) -> Option<f64> {
+    let seconds = elapsed.as_secs_f64();
+    (completed > 0 && seconds > 0.0).then(|| completed as f64 / seconds)
+}
+
 /// Setup logging based on CLI options
 fn setup_logging(_cli: &Cli) -> Result<()> {
     // For now, just handle verbosity flags
@@ -249,8 +844,23 @@ fn setup_logging(_cli: &Cli) -> Result<()> {
 }
 
 /// Validate multiple files
-async fn validate_files(cli: &Cli, files: Vec<PathBuf>, fail_fast: bool) -> Result<()> {
-    if !cli.quiet {
+async fn validate_files(
+    cli: &Cli,
+    files: Vec<PathBuf>,
+    fail_fast: bool,
+    fix: bool,
+    explain_tier: bool,
+    gates: &ValidationGates,
+) -> Result<()> {
+    // A bad template fails before any document is validated
+    let template = load_report_template(cli)?;
+
+    // JSON lines are streamed as files complete, so stdout carries nothing else
+    let mut stream = match cli.format {
+        OutputFormat::Jsonl => Some(open_jsonl_sink(cli)?),
+        _ => None,
+    };
+    if !cli.quiet && stream.is_none() {
         println!("{} AISP Validator", "🔍".bold());
         println!();
     }
@@ -263,7 +873,7 @@ async fn validate_files(cli: &Cli, files: Vec<PathBuf>, fail_fast: bool) -> Resu
         let pb = ProgressBar::new(files.len() as u64);
         pb.set_style(
             ProgressStyle::default_bar()
-                .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} {msg}")
+                .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} (eta {eta}) {msg}")
                 .unwrap()
                 .progress_chars("#>-"),
         );
@@ -271,38 +881,81 @@ async fn validate_files(cli: &Cli, files: Vec<PathBuf>, fail_fast: bool) -> Resu
     } else {
         None
     };
-
-    for file in files {
+    let started = Instant::now();
+    let mut failed = false;
+    let mut reported = HashMap::new();
+
+    // Each result is reported as soon as it is ready, and returns whether
+    // validation should go on
+    let mut report = |index: usize, result: Result<CliValidationResult>| -> Result<bool> {
+        let result = result?;
+        if let Some(pb) = &progress {
+            pb.set_message(progress_message(&result.file, pb.position(), started.elapsed()));
+        }
+        if cli.strict_json && matches!(cli.format, OutputFormat::Json | OutputFormat::Jsonl) {
+            check_strict_json(&result)?;
+        }
+        if let Some(sink) = stream.as_mut() {
+            write_jsonl(sink, &result)?;
+        }
+        if let Some(dir) = &cli.output_dir {
+            write_report(cli, dir, &result, template.as_ref(), &mut reported)?;
+        }
         if let Some(pb) = &progress {
-            pb.set_message(format!("Validating {}", file.display()));
+            pb.inc(1);
         }
 
-        let result = validate_single_file(cli, &file).await?;
-        
-        if !result.valid {
+        let passed = result.valid && result.gate_failures.is_empty();
+        if !passed {
             total_errors += 1;
-            if fail_fast {
-                if let Some(pb) = progress {
-                    pb.finish_and_clear();
-                }
-                eprintln!("{} Validation failed for {}", "✗".red().bold(), file.display());
-                std::process::exit(1);
+            if fail_fast && !cli.quiet {
+                eprintln!("{} Validation failed for {}", "✗".red().bold(), result.file.display());
             }
         }
 
-        results.push(result);
-        
-        if let Some(pb) = &progress {
-            pb.inc(1);
+        // Streamed results are only kept when the profile or metrics need them
+        if stream.is_none() || cli.profile.is_some() || cli.emit_metrics.is_some() {
+            results.push((index, result));
+        }
+        failed |= !passed;
+        Ok(passed || !fail_fast)
+    };
+
+    if cli.jobs > 1 && files.len() > 1 {
+        validate_parallel(cli, &files, fix, explain_tier, gates, &mut report)?;
+    } else {
+        for (index, file) in files.iter().enumerate() {
+            if !report(index, validate_single_file(cli, file, fix, explain_tier, gates))? {
+                break;
+            }
         }
     }
 
+    // Workers finish out of order, but the summary follows the order of `files`
+    results.sort_by_key(|(index, _)| *index);
+    let results: Vec<CliValidationResult> = results.into_iter().map(|(_, result)| result).collect();
+
+    // The profile and metrics cover the files validated before a fail-fast stop
+    if let Some(profile_path) = &cli.profile {
+        write_profile(profile_path, &results)?;
+    }
+
+    if let (Some(format), Some(metrics_path)) = (cli.emit_metrics, &cli.metrics_file) {
+        write_metrics(metrics_path, format, &results)?;
+    }
+
     if let Some(pb) = progress {
         pb.finish_and_clear();
     }
 
+    if fail_fast && failed {
+        std::process::exit(1);
+    }
+
     // Output results
-    output_results(cli, &results)?;
+    if stream.is_none() {
+        output_results(cli, &results, template.as_ref())?;
+    }
 
     if total_errors > 0 {
         if !cli.quiet {
@@ -319,17 +972,152 @@ async fn validate_files(cli: &Cli, files: Vec<PathBuf>, fail_fast: bool) -> Resu
     Ok(())
 }
 
-/// Validate a single file
-async fn validate_single_file(cli: &Cli, file: &Path) -> Result<CliValidationResult> {
-    let source = fs::read_to_string(file)
-        .with_context(|| format!("Failed to read file {}", file.display()))?;
+/// Path argument that stands for standard input
+const STDIN_PATH: &str = "-";
+
+/// Name reported for a document read from standard input
+const STDIN_NAME: &str = "<stdin>";
+
+/// Read a document from a file, or from `stdin` when the path is `-`,
+/// returning the name to report it under along with its source
+fn read_document(path: &Path, mut stdin: impl Read) -> Result<(PathBuf, String)> {
+    if path == Path::new(STDIN_PATH) {
+        let mut source = String::new();
+        stdin
+            .read_to_string(&mut source)
+            .context("Failed to read document from stdin")?;
+        return Ok((PathBuf::from(STDIN_NAME), source));
+    }
+    let source = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read file {}", path.display()))?;
+    Ok((path.to_path_buf(), source))
+}
+
+/// Validate a single file, first writing back any remediations when `fix`
+/// is set, and explaining its density tier when `explain_tier` is set
+fn validate_single_file(
+    cli: &Cli,
+    file: &Path,
+    fix: bool,
+    explain_tier: bool,
+    gates: &ValidationGates,
+) -> Result<CliValidationResult> {
+    let (file, mut source) = read_document(file, io::stdin().lock())?;
+    if fix && file != Path::new(STDIN_NAME) {
+        source = apply_remediations(cli, &file, source)?;
+    }
+    let mut result = validate_source(cli, file, &source, gates);
+    if explain_tier {
+        result.tier_explanation = AispValidator::explain_tier(&source).to_string().lines().map(String::from).collect();
+    }
+    Ok(result)
+}
+
+/// Validate `files` on `cli.jobs` worker threads scheduled by
+/// `cli.parallel_strategy`, handing each result to `report` with the index
+/// of its file as soon as a worker finishes it. No further files are
+/// scheduled once `report` returns `false` or fails.
+fn validate_parallel(
+    cli: &Cli,
+    files: &[PathBuf],
+    fix: bool,
+    explain_tier: bool,
+    gates: &ValidationGates,
+    mut report: impl FnMut(usize, Result<CliValidationResult>) -> Result<bool>,
+) -> Result<()> {
+    let jobs = cli.jobs.clamp(1, files.len().max(1));
+    let sizes: Vec<u64> = files.iter().map(|file| fs::metadata(file).map_or(0, |meta| meta.len())).collect();
+    let next = AtomicUsize::new(0);
+    let stop = AtomicBool::new(false);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        let (next, stop) = (&next, &stop);
+        // Files already underway when validation stops are finished but
+        // no longer reported
+        let validate = move |index: usize, sender: &mpsc::Sender<_>| {
+            let result = validate_single_file(cli, &files[index], fix, explain_tier, gates);
+            let _ = sender.send((index, result));
+        };
+
+        match cli.parallel_strategy.resolve(&sizes) {
+            ParallelStrategy::Auto | ParallelStrategy::WorkStealing => {
+                for _ in 0..jobs {
+                    let sender = sender.clone();
+                    scope.spawn(move || loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        if index >= files.len() || stop.load(Ordering::Relaxed) {
+                            break;
+                        }
+                        validate(index, &sender);
+                    });
+                }
+            }
+            ParallelStrategy::Chunked => {
+                let share = files.len().div_ceil(jobs);
+                for start in (0..files.len()).step_by(share) {
+                    let sender = sender.clone();
+                    scope.spawn(move || {
+                        for index in start..(start + share).min(files.len()) {
+                            if stop.load(Ordering::Relaxed) {
+                                break;
+                            }
+                            validate(index, &sender);
+                        }
+                    });
+                }
+            }
+        }
+        drop(sender);
+
+        for (index, result) in &receiver {
+            let proceed = report(index, result);
+            if !matches!(proceed, Ok(true)) {
+                stop.store(true, Ordering::Relaxed);
+                return proceed.map(|_| ());
+            }
+        }
+        Ok(())
+    })
+}
+
+/// Apply the patches suggested by validation to `source`, writing the file
+/// back when any applied
+fn apply_remediations(cli: &Cli, path: &Path, source: String) -> Result<String> {
+    let result = AispValidator::with_config(validation_config(cli)).validate(&source);
+    let (patched, applied) = AispValidator::apply_remediations(&source, &result.warnings);
+    if applied == 0 {
+        return Ok(source);
+    }
+    fs::write(path, &patched).with_context(|| format!("Failed to write fixed file {}", path.display()))?;
+    Ok(patched)
+}
 
-    // Create validation config
+/// Validator configuration for the selected level and flags
+fn validation_config(cli: &Cli) -> ValidationConfig {
     let mut config = ValidationConfig::default();
     config.strict_mode = cli.strict;
-    config.include_timing = cli.timing;
+    config.deny_warnings = cli.deny_warnings;
+    config.min_verified_properties = cli.min_verified_properties;
+    config.meta_key_order = cli.meta_key_order.clone();
+    if let Some(min_symbol_density) = cli.min_symbol_density {
+        config.min_symbol_density = min_symbol_density;
+    }
+    config.include_timing = cli.timing || cli.profile.is_some();
     config.include_ast = cli.include_ast;
     config.include_symbol_stats = true;
+    config.timeout = cli.timeout;
+    if let Some(profile) = &cli.language_profile {
+        config.profile = profile.clone();
+    }
+    if let Some(max_document_size) = cli.max_document_size {
+        config.max_document_size = max_document_size;
+    }
+    config.enable_relational_analysis = matches!(
+        cli.level,
+        ValidationLevel::Relational | ValidationLevel::Temporal | ValidationLevel::Formal
+    );
+    config.enable_temporal_analysis = matches!(cli.level, ValidationLevel::Temporal | ValidationLevel::Formal);
 
     // Set validation level
     match cli.level {
@@ -343,12 +1131,28 @@ async fn validate_single_file(cli: &Cli, file: &Path) -> Result<CliValidationRes
         _ => {}
     }
 
-    let validator = AispValidator::with_config(config);
-    let validation_result = validator.validate(&source);
+    config
+}
+
+/// Parse a `--timeout` value in seconds, which may be fractional
+fn parse_seconds(value: &str) -> std::result::Result<Duration, String> {
+    let seconds: f64 = value.parse().map_err(|_| format!("`{}` is not a number of seconds", value))?;
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("`{}` is not a non-negative number of seconds", value))
+}
+
+/// Validate a document already read into memory
+fn validate_source(
+    cli: &Cli,
+    file: PathBuf,
+    source: &str,
+    gates: &ValidationGates,
+) -> CliValidationResult {
+    let validator = AispValidator::with_config(validation_config(cli));
+    let validation_result = validator.validate(source);
 
     // Convert to CLI result
     let mut cli_result = CliValidationResult {
-        file: file.to_path_buf(),
+        file,
         valid: validation_result.valid,
         tier: validation_result.tier_name.clone(),
         tier_symbol: validation_result.tier_symbol.clone(),
@@ -365,30 +1169,53 @@ async fn validate_single_file(cli: &Cli, file: &Path) -> Result<CliValidationRes
         } else {
             Vec::new()
         },
+        remediations: validation_result
+            .warnings
+            .iter()
+            .filter_map(|warning| Some(warning.remediation.as_ref()?.to_string()))
+            .collect(),
+        tier_explanation: Vec::new(),
+        gate_failures: gates.check(
+            validation_result.tier,
+            validation_result.document_size,
+            validation_result.ambiguity,
+        ),
+        timed_out: validation_result.timed_out,
         relational_analysis: None,
         temporal_analysis: None,
         formal_verification: None,
+        capabilities: if cli.verbose {
+            validation_result.capabilities().stages.clone()
+        } else {
+            Vec::new()
+        },
+        profile: validation_result.profile.as_ref().map(ProfileNode::from),
     };
 
     // Add analysis summaries based on validation level
-    if let Some(semantic) = &validation_result.semantic_analysis {
-        if let Some(rel_analysis) = &semantic.relational_analysis() {
-            cli_result.relational_analysis = Some(RelationalSummary {
-                consistency_score: rel_analysis.consistency_score,
-                constraints_total: rel_analysis.constraint_analysis.constraints.len(),
-                constraints_satisfied: rel_analysis.constraint_analysis.satisfied.len(),
-                conflicts: rel_analysis.conflict_analysis.conflicts.len(),
-            });
-        }
+    if let Some(rel_analysis) = &validation_result.relational_analysis {
+        cli_result.relational_analysis = Some(RelationalSummary {
+            consistency_score: rel_analysis.consistency_score,
+            constraints_total: rel_analysis.constraint_analysis.constraints.len(),
+            constraints_satisfied: rel_analysis.constraint_analysis.satisfied.len(),
+            conflicts: rel_analysis.conflict_analysis.conflicts.len(),
+            unsat_core: rel_analysis.constraint_analysis.unsat_core.clone(),
+            witness: rel_analysis
+                .constraint_analysis
+                .witness
+                .as_ref()
+                .map(|witness| witness.iter().map(|(name, value)| (name.clone(), value.clone())).collect()),
+        });
+    }
 
-        if let Some(temp_analysis) = &semantic.temporal_analysis() {
-            cli_result.temporal_analysis = Some(TemporalSummary {
-                consistency_score: temp_analysis.consistency_score,
-                ltl_formulas: temp_analysis.formula_analysis.formulas.len(),
-                ctl_formulas: temp_analysis.formula_analysis.formulas.len(), // Both use same formula set
-                patterns_detected: temp_analysis.pattern_analysis.patterns.len(),
-            });
-        }
+    if let Some(temp_analysis) = &validation_result.temporal_analysis {
+        cli_result.temporal_analysis = Some(TemporalSummary {
+            consistency_score: temp_analysis.consistency_score,
+            ltl_formulas: count_formulas(&temp_analysis.formula_analysis.formulas, FormulaType::LTL),
+            ctl_formulas: count_formulas(&temp_analysis.formula_analysis.formulas, FormulaType::CTL),
+            patterns_detected: temp_analysis.pattern_analysis.patterns.len(),
+            max_temporal_depth: temp_analysis.operator_analysis.complexity.max_temporal_depth,
+        });
     }
 
     if let Some(formal) = &validation_result.formal_verification {
@@ -413,13 +1240,23 @@ async fn validate_single_file(cli: &Cli, file: &Path) -> Result<CliValidationRes
         });
     }
 
-    Ok(cli_result)
+    cli_result
+}
+
+fn count_formulas(formulas: &[AnalyzedFormula], formula_type: FormulaType) -> usize {
+    formulas.iter().filter(|formula| formula.formula_type == formula_type).count()
 }
 
 /// Output validation results
-fn output_results(cli: &Cli, results: &[CliValidationResult]) -> Result<()> {
+fn output_results(cli: &Cli, results: &[CliValidationResult], template: Option<&ReportTemplate>) -> Result<()> {
+    if let (Some(template), OutputFormat::Human | OutputFormat::Detailed) = (template, &cli.format) {
+        return output_template(cli, template, results);
+    }
     match cli.format {
         OutputFormat::Json => output_json(cli, results),
+        OutputFormat::Jsonl => write_output(cli, render_jsonl(results)?.trim_end()),
+        OutputFormat::Toml => output_toml(cli, results),
+        OutputFormat::Yaml => output_yaml(cli, results),
         OutputFormat::Human => output_human(cli, results, false),
         OutputFormat::Detailed => output_human(cli, results, true),
         OutputFormat::Minimal => output_minimal(cli, results),
@@ -428,114 +1265,467 @@ fn output_results(cli: &Cli, results: &[CliValidationResult]) -> Result<()> {
 
 /// Output results in JSON format
 fn output_json(cli: &Cli, results: &[CliValidationResult]) -> Result<()> {
-    let json = if results.len() == 1 {
-        serde_json::to_string_pretty(&results[0])?
-    } else {
-        serde_json::to_string_pretty(results)?
-    };
-
-    write_output(cli, &json)?;
+    write_output(cli, &render_json(results, cli.json_compact)?)?;
     Ok(())
 }
 
-/// Output results in human-readable format
-fn output_human(cli: &Cli, results: &[CliValidationResult], detailed: bool) -> Result<()> {
-    for result in results {
-        if !cli.quiet {
-            println!("{}", format!("File: {}", result.file.display()).bold());
-            
-            // Validation status
-            if result.valid {
-                println!("  Status: {} {}", "✓".green().bold(), "Valid".green().bold());
-            } else {
-                println!("  Status: {} {}", "✗".red().bold(), "Invalid".red().bold());
-            }
-
-            // Quality tier
-            println!("  Quality: {} {} (δ={:.3}, ambiguity={:.3})", 
-                result.tier_symbol.blue().bold(),
-                result.tier.blue(),
-                result.delta,
-                result.ambiguity
-            );
-
-            // Document size
-            println!("  Size: {} bytes", result.document_size);
-
-            // Timing information
-            if let Some(total_ms) = result.total_time_ms {
-                println!("  Time: {}ms total", total_ms);
-                if detailed {
-                    if let Some(parse_ms) = result.parse_time_ms {
-                        println!("    Parse: {}ms", parse_ms);
-                    }
-                    if let Some(semantic_ms) = result.semantic_time_ms {
-                        println!("    Semantic: {}ms", semantic_ms);
-                    }
-                }
-            }
-
-            // Analysis results
-            if detailed {
-                if let Some(rel) = &result.relational_analysis {
-                    println!("  Relational Analysis:");
-                    println!("    Consistency: {:.3}", rel.consistency_score);
-                    println!("    Constraints: {}/{} satisfied", 
-                        rel.constraints_satisfied, rel.constraints_total);
-                    if rel.conflicts > 0 {
-                        println!("    Conflicts: {}", rel.conflicts.to_string().red());
-                    }
-                }
-
-                if let Some(temp) = &result.temporal_analysis {
-                    println!("  Temporal Analysis:");
-                    println!("    Consistency: {:.3}", temp.consistency_score);
-                    println!("    LTL Formulas: {}", temp.ltl_formulas);
-                    println!("    CTL Formulas: {}", temp.ctl_formulas);
-                    println!("    Patterns: {}", temp.patterns_detected);
-                }
-
-                if let Some(formal) = &result.formal_verification {
-                    println!("  Formal Verification:");
-                    println!("    Status: {}", formal.status);
-                    println!("    Properties: {}/{} proven", 
-                        formal.properties_proven, formal.properties_checked);
-                    println!("    Time: {}ms", formal.verification_time_ms);
-                }
-            }
-
-            // Warnings and errors
-            if !result.warnings.is_empty() {
-                println!("  Warnings:");
-                for warning in &result.warnings {
-                    println!("    {}", warning.yellow());
-                }
-            }
+/// Render results as JSON, a lone result as an object rather than an array
+fn render_json(results: &[CliValidationResult], compact: bool) -> Result<String> {
+    let json = match (results, compact) {
+        ([result], false) => serde_json::to_string_pretty(result)?,
+        ([result], true) => serde_json::to_string(result)?,
+        (_, false) => serde_json::to_string_pretty(results)?,
+        (_, true) => serde_json::to_string(results)?,
+    };
+    Ok(json)
+}
 
-            if !result.errors.is_empty() {
-                println!("  Errors:");
-                for error in &result.errors {
-                    println!("    {}", error.red());
-                }
-            }
+/// Fields that serialize as `null` when absent rather than being omitted
+const NULLABLE_JSON_FIELDS: &[&str] = &["parse_time_ms", "semantic_time_ms", "total_time_ms"];
 
-            println!();
-        }
+/// Check that `result` serializes without unexpected nulls and reads back
+/// unchanged; non-finite numbers, for instance, silently become `null`
+fn check_strict_json(result: &CliValidationResult) -> Result<()> {
+    let value = serde_json::to_value(result)?;
+    if let Some(field) = null_json_fields(&value, "").first() {
+        anyhow::bail!("Strict JSON: `{}` is null in the result for {}", field, result.file.display());
     }
 
+    let restored: CliValidationResult = serde_json::from_value(value)
+        .with_context(|| format!("Strict JSON: result for {} does not read back", result.file.display()))?;
+    if restored != (CliValidationResult { profile: None, ..result.clone() }) {
+        anyhow::bail!("Strict JSON: result for {} changes when read back", result.file.display());
+    }
     Ok(())
 }
 
-/// Output results in minimal format
-fn output_minimal(cli: &Cli, results: &[CliValidationResult]) -> Result<()> {
+/// Paths of `null` values in `value`, other than the nullable top-level fields
+fn null_json_fields(value: &serde_json::Value, path: &str) -> Vec<String> {
+    match value {
+        serde_json::Value::Null => vec![path.to_string()],
+        serde_json::Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .flat_map(|(index, item)| null_json_fields(item, &format!("{}[{}]", path, index)))
+            .collect(),
+        serde_json::Value::Object(fields) => fields
+            .iter()
+            .filter(|(key, field)| !(path.is_empty() && field.is_null() && NULLABLE_JSON_FIELDS.contains(&key.as_str())))
+            .flat_map(|(key, field)| {
+                let path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                null_json_fields(field, &path)
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Destination for streamed JSON lines: the output file, or stdout
+fn open_jsonl_sink(cli: &Cli) -> Result<Box<dyn Write>> {
+    Ok(match &cli.output {
+        Some(path) => Box::new(io::BufWriter::new(
+            fs::File::create(path).with_context(|| format!("Failed to write to {}", path.display()))?,
+        )),
+        None if cli.quiet => Box::new(io::sink()),
+        None => Box::new(io::stdout()),
+    })
+}
+
+/// Write one result as a single JSON line and flush it
+fn write_jsonl(sink: &mut dyn Write, result: &CliValidationResult) -> Result<()> {
+    serde_json::to_writer(&mut *sink, result)?;
+    sink.write_all(b"\n")?;
+    sink.flush()?;
+    Ok(())
+}
+
+/// Render results as JSON lines, one compact object per result
+fn render_jsonl(results: &[CliValidationResult]) -> Result<String> {
+    let mut buffer = Vec::new();
+    for result in results {
+        write_jsonl(&mut buffer, result)?;
+    }
+    Ok(String::from_utf8(buffer)?)
+}
+
+/// Output results in TOML format
+fn output_toml(cli: &Cli, results: &[CliValidationResult]) -> Result<()> {
+    write_output(cli, &render_toml(results)?)
+}
+
+/// Output results in YAML format
+fn output_yaml(cli: &Cli, results: &[CliValidationResult]) -> Result<()> {
+    write_output(cli, &render_yaml(results)?)
+}
+
+/// Render results as TOML, always wrapped in a `results` table array
+fn render_toml(results: &[CliValidationResult]) -> Result<String> {
+    let report = CliValidationReport {
+        results: results.to_vec(),
+    };
+    toml::to_string_pretty(&report).context("Failed to serialize results as TOML")
+}
+
+/// Render results as YAML, mirroring the JSON single/multiple shape
+fn render_yaml(results: &[CliValidationResult]) -> Result<String> {
+    let yaml = if results.len() == 1 {
+        serde_yaml::to_string(&results[0])
+    } else {
+        serde_yaml::to_string(results)
+    };
+    yaml.context("Failed to serialize results as YAML")
+}
+
+/// Write `--profile` timing trees, mirroring the JSON single/multiple shape
+fn write_profile(path: &Path, results: &[CliValidationResult]) -> Result<()> {
+    let profiles: Vec<FileProfile> = results
+        .iter()
+        .filter_map(|result| {
+            result.profile.clone().map(|profile| FileProfile {
+                file: result.file.clone(),
+                profile,
+            })
+        })
+        .collect();
+
+    let json = if profiles.len() == 1 {
+        serde_json::to_string_pretty(&profiles[0])?
+    } else {
+        serde_json::to_string_pretty(&profiles)?
+    };
+
+    fs::write(path, json)
+        .with_context(|| format!("Failed to write profile to {}", path.display()))
+}
+
+/// Write aggregate metrics for a run to `path`
+fn write_metrics(path: &Path, format: MetricsFormat, results: &[CliValidationResult]) -> Result<()> {
+    let rendered = match format {
+        MetricsFormat::Prometheus => render_prometheus(results),
+    };
+    fs::write(path, rendered)
+        .with_context(|| format!("Failed to write metrics to {}", path.display()))
+}
+
+/// Document counts by tier, failures and mean δ in Prometheus text
+/// exposition format
+fn render_prometheus(results: &[CliValidationResult]) -> String {
+    let tiers = [
+        QualityTier::Platinum,
+        QualityTier::Gold,
+        QualityTier::Silver,
+        QualityTier::Bronze,
+        QualityTier::Reject,
+    ];
+    let failures = results
+        .iter()
+        .filter(|result| !result.valid || !result.gate_failures.is_empty())
+        .count();
+    let delta_average = if results.is_empty() {
+        0.0
+    } else {
+        results.iter().map(|result| result.delta).sum::<f64>() / results.len() as f64
+    };
+
+    let mut out = String::new();
+    out.push_str("# HELP aisp_documents_total Documents validated in the run.\n");
+    out.push_str("# TYPE aisp_documents_total gauge\n");
+    out.push_str(&format!("aisp_documents_total {}\n", results.len()));
+    out.push_str("# HELP aisp_documents_by_tier Documents validated in the run, by quality tier.\n");
+    out.push_str("# TYPE aisp_documents_by_tier gauge\n");
+    for tier in tiers {
+        let count = results.iter().filter(|result| result.tier == tier.name()).count();
+        out.push_str(&format!("aisp_documents_by_tier{{tier=\"{}\"}} {}\n", tier.name().to_lowercase(), count));
+    }
+    out.push_str("# HELP aisp_validation_failures Documents that failed validation or a gate.\n");
+    out.push_str("# TYPE aisp_validation_failures gauge\n");
+    out.push_str(&format!("aisp_validation_failures {}\n", failures));
+    out.push_str("# HELP aisp_delta_average Mean semantic density (delta) across documents.\n");
+    out.push_str("# TYPE aisp_delta_average gauge\n");
+    out.push_str(&format!("aisp_delta_average {}\n", delta_average));
+    out
+}
+
+/// Output results in human-readable format
+fn output_human(cli: &Cli, results: &[CliValidationResult], detailed: bool) -> Result<()> {
+    if !cli.quiet && !results.is_empty() {
+        println!("{}", render_human(results, detailed));
+    }
+    Ok(())
+}
+
+/// Render results in human-readable format, colored unless disabled
+fn render_human(results: &[CliValidationResult], detailed: bool) -> String {
+    let mut lines = Vec::new();
+    for result in results {
+        lines.push(format!("File: {}", result.file.display()).bold().to_string());
+        
+        // Validation status
+        if result.valid {
+            lines.push(format!("  Status: {} {}", "✓".green().bold(), "Valid".green().bold()));
+        } else if result.timed_out {
+            lines.push(format!("  Status: {} {}", "✗".red().bold(), "Timed out, partial result".red().bold()));
+        } else {
+            lines.push(format!("  Status: {} {}", "✗".red().bold(), "Invalid".red().bold()));
+        }
+
+        // Quality tier
+        lines.push(format!("  Quality: {} {} (δ={:.3}, ambiguity={:.3})", 
+            result.tier_symbol.blue().bold(),
+            result.tier.blue(),
+            result.delta,
+            result.ambiguity
+        ));
+
+        // Document size
+        lines.push(format!("  Size: {} bytes", result.document_size));
+
+        // Timing information
+        if let Some(total_ms) = result.total_time_ms {
+            lines.push(format!("  Time: {}ms total", total_ms));
+            if detailed {
+                if let Some(parse_ms) = result.parse_time_ms {
+                    lines.push(format!("    Parse: {}ms", parse_ms));
+                }
+                if let Some(semantic_ms) = result.semantic_time_ms {
+                    lines.push(format!("    Semantic: {}ms", semantic_ms));
+                }
+            }
+        }
+
+        // Analysis results
+        if detailed {
+            if let Some(rel) = &result.relational_analysis {
+                lines.push("  Relational Analysis:".to_string());
+                lines.push(format!("    Consistency: {:.3}", rel.consistency_score));
+                lines.push(format!("    Constraints: {}/{} satisfied", 
+                    rel.constraints_satisfied, rel.constraints_total));
+                if rel.conflicts > 0 {
+                    lines.push(format!("    Conflicts: {}", rel.conflicts.to_string().red()));
+                }
+                if let Some(witness) = rel.witness.as_ref().filter(|witness| !witness.is_empty()) {
+                    let assignments: Vec<String> =
+                        witness.iter().map(|(name, value)| format!("{} = {}", name, value)).collect();
+                    lines.push(format!("    Witness: {}", assignments.join(", ")));
+                }
+                if !rel.unsat_core.is_empty() {
+                    lines.push(format!("    Unsatisfiable together: {}", rel.unsat_core.join(", ").red()));
+                }
+            }
+
+            if let Some(temp) = &result.temporal_analysis {
+                lines.push("  Temporal Analysis:".to_string());
+                lines.push(format!("    Consistency: {:.3}", temp.consistency_score));
+                lines.push(format!("    LTL Formulas: {}", temp.ltl_formulas));
+                lines.push(format!("    CTL Formulas: {}", temp.ctl_formulas));
+                lines.push(format!("    Patterns: {}", temp.patterns_detected));
+                lines.push(format!("    Max Temporal Depth: {}", temp.max_temporal_depth));
+            }
+
+            if let Some(formal) = &result.formal_verification {
+                lines.push("  Formal Verification:".to_string());
+                lines.push(format!("    Status: {}", formal.status));
+                lines.push(format!("    Properties: {}/{} proven", 
+                    formal.properties_proven, formal.properties_checked));
+                lines.push(format!("    Time: {}ms", formal.verification_time_ms));
+            }
+        }
+
+        if !result.capabilities.is_empty() {
+            lines.push("  Capabilities:".to_string());
+            for capability in &result.capabilities {
+                lines.push(format!("    {}", capability));
+            }
+        }
+
+        // Warnings and errors
+        if !result.warnings.is_empty() {
+            lines.push("  Warnings:".to_string());
+            for warning in &result.warnings {
+                lines.push(format!("    {}", warning.yellow()));
+            }
+        }
+
+        if !result.errors.is_empty() {
+            lines.push("  Errors:".to_string());
+            for error in &result.errors {
+                lines.push(format!("    {}", error.red()));
+            }
+        }
+
+        if !result.remediations.is_empty() {
+            lines.push("  Fixes:".to_string());
+            for remediation in &result.remediations {
+                lines.push(format!("    {}", remediation.cyan()));
+            }
+        }
+
+        if !result.tier_explanation.is_empty() {
+            lines.push("  Tier:".to_string());
+            for line in &result.tier_explanation {
+                lines.push(format!("    {}", line));
+            }
+        }
+
+        if !result.gate_failures.is_empty() {
+            lines.push("  Gates:".to_string());
+            for failure in &result.gate_failures {
+                lines.push(format!("    {}", failure.red()));
+            }
+        }
+
+        lines.push(String::new());
+    }
+    lines.join("\n")
+}
+
+/// Read and validate the `--report-template` file, if one was given
+///
+/// Only human-readable output goes through a template, so any other
+/// format is an error rather than silently ignoring it.
+fn load_report_template(cli: &Cli) -> Result<Option<ReportTemplate>> {
+    if cli.report_template.is_some() && !matches!(cli.format, OutputFormat::Human | OutputFormat::Detailed) {
+        anyhow::bail!("--report-template only applies to the human and detailed formats");
+    }
+    cli.report_template
+        .as_deref()
+        .map(|path| {
+            let source = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read report template {}", path.display()))?;
+            ReportTemplate::parse(&source)
+                .with_context(|| format!("Invalid report template {}", path.display()))
+        })
+        .transpose()
+}
+
+/// Output results through a report template
+fn output_template(cli: &Cli, template: &ReportTemplate, results: &[CliValidationResult]) -> Result<()> {
+    if !results.is_empty() {
+        write_output(cli, &render_template(template, results)?)?;
+    }
+    Ok(())
+}
+
+/// Render results through a report template, which sees them as `results`
+fn render_template(template: &ReportTemplate, results: &[CliValidationResult]) -> Result<String> {
+    let context = serde_json::json!({ "results": serde_json::to_value(results)? });
+    Ok(template.render(&context))
+}
+
+/// Output results in minimal format
+fn output_minimal(cli: &Cli, results: &[CliValidationResult]) -> Result<()> {
     for result in results {
-        let status = if result.valid { "✓" } else { "✗" };
-        let line = format!("{} {} {}", status, result.file.display(), result.tier_symbol);
-        write_output(cli, &line)?;
+        write_output(cli, &render_minimal(result))?;
     }
     Ok(())
 }
 
+/// Render one result as a status line followed by its warnings
+fn render_minimal(result: &CliValidationResult) -> String {
+    let status = if result.valid && result.gate_failures.is_empty() { "✓" } else { "✗" };
+    let mut line = format!("{} {} {}", status, result.file.display(), result.tier_symbol);
+    if result.timed_out {
+        line.push_str(" (timed out)");
+    }
+    for warning in &result.warnings {
+        line.push_str(&format!("\n  ⚠ {}", warning));
+    }
+    for remediation in &result.remediations {
+        line.push_str(&format!("\n  ↳ {}", remediation));
+    }
+    for explanation in &result.tier_explanation {
+        line.push_str(&format!("\n  {}", explanation));
+    }
+    line
+}
+
+/// Write the `--output-dir` report for one result, creating directories
+/// as needed
+///
+/// `reported` maps each report already written to its file, so two files
+/// sharing a report path fail instead of overwriting each other.
+fn write_report(
+    cli: &Cli,
+    dir: &Path,
+    result: &CliValidationResult,
+    template: Option<&ReportTemplate>,
+    reported: &mut HashMap<PathBuf, PathBuf>,
+) -> Result<PathBuf> {
+    let path = report_path(dir, &result.file, &cli.format);
+    if let Some(earlier) = reported.insert(path.clone(), result.file.clone()) {
+        anyhow::bail!(
+            "Reports for {} and {} would both be written to {}",
+            earlier.display(),
+            result.file.display(),
+            path.display()
+        );
+    }
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create report directory {}", parent.display()))?;
+    }
+
+    let results = std::slice::from_ref(result);
+    let report = match (&cli.format, template) {
+        (OutputFormat::Human | OutputFormat::Detailed, Some(template)) => render_template(template, results)?,
+        (OutputFormat::Json, _) => render_json(results, cli.json_compact)?,
+        (OutputFormat::Jsonl, _) => render_jsonl(results)?,
+        (OutputFormat::Toml, _) => render_toml(results)?,
+        (OutputFormat::Yaml, _) => render_yaml(results)?,
+        (OutputFormat::Human, None) => render_template(&ReportTemplate::builtin(), results)?,
+        (OutputFormat::Detailed, None) => strip_ansi(&render_human(results, true)),
+        (OutputFormat::Minimal, _) => render_minimal(result) + "\n",
+    };
+    fs::write(&path, report).with_context(|| format!("Failed to write report to {}", path.display()))?;
+    Ok(path)
+}
+
+/// Report location for a validated file: its path relative to the working
+/// directory, or its file name when outside it, with the format's extension
+fn report_path(dir: &Path, file: &Path, format: &OutputFormat) -> PathBuf {
+    let relative = if file == Path::new(STDIN_NAME) {
+        PathBuf::from("stdin")
+    } else if file.is_absolute() {
+        std::env::current_dir()
+            .ok()
+            .and_then(|cwd| file.strip_prefix(cwd).ok().map(Path::to_path_buf))
+            .or_else(|| file.file_name().map(PathBuf::from))
+            .unwrap_or_default()
+    } else {
+        file.to_path_buf()
+    };
+    let mirrored: PathBuf = relative
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part),
+            _ => None,
+        })
+        .collect();
+
+    let extension = match format {
+        OutputFormat::Json => "json",
+        OutputFormat::Jsonl => "jsonl",
+        OutputFormat::Toml => "toml",
+        OutputFormat::Yaml => "yaml",
+        OutputFormat::Human | OutputFormat::Detailed | OutputFormat::Minimal => "txt",
+    };
+    dir.join(mirrored).with_extension(extension)
+}
+
+/// Remove terminal color sequences so report files stay plain text
+fn strip_ansi(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            plain.push(c);
+        }
+    }
+    plain
+}
+
 /// Write output to file or stdout
 fn write_output(cli: &Cli, content: &str) -> Result<()> {
     match &cli.output {
@@ -543,6 +1733,7 @@ fn write_output(cli: &Cli, content: &str) -> Result<()> {
             fs::write(output_file, content)
                 .with_context(|| format!("Failed to write to {}", output_file.display()))?;
         }
+        None if cli.quiet => {}
         None => {
             println!("{}", content);
         }
@@ -552,33 +1743,487 @@ fn write_output(cli: &Cli, content: &str) -> Result<()> {
 
 /// Analyze a single file in detail
 async fn analyze_file(cli: &Cli, file: PathBuf, _symbols: bool, _complexity: bool) -> Result<()> {
-    println!("{} Analyzing {}", "🔬".bold(), file.display().to_string().cyan());
+    let name = if file == Path::new(STDIN_PATH) { PathBuf::from(STDIN_NAME) } else { file.clone() };
+    println!("{} Analyzing {}", "🔬".bold(), name.display().to_string().cyan());
     
     // For now, just run detailed validation
     let mut detailed_cli = cli.clone();
     detailed_cli.format = OutputFormat::Detailed;
     
-    validate_single_file(&detailed_cli, &file).await?;
+    validate_single_file(&detailed_cli, &file, false, false, &ValidationGates::default())?;
     
     Ok(())
 }
 
+/// Files changed between `git_ref` and the working tree, relative to the
+/// current directory, excluding deletions
+fn git_changed_files(git_ref: &str) -> Result<Vec<PathBuf>> {
+    let output = std::process::Command::new("git")
+        .args(["diff", "--name-only", "--relative", "--diff-filter=d", git_ref, "--"])
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git diff against {} failed: {}",
+            git_ref,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).lines().map(PathBuf::from).collect())
+}
+
+/// Keep the `.aisp` files among `changed` that lie under one of `roots`,
+/// or all of them when no roots are given
+fn filter_changed_files(changed: &[PathBuf], roots: &[PathBuf]) -> Vec<PathBuf> {
+    let normalize = |path: &Path| -> PathBuf {
+        path.components().filter(|component| *component != std::path::Component::CurDir).collect()
+    };
+    let roots: Vec<PathBuf> = roots.iter().map(|root| normalize(root)).collect();
+
+    let mut files: Vec<PathBuf> = changed
+        .iter()
+        .map(|path| normalize(path))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "aisp"))
+        .filter(|path| roots.is_empty() || roots.iter().any(|root| path.starts_with(root)))
+        .collect();
+    files.sort();
+    files.dedup();
+    files
+}
+
 /// Check syntax of files (fast mode)
 async fn check_files(cli: &Cli, files: Vec<PathBuf>) -> Result<()> {
     let mut syntax_cli = cli.clone();
     syntax_cli.level = ValidationLevel::Syntax;
     syntax_cli.format = OutputFormat::Minimal;
     
-    validate_files(&syntax_cli, files, false).await
+    validate_files(&syntax_cli, files, false, false, false, &ValidationGates::default()).await
 }
 
 /// Format AISP file
-async fn format_file(_cli: &Cli, _file: PathBuf, _in_place: bool) -> Result<()> {
-    // TODO: Implement AISP formatter
-    eprintln!("{} Formatting not yet implemented", "⚠️".yellow());
+async fn format_file(cli: &Cli, file: PathBuf, in_place: bool) -> Result<()> {
+    let source = fs::read_to_string(&file)
+        .with_context(|| format!("Failed to read file {}", file.display()))?;
+    let formatted = aisp_core::parser::format_document_with_meta_order(&source, &cli.meta_key_order)
+        .map_err(|error| anyhow::anyhow!("Cannot format {}: {}", file.display(), error))?;
+
+    if in_place {
+        fs::write(&file, formatted)
+            .with_context(|| format!("Failed to write {}", file.display()))?;
+    } else {
+        write_output(cli, formatted.trim_end())?;
+    }
+    Ok(())
+}
+
+/// Lint files, exiting non-zero while findings remain
+fn lint_files(cli: &Cli, files: &[PathBuf], fix: bool) -> Result<()> {
+    let reports = files.iter().map(|file| lint_file(file, fix)).collect::<Result<Vec<_>>>()?;
+
+    match cli.format {
+        OutputFormat::Json => write_output(cli, &serde_json::to_string_pretty(&reports)?)?,
+        OutputFormat::Yaml => write_output(cli, &serde_yaml::to_string(&reports)?)?,
+        _ => {
+            let mut lines = Vec::new();
+            for report in &reports {
+                let file = report.file.display();
+                lines.extend(report.fixed.iter().map(|finding| format!("{}:{} {}", file, finding, "(fixed)".green())));
+                lines.extend(report.findings.iter().map(|finding| format!("{}:{}", file, finding.yellow())));
+            }
+            if !lines.is_empty() {
+                write_output(cli, &lines.join("\n"))?;
+            }
+        }
+    }
+
+    if reports.iter().any(|report| !report.findings.is_empty()) {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Lint one file, first writing back any fixes when `fix` is set
+fn lint_file(path: &Path, fix: bool) -> Result<LintReport> {
+    let source = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read file {}", path.display()))?;
+
+    let (linted, fixed) = if fix {
+        let (fixed_source, resolved) = AispValidator::lint_fix(&source);
+        if fixed_source != source {
+            fs::write(path, &fixed_source)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+        (fixed_source, resolved)
+    } else {
+        (source, Vec::new())
+    };
+
+    Ok(LintReport {
+        file: path.to_path_buf(),
+        fixed: fixed.iter().map(ToString::to_string).collect(),
+        findings: AispValidator::lint(&linted).iter().map(ToString::to_string).collect(),
+    })
+}
+
+/// Scaffold a new document at `path` and validate it
+fn init_document(cli: &Cli, path: &Path, name: Option<&str>, force: bool) -> Result<()> {
+    if path.exists() && !force {
+        anyhow::bail!("{} already exists; pass --force to overwrite it", path.display());
+    }
+
+    let name = match name {
+        Some(name) => name.to_string(),
+        None => path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default(),
+    };
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')) {
+        anyhow::bail!("Invalid document name `{}`: use letters, digits, `-`, `_` or `.`, or pass --name", name);
+    }
+
+    // Validated before writing, so a broken scaffold never lands on disk
+    let source = scaffold_document(&name, &today());
+    let result = validate_source(cli, path.to_path_buf(), &source, &ValidationGates::default());
+    if !result.valid {
+        anyhow::bail!("Scaffold for {} failed validation: {}", path.display(), result.errors.join("; "));
+    }
+    fs::write(path, &source).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    if !cli.quiet {
+        write_output(cli, &format!("{} Created {} ({})", "✓".green(), path.display(), result.tier))?;
+    }
+    Ok(())
+}
+
+/// Minimal valid AISP 5.1 document with every required block
+fn scaffold_document(name: &str, date: &str) -> String {
+    format!(
+        "𝔸5.1.{name}@{date}
+
+⟦Ω:Meta⟧{{
+  // Domain, purpose and other document metadata
+  domain≜{name}
+}}
+
+⟦Σ:Types⟧{{
+  // Types used by the rules and functions below
+  State≜{{Idle,Busy}}
+}}
+
+⟦Γ:Rules⟧{{
+  // Invariants over the types
+  ∀s:State→Valid(s)
+}}
+
+⟦Λ:Funcs⟧{{
+  // Function definitions
+  id≜λx.x
+}}
+
+// Evidence: claimed symbol density (δ)
+⟦Ε⟧⟨δ≜0.5⟩
+"
+    )
+}
+
+/// Current UTC date as `YYYY-MM-DD`
+fn today() -> String {
+    chrono::Utc::now().format("%Y-%m-%d").to_string()
+}
+
+/// Print statistics aggregated over every document under `path`
+fn corpus_stats(cli: &Cli, path: &Path, top: usize) -> Result<()> {
+    let mut config = ValidationConfig::default();
+    config.strict_mode = cli.strict;
+    config.deny_warnings = cli.deny_warnings;
+    let documents = validate_corpus(path, &AispValidator::with_config(config))?;
+    if documents.is_empty() {
+        anyhow::bail!("No .aisp files found under {}", path.display());
+    }
+    let stats = collect_corpus_stats(&documents, top)?;
+
+    match cli.format {
+        OutputFormat::Json => write_output(cli, &serde_json::to_string_pretty(&stats)?),
+        OutputFormat::Yaml => write_output(cli, &serde_yaml::to_string(&stats)?),
+        OutputFormat::Toml => write_output(cli, &toml::to_string_pretty(&stats)?),
+        _ => write_output(cli, &render_corpus_stats(path, &stats)),
+    }
+}
+
+/// Validate the `.aisp` files under a directory, recursively and in path
+/// order and honoring its `.aispignore`, or `path` itself when it is a file
+fn validate_corpus(path: &Path, validator: &AispValidator) -> Result<Vec<(PathBuf, ValidationResult)>> {
+    if !path.is_dir() {
+        let source = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read file {}", path.display()))?;
+        return Ok(vec![(path.to_path_buf(), validator.validate(&source))]);
+    }
+
+    let documents = validator.validate_directory(path, true)?;
+    // Statistics over a partly unreadable corpus would be misleading
+    let unreadable = documents
+        .iter()
+        .filter_map(|(_, result)| result.error.as_ref())
+        .find(|error| matches!(error, AispError::IoError { .. }));
+    if let Some(error) = unreadable {
+        anyhow::bail!("{}", error);
+    }
+    Ok(documents)
+}
+
+/// Aggregate tiers, density, symbol usage and sizes over validated files
+fn collect_corpus_stats(documents: &[(PathBuf, ValidationResult)], top: usize) -> Result<CorpusStats> {
+    let tiers = [
+        QualityTier::Platinum,
+        QualityTier::Gold,
+        QualityTier::Silver,
+        QualityTier::Bronze,
+        QualityTier::Reject,
+    ];
+    let mut tier_counts = [0usize; 5];
+    let mut valid = 0;
+    let mut density_total = 0.0;
+    let mut symbols: HashMap<char, usize> = HashMap::new();
+    let mut sizes = Vec::with_capacity(documents.len());
+
+    for (file, result) in documents {
+        let source = fs::read_to_string(file)
+            .with_context(|| format!("Failed to read file {}", file.display()))?;
+
+        if result.valid {
+            valid += 1;
+        }
+        if let Some(index) = tiers.iter().position(|tier| *tier == result.tier) {
+            tier_counts[index] += 1;
+        }
+        density_total += aisp_core::symbols::calculate_symbol_density(&source);
+        for (symbol, count) in aisp_core::symbols::symbol_histogram(&source) {
+            *symbols.entry(symbol).or_insert(0) += count;
+        }
+        sizes.push(DocumentSize { file: file.clone(), bytes: source.len() });
+    }
+
+    let mut top_symbols: Vec<SymbolCount> = symbols
+        .into_iter()
+        .map(|(symbol, count)| SymbolCount { symbol: symbol.to_string(), count })
+        .collect();
+    top_symbols.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.symbol.cmp(&b.symbol)));
+    top_symbols.truncate(top);
+
+    Ok(CorpusStats {
+        documents: documents.len(),
+        valid,
+        tier_distribution: tiers
+            .iter()
+            .zip(tier_counts)
+            .map(|(tier, count)| TierCount {
+                tier: tier.name().to_string(),
+                tier_symbol: tier.symbol().to_string(),
+                count,
+            })
+            .collect(),
+        average_density: if documents.is_empty() { 0.0 } else { density_total / documents.len() as f64 },
+        top_symbols,
+        largest: sizes.iter().max_by_key(|size| size.bytes).cloned(),
+        smallest: sizes.iter().min_by_key(|size| size.bytes).cloned(),
+    })
+}
+
+/// Human-readable corpus report with a bar per tier
+fn render_corpus_stats(path: &Path, stats: &CorpusStats) -> String {
+    const BAR_WIDTH: usize = 30;
+    let peak = stats.tier_distribution.iter().map(|tier| tier.count).max().unwrap_or(0).max(1);
+
+    let mut lines = vec![
+        format!("{} Corpus statistics for {}", "📊".bold(), path.display().to_string().cyan()),
+        format!("  Documents: {} ({} valid)", stats.documents, stats.valid),
+        "  Tiers:".to_string(),
+    ];
+    for tier in &stats.tier_distribution {
+        let bar = "█".repeat((tier.count * BAR_WIDTH).div_ceil(peak));
+        lines.push(format!("    {} {:<9} {:>4} {}", tier.tier_symbol, tier.tier, tier.count, bar.blue()));
+    }
+    lines.push(format!("  Average density: {:.3}", stats.average_density));
+    if !stats.top_symbols.is_empty() {
+        let symbols: Vec<String> = stats
+            .top_symbols
+            .iter()
+            .map(|symbol| format!("{} {}", symbol.symbol, symbol.count))
+            .collect();
+        lines.push(format!("  Top symbols: {}", symbols.join(", ")));
+    }
+    if let Some(largest) = &stats.largest {
+        lines.push(format!("  Largest: {} ({} bytes)", largest.file.display(), largest.bytes));
+    }
+    if let Some(smallest) = &stats.smallest {
+        lines.push(format!("  Smallest: {} ({} bytes)", smallest.file.display(), smallest.bytes));
+    }
+    lines.join("\n")
+}
+
+/// Print the recognized AISP symbol table
+fn list_symbols(cli: &Cli) -> Result<()> {
+    let entries = symbol_entries();
+    match cli.format {
+        OutputFormat::Json => write_output(cli, &serde_json::to_string_pretty(&entries)?),
+        OutputFormat::Yaml => write_output(cli, &serde_yaml::to_string(&entries)?),
+        _ => write_output(cli, &render_symbols(&entries)),
+    }
+}
+
+/// Rows of `AISP_SYMBOLS`, in table order
+fn symbol_entries() -> Vec<SymbolEntry> {
+    aisp_core::symbols::AISP_SYMBOLS
+        .iter()
+        .map(|symbol| SymbolEntry {
+            symbol: symbol.char.to_string(),
+            name: symbol.name.to_string(),
+            category: format!("{:?}", symbol.category),
+            ascii: symbol.ascii_alt.map(String::from),
+        })
+        .collect()
+}
+
+/// Human-readable symbol table, one symbol per line
+fn render_symbols(entries: &[SymbolEntry]) -> String {
+    let mut lines = vec![format!("{} AISP symbols ({})", "🔣".bold(), entries.len())];
+    for entry in entries {
+        let ascii = entry.ascii.as_deref().map(|ascii| format!("  {}", ascii.dimmed())).unwrap_or_default();
+        lines.push(format!("  {}  {:<22} {:<15}{}", entry.symbol, entry.name, entry.category.cyan(), ascii));
+    }
+    lines.join("\n")
+}
+
+/// Validate one document repeatedly and report latency and throughput
+fn bench_document(cli: &Cli, path: &Path, iterations: usize, warmup: usize) -> Result<()> {
+    if iterations == 0 {
+        anyhow::bail!("--iterations must be at least 1");
+    }
+    let (file, source) = read_document(path, io::stdin().lock())?;
+    let validator = AispValidator::with_config(validation_config(cli));
+    let report = run_bench(&validator, file, &source, iterations, warmup);
+
+    match cli.format {
+        OutputFormat::Json => write_output(cli, &serde_json::to_string_pretty(&report)?),
+        OutputFormat::Yaml => write_output(cli, &serde_yaml::to_string(&report)?),
+        OutputFormat::Toml => write_output(cli, &toml::to_string_pretty(&report)?),
+        _ => write_output(cli, &render_bench(&report)),
+    }
+}
+
+/// Time `iterations` validations of `source` after `warmup` untimed ones
+fn run_bench(validator: &AispValidator, file: PathBuf, source: &str, iterations: usize, warmup: usize) -> BenchReport {
+    for _ in 0..warmup {
+        validator.validate(source);
+    }
+
+    let mut samples = Vec::with_capacity(iterations);
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let sample_start = Instant::now();
+        validator.validate(source);
+        samples.push(sample_start.elapsed().as_secs_f64() * 1000.0);
+    }
+    let elapsed = start.elapsed().as_secs_f64();
+    samples.sort_by(f64::total_cmp);
+
+    BenchReport {
+        file,
+        iterations: samples.len(),
+        warmup,
+        mean_ms: samples.iter().sum::<f64>() / samples.len() as f64,
+        median_ms: percentile(&samples, 0.5),
+        p95_ms: percentile(&samples, 0.95),
+        p99_ms: percentile(&samples, 0.99),
+        documents_per_second: samples.len() as f64 / elapsed.max(f64::MIN_POSITIVE),
+    }
+}
+
+/// Nearest-rank percentile of ascending, non-empty samples
+fn percentile(sorted: &[f64], fraction: f64) -> f64 {
+    let rank = (fraction * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Human-readable benchmark summary
+fn render_bench(report: &BenchReport) -> String {
+    [
+        format!("{} Benchmark for {}", "⏱".bold(), report.file.display().to_string().cyan()),
+        format!("  Iterations: {} (after {} warmup)", report.iterations, report.warmup),
+        format!("  Mean: {:.3} ms", report.mean_ms),
+        format!("  Median: {:.3} ms", report.median_ms),
+        format!("  p95: {:.3} ms", report.p95_ms),
+        format!("  p99: {:.3} ms", report.p99_ms),
+        format!("  Throughput: {:.1} documents/s", report.documents_per_second),
+    ]
+    .join("\n")
+}
+
+/// Run the theorem prover over one document's properties
+fn verify_document(cli: &Cli, path: &Path, timeout: u64, properties: &[String]) -> Result<()> {
+    let selected = parse_property_kinds(properties)?;
+    let (file, source) = read_document(path, io::stdin().lock())?;
+    let mut config = validation_config(cli);
+    config.z3_timeout = Duration::from_secs(timeout);
+    let report = run_verify(&AispValidator::with_config(config), file, &source, &selected)?;
+
+    match cli.format {
+        OutputFormat::Json => write_output(cli, &serde_json::to_string_pretty(&report)?)?,
+        OutputFormat::Yaml => write_output(cli, &serde_yaml::to_string(&report)?)?,
+        OutputFormat::Toml => write_output(cli, &toml::to_string_pretty(&report)?)?,
+        _ => write_output(cli, &render_verify(&report))?,
+    }
+
+    if report.properties.iter().any(|property| property.status == PropertyStatus::Disproven.name()) {
+        std::process::exit(1);
+    }
     Ok(())
 }
 
+/// Property kinds named by `--properties`, rejecting unknown names
+fn parse_property_kinds(names: &[String]) -> Result<Vec<PropertyType>> {
+    names
+        .iter()
+        .map(|name| {
+            PropertyType::from_name(name.trim()).with_context(|| {
+                let known: Vec<&str> = PropertyType::ALL.iter().map(PropertyType::name).collect();
+                format!("Unknown property kind '{}' (expected one of: {})", name, known.join(", "))
+            })
+        })
+        .collect()
+}
+
+/// Verify the selected properties of `source`
+fn run_verify(validator: &AispValidator, file: PathBuf, source: &str, selected: &[PropertyType]) -> Result<VerifyReport> {
+    let outcomes = validator
+        .verify_properties(source, selected)
+        .with_context(|| format!("Failed to verify {}", file.display()))?;
+    Ok(VerifyReport {
+        file,
+        properties: outcomes.iter().map(PropertyReport::from).collect(),
+    })
+}
+
+/// Human-readable verification summary
+fn render_verify(report: &VerifyReport) -> String {
+    let mut lines = vec![format!("{} Verification of {}", "🔬".bold(), report.file.display().to_string().cyan())];
+    for property in &report.properties {
+        let status = match property.status.as_str() {
+            "proven" => property.status.green(),
+            "disproven" => property.status.red(),
+            _ => property.status.yellow(),
+        };
+        lines.push(format!("  {} [{}]: {}", property.name, property.kind, status));
+        if let Some(counterexample) = &property.counterexample {
+            let assignments: Vec<String> =
+                counterexample.iter().map(|(variable, value)| format!("{} = {}", variable, value)).collect();
+            lines.push(format!("    Counterexample: {}", assignments.join(", ")));
+        }
+    }
+    let proven = report.properties.iter().filter(|property| property.status == "proven").count();
+    lines.push(format!("  Proven: {}/{}", proven, report.properties.len()));
+    lines.join("\n")
+}
+
 /// Interactive validation mode
 async fn interactive_mode(_cli: &Cli) -> Result<()> {
     println!("{} Interactive AISP Validator", "🚀".bold());
@@ -685,18 +2330,1047 @@ impl Clone for Cli {
             input: self.input.clone(),
             format: self.format.clone(),
             output: self.output.clone(),
+            output_dir: self.output_dir.clone(),
             level: self.level.clone(),
             strict: self.strict,
+            deny_warnings: self.deny_warnings,
+            min_verified_properties: self.min_verified_properties,
+            meta_key_order: self.meta_key_order.clone(),
+            min_symbol_density: self.min_symbol_density,
             timing: self.timing,
+            profile: self.profile.clone(),
+            emit_metrics: self.emit_metrics,
+            metrics_file: self.metrics_file.clone(),
             include_ast: self.include_ast,
+            strict_json: self.strict_json,
+            json_compact: self.json_compact,
+            report_template: self.report_template.clone(),
             #[cfg(feature = "z3-verification")]
             formal_verification: self.formal_verification,
             #[cfg(feature = "z3-verification")]
             z3_timeout: self.z3_timeout,
+            timeout: self.timeout,
             verbose: self.verbose,
             quiet: self.quiet,
+            color: self.color,
             jobs: self.jobs,
+            parallel_strategy: self.parallel_strategy,
+            config: self.config.clone(),
+            language_profile: self.language_profile.clone(),
+            max_document_size: self.max_document_size,
             command: None, // Don't clone the command
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_result(file: &str) -> CliValidationResult {
+        CliValidationResult {
+            file: PathBuf::from(file),
+            valid: true,
+            tier: "Platinum".to_string(),
+            tier_symbol: "⭐".to_string(),
+            tier_value: 4,
+            delta: 0.82,
+            ambiguity: 0.01,
+            document_size: 512,
+            parse_time_ms: Some(3),
+            semantic_time_ms: None,
+            total_time_ms: Some(5),
+            warnings: vec!["Low symbol density in ⟦Λ⟧".to_string()],
+            errors: Vec::new(),
+            remediations: Vec::new(),
+            tier_explanation: Vec::new(),
+            gate_failures: Vec::new(),
+            timed_out: false,
+            relational_analysis: None,
+            temporal_analysis: Some(TemporalSummary {
+                consistency_score: 1.0,
+                ltl_formulas: 2,
+                ctl_formulas: 2,
+                patterns_detected: 1,
+                max_temporal_depth: 2,
+            }),
+            formal_verification: None,
+            capabilities: Vec::new(),
+            profile: None,
+        }
+    }
+
+    #[test]
+    fn test_prometheus_metrics_aggregate_results() {
+        let cli = Cli::try_parse_from(["aisp", "--emit-metrics", "prometheus", "--metrics-file", "m.prom", "a.aisp"])
+            .unwrap();
+        assert_eq!(cli.emit_metrics, Some(MetricsFormat::Prometheus));
+        assert!(Cli::try_parse_from(["aisp", "--emit-metrics", "prometheus", "a.aisp"]).is_err());
+
+        let mut gold = sample_result("b.aisp");
+        gold.tier = "Gold".to_string();
+        gold.delta = 0.62;
+        let mut rejected = sample_result("c.aisp");
+        rejected.tier = "Reject".to_string();
+        rejected.valid = false;
+        rejected.delta = 0.14;
+        let mut gated = sample_result("d.aisp");
+        gated.gate_failures = vec!["tier below gold".to_string()];
+        let results = vec![sample_result("a.aisp"), gold, rejected, gated];
+
+        let rendered = render_prometheus(&results);
+        for line in rendered.lines().filter(|line| !line.starts_with('#')) {
+            let (name, value) = line.rsplit_once(' ').expect("metric line has a value");
+            assert!(value.parse::<f64>().is_ok(), "{}", line);
+            assert!(name.starts_with("aisp_") && !name.contains(' '), "{}", line);
+        }
+        assert!(rendered.contains("# TYPE aisp_documents_by_tier gauge\n"));
+        assert!(rendered.contains("aisp_documents_total 4\n"));
+        assert!(rendered.contains("aisp_documents_by_tier{tier=\"platinum\"} 2\n"));
+        assert!(rendered.contains("aisp_documents_by_tier{tier=\"gold\"} 1\n"));
+        assert!(rendered.contains("aisp_documents_by_tier{tier=\"silver\"} 0\n"));
+        assert!(rendered.contains("aisp_documents_by_tier{tier=\"reject\"} 1\n"));
+        assert!(rendered.contains("aisp_validation_failures 2\n"));
+        let average = rendered
+            .lines()
+            .find_map(|line| line.strip_prefix("aisp_delta_average "))
+            .unwrap()
+            .parse::<f64>()
+            .unwrap();
+        assert!((average - (0.82 + 0.62 + 0.14 + 0.82) / 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_toml_round_trip_preserves_tier_symbol() {
+        let results = vec![sample_result("a.aisp"), sample_result("b.aisp")];
+
+        let rendered = render_toml(&results).unwrap();
+        assert!(rendered.contains("⭐"));
+
+        let parsed: CliValidationReport = toml::from_str(&rendered).unwrap();
+        assert_eq!(parsed.results, results);
+    }
+
+    #[test]
+    fn test_yaml_round_trip_preserves_tier_symbol() {
+        let single = vec![sample_result("a.aisp")];
+        let rendered = render_yaml(&single).unwrap();
+        assert!(rendered.contains("⭐"));
+
+        let parsed: CliValidationResult = serde_yaml::from_str(&rendered).unwrap();
+        assert_eq!(parsed, single[0]);
+
+        let batch = vec![sample_result("a.aisp"), sample_result("b.aisp")];
+        let parsed: Vec<CliValidationResult> =
+            serde_yaml::from_str(&render_yaml(&batch).unwrap()).unwrap();
+        assert_eq!(parsed, batch);
+    }
+
+    #[test]
+    fn test_jsonl_lines_parse_independently() {
+        let mut invalid = sample_result("b.aisp");
+        invalid.valid = false;
+        invalid.errors = vec!["Missing required blocks".to_string()];
+        let results = vec![sample_result("a.aisp"), invalid, sample_result("c.aisp")];
+
+        let mut stream = Vec::new();
+        for result in &results {
+            write_jsonl(&mut stream, result).unwrap();
+        }
+        let rendered = String::from_utf8(stream).unwrap();
+        assert_eq!(rendered, render_jsonl(&results).unwrap());
+
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines.len(), results.len());
+        for (line, expected) in lines.iter().zip(&results) {
+            let parsed: CliValidationResult = serde_json::from_str(line).unwrap();
+            assert_eq!(&parsed, expected);
+        }
+
+        let cli = Cli::try_parse_from(["aisp", "--format", "jsonl", "doc.aisp"]).unwrap();
+        assert!(matches!(cli.format, OutputFormat::Jsonl));
+    }
+
+    #[test]
+    fn test_json_compact_writes_each_result_on_one_line() {
+        let results = vec![sample_result("a.aisp"), sample_result("b.aisp")];
+
+        let single = render_json(&results[..1], true).unwrap();
+        assert!(!single.contains('\n'));
+        assert_eq!(serde_json::from_str::<CliValidationResult>(&single).unwrap(), results[0]);
+        assert!(render_json(&results[..1], false).unwrap().contains('\n'));
+
+        let multiple = render_json(&results, true).unwrap();
+        assert!(!multiple.contains('\n'));
+        assert_eq!(serde_json::from_str::<Vec<CliValidationResult>>(&multiple).unwrap(), results);
+
+        let cli = Cli::try_parse_from(["aisp", "--format", "json", "--json-compact", "doc.aisp"]).unwrap();
+        assert!(cli.json_compact);
+
+        // Per-file reports follow the same flag
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_report(&cli, dir.path(), &results[0], None, &mut HashMap::new()).unwrap();
+        assert_eq!(fs::read_to_string(path).unwrap(), single);
+    }
+
+    #[test]
+    fn test_progress_throughput_from_timings() {
+        assert_eq!(throughput(0, Duration::from_secs(3)), None);
+        assert_eq!(throughput(5, Duration::ZERO), None);
+        assert_eq!(throughput(25, Duration::from_secs(2)), Some(12.5));
+        assert_eq!(throughput(3, Duration::from_millis(1500)), Some(2.0));
+
+        let file = Path::new("docs/a.aisp");
+        assert_eq!(progress_message(file, 0, Duration::ZERO), "Validating docs/a.aisp");
+        assert_eq!(progress_message(file, 25, Duration::from_secs(2)), "12.5 files/s, validating docs/a.aisp");
+    }
+
+    #[test]
+    fn test_strict_json_rejects_lossy_results() {
+        let mut complete = sample_result("a.aisp");
+        complete.semantic_time_ms = Some(1);
+        complete.gate_failures = vec!["Tier Silver is below the minimum Gold".to_string()];
+        complete.relational_analysis = Some(RelationalSummary {
+            consistency_score: 0.9,
+            constraints_total: 4,
+            constraints_satisfied: 3,
+            conflicts: 1,
+            unsat_core: vec![],
+            witness: Some(BTreeMap::from([("s".to_string(), "Idle".to_string())])),
+        });
+        complete.formal_verification = Some(FormalSummary {
+            status: "Proven".to_string(),
+            properties_checked: 2,
+            properties_proven: 2,
+            verification_time_ms: 7,
+        });
+        assert!(check_strict_json(&complete).is_ok());
+        assert!(check_strict_json(&sample_result("b.aisp")).is_ok());
+
+        // Non-finite numbers serialize as null
+        let mut broken = complete.clone();
+        broken.delta = f64::NAN;
+        let error = check_strict_json(&broken).unwrap_err().to_string();
+        assert!(error.contains("`delta` is null"), "{}", error);
+
+        let mut broken = complete;
+        broken.relational_analysis.as_mut().unwrap().consistency_score = f64::INFINITY;
+        let error = check_strict_json(&broken).unwrap_err().to_string();
+        assert!(error.contains("`relational_analysis.consistency_score` is null"), "{}", error);
+
+        let cli = Cli::try_parse_from(["aisp", "--strict-json", "--format", "json", "doc.aisp"]).unwrap();
+        assert!(cli.strict_json);
+    }
+
+    #[test]
+    fn test_strict_json_accepts_verbose_capabilities() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        let cli = Cli::try_parse_from(["aisp", "-v", "--strict-json", "--format", "json", "doc.aisp"]).unwrap();
+
+        let result = validate_source(&cli, PathBuf::from("doc.aisp"), source, &ValidationGates::default());
+        assert!(result.capabilities.iter().any(|capability| capability.reason.is_none()));
+        assert!(result.capabilities.iter().any(|capability| capability.reason.is_some()));
+        check_strict_json(&result).unwrap();
+    }
+
+    #[test]
+    fn test_min_tier_gate_rejects_lower_tier() {
+        let gates = ValidationGates {
+            min_tier: Some(TierArg::Gold.into()),
+            max_size: None,
+            max_ambiguity: None,
+        };
+
+        let failures = gates.check(QualityTier::Silver, 512, 0.0);
+        assert_eq!(failures.len(), 1);
+        assert!(failures[0].contains("Silver"));
+        assert!(failures[0].contains("Gold"));
+
+        assert!(gates.check(QualityTier::Gold, 512, 0.0).is_empty());
+        assert!(gates.check(QualityTier::Platinum, 512, 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_max_size_gate_rejects_oversize_document() {
+        let gates = ValidationGates {
+            min_tier: None,
+            max_size: Some(65536),
+            max_ambiguity: None,
+        };
+
+        let failures = gates.check(QualityTier::Platinum, 65537, 0.0);
+        assert_eq!(failures, vec!["Document size 65537 bytes exceeds limit of 65536 bytes".to_string()]);
+        assert!(gates.check(QualityTier::Platinum, 65536, 0.0).is_empty());
+        assert!(ValidationGates::default().check(QualityTier::Reject, usize::MAX, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_ambiguity_gate_fails_valid_document_at_bound() {
+        let gates = ValidationGates {
+            max_ambiguity: Some(0.01),
+            ..ValidationGates::default()
+        };
+        assert_eq!(
+            gates.check(QualityTier::Platinum, 512, 0.01),
+            vec!["Ambiguity 0.010 meets or exceeds limit of 0.010".to_string()]
+        );
+        assert!(gates.check(QualityTier::Platinum, 512, 0.009).is_empty());
+
+        // Unambiguous enough for the default validity criteria, not for a zero bound
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        let cli = Cli::try_parse_from(["aisp", "doc.aisp"]).unwrap();
+        let result = validate_source(&cli, PathBuf::from("doc.aisp"), source, &ValidationGates::default());
+        assert!(result.valid);
+        assert!(result.gate_failures.is_empty());
+
+        let cli = Cli::try_parse_from(["aisp", "validate", "--fail-on-ambiguity", "0", "doc.aisp"]).unwrap();
+        let gates = match cli.command {
+            Some(Commands::Validate { fail_on_ambiguity, .. }) => ValidationGates {
+                max_ambiguity: fail_on_ambiguity,
+                ..ValidationGates::default()
+            },
+            _ => panic!("expected validate subcommand"),
+        };
+        let result = validate_source(&cli, PathBuf::from("doc.aisp"), source, &gates);
+        assert!(result.valid);
+        assert_eq!(result.gate_failures, vec!["Ambiguity 0.000 meets or exceeds limit of 0.000".to_string()]);
+    }
+
+    #[test]
+    fn test_command_line_overrides_config_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("aisp.toml");
+        fs::write(
+            &path,
+            "level = \"temporal\"\ndeny-warnings = true\nprofile = \"minimal\"\n\n\
+             [thresholds]\nmin-tier = \"gold\"\nmax-size = 1024\n",
+        )
+        .unwrap();
+        let config = path.to_str().unwrap();
+        let thresholds = |cli: &Cli| match cli.command {
+            Some(Commands::Validate { min_tier, max_size, .. }) => (min_tier, max_size),
+            _ => panic!("expected validate subcommand"),
+        };
+
+        let matches = Cli::command().try_get_matches_from(["aisp", "--config", config, "validate", "doc.aisp"]).unwrap();
+        let cli = cli_from_matches(&matches).unwrap();
+        assert_eq!(cli.level, ValidationLevel::Temporal);
+        assert!(cli.deny_warnings);
+        assert_eq!(validation_config(&cli).profile.name, "minimal");
+        assert_eq!(thresholds(&cli), (Some(TierArg::Gold), Some(1024)));
+
+        let matches = Cli::command()
+            .try_get_matches_from(["aisp", "--config", config, "--level", "syntax", "validate", "--max-size", "2048", "doc.aisp"])
+            .unwrap();
+        let cli = cli_from_matches(&matches).unwrap();
+        assert_eq!(cli.level, ValidationLevel::Syntax);
+        assert!(!validation_config(&cli).enable_temporal_analysis);
+        assert_eq!(thresholds(&cli), (Some(TierArg::Gold), Some(2048)));
+
+        fs::write(&path, "levl = \"syntax\"\n").unwrap();
+        let matches = Cli::command().try_get_matches_from(["aisp", "--config", config, "doc.aisp"]).unwrap();
+        let error = format!("{:#}", cli_from_matches(&matches).err().unwrap());
+        assert!(error.starts_with(&format!("Invalid config file {}", config)), "{}", error);
+        assert!(error.contains("unknown field `levl`"), "{}", error);
+    }
+
+    #[test]
+    fn test_profile_node_mirrors_stage_tree() {
+        let profile = StageProfile::new("validate", Duration::from_millis(12)).with_children(vec![
+            StageProfile::new("parse", Duration::from_millis(2)),
+            StageProfile::new("semantic", Duration::from_millis(9))
+                .with_children(vec![StageProfile::new("type", Duration::from_millis(4))]),
+        ]);
+
+        let node = ProfileNode::from(&profile);
+        assert_eq!(node.duration_ms, 12.0);
+        assert_eq!(node.children[1].children[0].stage, "type");
+
+        let json = serde_json::to_value(&node).unwrap();
+        assert_eq!(json["children"][0]["stage"], "parse");
+        assert!(json["children"][0].get("children").is_none());
+
+        let cli = Cli::try_parse_from(["aisp", "--profile", "out.json", "doc.aisp"]).unwrap();
+        assert_eq!(cli.profile, Some(PathBuf::from("out.json")));
+    }
+
+    #[test]
+    fn test_validate_subcommand_parses_gate_flags() {
+        let cli = Cli::try_parse_from([
+            "aisp", "validate", "--min-tier", "gold", "--max-size", "65536", "doc.aisp",
+        ])
+        .unwrap();
+
+        match cli.command {
+            Some(Commands::Validate { min_tier, max_size, files, .. }) => {
+                assert_eq!(QualityTier::from(min_tier.unwrap()), QualityTier::Gold);
+                assert_eq!(max_size, Some(65536));
+                assert_eq!(files, vec![PathBuf::from("doc.aisp")]);
+            }
+            _ => panic!("expected validate subcommand"),
+        }
+    }
+
+    #[test]
+    fn test_since_filters_changed_files_to_aisp_under_roots() {
+        let changed: Vec<PathBuf> = [
+            "specs/b.aisp",
+            "specs/nested/a.aisp",
+            "specs/README.md",
+            "docs/guide.aisp",
+            "src/main.rs",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+
+        assert_eq!(
+            filter_changed_files(&changed, &[PathBuf::from("./specs/")]),
+            vec![PathBuf::from("specs/b.aisp"), PathBuf::from("specs/nested/a.aisp")]
+        );
+        assert_eq!(
+            filter_changed_files(&changed, &[PathBuf::from("specs/nested/a.aisp"), PathBuf::from("docs")]),
+            vec![PathBuf::from("docs/guide.aisp"), PathBuf::from("specs/nested/a.aisp")]
+        );
+        assert_eq!(filter_changed_files(&changed, &[]).len(), 3);
+        assert!(filter_changed_files(&changed, &[PathBuf::from("other")]).is_empty());
+        assert!(filter_changed_files(&[], &[PathBuf::from("specs")]).is_empty());
+
+        let cli = Cli::try_parse_from(["aisp", "validate", "--since", "main", "specs/"]).unwrap();
+        match cli.command {
+            Some(Commands::Validate { since, files, .. }) => {
+                assert_eq!(since.as_deref(), Some("main"));
+                assert_eq!(files, vec![PathBuf::from("specs/")]);
+            }
+            _ => panic!("expected validate subcommand"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_output_dir_writes_one_report_per_file() {
+        let document = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        let dir = tempfile::tempdir().unwrap();
+        let inputs = dir.path().join("specs");
+        fs::create_dir(&inputs).unwrap();
+        fs::write(inputs.join("alpha.aisp"), document).unwrap();
+        fs::write(inputs.join("beta.aisp"), document).unwrap();
+        let reports = dir.path().join("reports");
+
+        let cli = Cli::try_parse_from([
+            "aisp",
+            "--quiet",
+            "--format",
+            "json",
+            "--output-dir",
+            reports.to_str().unwrap(),
+            "-o",
+            dir.path().join("combined.json").to_str().unwrap(),
+        ])
+        .unwrap();
+        let files = vec![inputs.join("alpha.aisp"), inputs.join("beta.aisp")];
+        validate_files(&cli, files, false, false, false, &ValidationGates::default()).await.unwrap();
+
+        let mut written: Vec<PathBuf> = fs::read_dir(&reports)
+            .unwrap()
+            .map(|entry| entry.unwrap().path().strip_prefix(&reports).unwrap().to_path_buf())
+            .collect();
+        written.sort();
+        assert_eq!(written, vec![PathBuf::from("alpha.json"), PathBuf::from("beta.json")]);
+
+        let report: CliValidationResult =
+            serde_json::from_str(&fs::read_to_string(reports.join("beta.json")).unwrap()).unwrap();
+        assert_eq!(report.file, inputs.join("beta.aisp"));
+        assert!(report.valid);
+
+        assert_eq!(
+            report_path(Path::new("out"), Path::new("./specs/nested/a.aisp"), &OutputFormat::Yaml),
+            PathBuf::from("out/specs/nested/a.yaml")
+        );
+        assert_eq!(
+            report_path(Path::new("out"), Path::new(STDIN_NAME), &OutputFormat::Human),
+            PathBuf::from("out/stdin.txt")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_output_dir_rejects_files_sharing_a_report() {
+        let document = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        let dir = tempfile::tempdir().unwrap();
+        // Outside the working directory, both flatten to `spec.json`
+        for name in ["first", "second"] {
+            fs::create_dir(dir.path().join(name)).unwrap();
+            fs::write(dir.path().join(name).join("spec.aisp"), document).unwrap();
+        }
+        let reports = dir.path().join("reports");
+
+        let cli = Cli::try_parse_from(["aisp", "--quiet", "--format", "json", "--output-dir", reports.to_str().unwrap()])
+            .unwrap();
+        let files = vec![dir.path().join("first/spec.aisp"), dir.path().join("second/spec.aisp")];
+        let error = validate_files(&cli, files, false, false, false, &ValidationGates::default())
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("would both be written to"), "{}", error);
+
+        let report: CliValidationResult =
+            serde_json::from_str(&fs::read_to_string(reports.join("spec.json")).unwrap()).unwrap();
+        assert_eq!(report.file, dir.path().join("first/spec.aisp"));
+    }
+
+    #[test]
+    fn test_deny_warnings_fails_documents_with_warnings() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  Task≜State;\n  State≜{Idle,Busy}\n}\n\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        let gates = ValidationGates::default();
+
+        let cli = Cli::try_parse_from(["aisp", "doc.aisp"]).unwrap();
+        let result = validate_source(&cli, PathBuf::from("doc.aisp"), source, &gates);
+        assert!(result.valid);
+        assert_eq!(result.warnings.len(), 1);
+
+        let cli = Cli::try_parse_from(["aisp", "--deny-warnings", "doc.aisp"]).unwrap();
+        let result = validate_source(&cli, PathBuf::from("doc.aisp"), source, &gates);
+        assert!(!result.valid);
+        assert!(result.errors[0].contains("warning(s) denied"), "{:?}", result.errors);
+        assert!(result.warnings[0].starts_with("Error: Forward reference"), "{:?}", result.warnings);
+    }
+
+    #[test]
+    fn test_min_verified_properties_applies_at_formal_level() {
+        let source = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                      ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+        let gates = ValidationGates::default();
+
+        let cli = Cli::try_parse_from(["aisp", "--level", "formal", "--min-verified-properties", "1", "doc.aisp"]).unwrap();
+        assert_eq!(validation_config(&cli).min_verified_properties, 1);
+        let result = validate_source(&cli, PathBuf::from("doc.aisp"), source, &gates);
+        assert!(!result.valid);
+        assert!(result.errors[0].contains("at least 1 required"), "{:?}", result.errors);
+
+        let cli = Cli::try_parse_from(["aisp", "--min-verified-properties", "1", "doc.aisp"]).unwrap();
+        assert!(validate_source(&cli, PathBuf::from("doc.aisp"), source, &gates).valid);
+    }
+
+    #[test]
+    fn test_min_symbol_density_flag_sets_the_density_floor() {
+        // δ is 0.227 here
+        let source = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                      ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+        let gates = ValidationGates::default();
+        let low_density = |cli: &Cli| {
+            let result = validate_source(cli, PathBuf::from("doc.aisp"), source, &gates);
+            result.warnings.iter().any(|warning| warning.contains("Low Symbol Density"))
+        };
+
+        let cli = Cli::try_parse_from(["aisp", "doc.aisp"]).unwrap();
+        assert_eq!(validation_config(&cli).min_symbol_density, aisp_core::MIN_SYMBOL_DENSITY);
+        assert!(!low_density(&cli));
+
+        let cli = Cli::try_parse_from(["aisp", "--min-symbol-density", "0.5", "doc.aisp"]).unwrap();
+        assert_eq!(validation_config(&cli).min_symbol_density, 0.5);
+        assert!(low_density(&cli));
+    }
+
+    #[test]
+    fn test_meta_key_order_flag_warns_on_deviating_meta_block() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{\n  domain≜x;\n  Vision≜plan\n}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        let gates = ValidationGates::default();
+
+        let cli = Cli::try_parse_from(["aisp", "--meta-key-order", "Vision,domain", "doc.aisp"]).unwrap();
+        assert_eq!(cli.meta_key_order, vec!["Vision", "domain"]);
+        let result = validate_source(&cli, PathBuf::from("doc.aisp"), source, &gates);
+        assert!(result.valid);
+        assert!(result.warnings.iter().any(|warning| warning.contains("Meta key order")), "{:?}", result.warnings);
+
+        let cli = Cli::try_parse_from(["aisp", "doc.aisp"]).unwrap();
+        let result = validate_source(&cli, PathBuf::from("doc.aisp"), source, &gates);
+        assert!(result.warnings.iter().all(|warning| !warning.contains("Meta key order")));
+    }
+
+    #[test]
+    fn test_verbose_reports_stage_capabilities_for_level() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        let gates = ValidationGates::default();
+        let status = |result: &CliValidationResult, stage: &str| {
+            result.capabilities.iter().find(|capability| capability.stage == stage).unwrap().status
+        };
+
+        let cli = Cli::try_parse_from(["aisp", "doc.aisp"]).unwrap();
+        let result = validate_source(&cli, PathBuf::from("doc.aisp"), source, &gates);
+        assert!(result.capabilities.is_empty());
+
+        let cli = Cli::try_parse_from(["aisp", "--verbose", "doc.aisp"]).unwrap();
+        let result = validate_source(&cli, PathBuf::from("doc.aisp"), source, &gates);
+        assert_eq!(status(&result, "semantic"), StageStatus::Ran);
+        assert_eq!(status(&result, "temporal"), StageStatus::Disabled);
+        let rendered = strip_ansi(&render_human(&[result], false));
+        assert!(rendered.contains("temporal: disabled, disabled in configuration"), "{}", rendered);
+
+        let cli = Cli::try_parse_from(["aisp", "--verbose", "--level", "temporal", "doc.aisp"]).unwrap();
+        let result = validate_source(&cli, PathBuf::from("doc.aisp"), source, &gates);
+        assert_eq!(status(&result, "relational"), StageStatus::Ran);
+        assert_eq!(status(&result, "temporal"), StageStatus::Ran);
+        assert_eq!(status(&result, "formal"), StageStatus::Disabled);
+    }
+
+    #[test]
+    fn test_lint_fix_resolves_fixable_and_keeps_unfixable_findings() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("doc.aisp");
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  task_state≜{Idle,Busy}\n}\n\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:task_state→Valid(s)\n}\n\n⟦Λ:Funcs⟧{}\n\n⟦Ε⟧⟨δ≜0.5⟩\n";
+        fs::write(&path, source).unwrap();
+
+        let report = lint_file(&path, false).unwrap();
+        assert_eq!(report.findings.len(), 2);
+        assert!(report.findings[0].contains("[type-name-case]"));
+        assert!(report.findings[1].contains("[empty-block]"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), source);
+
+        let report = lint_file(&path, true).unwrap();
+        assert_eq!(report.fixed.len(), 1);
+        assert!(report.fixed[0].contains("[type-name-case]"));
+        assert_eq!(report.findings.len(), 1);
+        assert!(report.findings[0].contains("[empty-block]"));
+        assert!(fs::read_to_string(&path).unwrap().contains("∀s:TaskState→Valid(s)"));
+
+        let cli = Cli::try_parse_from(["aisp", "lint", "--fix", "doc.aisp"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Lint { fix: true, .. })));
+    }
+
+    #[test]
+    fn test_validate_fix_inserts_missing_blocks() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("doc.aisp");
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n";
+        fs::write(&path, source).unwrap();
+        let cli = Cli::try_parse_from(["aisp", "validate", "--fix", "doc.aisp"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Validate { fix: true, .. })));
+
+        let result = validate_source(&cli, path.clone(), source, &ValidationGates::default());
+        assert!(result.remediations.contains(&"Insert the missing Types block `⟦Σ:Types⟧{}`".to_string()));
+        assert!(render_minimal(&result).contains("\n  ↳ Insert the missing Functions block"));
+
+        let fixed = apply_remediations(&cli, &path, source.to_string()).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), fixed);
+        assert!(fixed.contains("⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n}\n\n⟦Γ:Rules⟧"));
+        let result = validate_source(&cli, path, &fixed, &ValidationGates::default());
+        assert!(result.errors.iter().all(|error| !error.contains("Missing required blocks")), "{:?}", result.errors);
+    }
+
+    #[test]
+    fn test_validate_explain_tier_names_the_missed_threshold() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("doc.aisp");
+        fs::write(
+            &path,
+            "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+             ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{f≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩",
+        )
+        .unwrap();
+        let cli = Cli::try_parse_from(["aisp", "validate", "--explain-tier", "doc.aisp"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Validate { explain_tier: true, .. })));
+
+        let gates = ValidationGates::default();
+        let result = validate_single_file(&cli, &path, false, true, &gates).unwrap();
+        assert_eq!(result.tier_explanation[0], "Density: δ=0.229 (Bronze, δ≥0.20)");
+        let explanation = AispValidator::explain_tier(&fs::read_to_string(&path).unwrap());
+        assert_eq!(result.tier, explanation.tier.name());
+        let rendered = strip_ansi(&render_human(&[result], false));
+        assert!(rendered.contains("  Tier:\n    Density: δ=0.229"), "{}", rendered);
+        assert!(rendered.contains("    Missed: Silver threshold δ≥0.40 by 0.171"), "{}", rendered);
+        assert!(rendered.contains("    Suggestions:\n      Write about 21 more"), "{}", rendered);
+
+        let result = validate_single_file(&cli, &path, false, false, &gates).unwrap();
+        assert!(result.tier_explanation.is_empty());
+    }
+
+    #[test]
+    fn test_timeout_reports_a_partial_result() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("doc.aisp");
+        fs::write(
+            &path,
+            "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+             ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{f≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩",
+        )
+        .unwrap();
+        let gates = ValidationGates::default();
+        let cli = Cli::try_parse_from(["aisp", "--level", "temporal", "--timeout", "0.000000001", "validate", "doc.aisp"]).unwrap();
+        assert_eq!(cli.timeout, Some(Duration::from_nanos(1)));
+
+        let result = validate_single_file(&cli, &path, false, false, &gates).unwrap();
+        assert!(result.timed_out);
+        assert!(!result.valid);
+        assert!(result.errors[0].contains("Timed out after 1ns before"), "{:?}", result.errors);
+        assert!(result.temporal_analysis.is_none());
+        assert!(strip_ansi(&render_human(&[result.clone()], false)).contains("  Status: ✗ Timed out, partial result"));
+        assert!(render_minimal(&result).ends_with("(timed out)"));
+        assert_eq!(serde_json::to_value(&result).unwrap()["timed_out"], true);
+
+        let cli = Cli::try_parse_from(["aisp", "--level", "temporal", "--timeout", "600", "validate", "doc.aisp"]).unwrap();
+        let result = validate_single_file(&cli, &path, false, false, &gates).unwrap();
+        assert!(!result.timed_out && result.valid, "{:?}", result.errors);
+        assert!(serde_json::to_value(&result).unwrap().get("timed_out").is_none());
+
+        assert!(Cli::try_parse_from(["aisp", "--timeout", "-1", "validate", "doc.aisp"]).is_err());
+    }
+
+    #[test]
+    fn test_parallel_strategies_produce_identical_results() {
+        let dir = tempfile::tempdir().unwrap();
+        let files: Vec<PathBuf> = (0..7)
+            .map(|index| {
+                let path = dir.path().join(format!("doc{}.aisp", index));
+                let rules = "  ∀s:State→Valid(s)\n".repeat(index * 3 + 1);
+                let source = format!(
+                    "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{{domain≜x}}\n\n⟦Σ:Types⟧{{\n  State≜{{Idle,Busy}}\n}}\n\n\
+                     ⟦Γ:Rules⟧{{\n{}}}\n\n⟦Λ:Funcs⟧{{id≜λx.x}}\n\n⟦Ε⟧⟨δ≜0.5⟩\n",
+                    if index == 3 { "broken".to_string() } else { rules }
+                );
+                fs::write(&path, source).unwrap();
+                path
+            })
+            .collect();
+        // Timings vary from run to run
+        let untimed = |result: CliValidationResult| CliValidationResult {
+            parse_time_ms: None,
+            semantic_time_ms: None,
+            total_time_ms: None,
+            ..result
+        };
+        let gates = ValidationGates::default();
+        let sequential: Vec<_> = files
+            .iter()
+            .map(|file| untimed(validate_single_file(&Cli::parse_from(["aisp"]), file, false, false, &gates).unwrap()))
+            .collect();
+
+        for strategy in ["work-stealing", "chunked", "auto"] {
+            let cli = Cli::parse_from(["aisp", "-j", "3", "--parallel-strategy", strategy]);
+            let mut parallel = vec![None; files.len()];
+            validate_parallel(&cli, &files, false, false, &gates, |index, result| {
+                parallel[index] = Some(untimed(result?));
+                Ok(true)
+            })
+            .unwrap();
+            let parallel: Vec<_> = parallel.into_iter().map(Option::unwrap).collect();
+            assert_eq!(parallel, sequential, "{}", strategy);
+
+            // Stopping after the first result reports nothing further
+            let mut reported = 0;
+            validate_parallel(&cli, &files, false, false, &gates, |_, _| {
+                reported += 1;
+                Ok(false)
+            })
+            .unwrap();
+            assert_eq!(reported, 1, "{}", strategy);
+        }
+    }
+
+    #[test]
+    fn test_auto_parallel_strategy_follows_size_distribution() {
+        // One large file among small ones would leave a fixed share lopsided
+        let skewed = [400_000, 20_000, 30_000, 25_000, 20_000];
+        assert_eq!(ParallelStrategy::Auto.resolve(&skewed), ParallelStrategy::WorkStealing);
+        assert_eq!(ParallelStrategy::Auto.resolve(&[300; 50]), ParallelStrategy::WorkStealing);
+        assert_eq!(ParallelStrategy::Auto.resolve(&[200_000, 220_000, 210_000]), ParallelStrategy::Chunked);
+        assert_eq!(ParallelStrategy::Chunked.resolve(&skewed), ParallelStrategy::Chunked);
+    }
+
+    #[test]
+    fn test_dash_reads_document_from_stdin() {
+        let document = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        let stdin = io::Cursor::new(document.as_bytes());
+
+        let (file, source) = read_document(Path::new("-"), stdin).unwrap();
+        assert_eq!(file, PathBuf::from("<stdin>"));
+        assert_eq!(source, document);
+
+        let cli = Cli::try_parse_from(["aisp", "validate", "-"]).unwrap();
+        match cli.command {
+            Some(Commands::Validate { ref files, .. }) => assert_eq!(files, &vec![PathBuf::from("-")]),
+            _ => panic!("expected validate subcommand"),
+        }
+        let result = validate_source(&cli, file, &source, &ValidationGates::default());
+        assert_eq!(result.file, PathBuf::from("<stdin>"));
+        assert!(result.valid, "{:?}", result.errors);
+        assert_eq!(result.document_size, document.len());
+    }
+
+    #[test]
+    fn test_color_never_emits_no_ansi_codes() {
+        let mut invalid = sample_result("b.aisp");
+        invalid.valid = false;
+        invalid.errors = vec!["Missing required blocks".to_string()];
+        let results = vec![sample_result("a.aisp"), invalid];
+
+        assert!(ColorChoice::Auto.enabled(true));
+        assert!(!ColorChoice::Auto.enabled(false));
+        assert!(ColorChoice::Always.enabled(false));
+
+        ColorChoice::Always.apply();
+        assert!(render_human(&results, true).contains('\x1b'));
+
+        ColorChoice::Never.apply();
+        let rendered = render_human(&results, true);
+        assert!(!rendered.contains('\x1b'), "{:?}", rendered);
+        assert!(rendered.contains("  Status: ✓ Valid"));
+        assert!(rendered.contains("  Status: ✗ Invalid"));
+
+        let cli = Cli::try_parse_from(["aisp", "--color", "never", "doc.aisp"]).unwrap();
+        assert_eq!(cli.color, ColorChoice::Never);
+        assert_eq!(Cli::try_parse_from(["aisp", "doc.aisp"]).unwrap().color, ColorChoice::Auto);
+    }
+
+    #[test]
+    fn test_detailed_output_shows_constraint_witness_and_core() {
+        let summary = |witness: Option<BTreeMap<String, String>>, unsat_core: Vec<String>| RelationalSummary {
+            consistency_score: 1.0,
+            constraints_total: 2,
+            constraints_satisfied: 2,
+            conflicts: 0,
+            unsat_core,
+            witness,
+        };
+        let mut satisfiable = sample_result("a.aisp");
+        satisfiable.relational_analysis = Some(summary(
+            Some(BTreeMap::from([("x".to_string(), "Idle".to_string()), ("y".to_string(), "Busy".to_string())])),
+            vec![],
+        ));
+        let mut unsatisfiable = sample_result("b.aisp");
+        unsatisfiable.relational_analysis = Some(summary(None, vec!["a_ne_b".to_string(), "a_eq_b".to_string()]));
+
+        let rendered = strip_ansi(&render_human(&[satisfiable, unsatisfiable], true));
+        assert!(rendered.contains("    Witness: x = Idle, y = Busy"), "{}", rendered);
+        assert!(rendered.contains("    Unsatisfiable together: a_ne_b, a_eq_b"), "{}", rendered);
+    }
+
+    #[test]
+    fn test_init_scaffold_validates() {
+        let scaffold = scaffold_document("MyProto", "2026-01-25");
+        assert!(scaffold.starts_with("𝔸5.1.MyProto@2026-01-25\n"));
+        for block in ["⟦Ω:Meta⟧", "⟦Σ:Types⟧", "⟦Γ:Rules⟧", "⟦Λ:Funcs⟧", "⟦Ε⟧"] {
+            assert!(scaffold.contains(block), "missing {}", block);
+        }
+
+        let result = AispValidator::new().validate(&scaffold);
+        assert!(result.valid, "{:?}", result.error);
+        // `Valid(s)` is a predicate, not a call to an undefined function
+        assert!(
+            result.warnings.iter().all(|warning| !warning.message.starts_with("Undefined function")),
+            "{:?}",
+            result.warnings
+        );
+
+        let date = today();
+        assert!(chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d").is_ok(), "{}", date);
+        assert!(scaffold_document("x", &date).contains(&format!("@{}\n", date)));
+    }
+
+    #[test]
+    fn test_init_refuses_to_overwrite_without_force() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("mydoc.aisp");
+        let cli = Cli::try_parse_from(["aisp", "--quiet", "init", "mydoc.aisp", "--name", "MyProto"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Init { force: false, .. })));
+
+        init_document(&cli, &path, Some("MyProto"), false).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().starts_with("𝔸5.1.MyProto@"));
+
+        fs::write(&path, "keep me").unwrap();
+        let error = init_document(&cli, &path, Some("MyProto"), false).unwrap_err();
+        assert!(error.to_string().contains("--force"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "keep me");
+
+        init_document(&cli, &path, None, true).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().starts_with("𝔸5.1.mydoc@"));
+
+        assert!(init_document(&cli, &dir.path().join("other.aisp"), Some("My Proto"), false).is_err());
+    }
+
+    #[test]
+    fn test_validate_corpus_honors_aispignore() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("vendor/nested")).unwrap();
+        fs::write(dir.path().join("a.aisp"), "").unwrap();
+        fs::write(dir.path().join("vendor/v.aisp"), "").unwrap();
+        fs::write(dir.path().join("vendor/nested/w.aisp"), "").unwrap();
+        fs::write(dir.path().join(".aispignore"), "vendor/\n").unwrap();
+
+        let documents = validate_corpus(dir.path(), &AispValidator::new()).unwrap();
+        let files: Vec<&PathBuf> = documents.iter().map(|(file, _)| file).collect();
+        assert_eq!(files, vec![&dir.path().join("a.aisp")]);
+    }
+
+    #[test]
+    fn test_stats_over_fixture_directory() {
+        const VALID: &str = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("nested")).unwrap();
+        fs::write(dir.path().join("a.aisp"), VALID).unwrap();
+        fs::write(dir.path().join("nested/b.aisp"), format!("{}\n", VALID)).unwrap();
+        fs::write(dir.path().join("broken.aisp"), "not a document").unwrap();
+        fs::write(dir.path().join("notes.txt"), VALID).unwrap();
+
+        let validator = AispValidator::new();
+        let documents = validate_corpus(dir.path(), &validator).unwrap();
+        assert_eq!(documents.len(), 3);
+
+        let expected_tier = validator.validate(VALID).tier;
+        let stats = collect_corpus_stats(&documents, 3).unwrap();
+
+        assert_eq!(stats.documents, 3);
+        assert_eq!(stats.valid, 2);
+        assert_eq!(stats.tier_distribution.iter().map(|tier| tier.count).sum::<usize>(), 3);
+        let count_of = |tier: QualityTier| {
+            stats.tier_distribution.iter().find(|entry| entry.tier == tier.name()).unwrap().count
+        };
+        assert_eq!(count_of(expected_tier), 2);
+        assert_eq!(count_of(QualityTier::Reject), 1);
+
+        assert_eq!(stats.top_symbols.len(), 3);
+        assert!(stats.top_symbols.windows(2).all(|pair| pair[0].count >= pair[1].count));
+        assert_eq!(stats.largest.unwrap().file, dir.path().join("nested/b.aisp"));
+        assert_eq!(stats.smallest.unwrap().file, dir.path().join("broken.aisp"));
+        assert!(stats.average_density > 0.0);
+    }
+
+    #[test]
+    fn test_list_symbols_includes_categories() {
+        let cli = Cli::try_parse_from(["aisp", "list-symbols"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::ListSymbols)));
+
+        let entries = symbol_entries();
+        let forall = entries.iter().find(|entry| entry.symbol == "∀").unwrap();
+        assert_eq!((forall.name.as_str(), forall.category.as_str()), ("FOR_ALL", "Quantifier"));
+        assert_eq!(forall.ascii.as_deref(), Some("forall"));
+
+        let human = strip_ansi(&render_symbols(&entries));
+        assert!(human.lines().any(|line| line.contains("∀") && line.contains("FOR_ALL") && line.contains("Quantifier")));
+        let json: Vec<SymbolEntry> = serde_json::from_str(&serde_json::to_string(&entries).unwrap()).unwrap();
+        assert_eq!(json, entries);
+    }
+
+    #[test]
+    fn test_bench_runs_requested_iterations() {
+        const VALID: &str = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        let report = run_bench(&AispValidator::new(), PathBuf::from("doc.aisp"), VALID, 25, 2);
+
+        assert_eq!(report.iterations, 25);
+        assert_eq!(report.warmup, 2);
+        assert!(report.documents_per_second > 0.0);
+        assert!(report.median_ms <= report.p95_ms && report.p95_ms <= report.p99_ms);
+        assert_eq!(percentile(&[1.0, 2.0, 3.0, 4.0], 0.5), 2.0);
+        assert_eq!(percentile(&[1.0, 2.0, 3.0, 4.0], 0.99), 4.0);
+    }
+
+    #[test]
+    fn test_verify_checks_only_selected_property_kinds() {
+        const VALID: &str = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                             ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+        let validator = AispValidator::new();
+        let file = PathBuf::from("doc.aisp");
+
+        let all = run_verify(&validator, file.clone(), VALID, &[]).unwrap();
+        let selected = parse_property_kinds(&["type-safety".to_string()]).unwrap();
+        let filtered = run_verify(&validator, file, VALID, &selected).unwrap();
+
+        assert!(!filtered.properties.is_empty());
+        assert!(filtered.properties.len() < all.properties.len());
+        assert!(filtered.properties.iter().all(|property| property.kind == "type-safety"));
+        assert!(filtered.properties.iter().all(|property| ["proven", "disproven", "unknown"].contains(&property.status.as_str())));
+    }
+
+    #[test]
+    fn test_verify_parses_property_selection() {
+        let cli = Cli::try_parse_from(["aisp", "verify", "doc.aisp", "--timeout", "60", "--properties", "type-safety,liveness"]).unwrap();
+        match cli.command {
+            Some(Commands::Verify { timeout, ref properties, .. }) => {
+                assert_eq!(timeout, 60);
+                assert_eq!(
+                    parse_property_kinds(properties).unwrap(),
+                    vec![PropertyType::TypeSafety, PropertyType::TemporalLiveness]
+                );
+            }
+            _ => panic!("expected the verify subcommand"),
+        }
+
+        let error = parse_property_kinds(&["safty".to_string()]).unwrap_err();
+        assert!(error.to_string().starts_with("Unknown property kind 'safty' (expected one of: type-safety,"));
+    }
+
+    #[test]
+    fn test_report_template_renders_result_fields() {
+        let template = ReportTemplate::parse(
+            "{{#each results}}{{file}}|{{tier}}|{{fixed delta 2}}|{{temporal_analysis.ltl_formulas}}\n{{/each}}",
+        )
+        .unwrap();
+        let results = vec![sample_result("a.aisp"), sample_result("b.aisp")];
+
+        assert_eq!(
+            render_template(&template, &results).unwrap(),
+            "a.aisp|Platinum|0.82|2\nb.aisp|Platinum|0.82|2\n"
+        );
+    }
+
+    #[test]
+    fn test_default_report_template_matches_human_output() {
+        let mut invalid = sample_result("b.aisp");
+        invalid.valid = false;
+        invalid.errors = vec!["Missing required blocks".to_string()];
+        invalid.gate_failures = vec!["tier Gold below minimum Platinum".to_string()];
+        invalid.total_time_ms = None;
+        invalid.remediations = vec!["Add ⟦Ε⟧ evidence block".to_string()];
+        invalid.tier_explanation = vec!["δ below 0.75".to_string()];
+        let mut timed_out = sample_result("c.aisp");
+        timed_out.valid = false;
+        timed_out.timed_out = true;
+        timed_out.capabilities = vec![
+            StageCapability { stage: "parse".to_string(), status: StageStatus::Ran, reason: None },
+            StageCapability {
+                stage: "formal".to_string(),
+                status: StageStatus::Disabled,
+                reason: Some("Z3 not compiled in".to_string()),
+            },
+        ];
+        let results = vec![sample_result("a.aisp"), invalid, timed_out];
+
+        let template = ReportTemplate::builtin();
+        assert_eq!(render_template(&template, &results).unwrap(), strip_ansi(&render_human(&results, false)));
+    }
+
+    #[test]
+    fn test_report_template_requires_human_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("report.hbs");
+        fs::write(&path, "{{#each results}}{{file}}\n{{/each}}").unwrap();
+        let path_arg = path.to_str().unwrap();
+
+        for format in ["human", "detailed"] {
+            let cli = Cli::try_parse_from(["aisp", "--format", format, "--report-template", path_arg, "doc.aisp"]).unwrap();
+            assert!(load_report_template(&cli).unwrap().is_some());
+        }
+        let cli = Cli::try_parse_from(["aisp", "--format", "json", "--report-template", path_arg, "doc.aisp"]).unwrap();
+        assert_eq!(
+            load_report_template(&cli).unwrap_err().to_string(),
+            "--report-template only applies to the human and detailed formats"
+        );
+    }
+
+    #[test]
+    fn test_bad_report_template_is_rejected_at_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bad.hbs");
+        fs::write(&path, "{{#each results}}\n{{file}}\n").unwrap();
+
+        let path_arg = path.to_str().unwrap();
+        let cli = Cli::try_parse_from(["aisp", "--report-template", path_arg, "doc.aisp"]).unwrap();
+        let error = load_report_template(&cli).unwrap_err();
+        assert_eq!(
+            format!("{:#}", error),
+            format!("Invalid report template {}: line 1: unclosed '{{{{#each}}}}'", path.display())
+        );
+    }
+}
@@ -0,0 +1,322 @@
+//! Report Templates
+//!
+//! `--report-template` renders validation results through a user-supplied
+//! template written in a small Handlebars-style language:
+//!
+//! - `{{field}}` and `{{field.nested}}` substitute a result field
+//! - `{{fixed field 3}}` formats a number with 3 decimal places
+//! - `{{#if field}}…{{else}}…{{/if}}` and `{{#unless field}}…{{/unless}}`
+//!   test a field; `false`, null, empty strings and empty lists are false
+//! - `{{#each field}}…{{/each}}` repeats over a list, with `{{this}}`,
+//!   `{{@index}}`, `{{@first}}` and `{{@last}}` for the current item
+//!
+//! The template sees `results`, the serialized validation results. Names
+//! resolve against the innermost `each` item first, then outward.
+
+use serde_json::Value;
+use std::fmt;
+
+/// Template reproducing the plain human-readable output
+pub const DEFAULT_TEMPLATE: &str = include_str!("../templates/report.hbs");
+
+/// Why a template failed to load
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemplateError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BlockKind {
+    If,
+    Unless,
+    Each,
+}
+
+impl BlockKind {
+    fn name(self) -> &'static str {
+        match self {
+            BlockKind::If => "if",
+            BlockKind::Unless => "unless",
+            BlockKind::Each => "each",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Text(String),
+    Value(String),
+    Fixed(String, usize),
+    Block {
+        kind: BlockKind,
+        path: String,
+        body: Vec<Node>,
+        otherwise: Vec<Node>,
+    },
+}
+
+/// A block whose closing tag has not been reached yet
+struct OpenBlock {
+    kind: BlockKind,
+    path: String,
+    line: usize,
+    body: Vec<Node>,
+    otherwise: Option<Vec<Node>>,
+}
+
+impl OpenBlock {
+    fn nodes(&mut self) -> &mut Vec<Node> {
+        match &mut self.otherwise {
+            Some(otherwise) => otherwise,
+            None => &mut self.body,
+        }
+    }
+}
+
+/// A parsed, validated report template
+#[derive(Debug, Clone)]
+pub struct ReportTemplate {
+    nodes: Vec<Node>,
+}
+
+impl ReportTemplate {
+    /// The built-in `DEFAULT_TEMPLATE`
+    pub fn builtin() -> Self {
+        Self::parse(DEFAULT_TEMPLATE).expect("the default report template parses")
+    }
+
+    /// Parse `source`, rejecting malformed tags and unbalanced blocks
+    pub fn parse(source: &str) -> Result<Self, TemplateError> {
+        let mut root = Vec::new();
+        let mut open: Vec<OpenBlock> = Vec::new();
+        let mut rest = source;
+
+        while !rest.is_empty() {
+            let line = source[..source.len() - rest.len()].matches('\n').count() + 1;
+            let Some(start) = rest.find("{{") else {
+                push_node(&mut root, &mut open, Node::Text(rest.to_string()));
+                break;
+            };
+            if start > 0 {
+                push_node(&mut root, &mut open, Node::Text(rest[..start].to_string()));
+            }
+            let line = line + rest[..start].matches('\n').count();
+            let after = &rest[start + 2..];
+            let end = after.find("}}").ok_or_else(|| error(line, "unclosed '{{'"))?;
+            let tag = after[..end].trim();
+            rest = &after[end + 2..];
+
+            let words: Vec<&str> = tag.split_whitespace().collect();
+            match words.as_slice() {
+                [] => return Err(error(line, "empty tag '{{}}'")),
+                [opener, path] if opener.starts_with('#') => {
+                    let kind = match &opener[1..] {
+                        "if" => BlockKind::If,
+                        "unless" => BlockKind::Unless,
+                        "each" => BlockKind::Each,
+                        other => return Err(error(line, &format!("unknown block helper '#{}'", other))),
+                    };
+                    check_path(path, line)?;
+                    open.push(OpenBlock { kind, path: path.to_string(), line, body: Vec::new(), otherwise: None });
+                }
+                [opener, ..] if opener.starts_with('#') => {
+                    return Err(error(line, &format!("'{{{{{}}}}}' takes exactly one field", tag)));
+                }
+                [closer] if closer.starts_with('/') => {
+                    let block = open
+                        .pop()
+                        .ok_or_else(|| error(line, &format!("'{{{{{}}}}}' has no matching block", closer)))?;
+                    if &closer[1..] != block.kind.name() {
+                        return Err(error(
+                            line,
+                            &format!("'{{{{{}}}}}' closes '{{{{#{}}}}}' from line {}", closer, block.kind.name(), block.line),
+                        ));
+                    }
+                    let node = Node::Block {
+                        kind: block.kind,
+                        path: block.path,
+                        body: block.body,
+                        otherwise: block.otherwise.unwrap_or_default(),
+                    };
+                    push_node(&mut root, &mut open, node);
+                }
+                ["else"] => match open.last_mut() {
+                    Some(block) if block.kind != BlockKind::Each && block.otherwise.is_none() => {
+                        block.otherwise = Some(Vec::new());
+                    }
+                    _ => return Err(error(line, "'{{else}}' outside an '{{#if}}' or '{{#unless}}' block")),
+                },
+                ["fixed", path, digits] => {
+                    check_path(path, line)?;
+                    let digits = digits
+                        .parse()
+                        .map_err(|_| error(line, &format!("'fixed' expects a digit count, found '{}'", digits)))?;
+                    push_node(&mut root, &mut open, Node::Fixed(path.to_string(), digits));
+                }
+                [path] => {
+                    check_path(path, line)?;
+                    push_node(&mut root, &mut open, Node::Value(path.to_string()));
+                }
+                [helper, ..] => return Err(error(line, &format!("unknown helper '{}'", helper))),
+            }
+        }
+
+        if let Some(block) = open.pop() {
+            return Err(error(block.line, &format!("unclosed '{{{{#{}}}}}'", block.kind.name())));
+        }
+        Ok(Self { nodes: root })
+    }
+
+    /// Render the template with `context` as its outermost scope
+    pub fn render(&self, context: &Value) -> String {
+        let mut output = String::new();
+        render_nodes(&self.nodes, &Scope { value: context, item: None, parent: None }, &mut output);
+        output
+    }
+}
+
+fn error(line: usize, message: &str) -> TemplateError {
+    TemplateError { line, message: message.to_string() }
+}
+
+fn push_node(root: &mut Vec<Node>, open: &mut [OpenBlock], node: Node) {
+    match open.last_mut() {
+        Some(block) => block.nodes().push(node),
+        None => root.push(node),
+    }
+}
+
+fn check_path(path: &str, line: usize) -> Result<(), TemplateError> {
+    let valid = match path {
+        "@index" | "@first" | "@last" => true,
+        _ => path
+            .split('.')
+            .all(|segment| !segment.is_empty() && segment.chars().all(|c| c.is_alphanumeric() || c == '_')),
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(error(line, &format!("invalid field name '{}'", path)))
+    }
+}
+
+/// Name resolution context: the current `each` item and its enclosing scopes
+struct Scope<'a> {
+    value: &'a Value,
+    /// Index and length of the list being iterated
+    item: Option<(usize, usize)>,
+    parent: Option<&'a Scope<'a>>,
+}
+
+impl<'a> Scope<'a> {
+    fn lookup(&self, path: &str) -> Value {
+        match path {
+            "@index" => self.item.map_or(Value::Null, |(index, _)| index.into()),
+            "@first" => self.item.map_or(Value::Null, |(index, _)| (index == 0).into()),
+            "@last" => self.item.map_or(Value::Null, |(index, len)| (index + 1 == len).into()),
+            _ => {
+                let mut segments = path.split('.');
+                let first = segments.next().unwrap_or_default();
+                let mut value = if first == "this" { Some(self.value) } else { self.find(first) };
+                for segment in segments {
+                    value = value.and_then(|value| value.get(segment));
+                }
+                value.cloned().unwrap_or(Value::Null)
+            }
+        }
+    }
+
+    fn find(&self, key: &str) -> Option<&'a Value> {
+        self.value.get(key).or_else(|| self.parent.and_then(|parent| parent.find(key)))
+    }
+}
+
+fn render_nodes(nodes: &[Node], scope: &Scope<'_>, output: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => output.push_str(text),
+            Node::Value(path) => output.push_str(&display(&scope.lookup(path))),
+            Node::Fixed(path, digits) => match scope.lookup(path).as_f64() {
+                Some(number) => output.push_str(&format!("{:.*}", digits, number)),
+                None => output.push_str(&display(&scope.lookup(path))),
+            },
+            Node::Block { kind: BlockKind::Each, path, body, .. } => {
+                if let Value::Array(items) = scope.lookup(path) {
+                    for (index, item) in items.iter().enumerate() {
+                        let inner = Scope { value: item, item: Some((index, items.len())), parent: Some(scope) };
+                        render_nodes(body, &inner, output);
+                    }
+                }
+            }
+            Node::Block { kind, path, body, otherwise } => {
+                let truthy = is_truthy(&scope.lookup(path));
+                let taken = if truthy == (*kind == BlockKind::If) { body } else { otherwise };
+                render_nodes(taken, scope, output);
+            }
+        }
+    }
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(flag) => *flag,
+        Value::String(text) => !text.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Number(_) | Value::Object(_) => true,
+    }
+}
+
+fn display(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_each_if_and_fixed() {
+        let template = ReportTemplate::parse(
+            "{{#each results}}{{@index}}:{{file}}={{fixed delta 2}}{{#if warnings}} [{{#each warnings}}{{this}}/{{file}}{{/each}}]{{/if}}{{#unless @last}}, {{/unless}}{{/each}}",
+        )
+        .unwrap();
+        let context = json!({"results": [
+            {"file": "a.aisp", "delta": 0.456, "warnings": ["w"]},
+            {"file": "b.aisp", "delta": 1, "warnings": []},
+        ]});
+
+        assert_eq!(template.render(&context), "0:a.aisp=0.46 [w/a.aisp], 1:b.aisp=1.00");
+    }
+
+    #[test]
+    fn test_malformed_templates_report_their_line() {
+        let cases = [
+            ("a\n{{file", "line 2: unclosed '{{'"),
+            ("{{#each results}}\n{{/if}}", "line 2: '{{/if}}' closes '{{#each}}' from line 1"),
+            ("{{#if valid}}", "line 1: unclosed '{{#if}}'"),
+            ("{{#with x}}{{/with}}", "line 1: unknown block helper '#with'"),
+            ("{{else}}", "line 1: '{{else}}' outside an '{{#if}}' or '{{#unless}}' block"),
+            ("{{fixed delta x}}", "line 1: 'fixed' expects a digit count, found 'x'"),
+            ("{{file-name}}", "line 1: invalid field name 'file-name'"),
+        ];
+        for (source, expected) in cases {
+            let error = ReportTemplate::parse(source).unwrap_err();
+            assert_eq!(error.to_string(), expected, "template {:?}", source);
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! `--emit-metrics` and `--profile` are written even when `--fail-fast` stops the run
+
+use assert_cmd::Command;
+use std::fs;
+use tempfile::TempDir;
+
+const VALID: &str = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                     ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+
+#[test]
+fn test_fail_fast_still_writes_metrics_and_profile() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("invalid.aisp"), "not an AISP document").unwrap();
+    fs::write(dir.path().join("valid.aisp"), VALID).unwrap();
+    let metrics = dir.path().join("m.prom");
+    let profile = dir.path().join("profile.json");
+
+    Command::cargo_bin("aisp-cli")
+        .unwrap()
+        .arg("--quiet")
+        .args(["--emit-metrics", "prometheus", "--metrics-file"])
+        .arg(&metrics)
+        .arg("--profile")
+        .arg(&profile)
+        .args(["validate", "--fail-fast"])
+        .arg(dir.path().join("invalid.aisp"))
+        .arg(dir.path().join("valid.aisp"))
+        .assert()
+        .code(1);
+
+    // Only the failing file was validated before the run stopped
+    let rendered = fs::read_to_string(&metrics).unwrap();
+    assert!(rendered.contains("aisp_documents_total 1\n"), "{}", rendered);
+    assert!(rendered.contains("aisp_validation_failures 1\n"), "{}", rendered);
+    assert!(profile.exists());
+}
@@ -0,0 +1,35 @@
+//! `--quiet` prints nothing and reports only through the exit code
+
+use assert_cmd::Command;
+use std::fs;
+use tempfile::TempDir;
+
+const VALID: &str = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                     ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+
+fn quiet(dir: &TempDir, name: &str, args: &[&str]) -> assert_cmd::assert::Assert {
+    Command::cargo_bin("aisp-cli")
+        .unwrap()
+        .arg("--quiet")
+        .args(args)
+        .arg(dir.path().join(name))
+        .assert()
+}
+
+#[test]
+fn test_quiet_valid_document_prints_nothing() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("valid.aisp"), VALID).unwrap();
+
+    quiet(&dir, "valid.aisp", &[]).code(0).stdout("").stderr("");
+    quiet(&dir, "valid.aisp", &["--format", "json"]).code(0).stdout("").stderr("");
+}
+
+#[test]
+fn test_quiet_invalid_document_prints_nothing() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("invalid.aisp"), "not an AISP document").unwrap();
+
+    quiet(&dir, "invalid.aisp", &[]).code(1).stdout("").stderr("");
+    quiet(&dir, "missing.aisp", &[]).code(1).stdout("").stderr("");
+}
@@ -341,17 +341,14 @@ impl AntiDriftValidator {
         
         // Detect drift from semantic analysis
         let semantic_incidents = self.detect_semantic_drift(document, semantic_result)?;
-        eprintln!("DEBUG: Semantic drift incidents: {}", semantic_incidents.len());
         incidents.extend(semantic_incidents);
         
         // Detect structural drift
         let structural_incidents = self.detect_structural_drift(document)?;
-        eprintln!("DEBUG: Structural drift incidents: {}", structural_incidents.len());
         incidents.extend(structural_incidents);
         
         // Detect behavioral drift
         let behavioral_incidents = self.detect_behavioral_drift(document)?;
-        eprintln!("DEBUG: Behavioral drift incidents: {}", behavioral_incidents.len());
         incidents.extend(behavioral_incidents);
         
         // Analyze drift trends
@@ -819,6 +816,7 @@ mod tests {
                 protocol: Some("aisp".to_string()),
             },
             blocks: vec![],
+            used_symbols: Default::default(),
             span: Some(Span::new(0, 0, 1, 1)),
         }
     }
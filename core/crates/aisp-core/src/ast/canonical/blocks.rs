@@ -5,6 +5,7 @@
 
 use super::types::*;
 use std::collections::HashMap;
+use crate::semantic::QualityTier;
 use serde::{Serialize, Deserialize};
 
 /// Canonical Block representation with consistent method access patterns
@@ -68,12 +69,45 @@ impl CanonicalAispBlock {
             _ => None,
         }
     }
+
+    /// Get the block's source span if it was parsed from text
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            CanonicalAispBlock::Meta(meta) => meta.span.as_ref(),
+            CanonicalAispBlock::Types(types) => types.span.as_ref(),
+            CanonicalAispBlock::Rules(rules) => rules.span.as_ref(),
+            CanonicalAispBlock::Functions(functions) => functions.span.as_ref(),
+            CanonicalAispBlock::Evidence(evidence) => evidence.span.as_ref(),
+        }
+    }
+
+    /// Get the block's span for updating
+    pub fn span_mut(&mut self) -> &mut Option<Span> {
+        match self {
+            CanonicalAispBlock::Meta(meta) => &mut meta.span,
+            CanonicalAispBlock::Types(types) => &mut types.span,
+            CanonicalAispBlock::Rules(rules) => &mut rules.span,
+            CanonicalAispBlock::Functions(functions) => &mut functions.span,
+            CanonicalAispBlock::Evidence(evidence) => &mut evidence.span,
+        }
+    }
+
+    /// Byte range `(start, end)` of the whole block, from `⟦` to its
+    /// closing delimiter, in the source it was parsed from
+    ///
+    /// The parser sets this for every block it produces, so
+    /// `&source[start..end]` is exactly the block's text even when the
+    /// source was normalized before parsing.
+    pub fn byte_range(&self) -> Option<(usize, usize)> {
+        self.span().map(|span| (span.start, span.end))
+    }
 }
 
 /// Meta block for document metadata and configuration
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MetaBlock {
     pub entries: HashMap<String, MetaEntry>,
+    pub keys: Vec<String>, // Keys of `entries` in source order
     pub raw_entries: Vec<String>, // Keep raw strings for parsing
     pub span: Option<Span>,
 }
@@ -107,8 +141,11 @@ pub struct FunctionsBlock {
 pub struct EvidenceBlock {
     pub delta: Option<f64>,
     pub phi: Option<u64>, 
-    pub tau: Option<String>,
+    pub tau: Option<QualityTier>,
     pub metrics: HashMap<String, f64>,
+    /// Unit of each metric given with one, e.g. `ms` for `latency≜100ms`
+    #[serde(default)]
+    pub units: HashMap<String, String>,
     pub raw_evidence: Vec<String>, // Keep raw strings for parsing
     pub span: Option<Span>,
 }
@@ -118,15 +155,28 @@ impl MetaBlock {
     pub fn parse_entries(&mut self) {
         for raw_entry in &self.raw_entries {
             if let Some((key, value)) = Self::parse_meta_entry(raw_entry) {
+                if !self.keys.contains(&key) {
+                    self.keys.push(key.clone());
+                }
+                // Keep the source span recorded by the parser
+                let span = self.entries.get(&key).and_then(|entry| entry.span.clone());
                 self.entries.insert(key.clone(), MetaEntry {
                     key,
                     value,
-                    span: None,
+                    span,
                 });
             }
         }
     }
     
+    /// Keys sorted into the canonical `order`: listed keys first, in that
+    /// order, then any others in source order
+    pub fn canonical_keys(&self, order: &[String]) -> Vec<String> {
+        let mut keys = self.keys.clone();
+        keys.sort_by_key(|key| meta_key_rank(order, key));
+        keys
+    }
+    
     fn parse_meta_entry(entry: &str) -> Option<(String, MetaValue)> {
         // Simple parsing - extend as needed
         if let Some(pos) = entry.find('≜') { // ≜ definition symbol
@@ -151,12 +201,18 @@ impl MetaBlock {
     }
 }
 
+/// Position of `key` in a canonical meta key order; unlisted keys rank last
+pub fn meta_key_rank(order: &[String], key: &str) -> usize {
+    order.iter().position(|listed| listed == key).unwrap_or(order.len())
+}
+
 impl TypesBlock {
     /// Parse raw definitions into structured TypeDefinition objects
     pub fn parse_definitions(&mut self) {
         for raw_def in &self.raw_definitions {
             if let Some((name, type_expr)) = Self::parse_type_definition(raw_def) {
-                self.definitions.insert(name.clone(), TypeDefinition {
+                // Definitions the parser already structured keep their type and span
+                self.definitions.entry(name.clone()).or_insert(TypeDefinition {
                     name,
                     type_expr,
                     span: None,
@@ -194,6 +250,10 @@ impl RulesBlock {
     /// Parse raw rules into structured LogicalRule objects
     pub fn parse_rules(&mut self) {
         for raw_rule in &self.raw_rules {
+            // Rules the parser already structured keep their expression and span
+            if self.rules.iter().any(|rule| rule.raw_text == raw_rule.trim()) {
+                continue;
+            }
             if let Some(rule) = Self::parse_logical_rule(raw_rule) {
                 self.rules.push(rule);
             }
@@ -215,6 +275,10 @@ impl FunctionsBlock {
     /// Parse raw functions into structured FunctionDefinition objects
     pub fn parse_functions(&mut self) {
         for raw_func in &self.raw_functions {
+            // Functions the parser already structured keep their lambda and span
+            if self.functions.iter().any(|function| function.raw_text == raw_func.trim()) {
+                continue;
+            }
             if let Some(func) = Self::parse_function_definition(raw_func) {
                 self.functions.push(func);
             }
@@ -265,8 +329,8 @@ impl EvidenceBlock {
             }
         }
         if evidence_str.contains('τ') {
-            if let Some(tau_val) = Self::extract_string_value(evidence_str, 'τ') {
-                self.tau = Some(tau_val);
+            if let Some(tau) = Self::extract_string_value(evidence_str, 'τ').and_then(|glyph| QualityTier::from_glyph(&glyph)) {
+                self.tau = Some(tau);
             }
         }
     }
@@ -310,6 +374,7 @@ mod tests {
     fn test_block_type_identification() {
         let meta_block = CanonicalAispBlock::Meta(MetaBlock {
             entries: HashMap::new(),
+            keys: Vec::new(),
             raw_entries: vec!["test".to_string()],
             span: None,
         });
@@ -323,6 +388,7 @@ mod tests {
     fn test_meta_entry_parsing() {
         let mut meta_block = MetaBlock {
             entries: HashMap::new(),
+            keys: Vec::new(),
             raw_entries: vec!["Vision≜\"test\"".to_string(), "count≜42".to_string()],
             span: None,
         };
@@ -331,6 +397,21 @@ mod tests {
         assert_eq!(meta_block.entries.len(), 2);
         assert!(meta_block.entries.contains_key("Vision"));
         assert!(meta_block.entries.contains_key("count"));
+        assert_eq!(meta_block.keys, vec!["Vision", "count"]);
+    }
+
+    #[test]
+    fn test_canonical_keys_follow_the_order_then_source_order() {
+        let mut meta_block = MetaBlock {
+            entries: HashMap::new(),
+            keys: Vec::new(),
+            raw_entries: ["extra≜1", "Date≜2026", "other≜2", "Vision≜v"].map(String::from).to_vec(),
+            span: None,
+        };
+        meta_block.parse_entries();
+
+        let order = ["Vision", "Author", "Date"].map(String::from);
+        assert_eq!(meta_block.canonical_keys(&order), vec!["Vision", "Date", "extra", "other"]);
     }
 
     #[test]
@@ -340,6 +421,7 @@ mod tests {
             phi: None,
             tau: None,
             metrics: HashMap::new(),
+            units: HashMap::new(),
             raw_evidence: vec!["δ≜0.001".to_string(), "φ≜42".to_string()],
             span: None,
         };
@@ -377,4 +459,18 @@ mod tests {
         assert_eq!(functions_block.functions[0].name, "f");
         assert_eq!(functions_block.functions[0].raw_text, "f≜λx.x + 1");
     }
+
+    #[test]
+    fn test_parsed_rules_and_functions_are_not_duplicated() {
+        let source = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n  p→p\n}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+        let mut document = crate::parser::robust_parser::RobustAispParser::new().parse(source).document.unwrap();
+        document.parse_structured_data();
+
+        let rules = document.blocks.iter().find_map(|block| block.as_rules()).unwrap();
+        assert_eq!(rules.rules.len(), 2);
+        assert!(rules.rules.iter().all(|rule| rule.span.is_some()));
+        let functions = document.blocks.iter().find_map(|block| block.as_functions()).unwrap();
+        assert_eq!(functions.functions.len(), 1);
+    }
 }
\ No newline at end of file
@@ -139,6 +139,7 @@ mod tests {
 
         doc.add_block(CanonicalAispBlock::Meta(MetaBlock {
             entries,
+            keys: vec!["test_key".to_string()],
             raw_entries: Vec::new(),
             span: None,
         }));
@@ -6,6 +6,7 @@
 use super::types::*;
 use super::blocks::*;
 use serde::{Serialize, Deserialize};
+use std::collections::HashSet;
 
 /// Canonical AISP Document representation - SINGLE SOURCE OF TRUTH
 /// 
@@ -16,6 +17,9 @@ pub struct CanonicalAispDocument {
     pub header: DocumentHeader,
     pub metadata: DocumentMetadata,
     pub blocks: Vec<CanonicalAispBlock>,
+    /// AISP symbols occurring outside comments, recorded by the parser
+    #[serde(default)]
+    pub used_symbols: HashSet<char>,
     pub span: Option<Span>,
 }
 
@@ -33,6 +37,7 @@ impl Default for CanonicalAispDocument {
                 protocol: None,
             },
             blocks: Vec::new(),
+            used_symbols: HashSet::new(),
             span: None,
         }
     }
@@ -53,6 +58,7 @@ impl CanonicalAispDocument {
                 protocol: None,
             },
             blocks: Vec::new(),
+            used_symbols: HashSet::new(),
             span: None,
         }
     }
@@ -294,6 +300,7 @@ mod tests {
         
         doc.add_block(CanonicalAispBlock::Meta(MetaBlock {
             entries: HashMap::new(),
+            keys: Vec::new(),
             raw_entries: vec!["meta1".to_string()],
             span: None,
         }));
@@ -325,6 +332,7 @@ mod tests {
         
         doc.add_block(CanonicalAispBlock::Meta(MetaBlock {
             entries: meta_entries,
+            keys: vec!["key1".to_string()],
             raw_entries: Vec::new(),
             span: None,
         }));
@@ -362,6 +370,7 @@ mod tests {
         
         doc.add_block(CanonicalAispBlock::Meta(MetaBlock {
             entries: HashMap::new(),
+            keys: Vec::new(),
             raw_entries: vec!["Vision≜\"test\"".to_string()],
             span: None,
         }));
@@ -374,6 +383,28 @@ mod tests {
         assert!(meta_blocks[0].entries.contains_key("Vision"));
     }
 
+    #[test]
+    fn test_parsed_document_json_round_trip() {
+        let source = "𝔸5.1.RoundTrip@2026-01-25\n\n\
+                      ⟦Ω:Meta⟧{\n  domain≜scheduling;\n  Vision≜\"queue work\";\n  limit≜8\n}\n\n\
+                      ⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n  Count≜ℕ\n}\n\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n\
+                      ⟦Λ:Funcs⟧{\n  id≜λx.x\n}\n\n\
+                      ⟦Ε⟧⟨δ≜0.75;φ≜100⟩";
+        let parsed = crate::parser::parse(source).unwrap();
+        let document = parsed.get_primary_document().unwrap().clone();
+        assert_eq!(document.get_meta_blocks()[0].entries.len(), 3);
+        let types = document.get_types_blocks()[0];
+        assert_eq!(types.definitions.len(), 2);
+        assert!(types.definitions["State"].span.is_some());
+        assert_eq!(document.get_evidence_blocks().len(), 1);
+
+        let json = serde_json::to_string(&document).unwrap();
+        let restored: CanonicalAispDocument = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, document);
+    }
+
     #[test]
     fn test_convenience_accessors() {
         let mut doc = CanonicalAispDocument::default();
@@ -32,6 +32,7 @@ pub fn create_document(name: &str, version: &str, date: &str) -> CanonicalAispDo
 pub fn create_meta_block(raw_entries: Vec<String>) -> CanonicalAispBlock {
     CanonicalAispBlock::Meta(MetaBlock {
         entries: std::collections::HashMap::new(),
+        keys: Vec::new(),
         raw_entries,
         span: None,
     })
@@ -67,6 +68,7 @@ pub fn create_evidence_block(raw_evidence: Vec<String>) -> CanonicalAispBlock {
         phi: None,
         tau: None,
         metrics: std::collections::HashMap::new(),
+        units: std::collections::HashMap::new(),
         raw_evidence,
         span: None,
     })
@@ -240,7 +242,7 @@ mod integration_tests {
         doc.add_block(create_evidence_block(vec![
             "δ≜0.001".to_string(),
             "φ≜42".to_string(),
-            "τ≜formal".to_string(),
+            "τ≜◊⁺⁺".to_string(),
         ]));
         
         // Parse structured data
@@ -270,6 +272,6 @@ mod integration_tests {
         let evidence_blocks = doc.get_evidence_blocks();
         assert_eq!(evidence_blocks[0].delta, Some(0.001));
         assert_eq!(evidence_blocks[0].phi, Some(42));
-        assert_eq!(evidence_blocks[0].tau, Some("formal".to_string()));
+        assert_eq!(evidence_blocks[0].tau, Some(crate::semantic::QualityTier::Platinum));
     }
 }
\ No newline at end of file
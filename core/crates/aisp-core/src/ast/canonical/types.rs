@@ -62,6 +62,11 @@ pub enum TypeExpression {
         params: Vec<TypeExpression>,
         return_type: Box<TypeExpression>,
     },
+    /// Instantiation of a generic type, such as `Pair⟨ℕ,ℝ⟩`
+    Generic {
+        name: String,
+        arguments: Vec<TypeExpression>,
+    },
     // Legacy compatibility variants
     Enumeration(Vec<String>),
     Tuple(Vec<TypeExpression>),
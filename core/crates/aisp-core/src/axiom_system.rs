@@ -4,7 +4,8 @@
 //! theorem prover, including logical, temporal, and AISP-specific axioms.
 
 use crate::property_types::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
 
 /// Axiom in the proof system
 #[derive(Debug, Clone)]
@@ -19,6 +20,13 @@ pub struct Axiom {
     pub priority: u8,
 }
 
+/// Complete set of axioms and inference rules
+#[derive(Debug, Clone)]
+pub struct AxiomSystem {
+    pub axioms: Vec<Axiom>,
+    pub inference_rules: Vec<InferenceRule>,
+}
+
 /// Types of axioms
 #[derive(Debug, Clone, PartialEq)]
 pub enum AxiomType {
@@ -233,6 +241,14 @@ impl AxiomSystemBuilder {
         (self.axioms, self.inference_rules)
     }
 
+    /// Build as an `AxiomSystem`
+    pub fn build_system(self) -> AxiomSystem {
+        AxiomSystem {
+            axioms: self.axioms,
+            inference_rules: self.inference_rules,
+        }
+    }
+
     // Helper methods for creating specific formulas
     fn create_modus_ponens_formula(&self) -> FormulaStructure {
         // ((P → Q) ∧ P) → Q
@@ -473,6 +489,249 @@ impl Default for AxiomSystemBuilder {
     }
 }
 
+impl AxiomSystem {
+    /// Propositional, predicate, temporal and AISP axioms with their rules
+    pub fn standard() -> Self {
+        let mut builder = AxiomSystemBuilder::new();
+        builder.add_propositional_axioms();
+        builder.add_predicate_axioms();
+        builder.add_temporal_axioms();
+        builder.add_aisp_axioms();
+        builder.build_system()
+    }
+
+    /// Export the axioms as an Isabelle/HOL theory
+    ///
+    /// Logical and arithmetic axioms hold in HOL, so they become lemmas that
+    /// Isabelle must prove. Temporal, type-theoretic, AISP and domain axioms
+    /// have no HOL counterpart; they become `axiomatization` entries over
+    /// uninterpreted constants, declared in the group that first uses them.
+    pub fn export_isabelle(&self) -> String {
+        let mut theory = String::from("theory AISP_Axioms\n  imports Main\nbegin\n");
+        let mut declared = HashSet::new();
+
+        let mut groups: Vec<(&AxiomType, Vec<&Axiom>)> = Vec::new();
+        for axiom in &self.axioms {
+            match groups.iter_mut().find(|(axiom_type, _)| **axiom_type == axiom.axiom_type) {
+                Some((_, members)) => members.push(axiom),
+                None => groups.push((&axiom.axiom_type, vec![axiom])),
+            }
+        }
+
+        for (axiom_type, axioms) in groups {
+            let _ = write!(theory, "\nsection \\<open>{} axioms\\<close>\n\n", axiom_type_title(axiom_type));
+            match axiom_type {
+                AxiomType::Logical | AxiomType::Arithmetic => {
+                    let method = if *axiom_type == AxiomType::Logical { "blast" } else { "arith" };
+                    for axiom in axioms {
+                        let _ = write!(
+                            theory,
+                            "lemma {}: \"{}\"\n  by {}\n\n",
+                            isabelle_name(&axiom.name),
+                            formula_to_isabelle(&axiom.formula),
+                            method
+                        );
+                    }
+                }
+                _ => {
+                    let mut constants = Vec::new();
+                    for axiom in &axioms {
+                        collect_constants(&axiom.formula, &mut constants);
+                    }
+                    constants.retain(|(name, _)| declared.insert(name.clone()));
+
+                    theory.push_str("axiomatization");
+                    for (index, (name, signature)) in constants.iter().enumerate() {
+                        let separator = if index + 1 < constants.len() { " and" } else { "" };
+                        let _ = write!(theory, "\n  {} :: \"{}\"{}", name, signature, separator);
+                    }
+                    theory.push_str("\nwhere\n");
+                    for (index, axiom) in axioms.iter().enumerate() {
+                        let separator = if index + 1 < axioms.len() { " and" } else { "" };
+                        let _ = writeln!(
+                            theory,
+                            "  {}: \"{}\"{}",
+                            isabelle_name(&axiom.name),
+                            formula_to_isabelle(&axiom.formula),
+                            separator
+                        );
+                    }
+                    theory.push('\n');
+                }
+            }
+        }
+
+        theory.push_str("end\n");
+        theory
+    }
+}
+
+impl Default for AxiomSystem {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+fn axiom_type_title(axiom_type: &AxiomType) -> &'static str {
+    match axiom_type {
+        AxiomType::Logical => "Logical",
+        AxiomType::Arithmetic => "Arithmetic",
+        AxiomType::TypeTheory => "Type theory",
+        AxiomType::Temporal => "Temporal",
+        AxiomType::AispSpecific => "AISP",
+        AxiomType::Domain => "Domain",
+    }
+}
+
+/// Axiom names may be free text; Isabelle needs an identifier
+fn isabelle_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+    match name.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() => name,
+        _ => format!("ax_{}", name),
+    }
+}
+
+/// Uninterpreted constants a formula needs, with their Isabelle types
+fn collect_constants(formula: &FormulaStructure, constants: &mut Vec<(String, String)>) {
+    let mut add = |name: &str, signature: String| {
+        if !constants.iter().any(|(existing, _)| existing == name) {
+            constants.push((name.to_string(), signature));
+        }
+    };
+
+    match formula {
+        FormulaStructure::TemporalAlways(inner) => {
+            add("always", "bool \\<Rightarrow> bool".to_string());
+            collect_constants(inner, constants);
+        }
+        FormulaStructure::TemporalEventually(inner) => {
+            add("eventually", "bool \\<Rightarrow> bool".to_string());
+            collect_constants(inner, constants);
+        }
+        FormulaStructure::TemporalUntil(left, right) => {
+            add("until", "bool \\<Rightarrow> bool \\<Rightarrow> bool".to_string());
+            collect_constants(left, constants);
+            collect_constants(right, constants);
+        }
+        FormulaStructure::PathForall(inner) => {
+            add("all_paths", "bool \\<Rightarrow> bool".to_string());
+            collect_constants(inner, constants);
+        }
+        FormulaStructure::PathExists(inner) => {
+            add("some_path", "bool \\<Rightarrow> bool".to_string());
+            collect_constants(inner, constants);
+        }
+        FormulaStructure::FunctionApplication(name, terms) => {
+            let mut signature: Vec<String> = (0..terms.len())
+                .map(|index| format!("'{}", (b'a' + index as u8) as char))
+                .collect();
+            signature.push("bool".to_string());
+            add(name, signature.join(" \\<Rightarrow> "));
+        }
+        FormulaStructure::Negation(inner)
+        | FormulaStructure::Universal(_, inner)
+        | FormulaStructure::Existential(_, inner) => collect_constants(inner, constants),
+        FormulaStructure::Conjunction(parts) | FormulaStructure::Disjunction(parts) => {
+            for part in parts {
+                collect_constants(part, constants);
+            }
+        }
+        FormulaStructure::Implication(left, right) | FormulaStructure::Biconditional(left, right) => {
+            collect_constants(left, constants);
+            collect_constants(right, constants);
+        }
+        FormulaStructure::Atomic(_)
+        | FormulaStructure::ArithmeticEqual(_, _)
+        | FormulaStructure::ArithmeticLessEqual(_, _)
+        | FormulaStructure::SetMembership(_, _) => {}
+    }
+}
+
+fn formula_to_isabelle(formula: &FormulaStructure) -> String {
+    match formula {
+        FormulaStructure::Atomic(atomic) => application(&atomic.predicate, &atomic.terms),
+        FormulaStructure::Negation(inner) => format!("(\\<not> {})", formula_to_isabelle(inner)),
+        FormulaStructure::Conjunction(parts) => join_formulas(parts, " \\<and> ", "True"),
+        FormulaStructure::Disjunction(parts) => join_formulas(parts, " \\<or> ", "False"),
+        FormulaStructure::Implication(left, right) => {
+            format!("({} \\<longrightarrow> {})", formula_to_isabelle(left), formula_to_isabelle(right))
+        }
+        FormulaStructure::Biconditional(left, right) => {
+            format!("({} \\<longleftrightarrow> {})", formula_to_isabelle(left), formula_to_isabelle(right))
+        }
+        FormulaStructure::Universal(quantifier, body) => {
+            format!("(\\<forall>{}. {})", quantifier.variable, formula_to_isabelle(body))
+        }
+        FormulaStructure::Existential(quantifier, body) => {
+            format!("(\\<exists>{}. {})", quantifier.variable, formula_to_isabelle(body))
+        }
+        FormulaStructure::TemporalAlways(inner) => format!("(always {})", formula_to_isabelle(inner)),
+        FormulaStructure::TemporalEventually(inner) => format!("(eventually {})", formula_to_isabelle(inner)),
+        FormulaStructure::TemporalUntil(left, right) => {
+            format!("(until {} {})", formula_to_isabelle(left), formula_to_isabelle(right))
+        }
+        FormulaStructure::PathForall(inner) => format!("(all_paths {})", formula_to_isabelle(inner)),
+        FormulaStructure::PathExists(inner) => format!("(some_path {})", formula_to_isabelle(inner)),
+        FormulaStructure::ArithmeticEqual(left, right) => {
+            format!("({} = {})", term_to_isabelle(left), term_to_isabelle(right))
+        }
+        FormulaStructure::ArithmeticLessEqual(left, right) => {
+            format!("({} \\<le> {})", term_to_isabelle(left), term_to_isabelle(right))
+        }
+        FormulaStructure::SetMembership(element, set) => {
+            format!("({} \\<in> {})", term_to_isabelle(element), term_to_isabelle(set))
+        }
+        FormulaStructure::FunctionApplication(name, terms) => application(name, terms),
+    }
+}
+
+fn join_formulas(parts: &[FormulaStructure], operator: &str, empty: &str) -> String {
+    if parts.is_empty() {
+        return empty.to_string();
+    }
+    let parts: Vec<String> = parts.iter().map(formula_to_isabelle).collect();
+    format!("({})", parts.join(operator))
+}
+
+fn application(name: &str, terms: &[Term]) -> String {
+    if terms.is_empty() {
+        return name.to_string();
+    }
+    let arguments: Vec<String> = terms.iter().map(term_to_isabelle).collect();
+    format!("({} {})", name, arguments.join(" "))
+}
+
+fn term_to_isabelle(term: &Term) -> String {
+    match term {
+        Term::Variable(name, _) => name.clone(),
+        Term::Constant(value, _) if value.parse::<f64>().is_ok() => value.clone(),
+        Term::Constant(value, _) => format!("''{}''", value),
+        Term::Function(name, arguments) => application(name, arguments),
+        Term::Arithmetic(op, left, right) => {
+            let operator = match op {
+                ArithmeticOp::Add => "+",
+                ArithmeticOp::Subtract => "-",
+                ArithmeticOp::Multiply => "*",
+                ArithmeticOp::Divide => "div",
+                ArithmeticOp::Modulo => "mod",
+                ArithmeticOp::Power => "^",
+            };
+            format!("({} {} {})", term_to_isabelle(left), operator, term_to_isabelle(right))
+        }
+        Term::Set(elements) => {
+            let elements: Vec<String> = elements.iter().map(term_to_isabelle).collect();
+            format!("{{{}}}", elements.join(", "))
+        }
+        Term::ArrayAccess(array, index) => {
+            format!("({} ! {})", term_to_isabelle(array), term_to_isabelle(index))
+        }
+    }
+}
+
 impl Axiom {
     /// Create new axiom
     pub fn new(name: String, formula: FormulaStructure, axiom_type: AxiomType, priority: u8) -> Self {
@@ -589,4 +848,28 @@ mod tests {
         assert!(rule.can_apply(&formulas));
         assert!(!rule.can_apply(&[]));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_isabelle_export_has_entry_for_every_axiom() {
+        let system = AxiomSystem::standard();
+        let theory = system.export_isabelle();
+
+        assert!(theory.starts_with("theory AISP_Axioms\n  imports Main\nbegin\n"));
+        assert!(theory.ends_with("end\n"));
+        for axiom in &system.axioms {
+            assert!(
+                theory.contains(&format!("lemma {}: \"", axiom.name))
+                    || theory.contains(&format!("  {}: \"", axiom.name)),
+                "missing entry for {}",
+                axiom.name
+            );
+        }
+
+        assert!(theory.contains(
+            "lemma modus_ponens: \"(((P \\<longrightarrow> Q) \\<and> P) \\<longrightarrow> Q)\"\n  by blast"
+        ));
+        assert!(theory.contains("  always :: \"bool \\<Rightarrow> bool\""));
+        assert!(theory.contains("  hasType :: \"'a \\<Rightarrow> 'b \\<Rightarrow> bool\""));
+        assert_eq!(theory.matches("always ::").count(), 1);
+    }
+}
@@ -19,8 +19,10 @@ use crate::{
     z3_verification::PropertyResult,
 };
 use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::time::{Duration, Instant};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Complete compositional proof system for AISP layer integration
 pub struct CompositionalProofChain {
@@ -97,6 +99,59 @@ pub enum LayerIdentifier {
     L2Search,
 }
 
+/// Proposition with the type it inhabits at a layer boundary
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypedProposition {
+    pub name: String,
+    pub proposition_type: String,
+}
+
+/// Proof object for one layer together with its composition interface
+///
+/// `assumes` lists what the proof takes as input from the layer below;
+/// `establishes` lists what it provides to the layer above.
+#[derive(Debug, Clone)]
+pub struct LayerProof {
+    pub layer: LayerIdentifier,
+    pub assumes: Vec<TypedProposition>,
+    pub establishes: Vec<TypedProposition>,
+    pub proof_certificate: ProofCertificate,
+}
+
+/// Result of successfully composing a sequence of layer proofs
+#[derive(Debug, Clone)]
+pub struct ComposedProof {
+    /// Layers in composition order
+    pub layers: Vec<LayerIdentifier>,
+    /// Assumptions left open by the first layer
+    pub assumes: Vec<TypedProposition>,
+    /// Guarantees provided by the last layer
+    pub establishes: Vec<TypedProposition>,
+}
+
+/// Failure to compose two adjacent layer proofs
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum CompositionError {
+    #[error("Cannot compose an empty proof chain")]
+    EmptyChain,
+
+    #[error("Broken link {from} → {to}: '{assumption}' is assumed but not established")]
+    MissingAssumption {
+        from: LayerIdentifier,
+        to: LayerIdentifier,
+        assumption: String,
+    },
+
+    #[error("Broken link {from} → {to}: '{assumption}' expected type {expected}, found {found}")]
+    TypeMismatch {
+        from: LayerIdentifier,
+        to: LayerIdentifier,
+        assumption: String,
+        expected: String,
+        found: String,
+    },
+}
+
 /// Formal mathematical statement
 #[derive(Debug, Clone)]
 pub struct FormalStatement {
@@ -414,6 +469,47 @@ impl CompositionalProofChain {
         })
     }
 
+    /// Check that a sequence of layer proofs composes
+    ///
+    /// Each link `p_i → p_{i+1}` composes when every assumption of `p_{i+1}`
+    /// is established by `p_i` at the same type. The first failing link is
+    /// reported with the assumption that could not be discharged.
+    pub fn check_composition(&self, proofs: &[LayerProof]) -> Result<ComposedProof, CompositionError> {
+        let (first, last) = match (proofs.first(), proofs.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Err(CompositionError::EmptyChain),
+        };
+
+        for link in proofs.windows(2) {
+            let (lower, upper) = (&link[0], &link[1]);
+            for assumption in &upper.assumes {
+                let provided = lower.establishes.iter()
+                    .find(|prop| prop.name == assumption.name)
+                    .ok_or_else(|| CompositionError::MissingAssumption {
+                        from: lower.layer,
+                        to: upper.layer,
+                        assumption: assumption.name.clone(),
+                    })?;
+
+                if provided.proposition_type != assumption.proposition_type {
+                    return Err(CompositionError::TypeMismatch {
+                        from: lower.layer,
+                        to: upper.layer,
+                        assumption: assumption.name.clone(),
+                        expected: assumption.proposition_type.clone(),
+                        found: provided.proposition_type.clone(),
+                    });
+                }
+            }
+        }
+
+        Ok(ComposedProof {
+            layers: proofs.iter().map(|proof| proof.layer).collect(),
+            assumes: first.assumes.clone(),
+            establishes: last.establishes.clone(),
+        })
+    }
+
     /// Verify Layer 0 (Signal Theory) properties
     /// Proves: 𝕃₀.⊢stable ∧ 𝕃₀.⊢deterministic
     fn verify_layer_0_properties(&mut self, input: &VectorSpaceInput) -> AispResult<LayerVerificationResult> {
@@ -957,6 +1053,59 @@ impl TheoremDatabase {
     }
 }
 
+impl TypedProposition {
+    pub fn new(name: &str, proposition_type: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            proposition_type: proposition_type.to_string(),
+        }
+    }
+}
+
+impl LayerProof {
+    /// Proof object with the interface the AISP 5.1 chain assigns to `layer`
+    ///
+    /// 𝕃₀ ⊢ stable∧deterministic, 𝕃₁ ⊢ integrity∧zero_copy, 𝕃₂ ⊢ terminates∧bounded,
+    /// each assuming exactly what the layer below establishes.
+    pub fn standard(layer: LayerIdentifier, proof_certificate: ProofCertificate) -> Self {
+        let l0 = vec![
+            TypedProposition::new("stable", "Stable(𝕃₀)"),
+            TypedProposition::new("deterministic", "Deterministic(𝕃₀)"),
+        ];
+        let l1 = vec![
+            TypedProposition::new("integrity", "Integrity(𝕃₁)"),
+            TypedProposition::new("zero_copy", "ZeroCopy(𝕃₁)"),
+        ];
+        let l2 = vec![
+            TypedProposition::new("terminates", "Terminates(𝕃₂)"),
+            TypedProposition::new("bounded", "Bounded(𝕃₂)"),
+        ];
+
+        let (assumes, establishes) = match layer {
+            LayerIdentifier::L0Signal => (vec![], l0),
+            LayerIdentifier::L1Pocket => (l0, l1),
+            LayerIdentifier::L2Search => (l1, l2),
+        };
+
+        Self {
+            layer,
+            assumes,
+            establishes,
+            proof_certificate,
+        }
+    }
+}
+
+impl fmt::Display for LayerIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayerIdentifier::L0Signal => write!(f, "𝕃₀"),
+            LayerIdentifier::L1Pocket => write!(f, "𝕃₁"),
+            LayerIdentifier::L2Search => write!(f, "𝕃₂"),
+        }
+    }
+}
+
 impl Default for CompositionalProofChain {
     fn default() -> Self {
         Self::new()
@@ -1009,4 +1158,72 @@ mod tests {
         assert!(guarantees.optimality_guaranteed);
         assert_eq!(guarantees.overall_confidence, 0.95);
     }
-}
\ No newline at end of file
+
+    fn test_certificate(validator: &str) -> ProofCertificate {
+        ProofCertificate {
+            proof_method: ProofMethod::DirectProof,
+            proof_steps: vec![],
+            verification_time: Duration::from_millis(1),
+            proof_validator: validator.to_string(),
+            certificate_hash: [0u8; 32],
+        }
+    }
+
+    fn standard_chain() -> Vec<LayerProof> {
+        vec![
+            LayerProof::standard(LayerIdentifier::L0Signal, test_certificate("l0")),
+            LayerProof::standard(LayerIdentifier::L1Pocket, test_certificate("l1")),
+            LayerProof::standard(LayerIdentifier::L2Search, test_certificate("l2")),
+        ]
+    }
+
+    #[test]
+    fn test_valid_chain_composes() {
+        let chain = CompositionalProofChain::new();
+        let composed = chain.check_composition(&standard_chain()).unwrap();
+
+        assert_eq!(
+            composed.layers,
+            vec![LayerIdentifier::L0Signal, LayerIdentifier::L1Pocket, LayerIdentifier::L2Search]
+        );
+        assert!(composed.assumes.is_empty());
+        assert!(composed.establishes.contains(&TypedProposition::new("bounded", "Bounded(𝕃₂)")));
+    }
+
+    #[test]
+    fn test_type_mismatch_names_broken_link() {
+        let chain = CompositionalProofChain::new();
+        let mut proofs = standard_chain();
+        proofs[1].establishes[0] = TypedProposition::new("integrity", "Integrity(𝕃₀)");
+
+        let error = chain.check_composition(&proofs).unwrap_err();
+        assert_eq!(
+            error,
+            CompositionError::TypeMismatch {
+                from: LayerIdentifier::L1Pocket,
+                to: LayerIdentifier::L2Search,
+                assumption: "integrity".to_string(),
+                expected: "Integrity(𝕃₁)".to_string(),
+                found: "Integrity(𝕃₀)".to_string(),
+            }
+        );
+        assert!(error.to_string().contains("𝕃₁ → 𝕃₂"));
+    }
+
+    #[test]
+    fn test_missing_assumption_and_empty_chain_rejected() {
+        let chain = CompositionalProofChain::new();
+        let mut proofs = standard_chain();
+        proofs[0].establishes.retain(|prop| prop.name != "deterministic");
+
+        assert_eq!(
+            chain.check_composition(&proofs).unwrap_err(),
+            CompositionError::MissingAssumption {
+                from: LayerIdentifier::L0Signal,
+                to: LayerIdentifier::L1Pocket,
+                assumption: "deterministic".to_string(),
+            }
+        );
+        assert_eq!(chain.check_composition(&[]).unwrap_err(), CompositionError::EmptyChain);
+    }
+}
@@ -320,6 +320,7 @@ impl Default for ConflictDetector {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::constraint_solver::Satisfiability;
     use std::collections::HashMap;
 
     #[test]
@@ -339,6 +340,9 @@ mod tests {
             unsatisfied: vec![],
             conflicts: vec![],
             satisfaction_score: 1.0,
+            witness: None,
+            unsat_core: vec![],
+            satisfiability: Satisfiability::Satisfiable,
         };
 
         let result = detector.detect_constraint_conflicts(&constraint_analysis)?;
@@ -378,6 +382,9 @@ mod tests {
             unsatisfied: vec!["c1".to_string(), "c2".to_string()],
             conflicts: vec![],
             satisfaction_score: 0.0,
+            witness: None,
+            unsat_core: vec![],
+            satisfiability: Satisfiability::Satisfiable,
         };
 
         let result = detector.detect_constraint_conflicts(&constraint_analysis)?;
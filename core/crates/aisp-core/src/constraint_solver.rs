@@ -5,7 +5,11 @@
 
 use crate::ast::canonical::*;
 use crate::conflict_types::ConflictSeverity;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Partial assignments the witness and unsat core searches may try
+/// together before the analysis gives up as unknown
+pub const SEARCH_NODE_BUDGET: usize = 100_000;
 
 /// Constraint solver for relational logic
 pub struct ConstraintSolver {
@@ -15,6 +19,8 @@ pub struct ConstraintSolver {
     domains: HashMap<String, Vec<String>>,
     /// Current variable assignments
     assignments: HashMap<String, String>,
+    /// Partial assignments each analysis may try
+    search_budget: usize,
 }
 
 /// A constraint in the relational system
@@ -75,6 +81,31 @@ pub struct ConstraintAnalysisResult {
     pub conflicts: Vec<ConstraintConflict>,
     /// Overall satisfaction score (0.0-1.0)
     pub satisfaction_score: f64,
+    /// Variable assignment satisfying every constraint, if one exists
+    pub witness: Option<HashMap<String, String>>,
+    /// Minimal set of constraint IDs that cannot hold together; empty when
+    /// a witness was found
+    pub unsat_core: Vec<String>,
+    /// Whether the search settled satisfiability within its budget
+    pub satisfiability: Satisfiability,
+}
+
+/// Whether the constraints admit a common assignment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Satisfiability {
+    /// A witness satisfies every constraint
+    Satisfiable,
+    /// No assignment satisfies every constraint
+    Unsatisfiable,
+    /// The search ran out of budget before settling either way
+    Unknown,
+}
+
+/// Outcome of one witness search
+enum Search {
+    Found(HashMap<String, String>),
+    Exhausted,
+    OverBudget,
 }
 
 /// Conflict between constraints
@@ -98,6 +129,7 @@ impl ConstraintSolver {
             constraints: Vec::new(),
             domains: HashMap::new(),
             assignments: HashMap::new(),
+            search_budget: SEARCH_NODE_BUDGET,
         }
     }
 
@@ -231,12 +263,21 @@ impl ConstraintSolver {
     }
 
     /// Analyze constraint satisfaction
-    fn analyze_satisfaction(&self) -> ConstraintAnalysisResult {
+    fn analyze_satisfaction(&mut self) -> ConstraintAnalysisResult {
         let mut satisfied = Vec::new();
         let mut unsatisfied = Vec::new();
         let mut conflicts = Vec::new();
 
-        // Simple satisfaction check (in practice, this would use CSP solving)
+        let all: Vec<&Constraint> = self.constraints.iter().collect();
+        let mut budget = self.search_budget;
+        let (witness, unsat_core, satisfiability) = match self.find_witness(&all, &mut budget) {
+            Search::Found(witness) => (Some(witness), Vec::new(), Satisfiability::Satisfiable),
+            Search::Exhausted => (None, self.minimal_unsat_core(&mut budget), Satisfiability::Unsatisfiable),
+            Search::OverBudget => (None, Vec::new(), Satisfiability::Unknown),
+        };
+        // Without a witness each constraint is checked as written
+        self.assignments = witness.clone().unwrap_or_default();
+
         for constraint in &self.constraints {
             if self.is_constraint_satisfied(constraint) {
                 satisfied.push(constraint.id.clone());
@@ -260,27 +301,166 @@ impl ConstraintSolver {
             unsatisfied,
             conflicts,
             satisfaction_score,
+            witness,
+            unsat_core,
+            satisfiability,
+        }
+    }
+
+    /// Search for an assignment satisfying every given constraint
+    ///
+    /// Names listed as values of a known domain are constants. Any other
+    /// name in a membership, equality or inequality constraint is a
+    /// variable ranging over the intersection of the sets it must belong to,
+    /// or, when no membership restricts it, over every constant plus one
+    /// fresh value (`_v0`, `_v1`, ...) per variable. Other constraint kinds
+    /// do not restrict the assignment. Each partial assignment tried uses up
+    /// one unit of `budget`.
+    fn find_witness(&self, constraints: &[&Constraint], budget: &mut usize) -> Search {
+        let constants: BTreeSet<&String> = self.domains.values().flatten().collect();
+        // `None` marks a variable no membership constraint restricts
+        let mut candidates: BTreeMap<String, Option<Vec<String>>> = BTreeMap::new();
+        let mut checks = Vec::new();
+
+        for constraint in constraints {
+            match &constraint.constraint_type {
+                ConstraintType::Membership { variable, set } => {
+                    let Some(domain) = self.domains.get(set) else {
+                        return Search::Exhausted;
+                    };
+                    if constants.contains(variable) {
+                        if !domain.contains(variable) {
+                            return Search::Exhausted;
+                        }
+                        continue;
+                    }
+                    let entry = candidates.entry(variable.clone()).or_insert(None);
+                    *entry = Some(match entry.take() {
+                        Some(existing) => existing.into_iter().filter(|value| domain.contains(value)).collect(),
+                        None => domain.clone(),
+                    });
+                }
+                ConstraintType::Equality { left, right } | ConstraintType::Inequality { left, right } => {
+                    for name in [left, right] {
+                        if !constants.contains(name) {
+                            candidates.entry(name.clone()).or_insert(None);
+                        }
+                    }
+                    checks.push(*constraint);
+                }
+                _ => {}
+            }
+        }
+
+        let universe: Vec<String> = constants
+            .iter()
+            .map(|value| value.to_string())
+            .chain((0..candidates.len()).map(|index| format!("_v{}", index)))
+            .collect();
+        let variables: Vec<(String, Vec<String>)> = candidates
+            .into_iter()
+            .map(|(name, domain)| (name, domain.unwrap_or_else(|| universe.clone())))
+            .collect();
+
+        let mut assignment = HashMap::new();
+        match Self::assign(&variables, 0, &checks, &mut assignment, budget) {
+            Some(true) => Search::Found(assignment),
+            Some(false) => Search::Exhausted,
+            None => Search::OverBudget,
+        }
+    }
+
+    /// Backtracking search assigning `variables[index..]`, or `None` once
+    /// `budget` runs out
+    fn assign(
+        variables: &[(String, Vec<String>)],
+        index: usize,
+        checks: &[&Constraint],
+        assignment: &mut HashMap<String, String>,
+        budget: &mut usize,
+    ) -> Option<bool> {
+        let Some((name, domain)) = variables.get(index) else {
+            return Some(true);
+        };
+        for value in domain {
+            *budget = budget.checked_sub(1)?;
+            assignment.insert(name.clone(), value.clone());
+            let consistent = checks.iter().all(|check| Self::consistent(check, variables, assignment));
+            if consistent && Self::assign(variables, index + 1, checks, assignment, budget)? {
+                return Some(true);
+            }
+        }
+        assignment.remove(name);
+        Some(false)
+    }
+
+    /// Whether an equality or inequality can still hold under a partial assignment
+    fn consistent(
+        constraint: &Constraint,
+        variables: &[(String, Vec<String>)],
+        assignment: &HashMap<String, String>,
+    ) -> bool {
+        let value = |name: &String| match assignment.get(name) {
+            Some(value) => Some(value.clone()),
+            None if variables.iter().any(|(variable, _)| variable == name) => None,
+            None => Some(name.clone()),
+        };
+        match &constraint.constraint_type {
+            ConstraintType::Equality { left, right } => match (value(left), value(right)) {
+                (Some(left), Some(right)) => left == right,
+                _ => true,
+            },
+            ConstraintType::Inequality { left, right } => match (value(left), value(right)) {
+                (Some(left), Some(right)) => left != right,
+                _ => true,
+            },
+            _ => true,
         }
     }
 
+    /// Shrink the unsatisfiable constraint set to a minimal one by dropping
+    /// every constraint whose removal leaves the rest unsatisfiable
+    ///
+    /// A constraint whose removal cannot be settled within `budget` is
+    /// kept, so the core still cannot hold together but may not be minimal.
+    fn minimal_unsat_core(&self, budget: &mut usize) -> Vec<String> {
+        let mut core: Vec<&Constraint> = self.constraints.iter().collect();
+        let mut index = 0;
+        while index < core.len() {
+            let mut without = core.clone();
+            without.remove(index);
+            if let Search::Exhausted = self.find_witness(&without, budget) {
+                core = without;
+            } else {
+                index += 1;
+            }
+        }
+        core.iter().map(|constraint| constraint.id.clone()).collect()
+    }
+
+    /// Value assigned to a name, or the name itself for constants
+    fn value_of<'a>(&'a self, name: &'a String) -> &'a String {
+        self.assignments.get(name).unwrap_or(name)
+    }
+
     /// Check if a constraint is satisfied
     fn is_constraint_satisfied(&self, constraint: &Constraint) -> bool {
         match &constraint.constraint_type {
             ConstraintType::Membership { variable, set } => {
                 // Check if variable is in the domain of the set
                 if let Some(domain) = self.domains.get(set) {
-                    domain.contains(variable)
+                    domain.contains(self.value_of(variable))
                 } else {
                     false
                 }
             }
             ConstraintType::Equality { left, right } => {
                 // Check if both variables have the same assignment
-                self.assignments.get(left) == self.assignments.get(right)
+                self.value_of(left) == self.value_of(right)
             }
             ConstraintType::Inequality { left, right } => {
                 // Check if variables have different assignments
-                self.assignments.get(left) != self.assignments.get(right)
+                self.value_of(left) != self.value_of(right)
             }
             _ => true, // Simplified - assume other constraints are satisfied
         }
@@ -353,6 +533,7 @@ mod tests {
                 raw_definitions: Vec::new(),
                 span: Some(Span::new(1, 1, 3, 1)),
             })],
+            used_symbols: Default::default(),
             span: Some(Span::new(1, 1, 10, 1)),
         }
     }
@@ -420,6 +601,105 @@ mod tests {
         }
     }
 
+    fn constraint(id: &str, constraint_type: ConstraintType) -> Constraint {
+        Constraint {
+            id: id.to_string(),
+            variables: Vec::new(),
+            constraint_type,
+            priority: ConstraintPriority::High,
+            span: Span::new(1, 1, 1, 10),
+        }
+    }
+
+    fn solver_with(constraints: Vec<Constraint>) -> ConstraintSolver {
+        let mut solver = ConstraintSolver::new();
+        solver.domains.insert("State".to_string(), vec!["Idle".to_string(), "Busy".to_string()]);
+        solver.constraints = constraints;
+        solver
+    }
+
+    fn member(id: &str, variable: &str) -> Constraint {
+        constraint(id, ConstraintType::Membership { variable: variable.to_string(), set: "State".to_string() })
+    }
+
+    fn equal(id: &str, left: &str, right: &str) -> Constraint {
+        constraint(id, ConstraintType::Equality { left: left.to_string(), right: right.to_string() })
+    }
+
+    fn differ(id: &str, left: &str, right: &str) -> Constraint {
+        constraint(id, ConstraintType::Inequality { left: left.to_string(), right: right.to_string() })
+    }
+
+    #[test]
+    fn test_satisfiable_constraints_produce_witness() {
+        let mut solver = solver_with(vec![
+            member("x_state", "x"),
+            member("y_state", "y"),
+            differ("x_ne_y", "x", "y"),
+            equal("x_eq_idle", "x", "Idle"),
+            equal("z_eq_y", "z", "y"),
+        ]);
+
+        let result = solver.analyze_satisfaction();
+
+        let witness = result.witness.expect("constraints are satisfiable");
+        assert_eq!(witness["x"], "Idle");
+        assert_eq!(witness["y"], "Busy");
+        assert_eq!(witness["z"], "Busy");
+        assert!(result.unsat_core.is_empty());
+        assert_eq!(result.satisfiability, Satisfiability::Satisfiable);
+        assert!(result.unsatisfied.is_empty(), "{:?}", result.unsatisfied);
+        assert_eq!(result.satisfaction_score, 1.0);
+    }
+
+    #[test]
+    fn test_unsatisfiable_constraints_produce_minimal_core() {
+        // Three pairwise-distinct members of a two-value set cannot exist;
+        // the equality to an unrelated variable plays no part in that
+        let mut solver = solver_with(vec![
+            member("a_state", "a"),
+            member("b_state", "b"),
+            member("c_state", "c"),
+            equal("d_eq_a", "d", "a"),
+            differ("a_ne_b", "a", "b"),
+            differ("b_ne_c", "b", "c"),
+            differ("a_ne_c", "a", "c"),
+        ]);
+
+        let result = solver.analyze_satisfaction();
+
+        assert!(result.witness.is_none());
+        assert_eq!(
+            result.unsat_core,
+            vec!["a_state", "b_state", "c_state", "a_ne_b", "b_ne_c", "a_ne_c"]
+        );
+        assert!(!result.unsatisfied.is_empty());
+    }
+
+    #[test]
+    fn test_search_over_budget_is_unknown() {
+        // Refuting three distinct members of a two-value set takes more
+        // than five partial assignments
+        let constraints = vec![
+            member("a_state", "a"),
+            member("b_state", "b"),
+            member("c_state", "c"),
+            differ("a_ne_b", "a", "b"),
+            differ("b_ne_c", "b", "c"),
+            differ("a_ne_c", "a", "c"),
+        ];
+        let mut solver = solver_with(constraints.clone());
+        solver.search_budget = 5;
+
+        let result = solver.analyze_satisfaction();
+        assert_eq!(result.satisfiability, Satisfiability::Unknown);
+        assert!(result.witness.is_none());
+        assert!(result.unsat_core.is_empty());
+
+        let result = solver_with(constraints).analyze_satisfaction();
+        assert_eq!(result.satisfiability, Satisfiability::Unsatisfiable);
+    }
+
     #[test]
     fn test_constraint_priorities() {
         assert!(ConstraintPriority::Critical > ConstraintPriority::High);
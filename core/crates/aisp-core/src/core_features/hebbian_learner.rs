@@ -8,6 +8,10 @@ use crate::{
     pocket_architecture::{ContentHash, InteractionResult},
 };
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Affinities smaller in magnitude than this are dropped by decay
+const NEGLIGIBLE_AFFINITY: f64 = 1e-6;
 
 /// F₇: Enhanced Hebbian Learning System
 /// Implements: ⊕→+1;⊖→-10 for adaptive affinity learning
@@ -22,6 +26,10 @@ pub struct EnhancedHebbianLearner {
     decay_factor: f64,
     /// Affinity matrix for pocket pairs
     affinity_matrix: HashMap<(ContentHash, ContentHash), f64>,
+    /// Time over which unused affinities halve, when decay is automatic
+    decay_half_life: Option<Duration>,
+    /// When time-based decay was last applied
+    last_decay: Instant,
     /// Learning performance statistics
     learning_stats: HebbianStatistics,
     /// Confidence tracking for predictions
@@ -47,6 +55,8 @@ impl EnhancedHebbianLearner {
             learning_rate: 0.1,
             decay_factor: 0.99,
             affinity_matrix: HashMap::new(),
+            decay_half_life: None,
+            last_decay: Instant::now(),
             learning_stats: HebbianStatistics::default(),
             confidence_tracker: ConfidenceTracker::new(),
         }
@@ -65,6 +75,8 @@ impl EnhancedHebbianLearner {
             learning_rate: learning_rate.max(0.0).min(1.0),
             decay_factor: decay_factor.max(0.0).min(1.0),
             affinity_matrix: HashMap::new(),
+            decay_half_life: None,
+            last_decay: Instant::now(),
             learning_stats: HebbianStatistics::default(),
             confidence_tracker: ConfidenceTracker::new(),
         }
@@ -77,6 +89,7 @@ impl EnhancedHebbianLearner {
         pocket_b: ContentHash,
         interaction_result: InteractionResult,
     ) -> AispResult<f64> {
+        self.apply_time_decay();
         let key = (pocket_a, pocket_b);
         let current_affinity = self.affinity_matrix.get(&key).copied().unwrap_or(0.0);
         
@@ -111,9 +124,12 @@ impl EnhancedHebbianLearner {
         Ok(bounded_affinity)
     }
 
-    /// Get current affinity between two pockets
+    /// Get current affinity between two pockets, including the time-based
+    /// decay accrued since it was last applied
     pub fn get_affinity(&self, pocket_a: ContentHash, pocket_b: ContentHash) -> f64 {
-        self.affinity_matrix.get(&(pocket_a, pocket_b)).copied().unwrap_or(0.0)
+        let affinity = self.affinity_matrix.get(&(pocket_a, pocket_b)).copied().unwrap_or(0.0)
+            * self.pending_decay_factor();
+        if affinity.abs() < NEGLIGIBLE_AFFINITY { 0.0 } else { affinity }
     }
 
     /// Predict interaction success based on affinity
@@ -245,13 +261,52 @@ impl EnhancedHebbianLearner {
 
     /// Apply forgetting mechanism to old affinities
     pub fn apply_forgetting(&mut self, forgetting_rate: f64) {
-        let forgetting_factor = 1.0 - forgetting_rate.max(0.0).min(1.0);
-        
-        for affinity in self.affinity_matrix.values_mut() {
-            *affinity *= forgetting_factor;
+        self.decay(1.0 - forgetting_rate.max(0.0).min(1.0));
+    }
+
+    /// Multiply every affinity by `factor` (clamped to [0, 1]), dropping
+    /// those that become negligible so stale associations fade out
+    pub fn decay(&mut self, factor: f64) {
+        let factor = factor.max(0.0).min(1.0);
+        self.affinity_matrix.retain(|_, affinity| {
+            *affinity *= factor;
+            affinity.abs() >= NEGLIGIBLE_AFFINITY
+        });
+    }
+
+    /// Decay affinities automatically, halving them every `half_life`;
+    /// `None` turns automatic decay off
+    pub fn set_decay_half_life(&mut self, half_life: Option<Duration>) {
+        self.decay_half_life = half_life.filter(|half_life| !half_life.is_zero());
+        self.last_decay = Instant::now();
+    }
+
+    /// Decay affinities as if `elapsed` had passed since time-based decay
+    /// was last applied; does nothing when automatic decay is off
+    pub fn decay_for(&mut self, elapsed: Duration) {
+        if self.decay_half_life.is_some() {
+            self.last_decay = Instant::now();
+            self.decay(self.decay_factor_for(elapsed));
         }
     }
 
+    /// Apply the time-based decay accrued since it was last applied
+    fn apply_time_decay(&mut self) {
+        self.decay_for(self.last_decay.elapsed());
+    }
+
+    /// Factor the time-based decay accrued so far scales affinities by
+    fn pending_decay_factor(&self) -> f64 {
+        self.decay_factor_for(self.last_decay.elapsed())
+    }
+
+    /// Factor affinities shrink by over `elapsed` under the half-life
+    fn decay_factor_for(&self, elapsed: Duration) -> f64 {
+        self.decay_half_life.map_or(1.0, |half_life| {
+            0.5f64.powf(elapsed.as_secs_f64() / half_life.as_secs_f64())
+        })
+    }
+
     /// Update convergence metrics
     fn update_convergence_metrics(&mut self) {
         let average_affinity = if self.affinity_matrix.is_empty() {
@@ -636,6 +691,67 @@ mod tests {
         assert!(forgotten_affinity > 0.0); // Should still be positive
     }
 
+    #[test]
+    fn test_decay_reduces_high_affinity() {
+        let mut learner = EnhancedHebbianLearner::new();
+        let pocket_a = [123; 32];
+        let pocket_b = [45; 32];
+        for _ in 0..20 {
+            learner.update_affinity(pocket_a, pocket_b, InteractionResult::Success).unwrap();
+        }
+        let learned = learner.get_affinity(pocket_a, pocket_b);
+        assert!(learned > 1.0);
+
+        learner.decay(0.5);
+        assert!((learner.get_affinity(pocket_a, pocket_b) - learned * 0.5).abs() < 1e-9);
+        assert!(learner.get_affinity(pocket_a, pocket_b) > 0.0);
+    }
+
+    #[test]
+    fn test_decay_to_zero_empties_affinity_table() {
+        let mut learner = EnhancedHebbianLearner::new();
+        learner.update_affinity([1; 32], [2; 32], InteractionResult::Success).unwrap();
+        learner.update_affinity([3; 32], [4; 32], InteractionResult::Failure).unwrap();
+        assert_eq!(learner.get_affinity_summary().total_pairs, 2);
+
+        learner.decay(0.0);
+
+        assert_eq!(learner.get_affinity_summary().total_pairs, 0);
+        assert!(learner.get_top_affinities(10).is_empty());
+        assert_eq!(learner.get_affinity([1; 32], [2; 32]), 0.0);
+    }
+
+    #[test]
+    fn test_time_based_decay_follows_half_life() {
+        let mut learner = EnhancedHebbianLearner::new();
+        let pocket_a = [5; 32];
+        let pocket_b = [6; 32];
+        learner.update_affinity(pocket_a, pocket_b, InteractionResult::Success).unwrap();
+        let learned = learner.get_affinity(pocket_a, pocket_b);
+
+        // Without a half-life, time does not decay affinities
+        learner.decay_for(Duration::from_secs(3600));
+        assert_eq!(learner.get_affinity(pocket_a, pocket_b), learned);
+
+        learner.set_decay_half_life(Some(Duration::from_secs(3600)));
+        learner.decay_for(Duration::from_secs(7200));
+        assert!((learner.get_affinity(pocket_a, pocket_b) - learned * 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_reads_include_accrued_time_decay() {
+        let mut learner = EnhancedHebbianLearner::new();
+        let pocket_a = [7; 32];
+        let pocket_b = [8; 32];
+        learner.update_affinity(pocket_a, pocket_b, InteractionResult::Success).unwrap();
+        let learned = learner.get_affinity(pocket_a, pocket_b);
+
+        learner.set_decay_half_life(Some(Duration::from_millis(1)));
+        std::thread::sleep(Duration::from_millis(20));
+        // No update ran since, yet the read sees the affinity decayed
+        assert!(learner.get_affinity(pocket_a, pocket_b) < learned / 2.0);
+    }
+
     #[test]
     fn test_reset() {
         let mut learner = EnhancedHebbianLearner::new();
@@ -166,6 +166,26 @@ impl CoreFeaturesManager {
         Ok(new_affinity)
     }
 
+    /// Decay all learned affinities by `factor`, so stale associations
+    /// fade (F₇: Hebbian Learning)
+    pub fn decay_affinities(&mut self, factor: f64) -> AispResult<()> {
+        if !self.feature_config.enable_hebbian_learning {
+            return Err(AispError::validation_error(
+                "Hebbian learning is disabled".to_string()
+            ));
+        }
+
+        self.hebbian_learner.decay(factor);
+        self.update_feature_usage("affinity_decay");
+        Ok(())
+    }
+
+    /// Decay affinities automatically with the given half-life, or stop
+    /// doing so with `None`
+    pub fn set_affinity_half_life(&mut self, half_life: Option<Duration>) {
+        self.hebbian_learner.set_decay_half_life(half_life);
+    }
+
     /// Get predicted interaction success
     pub fn predict_interaction_success(
         &self,
@@ -695,6 +715,26 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_affinity_decay_through_manager() {
+        let mut manager = CoreFeaturesManager::new();
+        let content_a = [123; 32];
+        let content_b = [45; 32];
+        let learned = manager.update_interaction_affinity(content_a, content_b, InteractionResult::Success).unwrap();
+
+        manager.decay_affinities(0.5).unwrap();
+        assert!(manager.hebbian_learner.get_affinity(content_a, content_b) < learned);
+
+        manager.decay_affinities(0.0).unwrap();
+        assert_eq!(manager.get_comprehensive_statistics().affinity_summary.total_pairs, 0);
+
+        manager.update_configuration(CoreFeatureConfiguration {
+            enable_hebbian_learning: false,
+            ..CoreFeatureConfiguration::default()
+        });
+        assert!(manager.decay_affinities(0.5).is_err());
+    }
+
     #[test]
     fn test_content_analysis_result() {
         let content_a = ContentHash(111);
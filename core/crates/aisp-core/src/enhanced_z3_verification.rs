@@ -47,6 +47,7 @@ mod integration_tests {
                 protocol: None,
             },
             blocks: vec![],
+            used_symbols: Default::default(),
             span: Some(Span {
                 start: 0,
                 end: 0,
@@ -136,6 +136,26 @@ pub struct AispWarning {
     pub message: String,
     pub line: Option<usize>,
     pub severity: WarningSeverity,
+    /// How to resolve the diagnostic, when the validator knows
+    pub remediation: Option<Remediation>,
+}
+
+/// A suggested resolution for a diagnostic
+#[derive(Debug, Clone, PartialEq)]
+pub struct Remediation {
+    /// What the author should do, in words
+    pub description: String,
+    /// An edit that makes the change, when one can be computed
+    pub patch: Option<Patch>,
+}
+
+/// A concrete edit to a document's source
+#[derive(Debug, Clone, PartialEq)]
+pub enum Patch {
+    /// Insert `text` as the named block, at its place in canonical order
+    InsertBlock { block: String, text: String },
+    /// Replace `found` at a 1-based line and character column with `glyph`
+    ReplaceGlyph { line: usize, column: usize, found: String, glyph: String },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -151,6 +171,7 @@ impl AispWarning {
             message: message.into(),
             line: None,
             severity: WarningSeverity::Info,
+            remediation: None,
         }
     }
 
@@ -159,6 +180,7 @@ impl AispWarning {
             message: message.into(),
             line: None,
             severity: WarningSeverity::Warning,
+            remediation: None,
         }
     }
 
@@ -167,6 +189,7 @@ impl AispWarning {
             message: message.into(),
             line: None,
             severity: WarningSeverity::Error,
+            remediation: None,
         }
     }
 
@@ -174,6 +197,17 @@ impl AispWarning {
         self.line = Some(line);
         self
     }
+
+    pub fn with_remediation(mut self, remediation: Remediation) -> Self {
+        self.remediation = Some(remediation);
+        self
+    }
+}
+
+impl std::fmt::Display for Remediation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.description)
+    }
 }
 
 impl std::fmt::Display for AispWarning {
@@ -546,6 +546,7 @@ mod tests {
                 protocol: None,
             },
             blocks: vec![],
+            used_symbols: Default::default(),
             span: Some(Span::new(0, 10, 1, 1)),
         }
     }
@@ -5,7 +5,8 @@
 
 use crate::ast::canonical::*;
 use crate::error::*;
-use crate::property_types::{FormulaStructure, PropertyFormula, Term, AtomicFormula, PropertyComplexity, PropertyType};
+use crate::model_checker::TemporalFormula;
+use crate::property_types::{ArithmeticOp, FormulaStructure, PropertyFormula, Term, AtomicFormula, PropertyComplexity, PropertyType};
 use crate::property_types::Quantifier as PropertyQuantifier;
 use std::collections::HashSet;
 
@@ -15,6 +16,9 @@ pub struct FormulaConverter;
 impl FormulaConverter {
     /// Convert logical expression to formula structure
     pub fn convert_logical_expression_structure(expr: &LogicalExpression) -> AispResult<FormulaStructure> {
+        if let Some(comparison) = Self::convert_comparison(expr) {
+            return Ok(comparison);
+        }
         match expr {
             LogicalExpression::Temporal { op, operand } => {
                 let operand_structure = Self::convert_logical_expression_structure(operand)?;
@@ -75,6 +79,9 @@ impl FormulaConverter {
                     type_signature: None,
                 }))
             }
+            LogicalExpression::Unary { op: UnaryOperator::Not, operand } => {
+                Ok(FormulaStructure::Negation(Box::new(Self::convert_logical_expression_structure(operand)?)))
+            }
             _ => {
                 // Fallback for other expression types
                 Ok(FormulaStructure::Atomic(AtomicFormula {
@@ -86,6 +93,140 @@ impl FormulaConverter {
         }
     }
 
+    /// Convert a comparison between arithmetic terms, such as `x+y≤20`,
+    /// to `=` and `≤` over real-valued terms
+    ///
+    /// Strict and reversed comparisons are expressed through negation and
+    /// swapped operands. `None` when `expr` is not such a comparison.
+    fn convert_comparison(expr: &LogicalExpression) -> Option<FormulaStructure> {
+        let LogicalExpression::Binary { op, left, right } = expr else {
+            return None;
+        };
+        if !matches!(
+            op,
+            BinaryOperator::Equals
+                | BinaryOperator::NotEquals
+                | BinaryOperator::LessThan
+                | BinaryOperator::LessEqual
+                | BinaryOperator::GreaterThan
+                | BinaryOperator::GreaterEqual
+        ) {
+            return None;
+        }
+        let left = Self::convert_arithmetic_term(left)?;
+        let right = Self::convert_arithmetic_term(right)?;
+        let not = |structure| FormulaStructure::Negation(Box::new(structure));
+        Some(match op {
+            BinaryOperator::Equals => FormulaStructure::ArithmeticEqual(left, right),
+            BinaryOperator::NotEquals => not(FormulaStructure::ArithmeticEqual(left, right)),
+            BinaryOperator::LessEqual => FormulaStructure::ArithmeticLessEqual(left, right),
+            BinaryOperator::GreaterEqual => FormulaStructure::ArithmeticLessEqual(right, left),
+            BinaryOperator::LessThan => not(FormulaStructure::ArithmeticLessEqual(right, left)),
+            _ => not(FormulaStructure::ArithmeticLessEqual(left, right)),
+        })
+    }
+
+    /// Arithmetic term of a comparison operand: a variable, a number, or
+    /// raw text built from them with `+`, `-`, `*` and parentheses
+    fn convert_arithmetic_term(expr: &LogicalExpression) -> Option<Term> {
+        match expr {
+            LogicalExpression::Variable(name) => Some(Term::Variable(name.clone(), None)),
+            LogicalExpression::Constant(ConstantValue::Number(value)) => Some(Self::real_term(*value)),
+            LogicalExpression::Raw(text) => {
+                let tokens = Self::tokenize_arithmetic(text)?;
+                let mut position = 0;
+                let term = Self::parse_sum(&tokens, &mut position)?;
+                (position == tokens.len()).then_some(term)
+            }
+            _ => None,
+        }
+    }
+
+    /// Real constant, with negative values written as a subtraction since
+    /// SMT-LIB has no negative literals
+    fn real_term(value: f64) -> Term {
+        let literal = |value: f64| {
+            let text = value.to_string();
+            Term::Constant(if text.contains('.') { text } else { format!("{}.0", text) }, "ℝ".to_string())
+        };
+        if value < 0.0 {
+            Term::Arithmetic(ArithmeticOp::Subtract, Box::new(literal(0.0)), Box::new(literal(-value)))
+        } else {
+            literal(value)
+        }
+    }
+
+    fn tokenize_arithmetic(text: &str) -> Option<Vec<String>> {
+        let mut tokens = Vec::new();
+        let mut chars = text.chars().peekable();
+        while let Some(&ch) = chars.peek() {
+            if ch.is_whitespace() {
+                chars.next();
+            } else if ch.is_ascii_alphanumeric() || ch == '_' || ch == '.' {
+                let mut token = String::new();
+                while let Some(&next) = chars.peek().filter(|ch| ch.is_ascii_alphanumeric() || **ch == '_' || **ch == '.') {
+                    token.push(next);
+                    chars.next();
+                }
+                tokens.push(token);
+            } else if matches!(ch, '+' | '-' | '*' | '(' | ')') {
+                tokens.push(ch.to_string());
+                chars.next();
+            } else {
+                return None;
+            }
+        }
+        Some(tokens)
+    }
+
+    /// sum := product (('+' | '-') product)*
+    fn parse_sum(tokens: &[String], position: &mut usize) -> Option<Term> {
+        let mut sum = Self::parse_product(tokens, position)?;
+        while let Some(op) = tokens.get(*position).and_then(|token| match token.as_str() {
+            "+" => Some(ArithmeticOp::Add),
+            "-" => Some(ArithmeticOp::Subtract),
+            _ => None,
+        }) {
+            *position += 1;
+            sum = Term::Arithmetic(op, Box::new(sum), Box::new(Self::parse_product(tokens, position)?));
+        }
+        Some(sum)
+    }
+
+    /// product := atom ('*' atom)*
+    fn parse_product(tokens: &[String], position: &mut usize) -> Option<Term> {
+        let mut product = Self::parse_atom(tokens, position)?;
+        while tokens.get(*position).map(String::as_str) == Some("*") {
+            *position += 1;
+            product = Term::Arithmetic(ArithmeticOp::Multiply, Box::new(product), Box::new(Self::parse_atom(tokens, position)?));
+        }
+        Some(product)
+    }
+
+    /// atom := '-' atom | '(' sum ')' | number | identifier
+    fn parse_atom(tokens: &[String], position: &mut usize) -> Option<Term> {
+        let token = tokens.get(*position)?;
+        *position += 1;
+        match token.as_str() {
+            "-" => {
+                let operand = Self::parse_atom(tokens, position)?;
+                Some(Term::Arithmetic(ArithmeticOp::Subtract, Box::new(Self::real_term(0.0)), Box::new(operand)))
+            }
+            "(" => {
+                let inner = Self::parse_sum(tokens, position)?;
+                (tokens.get(*position).map(String::as_str) == Some(")")).then(|| {
+                    *position += 1;
+                    inner
+                })
+            }
+            ")" | "+" | "*" => None,
+            number if number.starts_with(|ch: char| ch.is_ascii_digit() || ch == '.') => {
+                number.parse::<f64>().ok().map(Self::real_term)
+            }
+            name => Some(Term::Variable(name.to_string(), None)),
+        }
+    }
+
     /// Convert rule expression to mathematical formula
     pub fn convert_rule_to_formula(expr: &LogicalExpression) -> AispResult<PropertyFormula> {
         let structure = Self::convert_logical_expression_structure(expr)?;
@@ -106,6 +247,105 @@ impl FormulaConverter {
         Self::convert_rule_to_formula(expr)
     }
 
+    /// Convert a temporal logic formula, CTL operators included, to a
+    /// property formula
+    ///
+    /// Each CTL operator becomes a path quantifier over the matching LTL
+    /// structure, so `AG p` is `PathForall(TemporalAlways(p))`. Next-state
+    /// operators have no property encoding and are rejected.
+    pub fn convert_ctl_formula(formula: &TemporalFormula) -> AispResult<PropertyFormula> {
+        let mut predicates = HashSet::new();
+        let structure = Self::convert_temporal_formula_structure(formula, &mut predicates)?;
+
+        Ok(PropertyFormula {
+            structure,
+            quantifiers: Vec::new(),
+            free_variables: HashSet::new(),
+            predicates,
+            functions: HashSet::new(),
+            constants: HashSet::new(),
+        })
+    }
+
+    /// Convert temporal formula to formula structure, collecting its atomic
+    /// propositions
+    fn convert_temporal_formula_structure(
+        formula: &TemporalFormula,
+        predicates: &mut HashSet<String>,
+    ) -> AispResult<FormulaStructure> {
+        let mut convert = |inner: &TemporalFormula| Self::convert_temporal_formula_structure(inner, predicates).map(Box::new);
+
+        Ok(match formula {
+            TemporalFormula::Atomic(name) => {
+                predicates.insert(name.clone());
+                FormulaStructure::Atomic(AtomicFormula {
+                    predicate: name.clone(),
+                    terms: vec![],
+                    type_signature: None,
+                })
+            }
+            TemporalFormula::Not(inner) => FormulaStructure::Negation(convert(inner)?),
+            TemporalFormula::And(left, right) => FormulaStructure::Conjunction(vec![*convert(left)?, *convert(right)?]),
+            TemporalFormula::Or(left, right) => FormulaStructure::Disjunction(vec![*convert(left)?, *convert(right)?]),
+            TemporalFormula::Implies(left, right) => FormulaStructure::Implication(convert(left)?, convert(right)?),
+            TemporalFormula::Always(inner) => FormulaStructure::TemporalAlways(convert(inner)?),
+            TemporalFormula::Eventually(inner) => FormulaStructure::TemporalEventually(convert(inner)?),
+            TemporalFormula::Until(left, right) => FormulaStructure::TemporalUntil(convert(left)?, convert(right)?),
+            // φ R ψ ≡ ¬(¬φ U ¬ψ)
+            TemporalFormula::Release(left, right) => FormulaStructure::Negation(Box::new(FormulaStructure::TemporalUntil(
+                Box::new(FormulaStructure::Negation(convert(left)?)),
+                Box::new(FormulaStructure::Negation(convert(right)?)),
+            ))),
+            // φ W ψ ≡ (φ U ψ) ∨ □φ
+            TemporalFormula::WeakUntil(left, right) => {
+                let left = convert(left)?;
+                FormulaStructure::Disjunction(vec![
+                    FormulaStructure::TemporalUntil(left.clone(), convert(right)?),
+                    FormulaStructure::TemporalAlways(left),
+                ])
+            }
+            // φ M ψ ≡ ψ U (φ ∧ ψ)
+            TemporalFormula::StrongRelease(left, right) => {
+                let right = convert(right)?;
+                FormulaStructure::TemporalUntil(
+                    right.clone(),
+                    Box::new(FormulaStructure::Conjunction(vec![*convert(left)?, *right])),
+                )
+            }
+            TemporalFormula::ForallAlways(inner) => {
+                FormulaStructure::PathForall(Box::new(FormulaStructure::TemporalAlways(convert(inner)?)))
+            }
+            TemporalFormula::ExistsAlways(inner) => {
+                FormulaStructure::PathExists(Box::new(FormulaStructure::TemporalAlways(convert(inner)?)))
+            }
+            TemporalFormula::ForallEventually(inner) => {
+                FormulaStructure::PathForall(Box::new(FormulaStructure::TemporalEventually(convert(inner)?)))
+            }
+            TemporalFormula::ExistsEventually(inner) => {
+                FormulaStructure::PathExists(Box::new(FormulaStructure::TemporalEventually(convert(inner)?)))
+            }
+            TemporalFormula::ForallUntil(left, right) => {
+                FormulaStructure::PathForall(Box::new(FormulaStructure::TemporalUntil(convert(left)?, convert(right)?)))
+            }
+            TemporalFormula::ExistsUntil(left, right) => {
+                FormulaStructure::PathExists(Box::new(FormulaStructure::TemporalUntil(convert(left)?, convert(right)?)))
+            }
+            TemporalFormula::Next(_) | TemporalFormula::ForallNext(_) | TemporalFormula::ExistsNext(_) => {
+                return Err(AispError::validation_error(
+                    "Next-state operators have no property encoding".to_string(),
+                ));
+            }
+            TemporalFormula::Yesterday(_)
+            | TemporalFormula::Previously(_)
+            | TemporalFormula::Historically(_)
+            | TemporalFormula::Since(..) => {
+                return Err(AispError::validation_error(
+                    "Past-time operators have no property encoding".to_string(),
+                ));
+            }
+        })
+    }
+
     /// Extract quantifiers from logical expression
     pub fn extract_quantifiers(_expr: &LogicalExpression) -> Vec<PropertyQuantifier> {
         // Simplified implementation - would implement full quantifier extraction
@@ -268,6 +508,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ctl_formula_conversion() {
+        use crate::model_checker::TemporalFormula as Ctl;
+
+        // AG(p → EF q)
+        let formula = Ctl::ForallAlways(Box::new(Ctl::Implies(
+            Box::new(Ctl::Atomic("p".to_string())),
+            Box::new(Ctl::ExistsEventually(Box::new(Ctl::Atomic("q".to_string())))),
+        )));
+
+        let property = FormulaConverter::convert_ctl_formula(&formula).unwrap();
+        let atom = |name: &str| FormulaStructure::Atomic(AtomicFormula {
+            predicate: name.to_string(),
+            terms: vec![],
+            type_signature: None,
+        });
+        assert_eq!(
+            property.structure,
+            FormulaStructure::PathForall(Box::new(FormulaStructure::TemporalAlways(Box::new(
+                FormulaStructure::Implication(
+                    Box::new(atom("p")),
+                    Box::new(FormulaStructure::PathExists(Box::new(FormulaStructure::TemporalEventually(
+                        Box::new(atom("q"))
+                    )))),
+                )
+            ))))
+        );
+        assert_eq!(property.predicates, ["p", "q"].iter().map(|p| p.to_string()).collect());
+
+        // Branching-time properties are left to the model checker
+        let mut smt = crate::smt_formula_converter::SMTFormulaConverter::new();
+        let error = smt.convert_formula_to_smt(&property).unwrap_err();
+        assert!(error.to_string().contains("Path quantifiers have no SMT encoding"), "{}", error);
+    }
+
+    #[test]
+    fn test_ctl_until_and_next_conversion() {
+        use crate::model_checker::TemporalFormula as Ctl;
+
+        let until = Ctl::ForallUntil(Box::new(Ctl::Atomic("p".to_string())), Box::new(Ctl::Atomic("q".to_string())));
+        match FormulaConverter::convert_ctl_formula(&until).unwrap().structure {
+            FormulaStructure::PathForall(inner) => assert!(matches!(*inner, FormulaStructure::TemporalUntil(_, _))),
+            other => panic!("Expected universal path quantifier, got {:?}", other),
+        }
+
+        let next = Ctl::ExistsNext(Box::new(Ctl::Atomic("p".to_string())));
+        assert!(FormulaConverter::convert_ctl_formula(&next).is_err());
+    }
+
     #[test]
     fn test_rule_property_classification() {
         let temporal_expr = LogicalExpression::Temporal {
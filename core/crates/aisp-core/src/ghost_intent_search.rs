@@ -268,6 +268,28 @@ pub enum OptimalityType {
     Approximate { error_bound: f64 },
 }
 
+/// Partially written specification used as an auto-completion query
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialSpec {
+    /// Intent captured so far (ψ_have of the spec under construction)
+    pub intent: IntentVector,
+    /// Classification tags every candidate pocket must carry
+    pub required_tags: Vec<String>,
+}
+
+/// Candidate intent inferred for a partial specification
+#[derive(Debug, Clone)]
+pub struct RankedIntent {
+    /// Pocket the intent was inferred from
+    pub pocket_id: ContentHash,
+    /// Intent carried by the pocket
+    pub intent: IntentVector,
+    /// What the candidate adds to the partial spec: ψ_candidate ⊖ ψ_partial
+    pub ghost_intent: GhostIntent,
+    /// Alignment with the partial spec [0,1]
+    pub score: f64,
+}
+
 /// Search performance metrics
 #[derive(Debug, Clone, Default)]
 pub struct SearchMetrics {
@@ -300,6 +322,54 @@ impl GhostIntentSearchEngine {
         engine
     }
 
+    /// Register a pocket as a candidate for search and queries
+    pub fn register_pocket(&mut self, pocket: Pocket) {
+        self.pocket_repository.insert(pocket);
+    }
+
+    /// Query the top-k intents that complete a partial specification
+    ///
+    /// Candidates are scored by alignment with the partial intent and then
+    /// selected with the same DPP initialization used for search beams,
+    /// using a quality-weighted kernel L_ij = q_i·S_ij·q_j so near-duplicate
+    /// pockets do not crowd out distinct completions. Results are sorted by
+    /// score, highest first, and never exceed `k`.
+    pub fn query(&self, partial: &PartialSpec, k: usize) -> Vec<RankedIntent> {
+        if k == 0 {
+            return vec![];
+        }
+
+        let candidates: Vec<ContentHash> = match self.pocket_repository.find_candidate_pockets(&partial.intent) {
+            Ok(candidates) => candidates
+                .into_iter()
+                .filter(|id| self.pocket_repository.has_tags(id, &partial.required_tags))
+                .collect(),
+            Err(_) => return vec![],
+        };
+
+        let mut scored = Vec::with_capacity(candidates.len());
+        for pocket_id in candidates {
+            let Ok(intent) = self.extract_intent_from_pocket(pocket_id) else { continue };
+            let Ok(ghost_intent) = self.calculate_ghost_intent(&intent, &partial.intent) else { continue };
+            let score = self.calculate_intent_confidence(&partial.intent, &intent);
+            scored.push(RankedIntent { pocket_id, intent, ghost_intent, score });
+        }
+
+        let kernel_matrix = self.build_quality_kernel_matrix(&scored);
+        let dpp = DeterminantalPointProcess::new(kernel_matrix);
+        let selected = match dpp.select_diverse_subset(k) {
+            Ok(selected) => selected,
+            Err(_) => return vec![],
+        };
+
+        let mut ranked: Vec<RankedIntent> = selected
+            .into_iter()
+            .filter_map(|idx| scored.get(idx).cloned())
+            .collect();
+        ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+        ranked
+    }
+
     /// Execute ghost intent search with formal guarantees
     /// Implements: Run:ψ→𝔹eam; Run≜λψ_*.argmax_{b∈search(‖*init(⊞(ψ_*)),0)}μ_f(b)
     pub fn execute_search(&mut self, target_intent: IntentVector) -> AispResult<GhostSearchResult> {
@@ -757,8 +827,25 @@ impl GhostIntentSearchEngine {
         Ok(vec![vec![1.0]])
     }
 
-    fn extract_intent_from_pocket(&self, _pocket_id: ContentHash) -> AispResult<IntentVector> {
-        Ok(IntentVector::new(64))
+    /// Quality-weighted DPP kernel: L_ij = q_i·|cos(ψ_i, ψ_j)|·q_j
+    fn build_quality_kernel_matrix(&self, candidates: &[RankedIntent]) -> Vec<Vec<f64>> {
+        candidates.iter().enumerate().map(|(i, a)| {
+            candidates.iter().enumerate().map(|(j, b)| {
+                let similarity = if i == j {
+                    1.0
+                } else {
+                    self.calculate_intent_confidence(&a.intent, &b.intent)
+                };
+                a.score * similarity * b.score
+            }).collect()
+        }).collect()
+    }
+
+    fn extract_intent_from_pocket(&self, pocket_id: ContentHash) -> AispResult<IntentVector> {
+        Ok(self.pocket_repository
+            .get(&pocket_id)
+            .map(|pocket| IntentVector::from_signal(&pocket.header.signal_vector))
+            .unwrap_or_else(|| IntentVector::new(64)))
     }
 
     fn calculate_beam_fitness(&self, _pocket_id: ContentHash, _target: &IntentVector) -> AispResult<f64> {
@@ -798,6 +885,22 @@ impl PocketRepository {
         }
     }
 
+    pub fn insert(&mut self, pocket: Pocket) {
+        self.pockets.insert(pocket.header.id, pocket);
+    }
+
+    pub fn get(&self, pocket_id: &ContentHash) -> Option<&Pocket> {
+        self.pockets.get(pocket_id)
+    }
+
+    /// Whether a pocket carries every one of the given classification tags
+    pub fn has_tags(&self, pocket_id: &ContentHash, tags: &[String]) -> bool {
+        match self.pockets.get(pocket_id) {
+            Some(pocket) => tags.iter().all(|tag| pocket.membrane.classification_tags.contains(tag)),
+            None => tags.is_empty(),
+        }
+    }
+
     pub fn find_candidate_pockets(&self, _target: &IntentVector) -> AispResult<Vec<ContentHash>> {
         Ok(self.pockets.keys().cloned().collect())
     }
@@ -846,9 +949,67 @@ impl DeterminantalPointProcess {
         }
     }
 
+    /// Greedy MAP approximation of argmax_{|S|=K} det(Ker(S))
+    ///
+    /// Adds the point that maximizes the subset determinant at each step and
+    /// stops early once every remaining point would make the kernel singular,
+    /// so the result can be smaller than `k`.
     pub fn select_diverse_subset(&self, k: usize) -> AispResult<Vec<usize>> {
-        // Simplified DPP implementation - would use proper eigendecomposition
-        Ok((0..k.min(self.kernel_matrix.len())).collect())
+        let n = self.kernel_matrix.len();
+        if self.kernel_matrix.iter().any(|row| row.len() != n) {
+            return Err(AispError::validation_error("DPP kernel matrix must be square".to_string()));
+        }
+
+        let mut selected: Vec<usize> = Vec::new();
+        while selected.len() < k.min(n) {
+            let mut best: Option<(usize, f64)> = None;
+            for candidate in (0..n).filter(|i| !selected.contains(i)) {
+                let mut subset = selected.clone();
+                subset.push(candidate);
+                let det = self.subset_determinant(&subset);
+                if best.map_or(true, |(_, best_det)| det > best_det) {
+                    best = Some((candidate, det));
+                }
+            }
+
+            match best {
+                Some((candidate, det)) if det > 1e-12 => selected.push(candidate),
+                _ => break,
+            }
+        }
+
+        Ok(selected)
+    }
+
+    /// Determinant of the kernel restricted to `subset` (Gaussian elimination)
+    fn subset_determinant(&self, subset: &[usize]) -> f64 {
+        let mut m: Vec<Vec<f64>> = subset.iter()
+            .map(|&i| subset.iter().map(|&j| self.kernel_matrix[i][j]).collect())
+            .collect();
+        let size = m.len();
+        let mut det = 1.0;
+
+        for col in 0..size {
+            let pivot = (col..size)
+                .max_by(|&a, &b| m[a][col].abs().total_cmp(&m[b][col].abs()))
+                .unwrap_or(col);
+            if m[pivot][col].abs() < f64::EPSILON {
+                return 0.0;
+            }
+            if pivot != col {
+                m.swap(pivot, col);
+                det = -det;
+            }
+            det *= m[col][col];
+            for row in (col + 1)..size {
+                let factor = m[row][col] / m[col][col];
+                for c in col..size {
+                    m[row][c] -= factor * m[col][c];
+                }
+            }
+        }
+
+        det
     }
 }
 
@@ -861,6 +1022,33 @@ impl IntentVector {
             dimension_count: dimension * 3,
         }
     }
+
+    /// Map a pocket signal vector onto intent dimensions
+    /// (V_H → semantic, V_L → functional, V_S → temporal)
+    pub fn from_signal(signal: &SignalVector) -> Self {
+        let widen = |values: &[f32]| values.iter().map(|&v| v as f64).collect::<Vec<f64>>();
+        Self {
+            semantic_dimensions: widen(&signal.semantic),
+            functional_dimensions: widen(&signal.structural),
+            temporal_dimensions: widen(&signal.safety),
+            dimension_count: signal.total_dimension(),
+        }
+    }
+}
+
+impl PartialSpec {
+    pub fn new(intent: IntentVector) -> Self {
+        Self {
+            intent,
+            required_tags: Vec::new(),
+        }
+    }
+
+    /// Require candidates to carry a classification tag
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.required_tags.push(tag.into());
+        self
+    }
 }
 
 impl GhostIntent {
@@ -898,6 +1086,7 @@ impl Default for GhostIntentSearchEngine {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::pocket_architecture::PocketArchitectureVerifier;
 
     #[test]
     fn test_ghost_intent_calculation() {
@@ -954,4 +1143,66 @@ mod tests {
         assert_eq!(proof.proof_method, TerminationMethod::MonotonicGhostDecreasing);
         assert_eq!(proof.proof_validity, TruthValue::True);
     }
-}
\ No newline at end of file
+
+    fn pocket_with_signal(verifier: &mut PocketArchitectureVerifier, name: &str, semantic: &[f32], tags: &[&str]) -> Pocket {
+        let mut signal = SignalVector::new();
+        signal.semantic[..semantic.len()].copy_from_slice(semantic);
+        let mut pocket = verifier.create_pocket(name.to_string(), signal).unwrap();
+        pocket.membrane.classification_tags = tags.iter().map(|t| t.to_string()).collect();
+        pocket
+    }
+
+    fn engine_with_candidates() -> GhostIntentSearchEngine {
+        let mut verifier = PocketArchitectureVerifier::new();
+        let mut engine = GhostIntentSearchEngine::new();
+        engine.register_pocket(pocket_with_signal(&mut verifier, "𝔸 exact", &[1.0, 0.0, 0.0], &["rules"]));
+        engine.register_pocket(pocket_with_signal(&mut verifier, "𝔸 close", &[0.9, 0.3, 0.0], &["rules"]));
+        engine.register_pocket(pocket_with_signal(&mut verifier, "𝔸 partial", &[0.5, 0.5, 0.5], &["types"]));
+        engine.register_pocket(pocket_with_signal(&mut verifier, "𝔸 distant", &[0.1, 0.2, 1.0], &["types"]));
+        engine
+    }
+
+    fn partial_spec() -> PartialSpec {
+        let mut signal = SignalVector::new();
+        signal.semantic[0] = 1.0;
+        PartialSpec::new(IntentVector::from_signal(&signal))
+    }
+
+    #[test]
+    fn test_query_returns_at_most_k_sorted_by_score() {
+        let engine = engine_with_candidates();
+
+        for k in 0..=6 {
+            let results = engine.query(&partial_spec(), k);
+            assert!(results.len() <= k);
+            assert!(results.windows(2).all(|w| w[0].score >= w[1].score));
+        }
+
+        let top = engine.query(&partial_spec(), 1);
+        assert_eq!(top.len(), 1);
+        assert!((top[0].score - 1.0).abs() < 1e-9);
+        assert!(top[0].ghost_intent.magnitude < 1e-9);
+    }
+
+    #[test]
+    fn test_query_filters_by_required_tags() {
+        let engine = engine_with_candidates();
+
+        let results = engine.query(&partial_spec().with_tag("types"), 4);
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.score < 1.0));
+        assert!(engine.query(&partial_spec().with_tag("missing"), 4).is_empty());
+    }
+
+    #[test]
+    fn test_dpp_skips_duplicate_points() {
+        let dpp = DeterminantalPointProcess::new(vec![
+            vec![1.0, 1.0, 0.0],
+            vec![1.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+        ]);
+
+        let selected = dpp.select_diverse_subset(3).unwrap();
+        assert_eq!(selected, vec![0, 2]);
+    }
+}
@@ -699,6 +699,7 @@ mod tests {
                 protocol: Some("aisp".to_string()),
             },
             blocks: vec![],
+            used_symbols: Default::default(),
             span: Some(Span::new(0, 0, 1, 1)),
         }
     }
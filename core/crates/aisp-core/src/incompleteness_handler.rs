@@ -197,6 +197,15 @@ impl IncompletenessHandler {
         }
     }
     
+    /// Classify a statement the prover could not settle
+    ///
+    /// Returns a structural reason (Gödel sentence, self-reference, halting,
+    /// Russell, Rice) when the statement matches a known-hard form, and
+    /// `None` when the failure is better explained by exhausted resources.
+    pub fn classify_undecided(&self, statement: &str) -> Option<UndecidabilityReason> {
+        self.detect_undecidability(statement)
+    }
+
     /// Detect patterns that indicate undecidability
     fn detect_undecidability(&self, statement: &str) -> Option<UndecidabilityReason> {
         let normalized = statement.to_lowercase();
@@ -14,7 +14,7 @@ use crate::{
         DiscoveredInvariant, InvariantType, InvariantEvidence, EvidenceType,
         InvariantDiscoveryConfig, DiscoveryStats,
     },
-    property_types::{SourceLocation},
+    property_types::{FormulaStructure, SourceLocation, Term},
 };
 
 /// Core invariant analysis engine
@@ -47,6 +47,11 @@ impl InvariantAnalyzer {
         if self.config.enable_patterns {
             self.analyze_function_invariants(document)?;
         }
+        if self.config.enable_numerical_analysis {
+            let seeds = evidence_seeds(document);
+            self.analyze_evidence_invariants(&seeds)?;
+            self.cross_check_evidence(&seeds);
+        }
         
         // Filter by confidence threshold
         let mut result: Vec<DiscoveredInvariant> = self.discovered_invariants.iter()
@@ -95,6 +100,70 @@ impl InvariantAnalyzer {
         Ok(())
     }
     
+    /// Propose a range invariant for each declared evidence metric, bounded
+    /// by its value rounded outward from zero
+    fn analyze_evidence_invariants(&mut self, seeds: &[(String, f64)]) -> AispResult<()> {
+        for (name, value) in seeds {
+            let min = value.floor().min(0.0) as i64;
+            let max = value.ceil().max(0.0) as i64;
+            let formula = crate::invariant_formulas::create_range_formula(name, min, max)?;
+            let mut invariant = DiscoveredInvariant::new(
+                format!("evidence_bound_{}", name),
+                format!("Evidence bound for {}", name),
+                formula,
+                InvariantType::NumericalInvariant,
+                0.85,
+            );
+
+            let location = SourceLocation {
+                block_type: "Evidence".to_string(),
+                line: None,
+                column: None,
+                source_text: Some(format!("{}≜{}", name, value)),
+            };
+            invariant.add_evidence(InvariantEvidence::new(
+                EvidenceType::EmpiricalObservation,
+                0.85,
+                format!("Evidence block declares {}≜{}", name, value),
+                location.clone(),
+            ));
+            invariant.add_source(location);
+
+            self.discovered_invariants.push(invariant);
+        }
+        Ok(())
+    }
+
+    /// Halve the confidence of invariants bounding a metric, as their
+    /// quantified variable or as the subject of a comparison, whose declared
+    /// evidence value falls outside those bounds
+    fn cross_check_evidence(&mut self, seeds: &[(String, f64)]) {
+        for invariant in &mut self.discovered_invariants {
+            let FormulaStructure::Universal(_, body) = &invariant.formula.structure else {
+                continue;
+            };
+            let Some((name, value)) = seeds.iter().find(|(name, value)| {
+                let (lower, upper) = numeric_bounds(body, name);
+                lower.is_some_and(|lower| *value < lower) || upper.is_some_and(|upper| *value > upper)
+            }) else {
+                continue;
+            };
+            invariant.confidence *= 0.5;
+            invariant.add_evidence(InvariantEvidence::new(
+                EvidenceType::EmpiricalObservation,
+                0.0,
+                format!("Contradicted by declared evidence {}≜{}", name, value),
+                SourceLocation {
+                    block_type: "Evidence".to_string(),
+                    line: None,
+                    column: None,
+                    source_text: Some(format!("{}≜{}", name, value)),
+                },
+            ));
+            self.discovery_stats.evidence_conflicts += 1;
+        }
+    }
+
     /// Analyze a single type definition
     fn analyze_type_definition(&mut self, type_name: &str, type_def: &TypeExpression) -> AispResult<()> {
         match type_def {
@@ -228,6 +297,52 @@ impl InvariantAnalyzer {
     }
 }
 
+/// Numeric facts declared in evidence blocks: δ, φ, then custom metrics by name
+fn evidence_seeds(document: &AispDocument) -> Vec<(String, f64)> {
+    let mut seeds = Vec::new();
+    for block in &document.blocks {
+        if let AispBlock::Evidence(evidence) = block {
+            seeds.extend(evidence.delta.map(|delta| ("δ".to_string(), delta)));
+            seeds.extend(evidence.phi.map(|phi| ("φ".to_string(), phi as f64)));
+            let mut metrics: Vec<(String, f64)> = evidence.metrics.iter().map(|(name, value)| (name.clone(), *value)).collect();
+            metrics.sort_by(|a, b| a.0.cmp(&b.0));
+            seeds.extend(metrics);
+        }
+    }
+    seeds
+}
+
+/// Lower and upper constant bounds on the variable named `subject`, from
+/// `≥`/`≤` comparisons in an atom or conjunction
+fn numeric_bounds(body: &FormulaStructure, subject: &str) -> (Option<f64>, Option<f64>) {
+    let atoms = match body {
+        FormulaStructure::Conjunction(parts) => parts.iter().collect(),
+        other => vec![other],
+    };
+
+    let (mut lower, mut upper) = (None, None);
+    for atom in atoms {
+        let FormulaStructure::Atomic(atom) = atom else {
+            continue;
+        };
+        let [Term::Variable(variable, _), Term::Constant(constant, _)] = atom.terms.as_slice() else {
+            continue;
+        };
+        if variable != subject {
+            continue;
+        }
+        let Ok(bound) = constant.parse::<f64>() else {
+            continue;
+        };
+        match atom.predicate.as_str() {
+            "≥" => lower = Some(bound),
+            "≤" => upper = Some(bound),
+            _ => {}
+        }
+    }
+    (lower, upper)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -342,4 +457,104 @@ mod tests {
             .any(|inv| inv.id.contains("generic_type"));
         assert!(!has_generic_invariant);
     }
-}
\ No newline at end of file
+
+    fn document_with_evidence(phi: u64, metrics: &[(&str, f64)]) -> AispDocument {
+        let mut document = crate::ast::canonical::create_document("TestDoc", "5.1", "2026-01-26");
+        let natural = TypeDefinition {
+            name: "Natural".to_string(),
+            type_expr: TypeExpression::Basic(BasicType::Natural),
+            span: None,
+        };
+        document.add_block(AispBlock::Types(TypesBlock {
+            definitions: HashMap::from([("Natural".to_string(), natural)]),
+            raw_definitions: vec!["Natural≜ℕ".to_string()],
+            span: None,
+        }));
+        document.add_block(AispBlock::Evidence(crate::ast::canonical::EvidenceBlock {
+            delta: None,
+            phi: Some(phi),
+            tau: None,
+            metrics: metrics.iter().map(|(name, value)| (name.to_string(), *value)).collect(),
+            units: Default::default(),
+            raw_evidence: Vec::new(),
+            span: None,
+        }));
+        document
+    }
+
+    #[test]
+    fn test_evidence_metric_bounds_discovered_invariant() {
+        let mut analyzer = InvariantAnalyzer::new(InvariantDiscoveryConfig::default());
+        let result = analyzer.analyze(&document_with_evidence(100, &[])).unwrap();
+
+        let bound = result.iter().find(|inv| inv.id == "evidence_bound_φ").unwrap();
+        assert_eq!(bound.invariant_type, InvariantType::NumericalInvariant);
+        assert_eq!(numeric_bounds(match &bound.formula.structure {
+            FormulaStructure::Universal(_, body) => body,
+            _ => panic!("expected a quantified bound"),
+        }, "x"), (Some(0.0), Some(100.0)));
+        assert!(bound.has_evidence_type(&EvidenceType::EmpiricalObservation));
+
+        let mut config = InvariantDiscoveryConfig::default();
+        config.enable_numerical_analysis = false;
+        let result = InvariantAnalyzer::new(config).analyze(&document_with_evidence(100, &[])).unwrap();
+        assert!(result.iter().all(|inv| !inv.id.starts_with("evidence_bound")));
+    }
+
+    /// `∀variable:ℕ. subject ≤ max`
+    fn upper_bound_invariant(variable: &str, subject: &str, max: i64) -> DiscoveredInvariant {
+        let mut formula = crate::invariant_formulas::create_non_negativity_formula("ℕ").unwrap();
+        formula.structure = FormulaStructure::Universal(
+            crate::property_types::Quantifier {
+                variable: variable.to_string(),
+                variable_type: Some("ℕ".to_string()),
+                domain: None,
+            },
+            Box::new(FormulaStructure::Atomic(crate::property_types::AtomicFormula {
+                predicate: "≤".to_string(),
+                terms: vec![
+                    Term::Variable(subject.to_string(), Some("ℕ".to_string())),
+                    Term::Constant(max.to_string(), "ℕ".to_string()),
+                ],
+                type_signature: None,
+            })),
+        );
+        DiscoveredInvariant::new(format!("{}_bound", subject), "Bound".to_string(), formula, InvariantType::NumericalInvariant, 0.8)
+    }
+
+    #[test]
+    fn test_contradicting_evidence_lowers_confidence() {
+        let seeds = vec![("latency".to_string(), 80.0)];
+        let mut analyzer = InvariantAnalyzer::new(InvariantDiscoveryConfig::default());
+        analyzer.discovered_invariants = vec![
+            // Bounds the metric as the quantified variable
+            upper_bound_invariant("latency", "latency", 50),
+            // Bounds the metric as the subject of a comparison
+            upper_bound_invariant("x", "latency", 60),
+            // Bounds another quantity
+            upper_bound_invariant("x", "x", 50),
+            // Consistent with the declared value
+            upper_bound_invariant("latency", "latency", 100),
+        ];
+        analyzer.cross_check_evidence(&seeds);
+
+        let confidences: Vec<f64> = analyzer.discovered_invariants.iter().map(|inv| inv.confidence).collect();
+        assert_eq!(confidences, vec![0.4, 0.4, 0.8, 0.8]);
+        assert_eq!(analyzer.get_stats().evidence_conflicts, 2);
+        assert!(analyzer.discovered_invariants[0]
+            .evidence
+            .iter()
+            .any(|e| e.description == "Contradicted by declared evidence latency≜80"));
+    }
+
+    #[test]
+    fn test_metric_named_after_a_type_does_not_contradict_it() {
+        // `Natural≜-3` is a metric, not a value of the type `Natural`
+        let document = document_with_evidence(100, &[("Natural", -3.0)]);
+        let mut analyzer = InvariantAnalyzer::new(InvariantDiscoveryConfig::default());
+        let result = analyzer.analyze(&document).unwrap();
+        let natural = result.iter().find(|inv| inv.id == "nat_nonneg_Natural").unwrap();
+        assert_eq!(natural.confidence, 0.95);
+        assert_eq!(analyzer.get_stats().evidence_conflicts, 0);
+    }
+}
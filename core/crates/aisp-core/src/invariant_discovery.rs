@@ -134,6 +134,7 @@ mod tests {
                     span: Some(Span::new(0, 0, 1, 1)),
                 }),
             ],
+            used_symbols: Default::default(),
             span: Some(Span::new(0, 0, 1, 1)),
         };
 
@@ -180,6 +181,7 @@ mod tests {
                     span: Some(Span::new(0, 0, 1, 1)),
                 }),
             ],
+            used_symbols: Default::default(),
             span: Some(Span::new(0, 0, 1, 1)),
         };
 
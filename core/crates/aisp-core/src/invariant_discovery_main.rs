@@ -173,6 +173,7 @@ mod tests {
                     span: Some(Span::new(1, 1, 3, 1)),
                 }),
             ],
+            used_symbols: Default::default(),
             span: Some(Span::new(1, 1, 10, 1)),
         }
     }
@@ -314,6 +315,7 @@ mod tests {
             },
             metadata: DocumentMetadata { domain: None, protocol: None },
             blocks: vec![],
+            used_symbols: Default::default(),
             span: None,
         };
         
@@ -4,7 +4,7 @@
 //! the invariant discovery system.
 
 use crate::{
-    property_types::{PropertyFormula, SourceLocation},
+    property_types::{FormulaStructure, PropertyFormula, SourceLocation},
 };
 use std::time::Duration;
 
@@ -52,6 +52,81 @@ pub enum InvariantType {
     StructuralInvariant,
 }
 
+/// Safety/liveness classification of a property
+///
+/// A safety property says nothing bad ever happens and is verified by
+/// checking that its negation is unsatisfiable; a liveness property says
+/// something good eventually happens and is checked for satisfiability.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemporalClass {
+    /// Violated by a finite prefix, such as `□P`
+    Safety,
+    /// Never violated by a finite prefix, such as `◇P` or `□(P → ◇Q)`
+    Liveness,
+}
+
+impl TemporalClass {
+    /// Classify `structure` by its temporal operators, or `None` when it
+    /// has none
+    ///
+    /// An eventuality (`◇`, or `U` which promises its right operand) makes
+    /// a property liveness. Negation swaps `□` and `◇`, so `¬◇P` is safety
+    /// and `¬□P` is liveness.
+    pub fn of(structure: &FormulaStructure) -> Option<Self> {
+        let (mut temporal, mut liveness) = (false, false);
+        Self::scan(structure, true, &mut temporal, &mut liveness);
+        temporal.then_some(if liveness { Self::Liveness } else { Self::Safety })
+    }
+
+    /// Record temporal operators under `structure`, read with the given
+    /// polarity
+    fn scan(structure: &FormulaStructure, positive: bool, temporal: &mut bool, liveness: &mut bool) {
+        match structure {
+            FormulaStructure::TemporalAlways(inner) => {
+                *temporal = true;
+                *liveness |= !positive;
+                Self::scan(inner, positive, temporal, liveness);
+            }
+            FormulaStructure::TemporalEventually(inner) => {
+                *temporal = true;
+                *liveness |= positive;
+                Self::scan(inner, positive, temporal, liveness);
+            }
+            FormulaStructure::TemporalUntil(left, right) => {
+                *temporal = true;
+                *liveness |= positive;
+                Self::scan(left, positive, temporal, liveness);
+                Self::scan(right, positive, temporal, liveness);
+            }
+            FormulaStructure::Negation(inner) => Self::scan(inner, !positive, temporal, liveness),
+            FormulaStructure::Implication(antecedent, consequent) => {
+                Self::scan(antecedent, !positive, temporal, liveness);
+                Self::scan(consequent, positive, temporal, liveness);
+            }
+            FormulaStructure::Biconditional(left, right) => {
+                for side in [left, right] {
+                    Self::scan(side, positive, temporal, liveness);
+                    Self::scan(side, !positive, temporal, liveness);
+                }
+            }
+            FormulaStructure::Conjunction(parts) | FormulaStructure::Disjunction(parts) => {
+                for part in parts {
+                    Self::scan(part, positive, temporal, liveness);
+                }
+            }
+            FormulaStructure::Universal(_, inner)
+            | FormulaStructure::Existential(_, inner)
+            | FormulaStructure::PathForall(inner)
+            | FormulaStructure::PathExists(inner) => Self::scan(inner, positive, temporal, liveness),
+            FormulaStructure::Atomic(_)
+            | FormulaStructure::ArithmeticEqual(..)
+            | FormulaStructure::ArithmeticLessEqual(..)
+            | FormulaStructure::SetMembership(..)
+            | FormulaStructure::FunctionApplication(..) => {}
+        }
+    }
+}
+
 /// Evidence supporting an invariant
 #[derive(Debug, Clone, PartialEq)]
 pub struct InvariantEvidence {
@@ -131,6 +206,8 @@ pub struct DiscoveryStats {
     pub verified_correct: usize,
     /// Number of disproven invariants
     pub disproven: usize,
+    /// Number of invariants contradicted by a declared evidence metric
+    pub evidence_conflicts: usize,
     /// Time spent on verification
     pub verification_time: Duration,
 }
@@ -143,6 +220,7 @@ impl Default for DiscoveryStats {
             functional_invariants: 0,
             verified_correct: 0,
             disproven: 0,
+            evidence_conflicts: 0,
             verification_time: Duration::new(0, 0),
         }
     }
@@ -191,6 +269,12 @@ impl DiscoveredInvariant {
         self.evidence.iter()
             .any(|e| &e.evidence_type == evidence_type)
     }
+
+    /// Whether this invariant is a safety or a liveness property; one
+    /// without temporal operators holds in every state, which is safety
+    pub fn temporal_class(&self) -> TemporalClass {
+        TemporalClass::of(&self.formula.structure).unwrap_or(TemporalClass::Safety)
+    }
 }
 
 impl InvariantEvidence {
@@ -339,6 +423,28 @@ mod tests {
         assert!(!invariant.has_evidence_type(&EvidenceType::MathematicalAnalysis));
     }
 
+    #[test]
+    fn test_temporal_class_follows_temporal_structure() {
+        let p = || Box::new(create_test_formula().structure);
+        let invariant = |structure: FormulaStructure| {
+            let formula = PropertyFormula { structure, ..create_test_formula() };
+            DiscoveredInvariant::new("inv".to_string(), "Invariant".to_string(), formula, InvariantType::TemporalInvariant, 0.9)
+        };
+
+        assert_eq!(invariant(FormulaStructure::TemporalAlways(p())).temporal_class(), TemporalClass::Safety);
+        assert_eq!(invariant(FormulaStructure::TemporalEventually(p())).temporal_class(), TemporalClass::Liveness);
+        assert_eq!(invariant(*p()).temporal_class(), TemporalClass::Safety);
+
+        let response = FormulaStructure::TemporalAlways(Box::new(FormulaStructure::Implication(
+            p(),
+            Box::new(FormulaStructure::TemporalEventually(p())),
+        )));
+        assert_eq!(TemporalClass::of(&response), Some(TemporalClass::Liveness));
+        let never = FormulaStructure::Negation(Box::new(FormulaStructure::TemporalEventually(p())));
+        assert_eq!(TemporalClass::of(&never), Some(TemporalClass::Safety));
+        assert_eq!(TemporalClass::of(&p()), None);
+    }
+
     #[test]
     fn test_invariant_discovery_config_default() {
         let config = InvariantDiscoveryConfig::default();
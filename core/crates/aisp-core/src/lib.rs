@@ -140,12 +140,22 @@ pub const AISP_VERSION: &str = "5.1";
 /// Maximum supported document size (1MB)
 pub const MAX_DOCUMENT_SIZE: usize = 1024 * 1024;
 
+/// Symbol density below which a parsed document is flagged as likely prose
+pub const MIN_SYMBOL_DENSITY: f64 = 0.02;
+
 /// Quality tier thresholds
 pub mod tier_thresholds {
     pub const PLATINUM: f64 = 0.75;
     pub const GOLD: f64 = 0.60;
     pub const SILVER: f64 = 0.40;
     pub const BRONZE: f64 = 0.20;
+
+    /// Ambiguity must stay strictly below this bound for acceptance
+    pub const AMBIGUITY: f64 = 0.02;
+
+    /// Overall verification confidence must stay strictly above this bound
+    /// for a document to be valid
+    pub const CONFIDENCE: f64 = 0.80;
 }
 
 #[cfg(test)]
@@ -3,10 +3,21 @@
 //! This module provides safe evaluation of mathematical expressions with proper
 //! handling of division by zero, NaN propagation, and formal error conditions.
 
+use num_rational::Ratio;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt;
 use thiserror::Error;
 
+/// Exact rational number used for validity-deciding threshold comparisons
+pub type Rational = Ratio<i64>;
+
+/// Decimal denominator used when lifting `f64` measurements into rationals
+///
+/// Nine decimal places is far finer than any AISP threshold while still
+/// absorbing the last-bit representation error of `f64` arithmetic.
+pub const RATIONAL_PRECISION: i64 = 1_000_000_000;
+
 /// Mathematical value with formal error handling
 #[derive(Debug, Clone)]
 pub enum MathValue {
@@ -249,6 +260,64 @@ impl MathEvaluator {
     }
 }
 
+/// Lift a finite `f64` into an exact rational, snapped to `RATIONAL_PRECISION`
+///
+/// Returns `None` for NaN and infinities, which have no rational counterpart.
+pub fn rational_from_f64(value: f64) -> Option<Rational> {
+    if !value.is_finite() {
+        return None;
+    }
+
+    let scaled = (value * RATIONAL_PRECISION as f64).round();
+    if scaled.abs() >= i64::MAX as f64 {
+        return None;
+    }
+
+    Some(Rational::new(scaled as i64, RATIONAL_PRECISION))
+}
+
+/// Exact comparison of two measurements after lifting both into rationals
+pub fn compare_exact(value: f64, threshold: f64) -> Option<Ordering> {
+    Some(rational_from_f64(value)?.cmp(&rational_from_f64(threshold)?))
+}
+
+/// Exact `value ≥ threshold`; non-finite values never meet a threshold
+pub fn meets_threshold(value: f64, threshold: f64) -> bool {
+    matches!(compare_exact(value, threshold), Some(Ordering::Greater | Ordering::Equal))
+}
+
+/// Exact `value < threshold`; non-finite values are never below a threshold
+pub fn below_threshold(value: f64, threshold: f64) -> bool {
+    matches!(compare_exact(value, threshold), Some(Ordering::Less))
+}
+
+impl MathEvaluator {
+    /// Build an exact ratio from integer counts (e.g. symbols / tokens)
+    pub fn exact_ratio(&self, numerator: i64, denominator: i64) -> Result<Rational, MathError> {
+        if denominator == 0 {
+            return Err(if numerator == 0 {
+                MathError::IndeterminateForm { expression: "0/0".to_string() }
+            } else {
+                MathError::DivisionByZero { expression: format!("{}/0", numerator) }
+            });
+        }
+
+        Ok(Rational::new(numerator, denominator))
+    }
+
+    /// Calculate ambiguity exactly as `1 - parse_unique/parse_total`
+    pub fn calculate_ambiguity_exact(&self, parse_unique: i64, parse_total: i64) -> Result<Rational, MathError> {
+        if parse_unique > parse_total {
+            return Err(MathError::DomainError {
+                operation: "ambiguity_calculation".to_string(),
+                message: "parse_unique cannot exceed parse_total".to_string(),
+            });
+        }
+
+        Ok(Rational::from_integer(1) - self.exact_ratio(parse_unique, parse_total)?)
+    }
+}
+
 impl PartialEq for MathValue {
     fn eq(&self, other: &Self) -> bool {
         const EPSILON: f64 = 1e-10;
@@ -336,6 +405,82 @@ mod tests {
         assert!(result.is_err());
     }
     
+    #[test]
+    fn test_rational_snaps_float_representation_error() {
+        // 1.15 - 0.4 evaluates to 0.7499999999999999 in f64
+        let delta = 1.15 - 0.4;
+        assert!(delta < 0.75);
+        assert_eq!(rational_from_f64(delta), Some(Rational::new(3, 4)));
+        assert!(meets_threshold(delta, 0.75));
+
+        // 0.1 + 0.1 + 0.2 + 0.2 + 0.15 evaluates to 0.7500000000000001
+        let delta = 0.1 + 0.1 + 0.2 + 0.2 + 0.15;
+        assert!(delta > 0.75);
+        assert_eq!(compare_exact(delta, 0.75), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn test_ambiguity_boundary_is_exact() {
+        // 0.3 - 0.28 evaluates to 0.019999999999999962 in f64
+        let ambiguity = 0.3 - 0.28;
+        assert!(ambiguity < 0.02);
+        assert!(!below_threshold(ambiguity, 0.02));
+        assert!(below_threshold(0.019, 0.02));
+
+        let evaluator = MathEvaluator::new();
+        let exact = evaluator.calculate_ambiguity_exact(98, 100).unwrap();
+        assert_eq!(exact, Rational::new(1, 50));
+        assert_eq!(exact, rational_from_f64(0.02).unwrap());
+    }
+
+    #[test]
+    fn test_exact_ratio_errors() {
+        let evaluator = MathEvaluator::new();
+
+        assert_eq!(evaluator.exact_ratio(3, 4).unwrap(), Rational::new(3, 4));
+        assert!(matches!(evaluator.exact_ratio(0, 0), Err(MathError::IndeterminateForm { .. })));
+        assert!(matches!(evaluator.exact_ratio(1, 0), Err(MathError::DivisionByZero { .. })));
+        assert!(evaluator.calculate_ambiguity_exact(5, 4).is_err());
+    }
+
+    #[test]
+    fn test_tier_boundaries_classify_deterministically() {
+        use crate::semantic::QualityTier;
+
+        assert_eq!(QualityTier::from_delta(0.75), QualityTier::Platinum);
+        assert_eq!(QualityTier::from_delta(1.15 - 0.4), QualityTier::Platinum);
+        assert_eq!(QualityTier::from_delta(0.1 * 6.0), QualityTier::Gold);
+        assert_eq!(QualityTier::from_delta(0.7 - 0.3), QualityTier::Silver);
+        assert_eq!(QualityTier::from_delta(0.2), QualityTier::Bronze);
+        assert_eq!(QualityTier::from_delta(0.19), QualityTier::Reject);
+        assert_eq!(QualityTier::from_delta(f64::NAN), QualityTier::Reject);
+    }
+
+    #[test]
+    fn test_verification_result_uses_shared_thresholds() {
+        use crate::semantic::{DeepVerificationResult, QualityTier};
+
+        let mut result = DeepVerificationResult::default();
+        result.semantic_score = 1.15 - 0.4;
+        result.overall_confidence = 0.7 + 0.1;
+        assert_eq!(result.tier(), QualityTier::Platinum);
+        assert_eq!(result.tier(), QualityTier::from_delta(result.delta()));
+        assert!(!result.valid());
+
+        result.semantic_score = 0.19;
+        result.overall_confidence = 0.800000001;
+        assert_eq!(result.tier(), QualityTier::Reject);
+        assert!(result.valid());
+    }
+
+    #[test]
+    fn test_non_finite_never_meets_threshold() {
+        assert_eq!(rational_from_f64(f64::NAN), None);
+        assert!(!meets_threshold(f64::NAN, 0.75));
+        assert!(!meets_threshold(f64::INFINITY, 0.75));
+        assert!(!below_threshold(f64::NEG_INFINITY, 0.02));
+    }
+
     #[test]
     fn test_circular_dependency_detection() {
         let mut evaluator = MathEvaluator::new();
@@ -8,6 +8,7 @@ use crate::error::*;
 use crate::property_extractor::*;
 use crate::temporal_new::*;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::time::{Duration, Instant};
 
@@ -150,6 +151,90 @@ pub enum TemporalFormula {
     ForallNext(Box<TemporalFormula>),        // AX
     ExistsUntil(Box<TemporalFormula>, Box<TemporalFormula>),  // EU
     ForallUntil(Box<TemporalFormula>, Box<TemporalFormula>),  // AU
+    // Past-time operators, evaluated over finite traces
+    /// Yesterday - Yφ, false at the first state
+    Yesterday(Box<TemporalFormula>),
+    /// Previously (once) - Pφ, at or before the current state
+    Previously(Box<TemporalFormula>),
+    /// Historically - Hφ, in every state up to the current one
+    Historically(Box<TemporalFormula>),
+    /// Since - φ S ψ, ψ held earlier and φ ever after
+    Since(Box<TemporalFormula>, Box<TemporalFormula>),
+}
+
+impl TemporalFormula {
+    /// Whether the formula holds or fails in a single state, with no LTL
+    /// path operator outside a CTL path quantifier
+    pub fn is_state_formula(&self) -> bool {
+        match self {
+            TemporalFormula::Atomic(_) => true,
+            TemporalFormula::Not(inner)
+            | TemporalFormula::ExistsAlways(inner)
+            | TemporalFormula::ForallAlways(inner)
+            | TemporalFormula::ExistsEventually(inner)
+            | TemporalFormula::ForallEventually(inner)
+            | TemporalFormula::ExistsNext(inner)
+            | TemporalFormula::ForallNext(inner) => inner.is_state_formula(),
+            TemporalFormula::And(left, right)
+            | TemporalFormula::Or(left, right)
+            | TemporalFormula::Implies(left, right)
+            | TemporalFormula::ExistsUntil(left, right)
+            | TemporalFormula::ForallUntil(left, right) => left.is_state_formula() && right.is_state_formula(),
+            TemporalFormula::Always(_)
+            | TemporalFormula::Eventually(_)
+            | TemporalFormula::Next(_)
+            | TemporalFormula::Until(..)
+            | TemporalFormula::Release(..)
+            | TemporalFormula::WeakUntil(..)
+            | TemporalFormula::StrongRelease(..)
+            | TemporalFormula::Yesterday(_)
+            | TemporalFormula::Previously(_)
+            | TemporalFormula::Historically(_)
+            | TemporalFormula::Since(..) => false,
+        }
+    }
+
+    /// Whether the formula uses any past-time operator
+    pub fn has_past_operators(&self) -> bool {
+        match self {
+            TemporalFormula::Atomic(_) => false,
+            TemporalFormula::Yesterday(_)
+            | TemporalFormula::Previously(_)
+            | TemporalFormula::Historically(_)
+            | TemporalFormula::Since(..) => true,
+            TemporalFormula::Not(inner)
+            | TemporalFormula::Always(inner)
+            | TemporalFormula::Eventually(inner)
+            | TemporalFormula::Next(inner)
+            | TemporalFormula::ExistsAlways(inner)
+            | TemporalFormula::ForallAlways(inner)
+            | TemporalFormula::ExistsEventually(inner)
+            | TemporalFormula::ForallEventually(inner)
+            | TemporalFormula::ExistsNext(inner)
+            | TemporalFormula::ForallNext(inner) => inner.has_past_operators(),
+            TemporalFormula::And(left, right)
+            | TemporalFormula::Or(left, right)
+            | TemporalFormula::Implies(left, right)
+            | TemporalFormula::Until(left, right)
+            | TemporalFormula::Release(left, right)
+            | TemporalFormula::WeakUntil(left, right)
+            | TemporalFormula::StrongRelease(left, right)
+            | TemporalFormula::ExistsUntil(left, right)
+            | TemporalFormula::ForallUntil(left, right) => {
+                left.has_past_operators() || right.has_past_operators()
+            }
+        }
+    }
+}
+
+/// Outcome of searching the state space for a violation of an invariant
+enum SafetySearch {
+    /// A shortest path to a violating state
+    Violation(CounterexampleTrace),
+    /// Every reachable state satisfies the invariant
+    NoViolation,
+    /// `max_states` was reached before the search was complete
+    Truncated,
 }
 
 /// Model checking algorithms
@@ -317,6 +402,11 @@ impl ModelChecker {
         Ok(())
     }
 
+    /// Replace the state space, for systems modelled outside a document
+    pub fn set_state_space(&mut self, state_space: StateSpace) {
+        self.state_space = state_space;
+    }
+
     /// Add temporal property for verification
     pub fn add_property(&mut self, property: TemporalProperty) {
         self.properties.push(property);
@@ -413,6 +503,18 @@ impl ModelChecker {
         &mut self,
         formula: &TemporalFormula,
     ) -> AispResult<(PropertyVerificationResult, Option<CounterexampleTrace>, Option<WitnessTrace>)> {
+        // □φ with a state formula φ is a safety property: a violation is a
+        // finite path to a state where φ fails
+        if let TemporalFormula::Always(invariant) = formula {
+            if invariant.is_state_formula() {
+                return match self.shortest_violation(invariant)? {
+                    SafetySearch::Violation(trace) => Ok((PropertyVerificationResult::Violated, Some(trace), None)),
+                    SafetySearch::NoViolation => Ok((PropertyVerificationResult::Satisfied, None, None)),
+                    SafetySearch::Truncated => Ok((PropertyVerificationResult::Unknown, None, None)),
+                };
+            }
+        }
+
         // Convert LTL formula to Büchi automaton (simplified)
         let negated_formula = TemporalFormula::Not(Box::new(formula.clone()));
         
@@ -484,6 +586,14 @@ impl ModelChecker {
                 let right_states = self.ctl_marking_algorithm(right)?;
                 Ok(left_states.union(&right_states).cloned().collect())
             }
+            TemporalFormula::Implies(left, right) => {
+                let left_states = self.ctl_marking_algorithm(left)?;
+                let right_states = self.ctl_marking_algorithm(right)?;
+                Ok(self.state_space.states.keys()
+                   .filter(|state_id| !left_states.contains(state_id) || right_states.contains(state_id))
+                   .cloned()
+                   .collect())
+            }
             TemporalFormula::ExistsNext(inner) => {
                 let inner_states = self.ctl_marking_algorithm(inner)?;
                 let mut result = HashSet::new();
@@ -642,12 +752,81 @@ impl ModelChecker {
 
     /// Find CTL counterexample
     fn find_ctl_counterexample(
-        &self,
-        _formula: &TemporalFormula,
+        &mut self,
+        formula: &TemporalFormula,
         _satisfying_states: &HashSet<StateId>,
     ) -> AispResult<Option<CounterexampleTrace>> {
-        // Simplified implementation
-        Ok(None)
+        match formula {
+            TemporalFormula::ForallAlways(invariant) => match self.shortest_violation(invariant)? {
+                SafetySearch::Violation(trace) => Ok(Some(trace)),
+                SafetySearch::NoViolation | SafetySearch::Truncated => Ok(None),
+            },
+            _ => Ok(None),
+        }
+    }
+
+    /// Shortest path from an initial state to a state violating `invariant`
+    ///
+    /// Breadth-first search from all initial states, recording each state's
+    /// predecessor, so the first violating state found ends a minimal trace.
+    /// Exploration stops at `max_states`, and a search cut short without
+    /// finding a violation is inconclusive.
+    fn shortest_violation(&mut self, invariant: &TemporalFormula) -> AispResult<SafetySearch> {
+        let satisfying = self.ctl_marking_algorithm(invariant)?;
+
+        let mut initial: Vec<StateId> = self.state_space.initial_states.iter().cloned().collect();
+        initial.sort_unstable();
+        let mut predecessors: HashMap<StateId, Option<&Transition>> = HashMap::new();
+        let mut queue = VecDeque::new();
+        for state_id in initial {
+            predecessors.insert(state_id, None);
+            queue.push_back(state_id);
+        }
+
+        let mut violation = None;
+        let mut truncated = false;
+        while let Some(state_id) = queue.pop_front() {
+            self.stats.states_explored += 1;
+            if !satisfying.contains(&state_id) {
+                violation = Some(state_id);
+                break;
+            }
+            if predecessors.len() >= self.max_states {
+                truncated |= self.state_space.transitions.get(&state_id).into_iter().flatten()
+                    .any(|transition| !predecessors.contains_key(&transition.to));
+                continue;
+            }
+            for transition in self.state_space.transitions.get(&state_id).into_iter().flatten() {
+                self.stats.transitions_explored += 1;
+                if !predecessors.contains_key(&transition.to) {
+                    predecessors.insert(transition.to, Some(transition));
+                    queue.push_back(transition.to);
+                }
+            }
+        }
+
+        let Some(mut state_id) = violation else {
+            return Ok(if truncated { SafetySearch::Truncated } else { SafetySearch::NoViolation });
+        };
+        let mut states = vec![state_id];
+        let mut transitions = Vec::new();
+        while let Some(Some(transition)) = predecessors.get(&state_id) {
+            transitions.push(
+                transition.action.clone().unwrap_or_else(|| format!("{}→{}", transition.from, transition.to)),
+            );
+            state_id = transition.from;
+            states.push(state_id);
+        }
+        states.reverse();
+        transitions.reverse();
+
+        Ok(SafetySearch::Violation(CounterexampleTrace {
+            id: format!("cex_{}", states.last().copied().unwrap_or_default()),
+            states,
+            transitions,
+            loop_point: None,
+            trace_type: TraceType::Finite,
+        }))
     }
 
     /// Extract state variables from AISP document
@@ -690,6 +869,26 @@ impl ModelChecker {
     }
 }
 
+impl CounterexampleTrace {
+    /// Number of transitions from the initial state to the violation
+    pub fn depth(&self) -> usize {
+        self.transitions.len()
+    }
+}
+
+impl fmt::Display for CounterexampleTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut states = self.states.iter();
+        if let Some(first) = states.next() {
+            write!(f, "s{}", first)?;
+        }
+        for (state, transition) in states.zip(&self.transitions) {
+            write!(f, " --{}--> s{}", transition, state)?;
+        }
+        Ok(())
+    }
+}
+
 impl StateSpace {
     /// Create new empty state space
     pub fn new() -> Self {
@@ -792,6 +991,110 @@ mod tests {
         assert_eq!(state1, state2);
     }
 
+    /// s0 -start-> s1 -fail-> s2, with s2 the only state not `safe`
+    fn chain_checker() -> ModelChecker {
+        let mut state_space = StateSpace::new();
+        for id in 0..3 {
+            state_space.states.insert(id, SystemState {
+                id,
+                variables: HashMap::new(),
+                locations: HashSet::new(),
+                properties: HashSet::new(),
+            });
+        }
+        for (from, to, action) in [(0, 1, "start"), (1, 2, "fail"), (0, 0, "idle")] {
+            state_space.transitions.entry(from).or_insert_with(Vec::new).push(Transition {
+                from,
+                to,
+                action: Some(action.to_string()),
+                guard: None,
+                probability: None,
+            });
+        }
+        state_space.initial_states.insert(0);
+        state_space.atomic_props.insert("safe".to_string(), [0, 1].into_iter().collect());
+        state_space.atomic_props.insert("started".to_string(), [1, 2].into_iter().collect());
+
+        let mut checker = ModelChecker::new();
+        checker.set_state_space(state_space);
+        checker
+    }
+
+    fn safety(id: &str, invariant: TemporalFormula, property_type: TemporalLogicType) -> TemporalProperty {
+        let formula = match property_type {
+            TemporalLogicType::CTL => TemporalFormula::ForallAlways(Box::new(invariant)),
+            _ => TemporalFormula::Always(Box::new(invariant)),
+        };
+        TemporalProperty { id: id.to_string(), name: id.to_string(), formula, property_type, expected: Some(true) }
+    }
+
+    #[test]
+    fn test_violated_safety_property_yields_shortest_trace() {
+        for property_type in [TemporalLogicType::LTL, TemporalLogicType::CTL] {
+            let mut checker = chain_checker();
+            checker.add_property(safety("safe", TemporalFormula::Atomic("safe".to_string()), property_type));
+
+            let result = checker.verify_properties().unwrap();
+            let property = &result.property_results["safe"];
+            assert_eq!(property.result, PropertyVerificationResult::Violated);
+
+            let trace = property.counterexample.as_ref().expect("counterexample");
+            assert_eq!(trace.depth(), 2);
+            assert_eq!(trace.states, vec![0, 1, 2]);
+            assert_eq!(trace.transitions, vec!["start", "fail"]);
+            assert_eq!(trace.to_string(), "s0 --start--> s1 --fail--> s2");
+        }
+    }
+
+    #[test]
+    fn test_satisfied_safety_property_has_no_trace() {
+        let mut checker = chain_checker();
+        let invariant = TemporalFormula::Implies(
+            Box::new(TemporalFormula::Not(Box::new(TemporalFormula::Atomic("started".to_string())))),
+            Box::new(TemporalFormula::Atomic("safe".to_string())),
+        );
+        checker.add_property(safety("unstarted_safe", invariant, TemporalLogicType::LTL));
+
+        let result = checker.verify_properties().unwrap();
+        let property = &result.property_results["unstarted_safe"];
+        assert_eq!(property.result, PropertyVerificationResult::Satisfied);
+        assert!(property.counterexample.is_none());
+        assert_eq!(result.status, VerificationStatus::Success);
+    }
+
+    #[test]
+    fn test_ltl_path_formula_under_always_is_not_a_safety_shortcut() {
+        let mut checker = chain_checker();
+        let recurrence = TemporalFormula::Always(Box::new(TemporalFormula::Eventually(Box::new(
+            TemporalFormula::Atomic("safe".to_string()),
+        ))));
+        assert!(!TemporalFormula::Eventually(Box::new(TemporalFormula::Atomic("safe".to_string()))).is_state_formula());
+        checker.add_property(TemporalProperty {
+            id: "recurrence".to_string(),
+            name: "recurrence".to_string(),
+            formula: recurrence,
+            property_type: TemporalLogicType::LTL,
+            expected: Some(true),
+        });
+        checker.add_property(safety("safe", TemporalFormula::Atomic("safe".to_string()), TemporalLogicType::LTL));
+
+        let result = checker.verify_properties().unwrap();
+        assert_eq!(result.property_results["recurrence"].result, PropertyVerificationResult::Satisfied);
+        assert_eq!(result.property_results["safe"].result, PropertyVerificationResult::Violated);
+    }
+
+    #[test]
+    fn test_search_cut_short_by_max_states_is_unknown() {
+        let mut checker = chain_checker();
+        checker.configure(ModelCheckingAlgorithm::ExplicitState, 1, Duration::from_secs(5));
+        checker.add_property(safety("safe", TemporalFormula::Atomic("safe".to_string()), TemporalLogicType::LTL));
+
+        let result = checker.verify_properties().unwrap();
+        let property = &result.property_results["safe"];
+        assert_eq!(property.result, PropertyVerificationResult::Unknown);
+        assert!(property.counterexample.is_none());
+    }
+
     #[test]
     fn test_value_types() {
         let bool_val = Value::Bool(true);
@@ -4,6 +4,7 @@
 //! enabling extraction of AISP code blocks from Markdown and other
 //! container formats while preserving context and metadata.
 
+use super::source_map::SourceMap;
 use crate::error::{AispError, AispResult};
 use std::collections::HashMap;
 
@@ -24,6 +25,9 @@ pub struct ExtractedAispBlock {
     
     /// Additional attributes from the code fence (e.g., ```aisp{label=test})
     pub attributes: HashMap<String, String>,
+
+    /// Maps lines of `content` back to lines of the source document
+    pub source_map: SourceMap,
 }
 
 /// Context information about the extraction process
@@ -156,12 +160,14 @@ impl AispCodeBlockExtractor {
         
         // Collect content until closing fence
         let mut content_lines = Vec::new();
+        let mut source_map = SourceMap::new();
         let content_start = *current_line;
         
         while *current_line < lines.len() && !self.is_aisp_fence_end(&lines[*current_line]) {
             let line = lines[*current_line];
             if self.preserve_whitespace || !line.trim().is_empty() {
                 content_lines.push(line);
+                source_map.push_lines(None, *current_line + 1, 1);
             }
             *current_line += 1;
         }
@@ -185,6 +191,7 @@ impl AispCodeBlockExtractor {
             end_line,
             label,
             attributes,
+            source_map,
         }))
     }
     
@@ -438,4 +445,18 @@ Just plain text with no AISP elements
         assert_eq!(blocks.len(), 1);
         assert_eq!(blocks[0].content.lines().count(), 2); // Empty lines removed
     }
+
+    #[test]
+    fn test_source_map_skips_removed_lines() {
+        let content = "# Title\n\n```aisp\n𝔸5.1.test@2026-01-30\n\n⟦Ω:Meta⟧{domain≜\"test\"}\n```";
+
+        let extractor = AispCodeBlockExtractor::with_options(false, false);
+        let blocks = extractor.extract_aisp_blocks(content).unwrap();
+
+        let source_map = &blocks[0].source_map;
+        assert_eq!(source_map.line_count(), 2);
+        assert_eq!(source_map.locate(1, 1).unwrap().line, 4);
+        assert_eq!(source_map.locate(2, 3).unwrap().line, 6);
+        assert_eq!(source_map.locate(2, 3).unwrap().column, 3);
+    }
 }
\ No newline at end of file
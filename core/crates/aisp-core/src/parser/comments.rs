@@ -0,0 +1,148 @@
+//! Source comments
+//!
+//! `#` and `//` comments run to the end of the line, `/* ... */` comments
+//! may span lines, and `;;` is still accepted as a line comment. The
+//! grammar skips all of them implicitly; this module recovers them with
+//! spans so the formatter can re-emit them, and strips them for text-level
+//! metrics such as symbol density.
+
+use crate::ast::canonical::Span;
+
+/// Comment delimiter style
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentKind {
+    /// `# ...`, `// ...` or `;; ...`
+    Line,
+    /// `/* ... */`
+    Block,
+}
+
+/// A comment as written in the source, delimiters included
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comment {
+    pub kind: CommentKind,
+    pub text: String,
+    pub span: Span,
+}
+
+fn comment_start(rest: &str) -> Option<CommentKind> {
+    if rest.starts_with("/*") {
+        Some(CommentKind::Block)
+    } else if rest.starts_with('#') || rest.starts_with("//") || rest.starts_with(";;") {
+        Some(CommentKind::Line)
+    } else {
+        None
+    }
+}
+
+/// Extract every comment outside string literals, in source order
+///
+/// An unterminated block comment runs to the end of the input, matching
+/// what the grammar would reject rather than silently swallowing less.
+pub fn extract_comments(source: &str) -> Vec<Comment> {
+    let mut comments = Vec::new();
+    let mut in_string = false;
+    let mut line = 1;
+    let mut line_start = 0;
+    let mut i = 0;
+
+    while let Some(c) = source[i..].chars().next() {
+        let rest = &source[i..];
+        if in_string {
+            in_string = c != '"';
+        } else if c == '"' {
+            in_string = true;
+        } else if let Some(kind) = comment_start(rest) {
+            let len = match kind {
+                CommentKind::Line => rest.find('\n').unwrap_or(rest.len()),
+                CommentKind::Block => rest.find("*/").map(|end| end + 2).unwrap_or(rest.len()),
+            };
+            let text = rest[..len].trim_end_matches('\r');
+            let column = source[line_start..i].chars().count() + 1;
+            comments.push(Comment {
+                kind,
+                text: text.to_string(),
+                span: Span::new(i, i + text.len(), line, column),
+            });
+            for (offset, ch) in rest[..len].char_indices() {
+                if ch == '\n' {
+                    line += 1;
+                    line_start = i + offset + 1;
+                }
+            }
+            i += len;
+            continue;
+        }
+
+        if c == '\n' {
+            line += 1;
+            line_start = i + 1;
+        }
+        i += c.len_utf8();
+    }
+
+    comments
+}
+
+/// Blank out comments, preserving byte offsets and line breaks
+pub fn strip_comments(source: &str) -> String {
+    let comments = extract_comments(source);
+    if comments.is_empty() {
+        return source.to_string();
+    }
+
+    let mut stripped = String::with_capacity(source.len());
+    let mut cursor = 0;
+    for comment in &comments {
+        stripped.push_str(&source[cursor..comment.span.start]);
+        for ch in comment.text.chars() {
+            if ch == '\n' {
+                stripped.push('\n');
+            } else {
+                stripped.extend(std::iter::repeat(' ').take(ch.len_utf8()));
+            }
+        }
+        cursor = comment.span.end;
+    }
+    stripped.push_str(&source[cursor..]);
+    stripped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extracts_line_and_block_comments_with_spans() {
+        let source = "𝔸5.1.doc@2026-01-25 # header\n/* spans\n two lines */\n⟦Ω:Meta⟧{domain≜x} // trailing";
+        let comments = extract_comments(source);
+
+        assert_eq!(comments.len(), 3);
+        assert_eq!(comments[0].kind, CommentKind::Line);
+        assert_eq!(comments[0].text, "# header");
+        assert_eq!((comments[0].span.line, comments[0].span.column), (1, 21));
+        assert_eq!(comments[1].kind, CommentKind::Block);
+        assert_eq!(comments[1].text, "/* spans\n two lines */");
+        assert_eq!(comments[1].span.line, 2);
+        assert_eq!(comments[2].text, "// trailing");
+        assert_eq!(comments[2].span.line, 4);
+        assert_eq!(&source[comments[2].span.start..comments[2].span.end], "// trailing");
+    }
+
+    #[test]
+    fn test_comment_markers_inside_strings_are_not_comments() {
+        let source = "⟦Ω:Meta⟧{url≜\"http://example.org/#top\"}";
+        assert!(extract_comments(source).is_empty());
+    }
+
+    #[test]
+    fn test_strip_preserves_offsets_and_lines() {
+        let source = "a≜1 # λ note\n/* ∀\n */b≜2";
+        let stripped = strip_comments(source);
+
+        assert_eq!(stripped.len(), source.len());
+        assert_eq!(stripped.lines().count(), source.lines().count());
+        assert_eq!(stripped.find("b≜2"), source.find("b≜2"));
+        assert!(!stripped.contains('λ') && !stripped.contains('∀'));
+    }
+}
@@ -1,20 +1,31 @@
 //! Evidence Content Parser
 //!
 //! Focused parser for evidence block content following SRP.
-//! Handles parsing of δ, φ, τ values and custom metrics.
+//! Handles parsing of δ, φ, τ values and custom metrics, which may carry
+//! a unit such as `latency≜100ms` or `mem≜2KB`.
 
 use crate::error::{AispError, AispResult};
+use crate::semantic::QualityTier;
 use std::collections::HashMap;
 
+/// Units a custom metric may carry: time, size, ratio and frequency
+pub const KNOWN_UNITS: &[&str] = &[
+    "ns", "us", "μs", "ms", "s", "min", "h", "B", "KB", "MB", "GB", "TB", "KiB", "MiB", "GiB", "%", "Hz", "kHz",
+    "MHz", "GHz",
+];
+
 /// SRP-focused parser for evidence block content
 pub struct EvidenceContentParser;
 
 impl EvidenceContentParser {
     /// Parse evidence entry from "symbol≜value" format
-    pub fn parse_evidence_entry(entry_text: &str) -> Option<EvidenceEntry> {
-        if let Some(pos) = entry_text.find('≜') {
+    ///
+    /// Entries with unparseable values are skipped, but a τ that is not a
+    /// tier glyph or a metric with an unknown unit is an error.
+    pub fn parse_evidence_entry(entry_text: &str) -> AispResult<Option<EvidenceEntry>> {
+        let entry = if let Some(pos) = entry_text.find('≜') {
             let key = entry_text[..pos].trim();
-            let value_text = entry_text[pos + '≜'.len_utf8()..].trim();
+            let value_text = entry_text[pos + '≜'.len_utf8()..].trim().trim_end_matches(';').trim_end();
             
             match key {
                 "δ" => {
@@ -31,26 +42,25 @@ impl EvidenceContentParser {
                         None
                     }
                 }
-                "τ" => {
-                    let tau_value = value_text.trim_matches('"').to_string();
-                    Some(EvidenceEntry::Tau(tau_value))
-                }
+                "τ" => Some(EvidenceEntry::Tau(Self::parse_tau(value_text)?)),
                 _ => {
-                    // Custom metric
+                    // Custom metric, with or without a unit
                     if let Ok(metric_value) = value_text.parse::<f64>() {
                         Some(EvidenceEntry::Metric(key.to_string(), metric_value))
                     } else {
-                        None
+                        Self::parse_measurement(key, value_text)?
+                            .map(|(value, unit)| EvidenceEntry::Measurement(key.to_string(), value, unit))
                     }
                 }
             }
         } else {
             None
-        }
+        };
+        Ok(entry)
     }
 
     /// Parse multiple evidence entries and return structured data
-    pub fn parse_evidence_block(content: &str) -> EvidenceData {
+    pub fn parse_evidence_block(content: &str) -> AispResult<EvidenceData> {
         let mut evidence_data = EvidenceData::new();
         
         for line in content.lines() {
@@ -59,12 +69,12 @@ impl EvidenceContentParser {
                 continue;
             }
             
-            if let Some(entry) = Self::parse_evidence_entry(line) {
+            if let Some(entry) = Self::parse_evidence_entry(line)? {
                 evidence_data.add_entry(entry);
             }
         }
         
-        evidence_data
+        Ok(evidence_data)
     }
 
     /// Validate delta value (accuracy threshold)
@@ -89,38 +99,32 @@ impl EvidenceContentParser {
         Ok(())
     }
 
-    /// Validate tau value (time or category string)
-    pub fn validate_tau(tau: &str) -> AispResult<()> {
-        if tau.is_empty() {
-            return Err(AispError::validation_error("Tau (τ) cannot be empty"));
-        }
-        
-        // Check for common time formats or categories
-        if Self::is_time_format(tau) || Self::is_category_format(tau) {
-            Ok(())
-        } else {
-            Err(AispError::validation_error(&format!(
-                "Invalid tau (τ) format: '{}'. Expected time format or category", 
-                tau
-            )))
-        }
-    }
-
-    /// Check if string represents time format
-    fn is_time_format(s: &str) -> bool {
-        // Simple time format validation
-        s.contains(':') || s.contains("ms") || s.contains("s") || 
-        s.contains("min") || s.contains("hour") || s.contains("day")
+    /// Parse tau value (quality tier glyph)
+    pub fn parse_tau(tau: &str) -> AispResult<QualityTier> {
+        let glyph = tau.trim().trim_matches('"');
+        QualityTier::from_glyph(glyph).ok_or_else(|| AispError::InvalidBlock {
+            block_name: "Evidence".to_string(),
+            message: format!("Unknown tier (τ) glyph '{}'. Expected one of ◊⁺⁺, ◊⁺, ◊, ◊⁻, ⊘", glyph),
+        })
     }
 
-    /// Check if string represents valid category
-    fn is_category_format(s: &str) -> bool {
-        // Categories like "formal", "semantic", "syntax", etc.
-        matches!(s.to_lowercase().as_str(), 
-            "formal" | "semantic" | "syntax" | "temporal" | "logical" | 
-            "mathematical" | "structural" | "behavioral" | "security" |
-            "performance" | "correctness" | "completeness"
-        )
+    /// Parse a number followed by a unit, e.g. `100ms`
+    ///
+    /// Text that does not start with a number is not a measurement; a
+    /// unit outside `KNOWN_UNITS` is an error.
+    pub fn parse_measurement(metric: &str, text: &str) -> AispResult<Option<(f64, String)>> {
+        let split = text.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(text.len());
+        let (number, unit) = text.split_at(split);
+        let (Ok(value), unit) = (number.parse::<f64>(), unit.trim()) else {
+            return Ok(None);
+        };
+        if !KNOWN_UNITS.contains(&unit) {
+            return Err(AispError::InvalidBlock {
+                block_name: "Evidence".to_string(),
+                message: format!("Unknown unit '{}' for metric '{}'. Expected one of {}", unit, metric, KNOWN_UNITS.join(", ")),
+            });
+        }
+        Ok(Some((value, unit.to_string())))
     }
 
     /// Extract confidence score from evidence data
@@ -138,16 +142,9 @@ impl EvidenceContentParser {
             confidence *= phi_factor;
         }
         
-        // Factor in tau category
-        if let Some(tau) = &evidence.tau {
-            let category_weight = match tau.to_lowercase().as_str() {
-                "formal" => 1.0,
-                "semantic" => 0.9,
-                "syntax" => 0.7,
-                "temporal" => 0.8,
-                _ => 0.6,
-            };
-            confidence *= category_weight;
+        // Factor in claimed tier, from 0.6 for ⊘ to 1.0 for ◊⁺⁺
+        if let Some(tau) = evidence.tau {
+            confidence *= 0.6 + 0.1 * tau.value() as f64;
         }
         
         confidence.min(1.0).max(0.0)
@@ -159,8 +156,10 @@ impl EvidenceContentParser {
 pub enum EvidenceEntry {
     Delta(f64),
     Phi(u64),
-    Tau(String),
+    Tau(QualityTier),
     Metric(String, f64),
+    /// Custom metric with its unit
+    Measurement(String, f64, String),
 }
 
 /// Structured evidence data
@@ -168,8 +167,10 @@ pub enum EvidenceEntry {
 pub struct EvidenceData {
     pub delta: Option<f64>,
     pub phi: Option<u64>,
-    pub tau: Option<String>,
+    pub tau: Option<QualityTier>,
     pub metrics: HashMap<String, f64>,
+    /// Unit of each metric given with one
+    pub units: HashMap<String, String>,
     pub raw_entries: Vec<String>,
 }
 
@@ -186,6 +187,10 @@ impl EvidenceData {
             EvidenceEntry::Metric(name, value) => {
                 self.metrics.insert(name, value);
             }
+            EvidenceEntry::Measurement(name, value, unit) => {
+                self.metrics.insert(name.clone(), value);
+                self.units.insert(name, unit);
+            }
         }
     }
     
@@ -202,43 +207,61 @@ mod tests {
 
     #[test]
     fn test_parse_delta_entry() {
-        let entry = EvidenceContentParser::parse_evidence_entry("δ≜0.001");
+        let entry = EvidenceContentParser::parse_evidence_entry("δ≜0.001").unwrap();
         assert_eq!(entry, Some(EvidenceEntry::Delta(0.001)));
     }
 
     #[test]
     fn test_parse_phi_entry() {
-        let entry = EvidenceContentParser::parse_evidence_entry("φ≜42");
+        let entry = EvidenceContentParser::parse_evidence_entry("φ≜42").unwrap();
         assert_eq!(entry, Some(EvidenceEntry::Phi(42)));
     }
 
     #[test]
     fn test_parse_tau_entry() {
-        let entry = EvidenceContentParser::parse_evidence_entry("τ≜formal");
-        assert_eq!(entry, Some(EvidenceEntry::Tau("formal".to_string())));
+        let entry = EvidenceContentParser::parse_evidence_entry("τ≜◊⁺").unwrap();
+        assert_eq!(entry, Some(EvidenceEntry::Tau(QualityTier::Gold)));
     }
 
     #[test]
     fn test_parse_metric_entry() {
-        let entry = EvidenceContentParser::parse_evidence_entry("accuracy≜0.95");
+        let entry = EvidenceContentParser::parse_evidence_entry("accuracy≜0.95").unwrap();
         assert_eq!(entry, Some(EvidenceEntry::Metric("accuracy".to_string(), 0.95)));
     }
 
+    #[test]
+    fn test_parse_metric_with_unit() {
+        let entry = EvidenceContentParser::parse_evidence_entry("latency≜100ms").unwrap();
+        assert_eq!(entry, Some(EvidenceEntry::Measurement("latency".to_string(), 100.0, "ms".to_string())));
+
+        let evidence = EvidenceContentParser::parse_evidence_block("mem≜2.5KB\ncoverage≜87%").unwrap();
+        assert_eq!(evidence.metrics["mem"], 2.5);
+        assert_eq!(evidence.units["mem"], "KB");
+        assert_eq!(evidence.units["coverage"], "%");
+    }
+
+    #[test]
+    fn test_parse_metric_rejects_unknown_unit() {
+        let error = EvidenceContentParser::parse_evidence_entry("latency≜100parsecs").unwrap_err();
+        assert!(error.to_string().contains("Unknown unit 'parsecs' for metric 'latency'"), "{}", error);
+        assert_eq!(EvidenceContentParser::parse_evidence_entry("owner≜ops").unwrap(), None);
+    }
+
     #[test]
     fn test_parse_evidence_block() {
         let content = r#"
         δ≜0.001
         φ≜98
-        τ≜formal
+        τ≜◊⁺⁺
         accuracy≜0.95
         // Comment
         precision≜0.92
         "#;
         
-        let evidence = EvidenceContentParser::parse_evidence_block(content);
+        let evidence = EvidenceContentParser::parse_evidence_block(content).unwrap();
         assert_eq!(evidence.delta, Some(0.001));
         assert_eq!(evidence.phi, Some(98));
-        assert_eq!(evidence.tau, Some("formal".to_string()));
+        assert_eq!(evidence.tau, Some(QualityTier::Platinum));
         assert_eq!(evidence.metrics.len(), 2);
         assert!(evidence.metrics.contains_key("accuracy"));
         assert!(evidence.metrics.contains_key("precision"));
@@ -260,12 +283,30 @@ mod tests {
     }
 
     #[test]
-    fn test_validate_tau() {
-        assert!(EvidenceContentParser::validate_tau("formal").is_ok());
-        assert!(EvidenceContentParser::validate_tau("semantic").is_ok());
-        assert!(EvidenceContentParser::validate_tau("10:30").is_ok());
-        assert!(EvidenceContentParser::validate_tau("100ms").is_ok());
-        assert!(EvidenceContentParser::validate_tau("").is_err());
+    fn test_parse_tau_accepts_each_tier_glyph() {
+        let tiers = [
+            ("⊘", QualityTier::Reject),
+            ("◊⁻", QualityTier::Bronze),
+            ("◊", QualityTier::Silver),
+            ("◊⁺", QualityTier::Gold),
+            ("◊⁺⁺", QualityTier::Platinum),
+        ];
+        for (glyph, tier) in tiers {
+            assert_eq!(EvidenceContentParser::parse_tau(glyph).unwrap(), tier);
+            assert_eq!(tier.glyph(), glyph);
+        }
+        assert_eq!(EvidenceContentParser::parse_tau("\"◊⁺\"").unwrap(), QualityTier::Gold);
+    }
+
+    #[test]
+    fn test_parse_tau_rejects_unknown_glyph() {
+        for tau in ["◊⁺⁺⁺", "◊⁺⁻", "formal", ""] {
+            assert!(EvidenceContentParser::parse_tau(tau).is_err(), "{}", tau);
+        }
+
+        let error = EvidenceContentParser::parse_evidence_entry("τ≜◊⁺⁺⁺").unwrap_err();
+        assert!(error.to_string().contains("Unknown tier (τ) glyph '◊⁺⁺⁺'"));
+        assert!(EvidenceContentParser::parse_evidence_block("δ≜0.5\nτ≜gold").is_err());
     }
 
     #[test]
@@ -273,14 +314,14 @@ mod tests {
         let mut evidence = EvidenceData::new();
         evidence.delta = Some(0.001);  // High accuracy
         evidence.phi = Some(95);       // High score
-        evidence.tau = Some("formal".to_string());  // Highest category
+        evidence.tau = Some(QualityTier::Platinum);  // Highest tier
         
         let confidence = EvidenceContentParser::calculate_confidence(&evidence);
         assert!(confidence > 0.9); // Should be high confidence
         
         let mut low_evidence = EvidenceData::new();
         low_evidence.delta = Some(0.1);  // Lower accuracy
-        low_evidence.tau = Some("syntax".to_string());  // Lower category
+        low_evidence.tau = Some(QualityTier::Bronze);  // Lower tier
         
         let low_confidence = EvidenceContentParser::calculate_confidence(&low_evidence);
         assert!(low_confidence < confidence); // Should be lower
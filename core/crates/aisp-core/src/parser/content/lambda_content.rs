@@ -53,7 +53,7 @@ impl LambdaContentParser {
     fn parse_unicode_lambda(text: &str) -> LambdaExpression {
         // Find the dot separator
         if let Some(dot_pos) = text.find('.') {
-            let param_part = &text[1..dot_pos].trim(); // Skip the λ
+            let param_part = &text['λ'.len_utf8()..dot_pos].trim(); // Skip the λ
             let body_part = text[dot_pos + 1..].trim();
             
             let parameters = Self::parse_parameters(param_part);
@@ -162,8 +162,8 @@ impl LambdaContentParser {
         // Check for common logical operators
         if body_text.contains('+') || body_text.contains('-') || 
            body_text.contains('*') || body_text.contains('/') {
-            // Mathematical expression - parse as application
-            Self::parse_mathematical_expression(body_text)
+            // Arithmetic is left to type inference, which re-reads the source
+            LogicalExpression::Raw(body_text.to_string())
         } else if body_text.contains('=') || body_text.contains('<') || body_text.contains('>') {
             // Comparison expression
             Self::parse_comparison_expression(body_text)
@@ -176,16 +176,6 @@ impl LambdaContentParser {
         }
     }
 
-    /// Parse mathematical expression (simplified)
-    fn parse_mathematical_expression(expr: &str) -> LogicalExpression {
-        // For now, treat as a function application
-        // This could be enhanced with proper expression parsing
-        LogicalExpression::Application {
-            function: "math".to_string(),
-            arguments: vec![LogicalExpression::Raw(expr.to_string())],
-        }
-    }
-
     /// Parse comparison expression (simplified)
     fn parse_comparison_expression(expr: &str) -> LogicalExpression {
         // For now, treat as raw logical expression
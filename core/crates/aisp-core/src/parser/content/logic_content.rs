@@ -62,7 +62,7 @@ impl LogicContentParser {
     fn parse_universal_quantifier(text: &str) -> (Option<Quantifier>, &str) {
         // Find the colon that separates quantifier from expression
         if let Some(colon_pos) = text.find(':') {
-            let quantifier_part = &text[1..colon_pos].trim(); // Skip ∀
+            let quantifier_part = &text['∀'.len_utf8()..colon_pos].trim(); // Skip ∀
             let expression_part = &text[colon_pos + 1..].trim();
             
             let (variable, domain) = Self::parse_quantifier_binding(quantifier_part);
@@ -84,7 +84,7 @@ impl LogicContentParser {
     /// Parse existential quantifier (∃x ∈ Domain: ...)
     fn parse_existential_quantifier(text: &str) -> (Option<Quantifier>, &str) {
         if let Some(colon_pos) = text.find(':') {
-            let quantifier_part = &text[1..colon_pos].trim(); // Skip ∃
+            let quantifier_part = &text['∃'.len_utf8()..colon_pos].trim(); // Skip ∃
             let expression_part = &text[colon_pos + 1..].trim();
             
             let (variable, domain) = Self::parse_quantifier_binding(quantifier_part);
@@ -257,7 +257,7 @@ impl LogicContentParser {
     fn try_parse_unary_expression(text: &str) -> Option<LogicalExpression> {
         if text.starts_with('¬') || text.starts_with("NOT") {
             let operand_text = if text.starts_with('¬') {
-                &text[1..].trim()
+                &text['¬'.len_utf8()..].trim()
             } else {
                 &text[3..].trim() // Skip "NOT"
             };
@@ -271,7 +271,7 @@ impl LogicContentParser {
         
         if text.starts_with('𝒫') || text.starts_with("PowerSet") {
             let operand_text = if text.starts_with('𝒫') {
-                &text[1..].trim()
+                &text['𝒫'.len_utf8()..].trim()
             } else {
                 &text[8..].trim() // Skip "PowerSet"
             };
@@ -340,7 +340,7 @@ impl LogicContentParser {
     fn try_parse_membership(text: &str) -> Option<LogicalExpression> {
         if let Some(in_pos) = text.find(" ∈ ") {
             let element_text = &text[..in_pos].trim();
-            let set_text = &text[in_pos + 3..].trim(); // Skip " ∈ "
+            let set_text = &text[in_pos + " ∈ ".len()..].trim();
             
             let element = Box::new(Self::parse_logical_expression(element_text));
             let set = Box::new(Self::parse_logical_expression(set_text));
@@ -390,27 +390,20 @@ impl LogicContentParser {
     /// Find main operator position (not inside parentheses)
     fn find_main_operator(text: &str, operator: &str) -> Option<usize> {
         let mut paren_depth = 0;
-        let chars: Vec<char> = text.chars().collect();
-        let op_chars: Vec<char> = operator.chars().collect();
-        
-        for i in 0..chars.len() {
-            match chars[i] {
+
+        // Byte offsets, so callers can slice around the operator
+        for (i, c) in text.char_indices() {
+            match c {
                 '(' => paren_depth += 1,
                 ')' => paren_depth -= 1,
                 _ => {
-                    if paren_depth == 0 {
-                        // Check if operator starts at position i
-                        if i + op_chars.len() <= chars.len() {
-                            let slice = &chars[i..i + op_chars.len()];
-                            if slice == &op_chars[..] {
-                                return Some(i);
-                            }
-                        }
+                    if paren_depth == 0 && text[i..].starts_with(operator) {
+                        return Some(i);
                     }
                 }
             }
         }
-        
+
         None
     }
 
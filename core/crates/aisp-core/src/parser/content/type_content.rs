@@ -14,9 +14,14 @@ impl TypeContentParser {
     pub fn parse_type_definition(def_text: &str) -> Option<(String, TypeExpression)> {
         if let Some(pos) = def_text.find('≜') {
             let name = def_text[..pos].trim().to_string();
-            let type_expr_text = def_text[pos + '≜'.len_utf8()..].trim();
+            let type_expr_text = def_text[pos + '≜'.len_utf8()..].trim().trim_end_matches(';').trim_end();
             
-            let type_expr = Self::parse_type_expression(type_expr_text);
+            // A generic definition's parameters shadow the basic type names
+            let parameters: Vec<&str> = name
+                .strip_suffix('⟩')
+                .and_then(|rest| rest.split_once('⟨'))
+                .map_or_else(Vec::new, |(_, parameters)| parameters.split(',').map(str::trim).collect());
+            let type_expr = Self::parse_type_expression_in(type_expr_text, &parameters);
             Some((name, type_expr))
         } else {
             None
@@ -25,12 +30,21 @@ impl TypeContentParser {
 
     /// Parse type expression from text
     pub fn parse_type_expression(type_text: &str) -> TypeExpression {
+        Self::parse_type_expression_in(type_text, &[])
+    }
+
+    /// Parse type expression from text, reading `parameters` as type
+    /// parameter names
+    fn parse_type_expression_in(type_text: &str, parameters: &[&str]) -> TypeExpression {
         let type_text = type_text.trim();
         
-        // Handle set types: {element_type}
+        // Handle set types: {element_type}, or enumerations: {Value1, Value2}
         if type_text.starts_with('{') && type_text.ends_with('}') {
             let inner = &type_text[1..type_text.len()-1].trim();
-            let element_type = Self::parse_type_expression(inner);
+            if let Some(values) = Self::enumeration_values(inner) {
+                return TypeExpression::Enumeration(values);
+            }
+            let element_type = Self::parse_type_expression_in(inner, parameters);
             return TypeExpression::Set(Box::new(element_type));
         }
         
@@ -38,7 +52,7 @@ impl TypeContentParser {
         if type_text.contains('|') {
             let types: Vec<TypeExpression> = type_text
                 .split('|')
-                .map(|t| Self::parse_type_expression(t.trim()))
+                .map(|t| Self::parse_type_expression_in(t.trim(), parameters))
                 .collect();
             return TypeExpression::Union(types);
         }
@@ -47,25 +61,36 @@ impl TypeContentParser {
         if type_text.contains('*') {
             let types: Vec<TypeExpression> = type_text
                 .split('*')
-                .map(|t| Self::parse_type_expression(t.trim()))
+                .map(|t| Self::parse_type_expression_in(t.trim(), parameters))
                 .collect();
             return TypeExpression::Product(types);
         }
         
         // Handle function types: (param1, param2) -> return_type
         if type_text.contains("->") {
-            return Self::parse_function_type(type_text);
+            return Self::parse_function_type(type_text, parameters);
         }
         
         // Handle tuple types: (Type1, Type2, Type3)
         if type_text.starts_with('(') && type_text.ends_with(')') && type_text.contains(',') {
             let inner = &type_text[1..type_text.len()-1];
-            let types: Vec<TypeExpression> = inner
-                .split(',')
-                .map(|t| Self::parse_type_expression(t.trim()))
+            let types: Vec<TypeExpression> = Self::split_arguments(inner)
+                .into_iter()
+                .map(|t| Self::parse_type_expression_in(t, parameters))
                 .collect();
             return TypeExpression::Tuple(types);
         }
+
+        // Handle generic instantiations: Pair⟨Type1, Type2⟩
+        if let Some((name, arguments)) = type_text.strip_suffix('⟩').and_then(|rest| rest.split_once('⟨')) {
+            return TypeExpression::Generic {
+                name: name.trim().to_string(),
+                arguments: Self::split_arguments(arguments)
+                    .into_iter()
+                    .map(|t| Self::parse_type_expression_in(t, parameters))
+                    .collect(),
+            };
+        }
         
         // Handle enumeration types: enum{value1, value2, value3}
         if type_text.starts_with("enum{") && type_text.ends_with('}') {
@@ -77,12 +102,48 @@ impl TypeContentParser {
             return TypeExpression::Enumeration(values);
         }
         
+        if parameters.contains(&type_text) {
+            return TypeExpression::Basic(BasicType::Custom(type_text.to_string()));
+        }
+
         // Parse as basic type
         TypeExpression::Basic(Self::parse_basic_type(type_text))
     }
 
+    /// Values of a brace enumeration body such as `Running, Stopped`: two or
+    /// more comma-separated plain identifiers
+    fn enumeration_values(inner: &str) -> Option<Vec<String>> {
+        let values: Vec<String> = inner.split(',').map(|v| v.trim().to_string()).collect();
+        let is_identifier = |value: &String| {
+            value.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_')
+                && value.chars().all(|c| c.is_alphanumeric() || c == '_')
+        };
+        (values.len() > 1 && values.iter().all(is_identifier)).then_some(values)
+    }
+
+    /// Comma-separated type arguments, leaving commas inside nested
+    /// brackets to the argument they belong to
+    fn split_arguments(text: &str) -> Vec<&str> {
+        let mut arguments = Vec::new();
+        let mut depth = 0usize;
+        let mut start = 0;
+        for (index, c) in text.char_indices() {
+            match c {
+                '(' | '⟨' | '{' => depth += 1,
+                ')' | '⟩' | '}' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    arguments.push(text[start..index].trim());
+                    start = index + c.len_utf8();
+                }
+                _ => {}
+            }
+        }
+        arguments.push(text[start..].trim());
+        arguments
+    }
+
     /// Parse function type from "(params) -> return" format
-    fn parse_function_type(type_text: &str) -> TypeExpression {
+    fn parse_function_type(type_text: &str, parameters: &[&str]) -> TypeExpression {
         if let Some(arrow_pos) = type_text.find("->") {
             let params_text = type_text[..arrow_pos].trim();
             let return_text = type_text[arrow_pos + 2..].trim();
@@ -95,14 +156,14 @@ impl TypeContentParser {
                 } else {
                     inner
                         .split(',')
-                        .map(|p| Self::parse_type_expression(p.trim()))
+                        .map(|p| Self::parse_type_expression_in(p.trim(), parameters))
                         .collect()
                 }
             } else {
-                vec![Self::parse_type_expression(params_text)]
+                vec![Self::parse_type_expression_in(params_text, parameters)]
             };
             
-            let return_type = Box::new(Self::parse_type_expression(return_text));
+            let return_type = Box::new(Self::parse_type_expression_in(return_text, parameters));
             
             TypeExpression::Function { params, return_type }
         } else {
@@ -229,6 +290,15 @@ impl TypeContentParser {
                 }
                 Self::validate_type_expression(return_type)
             },
+            TypeExpression::Generic { arguments, .. } => {
+                if arguments.is_empty() {
+                    return Err(AispError::validation_error("Generic type must have at least one type argument"));
+                }
+                for type_expr in arguments {
+                    Self::validate_type_expression(type_expr)?;
+                }
+                Ok(())
+            },
             TypeExpression::Tuple(types) => {
                 if types.len() < 2 {
                     return Err(AispError::validation_error("Tuple type must have at least two types"));
@@ -277,6 +347,12 @@ impl TypeContentParser {
                 }
                 Self::collect_type_dependencies(return_type, deps);
             },
+            TypeExpression::Generic { name, arguments } => {
+                deps.push(name.clone());
+                for type_expr in arguments {
+                    Self::collect_type_dependencies(type_expr, deps);
+                }
+            },
             _ => {}, // Built-in types don't create dependencies
         }
     }
@@ -314,6 +390,38 @@ mod tests {
         assert!(matches!(type_expr, TypeExpression::Function { .. }));
     }
 
+    #[test]
+    fn test_parse_generic_type() {
+        let (name, type_expr) = TypeContentParser::parse_type_definition("Pair⟨A,B⟩≜(A,B)").unwrap();
+        assert_eq!(name, "Pair⟨A,B⟩");
+        // `B` is the parameter here, not 𝔹
+        assert_eq!(
+            type_expr,
+            TypeExpression::Tuple(vec![
+                TypeExpression::Basic(BasicType::Custom("A".to_string())),
+                TypeExpression::Basic(BasicType::Custom("B".to_string())),
+            ])
+        );
+
+        let (_, type_expr) = TypeContentParser::parse_type_definition("Point≜Pair⟨ℝ,ℝ⟩;").unwrap();
+        assert!(matches!(type_expr, TypeExpression::Generic { ref name, .. } if name == "Pair"));
+
+        let type_expr = TypeContentParser::parse_type_expression("(Pair⟨ℕ,ℝ⟩, 𝔹)");
+        assert_eq!(
+            type_expr,
+            TypeExpression::Tuple(vec![
+                TypeExpression::Generic {
+                    name: "Pair".to_string(),
+                    arguments: vec![
+                        TypeExpression::Basic(BasicType::Natural),
+                        TypeExpression::Basic(BasicType::Real),
+                    ],
+                },
+                TypeExpression::Basic(BasicType::Boolean),
+            ])
+        );
+    }
+
     #[test]
     fn test_parse_vector_type() {
         let basic_type = TypeContentParser::parse_basic_type("Vector[3]");
@@ -331,6 +439,9 @@ mod tests {
         } else {
             panic!("Expected enumeration type");
         }
+
+        let type_expr = TypeContentParser::parse_type_expression("{Running, Stopped}");
+        assert_eq!(type_expr, TypeExpression::Enumeration(vec!["Running".to_string(), "Stopped".to_string()]));
     }
 
     #[test]
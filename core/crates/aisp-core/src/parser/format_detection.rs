@@ -12,6 +12,9 @@ pub enum DocumentFormat {
     /// Pure AISP document starting with 𝔸5.1.name@date
     PureAisp,
     
+    /// Pure AISP document on a single line, blocks separated by `;`
+    CompactAisp,
+    
     /// Markdown document containing AISP code blocks (```aisp)
     MarkdownWithAisp,
     
@@ -29,6 +32,7 @@ impl FormatDetector {
     /// Detect document format by analyzing content structure
     /// 
     /// This function uses efficient heuristics to identify document types:
+    /// - Compact AISP: Starts with 𝔸, fits on one line and separates blocks with `;`
     /// - Pure AISP: Starts with 𝔸 (Unicode MATHEMATICAL DOUBLE-STRUCK A)
     /// - Markdown with AISP: Has # headers and ```aisp code blocks
     /// - Mixed format: Contains AISP elements but doesn't fit pure patterns
@@ -43,6 +47,9 @@ impl FormatDetector {
         
         // Pure AISP detection: starts with 𝔸
         if trimmed.starts_with('𝔸') {
+            if !trimmed.contains('\n') && !Self::compact_separators(trimmed).is_empty() {
+                return DocumentFormat::CompactAisp;
+            }
             return DocumentFormat::PureAisp;
         }
         
@@ -66,10 +73,31 @@ impl FormatDetector {
         DocumentFormat::Unknown
     }
     
+    /// Byte offsets of the `;` separating blocks in a compact document: those
+    /// outside any bracket or string literal
+    pub fn compact_separators(content: &str) -> Vec<usize> {
+        let mut separators = Vec::new();
+        let mut depth = 0usize;
+        let mut in_string = false;
+
+        for (offset, ch) in content.char_indices() {
+            match ch {
+                '"' => in_string = !in_string,
+                _ if in_string => {}
+                '⟦' | '{' | '⟨' | '(' => depth += 1,
+                '⟧' | '}' | '⟩' | ')' => depth = depth.saturating_sub(1),
+                ';' if depth == 0 => separators.push(offset),
+                _ => {}
+            }
+        }
+        separators
+    }
+    
     /// Validate that a format can be parsed by our system
     pub fn is_parseable(format: &DocumentFormat) -> bool {
         match format {
             DocumentFormat::PureAisp => true,
+            DocumentFormat::CompactAisp => true,
             DocumentFormat::MarkdownWithAisp => true,
             DocumentFormat::MixedFormat => true, // Basic support
             DocumentFormat::Unknown => false,
@@ -80,6 +108,7 @@ impl FormatDetector {
     pub fn format_description(format: &DocumentFormat) -> &'static str {
         match format {
             DocumentFormat::PureAisp => "Pure AISP document",
+            DocumentFormat::CompactAisp => "Compact single-line AISP document",
             DocumentFormat::MarkdownWithAisp => "Markdown document with embedded AISP blocks",
             DocumentFormat::MixedFormat => "Mixed format document with AISP elements",
             DocumentFormat::Unknown => "Unknown or unsupported format",
@@ -115,7 +144,7 @@ impl FormatDetector {
         });
         
         let confidence = match format {
-            DocumentFormat::PureAisp if content.trim().starts_with('𝔸') => 1.0,
+            DocumentFormat::PureAisp | DocumentFormat::CompactAisp => 1.0,
             DocumentFormat::MarkdownWithAisp if aisp_block_count > 0 => 0.9,
             DocumentFormat::MixedFormat if has_unicode_math => 0.7,
             DocumentFormat::Unknown => 0.0,
@@ -123,7 +152,7 @@ impl FormatDetector {
         };
         
         let estimated_complexity = match (&format, aisp_block_count) {
-            (DocumentFormat::PureAisp, _) => FormatComplexity::Simple,
+            (DocumentFormat::PureAisp | DocumentFormat::CompactAisp, _) => FormatComplexity::Simple,
             (DocumentFormat::MarkdownWithAisp, 0..=3) => FormatComplexity::Moderate,
             (DocumentFormat::MarkdownWithAisp, _) => FormatComplexity::Complex,
             (DocumentFormat::MixedFormat, _) => FormatComplexity::Complex,
@@ -150,6 +179,17 @@ mod tests {
         assert_eq!(FormatDetector::detect(content), DocumentFormat::PureAisp);
     }
 
+    #[test]
+    fn test_compact_aisp_detection() {
+        let content = "𝔸5.1.test@2026-01-30;⟦Ω:Meta⟧{domain≜test};⟦Γ:Rules⟧{P(x);Q(x)}";
+        assert_eq!(FormatDetector::detect(content), DocumentFormat::CompactAisp);
+        assert_eq!(FormatDetector::compact_separators(content), vec![23, 52]);
+
+        // Semicolons inside blocks or on a multi-line document separate nothing
+        assert_eq!(FormatDetector::detect("𝔸5.1.test@2026-01-30 ⟦Γ:Rules⟧{P(x);Q(x)}"), DocumentFormat::PureAisp);
+        assert_eq!(FormatDetector::detect("𝔸5.1.test@2026-01-30;\n⟦Ω:Meta⟧{}"), DocumentFormat::PureAisp);
+    }
+
     #[test]
     fn test_markdown_with_aisp_detection() {
         let content = "# AISP Documentation\n\n```aisp\n𝔸5.1.test@2026-01-30\n⟦Ω:Meta⟧{}\n```";
@@ -177,6 +217,7 @@ mod tests {
     #[test]
     fn test_format_parseable() {
         assert!(FormatDetector::is_parseable(&DocumentFormat::PureAisp));
+        assert!(FormatDetector::is_parseable(&DocumentFormat::CompactAisp));
         assert!(FormatDetector::is_parseable(&DocumentFormat::MarkdownWithAisp));
         assert!(FormatDetector::is_parseable(&DocumentFormat::MixedFormat));
         assert!(!FormatDetector::is_parseable(&DocumentFormat::Unknown));
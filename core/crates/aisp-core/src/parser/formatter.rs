@@ -0,0 +1,304 @@
+//! Comment-preserving document formatter
+//!
+//! Re-emits a document in a canonical layout: the header, the optional
+//! domain/protocol declaration, then each block with one item per line.
+//! Item text is kept verbatim. Comments are re-attached by position: a
+//! comment sharing a source line with the preceding item trails it, any
+//! other comment is placed on its own line before whatever followed it.
+//! Meta entries can optionally be put in a canonical key order, each
+//! carrying its comments along.
+
+use super::comments::{extract_comments, strip_comments, Comment};
+use super::robust_parser::{AispParser, Rule};
+use crate::ast::canonical::meta_key_rank;
+use crate::error::{AispError, AispResult};
+use pest::iterators::Pair;
+use pest::Parser;
+
+const INDENT: &str = "  ";
+
+/// Format a document, keeping every comment
+///
+/// Fails on input the grammar rejects, including malformed blocks, rather
+/// than rewriting text it could not parse.
+pub fn format_document(source: &str) -> AispResult<String> {
+    format_document_with_meta_order(source, &[])
+}
+
+/// Format a document, sorting meta entries into the canonical key `order`
+///
+/// Listed keys come first, in the order given, then the remaining keys in
+/// source order. An empty order keeps every entry where it is.
+pub fn format_document_with_meta_order(source: &str, meta_key_order: &[String]) -> AispResult<String> {
+    let document = AispParser::parse(Rule::aisp_document, source)
+        .map_err(|error| {
+            let (line, column) = match error.line_col {
+                pest::error::LineColLocation::Pos((line, col)) => (line, col),
+                pest::error::LineColLocation::Span((line, col), _) => (line, col),
+            };
+            AispError::ParseError { line, column, message: error.to_string() }
+        })?
+        .next()
+        .ok_or_else(|| AispError::internal_error("Parser returned no document"))?;
+
+    let mut writer = CommentWriter::new(source);
+    for pair in document.into_inner() {
+        match pair.as_rule() {
+            Rule::header | Rule::domain_protocol_decl => {
+                let span = pair.as_span();
+                writer.line("", pair.as_str().trim(), span.start(), span.end());
+            }
+            Rule::aisp_blocks => {
+                for block in pair.into_inner() {
+                    writer.blank_line();
+                    write_block(&mut writer, block, meta_key_order)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    writer.finish()
+}
+
+fn write_block(writer: &mut CommentWriter, block: Pair<Rule>, meta_key_order: &[String]) -> AispResult<()> {
+    let block = match block.as_rule() {
+        Rule::aisp_block => block
+            .into_inner()
+            .next()
+            .ok_or_else(|| AispError::internal_error("Empty block"))?,
+        _ => block,
+    };
+    let block_span = block.as_span();
+    if block.as_rule() == Rule::malformed_block {
+        let (line, column) = block_span.start_pos().line_col();
+        return Err(AispError::ParseError {
+            line,
+            column,
+            message: "Cannot format a malformed block".to_string(),
+        });
+    }
+
+    let text = block.as_str();
+    let header_end = text.find('⟧').map(|pos| pos + '⟧'.len_utf8()).unwrap_or(text.len());
+    let header: String = text[..header_end].split_whitespace().collect();
+
+    let items = block.into_inner().find(|inner| {
+        matches!(
+            inner.as_rule(),
+            Rule::meta_entries
+                | Rule::type_definitions
+                | Rule::rule_definitions
+                | Rule::function_definitions
+                | Rule::error_definitions
+                | Rule::evidence_entries
+        )
+    });
+    let items_start = items
+        .as_ref()
+        .map(|items| items.as_span().start() - block_span.start())
+        .unwrap_or(header_end);
+    let opener = strip_comments(&text[header_end..items_start]).trim().to_string();
+    let closer = match opener.as_str() {
+        "{" => Some("}"),
+        "⟨" => Some("⟩"),
+        _ => None,
+    };
+
+    writer.line("", &format!("{}{}", header, opener), block_span.start(), block_span.start() + header_end);
+    if let Some(items) = items {
+        let reorder = items.as_rule() == Rule::meta_entries && !meta_key_order.is_empty();
+
+        // Each item is written with its comments, then the items are sorted;
+        // moved entries are terminated so they cannot run into their successor
+        let mut lines = Vec::new();
+        for item in items.into_inner() {
+            let span = item.as_span();
+            let mut text = item.as_str().trim().to_string();
+            let rank = match item.clone().into_inner().next() {
+                Some(key) if reorder => {
+                    if !text.ends_with(';') {
+                        text.push(';');
+                    }
+                    meta_key_rank(meta_key_order, key.as_str())
+                }
+                _ => 0,
+            };
+            let written = writer.output.len();
+            writer.line(INDENT, &text, span.start(), span.end());
+            lines.push((rank, writer.output.split_off(written)));
+        }
+        lines.sort_by_key(|(rank, _)| *rank);
+        for (_, line) in lines {
+            writer.output.push_str(&line);
+        }
+    }
+    if let Some(closer) = closer {
+        let close_start = block_span.end() - closer.len();
+        writer.leading(close_start, INDENT);
+        writer.line("", closer, close_start, block_span.end());
+    }
+    Ok(())
+}
+
+/// Output buffer that interleaves source comments with emitted lines
+struct CommentWriter<'a> {
+    source: &'a str,
+    comments: Vec<Comment>,
+    next: usize,
+    output: String,
+}
+
+impl<'a> CommentWriter<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            comments: extract_comments(source),
+            next: 0,
+            output: String::new(),
+        }
+    }
+
+    fn peek(&self) -> Option<&Comment> {
+        self.comments.get(self.next)
+    }
+
+    /// Emit own-line comments that start before `position`
+    fn leading(&mut self, position: usize, indent: &str) {
+        while let Some(comment) = self.peek() {
+            if comment.span.start >= position {
+                break;
+            }
+            let text = comment.text.clone();
+            self.output.push_str(indent);
+            self.output.push_str(&text);
+            self.output.push('\n');
+            self.next += 1;
+        }
+    }
+
+    /// Emit the source range `start..end` as `text`, plus a trailing comment
+    /// if only closing delimiters separate it from the range on its line
+    fn line(&mut self, indent: &str, text: &str, start: usize, end: usize) {
+        self.leading(start, indent);
+        // Comments inside the range are already part of `text`
+        while self.peek().is_some_and(|comment| comment.span.start < end) {
+            self.next += 1;
+        }
+
+        self.output.push_str(indent);
+        self.output.push_str(text);
+        if let Some(comment) = self.peek() {
+            let between = &self.source[end..comment.span.start];
+            if between.chars().all(|c| (c.is_whitespace() && c != '\n') || matches!(c, '}' | '⟩' | ';')) {
+                let trailing = comment.text.clone();
+                self.output.push(' ');
+                self.output.push_str(&trailing);
+                self.next += 1;
+            }
+        }
+        self.output.push('\n');
+    }
+
+    fn blank_line(&mut self) {
+        if !self.output.is_empty() && !self.output.ends_with("\n\n") {
+            self.output.push('\n');
+        }
+    }
+
+    fn finish(mut self) -> AispResult<String> {
+        if self.peek().is_some() {
+            self.blank_line();
+            self.leading(self.source.len(), "");
+        }
+        Ok(self.output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::AispValidator;
+
+    const COMMENTED: &str = "# Document about scheduling
+𝔸5.1.Commented@2026-01-25 // header note
+
+⟦Ω:Meta⟧{domain≜scheduling} # inline after block
+/* Types describe
+   the states */
+⟦Σ:Types⟧{
+  State≜{Idle,Busy} # two states
+}
+
+⟦Γ:Rules⟧{
+  ∀s:State→Valid(s)
+}
+
+⟦Λ:Funcs⟧{id≜λx.x}
+
+⟦Ε⟧⟨δ≜0.5⟩
+# end";
+
+    const UNCOMMENTED: &str = "𝔸5.1.Commented@2026-01-25
+
+⟦Ω:Meta⟧{domain≜scheduling}
+⟦Σ:Types⟧{
+  State≜{Idle,Busy}
+}
+
+⟦Γ:Rules⟧{
+  ∀s:State→Valid(s)
+}
+
+⟦Λ:Funcs⟧{id≜λx.x}
+
+⟦Ε⟧⟨δ≜0.5⟩";
+
+    #[test]
+    fn test_comments_are_ignored_by_validation() {
+        let validator = AispValidator::new();
+        let with_comments = validator.validate(COMMENTED);
+        let without_comments = validator.validate(UNCOMMENTED);
+
+        assert!(with_comments.valid, "{:?}", with_comments.error);
+        assert_eq!(with_comments.tier, without_comments.tier);
+        assert_eq!(with_comments.delta, without_comments.delta);
+        assert_eq!(with_comments.pure_density, without_comments.pure_density);
+    }
+
+    #[test]
+    fn test_comments_survive_format_round_trip() {
+        let formatted = format_document(COMMENTED).unwrap();
+
+        let original: Vec<String> = extract_comments(COMMENTED).into_iter().map(|c| c.text).collect();
+        let kept: Vec<String> = extract_comments(&formatted).into_iter().map(|c| c.text).collect();
+        assert_eq!(kept, original);
+
+        assert!(formatted.contains("  State≜{Idle,Busy} # two states\n"));
+        assert!(formatted.contains("𝔸5.1.Commented@2026-01-25 // header note\n"));
+        assert_eq!(format_document(&formatted).unwrap(), formatted);
+        assert!(AispValidator::new().validate(&formatted).valid);
+    }
+
+    #[test]
+    fn test_meta_entries_are_sorted_into_canonical_order() {
+        let source = UNCOMMENTED.replace(
+            "⟦Ω:Meta⟧{domain≜scheduling}",
+            "⟦Ω:Meta⟧{\n  domain≜scheduling;\n  Date≜today; # set on release\n  Vision≜plan\n}",
+        );
+        let order = ["Vision", "Author", "Date"].map(String::from);
+
+        let formatted = format_document_with_meta_order(&source, &order).unwrap();
+        assert!(formatted.contains(
+            "⟦Ω:Meta⟧{\n  Vision≜plan;\n  Date≜today; # set on release\n  domain≜scheduling;\n}"
+        ), "{}", formatted);
+        assert_eq!(format_document_with_meta_order(&formatted, &order).unwrap(), formatted);
+        assert!(AispValidator::new().validate(&formatted).valid);
+        assert_eq!(format_document(&source).unwrap(), format_document(&source).unwrap());
+        assert!(format_document(&source).unwrap().contains("  domain≜scheduling;\n  Date≜today;"));
+    }
+
+    #[test]
+    fn test_format_rejects_unparseable_input() {
+        assert!(format_document("not an AISP document").is_err());
+    }
+}
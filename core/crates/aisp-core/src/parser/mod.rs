@@ -4,11 +4,16 @@
 // Consolidated SRP-compliant parser architecture with multi-format support
 
 pub mod robust_parser;
+pub mod comments;
+pub mod near_miss;
+pub mod formatter;
 pub mod unicode_support;
 pub mod content;
 pub mod format_detection;
 pub mod aisp_extractor;
 pub mod multi_format;
+pub mod source_map;
+pub mod utf16;
 
 // Main parser exports (single source of truth)
 pub use robust_parser::{
@@ -20,6 +25,16 @@ pub use robust_parser::{
     AispParser,
 };
 
+// Comments and comment-preserving formatting
+pub use comments::{Comment, CommentKind, extract_comments, strip_comments};
+pub use formatter::{format_document, format_document_with_meta_order};
+
+// Original-source positions through extraction and includes
+pub use source_map::{SourceLocation, SourceMap};
+
+// Near-miss glyph suggestions for parse errors
+pub use near_miss::{block_letter, block_marker_near_misses, suggest_glyph, suggest_glyph_replacement, BlockMarkerNearMiss};
+
 // Unicode support
 pub use unicode_support::{
     UnicodeSymbolRegistry,
@@ -72,9 +87,11 @@ pub fn parse(source: &str) -> crate::error::AispResult<ParsedDocument> {
 /// Legacy compatibility function for pure AISP parsing only
 /// 
 /// This function maintains backward compatibility for existing code
-/// that expects only pure AISP document parsing.
+/// that expects only pure AISP document parsing. Input over
+/// `MAX_DOCUMENT_SIZE` fails with `AispError::DocumentTooLarge`.
 pub fn parse_aisp_only(source: &str) -> crate::error::AispResult<crate::ast::canonical::AispDocument> {
     let parser = RobustAispParser::new();
+    parser.check_document_size(source)?;
     let parse_result = parser.parse(source);
     parse_result.document.ok_or_else(|| {
         crate::error::AispError::ParseError {
@@ -135,7 +135,7 @@ impl MultiFormatParser {
         
         // Phase 2: Format-specific parsing
         let result = match analysis.format {
-            DocumentFormat::PureAisp => {
+            DocumentFormat::PureAisp | DocumentFormat::CompactAisp => {
                 self.parse_pure_aisp(content, detection_time_us)
             }
             DocumentFormat::MarkdownWithAisp => {
@@ -274,10 +274,18 @@ impl MultiFormatParser {
         let parser = RobustAispParser::new();
         let parse_result = parser.parse(&extracted_block.content);
         let document = parse_result.document.ok_or_else(|| {
-            AispError::ParseError {
-                message: "Failed to parse extracted block".to_string(),
-                line: 0,
-                column: 0,
+            // Report the first error in markdown coordinates rather than block-local ones
+            match parse_result.errors.first().and_then(|error| extracted_block.source_map.locate(error.line, error.column)) {
+                Some(origin) => AispError::ParseError {
+                    message: format!("Failed to parse extracted block: {}", parse_result.errors[0].message),
+                    line: origin.line,
+                    column: origin.column,
+                },
+                None => AispError::ParseError {
+                    message: "Failed to parse extracted block".to_string(),
+                    line: 0,
+                    column: 0,
+                },
             }
         })?;
         
@@ -0,0 +1,223 @@
+//! Near-miss glyph suggestions
+//!
+//! Authors typing on a plain keyboard reach for ASCII look-alikes such as
+//! `->` or `=>`, or write a letter resembling the glyph they meant, such as
+//! `A` for `∀`. When parsing fails on a line containing one, the error
+//! names the glyph most likely intended. Block markers are checked the same
+//! way, since a look-alike letter such as Cyrillic `Г` for `Γ` drops the
+//! whole block.
+
+use super::comments::strip_comments;
+use std::fmt;
+
+/// ASCII spellings the grammar rejects, with the glyph each imitates;
+/// longer spellings come first so `<=>` is not read as `<=`
+const CONFUSABLES: &[(&str, char)] = &[
+    ("<=>", '⇔'),
+    ("<->", '↔'),
+    ("::=", '≜'),
+    ("=>", '⇒'),
+    ("->", '→'),
+    ("<=", '≤'),
+    (">=", '≥'),
+    ("!=", '≠'),
+    ("==", '≡'),
+    (":=", '≜'),
+    ("/\\", '∧'),
+    ("\\/", '∨'),
+    ("[[", '⟦'),
+    ("]]", '⟧'),
+];
+
+/// Letters written in quantifier position, as in `Ax:T` or `E x:T`
+const QUANTIFIER_LOOKALIKES: &[(char, char)] = &[('A', '∀'), ('E', '∃')];
+
+/// Block letters with the block they open, the names that may follow them
+/// and the Latin, Cyrillic and mathematical letters mistaken for them
+const BLOCK_LETTERS: &[(char, &str, &[&str], &[char])] = &[
+    ('Ω', "Meta", &["Meta"], &['\u{2126}', 'Ѡ']),
+    ('Σ', "Types", &["Types"], &['∑', 'Ʃ']),
+    ('Γ', "Rules", &["Rules"], &['Г']),
+    ('Λ', "Functions", &["Funcs", "Functions"], &['∧', 'Ʌ', 'Л']),
+    ('Χ', "Errors", &["Errors"], &['X', 'Х']),
+    ('Ε', "Evidence", &["Evidence"], &['E', 'Е']),
+];
+
+/// A block marker whose letter is not the block's glyph, such as
+/// `⟦Г:Rules⟧` with a Cyrillic `Г`
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockMarkerNearMiss {
+    /// The marker as written, from `⟦` through `⟧`
+    pub marker: String,
+    /// Block the marker was meant to open, such as `Rules`
+    pub block: &'static str,
+    /// 1-based line of the letter
+    pub line: usize,
+    /// 1-based character column of the letter
+    pub column: usize,
+    /// Letter written
+    pub found: char,
+    /// Letter the block requires
+    pub glyph: char,
+}
+
+impl fmt::Display for BlockMarkerNearMiss {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` at line {} uses `{}` (U+{:04X}) where the {} block requires `{}`",
+            self.marker, self.line, self.found, self.found as u32, self.block, self.glyph
+        )
+    }
+}
+
+/// Glyph most likely meant on the 1-based `line` of `input`, taking the
+/// near-miss closest to `column` when the line has several
+pub fn suggest_glyph(input: &str, line: usize, column: usize) -> Option<char> {
+    suggest_glyph_replacement(input, line, column).map(|(_, _, glyph)| glyph)
+}
+
+/// Like [`suggest_glyph`], also giving the 1-based character column and
+/// the text the glyph should replace
+pub fn suggest_glyph_replacement(input: &str, line: usize, column: usize) -> Option<(usize, String, char)> {
+    let text = strip_comments(input.lines().nth(line.checked_sub(1)?)?);
+    let column = column.saturating_sub(1);
+
+    near_misses(&text)
+        .into_iter()
+        .min_by_key(|(position, _, _)| position.abs_diff(column))
+        .map(|(position, found, glyph)| (position + 1, found, glyph))
+}
+
+/// Letter that opens `block`, such as `Γ` for `Rules`
+pub fn block_letter(block: &str) -> Option<char> {
+    BLOCK_LETTERS.iter().find(|(_, name, _, _)| *name == block).map(|(glyph, ..)| *glyph)
+}
+
+/// Block markers outside comments whose letter imitates a block letter;
+/// the block is known from the name after `:` or, failing that, from the
+/// letter imitated
+pub fn block_marker_near_misses(input: &str) -> Vec<BlockMarkerNearMiss> {
+    let text = strip_comments(input);
+    let mut found = Vec::new();
+    for (offset, _) in text.match_indices('⟦') {
+        let letter_offset = offset + '⟦'.len_utf8();
+        let Some(letter) = text[letter_offset..].chars().next() else {
+            continue;
+        };
+        let rest = &text[letter_offset + letter.len_utf8()..];
+        let Some(close) = rest.find(['⟧', '\n']).filter(|&close| rest[close..].starts_with('⟧')) else {
+            continue;
+        };
+        let name = rest[..close].strip_prefix(':').map(str::trim);
+        let intended = match name {
+            Some(name) => BLOCK_LETTERS.iter().find(|(_, _, names, _)| names.contains(&name)),
+            None => BLOCK_LETTERS.iter().find(|(_, _, _, lookalikes)| lookalikes.contains(&letter)),
+        };
+        let Some(&(glyph, block, _, _)) = intended.filter(|(glyph, ..)| *glyph != letter) else {
+            continue;
+        };
+
+        let line_start = input[..letter_offset].rfind('\n').map_or(0, |newline| newline + 1);
+        found.push(BlockMarkerNearMiss {
+            marker: input[offset..letter_offset + letter.len_utf8() + close + '⟧'.len_utf8()].to_string(),
+            block,
+            line: input[..letter_offset].matches('\n').count() + 1,
+            column: input[line_start..letter_offset].chars().count() + 1,
+            found: letter,
+            glyph,
+        });
+    }
+    found
+}
+
+/// Every near-miss on a line, as (character column, spelling, intended glyph)
+fn near_misses(line: &str) -> Vec<(usize, String, char)> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut found = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        let rest: String = chars[index..].iter().collect();
+        if let Some((spelling, glyph)) = CONFUSABLES.iter().find(|(spelling, _)| rest.starts_with(spelling)) {
+            found.push((index, spelling.to_string(), *glyph));
+            index += spelling.chars().count();
+            continue;
+        }
+        if let Some(glyph) = quantifier_lookalike(&chars, index) {
+            found.push((index, chars[index].to_string(), glyph));
+        }
+        index += 1;
+    }
+    found
+}
+
+/// A standalone `A` or `E` followed by a variable and `:`; without a space
+/// the variable must be one letter, so `Alpha:T` stays an identifier
+fn quantifier_lookalike(chars: &[char], index: usize) -> Option<char> {
+    let (_, glyph) = QUANTIFIER_LOOKALIKES.iter().find(|(letter, _)| *letter == chars[index])?;
+    if index > 0 && is_identifier_char(chars[index - 1]) {
+        return None;
+    }
+
+    let rest = &chars[index + 1..];
+    let spaced = rest.iter().take_while(|c| **c == ' ').count();
+    let variable = rest[spaced..].iter().take_while(|c| is_identifier_char(**c)).count();
+    let annotated = rest.get(spaced + variable) == Some(&':');
+    (annotated && (variable == 1 || (spaced > 0 && variable > 0))).then_some(*glyph)
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_operators_suggest_glyphs() {
+        assert_eq!(suggest_glyph("s->t", 1, 2), Some('→'));
+        assert_eq!(suggest_glyph("p=>q", 1, 2), Some('⇒'));
+        assert_eq!(suggest_glyph("p<=>q", 1, 2), Some('⇔'));
+        assert_eq!(suggest_glyph("x<=y", 1, 1), Some('≤'));
+    }
+
+    #[test]
+    fn test_letter_in_quantifier_position_suggests_quantifier() {
+        assert_eq!(suggest_glyph("  Ax:State→Valid(x)", 1, 3), Some('∀'));
+        assert_eq!(suggest_glyph("E s:State→Busy(s)", 1, 1), Some('∃'));
+        assert_eq!(suggest_glyph("Alpha:State", 1, 1), None);
+        assert_eq!(suggest_glyph("TaskA:State", 1, 1), None);
+    }
+
+    #[test]
+    fn test_nearest_near_miss_on_the_error_line_wins() {
+        let input = "first->line\np=>q ∧ r->s";
+        assert_eq!(suggest_glyph(input, 2, 2), Some('⇒'));
+        assert_eq!(suggest_glyph(input, 2, 9), Some('→'));
+        assert_eq!(suggest_glyph(input, 3, 1), None);
+        assert_eq!(suggest_glyph("x // see a->b", 1, 1), None);
+    }
+
+    #[test]
+    fn test_block_marker_lookalikes_name_the_intended_block() {
+        let input = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n  ⟦Г:Rules⟧{\n}\n⟦Е⟧⟨δ≜0.5⟩ // ⟦∑:Types⟧";
+        let found = block_marker_near_misses(input);
+        assert_eq!(found.len(), 2, "{:?}", found);
+        assert_eq!((found[0].block, found[0].line, found[0].column, found[0].glyph), ("Rules", 3, 4, 'Γ'));
+        assert_eq!(
+            found[0].to_string(),
+            "`⟦Г:Rules⟧` at line 3 uses `Г` (U+0413) where the Rules block requires `Γ`"
+        );
+        assert_eq!((found[1].block, found[1].found, found[1].glyph), ("Evidence", 'Е', 'Ε'));
+
+        assert!(block_marker_near_misses("⟦Σ:Types⟧{}\n⟦Λ:Funcs⟧{}\n⟦Ε⟧⟨δ≜0.5⟩").is_empty());
+    }
+
+    #[test]
+    fn test_replacement_names_the_spelling_and_its_column() {
+        assert_eq!(suggest_glyph_replacement("  p<=>q", 1, 4), Some((4, "<=>".to_string(), '⇔')));
+        assert_eq!(suggest_glyph_replacement("E s:State", 1, 1), Some((1, "E".to_string(), '∃')));
+        assert_eq!(suggest_glyph_replacement("p∧q", 1, 1), None);
+    }
+}
@@ -4,7 +4,8 @@
 //! Each module is under 800 LOC with comprehensive inline unit tests.
 
 use pest::Parser;
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use crate::error::{AispError, AispResult};
 use crate::ast::canonical::{
@@ -24,6 +25,7 @@ use crate::ast::canonical::{
     LogicalExpression,
     FunctionDefinition,
     LambdaExpression,
+    Span,
 };
 
 // Import SRP content parsers
@@ -34,6 +36,13 @@ use super::content::{
     LambdaContentParser,
     EvidenceContentParser,
 };
+use super::content::evidence_content::KNOWN_UNITS;
+use super::comments::strip_comments;
+use super::near_miss::suggest_glyph;
+use super::source_map::{SourceLocation, SourceMap};
+use super::format_detection::{DocumentFormat, FormatDetector};
+use crate::symbols::{calculate_symbol_density, count_tokens, nfc_boundaries, normalize_nfc, symbol_histogram};
+use crate::semantic::QualityTier;
 
 //
 // MODULE: TYPES AND CONFIGURATION
@@ -50,10 +59,20 @@ pub struct RobustParserConfig {
     pub max_tokens_per_block: usize,
     /// Maximum total errors before aborting
     pub max_error_count: usize,
-    /// Enable Unicode normalization for security
+    /// Normalize input to NFC before parsing
     pub unicode_normalization: bool,
     /// Enable security validation checks
     pub security_validation: bool,
+    /// Symbol density below which a document is flagged as likely prose
+    pub min_symbol_density: f64,
+    /// Reject documents containing invisible control or format characters
+    /// outside comments and strings, instead of only reporting them
+    pub reject_invisible_characters: bool,
+    /// Largest input in bytes the parser will attempt
+    pub max_document_size: usize,
+    /// Accept lowercase block letters such as `⟦ω⟧`, normalizing them to
+    /// the canonical uppercase with a warning, instead of rejecting them
+    pub tolerant_block_markers: bool,
 }
 
 impl Default for RobustParserConfig {
@@ -65,6 +84,10 @@ impl Default for RobustParserConfig {
             max_error_count: 50,
             unicode_normalization: true,
             security_validation: true,
+            min_symbol_density: crate::MIN_SYMBOL_DENSITY,
+            reject_invisible_characters: false,
+            max_document_size: crate::MAX_DOCUMENT_SIZE,
+            tolerant_block_markers: true,
         }
     }
 }
@@ -79,6 +102,10 @@ impl RobustParserConfig {
             max_error_count: 10,
             unicode_normalization: true,
             security_validation: true,
+            min_symbol_density: crate::MIN_SYMBOL_DENSITY,
+            reject_invisible_characters: true,
+            max_document_size: crate::MAX_DOCUMENT_SIZE,
+            tolerant_block_markers: false,
         }
     }
 
@@ -91,6 +118,10 @@ impl RobustParserConfig {
             max_error_count: 1,
             unicode_normalization: false,
             security_validation: false,
+            min_symbol_density: crate::MIN_SYMBOL_DENSITY,
+            reject_invisible_characters: false,
+            max_document_size: crate::MAX_DOCUMENT_SIZE,
+            tolerant_block_markers: true,
         }
     }
 }
@@ -159,6 +190,13 @@ pub struct ParseError {
     pub context: String,
     pub security_impact: SecurityImpact,
     pub suggestions: Vec<String>,
+    /// Glyph the author most likely meant, e.g. `→` for a typed `->`
+    pub suggestion: Option<String>,
+    /// Source spans involved in the error (e.g. both sites of a duplicate)
+    pub spans: Vec<Span>,
+    /// Position in the original source when the parsed text was extracted
+    /// or assembled from other files
+    pub origin: Option<SourceLocation>,
 }
 
 impl ParseError {
@@ -177,6 +215,9 @@ impl ParseError {
             context: String::new(),
             security_impact: SecurityImpact::None,
             suggestions: Vec::new(),
+            suggestion: None,
+            spans: Vec::new(),
+            origin: None,
         }
     }
 
@@ -202,6 +243,63 @@ impl ParseError {
         self.suggestions.push(suggestion);
         self
     }
+
+    /// Suggest the glyph intended by a near-miss on the error line of `input`
+    pub fn with_near_miss_suggestion(mut self, input: &str) -> Self {
+        self.suggestion = suggest_glyph(input, self.line, self.column).map(String::from);
+        self
+    }
+
+    /// Add a related source span
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    /// Record the original source position of this error through `map`
+    pub fn with_source_map(mut self, map: &SourceMap) -> Self {
+        self.origin = map.locate(self.line, self.column);
+        self
+    }
+
+    /// Create duplicate definition error referencing both definition sites
+    pub fn duplicate_definition(kind: &str, name: &str, first: Span, second: Span, conflicting: bool) -> Self {
+        let message = if conflicting {
+            format!(
+                "Conflicting duplicate {} definition '{}' (first defined at {}:{})",
+                kind, name, first.line, first.column
+            )
+        } else {
+            format!(
+                "Duplicate {} definition '{}' (first defined at {}:{})",
+                kind, name, first.line, first.column
+            )
+        };
+
+        Self::new(ParseErrorType::DuplicateDefinition, second.line, second.column, message)
+            .with_span(first)
+            .with_span(second)
+            .with_suggestion(format!("Remove or rename one of the '{}' definitions", name))
+    }
+
+    /// Create error for an evidence τ that is not a tier glyph
+    pub fn unknown_tier(glyph: &str, span: Span) -> Self {
+        Self::new(
+            ParseErrorType::UnknownTier,
+            span.line,
+            span.column,
+            format!("Unknown tier (τ) glyph '{}'", glyph),
+        )
+        .with_span(span)
+        .with_suggestion("Use one of ◊⁺⁺, ◊⁺, ◊, ◊⁻, ⊘".to_string())
+    }
+
+    /// Create error for an evidence metric whose unit is not recognized
+    pub fn unknown_unit(unit: &str, span: Span) -> Self {
+        Self::new(ParseErrorType::UnknownUnit, span.line, span.column, format!("Unknown unit '{}'", unit))
+            .with_span(span)
+            .with_suggestion(format!("Use one of {}", KNOWN_UNITS.join(", ")))
+    }
 }
 
 /// Types of parse errors
@@ -212,6 +310,9 @@ pub enum ParseErrorType {
     StructuralError,
     SecurityViolation,
     RecoveryFailure,
+    DuplicateDefinition,
+    UnknownTier,
+    UnknownUnit,
 }
 
 /// Security impact assessment levels
@@ -299,6 +400,8 @@ pub enum SecurityIssueType {
     SuspiciousPattern,
     ResourceExhaustion,
     EncodingManipulation,
+    LowSymbolDensity,
+    InvisibleCharacter,
 }
 
 /// Security severity levels
@@ -321,6 +424,16 @@ pub struct BlockBoundary {
     pub is_well_formed: bool,
 }
 
+/// Lowercase block letter found directly after `⟦`
+#[derive(Debug, Clone)]
+struct LowercaseBlockMarker {
+    offset: usize,
+    line: usize,
+    column: usize,
+    found: char,
+    canonical: char,
+}
+
 //
 // MODULE: PEST GRAMMAR
 //
@@ -330,9 +443,9 @@ pub use pest::iterators::{Pair, Pairs};
 
 /// Enhanced inline grammar with comprehensive Unicode support
 #[derive(pest_derive::Parser)]
-#[grammar_inline = r#"
+#[grammar_inline = r##"
 WHITESPACE = _{ " " | "\t" | "\n" | "\r" }
-COMMENT = _{ "//" ~ (!"\n" ~ ANY)* | ";;" ~ (!"\n" ~ ANY)* }
+COMMENT = _{ "#" ~ (!"\n" ~ ANY)* | "//" ~ (!"\n" ~ ANY)* | ";;" ~ (!"\n" ~ ANY)* | "/*" ~ (!"*/" ~ ANY)* ~ "*/" }
 
 // Top-level document structure
 aisp_document = { 
@@ -346,7 +459,7 @@ aisp_document = {
 // Header with version, identifier, and date  
 header = { "𝔸" ~ version ~ "." ~ identifier ~ "@" ~ date }
 version = { ASCII_DIGIT+ ~ "." ~ ASCII_DIGIT+ }
-identifier = { (ASCII_ALPHANUMERIC | "-" | "_" | ".")+ }
+identifier = @{ (ASCII_ALPHANUMERIC | "-" | "_" | ".")+ }
 date = { ASCII_DIGIT{4} ~ "-" ~ ASCII_DIGIT{2} ~ "-" ~ ASCII_DIGIT{2} }
 
 // Domain and protocol declarations
@@ -385,7 +498,8 @@ meta_entries = { meta_entry* }
 meta_entry = { identifier ~ "≜" ~ (string_literal | identifier) ~ ";"? }
 
 type_definitions = { type_definition* }
-type_definition = { identifier ~ "≜" ~ type_expression ~ ";"? }
+type_definition = { type_name ~ "≜" ~ type_expression ~ ";"? }
+type_name = { identifier ~ ("⟨" ~ identifier ~ ("," ~ identifier)* ~ "⟩")? }
 
 rule_definitions = { rule_definition* }
 rule_definition = { logical_expr ~ ";"? }
@@ -402,18 +516,23 @@ evidence_entry = { evidence_symbol ~ "≜" ~ evidence_value ~ ";"? }
 // Enhanced expression types with Unicode mathematical symbols
 type_expression = { 
     set_type_expr |
+    tuple_type_expr |
     basic_type | 
+    generic_type_expr |
     identifier 
 }
 set_type_expr = { "{" ~ identifier ~ ("," ~ identifier)* ~ "}" }
-basic_type = { "ℕ" | "ℝ" | "ℂ" | "ℚ" | "ℤ" | "𝕊" | "𝔹" | "Unit" | "Natural" | "Boolean" }
+tuple_type_expr = { "(" ~ type_expression ~ ("," ~ type_expression)+ ~ ")" }
+generic_type_expr = { identifier ~ "⟨" ~ type_expression ~ ("," ~ type_expression)* ~ "⟩" }
+basic_type = { "ℕ" | "ℝ" | "ℂ" | "ℚ" | "ℤ" | "𝕊" | "𝔹" | "𝕌" | "Unit" | "Natural" | "Boolean" }
 
 lambda_expression = { 
-    "λ" ~ lambda_param ~ "." ~ lambda_param |
+    "λ" ~ lambda_param ~ lambda_annotation? ~ ("," ~ lambda_param ~ lambda_annotation?)* ~ "." ~ logical_expr |
     identifier
 }
 
-lambda_param = { (ASCII_ALPHA | "_") ~ (ASCII_ALPHANUMERIC | "_")* }
+lambda_param = @{ (ASCII_ALPHA | "_") ~ (ASCII_ALPHANUMERIC | "_")* }
+lambda_annotation = { ":" ~ type_expression }
 
 // Enhanced logical expressions with Unicode operators
 logical_expr = { 
@@ -460,16 +579,18 @@ argument_list = {
 }
 
 evidence_symbol = { "δ" | "φ" | "τ" | "|" ~ "𝔅" ~ "|" | identifier }
-evidence_value = { number | string_literal | quality_tier }
-quality_tier = { "◊" ~ ("⁺" | "⁻")* }
+evidence_value = { measurement | number | string_literal | quality_tier }
+measurement = ${ number ~ unit }
+unit = @{ (ASCII_ALPHA | "μ" | "%")+ }
+quality_tier = { "◊" ~ ("⁺" | "⁻")* | "⊘" }
 
 // Primitives with Unicode support
 number = { ASCII_DIGIT+ ~ ("." ~ ASCII_DIGIT+)? }
-string_literal = { "\"" ~ (!"\"" ~ ANY)* ~ "\"" }
+string_literal = @{ "\"" ~ (!"\"" ~ ANY)* ~ "\"" }
 
 // Error recovery
 malformed_block = { "⟦" ~ (!"⟧" ~ ANY)* ~ ("⟧" | &EOI) }
-"#]
+"##]
 pub struct AispParser;
 
 impl AispParser {
@@ -510,6 +631,10 @@ impl RobustAispParser {
                 max_error_count: 1,
                 unicode_normalization: true,
                 security_validation: true,
+                min_symbol_density: crate::MIN_SYMBOL_DENSITY,
+                reject_invisible_characters: true,
+                max_document_size: crate::MAX_DOCUMENT_SIZE,
+                tolerant_block_markers: false,
             },
         }
     }
@@ -519,11 +644,72 @@ impl RobustAispParser {
         self
     }
 
+    /// Set the symbol density floor for the low-density warning
+    pub fn with_min_symbol_density(mut self, density: f64) -> Self {
+        self.config.min_symbol_density = density;
+        self
+    }
+
+    /// Reject, rather than only report, invisible control and format characters
+    pub fn with_reject_invisible_characters(mut self, reject: bool) -> Self {
+        self.config.reject_invisible_characters = reject;
+        self
+    }
+
     pub fn with_error_recovery(mut self, enabled: bool) -> Self {
         self.config.enable_error_recovery = enabled;
         self
     }
 
+    /// Set the largest input in bytes the parser will attempt
+    pub fn with_max_document_size(mut self, max: usize) -> Self {
+        self.config.max_document_size = max;
+        self
+    }
+
+    /// Accept and normalize lowercase block letters such as `⟦ω⟧`
+    pub fn with_tolerant_block_markers(mut self, tolerant: bool) -> Self {
+        self.config.tolerant_block_markers = tolerant;
+        self
+    }
+
+    /// Reject input larger than `max_document_size` before any parsing work
+    pub fn check_document_size(&self, input: &str) -> AispResult<()> {
+        if input.len() > self.config.max_document_size {
+            return Err(AispError::DocumentTooLarge {
+                size: input.len(),
+                max: self.config.max_document_size,
+            });
+        }
+        Ok(())
+    }
+
+    /// The text the grammar sees: NFC-normalized, with the blocks of a
+    /// compact single-line document on their own lines and, when tolerated,
+    /// lowercase block letters uppercased
+    ///
+    /// Passes that read the source rather than the parsed document run on
+    /// this text, so they see the same blocks the parser does.
+    pub fn normalize_source<'a>(&self, input: &'a str) -> Cow<'a, str> {
+        let normalized = if self.config.unicode_normalization {
+            normalize_nfc(input)
+        } else {
+            Cow::Borrowed(input)
+        };
+        let normalized = match Self::expand_compact_blocks(&normalized) {
+            Cow::Borrowed(_) => normalized,
+            Cow::Owned(expanded) => Cow::Owned(expanded),
+        };
+        if !self.config.tolerant_block_markers {
+            return normalized;
+        }
+        let markers = Self::lowercase_block_markers(&normalized);
+        match Self::normalize_block_markers(&normalized, &markers) {
+            Cow::Borrowed(_) => normalized,
+            Cow::Owned(uppercased) => Cow::Owned(uppercased),
+        }
+    }
+
     /// Check if security validation is enabled
     pub fn has_security_validation(&self) -> bool {
         self.config.security_validation
@@ -531,6 +717,70 @@ impl RobustAispParser {
 
     /// Main parsing entry point with comprehensive error handling
     pub fn parse(&self, input: &str) -> ParseResult {
+        // Oversized input is a potential DoS and is never parsed
+        if let Err(error) = self.check_document_size(input) {
+            let mut result = ParseResult::failure(vec![
+                ParseError::new(ParseErrorType::SecurityViolation, 0, 0, error.to_string())
+                    .with_security_impact(SecurityImpact::High),
+            ]);
+            result.security_issues.push(SecurityIssue::new(
+                SecurityIssueType::ResourceExhaustion,
+                SecuritySeverity::High,
+                error.to_string(),
+                (0, 0),
+                "Reduce input size or raise max_document_size with caution".to_string(),
+            ));
+            return result;
+        }
+
+        // Decomposed glyphs would otherwise fail to match the grammar's
+        // precomposed symbols; spans refer to the normalized text, except
+        // block spans, which are mapped back to the input afterwards
+        let source = input;
+        let normalized = if self.config.unicode_normalization {
+            normalize_nfc(input)
+        } else {
+            Cow::Borrowed(input)
+        };
+        let input = normalized.as_ref();
+        let expanded = Self::expand_compact_blocks(input);
+        let input = expanded.as_ref();
+
+        let lowercase_markers = Self::lowercase_block_markers(input);
+        if !lowercase_markers.is_empty() && !self.config.tolerant_block_markers {
+            return ParseResult::failure(
+                lowercase_markers
+                    .iter()
+                    .map(|marker| {
+                        ParseError::syntax_error(
+                            marker.line,
+                            marker.column,
+                            format!("Lowercase block letter {} after ⟦", marker.found),
+                        )
+                        .with_suggestion(format!("Use the uppercase letter {}", marker.canonical))
+                    })
+                    .collect(),
+            );
+        }
+        let uppercased = Self::normalize_block_markers(input, &lowercase_markers);
+        let input = uppercased.as_ref();
+
+        let invisible_characters = if self.config.security_validation {
+            self.detect_invisible_characters(input)
+        } else {
+            Vec::new()
+        };
+        if self.config.reject_invisible_characters && !invisible_characters.is_empty() {
+            return ParseResult {
+                document: None,
+                errors: vec![],
+                warnings: vec![],
+                recovery_applied: false,
+                partial_success: false,
+                security_issues: invisible_characters,
+            };
+        }
+
         // Pre-parse security validation
         if self.config.security_validation {
             if let Some(security_issue) = self.detect_pre_parse_security_issues(input) {
@@ -546,10 +796,19 @@ impl RobustAispParser {
         }
 
         // Attempt primary parsing
-        match AispParser::parse(Rule::aisp_document, input) {
+        let mut result = match AispParser::parse(Rule::aisp_document, input) {
             Ok(pairs) => {
-                match self.build_ast_from_pairs(pairs, input) {
-                    Ok(document) => ParseResult::success(document),
+                let mut diagnostics = Vec::new();
+                match self.build_ast_from_pairs(pairs, input, &mut diagnostics) {
+                    Ok(document) if diagnostics.is_empty() => ParseResult::success(document),
+                    Ok(document) => ParseResult {
+                        document: Some(document),
+                        errors: diagnostics,
+                        warnings: Vec::new(),
+                        recovery_applied: false,
+                        partial_success: true,
+                        security_issues: Vec::new(),
+                    },
                     Err(ast_error) => {
                         if self.config.enable_error_recovery {
                             self.attempt_error_recovery(input, ast_error)
@@ -563,14 +822,38 @@ impl RobustAispParser {
                 if self.config.enable_error_recovery {
                     self.parse_with_error_recovery(input, pest_error)
                 } else {
-                    ParseResult::failure(vec![self.convert_pest_error_to_parse_error(pest_error)])
+                    ParseResult::failure(vec![self.convert_pest_error_to_parse_error(input, pest_error)])
                 }
             }
+        };
+
+        // Heuristic, not fatal: the document still parses
+        if self.config.security_validation && result.document.is_some() {
+            result.security_issues.extend(self.detect_low_symbol_density(input));
         }
+        result.security_issues.extend(invisible_characters);
+        if let (Cow::Owned(_), Some(document)) = (&normalized, result.document.as_mut()) {
+            Self::map_block_spans_to_source(source, document);
+        }
+        result.warnings.extend(lowercase_markers.iter().map(|marker| {
+            ParseWarning::new(
+                WarningType::DeprecatedSyntax,
+                marker.line,
+                marker.column,
+                format!("Lowercase block letter {} normalized to {}", marker.found, marker.canonical),
+                format!("Write the block marker with {}", marker.canonical),
+            )
+        }));
+        result
     }
 
     /// Build AST from successfully parsed Pest pairs
-    fn build_ast_from_pairs(&self, pairs: Pairs<Rule>, _input: &str) -> AispResult<AispDocument> {
+    fn build_ast_from_pairs(
+        &self,
+        pairs: Pairs<Rule>,
+        _input: &str,
+        diagnostics: &mut Vec<ParseError>,
+    ) -> AispResult<AispDocument> {
         let mut document = AispDocument {
             header: DocumentHeader {
                 version: String::new(),
@@ -583,12 +866,14 @@ impl RobustAispParser {
                 protocol: None,
             },
             blocks: Vec::new(),
+            used_symbols: HashSet::new(),
             span: None,
         };
 
         for pair in pairs {
             match pair.as_rule() {
                 Rule::aisp_document => {
+                    document.used_symbols = Self::used_symbols(pair.as_str());
                     for inner_pair in pair.into_inner() {
                         match inner_pair.as_rule() {
                             Rule::header => {
@@ -599,7 +884,9 @@ impl RobustAispParser {
                             }
                             Rule::aisp_blocks => {
                                 for block_pair in inner_pair.into_inner() {
-                                    if let Ok(block) = self.parse_block(block_pair) {
+                                    let span = Self::span_of(&block_pair);
+                                    if let Ok(mut block) = self.parse_block(block_pair, diagnostics) {
+                                        *block.span_mut() = Some(span);
                                         document.blocks.push(block);
                                     }
                                 }
@@ -666,14 +953,22 @@ impl RobustAispParser {
         Ok(DocumentMetadata { domain, protocol })
     }
 
-    /// Parse individual AISP block
-    fn parse_block(&self, pair: Pair<Rule>) -> AispResult<AispBlock> {
+    /// Parse individual AISP block, recording non-fatal diagnostics
+    fn parse_block(&self, pair: Pair<Rule>, diagnostics: &mut Vec<ParseError>) -> AispResult<AispBlock> {
         match pair.as_rule() {
             Rule::omega_block => self.parse_omega_block(pair),
-            Rule::sigma_block => self.parse_sigma_block(pair),
+            Rule::sigma_block => self.parse_sigma_block(pair, diagnostics),
             Rule::gamma_block => self.parse_gamma_block(pair),
-            Rule::lambda_block => self.parse_lambda_block(pair),
-            Rule::epsilon_block => self.parse_epsilon_block(pair),
+            Rule::lambda_block => self.parse_lambda_block(pair, diagnostics),
+            Rule::epsilon_block => self.parse_epsilon_block(pair, diagnostics),
+            Rule::aisp_block => match pair.into_inner().next() {
+                Some(inner) => self.parse_block(inner, diagnostics),
+                None => Err(AispError::ParseError {
+                    line: 1,
+                    column: 1,
+                    message: "Empty block content".to_string(),
+                }),
+            },
             Rule::malformed_block => {
                 Err(AispError::ParseError {
                     line: 1,
@@ -692,6 +987,7 @@ impl RobustAispParser {
     /// Parse Omega (Meta) block
     fn parse_omega_block(&self, pair: Pair<Rule>) -> AispResult<AispBlock> {
         let mut entries = HashMap::new();
+        let mut keys = Vec::new();
         let mut raw_entries = Vec::new();
 
         for inner in pair.into_inner() {
@@ -702,10 +998,13 @@ impl RobustAispParser {
                         raw_entries.push(entry_text.clone());
 
                         if let Some((key, value)) = MetaContentParser::parse_entry(&entry_text) {
+                            if !entries.contains_key(&key) {
+                                keys.push(key.clone());
+                            }
                             entries.insert(key.clone(), MetaEntry {
                                 key: key.clone(),
                                 value,
-                                span: None,
+                                span: Some(Self::span_of(&entry)),
                             });
                         }
                     }
@@ -716,14 +1015,17 @@ impl RobustAispParser {
 
         Ok(AispBlock::Meta(MetaBlock {
             entries,
+            keys,
             raw_entries,
             span: None,
         }))
     }
 
     /// Parse Sigma (Types) block
-    fn parse_sigma_block(&self, pair: Pair<Rule>) -> AispResult<AispBlock> {
-        let mut definitions = HashMap::new();
+    ///
+    /// Duplicate type names are reported as errors; the first definition is kept.
+    fn parse_sigma_block(&self, pair: Pair<Rule>, diagnostics: &mut Vec<ParseError>) -> AispResult<AispBlock> {
+        let mut definitions: HashMap<String, TypeDefinition> = HashMap::new();
         let mut raw_definitions = Vec::new();
 
         for inner in pair.into_inner() {
@@ -731,13 +1033,25 @@ impl RobustAispParser {
                 Rule::type_definitions => {
                     for def in inner.into_inner() {
                         let def_text = def.as_str().to_string();
+                        let span = Self::span_of(&def);
                         raw_definitions.push(def_text.clone());
 
                         if let Some((name, type_expr)) = TypeContentParser::parse_type_definition(&def_text) {
+                            if let Some(existing) = definitions.get(&name) {
+                                diagnostics.push(ParseError::duplicate_definition(
+                                    "type",
+                                    &name,
+                                    existing.span.clone().unwrap_or_default(),
+                                    span,
+                                    existing.type_expr != type_expr,
+                                ));
+                                continue;
+                            }
+
                             definitions.insert(name.clone(), TypeDefinition {
                                 name: name.clone(),
                                 type_expr,
-                                span: None,
+                                span: Some(span),
                             });
                         }
                     }
@@ -763,8 +1077,11 @@ impl RobustAispParser {
                 Rule::rule_definitions => {
                     for rule in inner.into_inner() {
                         let rule_text = rule.as_str().to_string();
+                        let span = Self::span_of(&rule);
                         raw_rules.push(rule_text.clone());
-                        rules.push(LogicContentParser::parse_logical_rule(&rule_text));
+                        let mut logical_rule = LogicContentParser::parse_logical_rule(&rule_text);
+                        logical_rule.span = Some(span);
+                        rules.push(logical_rule);
                     }
                 }
                 _ => {}
@@ -779,8 +1096,10 @@ impl RobustAispParser {
     }
 
     /// Parse Lambda (Functions) block
-    fn parse_lambda_block(&self, pair: Pair<Rule>) -> AispResult<AispBlock> {
-        let mut functions = Vec::new();
+    ///
+    /// Duplicate function names are reported as errors; the first definition is kept.
+    fn parse_lambda_block(&self, pair: Pair<Rule>, diagnostics: &mut Vec<ParseError>) -> AispResult<AispBlock> {
+        let mut functions: Vec<FunctionDefinition> = Vec::new();
         let mut raw_functions = Vec::new();
 
         for inner in pair.into_inner() {
@@ -788,14 +1107,26 @@ impl RobustAispParser {
                 Rule::function_definitions => {
                     for func in inner.into_inner() {
                         let func_text = func.as_str().to_string();
+                        let span = Self::span_of(&func);
                         raw_functions.push(func_text.clone());
 
                         if let Some((name, lambda)) = LambdaContentParser::parse_function_definition(&func_text) {
+                            if let Some(existing) = functions.iter().find(|f| f.name == name) {
+                                diagnostics.push(ParseError::duplicate_definition(
+                                    "function",
+                                    &name,
+                                    existing.span.clone().unwrap_or_default(),
+                                    span,
+                                    existing.lambda != lambda,
+                                ));
+                                continue;
+                            }
+
                             functions.push(FunctionDefinition {
                                 name: name.clone(),
                                 lambda,
                                 raw_text: func_text,
-                                span: None,
+                                span: Some(span),
                             });
                         }
                     }
@@ -812,12 +1143,14 @@ impl RobustAispParser {
     }
 
     /// Parse Epsilon (Evidence) block
-    fn parse_epsilon_block(&self, pair: Pair<Rule>) -> AispResult<AispBlock> {
+    fn parse_epsilon_block(&self, pair: Pair<Rule>, diagnostics: &mut Vec<ParseError>) -> AispResult<AispBlock> {
         let mut delta: Option<f64> = None;
         let mut phi: Option<u64> = None;
-        let mut tau: Option<String> = None;
+        let mut tau: Option<QualityTier> = None;
         let mut metrics = HashMap::new();
+        let mut units = HashMap::new();
         let mut raw_evidence = Vec::new();
+        let span = Self::span_of(&pair);
 
         for inner in pair.into_inner() {
             match inner.as_rule() {
@@ -826,7 +1159,22 @@ impl RobustAispParser {
                         let evidence_text = evidence.as_str().to_string();
                         raw_evidence.push(evidence_text.clone());
 
-                        if let Some(entry) = EvidenceContentParser::parse_evidence_entry(&evidence_text) {
+                        let entry = match EvidenceContentParser::parse_evidence_entry(&evidence_text) {
+                            Ok(entry) => entry,
+                            Err(_) => {
+                                let value = evidence.clone().into_inner().find(|inner| inner.as_rule() == Rule::evidence_value);
+                                let unit = value.clone().and_then(|value| value.into_inner().flatten().find(|inner| inner.as_rule() == Rule::unit));
+                                if let Some(unit) = unit {
+                                    diagnostics.push(ParseError::unknown_unit(unit.as_str(), Self::span_of(&unit)));
+                                    continue;
+                                }
+                                let span = value.as_ref().map_or_else(|| Self::span_of(&evidence), Self::span_of);
+                                let glyph = value.map_or(evidence_text.as_str(), |value| value.as_str());
+                                diagnostics.push(ParseError::unknown_tier(glyph, span));
+                                continue;
+                            }
+                        };
+                        if let Some(entry) = entry {
                             match entry {
                                 super::content::evidence_content::EvidenceEntry::Delta(d) => delta = Some(d),
                                 super::content::evidence_content::EvidenceEntry::Phi(p) => phi = Some(p),
@@ -834,6 +1182,10 @@ impl RobustAispParser {
                                 super::content::evidence_content::EvidenceEntry::Metric(name, value) => {
                                     metrics.insert(name, value);
                                 }
+                                super::content::evidence_content::EvidenceEntry::Measurement(name, value, unit) => {
+                                    metrics.insert(name.clone(), value);
+                                    units.insert(name, unit);
+                                }
                             }
                         }
                     }
@@ -847,8 +1199,9 @@ impl RobustAispParser {
             phi,
             tau,
             metrics,
+            units,
             raw_evidence,
-            span: None,
+            span: Some(span),
         }))
     }
 
@@ -870,9 +1223,10 @@ impl RobustAispParser {
                 protocol: None,
             },
             blocks: Vec::new(),
+            used_symbols: Self::used_symbols(input),
             span: None,
         };
-        let mut errors = vec![self.convert_pest_error_to_parse_error(original_error)];
+        let mut errors = vec![self.convert_pest_error_to_parse_error(input, original_error)];
         let mut warnings = vec![];
         let mut security_issues = vec![];
 
@@ -880,8 +1234,17 @@ impl RobustAispParser {
         let block_boundaries = self.extract_block_boundaries(input);
 
         for boundary in &block_boundaries {
-            match self.parse_single_block(&boundary) {
-                Ok(block) => {
+            match self.parse_single_block(&boundary, &mut errors) {
+                Ok(mut block) => {
+                    // Spans from the isolated parse are relative to the block
+                    let (line, column) = pest::Position::new(input, boundary.start_pos)
+                        .map_or((1, 1), |position| position.line_col());
+                    *block.span_mut() = Some(Span {
+                        start: boundary.start_pos,
+                        end: boundary.start_pos + boundary.content.len(),
+                        line,
+                        column,
+                    });
                     document.blocks.push(block);
                     if !boundary.is_well_formed {
                         warnings.push(ParseWarning::new(
@@ -921,6 +1284,74 @@ impl RobustAispParser {
         }
     }
 
+    /// AISP symbols occurring in the parsed text outside comments
+    fn used_symbols(text: &str) -> HashSet<char> {
+        crate::symbols::symbol_histogram(text).into_keys().collect()
+    }
+
+    /// Lowercase block letters directly after `⟦`, such as `⟦ω⟧`
+    fn lowercase_block_markers(input: &str) -> Vec<LowercaseBlockMarker> {
+        let mut markers = Vec::new();
+        let mut line = 1;
+        let mut line_start = 0;
+        let mut chars = input.char_indices().peekable();
+
+        while let Some((offset, ch)) = chars.next() {
+            if ch == '\n' {
+                line += 1;
+                line_start = offset + 1;
+            } else if ch == '⟦' {
+                if let Some(&(letter_offset, letter)) = chars.peek() {
+                    if let Some(canonical) = uppercase_block_letter(letter) {
+                        markers.push(LowercaseBlockMarker {
+                            offset: letter_offset,
+                            line,
+                            column: input[line_start..letter_offset].chars().count() + 1,
+                            found: letter,
+                            canonical,
+                        });
+                    }
+                }
+            }
+        }
+        markers
+    }
+
+    /// Replace lowercase block letters with their uppercase forms
+    ///
+    /// Greek letters of both cases are two bytes wide, so offsets and spans
+    /// are unchanged.
+    fn normalize_block_markers<'a>(input: &'a str, markers: &[LowercaseBlockMarker]) -> Cow<'a, str> {
+        if markers.is_empty() {
+            return Cow::Borrowed(input);
+        }
+
+        let mut normalized = input.to_string();
+        for marker in markers {
+            let end = marker.offset + marker.found.len_utf8();
+            normalized.replace_range(marker.offset..end, marker.canonical.encode_utf8(&mut [0; 4]));
+        }
+        Cow::Owned(normalized)
+    }
+
+    /// Put each block of a compact single-line document on its own line
+    ///
+    /// Separators are replaced byte for byte, so offsets still refer to the
+    /// original input while line numbers count blocks.
+    fn expand_compact_blocks(input: &str) -> Cow<'_, str> {
+        if FormatDetector::detect(input) != DocumentFormat::CompactAisp {
+            return Cow::Borrowed(input);
+        }
+
+        let separators = FormatDetector::compact_separators(input);
+        Cow::Owned(
+            input
+                .char_indices()
+                .map(|(offset, ch)| if separators.contains(&offset) { '\n' } else { ch })
+                .collect(),
+        )
+    }
+
     /// Extract block boundaries for error recovery
     fn extract_block_boundaries(&self, input: &str) -> Vec<BlockBoundary> {
         let mut boundaries = Vec::new();
@@ -959,11 +1390,11 @@ impl RobustAispParser {
     }
 
     /// Parse individual block during error recovery
-    fn parse_single_block(&self, boundary: &BlockBoundary) -> AispResult<AispBlock> {
+    fn parse_single_block(&self, boundary: &BlockBoundary, diagnostics: &mut Vec<ParseError>) -> AispResult<AispBlock> {
         match AispParser::parse(Rule::aisp_block, &boundary.content) {
             Ok(mut pairs) => {
                 if let Some(pair) = pairs.next() {
-                    self.parse_block(pair)
+                    self.parse_block(pair, diagnostics)
                 } else {
                     Err(AispError::ParseError {
                         line: 0,
@@ -984,6 +1415,7 @@ impl RobustAispParser {
         match block_type {
             "MetaBlock" => AispBlock::Meta(MetaBlock {
                 entries: HashMap::new(),
+                keys: Vec::new(),
                 raw_entries: Vec::new(),
                 span: None,
             }),
@@ -1007,11 +1439,13 @@ impl RobustAispParser {
                 phi: None,
                 tau: None,
                 metrics: HashMap::new(),
+                units: HashMap::new(),
                 raw_evidence: Vec::new(),
                 span: None,
             }),
             _ => AispBlock::Meta(MetaBlock {
                 entries: HashMap::new(),
+                keys: Vec::new(),
                 raw_entries: Vec::new(),
                 span: None,
             }),
@@ -1024,16 +1458,7 @@ impl RobustAispParser {
 
     /// Security validation methods
     fn detect_pre_parse_security_issues(&self, input: &str) -> Option<SecurityIssue> {
-        // Check for excessive size (potential DoS)
-        if input.len() > 1_000_000 {  // 1MB limit
-            return Some(SecurityIssue::new(
-                SecurityIssueType::ResourceExhaustion,
-                SecuritySeverity::High,
-                "Input exceeds maximum size limit".to_string(),
-                (0, 0),
-                "Reduce input size or increase limits with caution".to_string(),
-            ));
-        }
+        // Excessive size is rejected by `check_document_size` before this
 
         // Check for excessive nesting depth
         let max_depth = self.calculate_nesting_depth(input);
@@ -1061,6 +1486,60 @@ impl RobustAispParser {
         None
     }
 
+    /// Flag documents whose symbol density suggests prose labelled as AISP
+    fn detect_low_symbol_density(&self, input: &str) -> Option<SecurityIssue> {
+        // Counted the way density counts them, so the reported figures agree
+        let tokens = count_tokens(&strip_comments(input));
+        if tokens == 0 {
+            return None;
+        }
+
+        let density = calculate_symbol_density(input);
+        if density >= self.config.min_symbol_density {
+            return None;
+        }
+
+        let symbols: usize = symbol_histogram(input).values().sum();
+        Some(SecurityIssue::new(
+            SecurityIssueType::LowSymbolDensity,
+            SecuritySeverity::Low,
+            format!(
+                "Symbol density {:.3} is below {:.3} ({} AISP symbols in {} tokens); document may be prose rather than AISP",
+                density, self.config.min_symbol_density, symbols, tokens
+            ),
+            (0, 0),
+            "Express definitions and rules in AISP notation or lower the density floor".to_string(),
+        ))
+    }
+
+    /// Report control and zero-width format characters outside comments
+    /// and strings, one issue per character
+    fn detect_invisible_characters(&self, input: &str) -> Vec<SecurityIssue> {
+        let mut issues = Vec::new();
+        let mut in_string = false;
+        let mut line = 1;
+        let mut line_start = 0;
+
+        for (offset, ch) in strip_comments(input).char_indices() {
+            if ch == '\n' {
+                line += 1;
+                line_start = offset + 1;
+            } else if ch == '"' {
+                in_string = !in_string;
+            } else if !in_string && is_invisible_character(ch) {
+                let column = input[line_start..offset].chars().count() + 1;
+                issues.push(SecurityIssue::new(
+                    SecurityIssueType::InvisibleCharacter,
+                    SecuritySeverity::Medium,
+                    format!("Invisible character U+{:04X} at byte offset {}", ch as u32, offset),
+                    (line, column),
+                    "Remove the character; it can hide content from reviewers and diffs".to_string(),
+                ));
+            }
+        }
+        issues
+    }
+
     fn calculate_nesting_depth(&self, input: &str) -> usize {
         let mut depth = 0i32;
         let mut max_depth = 0usize;
@@ -1111,6 +1590,39 @@ impl RobustAispParser {
     // MODULE: UTILITY FUNCTIONS
     //
 
+    /// Source span of a Pest pair
+    fn span_of(pair: &Pair<Rule>) -> Span {
+        let pest_span = pair.as_span();
+        let (line, column) = pest_span.start_pos().line_col();
+        Span {
+            start: pest_span.start(),
+            end: pest_span.end(),
+            line,
+            column,
+        }
+    }
+
+    /// Map block spans from NFC-normalized text back to `source`
+    ///
+    /// Compact-form expansion and block-letter normalization keep byte
+    /// offsets, so only normalization can move them.
+    fn map_block_spans_to_source(source: &str, document: &mut AispDocument) {
+        let boundaries = nfc_boundaries(source);
+        let to_source = |offset: usize, round_up: bool| {
+            let index = boundaries.partition_point(|&(_, normalized)| normalized < offset);
+            match boundaries.get(index) {
+                Some(&(original, normalized)) if normalized == offset || round_up => original,
+                _ => boundaries[index.saturating_sub(1)].0,
+            }
+        };
+        for span in document.blocks.iter_mut().filter_map(|block| block.span_mut().as_mut()) {
+            span.start = to_source(span.start, false);
+            span.end = to_source(span.end, true);
+            let line_start = source[..span.start].rfind('\n').map_or(0, |newline| newline + 1);
+            span.column = source[line_start..span.start].chars().count() + 1;
+        }
+    }
+
     /// Safe Unicode-aware string slicing that respects character boundaries
     fn safe_slice<'a>(&self, input: &'a str, start: usize, end: usize) -> Option<&'a str> {
         // Convert byte positions to character positions
@@ -1118,7 +1630,12 @@ impl RobustAispParser {
         
         // Find character boundary positions
         let start_char_pos = chars.iter().find(|(pos, _)| *pos >= start).map(|(pos, _)| *pos)?;
-        let end_char_pos = chars.iter().rev().find(|(pos, _)| *pos < end).map(|(pos, _)| *pos + 1).unwrap_or(input.len());
+        let end_char_pos = chars
+            .iter()
+            .rev()
+            .find(|(pos, _)| *pos < end)
+            .map(|(pos, ch)| *pos + ch.len_utf8())
+            .unwrap_or(input.len());
         
         // Ensure positions are within bounds and at character boundaries
         if start_char_pos <= end_char_pos && input.is_char_boundary(start_char_pos) && input.is_char_boundary(end_char_pos) {
@@ -1188,7 +1705,7 @@ impl RobustAispParser {
     }
 
     /// Convert Pest error to parse error
-    fn convert_pest_error_to_parse_error(&self, error: pest::error::Error<Rule>) -> ParseError {
+    fn convert_pest_error_to_parse_error(&self, input: &str, error: pest::error::Error<Rule>) -> ParseError {
         let (line, column) = match error.line_col {
             pest::error::LineColLocation::Pos((line, col)) => (line, col),
             pest::error::LineColLocation::Span((line, col), _) => (line, col),
@@ -1198,6 +1715,7 @@ impl RobustAispParser {
             .with_security_impact(SecurityImpact::Medium)
             .with_suggestion("Check syntax near error location".to_string())
             .with_suggestion("Verify Unicode characters are valid".to_string())
+            .with_near_miss_suggestion(input)
     }
 
     /// Convert AST error to parse error
@@ -1265,7 +1783,14 @@ impl fmt::Display for ParseResult {
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}:{}] {}: {}", self.line, self.column, self.error_type, self.message)
+        match &self.origin {
+            Some(origin) => write!(
+                f,
+                "[{} (parsed {}:{})] {}: {}",
+                origin, self.line, self.column, self.error_type, self.message
+            ),
+            None => write!(f, "[{}:{}] {}: {}", self.line, self.column, self.error_type, self.message),
+        }
     }
 }
 
@@ -1277,10 +1802,33 @@ impl fmt::Display for ParseErrorType {
             ParseErrorType::StructuralError => write!(f, "Structural Error"),
             ParseErrorType::SecurityViolation => write!(f, "Security Violation"),
             ParseErrorType::RecoveryFailure => write!(f, "Recovery Failure"),
+            ParseErrorType::DuplicateDefinition => write!(f, "Duplicate Definition"),
+            ParseErrorType::UnknownTier => write!(f, "Unknown Tier"),
+            ParseErrorType::UnknownUnit => write!(f, "Unknown Unit"),
         }
     }
 }
 
+/// Canonical uppercase form of a lowercase block letter
+fn uppercase_block_letter(letter: char) -> Option<char> {
+    match letter {
+        'ω' => Some('Ω'),
+        'σ' => Some('Σ'),
+        'γ' => Some('Γ'),
+        'λ' => Some('Λ'),
+        'χ' => Some('Χ'),
+        'ε' => Some('Ε'),
+        _ => None,
+    }
+}
+
+/// Control characters other than line breaks and tabs, and zero-width or
+/// bidirectional format characters that render as nothing
+pub(crate) fn is_invisible_character(ch: char) -> bool {
+    (ch.is_control() && !matches!(ch, '\n' | '\r' | '\t'))
+        || matches!(ch, '\u{00AD}' | '\u{200B}'..='\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2060}'..='\u{2064}' | '\u{2066}'..='\u{2069}' | '\u{FEFF}')
+}
+
 impl fmt::Display for SecurityIssue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "[{:?}] {}: {}", self.severity, self.issue_type, self.description)
@@ -1295,6 +1843,8 @@ impl fmt::Display for SecurityIssueType {
             SecurityIssueType::SuspiciousPattern => write!(f, "Suspicious Pattern"),
             SecurityIssueType::ResourceExhaustion => write!(f, "Resource Exhaustion"),
             SecurityIssueType::EncodingManipulation => write!(f, "Encoding Manipulation"),
+            SecurityIssueType::LowSymbolDensity => write!(f, "Low Symbol Density"),
+            SecurityIssueType::InvisibleCharacter => write!(f, "Invisible Character"),
         }
     }
 }
@@ -1484,6 +2034,108 @@ mod tests {
         assert!(result.is_success() || result.partial_success);
     }
 
+    #[test]
+    fn test_consistent_duplicate_type_definition() {
+        let parser = RobustAispParser::new();
+        let input = "𝔸5.1.dup@2026-02-01\n⟦Σ:Types⟧{\n  T≜ℕ\n  T≜ℕ\n}";
+
+        let result = parser.parse(input);
+        assert!(!result.is_success());
+        assert_eq!(result.errors.len(), 1);
+
+        let error = &result.errors[0];
+        assert_eq!(error.error_type, ParseErrorType::DuplicateDefinition);
+        assert!(error.message.starts_with("Duplicate type definition 'T'"));
+        assert_eq!(error.spans.len(), 2);
+        assert_eq!(error.spans[0].line, 3);
+        assert_eq!(error.spans[1].line, 4);
+        assert_eq!((error.line, error.column), (4, 3));
+    }
+
+    #[test]
+    fn test_conflicting_duplicate_type_definition() {
+        let parser = RobustAispParser::new();
+        let input = "𝔸5.1.dup@2026-02-01\n⟦Σ:Types⟧{\n  T≜ℕ\n  T≜𝔹\n}";
+
+        let result = parser.parse(input);
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].message.starts_with("Conflicting duplicate type definition 'T'"));
+
+        // First definition wins rather than last-write-wins
+        let document = result.document.expect("document should still be produced");
+        match &document.blocks[0] {
+            AispBlock::Types(types) => {
+                assert_eq!(types.definitions.len(), 1);
+                assert_eq!(types.definitions["T"].type_expr, TypeExpression::Basic(crate::ast::canonical::BasicType::Natural));
+            }
+            other => panic!("Expected types block, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_distinct_type_definitions_have_no_duplicate_error() {
+        let parser = RobustAispParser::new();
+        let input = "𝔸5.1.nodup@2026-02-01\n⟦Σ:Types⟧{\n  T≜ℕ\n  U≜ℕ\n}";
+
+        let result = parser.parse(input);
+        assert!(result.is_success());
+    }
+
+    #[test]
+    fn test_evidence_tau_parses_into_tier() {
+        let parser = RobustAispParser::new();
+        let input = "𝔸5.1.tier@2026-02-01\n⟦Ε⟧⟨δ≜0.8;τ≜◊⁺⁺⟩";
+
+        let result = parser.parse(input);
+        assert!(result.is_success(), "{:?}", result.errors);
+        match &result.document.unwrap().blocks[0] {
+            AispBlock::Evidence(evidence) => assert_eq!(evidence.tau, Some(QualityTier::Platinum)),
+            other => panic!("Expected evidence block, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_evidence_metrics_carry_units() {
+        let parser = RobustAispParser::new();
+        let input = "𝔸5.1.units@2026-02-01\n⟦Ε⟧⟨δ≜0.8;latency≜100ms;mem≜2KB;accuracy≜0.95⟩";
+
+        let result = parser.parse(input);
+        assert!(result.is_success(), "{:?}", result.errors);
+        match &result.document.unwrap().blocks[0] {
+            AispBlock::Evidence(evidence) => {
+                assert_eq!(evidence.delta, Some(0.8));
+                assert_eq!((evidence.metrics["latency"], evidence.units["latency"].as_str()), (100.0, "ms"));
+                assert_eq!((evidence.metrics["mem"], evidence.units["mem"].as_str()), (2.0, "KB"));
+                assert_eq!(evidence.metrics["accuracy"], 0.95);
+                assert!(!evidence.units.contains_key("accuracy"));
+            }
+            other => panic!("Expected evidence block, got {:?}", other),
+        }
+
+        let result = parser.parse("𝔸5.1.units@2026-02-01\n⟦Ε⟧⟨latency≜100parsecs⟩");
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].error_type, ParseErrorType::UnknownUnit);
+        assert_eq!(result.errors[0].message, "Unknown unit 'parsecs'");
+        assert_eq!((result.errors[0].line, result.errors[0].column), (2, 16));
+    }
+
+    #[test]
+    fn test_unknown_evidence_tier_is_an_error() {
+        let parser = RobustAispParser::new();
+        let input = "𝔸5.1.tier@2026-02-01\n⟦Ε⟧⟨δ≜0.8;τ≜◊⁺⁺⁺⟩";
+
+        let result = parser.parse(input);
+        assert_eq!(result.errors.len(), 1);
+        let error = &result.errors[0];
+        assert_eq!(error.error_type, ParseErrorType::UnknownTier);
+        assert_eq!(error.message, "Unknown tier (τ) glyph '◊⁺⁺⁺'");
+        assert_eq!((error.line, error.column), (2, 13));
+        match &result.document.unwrap().blocks[0] {
+            AispBlock::Evidence(evidence) => assert_eq!(evidence.tau, None),
+            other => panic!("Expected evidence block, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_security_issue_creation() {
         let issue = SecurityIssue::new(
@@ -1499,6 +2151,81 @@ mod tests {
         assert_eq!(issue.location, (10, 20));
     }
 
+    #[test]
+    fn test_low_symbol_density_is_flagged() {
+        let prose = "The scheduler keeps a queue of pending jobs and hands each one to the next idle worker. \
+                     Workers report back when they finish so the queue can move on to the following job.";
+        let source = format!(
+            "𝔸5.1.Prose@2026-01-25\n\n⟦Ω:Meta⟧{{\n  summary≜\"{}\"\n}}\n\n⟦Γ:Rules⟧{{\n  {}\n}}\n",
+            prose.repeat(3), prose.repeat(3)
+        );
+        let result = RobustAispParser::new().parse(&source);
+
+        assert!(result.document.is_some());
+        let issue = result
+            .security_issues
+            .iter()
+            .find(|issue| issue.issue_type == SecurityIssueType::LowSymbolDensity)
+            .expect("low-density document should be flagged");
+        assert_eq!(issue.severity, SecuritySeverity::Low);
+
+        let relaxed = RobustAispParser::new().with_min_symbol_density(0.0).parse(&source);
+        assert!(relaxed.security_issues.is_empty());
+    }
+
+    #[test]
+    fn test_zero_width_space_is_reported_with_its_offset() {
+        let clean = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                     ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        assert!(RobustAispParser::new().parse(clean).security_issues.is_empty());
+        assert!(RobustAispParser::strict().parse(clean).document.is_some());
+
+        let hidden = clean.replace("Valid(s)", "Valid(s)\u{200B}");
+        let offset = hidden.find('\u{200B}').unwrap();
+
+        let result = RobustAispParser::new().parse(&hidden);
+        assert!(result.document.is_some());
+        let issues: Vec<_> = result
+            .security_issues
+            .iter()
+            .filter(|issue| issue.issue_type == SecurityIssueType::InvisibleCharacter)
+            .collect();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].location, (10, 20));
+        assert!(issues[0].description.contains(&format!("U+200B at byte offset {}", offset)));
+
+        let rejected = RobustAispParser::new().with_reject_invisible_characters(true).parse(&hidden);
+        assert!(rejected.document.is_none());
+        assert_eq!(rejected.security_issues.len(), 1);
+
+        let commented = clean.replace("{domain≜x}", "{domain≜x} // note\u{200B}");
+        assert!(RobustAispParser::strict().parse(&commented).security_issues.is_empty());
+    }
+
+    #[test]
+    fn test_dense_document_is_not_flagged() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        let result = RobustAispParser::new().parse(source);
+
+        assert!(result.document.is_some());
+        assert!(result.security_issues.is_empty());
+    }
+
+    #[test]
+    fn test_used_symbols_match_a_manual_scan() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        let document = RobustAispParser::new().parse(source).document.unwrap();
+
+        let scanned: HashSet<char> = source
+            .chars()
+            .filter(|&c| crate::symbols::is_aisp_symbol(c))
+            .collect();
+        assert!(scanned.contains(&'∀') && scanned.contains(&'λ'));
+        assert_eq!(document.used_symbols, scanned);
+    }
+
     #[test]
     fn test_parse_result_methods() {
         let mut result = ParseResult::new();
@@ -1513,10 +2240,112 @@ mod tests {
             },
             metadata: DocumentMetadata { domain: None, protocol: None },
             blocks: Vec::new(),
+            used_symbols: HashSet::new(),
             span: None,
         };
         
         let success_result = ParseResult::success(doc);
         assert!(success_result.is_success());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_ascii_arrow_in_operator_position_suggests_glyph() {
+        let valid = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                     ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        let parser = RobustAispParser::new();
+
+        let result = parser.parse(&valid.replace("State→Valid", "State->Valid"));
+        let error = &result.errors[0];
+        assert_eq!(error.line, 10);
+        assert_eq!(error.suggestion.as_deref(), Some("→"));
+
+        assert!(parser.parse(valid).errors.iter().all(|error| error.suggestion.is_none()));
+    }
+
+    #[test]
+    fn test_compact_document_parses_to_the_expanded_ast() {
+        let compact = "𝔸5.1.X@2026-01-25;⟦Ω:Meta⟧{domain≜x};⟦Σ:Types⟧{State≜{Idle,Busy}};\
+                       ⟦Γ:Rules⟧{∀s:State→Valid(s);∃s:State→Busy(s)};⟦Λ:Funcs⟧{id≜λx.x};⟦Ε⟧⟨δ≜0.5;φ≜3⟩";
+        let expanded = compact.replace("};⟦", "}\n⟦").replace("5;⟦", "5\n⟦");
+        let parser = RobustAispParser::new();
+
+        let compact_result = parser.parse(compact);
+        let expanded_result = parser.parse(&expanded);
+        assert!(compact_result.is_success(), "{:?}", compact_result.errors);
+        assert!(expanded_result.is_success(), "{:?}", expanded_result.errors);
+        assert_eq!(compact_result.document.as_ref().unwrap().blocks.len(), 5);
+        assert_eq!(compact_result.document, expanded_result.document);
+    }
+
+    #[test]
+    fn test_document_size_limit() {
+        let document = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                        ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+
+        let at_limit = RobustAispParser::new().with_max_document_size(document.len());
+        assert!(at_limit.check_document_size(document).is_ok());
+        assert!(at_limit.parse(document).is_success());
+
+        let over_limit = RobustAispParser::new().with_max_document_size(document.len() - 1);
+        assert!(matches!(
+            over_limit.check_document_size(document),
+            Err(AispError::DocumentTooLarge { size, max }) if size == document.len() && max == document.len() - 1
+        ));
+        let result = over_limit.parse(document);
+        assert!(result.document.is_none());
+        assert_eq!(result.errors[0].error_type, ParseErrorType::SecurityViolation);
+        assert!(result.errors[0].message.starts_with("Document too large"));
+        assert_eq!(result.security_issues[0].issue_type, SecurityIssueType::ResourceExhaustion);
+
+        let oversized = " ".repeat(crate::MAX_DOCUMENT_SIZE + 1);
+        assert!(matches!(crate::parser::parse_aisp_only(&oversized), Err(AispError::DocumentTooLarge { .. })));
+    }
+
+    #[test]
+    fn test_lowercase_block_markers_normalized_only_when_tolerant() {
+        let lowercase = "𝔸5.1.X@2026-01-25\n⟦ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                         ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦λ:Funcs⟧{id≜λx.x}\n⟦ε⟧⟨δ≜0.5⟩";
+        let canonical = lowercase.replace("⟦ω", "⟦Ω").replace("⟦λ", "⟦Λ").replace("⟦ε", "⟦Ε");
+
+        let tolerant = RobustAispParser::new().parse(lowercase);
+        assert!(tolerant.errors.is_empty(), "{:?}", tolerant.errors);
+        assert_eq!(tolerant.document, RobustAispParser::new().parse(&canonical).document);
+        assert_eq!(tolerant.warnings.len(), 3);
+        assert_eq!(tolerant.warnings[0].message, "Lowercase block letter ω normalized to Ω");
+        assert_eq!((tolerant.warnings[0].line, tolerant.warnings[0].column), (2, 2));
+
+        let strict = RobustAispParser::strict().parse(lowercase);
+        assert!(strict.document.is_none());
+        assert_eq!(strict.errors.len(), 3);
+        assert_eq!(strict.errors[0].error_type, ParseErrorType::SyntaxError);
+        assert_eq!(strict.errors[0].message, "Lowercase block letter ω after ⟦");
+        assert!(RobustAispParser::strict().parse(&canonical).document.is_some());
+    }
+
+    #[test]
+    fn test_block_byte_ranges_slice_the_source() {
+        let blocks = [
+            "⟦Ω:Meta⟧{\n  domain≜x\n}",
+            "⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}",
+            "⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}",
+            "⟦Λ:Funcs⟧{id≜λx.x}",
+            "⟦Ε⟧⟨δ≜0.5⟩",
+        ];
+        let input = format!("𝔸5.1.X@2026-01-25\n\n{}\n", blocks.join("\n\n"));
+        // The same document with a decomposed `∉` before the rules block
+        let decomposed = input.replace("\n\n⟦Γ", "\n;; s ∈\u{338} ∅\n⟦Γ");
+
+        for source in [input.as_str(), decomposed.as_str()] {
+            let document = RobustAispParser::new().parse(source).document.expect("document");
+            let sliced: Vec<&str> = document
+                .blocks
+                .iter()
+                .map(|block| {
+                    let (start, end) = block.byte_range().expect("parsed blocks have a range");
+                    &source[start..end]
+                })
+                .collect();
+            assert_eq!(sliced, blocks);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Source Maps
+//!
+//! The text handed to the parser is not always the text the author wrote.
+//! Markdown extraction drops fences and blank lines, and splicing in an
+//! included file inserts another file's lines. A `SourceMap` records, for
+//! every parsed line, the file and line it came from, so diagnostics can
+//! point at the original source.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// A position in original source coordinates
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// File the text came from; `None` for the top-level input
+    pub path: Option<PathBuf>,
+    /// 1-based line in that file
+    pub line: usize,
+    /// 1-based column in that line
+    pub column: usize,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{}:{}:{}", path.display(), self.line, self.column),
+            None => write!(f, "{}:{}", self.line, self.column),
+        }
+    }
+}
+
+/// Consecutive parsed lines copied from consecutive original lines
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Segment {
+    local_line: usize,
+    line_count: usize,
+    path: Option<PathBuf>,
+    original_line: usize,
+}
+
+/// Line translation from parsed text back to its original sources
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMap {
+    segments: Vec<Segment>,
+}
+
+impl SourceMap {
+    /// Create an empty source map
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map `line_count` lines of `path`, starting at `original_line`, onto
+    /// the next lines of the parsed text
+    pub fn push_lines(&mut self, path: Option<&Path>, original_line: usize, line_count: usize) {
+        if line_count == 0 {
+            return;
+        }
+        if let Some(last) = self.segments.last_mut() {
+            if last.path.as_deref() == path && last.original_line + last.line_count == original_line {
+                last.line_count += line_count;
+                return;
+            }
+        }
+        self.segments.push(Segment {
+            local_line: self.line_count() + 1,
+            line_count,
+            path: path.map(Path::to_path_buf),
+            original_line,
+        });
+    }
+
+    /// Number of parsed lines covered by the map
+    pub fn line_count(&self) -> usize {
+        self.segments.last().map_or(0, |last| last.local_line + last.line_count - 1)
+    }
+
+    /// Replace parsed line `line` (an include directive) with the lines
+    /// described by `included`
+    pub fn splice(&self, line: usize, included: &SourceMap) -> SourceMap {
+        let mut spliced = SourceMap::new();
+        for segment in &self.segments {
+            let path = segment.path.as_deref();
+            let end = segment.local_line + segment.line_count;
+            if (segment.local_line..end).contains(&line) {
+                let before = line - segment.local_line;
+                spliced.push_lines(path, segment.original_line, before);
+                for inner in &included.segments {
+                    spliced.push_lines(inner.path.as_deref(), inner.original_line, inner.line_count);
+                }
+                spliced.push_lines(path, segment.original_line + before + 1, end - line - 1);
+            } else {
+                spliced.push_lines(path, segment.original_line, segment.line_count);
+            }
+        }
+        spliced
+    }
+
+    /// Translate a parsed position into original source coordinates
+    pub fn locate(&self, line: usize, column: usize) -> Option<SourceLocation> {
+        self.segments
+            .iter()
+            .find(|segment| (segment.local_line..segment.local_line + segment.line_count).contains(&line))
+            .map(|segment| SourceLocation {
+                path: segment.path.clone(),
+                line: segment.original_line + (line - segment.local_line),
+                column,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::robust_parser::RobustAispParser;
+
+    #[test]
+    fn test_error_in_included_file_reports_its_path_and_line() {
+        let main = ["𝔸5.1.X@2026-01-25", "⟦Ω:Meta⟧{domain≜x}", "include \"types.aisp\"",
+                    "⟦Λ:Funcs⟧{id≜λx.x}", "⟦Ε⟧⟨δ≜0.5⟩"];
+        let types = ["⟦Σ:Types⟧{State≜{Idle,Busy}}", "⟦Γ:Rules⟧{∀s:State→Valid(s)}}"];
+
+        let mut main_map = SourceMap::new();
+        main_map.push_lines(Some(Path::new("main.aisp")), 1, main.len());
+        let mut types_map = SourceMap::new();
+        types_map.push_lines(Some(Path::new("types.aisp")), 1, types.len());
+        let map = main_map.splice(3, &types_map);
+
+        let source = [&main[..2], &types[..], &main[3..]].concat().join("\n");
+        assert_eq!(map.line_count(), source.lines().count());
+
+        let result = RobustAispParser::new().parse(&source);
+        let error = result.errors.first().expect("stray brace should fail to parse").clone();
+        assert_eq!(error.line, 4);
+
+        let error = error.with_source_map(&map);
+        let origin = error.origin.as_ref().unwrap();
+        assert_eq!(origin.path.as_deref(), Some(Path::new("types.aisp")));
+        assert_eq!(origin.line, 2);
+        assert_eq!(origin.column, error.column);
+    }
+
+    #[test]
+    fn test_lines_after_an_include_map_back_to_the_including_file() {
+        let mut main_map = SourceMap::new();
+        main_map.push_lines(Some(Path::new("main.aisp")), 1, 5);
+        let mut types_map = SourceMap::new();
+        types_map.push_lines(Some(Path::new("types.aisp")), 1, 2);
+        let map = main_map.splice(3, &types_map);
+
+        assert_eq!(map.line_count(), 6);
+        let location = map.locate(5, 7).unwrap();
+        assert_eq!(location.to_string(), "main.aisp:4:7");
+        assert_eq!(map.locate(2, 1).unwrap().to_string(), "main.aisp:2:1");
+        assert_eq!(map.locate(7, 1), None);
+    }
+}
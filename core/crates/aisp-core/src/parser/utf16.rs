@@ -0,0 +1,127 @@
+//! UTF-16 Input
+//!
+//! Browser hosts and Windows tools hand over text as UTF-16. Parsing it
+//! directly spares them re-encoding, and errors are positioned in UTF-16
+//! code units, the offsets those callers index their text by.
+
+use super::robust_parser::{ParseError, ParseErrorType, ParseResult, RobustAispParser};
+
+impl RobustAispParser {
+    /// Parse UTF-16 `input`, transcoding it to UTF-8 first
+    ///
+    /// A leading byte order mark is dropped. An unpaired surrogate fails
+    /// the parse with a `UnicodeError` at its position. Error columns, and the offsets and columns of error spans,
+    /// count UTF-16 code units; lines are unchanged. Spans in the parsed
+    /// document still refer to the transcoded UTF-8 text.
+    pub fn parse_utf16(&self, input: &[u16]) -> ParseResult {
+        let text = match decode(input) {
+            Ok(text) => text,
+            Err(error) => return ParseResult::failure(vec![error]),
+        };
+
+        let mut result = self.parse(&text);
+        for error in &mut result.errors {
+            error.column = utf16_column(&text, error.line, error.column);
+            for span in &mut error.spans {
+                span.start = utf16_offset(&text, span.start);
+                span.end = utf16_offset(&text, span.end);
+                span.column = utf16_column(&text, span.line, span.column);
+            }
+        }
+        result
+    }
+}
+
+/// Transcode UTF-16 to UTF-8, failing at the first unpaired surrogate
+fn decode(input: &[u16]) -> Result<String, ParseError> {
+    let input = input.strip_prefix(&[0xFEFF]).unwrap_or(input);
+    let mut text = String::with_capacity(input.len());
+    let (mut line, mut column) = (1, 1);
+    for decoded in char::decode_utf16(input.iter().copied()) {
+        match decoded {
+            Ok('\n') => {
+                text.push('\n');
+                line += 1;
+                column = 1;
+            }
+            Ok(ch) => {
+                text.push(ch);
+                column += ch.len_utf16();
+            }
+            Err(error) => {
+                return Err(ParseError::new(
+                    ParseErrorType::UnicodeError,
+                    line,
+                    column,
+                    format!("Unpaired UTF-16 surrogate 0x{:04X}", error.unpaired_surrogate()),
+                )
+                .with_suggestion("Re-encode the document as valid UTF-16".to_string()));
+            }
+        }
+    }
+    Ok(text)
+}
+
+/// 1-based UTF-16 column of the 1-based character `column` on `line`
+fn utf16_column(text: &str, line: usize, column: usize) -> usize {
+    let Some(line_text) = line.checked_sub(1).and_then(|index| text.split('\n').nth(index)) else {
+        return column;
+    };
+    let before = column.saturating_sub(1);
+    let units: usize = line_text.chars().take(before).map(char::len_utf16).sum();
+    // Columns past the end of the line stay one unit per position
+    units + before.saturating_sub(line_text.chars().count()) + 1
+}
+
+/// UTF-16 offset of byte offset `byte` in `text`
+fn utf16_offset(text: &str, byte: usize) -> usize {
+    text.get(..byte).map_or(byte, |prefix| prefix.encode_utf16().count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                            ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+
+    #[test]
+    fn test_valid_utf16_document_parses_like_utf8() {
+        let units: Vec<u16> = DOCUMENT.encode_utf16().collect();
+        assert!(units.len() < DOCUMENT.len());
+
+        let parser = RobustAispParser::new();
+        let result = parser.parse_utf16(&units);
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert!(result.document.is_some());
+        assert_eq!(result.document, parser.parse(DOCUMENT).document);
+
+        let with_bom: Vec<u16> = std::iter::once(0xFEFF).chain(units.iter().copied()).collect();
+        assert_eq!(parser.parse_utf16(&with_bom).document, result.document);
+    }
+
+    #[test]
+    fn test_unpaired_surrogate_is_an_error_at_its_utf16_position() {
+        // 𝔸 is two code units, so the surrogate after `𝔸5` is at column 4
+        let mut units: Vec<u16> = "𝔸5".encode_utf16().collect();
+        units.push(0xD800);
+        units.extend(".1.X@2026-01-25".encode_utf16());
+
+        let result = RobustAispParser::new().parse_utf16(&units);
+        assert!(result.document.is_none());
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].error_type, ParseErrorType::UnicodeError);
+        assert_eq!((result.errors[0].line, result.errors[0].column), (1, 4));
+        assert_eq!(result.errors[0].message, "Unpaired UTF-16 surrogate 0xD800");
+    }
+
+    #[test]
+    fn test_columns_count_utf16_code_units() {
+        // 𝔸 and 𝕊 are outside the Basic Multilingual Plane
+        let text = "a𝔸b\n𝕊x";
+        assert_eq!(utf16_column(text, 1, 3), 4);
+        assert_eq!(utf16_column(text, 2, 2), 3);
+        assert_eq!(utf16_column(text, 2, 5), 6);
+        assert_eq!(utf16_offset(text, "a𝔸".len()), 3);
+    }
+}
@@ -13,6 +13,7 @@ use crate::{
     mathematical_evaluator::{MathEvaluator, MathValue},
     incompleteness_handler::{IncompletenessHandler, TruthValue},
     ast::canonical::CanonicalAispDocument as AispDocument,
+    validator::AispValidator,
 };
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
@@ -409,6 +410,35 @@ pub struct TimeoutAnalysisResult {
     pub timeout_bound: Duration,
 }
 
+/// Scaling guarantee expressed as a bound on the fitted exponent k in t ∝ nᵏ
+#[derive(Debug, Clone)]
+pub struct ScalingAssertion {
+    /// Guarantee being exercised, as stated in the documentation
+    pub guarantee: String,
+    /// Complexity the guarantee claims
+    pub claimed_complexity: ComplexityFunction,
+    /// Fitted exponents must stay strictly below this bound
+    pub max_exponent: f64,
+}
+
+/// Best-of-N timing for one input size
+#[derive(Debug, Clone)]
+pub struct ScalingSample {
+    pub input_size: usize,
+    pub elapsed: Duration,
+}
+
+/// Outcome of running a scaling assertion over increasing input sizes
+#[derive(Debug, Clone)]
+pub struct ScalingReport {
+    pub guarantee: String,
+    pub samples: Vec<ScalingSample>,
+    /// Least-squares slope of ln(time) over ln(size)
+    pub fitted_exponent: f64,
+    pub max_exponent: f64,
+    pub holds: bool,
+}
+
 impl PerformanceGuaranteeVerifier {
     /// Create new performance guarantee verifier
     pub fn new() -> Self {
@@ -636,6 +666,69 @@ impl PerformanceGuaranteeVerifier {
         })
     }
 
+    /// Exercise a scaling assertion by timing `operation` at each input size
+    ///
+    /// `operation` receives the requested size and returns the actual input
+    /// size it processed (e.g. document bytes), which is what the exponent is
+    /// fitted against. Each size keeps the fastest of `repetitions` runs to
+    /// filter scheduler noise.
+    pub fn check_scaling<F>(
+        &self,
+        assertion: &ScalingAssertion,
+        sizes: &[usize],
+        repetitions: usize,
+        mut operation: F,
+    ) -> AispResult<ScalingReport>
+    where
+        F: FnMut(usize) -> AispResult<usize>,
+    {
+        if sizes.len() < 2 {
+            return Err(AispError::validation_error(
+                "Scaling assertions need at least two input sizes".to_string(),
+            ));
+        }
+
+        let mut samples = Vec::with_capacity(sizes.len());
+        for &size in sizes {
+            let mut best: Option<(usize, Duration)> = None;
+            for _ in 0..repetitions.max(1) {
+                let start = Instant::now();
+                let input_size = operation(size)?;
+                let elapsed = start.elapsed();
+                if best.map_or(true, |(_, fastest)| elapsed < fastest) {
+                    best = Some((input_size, elapsed));
+                }
+            }
+            if let Some((input_size, elapsed)) = best {
+                samples.push(ScalingSample { input_size, elapsed });
+            }
+        }
+
+        let fitted_exponent = fit_power_law_exponent(&samples);
+        Ok(ScalingReport {
+            guarantee: assertion.guarantee.clone(),
+            holds: fitted_exponent < assertion.max_exponent,
+            fitted_exponent,
+            max_exponent: assertion.max_exponent,
+            samples,
+        })
+    }
+
+    /// Check "validation is O(n) in document size" against the real validator
+    pub fn verify_validation_scaling(&self, sizes: &[usize]) -> AispResult<ScalingReport> {
+        let validator = AispValidator::new();
+        self.check_scaling(&ScalingAssertion::validation_linear(), sizes, 3, |size| {
+            let document = scaled_validation_document(size);
+            let result = validator.validate(&document);
+            match result.error {
+                Some(error) => Err(AispError::validation_error(format!(
+                    "Scaling document with {} definitions failed validation: {}", size, error
+                ))),
+                None => Ok(document.len()),
+            }
+        })
+    }
+
     // Helper methods for measurements and analysis
 
     fn simulate_prose_pipeline_success(&self, length: usize) -> f64 {
@@ -852,6 +945,62 @@ impl AmbiguityModel {
     }
 }
 
+impl ScalingAssertion {
+    /// Validation time grows linearly with document size; anything
+    /// sub-quadratic is accepted to leave room for timing noise
+    pub fn validation_linear() -> Self {
+        Self {
+            guarantee: "validation is O(n) in document size".to_string(),
+            claimed_complexity: ComplexityFunction::Linear,
+            max_exponent: 2.0,
+        }
+    }
+}
+
+/// Input sizes start, start·ratio, start·ratio², … (`steps` values)
+pub fn geometric_sizes(start: usize, ratio: usize, steps: usize) -> Vec<usize> {
+    std::iter::successors(Some(start.max(1)), |size| size.checked_mul(ratio.max(2)))
+        .take(steps)
+        .collect()
+}
+
+/// Least-squares slope of ln(elapsed) against ln(input_size)
+pub fn fit_power_law_exponent(samples: &[ScalingSample]) -> f64 {
+    let points: Vec<(f64, f64)> = samples.iter()
+        .filter(|sample| sample.input_size > 0)
+        .map(|sample| {
+            let nanos = sample.elapsed.as_nanos().max(1) as f64;
+            ((sample.input_size as f64).ln(), nanos.ln())
+        })
+        .collect();
+
+    if points.len() < 2 {
+        return 0.0;
+    }
+
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let covariance: f64 = points.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let variance: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+
+    if variance == 0.0 { 0.0 } else { covariance / variance }
+}
+
+/// Valid AISP document with `definitions` types and one rule per type
+pub fn scaled_validation_document(definitions: usize) -> String {
+    let mut document = String::from("𝔸5.1.Scaling@2026-01-25\n\n⟦Ω:Meta⟧{domain≜scaling}\n\n⟦Σ:Types⟧{\n");
+    for i in 0..definitions {
+        document.push_str(&format!("  T{i}≜{{a{i},b{i}}}\n"));
+    }
+    document.push_str("}\n\n⟦Γ:Rules⟧{\n");
+    for i in 0..definitions {
+        document.push_str(&format!("  ∀x:T{i}→Valid(x)\n"));
+    }
+    document.push_str("}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩");
+    document
+}
+
 impl ComplexityModel {
     fn new() -> Self {
         Self {
@@ -954,6 +1103,48 @@ mod tests {
                result.claimed_aisp_success > result.claimed_prose_success);
     }
 
+    fn synthetic_samples(exponent: i32) -> Vec<ScalingSample> {
+        geometric_sizes(100, 2, 5).into_iter()
+            .map(|size| ScalingSample {
+                input_size: size,
+                elapsed: Duration::from_nanos(((size as f64).powi(exponent) * 3.0) as u64),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_power_law_fit_distinguishes_linear_from_quadratic() {
+        assert_eq!(geometric_sizes(16, 2, 4), vec![16, 32, 64, 128]);
+
+        let linear = fit_power_law_exponent(&synthetic_samples(1));
+        let quadratic = fit_power_law_exponent(&synthetic_samples(2));
+        assert!((linear - 1.0).abs() < 0.01);
+        assert!((quadratic - 2.0).abs() < 0.01);
+        assert!(quadratic >= ScalingAssertion::validation_linear().max_exponent);
+    }
+
+    #[test]
+    fn test_validation_scales_sub_quadratically() {
+        let verifier = PerformanceGuaranteeVerifier::new();
+        let sizes = geometric_sizes(16, 2, 5);
+
+        let report = verifier.verify_validation_scaling(&sizes).unwrap();
+        assert_eq!(report.samples.len(), sizes.len());
+        assert!(report.samples.windows(2).all(|w| w[1].input_size > w[0].input_size));
+        assert!(
+            report.holds,
+            "{}: fitted exponent {:.2} ≥ {:.2} ({:?})",
+            report.guarantee, report.fitted_exponent, report.max_exponent, report.samples
+        );
+    }
+
+    #[test]
+    fn test_scaling_requires_multiple_sizes() {
+        let verifier = PerformanceGuaranteeVerifier::new();
+        let result = verifier.check_scaling(&ScalingAssertion::validation_linear(), &[10], 1, Ok);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_resource_usage_profile() {
         let profile = ResourceUsageProfile {
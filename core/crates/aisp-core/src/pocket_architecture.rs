@@ -727,6 +727,115 @@ pub enum InteractionResult {
     Failure,
 }
 
+/// Bounded content-addressed pocket store with affinity-weighted eviction
+///
+/// A single `Pocket` holds one nucleus, so the capacity bound lives on the
+/// collection. When an insert exceeds capacity, the stored pocket with the
+/// lowest retention score is evicted, where retention is the Hebbian
+/// affinity other stored pockets hold toward it. Ties fall back to least
+/// recently used, so with no learned affinities the policy is plain LRU.
+#[derive(Debug, Clone)]
+pub struct PocketStore {
+    /// Maximum number of pockets retained (at least one)
+    capacity: usize,
+    /// Stored pockets by CAS address
+    pockets: HashMap<ContentHash, Pocket>,
+    /// Logical access time per pocket for LRU tie-breaking
+    last_used: HashMap<ContentHash, u64>,
+    /// Monotonic logical clock
+    clock: u64,
+}
+
+impl PocketStore {
+    /// Create a store that retains at most `capacity` pockets
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            pockets: HashMap::with_capacity(capacity),
+            last_used: HashMap::with_capacity(capacity),
+            clock: 0,
+        }
+    }
+
+    /// Insert a pocket, returning the pocket evicted to stay within capacity
+    ///
+    /// Re-inserting an existing address replaces it without eviction. The
+    /// pocket being inserted is never the one evicted.
+    pub fn insert(&mut self, pocket: Pocket) -> Option<Pocket> {
+        let id = pocket.header.id;
+        self.pockets.insert(id, pocket);
+        self.touch(&id);
+
+        if self.pockets.len() <= self.capacity {
+            return None;
+        }
+
+        let victim = self.pockets.keys()
+            .filter(|candidate| **candidate != id)
+            .min_by(|a, b| {
+                self.retention_score(a)
+                    .total_cmp(&self.retention_score(b))
+                    .then_with(|| self.last_used[*a].cmp(&self.last_used[*b]))
+            })
+            .copied()?;
+
+        self.last_used.remove(&victim);
+        self.pockets.remove(&victim)
+    }
+
+    /// Look up a pocket and mark it as recently used
+    pub fn get(&mut self, id: &ContentHash) -> Option<&Pocket> {
+        if self.pockets.contains_key(id) {
+            self.touch(id);
+        }
+        self.pockets.get(id)
+    }
+
+    /// Mutable lookup (e.g. for Hebbian updates), marking it as recently used
+    pub fn get_mut(&mut self, id: &ContentHash) -> Option<&mut Pocket> {
+        if self.pockets.contains_key(id) {
+            self.touch(id);
+        }
+        self.pockets.get_mut(id)
+    }
+
+    /// Look up a pocket without affecting eviction order
+    pub fn peek(&self, id: &ContentHash) -> Option<&Pocket> {
+        self.pockets.get(id)
+    }
+
+    /// Check whether a pocket is stored
+    pub fn contains(&self, id: &ContentHash) -> bool {
+        self.pockets.contains_key(id)
+    }
+
+    /// Sum of affinities that other stored pockets hold toward `id`
+    pub fn retention_score(&self, id: &ContentHash) -> f64 {
+        self.pockets.iter()
+            .filter(|(other_id, _)| *other_id != id)
+            .filter_map(|(_, other)| other.membrane.affinity_scores.get(id))
+            .sum()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.pockets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pockets.is_empty()
+    }
+
+    fn touch(&mut self, id: &ContentHash) {
+        self.clock += 1;
+        self.last_used.insert(*id, self.clock);
+    }
+}
+
 impl Default for PocketArchitectureVerifier {
     fn default() -> Self {
         Self::new()
@@ -869,4 +978,48 @@ mod tests {
         verifier.update_affinity_hebbian(&mut pocket, other_id, InteractionResult::Failure).unwrap();
         assert_eq!(pocket.membrane.affinity_scores[&other_id], -0.9); // 0.1 + 0.1 * (-10.0)
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_pocket_store_evicts_least_recently_used_without_affinity() {
+        let mut verifier = PocketArchitectureVerifier::new();
+        let a = verifier.create_pocket("a".to_string(), SignalVector::new()).unwrap();
+        let b = verifier.create_pocket("b".to_string(), SignalVector::new()).unwrap();
+        let c = verifier.create_pocket("c".to_string(), SignalVector::new()).unwrap();
+        let (a_id, b_id, c_id) = (a.header.id, b.header.id, c.header.id);
+
+        let mut store = PocketStore::with_capacity(2);
+        assert!(store.insert(a).is_none());
+        assert!(store.insert(b).is_none());
+        store.get(&a_id);
+
+        let evicted = store.insert(c).unwrap();
+        assert_eq!(evicted.header.id, b_id);
+        assert_eq!(store.len(), 2);
+        assert!(store.contains(&a_id));
+        assert!(store.contains(&c_id));
+    }
+
+    #[test]
+    fn test_pocket_store_keeps_high_affinity_pockets() {
+        let mut verifier = PocketArchitectureVerifier::new();
+        let valued = verifier.create_pocket("valued".to_string(), SignalVector::new()).unwrap();
+        let plain = verifier.create_pocket("plain".to_string(), SignalVector::new()).unwrap();
+        let mut hub = verifier.create_pocket("hub".to_string(), SignalVector::new()).unwrap();
+        let newcomer = verifier.create_pocket("newcomer".to_string(), SignalVector::new()).unwrap();
+        let (valued_id, plain_id) = (valued.header.id, plain.header.id);
+
+        verifier.update_affinity_hebbian(&mut hub, valued_id, InteractionResult::Success).unwrap();
+
+        // `valued` is the oldest entry, so plain LRU would evict it first
+        let mut store = PocketStore::with_capacity(3);
+        store.insert(valued);
+        store.insert(plain);
+        store.insert(hub);
+        assert!(store.retention_score(&valued_id) > 0.0);
+
+        let evicted = store.insert(newcomer).unwrap();
+        assert_eq!(evicted.header.id, plain_id);
+        assert!(store.contains(&valued_id));
+        assert_eq!(store.len(), store.capacity());
+    }
+}
@@ -25,6 +25,9 @@ pub enum ProofSearchStrategy {
     Tableau,
     /// Natural deduction
     NaturalDeduction,
+    /// Backward chaining with increasing depth limits; finds the shortest
+    /// proof using memory linear in its depth
+    IterativeDeepening,
 }
 
 /// Proof search engine that coordinates different search strategies
@@ -37,6 +40,8 @@ pub struct ProofSearchEngine {
     config: SearchConfig,
     /// Search statistics
     stats: ProofSearchStats,
+    /// Steps of the last proof iterative deepening found
+    derivation: Vec<ProofStep>,
 }
 
 /// Configuration for proof search
@@ -84,6 +89,10 @@ pub struct ProofSearchStats {
     pub cache_misses: usize,
     /// Memory usage estimate
     pub memory_usage_mb: f64,
+    /// Depth of the proof found, for strategies that report it
+    pub proof_depth: Option<usize>,
+    /// Deepest level the search reached
+    pub max_depth_reached: usize,
 }
 
 /// Search context for maintaining state during proof search
@@ -122,6 +131,7 @@ impl ProofSearchEngine {
             inference_rules,
             config: SearchConfig::default(),
             stats: ProofSearchStats::default(),
+            derivation: Vec::new(),
         }
     }
 
@@ -279,6 +289,120 @@ impl ProofSearchEngine {
         Err(AispError::validation_error("Goal cannot be proven".to_string()))
     }
 
+    /// Execute iterative-deepening proof search
+    ///
+    /// Runs depth-limited backward chaining with limits 0, 1, 2, ... up to
+    /// `max_depth`, so the first proof found is a shortest one and the search
+    /// never descends past it. Only the current branch is kept in memory.
+    /// The depth of the proof is recorded in `proof_depth` and its steps
+    /// in [`derivation`](Self::derivation).
+    pub fn iterative_deepening_search(&mut self, goal: &FormulaStructure) -> AispResult<ProofOutcome> {
+        let start_time = Instant::now();
+        self.stats = ProofSearchStats::default();
+        self.derivation.clear();
+
+        for limit in 0..=self.config.max_depth {
+            let mut steps = Vec::new();
+            match self.depth_limited_search(goal, 0, limit, start_time, &mut steps) {
+                Some(true) => {
+                    self.derivation = steps;
+                    self.stats.proof_depth = Some(limit);
+                    self.stats.search_time = start_time.elapsed();
+                    return Ok(ProofOutcome::Proven);
+                }
+                Some(false) => {}
+                None => {
+                    self.stats.search_time = start_time.elapsed();
+                    return Ok(ProofOutcome::Timeout);
+                }
+            }
+        }
+
+        self.stats.search_time = start_time.elapsed();
+        Ok(ProofOutcome::Unknown)
+    }
+
+    /// Prove `goal` with at most `limit - depth` further rule applications
+    ///
+    /// On success the derivation of `goal` is appended to `steps`, premises
+    /// first, so its last step concludes `goal`. Returns `None` once the
+    /// timeout or step budget is exhausted.
+    fn depth_limited_search(
+        &mut self,
+        goal: &FormulaStructure,
+        depth: usize,
+        limit: usize,
+        start_time: Instant,
+        steps: &mut Vec<ProofStep>,
+    ) -> Option<bool> {
+        if start_time.elapsed() > self.config.timeout || self.stats.steps_explored >= self.config.max_steps {
+            return None;
+        }
+        self.stats.steps_explored += 1;
+        self.stats.max_depth_reached = self.stats.max_depth_reached.max(depth);
+
+        if let Some(axiom) = self.axioms.iter().find(|axiom| axiom.formula == *goal) {
+            steps.push(ProofStep {
+                step_id: steps.len(),
+                formula: goal.clone(),
+                justification: StepJustification::Axiom(axiom.name.clone()),
+                dependencies: vec![],
+                discharge_level: depth,
+                annotations: HashMap::new(),
+            });
+            return Some(true);
+        }
+        if depth == limit {
+            return Some(false);
+        }
+
+        for index in 0..self.inference_rules.len() {
+            let rule = &self.inference_rules[index];
+            let mut bindings = HashMap::new();
+            if !match_pattern(&rule.conclusion.pattern, goal, &mut bindings) {
+                continue;
+            }
+            // Rules whose premises mention variables the goal does not bind
+            // cannot be applied backwards
+            let Some(subgoals) = rule
+                .premises
+                .iter()
+                .map(|premise| instantiate_pattern(&premise.pattern, &bindings))
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+
+            let rule_name = rule.name.clone();
+            let mark = steps.len();
+            let mut premises = Vec::with_capacity(subgoals.len());
+            let mut proved = true;
+            for subgoal in &subgoals {
+                if !self.depth_limited_search(subgoal, depth + 1, limit, start_time, steps)? {
+                    proved = false;
+                    break;
+                }
+                premises.push(steps.len() - 1);
+            }
+            if proved {
+                self.stats.rules_applied += 1;
+                steps.push(ProofStep {
+                    step_id: steps.len(),
+                    formula: goal.clone(),
+                    justification: StepJustification::InferenceRule(rule_name, premises.clone()),
+                    dependencies: premises,
+                    discharge_level: depth,
+                    annotations: HashMap::new(),
+                });
+                return Some(true);
+            }
+            steps.truncate(mark);
+            self.stats.backtrack_count += 1;
+        }
+
+        Some(false)
+    }
+
     /// Execute forward chaining proof search
     pub fn forward_chaining_search(&mut self, goal: &FormulaStructure) -> AispResult<ProofOutcome> {
         let start_time = Instant::now();
@@ -445,6 +569,69 @@ impl ProofSearchEngine {
     pub fn get_stats(&self) -> &ProofSearchStats {
         &self.stats
     }
+
+    /// Steps of the proof the last successful iterative-deepening search
+    /// found, premises before the steps that use them
+    pub fn derivation(&self) -> &[ProofStep] {
+        &self.derivation
+    }
+}
+
+/// Match a rule pattern against a formula, extending `bindings`
+pub(crate) fn match_pattern(
+    pattern: &PatternStructure,
+    formula: &FormulaStructure,
+    bindings: &mut HashMap<String, FormulaStructure>,
+) -> bool {
+    match (pattern, formula) {
+        (PatternStructure::Variable(name), _) => match bindings.get(name) {
+            Some(bound) => bound == formula,
+            None => {
+                bindings.insert(name.clone(), formula.clone());
+                true
+            }
+        },
+        (PatternStructure::Literal(expected), _) => expected == formula,
+        (PatternStructure::Conjunction(patterns), FormulaStructure::Conjunction(formulas))
+        | (PatternStructure::Disjunction(patterns), FormulaStructure::Disjunction(formulas)) => {
+            patterns.len() == formulas.len()
+                && patterns
+                    .iter()
+                    .zip(formulas)
+                    .all(|(pattern, formula)| match_pattern(pattern, formula, bindings))
+        }
+        (PatternStructure::Implication(premise, conclusion), FormulaStructure::Implication(left, right)) => {
+            match_pattern(premise, left, bindings) && match_pattern(conclusion, right, bindings)
+        }
+        (PatternStructure::Quantified(QuantifierKind::Universal, _, body), FormulaStructure::Universal(_, inner))
+        | (PatternStructure::Quantified(QuantifierKind::Existential, _, body), FormulaStructure::Existential(_, inner)) => {
+            match_pattern(body, inner, bindings)
+        }
+        _ => false,
+    }
+}
+
+/// Build the formula a pattern denotes under `bindings`
+///
+/// Quantified patterns and unbound variables have no single instance.
+fn instantiate_pattern(
+    pattern: &PatternStructure,
+    bindings: &HashMap<String, FormulaStructure>,
+) -> Option<FormulaStructure> {
+    let all = |patterns: &[PatternStructure]| {
+        patterns.iter().map(|pattern| instantiate_pattern(pattern, bindings)).collect::<Option<Vec<_>>>()
+    };
+    match pattern {
+        PatternStructure::Variable(name) => bindings.get(name).cloned(),
+        PatternStructure::Literal(formula) => Some(formula.clone()),
+        PatternStructure::Conjunction(patterns) => Some(FormulaStructure::Conjunction(all(patterns)?)),
+        PatternStructure::Disjunction(patterns) => Some(FormulaStructure::Disjunction(all(patterns)?)),
+        PatternStructure::Implication(premise, conclusion) => Some(FormulaStructure::Implication(
+            Box::new(instantiate_pattern(premise, bindings)?),
+            Box::new(instantiate_pattern(conclusion, bindings)?),
+        )),
+        PatternStructure::Quantified(..) => None,
+    }
 }
 
 impl SearchContext {
@@ -519,6 +706,93 @@ mod tests {
         assert_eq!(context.depth, 0);
     }
 
+    fn atom(name: &str) -> FormulaStructure {
+        FormulaStructure::Atomic(AtomicFormula {
+            predicate: name.to_string(),
+            terms: vec![],
+            type_signature: None,
+        })
+    }
+
+    fn axiom(formula: FormulaStructure) -> Axiom {
+        Axiom {
+            name: format!("{:?}", formula),
+            formula,
+            axiom_type: AxiomType::Logical,
+            priority: 5,
+        }
+    }
+
+    fn variable(name: &str) -> FormulaPattern {
+        FormulaPattern { pattern: PatternStructure::Variable(name.to_string()), variables: HashMap::new() }
+    }
+
+    fn rules() -> Vec<InferenceRule> {
+        vec![
+            // Applies to every goal without making progress
+            InferenceRule::new("repeat".to_string(), RuleType::Structural, vec![variable("P")], variable("P"), vec![], 1),
+            InferenceRule::new(
+                "and_intro".to_string(),
+                RuleType::Introduction,
+                vec![variable("P"), variable("Q")],
+                FormulaPattern {
+                    pattern: PatternStructure::Conjunction(vec![
+                        PatternStructure::Variable("P".to_string()),
+                        PatternStructure::Variable("Q".to_string()),
+                    ]),
+                    variables: HashMap::new(),
+                },
+                vec![],
+                8,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_iterative_deepening_finds_depth_two_proof() {
+        let mut engine = ProofSearchEngine::new(vec![axiom(atom("A")), axiom(atom("B")), axiom(atom("C"))], rules());
+        let goal = FormulaStructure::Conjunction(vec![
+            FormulaStructure::Conjunction(vec![atom("A"), atom("B")]),
+            atom("C"),
+        ]);
+
+        assert_eq!(engine.iterative_deepening_search(&goal).unwrap(), ProofOutcome::Proven);
+
+        let stats = engine.get_stats();
+        assert_eq!(stats.proof_depth, Some(2));
+        assert_eq!(stats.max_depth_reached, 2);
+        assert!(stats.max_depth_reached < engine.config.max_depth);
+    }
+
+    #[test]
+    fn test_iterative_deepening_prefers_shallowest_proof() {
+        let shortcut = FormulaStructure::Conjunction(vec![atom("A"), atom("B")]);
+        let axioms = vec![axiom(atom("A")), axiom(atom("B")), axiom(atom("C")), axiom(shortcut.clone())];
+        let mut engine = ProofSearchEngine::new(axioms, rules());
+        let goal = FormulaStructure::Conjunction(vec![shortcut, atom("C")]);
+
+        assert_eq!(engine.iterative_deepening_search(&goal).unwrap(), ProofOutcome::Proven);
+        assert_eq!(engine.get_stats().proof_depth, Some(1));
+
+        let derivation = engine.derivation();
+        assert_eq!(derivation.len(), 3);
+        assert_eq!(derivation[2].formula, goal);
+        assert_eq!(
+            derivation[2].justification,
+            StepJustification::InferenceRule("and_intro".to_string(), vec![0, 1])
+        );
+    }
+
+    #[test]
+    fn test_iterative_deepening_gives_up_at_max_depth() {
+        let config = SearchConfig { max_depth: 4, ..SearchConfig::default() };
+        let mut engine = ProofSearchEngine::new(vec![axiom(atom("A"))], rules()).with_config(config);
+
+        assert_eq!(engine.iterative_deepening_search(&atom("B")).unwrap(), ProofOutcome::Unknown);
+        assert_eq!(engine.get_stats().proof_depth, None);
+        assert_eq!(engine.get_stats().max_depth_reached, 4);
+    }
+
     #[test]
     fn test_proof_search_stats_default() {
         let stats = ProofSearchStats::default();
@@ -3,10 +3,14 @@
 //! This module defines the core data structures for formal proofs,
 //! proof results, and counterexamples used in theorem proving.
 
+use crate::incompleteness_handler::UndecidabilityReason;
 use crate::property_types::*;
 use std::collections::HashMap;
 use std::time::Duration;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Complete proof result
 #[derive(Debug, Clone)]
 pub struct ProofResult {
@@ -35,12 +39,16 @@ pub enum ProofOutcome {
     Timeout,
     /// Unknown (no proof found within limits)
     Unknown,
+    /// Neither provable nor refutable: the property has a known-hard or
+    /// self-referential form, so further search would not settle it
+    Undecidable(UndecidabilityReason),
     /// Error during proof search
     Error(String),
 }
 
 /// Formal proof representation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FormalProof {
     /// Proven formula
     pub conclusion: PropertyFormula,
@@ -59,7 +67,8 @@ pub struct FormalProof {
 }
 
 /// Single proof step in natural deduction
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProofStep {
     /// Step number
     pub step_id: usize,
@@ -76,7 +85,8 @@ pub struct ProofStep {
 }
 
 /// Step justification types
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum StepJustification {
     /// Assumption introduction
     Assumption,
@@ -95,7 +105,8 @@ pub enum StepJustification {
 }
 
 /// Proof tree for structural representation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProofTree {
     /// Root formula (conclusion)
     pub root: FormulaStructure,
@@ -108,7 +119,8 @@ pub struct ProofTree {
 }
 
 /// Proof complexity metrics
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProofComplexity {
     /// Total number of steps
     pub step_count: usize,
@@ -207,6 +219,11 @@ impl ProofResult {
     pub fn is_conclusive(&self) -> bool {
         matches!(self.outcome, ProofOutcome::Proven | ProofOutcome::Disproven)
     }
+
+    /// Check if the property was classified as independent of the system
+    pub fn is_undecidable(&self) -> bool {
+        matches!(self.outcome, ProofOutcome::Undecidable(_))
+    }
 }
 
 impl FormalProof {
@@ -282,6 +299,21 @@ impl FormalProof {
     }
 }
 
+#[cfg(feature = "binary-proofs")]
+impl FormalProof {
+    /// Encode the proof in a compact binary form, for caching and transport
+    pub fn to_bytes(&self) -> crate::error::AispResult<Vec<u8>> {
+        bincode::serialize(self)
+            .map_err(|error| crate::error::AispError::internal_error(format!("Proof encoding failed: {}", error)))
+    }
+
+    /// Decode a proof produced by [`FormalProof::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> crate::error::AispResult<Self> {
+        bincode::deserialize(bytes)
+            .map_err(|error| crate::error::AispError::internal_error(format!("Invalid binary proof: {}", error)))
+    }
+}
+
 impl ProofTree {
     /// Create leaf node
     pub fn leaf(formula: FormulaStructure) -> Self {
@@ -425,6 +457,68 @@ mod tests {
         assert_eq!(tree.depth(), 2);
     }
 
+    #[cfg(feature = "binary-proofs")]
+    #[test]
+    fn test_binary_round_trip_of_multi_step_proof() {
+        let atom = |predicate: &str, variable: &str| FormulaStructure::Atomic(AtomicFormula {
+            predicate: predicate.to_string(),
+            terms: vec![Term::Variable(variable.to_string(), Some("State".to_string()))],
+            type_signature: Some(TypeSignature { inputs: vec!["State".to_string()], output: "Bool".to_string() }),
+        });
+        let premise = FormulaStructure::Implication(Box::new(atom("Ready", "s")), Box::new(atom("Valid", "s")));
+        let step = |step_id, formula: &FormulaStructure, justification, dependencies| ProofStep {
+            step_id,
+            formula: formula.clone(),
+            justification,
+            dependencies,
+            discharge_level: 0,
+            annotations: HashMap::from([("source".to_string(), format!("step {}", step_id))]),
+        };
+
+        let mut tree = ProofTree::node(
+            atom("Valid", "s"),
+            "modus_ponens".to_string(),
+            vec![ProofTree::leaf(premise.clone()), ProofTree::leaf(atom("Ready", "s"))],
+        );
+        tree.annotations.insert("goal".to_string(), "Valid(s)".to_string());
+        let mut proof = FormalProof {
+            conclusion: PropertyFormula {
+                structure: atom("Valid", "s"),
+                quantifiers: vec![Quantifier { variable: "s".to_string(), variable_type: Some("State".to_string()), domain: None }],
+                free_variables: std::collections::HashSet::new(),
+                predicates: ["Valid".to_string(), "Ready".to_string()].into(),
+                functions: std::collections::HashSet::new(),
+                constants: std::collections::HashSet::new(),
+            },
+            steps: vec![
+                step(0, &premise, StepJustification::Axiom("readiness".to_string()), vec![]),
+                step(1, &atom("Ready", "s"), StepJustification::Assumption, vec![]),
+                step(2, &atom("Valid", "s"), StepJustification::InferenceRule("modus_ponens".to_string(), vec![0, 1]), vec![0, 1]),
+            ],
+            axioms_used: vec!["readiness".to_string()],
+            rules_applied: vec!["modus_ponens".to_string()],
+            proof_tree: tree,
+            is_valid: true,
+            complexity: ProofComplexity {
+                step_count: 0,
+                max_depth: 0,
+                assumption_count: 0,
+                rule_applications: 0,
+                branching_factor: 0.0,
+                complexity_score: 0,
+            },
+        };
+        proof.calculate_complexity();
+
+        let bytes = proof.to_bytes().unwrap();
+        let decoded = FormalProof::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, proof);
+        assert_eq!(decoded.proof_tree.depth(), 2);
+        assert!(decoded.validate());
+
+        assert!(FormalProof::from_bytes(&bytes[..bytes.len() / 2]).is_err());
+    }
+
     #[test]
     fn test_counterexample_creation() {
         let mut counterexample = Counterexample::new();
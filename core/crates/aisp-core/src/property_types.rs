@@ -5,6 +5,9 @@
 
 use std::collections::{HashMap, HashSet};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// Extracted formal property ready for verification
 #[derive(Debug, Clone)]
 pub struct ExtractedProperty {
@@ -49,8 +52,46 @@ pub enum PropertyType {
     DependencyConstraint,
 }
 
+impl PropertyType {
+    /// Every property type, in declaration order
+    pub const ALL: [PropertyType; 10] = [
+        PropertyType::TypeSafety,
+        PropertyType::FunctionalCorrectness,
+        PropertyType::TemporalSafety,
+        PropertyType::TemporalLiveness,
+        PropertyType::RelationalConstraint,
+        PropertyType::SetMembership,
+        PropertyType::ArithmeticConstraint,
+        PropertyType::LogicalAssertion,
+        PropertyType::StructuralInvariant,
+        PropertyType::DependencyConstraint,
+    ];
+
+    /// Short kebab-case name used to select properties on the command line
+    pub fn name(&self) -> &'static str {
+        match self {
+            PropertyType::TypeSafety => "type-safety",
+            PropertyType::FunctionalCorrectness => "functional-correctness",
+            PropertyType::TemporalSafety => "safety",
+            PropertyType::TemporalLiveness => "liveness",
+            PropertyType::RelationalConstraint => "relational",
+            PropertyType::SetMembership => "set-membership",
+            PropertyType::ArithmeticConstraint => "arithmetic",
+            PropertyType::LogicalAssertion => "logical-assertion",
+            PropertyType::StructuralInvariant => "structural-invariant",
+            PropertyType::DependencyConstraint => "dependency",
+        }
+    }
+
+    /// Property type with the given `name`
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|property_type| property_type.name() == name)
+    }
+}
+
 /// Mathematical formula representation
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PropertyFormula {
     /// Logical structure of the formula
     pub structure: FormulaStructure,
@@ -68,6 +109,7 @@ pub struct PropertyFormula {
 
 /// Logical structure of formulas
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FormulaStructure {
     /// Atomic proposition (P(x))
     Atomic(AtomicFormula),
@@ -91,6 +133,10 @@ pub enum FormulaStructure {
     TemporalEventually(Box<FormulaStructure>),
     /// Temporal until (P U Q)
     TemporalUntil(Box<FormulaStructure>, Box<FormulaStructure>),
+    /// CTL universal path quantifier (A φ): φ holds on every path
+    PathForall(Box<FormulaStructure>),
+    /// CTL existential path quantifier (E φ): φ holds on some path
+    PathExists(Box<FormulaStructure>),
     /// Arithmetic equality (x = y)
     ArithmeticEqual(Term, Term),
     /// Arithmetic inequality (x ≤ y)
@@ -103,6 +149,7 @@ pub enum FormulaStructure {
 
 /// Atomic formula (predicate application)
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AtomicFormula {
     pub predicate: String,
     pub terms: Vec<Term>,
@@ -111,6 +158,7 @@ pub struct AtomicFormula {
 
 /// Mathematical terms
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Term {
     /// Variable (x)
     Variable(String, Option<String>), // name, type
@@ -128,6 +176,7 @@ pub enum Term {
 
 /// Arithmetic operations
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ArithmeticOp {
     Add,
     Subtract,
@@ -139,6 +188,7 @@ pub enum ArithmeticOp {
 
 /// Quantifier information
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Quantifier {
     /// Variable being quantified
     pub variable: String,
@@ -150,6 +200,7 @@ pub struct Quantifier {
 
 /// Type signature for predicates and functions
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TypeSignature {
     /// Input types
     pub inputs: Vec<String>,
@@ -13,6 +13,7 @@ use crate::{
     temporal_logic_solver::TemporalLogicSolver,
 };
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
 use std::time::{Duration, Instant};
 
 /// Comprehensive state machine analysis result
@@ -28,6 +29,8 @@ pub struct StateMachineAnalysis {
     pub protocol_compliance: ProtocolCompliance,
     /// Performance characteristics
     pub performance_metrics: StateMachinePerformance,
+    /// Unreachable and dead-end states, per machine
+    pub defects: Vec<StateMachineDefect>,
     /// Verification warnings and issues
     pub warnings: Vec<String>,
 }
@@ -57,6 +60,65 @@ pub struct ProtocolStateMachine {
     pub protocol_domain: Option<String>,
 }
 
+/// Structural defect of a single state machine
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateMachineDefect {
+    /// State that no path from the initial state reaches
+    UnreachableState { machine: String, state: String },
+    /// Non-final state without outgoing transitions
+    DeadEndState { machine: String, state: String },
+}
+
+impl fmt::Display for StateMachineDefect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StateMachineDefect::UnreachableState { machine, state } => {
+                write!(f, "State `{}` in machine `{}` is unreachable from the initial state", state, machine)
+            }
+            StateMachineDefect::DeadEndState { machine, state } => {
+                write!(f, "State `{}` in machine `{}` has no outgoing transitions and is not final", state, machine)
+            }
+        }
+    }
+}
+
+impl ProtocolStateMachine {
+    /// States reachable from the initial state, including the initial state
+    pub fn reachable_states(&self) -> HashSet<String> {
+        ProtocolStateMachineAnalyzer::analyze_reachability(std::slice::from_ref(self)).reachable_states
+    }
+
+    /// States no path from the initial state reaches, sorted
+    pub fn unreachable_states(&self) -> Vec<String> {
+        let mut states: Vec<String> = ProtocolStateMachineAnalyzer::analyze_reachability(std::slice::from_ref(self))
+            .unreachable_states
+            .into_iter()
+            .collect();
+        states.sort();
+        states
+    }
+
+    /// Non-final states without outgoing transitions, sorted
+    pub fn dead_end_states(&self) -> Vec<String> {
+        let mut states: Vec<String> = ProtocolStateMachineAnalyzer::deadlock_states(self).cloned().collect();
+        states.sort();
+        states
+    }
+
+    /// Unreachable states followed by dead ends
+    pub fn defects(&self) -> Vec<StateMachineDefect> {
+        let unreachable = self.unreachable_states().into_iter().map(|state| StateMachineDefect::UnreachableState {
+            machine: self.id.clone(),
+            state,
+        });
+        let dead_ends = self.dead_end_states().into_iter().map(|state| StateMachineDefect::DeadEndState {
+            machine: self.id.clone(),
+            state,
+        });
+        unreachable.chain(dead_ends).collect()
+    }
+}
+
 /// State transition definition
 #[derive(Debug, Clone, PartialEq)]
 pub struct StateTransition {
@@ -366,15 +428,27 @@ impl ProtocolStateMachineAnalyzer {
                 liveness_analysis: LivenessAnalysis::empty(),
                 protocol_compliance: ProtocolCompliance::empty(),
                 performance_metrics: StateMachinePerformance::empty(start_time.elapsed()),
+                defects: vec![],
                 warnings: vec!["No state machines found in document".to_string()],
             });
         }
 
         let mut warnings = Vec::new();
 
+        // Report structural defects by machine and state
+        let defects: Vec<StateMachineDefect> = state_machines
+            .iter()
+            .flat_map(ProtocolStateMachine::defects)
+            .filter(|defect| match defect {
+                StateMachineDefect::UnreachableState { .. } => self.config.enable_reachability,
+                StateMachineDefect::DeadEndState { .. } => self.config.enable_deadlock_detection,
+            })
+            .collect();
+        warnings.extend(defects.iter().map(|defect| defect.to_string()));
+
         // Perform reachability analysis
         let reachability = if self.config.enable_reachability {
-            self.analyze_reachability(&state_machines)?
+            Self::analyze_reachability(&state_machines)
         } else {
             ReachabilityAnalysis::empty()
         };
@@ -407,6 +481,7 @@ impl ProtocolStateMachineAnalyzer {
             liveness_analysis,
             protocol_compliance,
             performance_metrics,
+            defects,
             warnings,
         })
     }
@@ -486,7 +561,7 @@ impl ProtocolStateMachineAnalyzer {
     }
 
     /// Perform reachability analysis on state machines
-    fn analyze_reachability(&self, machines: &[ProtocolStateMachine]) -> AispResult<ReachabilityAnalysis> {
+    fn analyze_reachability(machines: &[ProtocolStateMachine]) -> ReachabilityAnalysis {
         let mut all_reachable = HashSet::new();
         let mut all_unreachable = HashSet::new();
         let mut reachability_graph = HashMap::new();
@@ -532,21 +607,21 @@ impl ProtocolStateMachineAnalyzer {
         }
 
         // Detect cycles and strongly connected components
-        let cycles = self.detect_cycles(machines);
-        let strongly_connected_components = self.find_strongly_connected_components(machines);
+        let cycles = Self::detect_cycles(machines);
+        let strongly_connected_components = Self::find_strongly_connected_components(machines);
 
-        Ok(ReachabilityAnalysis {
+        ReachabilityAnalysis {
             reachable_states: all_reachable,
             unreachable_states: all_unreachable,
             strongly_connected_components,
             reachability_graph,
             state_distances,
             cycles,
-        })
+        }
     }
 
     /// Detect cycles in state machines
-    fn detect_cycles(&self, machines: &[ProtocolStateMachine]) -> Vec<Vec<String>> {
+    fn detect_cycles(machines: &[ProtocolStateMachine]) -> Vec<Vec<String>> {
         let mut cycles = Vec::new();
         
         for machine in machines {
@@ -556,7 +631,7 @@ impl ProtocolStateMachineAnalyzer {
             
             for state in &machine.states {
                 if !visited.contains(state) {
-                    if let Some(cycle) = self.dfs_cycle_detection(
+                    if let Some(cycle) = Self::dfs_cycle_detection(
                         state,
                         machine,
                         &mut visited,
@@ -573,7 +648,6 @@ impl ProtocolStateMachineAnalyzer {
 
     /// DFS-based cycle detection
     fn dfs_cycle_detection(
-        &self,
         state: &str,
         machine: &ProtocolStateMachine,
         visited: &mut HashSet<String>,
@@ -585,7 +659,7 @@ impl ProtocolStateMachineAnalyzer {
         for transition in &machine.transitions {
             if transition.from_state == state {
                 if !visited.contains(&transition.to_state) {
-                    if let Some(cycle) = self.dfs_cycle_detection(
+                    if let Some(cycle) = Self::dfs_cycle_detection(
                         &transition.to_state,
                         machine,
                         visited,
@@ -605,7 +679,7 @@ impl ProtocolStateMachineAnalyzer {
     }
 
     /// Find strongly connected components using Tarjan's algorithm
-    fn find_strongly_connected_components(&self, machines: &[ProtocolStateMachine]) -> Vec<Vec<String>> {
+    fn find_strongly_connected_components(machines: &[ProtocolStateMachine]) -> Vec<Vec<String>> {
         // Simplified implementation - would use Tarjan's algorithm
         let mut components = Vec::new();
         
@@ -618,6 +692,13 @@ impl ProtocolStateMachineAnalyzer {
         components
     }
 
+    /// Non-final states of `machine` without outgoing transitions
+    fn deadlock_states(machine: &ProtocolStateMachine) -> impl Iterator<Item = &String> {
+        machine.states.iter().filter(move |state| {
+            !machine.final_states.contains(*state) && !machine.transitions.iter().any(|t| &t.from_state == *state)
+        })
+    }
+
     /// Analyze liveness properties
     fn analyze_liveness(&mut self, machines: &[ProtocolStateMachine], _reachability: &ReachabilityAnalysis) -> AispResult<LivenessAnalysis> {
         let mut deadlock_states = HashSet::new();
@@ -627,14 +708,7 @@ impl ProtocolStateMachineAnalyzer {
 
         for machine in machines {
             // Detect deadlock states (states with no outgoing transitions)
-            for state in &machine.states {
-                let has_outgoing = machine.transitions.iter()
-                    .any(|t| t.from_state == *state);
-                
-                if !has_outgoing && !machine.final_states.contains(state) {
-                    deadlock_states.insert(state.clone());
-                }
-            }
+            deadlock_states.extend(Self::deadlock_states(machine).cloned());
 
             // Create basic liveness properties
             for final_state in &machine.final_states {
@@ -878,6 +952,7 @@ mod tests {
                     span: Some(Span::new(0, 0, 1, 1)),
                 }),
             ],
+            used_symbols: Default::default(),
             span: Some(Span::new(0, 0, 1, 1)),
         }
     }
@@ -941,6 +1016,65 @@ mod tests {
         assert_eq!(timing.deadline, Some(Duration::from_secs(5)));
     }
 
+    fn machine(states: &[&str], finals: &[&str], transitions: &[(&str, &str)]) -> ProtocolStateMachine {
+        ProtocolStateMachine {
+            id: "door".to_string(),
+            name: "Door".to_string(),
+            states: states.iter().map(|s| s.to_string()).collect(),
+            initial_state: states[0].to_string(),
+            final_states: finals.iter().map(|s| s.to_string()).collect(),
+            transitions: transitions
+                .iter()
+                .map(|(from, to)| StateTransition {
+                    from_state: from.to_string(),
+                    to_state: to.to_string(),
+                    trigger: TransitionTrigger::Completion,
+                    guard: None,
+                    action: None,
+                    priority: 0,
+                    timing: None,
+                })
+                .collect(),
+            state_invariants: HashMap::new(),
+            transition_conditions: HashMap::new(),
+            machine_type: StateMachineType::DeterministicFinite,
+            protocol_domain: None,
+        }
+    }
+
+    #[test]
+    fn test_unreachable_and_dead_end_states_are_reported() {
+        let machine = machine(
+            &["Closed", "Open", "Jammed", "Locked", "Broken"],
+            &["Locked"],
+            &[("Closed", "Open"), ("Open", "Closed"), ("Open", "Jammed"), ("Closed", "Locked"), ("Broken", "Closed")],
+        );
+
+        assert_eq!(
+            machine.defects(),
+            vec![
+                StateMachineDefect::UnreachableState { machine: "door".to_string(), state: "Broken".to_string() },
+                StateMachineDefect::DeadEndState { machine: "door".to_string(), state: "Jammed".to_string() },
+            ]
+        );
+        assert_eq!(
+            machine.defects()[1].to_string(),
+            "State `Jammed` in machine `door` has no outgoing transitions and is not final"
+        );
+    }
+
+    #[test]
+    fn test_well_formed_machine_has_no_defects() {
+        let machine = machine(
+            &["Closed", "Open", "Locked"],
+            &["Locked"],
+            &[("Closed", "Open"), ("Open", "Closed"), ("Closed", "Locked")],
+        );
+
+        assert!(machine.defects().is_empty());
+        assert_eq!(machine.reachable_states().len(), 3);
+    }
+
     #[test]
     fn test_violation_severity_ordering() {
         assert!(ViolationSeverity::Critical > ViolationSeverity::High);
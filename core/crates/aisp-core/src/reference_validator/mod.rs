@@ -297,6 +297,7 @@ mod tests {
                 protocol: None,
             },
             blocks: vec![],
+            used_symbols: Default::default(),
             span: Some(Span::new(0, 0, 1, 1)),
         }
     }
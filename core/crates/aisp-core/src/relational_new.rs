@@ -5,7 +5,7 @@
 
 use crate::ast::canonical::*;
 use crate::error::*;
-use crate::constraint_solver::{ConstraintSolver, ConstraintAnalysisResult};
+use crate::constraint_solver::{ConstraintSolver, ConstraintAnalysisResult, Satisfiability};
 use crate::conflict_detector::{ConflictDetector, ConflictDetectionResult};
 use std::collections::HashMap;
 
@@ -267,13 +267,29 @@ impl RelationalAnalyzer {
         let mut contradictions = Vec::new();
         let mut contradiction_id = 0;
 
-        // Check for unsatisfiable constraint sets
-        if !constraint_analysis.unsatisfied.is_empty() {
+        // Check for unsatisfiable constraint sets, citing the minimal
+        // conflicting subset when the solver found one; a search that ran
+        // out of budget proves nothing either way
+        if !constraint_analysis.unsatisfied.is_empty() && constraint_analysis.satisfiability != Satisfiability::Unknown {
+            let (constraints, proof) = if constraint_analysis.unsat_core.is_empty() {
+                (
+                    constraint_analysis.unsatisfied.clone(),
+                    "Constraint solver found unsatisfiable constraints".to_string(),
+                )
+            } else {
+                (
+                    constraint_analysis.unsat_core.clone(),
+                    format!(
+                        "No assignment satisfies {} together",
+                        constraint_analysis.unsat_core.join(", ")
+                    ),
+                )
+            };
             contradictions.push(LogicalContradiction {
                 id: format!("lc_{}", contradiction_id),
-                constraints: constraint_analysis.unsatisfied.clone(),
+                constraints,
                 explanation: format!("Constraint system has {} unsatisfied constraints", constraint_analysis.unsatisfied.len()),
-                proof: Some("Constraint solver found unsatisfiable constraints".to_string()),
+                proof: Some(proof),
                 location: None,
             });
             contradiction_id += 1;
@@ -448,6 +464,7 @@ mod tests {
                 protocol: None,
             },
             blocks: vec![],
+            used_symbols: Default::default(),
             span: create_test_span(),
         };
 
@@ -509,6 +526,9 @@ mod tests {
             unsatisfied: vec![],
             conflicts: vec![],
             satisfaction_score: 0.9,
+            witness: None,
+            unsat_core: vec![],
+            satisfiability: Satisfiability::Satisfiable,
         };
         
         let type_violations = vec![];
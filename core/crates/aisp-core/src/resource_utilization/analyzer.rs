@@ -522,6 +522,7 @@ mod tests {
                 protocol: None,
             },
             blocks: Vec::new(),
+            used_symbols: Default::default(),
             span: None,
         };
         
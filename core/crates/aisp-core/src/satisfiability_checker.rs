@@ -11,6 +11,7 @@ use crate::{
     smt_types::{SmtFormula, SmtSort, SmtCommand},
 };
 use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 
 /// Result of satisfiability checking
 #[derive(Debug, Clone)]
@@ -23,6 +24,24 @@ pub enum SatisfiabilityResult {
     Unknown(String),
 }
 
+/// Result of adding invariants to the constraint system one at a time
+#[derive(Debug, Clone)]
+pub enum IncrementalResult {
+    /// Every invariant was added and the set stays satisfiable
+    Satisfiable(ConstraintModel),
+    /// Adding the invariant at `index` made the set unsatisfiable
+    Unsatisfiable {
+        index: usize,
+        invariant_id: String,
+        proof: UnsatisfiabilityProof,
+    },
+    /// Checking stopped after `checked` invariants without a verdict
+    Unknown {
+        checked: usize,
+        reason: String,
+    },
+}
+
 /// Model showing satisfying assignments for variables
 #[derive(Debug, Clone, PartialEq)]
 pub struct ConstraintModel {
@@ -110,19 +129,75 @@ impl SatisfiabilityChecker {
         Self { config }
     }
 
-    /// Check satisfiability of discovered invariants
+    /// Check satisfiability of discovered invariants within the configured timeout
     pub fn check_invariants(&self, invariants: &[DiscoveredInvariant]) 
         -> AispResult<SatisfiabilityResult> 
+    {
+        self.check_invariants_within(invariants, self.default_timeout())
+    }
+
+    /// Check satisfiability of discovered invariants, giving up with
+    /// `Unknown` once `timeout` has elapsed
+    pub fn check_invariants_within(&self, invariants: &[DiscoveredInvariant], timeout: Duration)
+        -> AispResult<SatisfiabilityResult>
     {
         if invariants.is_empty() {
             return Ok(SatisfiabilityResult::Satisfiable(ConstraintModel::empty()));
         }
+        let deadline = Instant::now() + timeout;
 
         // Convert invariants to constraint system
         let constraint_system = self.build_constraint_system(invariants)?;
+        if Instant::now() >= deadline {
+            return Ok(SatisfiabilityResult::Unknown(Self::timeout_reason(timeout)));
+        }
         
         // Check satisfiability using SMT solver
-        self.solve_constraint_system(&constraint_system)
+        self.solve_constraint_system(&constraint_system, deadline, timeout)
+    }
+
+    /// Add invariants one at a time, stopping at the first that makes the
+    /// set unsatisfiable
+    ///
+    /// This localizes the culprit in a large invariant set: every invariant
+    /// before the reported one is jointly satisfiable.
+    pub fn check_invariants_incrementally(&self, invariants: &[DiscoveredInvariant], timeout: Duration)
+        -> AispResult<IncrementalResult>
+    {
+        let deadline = Instant::now() + timeout;
+        let mut system = ConstraintSystem::empty();
+        let mut model = ConstraintModel::empty();
+
+        for (index, invariant) in invariants.iter().enumerate() {
+            if Instant::now() >= deadline {
+                return Ok(IncrementalResult::Unknown { checked: index, reason: Self::timeout_reason(timeout) });
+            }
+            self.add_invariant(&mut system, invariant)?;
+
+            match self.solve_constraint_system(&system, deadline, timeout)? {
+                SatisfiabilityResult::Satisfiable(next) => model = next,
+                SatisfiabilityResult::Unsatisfiable(proof) => {
+                    return Ok(IncrementalResult::Unsatisfiable {
+                        index,
+                        invariant_id: invariant.id.clone(),
+                        proof,
+                    });
+                }
+                SatisfiabilityResult::Unknown(reason) => {
+                    return Ok(IncrementalResult::Unknown { checked: index, reason });
+                }
+            }
+        }
+
+        Ok(IncrementalResult::Satisfiable(model))
+    }
+
+    fn default_timeout(&self) -> Duration {
+        Duration::from_secs(self.config.timeout_seconds as u64)
+    }
+
+    fn timeout_reason(timeout: Duration) -> String {
+        format!("Timed out after {}ms", timeout.as_millis())
     }
 
     /// Check satisfiability of a specific formula
@@ -130,7 +205,8 @@ impl SatisfiabilityChecker {
         -> AispResult<SatisfiabilityResult> 
     {
         let constraint_system = ConstraintSystem::from_formula(formula.clone())?;
-        self.solve_constraint_system(&constraint_system)
+        let timeout = self.default_timeout();
+        self.solve_constraint_system(&constraint_system, Instant::now() + timeout, timeout)
     }
 
     /// Check consistency between invariants
@@ -155,28 +231,23 @@ impl SatisfiabilityChecker {
     fn build_constraint_system(&self, invariants: &[DiscoveredInvariant]) 
         -> AispResult<ConstraintSystem> 
     {
-        let mut constraints = Vec::new();
-        let mut variables = HashSet::new();
-        let mut functions = HashSet::new();
-        let mut predicates = HashSet::new();
-
+        let mut system = ConstraintSystem::empty();
         for invariant in invariants {
-            // Extract constraints from formula
-            let constraint = self.formula_to_constraint(&invariant.formula)?;
-            constraints.push(constraint);
-
-            // Collect symbols
-            variables.extend(invariant.formula.free_variables.iter().cloned());
-            functions.extend(invariant.formula.functions.iter().cloned());
-            predicates.extend(invariant.formula.predicates.iter().cloned());
+            self.add_invariant(&mut system, invariant)?;
         }
+        Ok(system)
+    }
 
-        Ok(ConstraintSystem {
-            constraints,
-            variables,
-            functions,
-            predicates,
-        })
+    fn add_invariant(&self, system: &mut ConstraintSystem, invariant: &DiscoveredInvariant) -> AispResult<()> {
+        // Extract constraints from formula
+        let constraint = self.formula_to_constraint(&invariant.formula)?;
+        system.constraints.push(constraint);
+
+        // Collect symbols
+        system.variables.extend(invariant.formula.free_variables.iter().cloned());
+        system.functions.extend(invariant.formula.functions.iter().cloned());
+        system.predicates.extend(invariant.formula.predicates.iter().cloned());
+        Ok(())
     }
 
     fn formula_to_constraint(&self, formula: &PropertyFormula) 
@@ -299,14 +370,17 @@ impl SatisfiabilityChecker {
         }
     }
 
-    fn solve_constraint_system(&self, system: &ConstraintSystem) 
+    /// Solve `system`, giving up with `Unknown` once `deadline`, set
+    /// `timeout` after the check started, passes
+    fn solve_constraint_system(&self, system: &ConstraintSystem, deadline: Instant, timeout: Duration) 
         -> AispResult<SatisfiabilityResult> 
     {
         // Generate SMT-LIB commands for the constraint system
         let smt_commands = self.generate_smt_commands(system)?;
         
         // For now, simulate SMT solving (in practice would call Z3)
-        self.simulate_smt_solving(&smt_commands, system)
+        let result = self.simulate_smt_solving(&smt_commands, system, deadline)?;
+        Ok(result.unwrap_or_else(|| SatisfiabilityResult::Unknown(Self::timeout_reason(timeout))))
     }
 
     fn generate_smt_commands(&self, system: &ConstraintSystem) 
@@ -410,15 +484,19 @@ impl SatisfiabilityChecker {
         }
     }
 
-    fn simulate_smt_solving(&self, _commands: &[SmtCommand], system: &ConstraintSystem) 
-        -> AispResult<SatisfiabilityResult> 
+    /// Simulated solve, or `None` when `deadline` passes first
+    fn simulate_smt_solving(&self, _commands: &[SmtCommand], system: &ConstraintSystem, deadline: Instant) 
+        -> AispResult<Option<SatisfiabilityResult>> 
     {
         // Simplified satisfiability check
         // In practice, this would invoke Z3 or another SMT solver
         
         // Check for obvious contradictions
-        if self.has_trivial_contradiction(system) {
-            return Ok(SatisfiabilityResult::Unsatisfiable(
+        let Some(contradiction) = self.has_trivial_contradiction(system, deadline) else {
+            return Ok(None);
+        };
+        if contradiction {
+            return Ok(Some(SatisfiabilityResult::Unsatisfiable(
                 UnsatisfiabilityProof {
                     conflicting_constraints: vec!["trivial_contradiction".to_string()],
                     proof_steps: vec![ProofStep {
@@ -429,7 +507,7 @@ impl SatisfiabilityChecker {
                     }],
                     reason: "System contains trivial contradictions".to_string(),
                 }
-            ));
+            )));
         }
         
         // Generate a simple model
@@ -444,12 +522,48 @@ impl SatisfiabilityChecker {
             predicate_interpretations: HashMap::new(),
         };
         
-        Ok(SatisfiabilityResult::Satisfiable(model))
+        Ok(Some(SatisfiabilityResult::Satisfiable(model)))
+    }
+
+    /// An atomic constraint asserted both as is and negated, possibly
+    /// inside conjunctions, is a trivial contradiction
+    ///
+    /// `None` when `deadline` passes before the search is done.
+    fn has_trivial_contradiction(&self, system: &ConstraintSystem, deadline: Instant) -> Option<bool> {
+        let mut positive = Vec::new();
+        let mut negative = Vec::new();
+        for constraint in &system.constraints {
+            Self::collect_literals(constraint, &mut positive, &mut negative);
+        }
+        for atom in positive {
+            if Instant::now() >= deadline {
+                return None;
+            }
+            if negative.contains(&atom) {
+                return Some(true);
+            }
+        }
+        Some(false)
     }
 
-    fn has_trivial_contradiction(&self, _system: &ConstraintSystem) -> bool {
-        // Simplified contradiction detection
-        false
+    fn collect_literals<'a>(
+        constraint: &'a Constraint,
+        positive: &mut Vec<&'a AtomicConstraint>,
+        negative: &mut Vec<&'a AtomicConstraint>,
+    ) {
+        match constraint {
+            Constraint::Atomic(atom) => positive.push(atom),
+            Constraint::Negation(inner) => {
+                if let Constraint::Atomic(atom) = inner.as_ref() {
+                    negative.push(atom);
+                }
+            }
+            Constraint::Conjunction(left, right) => {
+                Self::collect_literals(left, positive, negative);
+                Self::collect_literals(right, positive, negative);
+            }
+            _ => {}
+        }
     }
 }
 
@@ -486,6 +600,15 @@ struct ConstraintSystem {
 }
 
 impl ConstraintSystem {
+    fn empty() -> Self {
+        Self {
+            constraints: Vec::new(),
+            variables: HashSet::new(),
+            functions: HashSet::new(),
+            predicates: HashSet::new(),
+        }
+    }
+
     fn from_formula(formula: PropertyFormula) -> AispResult<Self> {
         let variables = formula.free_variables;
         let functions = formula.functions;
@@ -723,4 +846,86 @@ mod tests {
         assert_eq!(proof.proof_steps.len(), 1);
         assert_eq!(proof.reason, "Contradiction detected");
     }
-}
\ No newline at end of file
+
+    fn literal_invariant(id: &str, predicate: &str, negated: bool) -> DiscoveredInvariant {
+        let atom = FormulaStructure::Atomic(AtomicFormula {
+            predicate: predicate.to_string(),
+            terms: vec![Term::Variable("y".to_string(), None)],
+            type_signature: None,
+        });
+        let mut formula = create_test_formula();
+        formula.structure = if negated { FormulaStructure::Negation(Box::new(atom)) } else { atom };
+        DiscoveredInvariant::new(id.to_string(), id.to_string(), formula, InvariantType::TypeStructural, 0.9)
+    }
+
+    #[test]
+    fn test_timeout_returns_unknown() {
+        let checker = SatisfiabilityChecker::default();
+        let invariants = vec![create_test_invariant()];
+
+        match checker.check_invariants_within(&invariants, Duration::ZERO).unwrap() {
+            SatisfiabilityResult::Unknown(reason) => assert!(reason.contains("Timed out")),
+            other => panic!("Expected unknown result, got {:?}", other),
+        }
+        match checker.check_invariants_incrementally(&invariants, Duration::ZERO).unwrap() {
+            IncrementalResult::Unknown { checked, .. } => assert_eq!(checked, 0),
+            other => panic!("Expected unknown result, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_timeout_interrupts_a_slow_solve() {
+        let checker = SatisfiabilityChecker::default();
+        // No literal repeats, so the contradiction search compares every pair
+        let invariants: Vec<DiscoveredInvariant> = (0..10_000)
+            .map(|i| literal_invariant(&format!("inv_{}", i), &format!("P{}", i), i % 2 == 1))
+            .collect();
+
+        let started = Instant::now();
+        assert!(matches!(
+            checker.check_invariants_within(&invariants, Duration::from_secs(600)).unwrap(),
+            SatisfiabilityResult::Satisfiable(_)
+        ));
+        let full = started.elapsed();
+
+        let started = Instant::now();
+        match checker.check_invariants_within(&invariants, full / 4).unwrap() {
+            SatisfiabilityResult::Unknown(reason) => assert!(reason.contains("Timed out")),
+            other => panic!("Expected unknown result, got {:?}", other),
+        }
+        assert!(started.elapsed() < full / 2, "{:?} of {:?}", started.elapsed(), full);
+    }
+
+    #[test]
+    fn test_incremental_check_pinpoints_conflicting_invariant() {
+        let checker = SatisfiabilityChecker::default();
+        let invariants = vec![
+            create_test_invariant(),
+            literal_invariant("ready", "Ready", false),
+            literal_invariant("busy", "Busy", false),
+            literal_invariant("not_ready", "Ready", true),
+            literal_invariant("idle", "Idle", false),
+        ];
+
+        assert!(matches!(
+            checker.check_invariants(&invariants[..3]).unwrap(),
+            SatisfiabilityResult::Satisfiable(_)
+        ));
+        assert!(matches!(
+            checker.check_invariants(&invariants).unwrap(),
+            SatisfiabilityResult::Unsatisfiable(_)
+        ));
+
+        match checker.check_invariants_incrementally(&invariants, Duration::from_secs(10)).unwrap() {
+            IncrementalResult::Unsatisfiable { index, invariant_id, .. } => {
+                assert_eq!(index, 3);
+                assert_eq!(invariant_id, "not_ready");
+            }
+            other => panic!("Expected a conflicting invariant, got {:?}", other),
+        }
+        assert!(matches!(
+            checker.check_invariants_incrementally(&invariants[..3], Duration::from_secs(10)).unwrap(),
+            IncrementalResult::Satisfiable(_)
+        ));
+    }
+}
@@ -311,6 +311,7 @@ mod tests {
                     span: Some(Span::new(0, 0, 1, 1)),
                 })
             ],
+            used_symbols: Default::default(),
             span: Some(Span::new(0, 0, 1, 1)),
         };
 
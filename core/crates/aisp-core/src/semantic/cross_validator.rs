@@ -32,13 +32,22 @@ use crate::semantic::deep_verifier::{
     LogicAnalysisResult, SecurityAssessment, ThreatLevel
 };
 use crate::semantic::behavioral_verifier::{
-    BehavioralVerifier, BehavioralVerificationResult, SafeExecutionSandbox
+    BehavioralVerifier, BehavioralVerificationResult, BehaviorClassification, SafeExecutionSandbox
 };
 use crate::error::{AispError, AispResult};
+use crate::proof_search::ProofSearchStrategy;
+use crate::proof_types::ProofOutcome;
+use crate::property_extractor::PropertyExtractor;
+use crate::theorem_prover::TheoremProver;
 use std::collections::{HashMap, HashSet};
 use std::time::{Duration, Instant};
 use std::fmt;
 
+/// Search depth for each property proof behind the formal score
+const PROOF_MAX_DEPTH: usize = 10;
+/// Time allowed for each property proof behind the formal score
+const PROOF_TIMEOUT: Duration = Duration::from_secs(5);
+
 // Re-export all public items from modular implementation
 pub use validation_types::*;
 pub use consistency_analyzer::*;
@@ -78,6 +87,10 @@ mod validation_types {
         pub consistency_analysis: ConsistencyAnalysis,
         pub conflicts_detected: Vec<VerificationConflict>,
         pub resolved_conflicts: Vec<ResolvedConflict>,
+        #[serde(default)]
+        pub verifier_disagreements: Vec<VerifierDisagreement>,
+        #[serde(default)]
+        pub unified_confidence: UnifiedConfidence,
         pub integration_metrics: IntegrationMetrics,
         pub final_assessment: FinalSecurityAssessment,
     }
@@ -117,6 +130,29 @@ mod validation_types {
         pub minority_opinions: Vec<MinorityOpinion>,
     }
 
+    /// A function one verifier passed and the other flagged
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    pub struct VerifierDisagreement {
+        pub function_name: String,
+        pub semantic_verdict: VerifierVerdict,
+        pub behavioral_verdict: VerifierVerdict,
+        pub recommendation: String,
+    }
+
+    /// Single confidence over the deep, behavioral and formal verifier
+    /// scores, lowered when they disagree
+    #[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+    pub struct UnifiedConfidence {
+        pub combined: f64,
+        pub deep: f64,
+        pub behavioral: f64,
+        /// Share of the properties the theorem prover settled that it
+        /// proved; `None` when it settled none
+        pub formal: Option<f64>,
+        /// Fraction of the mean score removed for disagreement
+        pub disagreement_penalty: f64,
+    }
+
     /// Final unified security assessment
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
     pub struct FinalSecurityAssessment {
@@ -187,6 +223,13 @@ mod validation_types {
         Inconclusive,
     }
 
+    /// One verifier's verdict on a function, with the reason when flagged
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    pub enum VerifierVerdict {
+        Passed,
+        Flagged(String),
+    }
+
     // Supporting structures
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)] pub struct LayerCorrelation { pub layer1: VerificationLayer, pub layer2: VerificationLayer, pub correlation: f64 }
     #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)] pub struct ConsistencyAnomaly { pub anomaly_type: String, pub description: String }
@@ -260,6 +303,8 @@ mod validation_types {
                 consistency_analysis: ConsistencyAnalysis::default(),
                 conflicts_detected: Vec::new(),
                 resolved_conflicts: Vec::new(),
+                verifier_disagreements: Vec::new(),
+                unified_confidence: UnifiedConfidence::default(),
                 integration_metrics: IntegrationMetrics::default(),
                 final_assessment: FinalSecurityAssessment::default(),
             }
@@ -443,6 +488,103 @@ mod consistency_analyzer {
             Ok(vec![])
         }
 
+        /// Compare the deep and behavioral verdicts for every executed function
+        ///
+        /// The deep verifier flags a function through a failure or warning
+        /// naming it; the behavioral verifier through a non-safe
+        /// classification or a security violation.
+        pub fn detect_verifier_disagreements(
+            &self,
+            semantic_results: &DeepVerificationResult,
+            behavioral_results: &BehavioralVerificationResult,
+        ) -> Vec<VerifierDisagreement> {
+            let details = &semantic_results.verification_details;
+            let mut disagreements = Vec::new();
+
+            for execution in &behavioral_results.execution_results {
+                let name = &execution.function_name;
+                let semantic_verdict = details
+                    .failed_verifications
+                    .iter()
+                    .find(|failure| &failure.component == name)
+                    .map(|failure| VerifierVerdict::Flagged(failure.reason.clone()))
+                    .or_else(|| {
+                        details
+                            .warnings
+                            .iter()
+                            .find(|warning| &warning.component == name)
+                            .map(|warning| VerifierVerdict::Flagged(warning.warning.clone()))
+                    })
+                    .unwrap_or(VerifierVerdict::Passed);
+
+                let behavioral_verdict = match (&execution.behavior_classification, execution.security_violations.first()) {
+                    (_, Some(violation)) => VerifierVerdict::Flagged(violation.description.clone()),
+                    (BehaviorClassification::Safe, None) => VerifierVerdict::Passed,
+                    (classification, None) => VerifierVerdict::Flagged(format!("Classified as {:?}", classification)),
+                };
+
+                let recommendation = match (&semantic_verdict, &behavioral_verdict) {
+                    (VerifierVerdict::Passed, VerifierVerdict::Flagged(_)) => format!(
+                        "Treat `{}` as unsafe and add the constraint its runtime behavior violated to the specification",
+                        name
+                    ),
+                    (VerifierVerdict::Flagged(_), VerifierVerdict::Passed) => format!(
+                        "Treat `{}` as unsafe until the deep verifier finding is resolved, then add behavioral inputs that exercise it",
+                        name
+                    ),
+                    _ => continue,
+                };
+
+                disagreements.push(VerifierDisagreement {
+                    function_name: name.clone(),
+                    semantic_verdict,
+                    behavioral_verdict,
+                    recommendation,
+                });
+            }
+
+            disagreements
+        }
+
+        /// Combine the deep, behavioral and formal verifier scores into one
+        /// confidence
+        ///
+        /// `proof_outcomes` are the theorem prover's outcomes for the
+        /// document's properties. The mean of the scores is scaled down by
+        /// their standard deviation, so verifiers that disagree yield less
+        /// confidence than the same mean reached in agreement. Without a
+        /// settled property there is no formal score to combine.
+        pub fn unified_confidence(
+            &self,
+            semantic_results: &DeepVerificationResult,
+            behavioral_results: &BehavioralVerificationResult,
+            proof_outcomes: &[ProofOutcome],
+        ) -> UnifiedConfidence {
+            let deep = semantic_results.overall_confidence.clamp(0.0, 1.0);
+            let behavioral = behavioral_results.overall_score.clamp(0.0, 1.0);
+            let formal = Self::formal_score(proof_outcomes);
+
+            let scores: Vec<f64> = [deep, behavioral].into_iter().chain(formal).collect();
+            let mean = scores.iter().sum::<f64>() / scores.len() as f64;
+            let variance = scores.iter().map(|score| (score - mean).powi(2)).sum::<f64>() / scores.len() as f64;
+            let disagreement_penalty = variance.sqrt();
+
+            UnifiedConfidence {
+                combined: mean * (1.0 - disagreement_penalty),
+                deep,
+                behavioral,
+                formal,
+                disagreement_penalty,
+            }
+        }
+
+        /// Share of the proven properties among those proven or disproven
+        pub fn formal_score(proof_outcomes: &[ProofOutcome]) -> Option<f64> {
+            let proven = proof_outcomes.iter().filter(|outcome| **outcome == ProofOutcome::Proven).count();
+            let disproven = proof_outcomes.iter().filter(|outcome| **outcome == ProofOutcome::Disproven).count();
+            (proven + disproven > 0).then(|| proven as f64 / (proven + disproven) as f64)
+        }
+
         /// Perform advanced correlation analysis between verification layers
         pub fn perform_advanced_correlation_analysis(
             &self,
@@ -565,6 +707,91 @@ mod consistency_analyzer {
             assert_eq!(correlations[0].layer2, VerificationLayer::BehavioralVerification);
         }
 
+        #[test]
+        fn test_verifier_disagreements_are_reported() {
+            let analyzer = ConsistencyAnalyzer::new();
+            let mut semantic_results = create_mock_semantic_results(0.9);
+            semantic_results.verification_details.failed_verifications.push(VerificationFailure {
+                component: "parse".to_string(),
+                reason: "Unbounded recursion".to_string(),
+            });
+            let mut behavioral_results = create_mock_behavioral_results(0.9);
+            behavioral_results.execution_results = vec![
+                ExecutionResult { function_name: "parse".to_string(), ..Default::default() },
+                ExecutionResult {
+                    function_name: "eval".to_string(),
+                    behavior_classification: BehaviorClassification::Malicious,
+                    ..Default::default()
+                },
+                ExecutionResult { function_name: "id".to_string(), ..Default::default() },
+            ];
+
+            let disagreements = analyzer.detect_verifier_disagreements(&semantic_results, &behavioral_results);
+
+            assert_eq!(disagreements.len(), 2);
+            assert_eq!(disagreements[0].function_name, "parse");
+            assert_eq!(disagreements[0].semantic_verdict, VerifierVerdict::Flagged("Unbounded recursion".to_string()));
+            assert_eq!(disagreements[0].behavioral_verdict, VerifierVerdict::Passed);
+            assert_eq!(disagreements[1].function_name, "eval");
+            assert_eq!(disagreements[1].semantic_verdict, VerifierVerdict::Passed);
+            assert_eq!(disagreements[1].behavioral_verdict, VerifierVerdict::Flagged("Classified as Malicious".to_string()));
+            assert!(disagreements.iter().all(|disagreement| disagreement.recommendation.contains("as unsafe")));
+        }
+
+        #[test]
+        fn test_agreeing_verifiers_keep_full_confidence() {
+            let analyzer = ConsistencyAnalyzer::new();
+            let unified = analyzer.unified_confidence(
+                &create_mock_semantic_results(1.0),
+                &create_mock_behavioral_results(1.0),
+                &[ProofOutcome::Proven, ProofOutcome::Proven, ProofOutcome::Unknown],
+            );
+
+            assert_eq!((unified.deep, unified.behavioral, unified.formal), (1.0, 1.0, Some(1.0)));
+            assert!(unified.disagreement_penalty < 1e-9);
+            assert!((unified.combined - 1.0).abs() < 1e-9);
+        }
+
+        #[test]
+        fn test_disagreeing_verifiers_are_penalized() {
+            let analyzer = ConsistencyAnalyzer::new();
+            // The prover refutes half of what the other verifiers accept
+            let outcomes = [ProofOutcome::Proven, ProofOutcome::Disproven];
+            let unified = analyzer.unified_confidence(
+                &create_mock_semantic_results(0.9),
+                &create_mock_behavioral_results(0.9),
+                &outcomes,
+            );
+
+            let mean = (0.9 + 0.9 + 0.5) / 3.0;
+            assert_eq!(unified.formal, Some(0.5));
+            assert!(unified.disagreement_penalty > 0.15);
+            assert!((unified.combined - mean * (1.0 - unified.disagreement_penalty)).abs() < 1e-9);
+
+            // The same mean reached in agreement scores higher
+            let agreeing = analyzer.unified_confidence(
+                &create_mock_semantic_results(mean),
+                &create_mock_behavioral_results(mean),
+                &[],
+            );
+            assert!(agreeing.combined > unified.combined);
+        }
+
+        #[test]
+        fn test_formal_score_ignores_unsettled_properties() {
+            assert_eq!(ConsistencyAnalyzer::formal_score(&[]), None);
+            assert_eq!(ConsistencyAnalyzer::formal_score(&[ProofOutcome::Unknown, ProofOutcome::Timeout]), None);
+
+            // Without a formal score only the deep and behavioral scores combine
+            let unified = ConsistencyAnalyzer::new().unified_confidence(
+                &create_mock_semantic_results(0.8),
+                &create_mock_behavioral_results(0.8),
+                &[ProofOutcome::Unknown],
+            );
+            assert_eq!(unified.formal, None);
+            assert!((unified.combined - 0.8).abs() < 1e-9);
+        }
+
         fn create_mock_semantic_results(confidence: f64) -> DeepVerificationResult {
             DeepVerificationResult {
                 overall_confidence: confidence,
@@ -1608,10 +1835,15 @@ impl CrossValidationChecker {
         )?;
         
         // Phase 3: Conflict detection and resolution
+        let verifier_disagreements = self.consistency_analyzer.detect_verifier_disagreements(
+            &semantic_results,
+            &behavioral_results,
+        );
         let conflicts_detected = self.detect_verification_conflicts(
             &semantic_results,
             &behavioral_results,
             &consistency_analysis,
+            &verifier_disagreements,
         )?;
         
         let resolved_conflicts = self.conflict_resolver.resolve_conflicts(&conflicts_detected)?;
@@ -1641,6 +1873,9 @@ impl CrossValidationChecker {
             &resolved_conflicts,
         );
         
+        let proof_outcomes = self.prove_properties(document);
+        let unified_confidence =
+            self.consistency_analyzer.unified_confidence(&semantic_results, &behavioral_results, &proof_outcomes);
         let conflict_resolution_score = self.calculate_conflict_resolution_score(&resolved_conflicts);
         let verification_coverage = self.calculate_verification_coverage(&semantic_results, &behavioral_results);
         
@@ -1664,11 +1899,27 @@ impl CrossValidationChecker {
             consistency_analysis,
             conflicts_detected,
             resolved_conflicts,
+            verifier_disagreements,
+            unified_confidence,
             integration_metrics,
             final_assessment,
         })
     }
 
+    /// Theorem prover outcome of each property extracted from `document`
+    ///
+    /// A document whose properties cannot be extracted has none.
+    fn prove_properties(&self, document: &AispDocument) -> Vec<ProofOutcome> {
+        let Ok(properties) = PropertyExtractor::new().extract_properties(document) else {
+            return Vec::new();
+        };
+        let mut prover = TheoremProver::with_config(ProofSearchStrategy::IterativeDeepening, PROOF_MAX_DEPTH, PROOF_TIMEOUT);
+        properties
+            .iter()
+            .map(|property| prover.prove_property(property).map_or(ProofOutcome::Unknown, |result| result.outcome))
+            .collect()
+    }
+
     /// Run semantic and behavioral verification in parallel
     fn run_parallel_verification(&mut self, document: &AispDocument) -> AispResult<(DeepVerificationResult, BehavioralVerificationResult)> {
         // Use orchestrator for optimized parallel execution
@@ -1685,6 +1936,7 @@ impl CrossValidationChecker {
         semantic_results: &DeepVerificationResult,
         behavioral_results: &BehavioralVerificationResult,
         consistency_analysis: &ConsistencyAnalysis,
+        verifier_disagreements: &[VerifierDisagreement],
     ) -> AispResult<Vec<VerificationConflict>> {
         let mut conflicts = Vec::new();
 
@@ -1746,6 +1998,26 @@ impl CrossValidationChecker {
             });
         }
 
+        // Check for per-function verdicts the two verifiers disagree on
+        for disagreement in verifier_disagreements {
+            conflicts.push(VerificationConflict {
+                conflict_id: format!("verifier_disagreement_{}", disagreement.function_name),
+                conflict_type: ConflictType::SemanticBehavioralMismatch,
+                affected_layers: vec![VerificationLayer::SemanticAnalysis, VerificationLayer::BehavioralVerification],
+                severity: ConflictSeverity::Significant,
+                description: format!("Verifiers disagree on function `{}`", disagreement.function_name),
+                evidence: ConflictEvidence {
+                    evidence_type: "VerdictDisagreement".to_string(),
+                    details: vec![
+                        format!("Semantic: {}", disagreement.semantic_verdict),
+                        format!("Behavioral: {}", disagreement.behavioral_verdict),
+                        disagreement.recommendation.clone(),
+                    ],
+                },
+                resolution_difficulty: ResolutionDifficulty::Complex,
+            });
+        }
+
         Ok(conflicts)
     }
 
@@ -1819,6 +2091,15 @@ impl Default for CrossValidationChecker {
     }
 }
 
+impl fmt::Display for VerifierVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifierVerdict::Passed => write!(f, "passed"),
+            VerifierVerdict::Flagged(reason) => write!(f, "flagged ({})", reason),
+        }
+    }
+}
+
 impl fmt::Display for CrossValidationResult {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Cross-Validation Result\n")?;
@@ -1826,9 +2107,28 @@ impl fmt::Display for CrossValidationResult {
         write!(f, "Overall Consistency: {:.1}%\n", self.overall_consistency_score * 100.0)?;
         write!(f, "Semantic-Behavioral Agreement: {:.1}%\n", self.semantic_behavioral_agreement * 100.0)?;
         write!(f, "Cross-Validation Confidence: {:.1}%\n", self.cross_validation_confidence * 100.0)?;
+        write!(
+            f,
+            "Unified Confidence: {:.1}% (deep {:.1}%, behavioral {:.1}%, formal {}, disagreement penalty {:.1}%)\n",
+            self.unified_confidence.combined * 100.0,
+            self.unified_confidence.deep * 100.0,
+            self.unified_confidence.behavioral * 100.0,
+            self.unified_confidence.formal.map_or_else(|| "n/a".to_string(), |formal| format!("{:.1}%", formal * 100.0)),
+            self.unified_confidence.disagreement_penalty * 100.0
+        )?;
         write!(f, "Conflict Resolution Score: {:.1}%\n", self.conflict_resolution_score * 100.0)?;
         write!(f, "Verification Coverage: {:.1}%\n", self.verification_coverage * 100.0)?;
         write!(f, "\nConflicts: {} detected, {} resolved\n", self.conflicts_detected.len(), self.resolved_conflicts.len())?;
+        for disagreement in &self.verifier_disagreements {
+            write!(
+                f,
+                "  {}: semantic {}, behavioral {} - {}\n",
+                disagreement.function_name,
+                disagreement.semantic_verdict,
+                disagreement.behavioral_verdict,
+                disagreement.recommendation
+            )?;
+        }
         write!(f, "Final Threat Level: {:?}\n", self.final_assessment.unified_threat_level)?;
         write!(f, "Security Confidence: {:.1}%\n", self.final_assessment.security_confidence * 100.0)?;
         Ok(())
@@ -1871,6 +2171,30 @@ mod integration_tests {
         assert!(validation.cross_validation_confidence <= 1.0);
     }
 
+    #[test]
+    fn test_verifier_disagreement_becomes_conflict() {
+        let checker = CrossValidationChecker::new();
+        let semantic_results = DeepVerificationResult { overall_confidence: 0.9, logic_consistency_score: 0.9, ..Default::default() };
+        let behavioral_results = BehavioralVerificationResult { overall_score: 0.9, ..Default::default() };
+        let disagreement = VerifierDisagreement {
+            function_name: "eval".to_string(),
+            semantic_verdict: VerifierVerdict::Passed,
+            behavioral_verdict: VerifierVerdict::Flagged("Classified as Malicious".to_string()),
+            recommendation: "Treat `eval` as unsafe".to_string(),
+        };
+
+        let conflicts = checker
+            .detect_verification_conflicts(&semantic_results, &behavioral_results, &ConsistencyAnalysis::default(), &[disagreement])
+            .unwrap();
+
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].conflict_type, ConflictType::SemanticBehavioralMismatch);
+        assert_eq!(
+            conflicts[0].evidence.details,
+            vec!["Semantic: passed", "Behavioral: flagged (Classified as Malicious)", "Treat `eval` as unsafe"]
+        );
+    }
+
     #[test]
     fn test_comprehensive_integration() {
         let mut checker = CrossValidationChecker::with_strict_validation();
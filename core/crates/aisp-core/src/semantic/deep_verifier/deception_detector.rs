@@ -8,9 +8,29 @@ use crate::ast::canonical::{CanonicalAispDocument as AispDocument, CanonicalAisp
 use crate::error::{AispError, AispResult};
 use std::collections::HashMap;
 
+/// User tuning for placeholder detection
+///
+/// `placeholder_patterns` are matched against each block item in addition to
+/// the built-in patterns. `allowlist` names identifiers whose definitions are
+/// legitimate stubs, or whole block types (e.g. `"Functions"`), that must
+/// never be flagged.
+#[derive(Debug, Clone, Default)]
+pub struct DeceptionConfig {
+    #[cfg(feature = "regex")]
+    pub placeholder_patterns: Vec<regex::Regex>,
+    pub allowlist: Vec<String>,
+}
+
+impl DeceptionConfig {
+    fn is_allowlisted(&self, name: &str) -> bool {
+        self.allowlist.iter().any(|allowed| allowed == name)
+    }
+}
+
 /// Deception detection for fake implementations and surface compliance
 pub struct DeceptionDetector {
     pub placeholder_patterns: Vec<PlaceholderPattern>,
+    pub config: DeceptionConfig,
     pub behavioral_analyzers: Vec<BehavioralAnalyzer>,
     pub complexity_analyzer: ComplexityAnalyzer,
     pub coverage_analyzer: CoverageAnalyzer,
@@ -22,6 +42,7 @@ impl DeceptionDetector {
     pub fn new() -> Self {
         let mut detector = Self {
             placeholder_patterns: Vec::new(),
+            config: DeceptionConfig::default(),
             behavioral_analyzers: Vec::new(),
             complexity_analyzer: ComplexityAnalyzer {
                 metrics: vec!["cyclomatic_complexity".to_string(), "cognitive_complexity".to_string()],
@@ -47,6 +68,13 @@ impl DeceptionDetector {
         detector
     }
 
+    /// Create detector with custom placeholder patterns and allowlist
+    pub fn with_config(config: DeceptionConfig) -> Self {
+        let mut detector = Self::new();
+        detector.config = config;
+        detector
+    }
+
     /// Analyze document for deception and authenticity
     pub fn analyze_document(&mut self, document: &AispDocument) -> AispResult<DeceptionAnalysisResult> {
         let mut placeholder_violations = Vec::new();
@@ -191,8 +219,15 @@ impl DeceptionDetector {
         let mut violations = Vec::new();
 
         for block in &document.blocks {
-            let block_str = format!("{:?}", block);
-            
+            if self.config.is_allowlisted(block.block_type()) {
+                continue;
+            }
+            let items: Vec<&str> = block_items(block)
+                .into_iter()
+                .filter(|item| !defined_name(item).is_some_and(|name| self.config.is_allowlisted(name)))
+                .collect();
+            let block_str = items.join("\n");
+
             for pattern in &self.placeholder_patterns {
                 if self.matches_pattern(&block_str, &pattern.detection_regex) {
                     violations.push(format!(
@@ -203,6 +238,19 @@ impl DeceptionDetector {
                     ));
                 }
             }
+
+            #[cfg(feature = "regex")]
+            for pattern in &self.config.placeholder_patterns {
+                if let Some(item) = items.iter().find(|item| pattern.is_match(item)) {
+                    violations.push(format!(
+                        "Placeholder detected ({}): CustomPattern /{}/ - matched `{}` in {} block",
+                        RiskLevel::Medium,
+                        pattern.as_str(),
+                        item.trim(),
+                        block.block_type()
+                    ));
+                }
+            }
         }
 
         Ok(violations)
@@ -362,6 +410,23 @@ impl DeceptionDetector {
     }
 }
 
+/// Source text of each item in a block
+fn block_items(block: &AispBlock) -> Vec<&str> {
+    let items = match block {
+        AispBlock::Meta(meta) => &meta.raw_entries,
+        AispBlock::Types(types) => &types.raw_definitions,
+        AispBlock::Rules(rules) => &rules.raw_rules,
+        AispBlock::Functions(functions) => &functions.raw_functions,
+        AispBlock::Evidence(evidence) => &evidence.raw_evidence,
+    };
+    items.iter().map(String::as_str).collect()
+}
+
+/// Identifier an item defines, i.e. the left-hand side of `≜`
+fn defined_name(item: &str) -> Option<&str> {
+    item.split_once('≜').map(|(name, _)| name.trim()).filter(|name| !name.is_empty())
+}
+
 impl std::fmt::Display for RiskLevel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -424,4 +489,59 @@ mod tests {
         assert_eq!(detector.behavioral_analyzers[0].analysis_type, "ImplementationDepth");
         assert_eq!(detector.behavioral_analyzers[1].analysis_type, "LogicComplexity");
     }
-}
\ No newline at end of file
+
+    fn document_with_functions(functions: &[&str]) -> AispDocument {
+        let source = format!(
+            "𝔸5.1.test@2026-01-25\n\n⟦Ω:Meta⟧{{domain≜test}}\n\n⟦Λ:Funcs⟧{{\n  {}\n}}\n",
+            functions.join("\n  ")
+        );
+        crate::parser::robust_parser::RobustAispParser::new()
+            .parse(&source)
+            .document
+            .expect("test document parses")
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_custom_pattern_is_flagged() {
+        let config = DeceptionConfig {
+            placeholder_patterns: vec![regex::Regex::new(r"stubbed_\w+").unwrap()],
+            allowlist: Vec::new(),
+        };
+        let mut detector = DeceptionDetector::with_config(config);
+        let document = document_with_functions(&["handler≜λx.stubbed_result"]);
+
+        let result = detector.analyze_document(&document).unwrap();
+        assert!(result
+            .placeholder_violations
+            .iter()
+            .any(|violation| violation.contains("CustomPattern") && violation.contains("handler")));
+        assert!(result.deception_score > 0.0);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_allowlisted_identifier_is_not_flagged() {
+        let config = DeceptionConfig {
+            placeholder_patterns: vec![regex::Regex::new(r"stubbed_\w+").unwrap()],
+            allowlist: vec!["interface".to_string()],
+        };
+        let mut detector = DeceptionDetector::with_config(config);
+        let document = document_with_functions(&["interface≜λx.stubbed_result", "run≜λx.x"]);
+
+        let result = detector.analyze_document(&document).unwrap();
+        assert!(result.placeholder_violations.is_empty(), "{:?}", result.placeholder_violations);
+    }
+
+    #[test]
+    fn test_allowlisted_block_type_is_skipped() {
+        let detector = DeceptionDetector::with_config(DeceptionConfig {
+            allowlist: vec!["Functions".to_string()],
+            ..DeceptionConfig::default()
+        });
+        let document = document_with_functions(&["interface≜λx.x"]);
+
+        assert!(detector.config.is_allowlisted("Functions"));
+        assert!(detector.detect_placeholders(&document).unwrap().is_empty());
+    }
+}
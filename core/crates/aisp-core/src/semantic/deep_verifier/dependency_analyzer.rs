@@ -89,6 +89,62 @@ impl DependencyGraphAnalyzer {
         })
     }
 
+    /// Render the dependency graph in Graphviz DOT format
+    ///
+    /// Every definition becomes a node and every reference an edge. Edges
+    /// on a cycle, and the nodes they connect, are drawn in red.
+    pub fn export_dot(&self) -> String {
+        let graph = &self.dependency_graph;
+        let mut nodes: Vec<&String> = graph
+            .nodes
+            .iter()
+            .chain(graph.edges.iter().flat_map(|(source, target)| [source, target]))
+            .collect();
+        nodes.sort();
+        nodes.dedup();
+
+        let cyclic: Vec<bool> = graph.edges.iter().map(|(source, target)| self.reaches(target, source)).collect();
+        let on_cycle = |node: &String| {
+            graph.edges.iter().zip(&cyclic).any(|((source, target), &cyclic)| cyclic && (source == node || target == node))
+        };
+
+        let mut dot = String::from("digraph dependencies {\n");
+        for node in nodes {
+            if on_cycle(node) {
+                dot.push_str(&format!("  {} [color=red];\n", dot_id(node)));
+            } else {
+                dot.push_str(&format!("  {};\n", dot_id(node)));
+            }
+        }
+        for ((source, target), cyclic) in graph.edges.iter().zip(cyclic) {
+            let style = if cyclic { " [color=red, label=\"cycle\"]" } else { "" };
+            dot.push_str(&format!("  {} -> {}{};\n", dot_id(source), dot_id(target), style));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Whether `to` can be reached from `from` along dependency edges
+    fn reaches(&self, from: &str, to: &str) -> bool {
+        let mut visited = HashSet::new();
+        let mut pending = vec![from];
+        while let Some(node) = pending.pop() {
+            if node == to {
+                return true;
+            }
+            if visited.insert(node) {
+                pending.extend(
+                    self.dependency_graph
+                        .edges
+                        .iter()
+                        .filter(|(source, _)| source == node)
+                        .map(|(_, target)| target.as_str()),
+                );
+            }
+        }
+        false
+    }
+
     /// Build dependency graph from AISP document
     fn build_dependency_graph(&mut self, document: &AispDocument) -> AispResult<()> {
         let mut nodes = HashSet::new();
@@ -401,6 +457,11 @@ impl DependencyGraphAnalyzer {
     }
 }
 
+/// Quoted DOT identifier
+fn dot_id(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
 impl Default for DependencyGraphAnalyzer {
     fn default() -> Self {
         Self::new()
@@ -434,6 +495,31 @@ mod tests {
         assert_eq!(analyzer.dependency_graph.edges.len(), 1);
     }
 
+    #[test]
+    fn test_export_dot_marks_cycles() {
+        let mut analyzer = DependencyGraphAnalyzer::new();
+        analyzer.dependency_graph = DependencyGraph {
+            nodes: ["State", "Task", "Queue", "run", "id"].map(String::from).to_vec(),
+            edges: [("Task", "State"), ("Queue", "run"), ("run", "Queue"), ("run", "Task")]
+                .iter()
+                .map(|(source, target)| (source.to_string(), target.to_string()))
+                .collect(),
+        };
+
+        let dot = analyzer.export_dot();
+
+        assert!(dot.starts_with("digraph dependencies {\n"));
+        assert!(dot.ends_with("}\n"));
+        for node in ["\"State\";", "\"Task\";", "\"id\";", "\"Queue\" [color=red];", "\"run\" [color=red];"] {
+            assert_eq!(dot.matches(&format!("  {}\n", node)).count(), 1, "{}", node);
+        }
+        assert_eq!(dot.matches(" -> ").count(), 4);
+        assert!(dot.contains("  \"Task\" -> \"State\";\n"));
+        assert!(dot.contains("  \"run\" -> \"Task\";\n"));
+        assert!(dot.contains("  \"Queue\" -> \"run\" [color=red, label=\"cycle\"];\n"));
+        assert!(dot.contains("  \"run\" -> \"Queue\" [color=red, label=\"cycle\"];\n"));
+    }
+
     #[test]
     fn test_cycle_detection_algorithms() {
         let analyzer = DependencyGraphAnalyzer::new();
@@ -4,9 +4,10 @@
 //! Implements SRP by focusing solely on logical consistency analysis
 
 use super::types::*;
+use super::rule_classifier::classify_rule_text;
 use crate::ast::canonical::{CanonicalAispDocument as AispDocument, CanonicalAispBlock as AispBlock};
 use crate::error::{AispError, AispResult};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Logic consistency checker for mathematical correctness
 pub struct LogicConsistencyChecker {
@@ -46,6 +47,7 @@ impl LogicConsistencyChecker {
     pub fn analyze_document(&mut self, document: &AispDocument) -> AispResult<LogicAnalysisResult> {
         let mut contradictions = Vec::new();
         let mut axiom_violations = Vec::new();
+        let mut flagged_rules = Vec::new();
         let mut consistency_score = 1.0;
 
         // Check logical consistency across all blocks
@@ -64,6 +66,22 @@ impl LogicConsistencyChecker {
                         axiom_violations.extend(rules_analysis);
                         consistency_score -= 0.2;
                     }
+
+                    let classified = self.classify_rules(rules_block);
+                    let unsatisfiable: Vec<String> = classified
+                        .iter()
+                        .filter(|rule| rule.kind == RuleKind::Unsatisfiable)
+                        .map(|rule| format!("Rule '{}' is unsatisfiable{}", rule.text, rule.location()))
+                        .collect();
+                    if !unsatisfiable.is_empty() {
+                        contradictions.extend(unsatisfiable);
+                        consistency_score -= 0.3;
+                    }
+                    flagged_rules.extend(
+                        classified
+                            .into_iter()
+                            .filter(|rule| matches!(rule.kind, RuleKind::Tautology | RuleKind::Unsatisfiable)),
+                    );
                 }
                 AispBlock::Evidence(evidence_block) => {
                     let evidence_analysis = self.check_evidence_logic(evidence_block)?;
@@ -96,9 +114,32 @@ impl LogicConsistencyChecker {
             consistency_score,
             contradictions,
             axiom_violations,
+            flagged_rules,
         })
     }
 
+    /// Classify each distinct rule of the block on its own
+    ///
+    /// Tautologies are vacuous and unsatisfiable rules can never hold;
+    /// rules outside the decidable fragment are classified unknown.
+    pub fn classify_rules(&self, rules_block: &crate::ast::canonical::RulesBlock) -> Vec<RuleClassification> {
+        let mut seen = HashSet::new();
+        rules_block
+            .rules
+            .iter()
+            .enumerate()
+            .filter_map(|(index, rule)| {
+                let text = rule.raw_text.trim().trim_end_matches(';').trim_end();
+                seen.insert(text.to_string()).then(|| RuleClassification {
+                    index,
+                    text: text.to_string(),
+                    span: rule.span.clone(),
+                    kind: classify_rule_text(text),
+                })
+            })
+            .collect()
+    }
+
     /// Setup default logical axioms
     fn setup_default_axioms(&mut self) {
         self.axiom_system.extend(vec![
@@ -339,6 +380,56 @@ mod tests {
         assert!(modus_ponens.formula.contains("→"));
     }
 
+    fn rules_block(rules: &[&str]) -> crate::ast::canonical::RulesBlock {
+        crate::ast::canonical::RulesBlock {
+            rules: rules
+                .iter()
+                .enumerate()
+                .map(|(line, text)| crate::ast::canonical::LogicalRule {
+                    quantifier: None,
+                    expression: crate::ast::canonical::LogicalExpression::Raw(text.to_string()),
+                    raw_text: text.to_string(),
+                    span: Some(crate::ast::canonical::Span::new(0, text.len(), line + 1, 1)),
+                })
+                .collect(),
+            raw_rules: Vec::new(),
+            span: None,
+        }
+    }
+
+    #[test]
+    fn test_rules_classified_with_spans() {
+        let checker = LogicConsistencyChecker::new();
+        let classified = checker.classify_rules(&rules_block(&["Ready(x)→Ready(x);", "x≠x;", "Ready(x)→Busy(x)", "x≠x"]));
+
+        let kinds: Vec<RuleKind> = classified.iter().map(|rule| rule.kind).collect();
+        assert_eq!(kinds, vec![RuleKind::Tautology, RuleKind::Unsatisfiable, RuleKind::Contingent]);
+        assert_eq!(classified[1].text, "x≠x");
+        assert_eq!(classified[1].span.as_ref().unwrap().line, 2);
+    }
+
+    #[test]
+    fn test_unsatisfiable_rule_is_a_contradiction() {
+        let mut checker = LogicConsistencyChecker::new();
+        let document = AispDocument {
+            header: crate::ast::canonical::DocumentHeader {
+                version: "5.1".to_string(),
+                name: "test".to_string(),
+                date: "2026-01-25".to_string(),
+                metadata: None,
+            },
+            metadata: crate::ast::canonical::DocumentMetadata { domain: None, protocol: None },
+            blocks: vec![AispBlock::Rules(rules_block(&["p→p", "1=2", "p→q"]))],
+            used_symbols: HashSet::new(),
+            span: None,
+        };
+
+        let analysis = checker.analyze_document(&document).unwrap();
+        assert_eq!(analysis.contradictions, vec!["Rule '1=2' is unsatisfiable at line 2, column 1".to_string()]);
+        let flagged: Vec<(&str, RuleKind)> = analysis.flagged_rules.iter().map(|rule| (rule.text.as_str(), rule.kind)).collect();
+        assert_eq!(flagged, vec![("p→p", RuleKind::Tautology), ("1=2", RuleKind::Unsatisfiable)]);
+    }
+
     #[test]
     fn test_axiom_types() {
         let foundational = AxiomType::Foundational;
@@ -7,6 +7,7 @@
 pub mod types;
 pub mod type_analyzer;
 pub mod logic_checker;
+pub mod rule_classifier;
 pub mod dependency_analyzer;
 pub mod mathematical_verifier;
 pub mod deception_detector;
@@ -16,7 +17,7 @@ pub use type_analyzer::TypeSystemAnalyzer;
 pub use logic_checker::LogicConsistencyChecker;
 pub use dependency_analyzer::DependencyGraphAnalyzer;
 pub use mathematical_verifier::MathematicalCorrectnessEngine;
-pub use deception_detector::DeceptionDetector;
+pub use deception_detector::{DeceptionConfig, DeceptionDetector};
 
 use crate::ast::canonical::{CanonicalAispDocument as AispDocument};
 use crate::error::{AispError, AispResult};
@@ -54,39 +55,57 @@ impl DeepSemanticVerifier {
         }
     }
 
+    /// Replace the deception detector's placeholder patterns and allowlist
+    pub fn with_deception_config(mut self, config: DeceptionConfig) -> Self {
+        self.deception_detector.config = config;
+        self
+    }
+
     /// Perform comprehensive deep semantic verification
     pub fn verify_document(&mut self, document: &AispDocument) -> AispResult<DeepVerificationResult> {
         let start_time = Instant::now();
-        
+        let mut stage_timings = Vec::with_capacity(6);
+        let mut stage_start = start_time;
+        let mut record_stage = |stage: &str| {
+            let now = Instant::now();
+            stage_timings.push(StageTiming { stage: stage.to_string(), duration: now - stage_start });
+            stage_start = now;
+        };
+
         // Stage 1: Type System Analysis
         let type_analysis = self.type_analyzer.analyze_document(document)
             .map_err(|e| AispError::ValidationError {
                 message: format!("Type analysis failed: {}", e),
             })?;
+        record_stage("type");
 
         // Stage 2: Logic Consistency Checking
         let logic_analysis = self.logic_checker.analyze_document(document)
             .map_err(|e| AispError::ValidationError {
                 message: format!("Logic analysis failed: {}", e),
             })?;
+        record_stage("logic");
 
         // Stage 3: Dependency Analysis
         let dependency_analysis = self.dependency_analyzer.analyze_document(document)
             .map_err(|e| AispError::ValidationError {
                 message: format!("Dependency analysis failed: {}", e),
             })?;
+        record_stage("dependency");
 
         // Stage 4: Mathematical Correctness Verification
         let mathematical_analysis = self.mathematical_verifier.analyze_document(document)
             .map_err(|e| AispError::ValidationError {
                 message: format!("Mathematical analysis failed: {}", e),
             })?;
+        record_stage("mathematical");
 
         // Stage 5: Deception Detection
         let deception_analysis = self.deception_detector.analyze_document(document)
             .map_err(|e| AispError::ValidationError {
                 message: format!("Deception analysis failed: {}", e),
             })?;
+        record_stage("deception");
 
         let verification_time = start_time.elapsed();
 
@@ -107,7 +126,7 @@ impl DeepSemanticVerifier {
             &deception_analysis,
         );
 
-        let verification_details = self.build_verification_details(
+        let mut verification_details = self.build_verification_details(
             &type_analysis,
             &logic_analysis,
             &dependency_analysis,
@@ -124,6 +143,9 @@ impl DeepSemanticVerifier {
             &deception_analysis,
         );
 
+        record_stage("synthesis");
+        verification_details.performance_metrics.stage_timings = stage_timings;
+
         Ok(DeepVerificationResult {
             overall_confidence,
             semantic_score: (type_analysis.type_safety_score + logic_analysis.consistency_score + dependency_analysis.impact_score) / 3.0,
@@ -265,6 +287,7 @@ impl DeepSemanticVerifier {
             performance_metrics: PerformanceMetrics {
                 verification_time_ms: verification_time.as_millis() as u64,
                 memory_usage_mb: 128,
+                stage_timings: Vec::new(),
             },
         }
     }
@@ -296,6 +319,14 @@ impl DeepSemanticVerifier {
             });
         }
 
+        // Tautological rules hold regardless of the document and constrain nothing
+        for rule in logic_analysis.flagged_rules.iter().filter(|rule| rule.kind == RuleKind::Tautology) {
+            recommendations.push(VerificationRecommendation {
+                priority: "Medium".to_string(),
+                recommendation: format!("Rule '{}'{} is a tautology and constrains nothing", rule.text, rule.location()),
+            });
+        }
+
         // Dependency recommendations
         if !dependency_analysis.circular_dependencies.is_empty() {
             recommendations.push(VerificationRecommendation {
@@ -384,6 +415,7 @@ mod tests {
             consistency_score: 0.8,
             contradictions: vec![],
             axiom_violations: vec![],
+            flagged_rules: vec![],
         };
         
         let dependency_analysis = DependencyAnalysisResult {
@@ -443,6 +475,7 @@ mod tests {
             consistency_score: 0.9,
             contradictions: vec![],
             axiom_violations: vec![],
+            flagged_rules: vec![],
         };
         
         let dependency_analysis = DependencyAnalysisResult {
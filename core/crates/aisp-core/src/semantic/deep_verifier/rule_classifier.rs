@@ -0,0 +1,293 @@
+//! Rule Classifier
+//!
+//! Decides whether a single rule is a tautology, unsatisfiable on its own,
+//! or contingent. The rule text is encoded as an SMT-LIB script, with
+//! comparisons over real-valued terms and bare names or predicate
+//! applications abstracted to Boolean constants, and the fallback solver
+//! checks both the rule and its negation. Both abstractions only add
+//! models, so a tautology or unsatisfiable verdict is definite.
+
+use super::types::RuleKind;
+use crate::z3_verification::{FallbackOutcome, FallbackSolver};
+use std::collections::BTreeMap;
+
+/// Classify the text of one rule
+pub fn classify_rule_text(text: &str) -> RuleKind {
+    let body = quantifier_body(text.trim().trim_end_matches(';'));
+    let Some((declarations, formula)) = RuleEncoder::encode(body) else {
+        return RuleKind::Unknown;
+    };
+
+    let solver = FallbackSolver::new();
+    let check = |assertion: String| solver.check_script(&format!("{}(assert {})\n(check-sat)", declarations, assertion));
+    match (check(formula.clone()), check(format!("(not {})", formula))) {
+        (FallbackOutcome::Unsat, _) => RuleKind::Unsatisfiable,
+        (_, FallbackOutcome::Unsat) => RuleKind::Tautology,
+        (FallbackOutcome::Sat(_), FallbackOutcome::Sat(_)) => RuleKind::Contingent,
+        _ => RuleKind::Unknown,
+    }
+}
+
+/// Body of a rule under its leading quantifiers
+///
+/// Over a nonempty domain a quantified rule is a tautology or
+/// unsatisfiable exactly when its body is.
+fn quantifier_body(text: &str) -> &str {
+    let text = text.trim();
+    let Some(rest) = text.strip_prefix('∀').or_else(|| text.strip_prefix('∃')) else {
+        return text;
+    };
+    let Some((binding, body)) = rest.split_once(':') else {
+        return text;
+    };
+    // `∀x∈D: body` binds before the colon, `∀x:T→body` types after it
+    if binding.contains('∈') {
+        return quantifier_body(body);
+    }
+    match body.split_once('→') {
+        Some((_, body)) => quantifier_body(body),
+        None => text,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Name(String),
+    Number(String),
+    Symbol(char),
+}
+
+const SYMBOLS: &str = "()+-*,=≠<>≤≥¬∧∨→⇒↔⇔⊤⊥";
+
+fn tokenize(text: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(&ch) = chars.peek() {
+        if ch.is_whitespace() {
+            chars.next();
+        } else if ch.is_ascii_digit() {
+            let mut number = String::new();
+            while let Some(&digit) = chars.peek().filter(|c| c.is_ascii_digit() || **c == '.') {
+                number.push(digit);
+                chars.next();
+            }
+            tokens.push(Token::Number(number));
+        } else if ch.is_alphabetic() || ch == '_' {
+            let mut name = String::new();
+            while let Some(&letter) = chars.peek().filter(|c| c.is_alphanumeric() || **c == '_') {
+                name.push(letter);
+                chars.next();
+            }
+            tokens.push(Token::Name(name));
+        } else if SYMBOLS.contains(ch) {
+            tokens.push(Token::Symbol(ch));
+            chars.next();
+        } else {
+            return None;
+        }
+    }
+    Some(tokens)
+}
+
+/// Operand of a rule, typed once the context it is used in is known
+enum Operand {
+    Formula(String),
+    Term(String),
+    /// Name or application, Boolean or arithmetic depending on use
+    Atom(String),
+}
+
+/// Recursive descent encoder from rule text to SMT-LIB
+///
+/// Precedence from loosest: `↔`, `→` (right associative), `∨`, `∧`, `¬`,
+/// comparisons, `+ -`, `*`. Returns `None` outside that fragment.
+struct RuleEncoder {
+    tokens: Vec<Token>,
+    position: usize,
+    constants: BTreeMap<(String, &'static str), String>,
+}
+
+impl RuleEncoder {
+    /// Declarations and the encoded formula for `text`
+    fn encode(text: &str) -> Option<(String, String)> {
+        let mut encoder = Self { tokens: tokenize(text)?, position: 0, constants: BTreeMap::new() };
+        let operand = encoder.biconditional()?;
+        let formula = encoder.formula(operand)?;
+        if encoder.position != encoder.tokens.len() {
+            return None;
+        }
+
+        let declarations = encoder
+            .constants
+            .iter()
+            .map(|((_, sort), name)| format!("(declare-const {} {})\n", name, sort))
+            .collect();
+        Some((declarations, formula))
+    }
+
+    fn eat(&mut self, symbols: &[char]) -> Option<char> {
+        match self.tokens.get(self.position) {
+            Some(Token::Symbol(symbol)) if symbols.contains(symbol) => {
+                self.position += 1;
+                Some(*symbol)
+            }
+            _ => None,
+        }
+    }
+
+    fn biconditional(&mut self) -> Option<Operand> {
+        let mut left = self.implication()?;
+        while self.eat(&['↔', '⇔']).is_some() {
+            let right = self.implication()?;
+            left = Operand::Formula(format!("(= {} {})", self.formula(left)?, self.formula(right)?));
+        }
+        Some(left)
+    }
+
+    fn implication(&mut self) -> Option<Operand> {
+        let premise = self.chain(Self::conjunction, &['∨'], "or")?;
+        if self.eat(&['→', '⇒']).is_none() {
+            return Some(premise);
+        }
+        let conclusion = self.implication()?;
+        Some(Operand::Formula(format!("(=> {} {})", self.formula(premise)?, self.formula(conclusion)?)))
+    }
+
+    fn conjunction(&mut self) -> Option<Operand> {
+        self.chain(Self::negation, &['∧'], "and")
+    }
+
+    /// Operands of `next` joined by an associative connective
+    fn chain(&mut self, next: fn(&mut Self) -> Option<Operand>, symbols: &[char], operator: &str) -> Option<Operand> {
+        let first = next(self)?;
+        if self.eat(symbols).is_none() {
+            return Some(first);
+        }
+        let mut operands = vec![self.formula(first)?];
+        loop {
+            let operand = next(self)?;
+            operands.push(self.formula(operand)?);
+            if self.eat(symbols).is_none() {
+                return Some(Operand::Formula(format!("({} {})", operator, operands.join(" "))));
+            }
+        }
+    }
+
+    fn negation(&mut self) -> Option<Operand> {
+        if self.eat(&['¬']).is_some() {
+            let operand = self.negation()?;
+            return Some(Operand::Formula(format!("(not {})", self.formula(operand)?)));
+        }
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> Option<Operand> {
+        let left = self.sum()?;
+        let Some(operator) = self.eat(&['=', '≠', '<', '>', '≤', '≥']) else {
+            return Some(left);
+        };
+        let left = self.term(left)?;
+        let right = self.sum()?;
+        let right = self.term(right)?;
+        Some(Operand::Formula(match operator {
+            '≠' => format!("(not (= {} {}))", left, right),
+            '≤' => format!("(<= {} {})", left, right),
+            '≥' => format!("(>= {} {})", left, right),
+            operator => format!("({} {} {})", operator, left, right),
+        }))
+    }
+
+    fn sum(&mut self) -> Option<Operand> {
+        let mut left = self.product()?;
+        while let Some(operator) = self.eat(&['+', '-']) {
+            let right = self.product()?;
+            left = Operand::Term(format!("({} {} {})", operator, self.term(left)?, self.term(right)?));
+        }
+        Some(left)
+    }
+
+    fn product(&mut self) -> Option<Operand> {
+        let mut left = self.primary()?;
+        while self.eat(&['*']).is_some() {
+            let right = self.primary()?;
+            left = Operand::Term(format!("(* {} {})", self.term(left)?, self.term(right)?));
+        }
+        Some(left)
+    }
+
+    fn primary(&mut self) -> Option<Operand> {
+        let token = self.tokens.get(self.position)?.clone();
+        self.position += 1;
+        match token {
+            Token::Number(number) => Some(Operand::Term(number)),
+            Token::Symbol('⊤') => Some(Operand::Formula("true".to_string())),
+            Token::Symbol('⊥') => Some(Operand::Formula("false".to_string())),
+            Token::Symbol('-') => {
+                let operand = self.primary()?;
+                Some(Operand::Term(format!("(- {})", self.term(operand)?)))
+            }
+            Token::Symbol('(') => {
+                let inner = self.biconditional()?;
+                self.eat(&[')'])?;
+                Some(inner)
+            }
+            Token::Name(name) if self.eat(&['(']).is_some() => {
+                let mut arguments = Vec::new();
+                if self.eat(&[')']).is_none() {
+                    loop {
+                        arguments.push(match self.sum()? {
+                            Operand::Formula(text) | Operand::Term(text) | Operand::Atom(text) => text,
+                        });
+                        if self.eat(&[')']).is_some() {
+                            break;
+                        }
+                        self.eat(&[','])?;
+                    }
+                }
+                Some(Operand::Atom(format!("{}({})", name, arguments.join(","))))
+            }
+            Token::Name(name) => Some(Operand::Atom(name)),
+            Token::Symbol(_) => None,
+        }
+    }
+
+    fn formula(&mut self, operand: Operand) -> Option<String> {
+        match operand {
+            Operand::Formula(formula) => Some(formula),
+            Operand::Atom(atom) => Some(self.constant(atom, "Bool")),
+            Operand::Term(_) => None,
+        }
+    }
+
+    fn term(&mut self, operand: Operand) -> Option<String> {
+        match operand {
+            Operand::Term(term) => Some(term),
+            Operand::Atom(atom) => Some(self.constant(atom, "Real")),
+            Operand::Formula(_) => None,
+        }
+    }
+
+    /// SMT constant standing for `atom` at `sort`
+    fn constant(&mut self, atom: String, sort: &'static str) -> String {
+        let next = format!("c{}", self.constants.len());
+        self.constants.entry((atom, sort)).or_insert(next).clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classifies_propositional_and_arithmetic_rules() {
+        assert_eq!(classify_rule_text("p→p;"), RuleKind::Tautology);
+        assert_eq!(classify_rule_text("Ready(x)→Ready(x)"), RuleKind::Tautology);
+        assert_eq!(classify_rule_text("x+1>x"), RuleKind::Tautology);
+        assert_eq!(classify_rule_text("x<x"), RuleKind::Unsatisfiable);
+        assert_eq!(classify_rule_text("1=2"), RuleKind::Unsatisfiable);
+        assert_eq!(classify_rule_text("p→q"), RuleKind::Contingent);
+        assert_eq!(classify_rule_text("∀s:State→s=s"), RuleKind::Tautology);
+        assert_eq!(classify_rule_text("x∈S"), RuleKind::Unknown);
+    }
+}
@@ -248,10 +248,6 @@ impl TypeSystemAnalyzer {
             recommendations.push("Implement proper type isolation mechanisms".to_string());
         }
 
-        if recommendations.is_empty() {
-            recommendations.push("Type system analysis passed successfully".to_string());
-        }
-
         recommendations
     }
 
@@ -151,6 +151,53 @@ pub struct LogicAnalysisResult {
     pub consistency_score: f64,
     pub contradictions: Vec<String>,
     pub axiom_violations: Vec<String>,
+    /// Rules that are tautological or unsatisfiable on their own
+    #[serde(default)]
+    pub flagged_rules: Vec<RuleClassification>,
+}
+
+/// How a single rule behaves on its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum RuleKind {
+    /// Always true, so the rule is vacuous
+    Tautology,
+    /// Always false, so the rule can never hold
+    Unsatisfiable,
+    /// True under some assignments and false under others
+    Contingent,
+    /// Outside the fragment the solver decides
+    Unknown,
+}
+
+impl fmt::Display for RuleKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleKind::Tautology => write!(f, "tautology"),
+            RuleKind::Unsatisfiable => write!(f, "unsatisfiable"),
+            RuleKind::Contingent => write!(f, "contingent"),
+            RuleKind::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Classification of one rule of a rules block
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct RuleClassification {
+    /// Position of the rule within its block
+    pub index: usize,
+    pub text: String,
+    pub span: Option<Span>,
+    pub kind: RuleKind,
+}
+
+impl RuleClassification {
+    /// " at line L, column C" when the rule's span is known, else empty
+    pub fn location(&self) -> String {
+        self.span
+            .as_ref()
+            .map(|span| format!(" at line {}, column {}", span.line, span.column))
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -197,7 +244,9 @@ pub struct DeceptionAnalysisResult {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)] pub struct VerificationFailure { pub component: String, pub reason: String }
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)] pub struct VerificationWarning { pub component: String, pub warning: String }
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)] pub struct CoverageMetrics { pub line_coverage: f64, pub branch_coverage: f64 }
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)] pub struct PerformanceMetrics { pub verification_time_ms: u64, pub memory_usage_mb: usize }
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)] pub struct PerformanceMetrics { pub verification_time_ms: u64, pub memory_usage_mb: usize, #[serde(default)] pub stage_timings: Vec<StageTiming> }
+/// Wall-clock time spent in one deep-verifier stage, in execution order
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)] pub struct StageTiming { pub stage: String, pub duration: std::time::Duration }
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)] pub struct AttackSurfaceAnalysis { pub surface_area: f64, pub vulnerabilities: Vec<String> }
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)] pub struct SecurityRecommendation { pub priority: String, pub action: String }
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)] pub struct ComplianceStatus { pub compliant: bool, pub missing_requirements: Vec<String> }
@@ -265,6 +314,7 @@ impl Default for PerformanceMetrics {
         Self {
             verification_time_ms: 0,
             memory_usage_mb: 0,
+            stage_timings: Vec::new(),
         }
     }
 }
@@ -332,6 +382,7 @@ impl DeepVerificationResult {
                 performance_metrics: PerformanceMetrics {
                     verification_time_ms: 1000,
                     memory_usage_mb: 100,
+                    stage_timings: Vec::new(),
                 },
             },
             security_assessment: SecurityAssessment {
@@ -376,6 +427,7 @@ mod tests {
                 performance_metrics: PerformanceMetrics {
                     verification_time_ms: 1500,
                     memory_usage_mb: 256,
+                    stage_timings: Vec::new(),
                 },
             },
             security_assessment: SecurityAssessment {
@@ -15,6 +15,7 @@ pub use deep_verifier::{
     DependencyGraphAnalyzer,
     MathematicalCorrectnessEngine,
     DeceptionDetector,
+    DeceptionConfig,
     SecurityAssessment,
     ThreatLevel,
     VerificationDetails,
@@ -51,8 +52,9 @@ pub use verification_pipeline::{
 pub use deep_verifier::DeepVerificationResult as SemanticAnalysisResult;
 pub type SemanticAnalysis = deep_verifier::DeepVerificationResult;
 
-// Quality tier enum for compatibility
-#[derive(Debug, Clone, PartialEq, Copy)]
+// Quality tier enum for compatibility; variants are declared in ascending
+// order so `Ord` ranks tiers from Reject to Platinum
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, serde::Serialize, serde::Deserialize)]
 pub enum QualityTier {
     Reject,
     Bronze,
@@ -81,7 +83,10 @@ impl SemanticAnalyzer {
 // Legacy compatibility adapter for old ValidationResult usage
 impl DeepVerificationResult {
     pub fn valid(&self) -> bool {
-        self.overall_confidence > 0.8
+        use crate::mathematical_evaluator::compare_exact;
+        use crate::tier_thresholds::CONFIDENCE;
+
+        compare_exact(self.overall_confidence, CONFIDENCE) == Some(std::cmp::Ordering::Greater)
     }
 
     pub fn tier(&self) -> QualityTier {
@@ -113,8 +118,13 @@ impl DeepVerificationResult {
         self.overall_confidence
     }
 
+    /// Recommendations worth acting on; informational status notes are skipped
     pub fn warnings(&self) -> Vec<String> {
-        self.recommendations.iter().map(|r| r.recommendation.clone()).collect()
+        self.recommendations
+            .iter()
+            .filter(|r| r.priority != "Info")
+            .map(|r| r.recommendation.clone())
+            .collect()
     }
 
     pub fn errors(&self) -> Vec<String> {
@@ -134,37 +144,6 @@ impl DeepVerificationResult {
         }
     }
 
-    pub fn relational_analysis(&self) -> Option<MockRelationalAnalysis> {
-        Some(MockRelationalAnalysis {
-            consistency_score: self.logic_consistency_score,
-            constraint_analysis: MockConstraintAnalysis {
-                constraints: vec!["type_consistency".to_string(), "logical_constraints".to_string()],
-                satisfied: vec!["type_consistency".to_string()],
-            },
-            conflict_analysis: MockConflictAnalysis {
-                conflicts: vec![],
-            },
-        })
-    }
-
-    pub fn temporal_analysis(&self) -> Option<MockTemporalAnalysis> {
-        Some(MockTemporalAnalysis {
-            consistency_score: self.logic_consistency_score,
-            formula_analysis: MockFormulaAnalysis {
-                formulas: vec!["temporal_formula_1".to_string(), "temporal_formula_2".to_string()],
-            },
-            pattern_analysis: MockPatternAnalysis {
-                patterns: vec!["pattern_1".to_string()],
-            },
-        })
-    }
-
-    pub fn symbol_stats(&self) -> MockSymbolStats {
-        MockSymbolStats {
-            category_counts: std::collections::HashMap::new(),
-        }
-    }
-
     pub fn block_score(&self) -> f64 {
         (self.semantic_score + self.type_safety_score +
          self.logic_consistency_score + self.mathematical_correctness_score) / 4.0
@@ -177,47 +156,21 @@ pub struct MockTypeAnalysis {
     pub undefined_types: Vec<String>,
 }
 
-#[derive(Debug, Clone)]
-pub struct MockRelationalAnalysis {
-    pub consistency_score: f64,
-    pub constraint_analysis: MockConstraintAnalysis,
-    pub conflict_analysis: MockConflictAnalysis,
-}
-
-#[derive(Debug, Clone)]
-pub struct MockTemporalAnalysis {
-    pub consistency_score: f64,
-    pub formula_analysis: MockFormulaAnalysis,
-    pub pattern_analysis: MockPatternAnalysis,
-}
-
-#[derive(Debug, Clone)]
-pub struct MockConstraintAnalysis {
-    pub constraints: Vec<String>,
-    pub satisfied: Vec<String>,
-}
-
-#[derive(Debug, Clone)]
-pub struct MockConflictAnalysis {
-    pub conflicts: Vec<String>,
-}
-
-#[derive(Debug, Clone)]
-pub struct MockFormulaAnalysis {
-    pub formulas: Vec<String>,
-}
-
-#[derive(Debug, Clone)]
-pub struct MockPatternAnalysis {
-    pub patterns: Vec<String>,
-}
-
-#[derive(Debug, Clone)]
-pub struct MockSymbolStats {
-    pub category_counts: std::collections::HashMap<String, usize>,
-}
-
 impl QualityTier {
+    /// Classify a density δ against `tier_thresholds` using exact comparisons
+    pub fn from_delta(delta: f64) -> Self {
+        use crate::mathematical_evaluator::meets_threshold;
+        use crate::tier_thresholds::{BRONZE, GOLD, PLATINUM, SILVER};
+
+        match delta {
+            d if meets_threshold(d, PLATINUM) => QualityTier::Platinum,
+            d if meets_threshold(d, GOLD) => QualityTier::Gold,
+            d if meets_threshold(d, SILVER) => QualityTier::Silver,
+            d if meets_threshold(d, BRONZE) => QualityTier::Bronze,
+            _ => QualityTier::Reject,
+        }
+    }
+
     pub fn symbol(&self) -> &str {
         match self {
             QualityTier::Reject => "⊘",
@@ -228,6 +181,30 @@ impl QualityTier {
         }
     }
 
+    /// Glyph used for the tier in documents, e.g. `τ≜◊⁺⁺`
+    pub fn glyph(&self) -> &'static str {
+        match self {
+            QualityTier::Reject => "⊘",
+            QualityTier::Bronze => "◊⁻",
+            QualityTier::Silver => "◊",
+            QualityTier::Gold => "◊⁺",
+            QualityTier::Platinum => "◊⁺⁺",
+        }
+    }
+
+    /// Tier written as `glyph`, if it is one of the canonical tier glyphs
+    pub fn from_glyph(glyph: &str) -> Option<Self> {
+        [
+            QualityTier::Reject,
+            QualityTier::Bronze,
+            QualityTier::Silver,
+            QualityTier::Gold,
+            QualityTier::Platinum,
+        ]
+        .into_iter()
+        .find(|tier| tier.glyph() == glyph)
+    }
+
     pub fn name(&self) -> &str {
         match self {
             QualityTier::Reject => "Reject",
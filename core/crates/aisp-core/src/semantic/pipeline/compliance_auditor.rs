@@ -416,6 +416,7 @@ mod tests {
                 performance_metrics: crate::semantic::deep_verifier::PerformanceMetrics {
                     verification_time_ms: 1000,
                     memory_usage_mb: 128,
+                    stage_timings: Vec::new(),
                 },
             },
             security_assessment: crate::semantic::deep_verifier::SecurityAssessment {
@@ -307,7 +307,7 @@ mod tests {
                         failed_verifications: Vec::new(),
                         warnings: Vec::new(),
                         coverage_metrics: crate::semantic::deep_verifier::CoverageMetrics { line_coverage: 0.95, branch_coverage: 0.95 },
-                        performance_metrics: crate::semantic::deep_verifier::PerformanceMetrics { verification_time_ms: 100, memory_usage_mb: 10 },
+                        performance_metrics: crate::semantic::deep_verifier::PerformanceMetrics { verification_time_ms: 100, memory_usage_mb: 10, stage_timings: Vec::new() },
                     },
                     security_assessment: crate::semantic::deep_verifier::SecurityAssessment {
                         threat_level: crate::semantic::deep_verifier::ThreatLevel::Minimal,
@@ -346,6 +346,8 @@ mod tests {
                 },
                 conflicts_detected: Vec::new(),
                 resolved_conflicts: Vec::new(),
+                verifier_disagreements: Vec::new(),
+                unified_confidence: Default::default(),
                 integration_metrics: crate::semantic::cross_validator::IntegrationMetrics {
                     pipeline_efficiency: 0.95,
                     verification_time_ms: 100,
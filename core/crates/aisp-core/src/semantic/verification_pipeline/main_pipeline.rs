@@ -584,7 +584,8 @@ mod tests {
                     },
                     performance_metrics: crate::semantic::deep_verifier::PerformanceMetrics { 
                         verification_time_ms: 100, 
-                        memory_usage_mb: 10 
+                        memory_usage_mb: 10,
+                        stage_timings: Vec::new(),
                     },
                 },
                 security_assessment: crate::semantic::deep_verifier::SecurityAssessment {
@@ -630,6 +631,8 @@ mod tests {
             },
             conflicts_detected: Vec::new(),
             resolved_conflicts: Vec::new(),
+            verifier_disagreements: Vec::new(),
+            unified_confidence: Default::default(),
             integration_metrics: crate::semantic::cross_validator::IntegrationMetrics {
                 pipeline_efficiency: 0.90,
                 verification_time_ms: 1000,
@@ -701,7 +704,8 @@ mod tests {
                         line_coverage: 0.96, branch_coverage: 0.96 
                     },
                     performance_metrics: crate::semantic::deep_verifier::PerformanceMetrics { 
-                        verification_time_ms: 100, memory_usage_mb: 10 
+                        verification_time_ms: 100, memory_usage_mb: 10,
+                        stage_timings: Vec::new(),
                     },
                 },
                 security_assessment: crate::semantic::deep_verifier::SecurityAssessment {
@@ -745,6 +749,8 @@ mod tests {
             },
             conflicts_detected: Vec::new(),
             resolved_conflicts: Vec::new(),
+            verifier_disagreements: Vec::new(),
+            unified_confidence: Default::default(),
             integration_metrics: crate::semantic::cross_validator::IntegrationMetrics {
                 pipeline_efficiency: 0.96,
                 verification_time_ms: 100,
@@ -824,7 +830,8 @@ mod tests {
                             line_coverage: 0.90, branch_coverage: 0.90 
                         },
                         performance_metrics: crate::semantic::deep_verifier::PerformanceMetrics { 
-                            verification_time_ms: 100, memory_usage_mb: 10 
+                            verification_time_ms: 100, memory_usage_mb: 10,
+                            stage_timings: Vec::new(),
                         },
                     },
                     security_assessment: crate::semantic::deep_verifier::SecurityAssessment {
@@ -868,6 +875,8 @@ mod tests {
                 },
                 conflicts_detected: Vec::new(),
                 resolved_conflicts: Vec::new(),
+                verifier_disagreements: Vec::new(),
+                unified_confidence: Default::default(),
                 integration_metrics: crate::semantic::cross_validator::IntegrationMetrics {
                     pipeline_efficiency: 0.90,
                     verification_time_ms: 1000,
@@ -102,6 +102,13 @@ impl SMTFormulaConverter {
                     t_var, t_var, right_smt, s_var, s_var, s_var, t_var, left_smt
                 ))
             }
+            FormulaStructure::PathForall(_) | FormulaStructure::PathExists(_) => {
+                // A path quantifier ranges over the branches of a model, which
+                // first-order SMT has no notion of
+                Err(AispError::validation_error(
+                    "Path quantifiers have no SMT encoding".to_string(),
+                ))
+            }
             FormulaStructure::ArithmeticEqual(left, right) => {
                 let left_smt = self.convert_term_to_smt(left)?;
                 let right_smt = self.convert_term_to_smt(right)?;
@@ -7,6 +7,7 @@ use crate::error::*;
 use crate::property_types::*;
 use crate::smt_types::*;
 use crate::smt_formula_converter::SMTFormulaConverter;
+use crate::invariant_types::TemporalClass;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 
@@ -224,17 +225,20 @@ impl SMTGenerator {
     fn generate_property_assertion(&mut self, property: &ExtractedProperty, assertion_name: &str, script: &mut String) -> AispResult<SMTExpectedResult> {
         let smt_formula = self.converter.convert_formula_to_smt(&property.formula)?;
         
-        // Determine expected result based on property type
-        let expected = match property.property_type {
-            PropertyType::TypeSafety | PropertyType::FunctionalCorrectness | PropertyType::TemporalSafety => {
-                // These should be valid (unsat when negated)
-                SMTExpectedResult::Unsat
-            }
-            PropertyType::TemporalLiveness => {
-                // Liveness properties should be satisfiable
-                SMTExpectedResult::Sat
-            }
-            _ => SMTExpectedResult::Unknown
+        // Temporal structure decides the expected result, falling back to
+        // the property type for formulas without temporal operators
+        let expected = match TemporalClass::of(&property.formula.structure) {
+            // Safety properties should be valid (unsat when negated)
+            Some(TemporalClass::Safety) => SMTExpectedResult::Unsat,
+            // Liveness properties should be satisfiable
+            Some(TemporalClass::Liveness) => SMTExpectedResult::Sat,
+            None => match property.property_type {
+                PropertyType::TypeSafety | PropertyType::FunctionalCorrectness | PropertyType::TemporalSafety => {
+                    SMTExpectedResult::Unsat
+                }
+                PropertyType::TemporalLiveness => SMTExpectedResult::Sat,
+                _ => SMTExpectedResult::Unknown
+            },
         };
 
         // Generate assertion
@@ -363,6 +367,60 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_temporal_class_sets_expected_results() -> AispResult<()> {
+        let p = || Box::new(FormulaStructure::Atomic(AtomicFormula {
+            predicate: "P".to_string(),
+            terms: vec![],
+            type_signature: None,
+        }));
+        let property = |id: &str, structure: FormulaStructure| ExtractedProperty {
+            id: id.to_string(),
+            name: id.to_string(),
+            property_type: PropertyType::LogicalAssertion,
+            formula: PropertyFormula {
+                structure,
+                quantifiers: vec![],
+                free_variables: HashSet::new(),
+                predicates: HashSet::new(),
+                functions: HashSet::new(),
+                constants: HashSet::new(),
+            },
+            context: PropertyContext {
+                type_definitions: HashMap::new(),
+                function_definitions: HashMap::new(),
+                constants: HashMap::new(),
+                dependencies: vec![],
+            },
+            source_location: SourceLocation {
+                block_type: "Rules".to_string(),
+                line: None,
+                column: None,
+                source_text: None,
+            },
+            complexity: PropertyComplexity {
+                quantifier_depth: 0,
+                logical_connectives: 1,
+                function_applications: 0,
+                variable_count: 0,
+                difficulty_score: 1,
+            },
+        };
+
+        let mut generator = SMTGenerator::new();
+        let result = generator.generate_smt_program(&[
+            property("always", FormulaStructure::TemporalAlways(p())),
+            property("eventually", FormulaStructure::TemporalEventually(p())),
+            property("atemporal", *p()),
+        ])?;
+
+        assert_eq!(
+            result.program.expected_results,
+            [SMTExpectedResult::Unsat, SMTExpectedResult::Sat, SMTExpectedResult::Unknown]
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_reset_functionality() {
         let mut generator = SMTGenerator::new();
@@ -3,8 +3,10 @@
 //! This module provides efficient lookup and parsing of AISP's special
 //! Unicode symbols with compile-time verification.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::OnceLock;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// AISP symbol categories for density calculation
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -176,6 +178,72 @@ pub fn lookup_symbol(ch: char) -> Option<&'static Symbol> {
     symbol_map.get(&ch).copied()
 }
 
+/// Look up an AISP symbol by glyph, which may arrive in decomposed form
+/// (e.g. `∈` followed by U+0338 for `∉`)
+pub fn lookup_glyph(glyph: &str) -> Option<&'static Symbol> {
+    let glyph = normalize_nfc(glyph);
+    let mut chars = glyph.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => lookup_symbol(ch),
+        _ => None,
+    }
+}
+
+/// Normalize text to NFC before symbol classification
+///
+/// Some input methods emit negated operators such as `∉` and `≢` as a base
+/// glyph plus a combining overlay, which per-character lookup would miss.
+/// Text already in NFC is borrowed; without the `unicode-normalization`
+/// feature text is returned unchanged.
+pub fn normalize_nfc(text: &str) -> Cow<'_, str> {
+    #[cfg(feature = "unicode-normalization")]
+    {
+        use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+        if is_nfc_quick(text.chars()) != IsNormalized::Yes {
+            return Cow::Owned(text.nfc().collect());
+        }
+    }
+    Cow::Borrowed(text)
+}
+
+/// Offsets at which `text` and its NFC normalization line up, as
+/// (offset in text, offset in normalized text) pairs in increasing order
+///
+/// Normalization only rewrites a starter together with the combining
+/// marks after it, so the text is cut before every starter that cannot
+/// compose with the character preceding it; each piece normalizes on its
+/// own. The list starts at `(0, 0)` and ends at both lengths.
+pub fn nfc_boundaries(text: &str) -> Vec<(usize, usize)> {
+    let mut boundaries = vec![(0, 0)];
+    #[cfg(feature = "unicode-normalization")]
+    {
+        use unicode_normalization::char::{canonical_combining_class, compose};
+        use unicode_normalization::UnicodeNormalization;
+
+        let mut normalized = 0;
+        let mut piece_start = 0;
+        let mut previous: Option<char> = None;
+        for (offset, ch) in text.char_indices() {
+            let cut = canonical_combining_class(ch) == 0 && previous.map_or(false, |previous| compose(previous, ch).is_none());
+            if cut {
+                normalized += text[piece_start..offset].nfc().map(char::len_utf8).sum::<usize>();
+                boundaries.push((offset, normalized));
+                piece_start = offset;
+            }
+            previous = Some(ch);
+        }
+        normalized += text[piece_start..].nfc().map(char::len_utf8).sum::<usize>();
+        if piece_start < text.len() {
+            boundaries.push((text.len(), normalized));
+        }
+    }
+    #[cfg(not(feature = "unicode-normalization"))]
+    boundaries.extend(text.char_indices().skip(1).map(|(offset, _)| (offset, offset)).chain(
+        (!text.is_empty()).then(|| (text.len(), text.len())),
+    ));
+    boundaries
+}
+
 /// Look up an AISP symbol by ASCII alternative
 pub fn lookup_ascii(ascii: &str) -> Option<&'static Symbol> {
     let (_, ascii_map) = init_symbol_maps();
@@ -195,33 +263,80 @@ pub fn symbols_in_category(category: SymbolCategory) -> Vec<&'static Symbol> {
         .collect()
 }
 
+/// Count non-whitespace tokens, one per grapheme cluster
+///
+/// A glyph carrying combining marks or a variation selector is a single
+/// token, so decorated symbols do not inflate the count. This is the basis
+/// for symbol density.
+pub fn count_tokens(text: &str) -> usize {
+    text.graphemes(true).filter(|grapheme| !is_whitespace_grapheme(grapheme)).count()
+}
+
+/// Count grapheme clusters built on an AISP symbol, the numerator of
+/// symbol density
+pub fn count_symbols(text: &str) -> usize {
+    text.graphemes(true).filter(|grapheme| grapheme_symbol(grapheme).is_some()).count()
+}
+
+/// Count non-whitespace Unicode scalar values, for comparison with
+/// `count_tokens`
+pub fn count_chars(text: &str) -> usize {
+    text.chars().filter(|c| !c.is_whitespace()).count()
+}
+
+fn is_whitespace_grapheme(grapheme: &str) -> bool {
+    grapheme.chars().all(char::is_whitespace)
+}
+
+/// The AISP symbol a grapheme cluster is built on, if any
+fn grapheme_symbol(grapheme: &str) -> Option<&'static Symbol> {
+    grapheme.chars().next().and_then(lookup_symbol)
+}
+
 /// Calculate pure symbol density
+///
+/// Comments are excluded, so annotating a document does not dilute it.
 pub fn calculate_symbol_density(text: &str) -> f64 {
-    let total_chars = text.chars().filter(|c| !c.is_whitespace()).count();
-    let symbol_count = text.chars().filter(|&c| is_aisp_symbol(c)).count();
+    let text = crate::parser::comments::strip_comments(text);
+    let text = normalize_nfc(&text);
+    let total_tokens = count_tokens(&text);
+    let symbol_count = count_symbols(&text);
     
-    if total_chars == 0 {
+    if total_tokens == 0 {
         0.0
     } else {
-        symbol_count as f64 / total_chars as f64
+        symbol_count as f64 / total_tokens as f64
+    }
+}
+
+/// Count occurrences of each AISP symbol, excluding comments
+pub fn symbol_histogram(text: &str) -> HashMap<char, usize> {
+    let text = crate::parser::comments::strip_comments(text);
+    let text = normalize_nfc(&text);
+    let mut histogram = HashMap::new();
+    for ch in text.chars().filter(|&c| is_aisp_symbol(c)) {
+        *histogram.entry(ch).or_insert(0) += 1;
     }
+    histogram
 }
 
-/// Calculate weighted symbol density by category
+/// Calculate weighted symbol density by category, excluding comments
 pub fn calculate_weighted_density(text: &str) -> f64 {
+    let text = crate::parser::comments::strip_comments(text);
+    let text = normalize_nfc(&text);
     let mut category_counts = HashMap::new();
-    let mut total_chars = 0;
+    let mut total_tokens = 0;
     
-    for ch in text.chars() {
-        if !ch.is_whitespace() {
-            total_chars += 1;
-            if let Some(symbol) = lookup_symbol(ch) {
+    for grapheme in text.graphemes(true) {
+        if !is_whitespace_grapheme(grapheme) {
+            total_tokens += 1;
+            if let Some(symbol) = grapheme_symbol(grapheme) {
                 *category_counts.entry(&symbol.category).or_insert(0) += 1;
             }
         }
     }
     
-    if total_chars == 0 {
+    if total_tokens == 0 {
         return 0.0;
     }
     
@@ -242,7 +357,7 @@ pub fn calculate_weighted_density(text: &str) -> f64 {
         weighted_score += count as f64 * weight;
     }
     
-    weighted_score / total_chars as f64
+    weighted_score / total_tokens as f64
 }
 
 #[cfg(test)]
@@ -286,6 +401,34 @@ mod tests {
         assert_eq!(density, 3.0 / 6.0); // 3 symbols out of 6 non-whitespace chars
     }
 
+    #[test]
+    fn test_symbol_density_ignores_comments() {
+        assert_eq!(calculate_symbol_density("≜∀⇒abc # plain words"), 3.0 / 6.0);
+        assert_eq!(calculate_symbol_density("≜∀⇒abc /* ∀∀∀ */"), 3.0 / 6.0);
+    }
+
+    #[test]
+    fn test_symbol_histogram() {
+        let histogram = symbol_histogram("∀x≜y ∀z # ∀");
+        assert_eq!(histogram.get(&'∀'), Some(&2));
+        assert_eq!(histogram.get(&'≜'), Some(&1));
+        assert_eq!(histogram.len(), 2);
+    }
+
+    #[test]
+    fn test_combining_mark_glyph_is_one_token() {
+        // ∀ with a combining dot above has no precomposed form
+        let glyph = "\u{2200}\u{0307}";
+        assert_eq!(count_chars(glyph), 2);
+        assert_eq!(count_tokens(glyph), 1);
+
+        let text = "\u{2200}\u{0307}x ⇒\u{FE0E}y";
+        assert_eq!(count_chars(text), 6);
+        assert_eq!(count_tokens(text), 4);
+        assert_eq!(calculate_symbol_density(text), 2.0 / 4.0);
+        assert_eq!(calculate_weighted_density(text), (2.5 + 2.5) / 4.0);
+    }
+
     #[test]
     fn test_weighted_density() {
         let text = "≜∀⇒"; // Definition + Quantifier + Logic
@@ -293,6 +436,22 @@ mod tests {
         assert!(weighted > calculate_symbol_density(text)); // Weighted should be higher
     }
 
+    #[test]
+    #[cfg(feature = "unicode-normalization")]
+    fn test_decomposed_operator_is_recognized() {
+        let decomposed = "x\u{2208}\u{0338}S";
+        assert_eq!(decomposed.chars().count(), 4);
+
+        assert_eq!(normalize_nfc(decomposed), "x∉S");
+        assert_eq!(lookup_glyph("\u{2208}\u{0338}").unwrap().name, "NOT_ELEMENT_OF");
+        assert_eq!(lookup_glyph("≢").unwrap().name, "NOT_EQUIVALENT");
+        assert!(lookup_glyph("x∉").is_none());
+        assert!(matches!(normalize_nfc("x∉S"), Cow::Borrowed(_)));
+
+        assert_eq!(symbol_histogram(decomposed).get(&'∉'), Some(&1));
+        assert_eq!(calculate_symbol_density(decomposed), calculate_symbol_density("x∉S"));
+    }
+
     #[test]
     fn test_symbols_in_category() {
         let logic_symbols = symbols_in_category(SymbolCategory::Logic);
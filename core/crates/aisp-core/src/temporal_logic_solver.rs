@@ -2,10 +2,13 @@
 //!
 //! This module provides capabilities for solving Linear Temporal Logic (LTL)
 //! and Computation Tree Logic (CTL) formulas, including satisfiability checking,
-//! formula evaluation, and constraint solving.
+//! formula evaluation, and constraint solving. Formulas evaluated over
+//! finite traces may also use past-time operators (yesterday, previously,
+//! historically and since).
 
 use crate::error::*;
 use crate::ast::canonical::Span;
+use crate::model_checker::TemporalFormula;
 use crate::temporal_operator_analyzer::{TemporalOperator, OperatorInstance};
 use crate::temporal_pattern_detector::{TemporalPattern, PatternType};
 use std::collections::{HashMap, HashSet};
@@ -26,6 +29,10 @@ pub struct TemporalLogicSolver {
     state_cache: HashMap<String, StateSpace>,
     /// Solver configuration
     config: SolverConfig,
+    /// Formulas checked against `traces` alongside the document's own
+    formulas: Vec<(String, TemporalFormula)>,
+    /// Sample executions that registered formulas are evaluated over
+    traces: Vec<ExecutionTrace>,
 }
 
 /// Solver configuration options
@@ -259,6 +266,8 @@ impl TemporalLogicSolver {
             formula_cache: HashMap::new(),
             state_cache: HashMap::new(),
             config: SolverConfig::default(),
+            formulas: Vec::new(),
+            traces: Vec::new(),
         }
     }
 
@@ -268,9 +277,22 @@ impl TemporalLogicSolver {
             formula_cache: HashMap::new(),
             state_cache: HashMap::new(),
             config,
+            formulas: Vec::new(),
+            traces: Vec::new(),
         }
     }
 
+    /// Register a formula for `solve_formulas` to check against the sample
+    /// traces; it may use past-time operators
+    pub fn add_formula(&mut self, id: &str, formula: TemporalFormula) {
+        self.formulas.push((id.to_string(), formula));
+    }
+
+    /// Add a sample execution for registered formulas to be evaluated over
+    pub fn add_trace(&mut self, trace: ExecutionTrace) {
+        self.traces.push(trace);
+    }
+
     /// Solve temporal logic formulas from document
     pub fn solve_formulas(
         &mut self,
@@ -345,6 +367,17 @@ impl TemporalLogicSolver {
             });
         }
 
+        for (id, formula) in &self.formulas {
+            let text = format!("{:?}", formula);
+            analyzed_formulas.push(AnalyzedFormula {
+                id: id.clone(),
+                complexity: self.calculate_complexity(&text),
+                formula: text,
+                formula_type: FormulaType::LTL,
+                result: self.check_on_traces(id, formula),
+            });
+        }
+
         // Determine overall status
         let overall_status = self.determine_overall_status(&analyzed_formulas);
 
@@ -477,6 +510,111 @@ impl TemporalLogicSolver {
         Ok((SatisfiabilityStatus::Satisfiable, None, None))
     }
 
+    /// Check a registered formula against the sample traces
+    ///
+    /// A trace it holds on witnesses satisfiability. One it fails on is
+    /// only reported as a counterexample, since executions outside the
+    /// sample may still satisfy it.
+    fn check_on_traces(&self, id: &str, formula: &TemporalFormula) -> SolverResult {
+        let start_time = std::time::Instant::now();
+        let witness = self.traces.iter().find(|trace| self.holds_on_trace(formula, trace));
+        let counterexample = self
+            .traces
+            .iter()
+            .find(|trace| !self.holds_on_trace(formula, trace))
+            .map(|trace| Counterexample {
+                violated_formula: format!("{:?}", formula),
+                trace: trace.clone(),
+                explanation: format!("{} does not hold at the first state", id),
+                violation_step: 0,
+            });
+
+        SolverResult {
+            formula_id: id.to_string(),
+            satisfiable: if witness.is_some() {
+                SatisfiabilityStatus::Satisfiable
+            } else {
+                SatisfiabilityStatus::Unknown
+            },
+            witness: witness.cloned(),
+            counterexample,
+            statistics: SolverStatistics {
+                solving_time_ms: start_time.elapsed().as_millis() as u64,
+                states_explored: self.traces.iter().map(|trace| trace.states.len()).sum(),
+                transitions_evaluated: 0,
+                peak_memory_bytes: self.estimate_memory_usage(),
+                iterations: self.traces.len(),
+            },
+            simplified_formula: None,
+        }
+    }
+
+    /// Evaluate a formula at the first state of a finite trace
+    pub fn holds_on_trace(&self, formula: &TemporalFormula, trace: &ExecutionTrace) -> bool {
+        !trace.states.is_empty() && self.evaluate_at(formula, trace, 0)
+    }
+
+    /// Evaluate a formula at `position` of a finite trace
+    ///
+    /// Positions outside the trace satisfy nothing. Next is false at the
+    /// last state, and past-time operators never see before the first.
+    /// A single trace is the only path, so CTL path quantifiers reduce to
+    /// the operator they quantify.
+    pub fn evaluate_at(&self, formula: &TemporalFormula, trace: &ExecutionTrace, position: usize) -> bool {
+        let last = match trace.states.len().checked_sub(1) {
+            Some(last) if position <= last => last,
+            _ => return false,
+        };
+        let eval = |inner: &TemporalFormula, at: usize| self.evaluate_at(inner, trace, at);
+        let until = |left: &TemporalFormula, right: &TemporalFormula| {
+            (position..=last)
+                .find(|&at| eval(right, at))
+                .map_or(false, |at| (position..at).all(|before| eval(left, before)))
+        };
+
+        match formula {
+            TemporalFormula::Atomic(name) => {
+                let state = &trace.states[position];
+                state.properties.contains(name)
+                    || state.assignments.get(name) == Some(&LogicalValue::True)
+            }
+            TemporalFormula::Not(inner) => !eval(inner, position),
+            TemporalFormula::And(left, right) => eval(left, position) && eval(right, position),
+            TemporalFormula::Or(left, right) => eval(left, position) || eval(right, position),
+            TemporalFormula::Implies(left, right) => !eval(left, position) || eval(right, position),
+            TemporalFormula::Next(inner)
+            | TemporalFormula::ExistsNext(inner)
+            | TemporalFormula::ForallNext(inner) => position < last && eval(inner, position + 1),
+            TemporalFormula::Always(inner)
+            | TemporalFormula::ExistsAlways(inner)
+            | TemporalFormula::ForallAlways(inner) => (position..=last).all(|at| eval(inner, at)),
+            TemporalFormula::Eventually(inner)
+            | TemporalFormula::ExistsEventually(inner)
+            | TemporalFormula::ForallEventually(inner) => (position..=last).any(|at| eval(inner, at)),
+            TemporalFormula::Until(left, right)
+            | TemporalFormula::ExistsUntil(left, right)
+            | TemporalFormula::ForallUntil(left, right) => until(left, right),
+            // φ W ψ ≡ (φ U ψ) ∨ □φ
+            TemporalFormula::WeakUntil(left, right) => {
+                until(left, right) || (position..=last).all(|at| eval(left, at))
+            }
+            // φ R ψ: ψ holds up to and including the first φ, or throughout
+            TemporalFormula::Release(left, right) => (position..=last)
+                .all(|at| eval(right, at) || (position..at).any(|before| eval(left, before))),
+            // φ M ψ ≡ ψ U (φ ∧ ψ)
+            TemporalFormula::StrongRelease(left, right) => (position..=last)
+                .find(|&at| eval(left, at) && eval(right, at))
+                .map_or(false, |at| (position..at).all(|before| eval(right, before))),
+            TemporalFormula::Yesterday(inner) => position > 0 && eval(inner, position - 1),
+            TemporalFormula::Previously(inner) => (0..=position).any(|at| eval(inner, at)),
+            TemporalFormula::Historically(inner) => (0..=position).all(|at| eval(inner, at)),
+            TemporalFormula::Since(left, right) => (0..=position)
+                .rev()
+                .find(|&at| eval(right, at))
+                .map_or(false, |at| (at + 1..=position).all(|after| eval(left, after))),
+        }
+    }
+
     /// Check CTL formula satisfiability
     fn check_ctl_satisfiability(
         &self,
@@ -769,6 +907,99 @@ mod tests {
         assert_eq!(result.overall_status, SatisfiabilityStatus::Valid);
     }
 
+    fn trace_of(steps: &[&[&str]]) -> ExecutionTrace {
+        let states: Vec<StateSnapshot> = steps
+            .iter()
+            .enumerate()
+            .map(|(step, props)| StateSnapshot {
+                id: format!("s{}", step),
+                assignments: HashMap::new(),
+                properties: props.iter().map(|prop| prop.to_string()).collect(),
+                step,
+            })
+            .collect();
+        ExecutionTrace {
+            length: states.len(),
+            states,
+            transitions: Vec::new(),
+            is_infinite: false,
+            loop_point: None,
+        }
+    }
+
+    #[test]
+    fn test_since_on_finite_traces() {
+        let solver = TemporalLogicSolver::new();
+        let p = || Box::new(TemporalFormula::Atomic("p".to_string()));
+        let q = || Box::new(TemporalFormula::Atomic("q".to_string()));
+        let since = TemporalFormula::Since(p(), q());
+
+        // q at step 1, p ever after
+        let trace = trace_of(&[&[], &["q"], &["p"], &["p"]]);
+        let results: Vec<bool> = (0..4).map(|at| solver.evaluate_at(&since, &trace, at)).collect();
+        assert_eq!(results, vec![false, true, true, true]);
+
+        // p breaks at step 2, so q at step 1 no longer supports step 3
+        let trace = trace_of(&[&["q"], &["p"], &[], &["p"]]);
+        let results: Vec<bool> = (0..4).map(|at| solver.evaluate_at(&since, &trace, at)).collect();
+        assert_eq!(results, vec![true, true, false, false]);
+
+        // Never q: p since q never holds
+        let trace = trace_of(&[&["p"], &["p"]]);
+        assert!(!solver.evaluate_at(&since, &trace, 1));
+
+        // □(p S q) reads as "q started it and p has held ever since"
+        let trace = trace_of(&[&["q"], &["p"], &["p", "q"], &["p"]]);
+        assert!(solver.holds_on_trace(&TemporalFormula::Always(Box::new(since)), &trace));
+    }
+
+    #[test]
+    fn test_previously_on_finite_traces() {
+        let solver = TemporalLogicSolver::new();
+        let previously = TemporalFormula::Previously(Box::new(TemporalFormula::Atomic("p".to_string())));
+
+        let trace = trace_of(&[&[], &[], &["p"], &[]]);
+        let results: Vec<bool> = (0..4).map(|at| solver.evaluate_at(&previously, &trace, at)).collect();
+        assert_eq!(results, vec![false, false, true, true]);
+        assert!(!solver.holds_on_trace(&previously, &trace));
+        assert!(solver.holds_on_trace(&TemporalFormula::Eventually(Box::new(previously.clone())), &trace));
+
+        let yesterday = TemporalFormula::Yesterday(Box::new(TemporalFormula::Atomic("p".to_string())));
+        let results: Vec<bool> = (0..4).map(|at| solver.evaluate_at(&yesterday, &trace, at)).collect();
+        assert_eq!(results, vec![false, false, false, true]);
+
+        assert!(previously.has_past_operators());
+        assert!(!TemporalFormula::Eventually(Box::new(TemporalFormula::Atomic("p".to_string()))).has_past_operators());
+        assert!(!solver.holds_on_trace(&previously, &trace_of(&[])));
+    }
+
+    #[test]
+    fn test_registered_formulas_are_solved_over_traces() {
+        let mut solver = TemporalLogicSolver::new();
+        let atom = |name: &str| Box::new(TemporalFormula::Atomic(name.to_string()));
+        // Every grant follows a request: □(grant → P request)
+        let granted = TemporalFormula::Always(Box::new(TemporalFormula::Implies(
+            atom("grant"),
+            Box::new(TemporalFormula::Previously(atom("request"))),
+        )));
+        solver.add_formula("granted_on_request", granted);
+        solver.add_formula("never_idle", TemporalFormula::Always(atom("busy")));
+        solver.add_trace(trace_of(&[&["grant"], &[]]));
+        solver.add_trace(trace_of(&[&["request"], &[], &["grant"]]));
+
+        let result = solver.solve_formulas(&[], &[], 0);
+        let granted = result.formulas.iter().find(|formula| formula.id == "granted_on_request").unwrap();
+        assert_eq!(granted.result.satisfiable, SatisfiabilityStatus::Satisfiable);
+        assert_eq!(granted.result.witness.as_ref().unwrap().states.len(), 3);
+        assert_eq!(granted.result.counterexample.as_ref().unwrap().trace.states.len(), 2);
+
+        // Failing every sample does not make a formula unsatisfiable
+        let idle = result.formulas.iter().find(|formula| formula.id == "never_idle").unwrap();
+        assert_eq!(idle.result.satisfiable, SatisfiabilityStatus::Unknown);
+        assert!(idle.result.witness.is_none());
+        assert_eq!(result.overall_status, SatisfiabilityStatus::Unknown);
+    }
+
     #[test]
     fn test_solver_config() {
         let config = SolverConfig {
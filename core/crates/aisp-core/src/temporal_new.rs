@@ -590,6 +590,7 @@ impl EmptyResult for OperatorValidationResult {
             complexity: OperatorComplexity {
                 operator_count: 0,
                 max_nesting: 0,
+                max_temporal_depth: 0,
                 avg_nesting: 0.0,
                 operator_frequency: HashMap::new(),
                 complexity_score: 0.0,
@@ -766,6 +767,7 @@ mod tests {
                 protocol: None,
             },
             blocks: Vec::new(),
+            used_symbols: Default::default(),
             span: Some(Span::new(1, 1, 1, 1)),
         };
         
@@ -7,12 +7,19 @@ use crate::ast::canonical::*;
 use crate::error::*;
 use std::collections::HashMap;
 
+/// Temporal operators a single formula may nest before a warning
+pub const DEFAULT_MAX_TEMPORAL_DEPTH: usize = 3;
+
 /// Temporal operator analyzer
 pub struct TemporalOperatorAnalyzer {
     /// Mapping of operator symbols to temporal operators
     operator_symbols: HashMap<char, TemporalOperator>,
     /// Detected operators in the document
     detected_operators: Vec<OperatorInstance>,
+    /// Deepest temporal operator nesting allowed in one formula without a warning
+    max_temporal_depth: usize,
+    /// Deepest temporal operator nesting found in any formula
+    deepest_formula: usize,
 }
 
 /// Temporal operators in AISP
@@ -91,6 +98,8 @@ pub struct OperatorComplexity {
     pub operator_count: usize,
     /// Maximum nesting depth
     pub max_nesting: usize,
+    /// Deepest nesting of temporal operators within a single formula
+    pub max_temporal_depth: usize,
     /// Average nesting depth
     pub avg_nesting: f64,
     /// Operator frequency distribution
@@ -137,9 +146,17 @@ impl TemporalOperatorAnalyzer {
         Self {
             operator_symbols,
             detected_operators: Vec::new(),
+            max_temporal_depth: DEFAULT_MAX_TEMPORAL_DEPTH,
+            deepest_formula: 0,
         }
     }
 
+    /// Warn on formulas nesting more than `depth` temporal operators
+    pub fn with_max_temporal_depth(mut self, depth: usize) -> Self {
+        self.max_temporal_depth = depth;
+        self
+    }
+
     /// Analyze temporal operators in the document
     /// 
     /// **Contract:**
@@ -150,6 +167,7 @@ impl TemporalOperatorAnalyzer {
     /// - **Performance:** O(n) where n = total characters in all blocks
     pub fn analyze_operators(&mut self, document: &AispDocument) -> OperatorValidationResult {
         self.detected_operators.clear();
+        self.deepest_formula = 0;
 
         let mut errors = Vec::new();
         let mut warnings = Vec::new();
@@ -204,7 +222,12 @@ impl TemporalOperatorAnalyzer {
     ) {
         for rule in &rules_block.rules {
             let rule_id = format!("rule_{:?}", rule.span);
+            let rule_label = rule
+                .span
+                .as_ref()
+                .map_or_else(|| "rule".to_string(), |span| format!("rule at line {}, column {}", span.line, span.column));
             let rule_text = self.extract_rule_text(&rule.expression);
+            self.check_temporal_depth(&rule.expression, &rule_label, warnings);
             
             let operators = self.extract_operators_from_text(
                 &rule_text,
@@ -216,7 +239,7 @@ impl TemporalOperatorAnalyzer {
             for operator in &operators {
                 if self.is_operator_malformed(operator) {
                     errors.push(AispError::ValidationError {
-                        message: format!("Invalid temporal operator {:?} in rule {}", operator.operator, rule_id),
+                        message: format!("Invalid temporal operator {:?} in {}", operator.operator, rule_label),
                     });
                 }
             }
@@ -235,6 +258,7 @@ impl TemporalOperatorAnalyzer {
         for function in &functions_block.functions {
             let function_name = &function.name;
             let function_text = self.extract_function_text(&function.lambda);
+            self.check_temporal_depth(&function.lambda.body, &format!("function '{}'", function_name), warnings);
             
             let operators = self.extract_operators_from_text(
                 &function_text,
@@ -259,11 +283,12 @@ impl TemporalOperatorAnalyzer {
         &mut self,
         meta_block: &MetaBlock,
         _errors: &mut Vec<AispError>,
-        _warnings: &mut Vec<AispWarning>,
+        warnings: &mut Vec<AispWarning>,
     ) {
         for (key, entry) in &meta_block.entries {
             if let MetaValue::Constraint(logical_expr) = &entry.value {
                 let constraint_text = format!("{:?}", logical_expr);
+                self.check_temporal_depth(logical_expr, &format!("meta constraint '{}'", key), warnings);
                 
                 let operators = self.extract_operators_from_text(
                     &constraint_text,
@@ -335,6 +360,80 @@ impl TemporalOperatorAnalyzer {
         operators
     }
 
+    /// Record the temporal nesting depth of one formula, warning past the limit
+    fn check_temporal_depth(&mut self, expression: &LogicalExpression, formula: &str, warnings: &mut Vec<AispWarning>) {
+        let depth = self.expression_temporal_depth(expression);
+        self.deepest_formula = self.deepest_formula.max(depth);
+        if depth > self.max_temporal_depth {
+            warnings.push(AispWarning::warning(format!(
+                "Formula in {} nests temporal operators {} deep (limit {}) - consider splitting it",
+                formula, depth, self.max_temporal_depth
+            )));
+        }
+    }
+
+    /// Deepest chain of nested temporal operators in an expression
+    fn expression_temporal_depth(&self, expression: &LogicalExpression) -> usize {
+        match expression {
+            LogicalExpression::Variable(_) | LogicalExpression::Constant(_) => 0,
+            LogicalExpression::Temporal { operand, .. } => 1 + self.expression_temporal_depth(operand),
+            LogicalExpression::Unary { operand, .. } => self.expression_temporal_depth(operand),
+            LogicalExpression::Binary { left, right, .. } => {
+                self.expression_temporal_depth(left).max(self.expression_temporal_depth(right))
+            }
+            LogicalExpression::Membership { element, set } => {
+                self.expression_temporal_depth(element).max(self.expression_temporal_depth(set))
+            }
+            LogicalExpression::Application { arguments, .. } => arguments
+                .iter()
+                .map(|argument| self.expression_temporal_depth(argument))
+                .max()
+                .unwrap_or(0),
+            LogicalExpression::Raw(text) => self.temporal_depth(text),
+        }
+    }
+
+    /// Deepest chain of temporal operators in `text` each within the scope
+    /// of the previous one
+    ///
+    /// An operator's scope ends at the bracket that closes around it or at a
+    /// logical connective at its own bracket level, so `□(p → ◊q)` nests two
+    /// operators while `□p ∧ ◊q` nests one.
+    fn temporal_depth(&self, text: &str) -> usize {
+        let chars: Vec<char> = text.chars().collect();
+        let mut bracket_depth: usize = 0;
+        // Bracket depth of each operator whose scope is still open
+        let mut open: Vec<usize> = Vec::new();
+        let mut deepest = 0;
+
+        for (i, &ch) in chars.iter().enumerate() {
+            match ch {
+                '(' | '[' | '{' => bracket_depth += 1,
+                ')' | ']' | '}' => {
+                    bracket_depth = bracket_depth.saturating_sub(1);
+                    open.retain(|&depth| depth <= bracket_depth);
+                }
+                '∧' | '∨' | '→' | '↔' | '⇒' | '⇔' | ',' | ';' => {
+                    open.retain(|&depth| depth < bracket_depth);
+                }
+                _ if self.operator_symbols.contains_key(&ch) => {
+                    // Letter operators (X, U, ...) only count as standalone tokens
+                    let is_word_char = |offset: Option<usize>| {
+                        offset.and_then(|j| chars.get(j)).map_or(false, |c| c.is_alphanumeric() || *c == '_')
+                    };
+                    if ch.is_alphabetic() && (is_word_char(i.checked_sub(1)) || is_word_char(Some(i + 1))) {
+                        continue;
+                    }
+                    open.push(bracket_depth);
+                    deepest = deepest.max(open.len());
+                }
+                _ => {}
+            }
+        }
+
+        deepest
+    }
+
     /// Extract operands for binary temporal operators
     fn extract_operands(&self, chars: &[char], operator_pos: usize) -> Vec<String> {
         let mut operands = Vec::new();
@@ -418,6 +517,7 @@ impl TemporalOperatorAnalyzer {
             return OperatorComplexity {
                 operator_count: 0,
                 max_nesting: 0,
+                max_temporal_depth: self.deepest_formula,
                 avg_nesting: 0.0,
                 operator_frequency: HashMap::new(),
                 complexity_score: 0.0,
@@ -453,6 +553,7 @@ impl TemporalOperatorAnalyzer {
         OperatorComplexity {
             operator_count,
             max_nesting,
+            max_temporal_depth: self.deepest_formula,
             avg_nesting,
             operator_frequency,
             complexity_score,
@@ -623,6 +724,7 @@ mod tests {
                 protocol: None,
             },
             blocks: vec![],
+            used_symbols: Default::default(),
             span: Some(Span::new(1, 1, 1, 1)),
         };
 
@@ -632,6 +734,79 @@ mod tests {
         assert!(result.valid);
     }
 
+    fn document_with_rules(rules: Vec<LogicalExpression>) -> AispDocument {
+        AispDocument {
+            header: DocumentHeader {
+                version: "5.1".to_string(),
+                name: "test".to_string(),
+                date: "2026-01-25".to_string(),
+                metadata: None,
+            },
+            metadata: DocumentMetadata {
+                domain: None,
+                protocol: None,
+            },
+            blocks: vec![AispBlock::Rules(RulesBlock {
+                rules: rules
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, expression)| LogicalRule {
+                        quantifier: None,
+                        raw_text: format!("{:?}", expression),
+                        expression,
+                        span: Some(Span::new(0, 0, index + 2, 3)),
+                    })
+                    .collect(),
+                raw_rules: vec![],
+                span: None,
+            })],
+            used_symbols: Default::default(),
+            span: Some(Span::new(1, 1, 1, 1)),
+        }
+    }
+
+    #[test]
+    fn test_temporal_depth_of_formula_text() {
+        let analyzer = TemporalOperatorAnalyzer::new();
+        assert_eq!(analyzer.temporal_depth("□(◊(□(◊p)))"), 4);
+        assert_eq!(analyzer.temporal_depth("□◊□◊p"), 4);
+        assert_eq!(analyzer.temporal_depth("□(p → ◊q)"), 2);
+        assert_eq!(analyzer.temporal_depth("□p ∧ ◊q"), 1);
+        assert_eq!(analyzer.temporal_depth("(□p) U (◊q)"), 2);
+        assert_eq!(analyzer.temporal_depth("Ready(s) → Valid(s)"), 0);
+    }
+
+    #[test]
+    fn test_deep_temporal_nesting_warns() {
+        let nested = |depth: usize| {
+            (0..depth).fold(LogicalExpression::Variable("p".to_string()), |operand, _| LogicalExpression::Temporal {
+                op: crate::ast::canonical::TemporalOperator::Always,
+                operand: Box::new(operand),
+            })
+        };
+        let depth_warnings = |result: &OperatorValidationResult| {
+            result.warnings.iter().filter(|w| w.message.contains("nests temporal operators")).count()
+        };
+
+        let deep = document_with_rules(vec![nested(4), LogicalExpression::Raw("□(◊(□(◊p)))".to_string())]);
+        let result = TemporalOperatorAnalyzer::new().analyze_operators(&deep);
+        assert_eq!(result.complexity.max_temporal_depth, 4);
+        assert_eq!(depth_warnings(&result), 2);
+        assert_eq!(
+            result.warnings[0].message,
+            "Formula in rule at line 2, column 3 nests temporal operators 4 deep (limit 3) - consider splitting it"
+        );
+
+        let shallow = document_with_rules(vec![nested(2), LogicalExpression::Raw("□p ∧ ◊q".to_string())]);
+        let result = TemporalOperatorAnalyzer::new().analyze_operators(&shallow);
+        assert_eq!(result.complexity.max_temporal_depth, 2);
+        assert_eq!(depth_warnings(&result), 0);
+
+        // The bound is configurable
+        let result = TemporalOperatorAnalyzer::new().with_max_temporal_depth(4).analyze_operators(&deep);
+        assert_eq!(depth_warnings(&result), 0);
+    }
+
     #[test]
     fn test_operator_validation() {
         let mut analyzer = TemporalOperatorAnalyzer::new();
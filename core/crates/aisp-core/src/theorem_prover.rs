@@ -8,6 +8,9 @@ use crate::property_types::*;
 use crate::proof_types::*;
 use crate::axiom_system::*;
 use crate::proof_search::*;
+use crate::incompleteness_handler::IncompletenessHandler;
+use std::collections::HashMap;
+use std::fmt;
 use std::time::{Duration, Instant};
 
 /// Automated theorem prover for AISP properties
@@ -24,20 +27,14 @@ pub struct TheoremProver {
     timeout: Duration,
     /// Proof search engine
     search_engine: ProofSearchEngine,
+    /// Heuristics for classifying properties the search cannot settle
+    incompleteness: IncompletenessHandler,
 }
 
 impl TheoremProver {
     /// Create new theorem prover with default configuration
     pub fn new() -> Self {
-        let mut builder = AxiomSystemBuilder::new();
-        
-        // Initialize standard axioms and rules
-        builder.add_propositional_axioms();
-        builder.add_predicate_axioms();
-        builder.add_temporal_axioms();
-        builder.add_aisp_axioms();
-        
-        let (axioms, inference_rules) = builder.build();
+        let AxiomSystem { axioms, inference_rules } = AxiomSystem::standard();
         let search_engine = ProofSearchEngine::new(axioms.clone(), inference_rules.clone());
         
         Self {
@@ -47,6 +44,7 @@ impl TheoremProver {
             max_depth: 50,
             timeout: Duration::from_secs(60),
             search_engine,
+            incompleteness: IncompletenessHandler::new(),
         }
     }
 
@@ -91,6 +89,9 @@ impl TheoremProver {
             ProofSearchStrategy::Resolution => {
                 self.search_engine.resolution_search(goal)?
             }
+            ProofSearchStrategy::IterativeDeepening => {
+                self.search_engine.iterative_deepening_search(goal)?
+            }
             _ => return Err(AispError::validation_error("Proof strategy not implemented".to_string())),
         };
         
@@ -132,8 +133,12 @@ impl TheoremProver {
             ProofSearchStrategy::Resolution => {
                 self.search_engine.resolution_search(goal)?
             }
+            ProofSearchStrategy::IterativeDeepening => {
+                self.search_engine.iterative_deepening_search(goal)?
+            }
             _ => return Err(AispError::validation_error("Proof strategy not implemented".to_string())),
         };
+        let outcome = self.classify_undecided(property, outcome);
 
         let search_time = start_time.elapsed();
         let search_stats = self.search_engine.get_stats().clone();
@@ -243,6 +248,7 @@ impl TheoremProver {
         let disproven_count = results.iter().filter(|r| r.is_disproven()).count();
         let timeout_count = results.iter().filter(|r| matches!(r.outcome, ProofOutcome::Timeout)).count();
         let unknown_count = results.iter().filter(|r| matches!(r.outcome, ProofOutcome::Unknown)).count();
+        let undecidable_count = results.iter().filter(|r| r.is_undecidable()).count();
         let error_count = results.iter().filter(|r| matches!(r.outcome, ProofOutcome::Error(_))).count();
 
         let total_search_time: Duration = results.iter().map(|r| r.search_time).sum();
@@ -265,6 +271,7 @@ impl TheoremProver {
             disproven_count,
             timeout_count,
             unknown_count,
+            undecidable_count,
             error_count,
             success_rate: if total_properties > 0 { proven_count as f64 / total_properties as f64 } else { 0.0 },
             total_search_time,
@@ -274,14 +281,113 @@ impl TheoremProver {
         }
     }
 
+    /// Check that a stored proof derives `goal` from this prover's axioms
+    /// and inference rules, without searching
+    pub fn verify_proof(&self, proof: &FormalProof, goal: &FormulaStructure) -> bool {
+        self.replay_proof(proof, goal).is_ok()
+    }
+
+    /// Re-apply every step of a stored proof in order, reporting the first
+    /// step that does not follow
+    ///
+    /// Axiom steps must restate a known axiom and rule steps must match the
+    /// named rule against earlier steps. Other justifications cannot be
+    /// checked against the axiom system and are rejected. Rule side
+    /// conditions are not re-checked.
+    pub fn replay_proof(&self, proof: &FormalProof, goal: &FormulaStructure) -> Result<(), ProofReplayError> {
+        let mut derived: HashMap<usize, &FormulaStructure> = HashMap::new();
+
+        for step in &proof.steps {
+            let invalid = |reason: String| ProofReplayError::InvalidStep { step_id: step.step_id, reason };
+            if derived.contains_key(&step.step_id) {
+                return Err(invalid("duplicate step id".to_string()));
+            }
+
+            match &step.justification {
+                StepJustification::Axiom(name) => {
+                    let axiom = self
+                        .axioms
+                        .iter()
+                        .find(|axiom| &axiom.name == name)
+                        .ok_or_else(|| invalid(format!("unknown axiom `{}`", name)))?;
+                    if axiom.formula != step.formula {
+                        return Err(invalid(format!("formula is not axiom `{}`", name)));
+                    }
+                }
+                StepJustification::InferenceRule(name, premises) => {
+                    let rule = self
+                        .inference_rules
+                        .iter()
+                        .find(|rule| &rule.name == name)
+                        .ok_or_else(|| invalid(format!("unknown inference rule `{}`", name)))?;
+                    if premises.len() != rule.premises.len() {
+                        return Err(invalid(format!(
+                            "`{}` takes {} premises, {} given",
+                            name,
+                            rule.premises.len(),
+                            premises.len()
+                        )));
+                    }
+
+                    let mut bindings = HashMap::new();
+                    for (pattern, premise) in rule.premises.iter().zip(premises) {
+                        let formula = derived
+                            .get(premise)
+                            .ok_or_else(|| invalid(format!("premise {} is not an earlier step", premise)))?;
+                        if !match_pattern(&pattern.pattern, formula, &mut bindings) {
+                            return Err(invalid(format!("premise {} does not match `{}`", premise, name)));
+                        }
+                    }
+                    if !match_pattern(&rule.conclusion.pattern, &step.formula, &mut bindings) {
+                        return Err(invalid(format!("formula does not follow from `{}`", name)));
+                    }
+                }
+                other => {
+                    return Err(invalid(format!("{:?} steps cannot be replayed", other)));
+                }
+            }
+            derived.insert(step.step_id, &step.formula);
+        }
+
+        match proof.steps.last() {
+            Some(step) if &step.formula == goal => Ok(()),
+            _ => Err(ProofReplayError::GoalNotDerived),
+        }
+    }
+
     // Private helper methods
-    fn construct_formal_proof(&self, property: &ExtractedProperty, goal: &FormulaStructure) -> AispResult<FormalProof> {
+    /// Record the derivation of `goal` so the proof can be replayed
+    ///
+    /// Only iterative deepening records its derivation, so proofs found by
+    /// the other strategies are re-derived with it.
+    fn construct_formal_proof(&mut self, property: &ExtractedProperty, goal: &FormulaStructure) -> AispResult<FormalProof> {
+        if self.search_engine.derivation().last().map(|step| &step.formula) != Some(goal) {
+            self.search_engine.iterative_deepening_search(goal)?;
+        }
+        let steps = self.search_engine.derivation().to_vec();
+        if steps.last().map(|step| &step.formula) != Some(goal) {
+            return Err(AispError::validation_error("No replayable derivation found".to_string()));
+        }
+
+        let mut axioms_used = Vec::new();
+        let mut rules_applied = Vec::new();
+        for step in &steps {
+            let (names, name) = match &step.justification {
+                StepJustification::Axiom(name) => (&mut axioms_used, name),
+                StepJustification::InferenceRule(name, _) => (&mut rules_applied, name),
+                _ => continue,
+            };
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+
         let mut proof = FormalProof {
             conclusion: property.formula.clone(),
-            steps: vec![],
-            axioms_used: vec![],
-            rules_applied: vec![],
-            proof_tree: ProofTree::leaf(goal.clone()),
+            proof_tree: derivation_tree(&steps, steps.len() - 1),
+            steps,
+            axioms_used,
+            rules_applied,
             is_valid: true,
             complexity: ProofComplexity {
                 step_count: 1,
@@ -309,18 +415,71 @@ impl TheoremProver {
         Ok(counterexample)
     }
 
+    /// Route inconclusive outcomes through the incompleteness heuristics
+    ///
+    /// A timeout or exhausted search on a self-referential or known-hard
+    /// property is reported as `Undecidable` rather than left as `Unknown`,
+    /// so reports distinguish "not settled yet" from "cannot be settled".
+    fn classify_undecided(&self, property: &ExtractedProperty, outcome: ProofOutcome) -> ProofOutcome {
+        if !matches!(outcome, ProofOutcome::Unknown | ProofOutcome::Timeout) {
+            return outcome;
+        }
+
+        let statement = property
+            .source_location
+            .source_text
+            .as_deref()
+            .unwrap_or(&property.name);
+
+        match self.incompleteness.classify_undecided(statement) {
+            Some(reason) => ProofOutcome::Undecidable(reason),
+            None => outcome,
+        }
+    }
+
     fn count_axiom_applications(&self) -> usize {
         // Count how many times axioms were used (would track in practice)
         self.axioms.len()
     }
 }
 
+/// Proof tree rooted at `steps[index]`, with its premises as children
+fn derivation_tree(steps: &[ProofStep], index: usize) -> ProofTree {
+    let step = &steps[index];
+    match &step.justification {
+        StepJustification::InferenceRule(name, premises) => ProofTree::node(
+            step.formula.clone(),
+            name.clone(),
+            premises.iter().map(|&premise| derivation_tree(steps, premise)).collect(),
+        ),
+        _ => ProofTree::leaf(step.formula.clone()),
+    }
+}
+
 impl Default for TheoremProver {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Why a stored proof failed to replay
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProofReplayError {
+    /// A step does not follow from the axioms and the steps before it
+    InvalidStep { step_id: usize, reason: String },
+    /// Every step checks, but the proof does not end in the goal
+    GoalNotDerived,
+}
+
+impl fmt::Display for ProofReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProofReplayError::InvalidStep { step_id, reason } => write!(f, "Step {}: {}", step_id, reason),
+            ProofReplayError::GoalNotDerived => write!(f, "Proof does not derive the goal"),
+        }
+    }
+}
+
 /// Summary of proof results
 #[derive(Debug, Clone)]
 pub struct ProofSummary {
@@ -334,6 +493,8 @@ pub struct ProofSummary {
     pub timeout_count: usize,
     /// Properties with unknown result
     pub unknown_count: usize,
+    /// Properties classified as undecidable within the system
+    pub undecidable_count: usize,
     /// Properties with errors
     pub error_count: usize,
     /// Success rate (proven / total)
@@ -367,7 +528,8 @@ impl ProofSummary {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::collections::HashSet;
+    use crate::incompleteness_handler::UndecidabilityReason;
+    use std::collections::{HashMap, HashSet};
 
     #[test]
     fn test_theorem_prover_creation() {
@@ -434,6 +596,7 @@ mod tests {
             disproven_count: 1,
             timeout_count: 1,
             unknown_count: 0,
+            undecidable_count: 0,
             error_count: 0,
             success_rate: 0.8,
             total_search_time: Duration::from_secs(4),
@@ -446,6 +609,188 @@ mod tests {
         assert_eq!(summary.efficiency(), 2.0); // 8 proven / 4 seconds = 2 per second
     }
 
+    fn property_with_text(name: &str, text: &str) -> ExtractedProperty {
+        ExtractedProperty {
+            id: name.to_string(),
+            name: name.to_string(),
+            property_type: PropertyType::TypeSafety,
+            formula: PropertyFormula {
+                structure: FormulaStructure::Atomic(AtomicFormula {
+                    predicate: "Valid".to_string(),
+                    terms: vec![],
+                    type_signature: None,
+                }),
+                quantifiers: vec![],
+                free_variables: HashSet::new(),
+                predicates: HashSet::new(),
+                functions: HashSet::new(),
+                constants: HashSet::new(),
+            },
+            context: PropertyContext {
+                type_definitions: HashMap::new(),
+                function_definitions: HashMap::new(),
+                constants: HashMap::new(),
+                dependencies: vec![],
+            },
+            source_location: SourceLocation {
+                block_type: "Rules".to_string(),
+                line: None,
+                column: None,
+                source_text: Some(text.to_string()),
+            },
+            complexity: PropertyComplexity::default(),
+        }
+    }
+
+    #[test]
+    fn test_self_referential_property_classified_as_undecidable() {
+        let prover = TheoremProver::new();
+        let self_ref = property_with_text("goedel", "This statement cannot be proven in the rules block");
+        let plain = property_with_text("bounded", "∀x:ℕ. x ≤ x + 1");
+
+        let self_ref_outcome = prover.classify_undecided(&self_ref, ProofOutcome::Timeout);
+        let plain_outcome = prover.classify_undecided(&plain, ProofOutcome::Timeout);
+
+        assert_eq!(
+            self_ref_outcome,
+            ProofOutcome::Undecidable(UndecidabilityReason::SelfReference)
+        );
+        assert_eq!(plain_outcome, ProofOutcome::Timeout);
+        assert_ne!(self_ref_outcome, plain_outcome);
+
+        // Conclusive outcomes are never reclassified
+        assert_eq!(
+            prover.classify_undecided(&self_ref, ProofOutcome::Proven),
+            ProofOutcome::Proven
+        );
+    }
+
+    #[test]
+    fn test_proof_summary_counts_undecidable() {
+        let prover = TheoremProver::new();
+        let results = vec![
+            ProofResult::new(ProofOutcome::Proven),
+            ProofResult::new(ProofOutcome::Timeout),
+            ProofResult::new(ProofOutcome::Undecidable(UndecidabilityReason::GoedelSentence)),
+        ];
+
+        let summary = prover.generate_summary(&results);
+        assert_eq!(summary.undecidable_count, 1);
+        assert_eq!(summary.timeout_count, 1);
+        assert_eq!(summary.unknown_count, 0);
+    }
+
+    fn atom(predicate: &str) -> FormulaStructure {
+        FormulaStructure::Atomic(AtomicFormula {
+            predicate: predicate.to_string(),
+            terms: vec![],
+            type_signature: None,
+        })
+    }
+
+    fn step(step_id: usize, formula: FormulaStructure, justification: StepJustification) -> ProofStep {
+        ProofStep {
+            step_id,
+            formula,
+            dependencies: match &justification {
+                StepJustification::InferenceRule(_, premises) => premises.clone(),
+                _ => vec![],
+            },
+            justification,
+            discharge_level: 0,
+            annotations: HashMap::new(),
+        }
+    }
+
+    /// `A ∧ B` from axioms `a` and `b` by conjunction introduction
+    fn conjunction_proof(goal: &FormulaStructure) -> (TheoremProver, FormalProof) {
+        let mut prover = TheoremProver::new();
+        prover.add_axiom(Axiom::new("a".to_string(), atom("A"), AxiomType::Domain, 5));
+        prover.add_axiom(Axiom::new("b".to_string(), atom("B"), AxiomType::Domain, 5));
+
+        let mut proof = FormalProof {
+            conclusion: property_with_text("conj", "A ∧ B").formula,
+            steps: vec![
+                step(1, atom("A"), StepJustification::Axiom("a".to_string())),
+                step(2, atom("B"), StepJustification::Axiom("b".to_string())),
+                step(3, goal.clone(), StepJustification::InferenceRule("and_intro".to_string(), vec![1, 2])),
+            ],
+            axioms_used: vec!["a".to_string(), "b".to_string()],
+            rules_applied: vec!["and_intro".to_string()],
+            proof_tree: ProofTree::leaf(goal.clone()),
+            is_valid: true,
+            complexity: ProofComplexity {
+                step_count: 0,
+                max_depth: 0,
+                assumption_count: 0,
+                rule_applications: 0,
+                branching_factor: 0.0,
+                complexity_score: 1,
+            },
+        };
+        proof.calculate_complexity();
+        (prover, proof)
+    }
+
+    #[test]
+    fn test_replay_accepts_valid_proof() {
+        let goal = FormulaStructure::Conjunction(vec![atom("A"), atom("B")]);
+        let (prover, proof) = conjunction_proof(&goal);
+
+        assert_eq!(prover.replay_proof(&proof, &goal), Ok(()));
+        assert!(prover.verify_proof(&proof, &goal));
+        assert_eq!(prover.replay_proof(&proof, &atom("A")), Err(ProofReplayError::GoalNotDerived));
+    }
+
+    #[test]
+    fn test_replay_rejects_tampered_proof_at_the_bad_step() {
+        let goal = FormulaStructure::Conjunction(vec![atom("A"), atom("B")]);
+        let (prover, mut proof) = conjunction_proof(&goal);
+        proof.steps[1].formula = atom("C");
+
+        assert!(!prover.verify_proof(&proof, &goal));
+        assert_eq!(
+            prover.replay_proof(&proof, &goal),
+            Err(ProofReplayError::InvalidStep { step_id: 2, reason: "formula is not axiom `b`".to_string() })
+        );
+
+        // A conclusion that does not follow from otherwise valid premises
+        let forged = FormulaStructure::Conjunction(vec![atom("A"), atom("C")]);
+        let (prover, proof) = conjunction_proof(&forged);
+        assert!(matches!(
+            prover.replay_proof(&proof, &forged),
+            Err(ProofReplayError::InvalidStep { step_id: 3, .. })
+        ));
+
+        // Premises must refer to earlier steps
+        let (prover, mut proof) = conjunction_proof(&goal);
+        proof.steps[2].justification = StepJustification::InferenceRule("and_intro".to_string(), vec![1, 3]);
+        assert_eq!(
+            prover.replay_proof(&proof, &goal).unwrap_err().to_string(),
+            "Step 3: premise 3 is not an earlier step"
+        );
+    }
+
+    #[test]
+    fn test_proof_found_by_prove_replays() {
+        let goal = FormulaStructure::Conjunction(vec![atom("A"), atom("B")]);
+        let mut property = property_with_text("conj", "A ∧ B");
+        property.formula.structure = goal.clone();
+
+        let mut prover = TheoremProver::with_config(ProofSearchStrategy::IterativeDeepening, 10, Duration::from_secs(5));
+        prover.add_axiom(Axiom::new("a".to_string(), atom("A"), AxiomType::Domain, 5));
+        prover.add_axiom(Axiom::new("b".to_string(), atom("B"), AxiomType::Domain, 5));
+
+        let result = prover.prove_property(&property).unwrap();
+        assert!(result.is_proven());
+        let proof = result.proof.expect("a proven property carries its proof");
+        assert_eq!(proof.steps.len(), 3);
+        assert_eq!(proof.axioms_used, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(proof.rules_applied, vec!["and_intro".to_string()]);
+        assert_eq!(prover.replay_proof(&proof, &goal), Ok(()));
+        assert!(prover.verify_proof(&proof, &goal));
+    }
+
     #[test]
     fn test_prove_empty_properties() {
         let mut prover = TheoremProver::new();
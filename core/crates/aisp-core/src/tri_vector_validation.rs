@@ -835,6 +835,7 @@ mod tests {
             blocks: vec![
                 // Would contain proper tri-vector type definitions
             ],
+            used_symbols: Default::default(),
             span: Some(Span {
                 start: 0,
                 end: 0,
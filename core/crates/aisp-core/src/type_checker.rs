@@ -1,18 +1,34 @@
 //! Type checking for AISP documents
 //!
 //! This module provides type validation and inference for AISP type systems,
-//! ensuring type safety across the document.
+//! ensuring type safety across the document. Lambda signatures and
+//! application arity are inferred separately in [`inference`].
 
+pub mod inference;
+
+pub use inference::{
+    FunctionSignature, FunctionTypeInference, InferenceResult, InferredType, TypeDiagnostic,
+    TypeErrorKind,
+};
+
+use crate::ast::canonical::{self, CanonicalAispBlock, CanonicalAispDocument};
 use crate::ast::*;
 use crate::error::*;
 use std::collections::{HashMap, HashSet};
 
 /// Type checker for AISP documents
+///
+/// A definition named `Pair⟨A,B⟩` is generic: `A` and `B` may be used in its
+/// body, and every `Pair⟨…⟩` instantiation must supply two type arguments.
 pub struct TypeChecker {
     /// Type definitions from the Types block
     type_definitions: HashMap<String, TypeExpression>,
+    /// Type parameters of generic definitions
+    type_parameters: HashMap<String, Vec<String>>,
     /// Error accumulator
     errors: Vec<AispError>,
+    /// Warning accumulator
+    warnings: Vec<AispWarning>,
 }
 
 impl TypeChecker {
@@ -20,7 +36,9 @@ impl TypeChecker {
     pub fn new() -> Self {
         let mut type_checker = Self {
             type_definitions: HashMap::new(),
+            type_parameters: HashMap::new(),
             errors: Vec::new(),
+            warnings: Vec::new(),
         };
         
         // Add built-in mathematical types
@@ -93,6 +111,45 @@ impl TypeChecker {
         
         TypeCheckResult {
             errors: self.errors.clone(),
+            warnings: self.warnings.clone(),
+            type_graph: self.build_type_dependency_graph(),
+            undefined_types: self.find_undefined_types(),
+        }
+    }
+
+    /// Check the ⟦Σ⟧ type definitions of a parsed document
+    ///
+    /// Function and meta checks need the legacy AST, so only type
+    /// definitions, generic parameters and instantiation arity are checked.
+    /// Instantiations in rule domains and lambda parameter annotations are
+    /// checked for arity too.
+    pub fn check_types(&mut self, document: &CanonicalAispDocument) -> TypeCheckResult {
+        for block in &document.blocks {
+            if let CanonicalAispBlock::Types(types_block) = block {
+                for (name, definition) in &types_block.definitions {
+                    self.define_type(name, from_canonical(&definition.type_expr));
+                }
+            }
+        }
+
+        self.validate_type_definitions();
+
+        for block in &document.blocks {
+            let texts: Vec<&str> = match block {
+                CanonicalAispBlock::Rules(rules) => rules.rules.iter().map(|rule| rule.raw_text.as_str()).collect(),
+                CanonicalAispBlock::Functions(functions) => {
+                    functions.functions.iter().map(|function| function.raw_text.as_str()).collect()
+                }
+                _ => continue,
+            };
+            for text in texts {
+                self.check_instantiations(text);
+            }
+        }
+
+        TypeCheckResult {
+            errors: self.errors.clone(),
+            warnings: self.warnings.clone(),
             type_graph: self.build_type_dependency_graph(),
             undefined_types: self.find_undefined_types(),
         }
@@ -103,18 +160,28 @@ impl TypeChecker {
         for block in &document.blocks {
             if let AispBlock::Types(types_block) = block {
                 for (name, definition) in &types_block.definitions {
-                    // Check for redefinition of user-defined types (not built-ins)
-                    if self.is_user_defined_type(name) {
-                        self.errors.push(AispError::TypeError {
-                            message: format!("Type '{}' redefined, using first definition", name),
-                        });
-                    } else {
-                        self.type_definitions.insert(name.clone(), definition.type_expr.clone());
-                    }
+                    self.define_type(name, definition.type_expr.clone());
                 }
             }
         }
     }
+
+    /// Record the definition of `name`, which may declare type parameters
+    fn define_type(&mut self, name: &str, type_expr: TypeExpression) {
+        let (name, parameters) = split_type_parameters(name);
+
+        // Check for redefinition of user-defined types (not built-ins)
+        if self.is_user_defined_type(&name) {
+            self.errors.push(AispError::TypeError {
+                message: format!("Type '{}' redefined, using first definition", name),
+            });
+        } else {
+            if !parameters.is_empty() {
+                self.type_parameters.insert(name.clone(), parameters);
+            }
+            self.type_definitions.insert(name, type_expr);
+        }
+    }
     
     /// Check if a type is user-defined (not a built-in type)
     fn is_user_defined_type(&self, name: &str) -> bool {
@@ -131,20 +198,89 @@ impl TypeChecker {
     /// Validate that type definitions are well-formed
     fn validate_type_definitions(&mut self) {
         for (name, type_expr) in &self.type_definitions.clone() {
-            if let Err(error) = self.validate_type_expression(type_expr, &mut HashSet::new()) {
+            let parameters = self.type_parameters.get(name).cloned().unwrap_or_default();
+            if let Err(error) = self.validate_type_parameters(name, &parameters, type_expr) {
+                self.errors.push(error);
+            }
+            if let Err(error) = self.validate_type_expression_in(type_expr, &parameters, &mut HashSet::new()) {
                 self.errors.push(error);
             }
         }
     }
 
+    /// Check a generic definition's parameters are distinct, warning about
+    /// any its body never uses
+    fn validate_type_parameters(
+        &mut self,
+        name: &str,
+        parameters: &[String],
+        body: &TypeExpression,
+    ) -> AispResult<()> {
+        for (index, parameter) in parameters.iter().enumerate() {
+            if parameters[..index].contains(parameter) {
+                return Err(AispError::TypeError {
+                    message: format!("Duplicate type parameter '{}' in '{}'", parameter, name),
+                });
+            }
+            if !references_type(body, parameter) {
+                self.warnings.push(AispWarning::warning(format!(
+                    "Type parameter '{}' of '{}' is never used",
+                    parameter, name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check `name` is instantiated with as many type arguments as it has
+    /// parameters; non-generic types take none
+    fn check_type_arity(&self, name: &str, arguments: usize) -> AispResult<()> {
+        let expected = self.type_parameters.get(name).map_or(0, Vec::len);
+        if expected != arguments {
+            return Err(AispError::TypeError {
+                message: format!(
+                    "Type '{}' expects {} type argument(s), found {}",
+                    name, expected, arguments
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// Check the arity of each defined type instantiated in a rule or
+    /// function definition
+    fn check_instantiations(&mut self, text: &str) {
+        for (name, arguments) in generic_instantiations(text) {
+            if !self.type_definitions.contains_key(&name) {
+                continue;
+            }
+            if let Err(AispError::TypeError { message }) = self.check_type_arity(&name, arguments) {
+                self.errors.push(AispError::TypeError {
+                    message: format!("{} in `{}`", message, text.trim()),
+                });
+            }
+        }
+    }
+
     /// Validate a type expression recursively
     fn validate_type_expression(
         &self, 
         type_expr: &TypeExpression, 
         visited: &mut HashSet<String>
+    ) -> AispResult<()> {
+        self.validate_type_expression_in(type_expr, &[], visited)
+    }
+
+    /// Validate a type expression with the given type parameters in scope
+    fn validate_type_expression_in(
+        &self,
+        type_expr: &TypeExpression,
+        parameters: &[String],
+        visited: &mut HashSet<String>
     ) -> AispResult<()> {
         match type_expr {
             TypeExpression::Basic(_) => Ok(()),
+            TypeExpression::Reference(name) if parameters.contains(name) => Ok(()),
             TypeExpression::Reference(name) => {
                 if visited.contains(name) {
                     return Err(AispError::TypeError {
@@ -157,6 +293,7 @@ impl TypeChecker {
                         symbol: name.clone(),
                     });
                 }
+                self.check_type_arity(name, 0)?;
                 
                 visited.insert(name.clone());
                 let referenced_type = &self.type_definitions[name];
@@ -166,11 +303,11 @@ impl TypeChecker {
                 Ok(())
             }
             TypeExpression::Array { element_type, size: _ } => {
-                self.validate_type_expression(element_type, visited)
+                self.validate_type_expression_in(element_type, parameters, visited)
             }
             TypeExpression::Function { input, output } => {
-                self.validate_type_expression(input, visited)?;
-                self.validate_type_expression(output, visited)
+                self.validate_type_expression_in(input, parameters, visited)?;
+                self.validate_type_expression_in(output, parameters, visited)
             }
             TypeExpression::Enumeration(values) => {
                 if values.is_empty() {
@@ -192,12 +329,21 @@ impl TypeChecker {
             }
             TypeExpression::Tuple(elements) => {
                 for element in elements {
-                    self.validate_type_expression(element, visited)?;
+                    self.validate_type_expression_in(element, parameters, visited)?;
                 }
                 Ok(())
             }
-            TypeExpression::Generic { .. } => {
-                // TODO: Validate generic type constraints
+            TypeExpression::Generic { name, parameters: arguments } => {
+                if !self.type_definitions.contains_key(name) {
+                    return Err(AispError::UndefinedSymbol {
+                        symbol: name.clone(),
+                    });
+                }
+                self.check_type_arity(name, arguments.len())?;
+
+                for argument in arguments {
+                    self.validate_type_expression_in(argument, parameters, visited)?;
+                }
                 Ok(())
             }
         }
@@ -298,6 +444,14 @@ impl TypeChecker {
                 self.collect_type_dependencies(input, dependencies);
                 self.collect_type_dependencies(output, dependencies);
             }
+            TypeExpression::Generic { name, parameters } => {
+                if !dependencies.contains(name) {
+                    dependencies.push(name.clone());
+                }
+                for parameter in parameters {
+                    self.collect_type_dependencies(parameter, dependencies);
+                }
+            }
             _ => {} // Basic types and enumerations have no dependencies
         }
     }
@@ -313,11 +467,132 @@ impl TypeChecker {
     }
 }
 
+/// Split a definition name such as `Pair⟨A,B⟩` into the type name and its
+/// type parameters
+fn split_type_parameters(name: &str) -> (String, Vec<String>) {
+    let generic = name
+        .strip_suffix('⟩')
+        .and_then(|rest| rest.split_once('⟨'));
+
+    match generic {
+        Some((base, parameters)) => (
+            base.trim().to_string(),
+            parameters
+                .split(',')
+                .map(str::trim)
+                .filter(|parameter| !parameter.is_empty())
+                .map(str::to_string)
+                .collect(),
+        ),
+        None => (name.to_string(), Vec::new()),
+    }
+}
+
+/// Names instantiated as `Name⟨…⟩` in `text`, with their number of type
+/// arguments, outermost first
+fn generic_instantiations(text: &str) -> Vec<(String, usize)> {
+    let mut instantiations = Vec::new();
+    for (open, _) in text.match_indices('⟨') {
+        let name_start = text[..open]
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| c.is_alphanumeric() || *c == '_')
+            .last()
+            .map_or(open, |(start, _)| start);
+        if name_start == open {
+            continue;
+        }
+
+        let mut depth = 0usize;
+        let mut arguments = 1;
+        for c in text[open..].chars() {
+            match c {
+                '⟨' | '(' => depth += 1,
+                '⟩' | ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                ',' if depth == 1 => arguments += 1,
+                _ => {}
+            }
+        }
+        instantiations.push((text[name_start..open].to_string(), arguments));
+    }
+    instantiations
+}
+
+/// The checker's view of a parsed type expression
+///
+/// Named types become references so their definitions and arity are
+/// checked. Unions and products are checked like tuples: only their members
+/// matter here.
+fn from_canonical(type_expr: &canonical::TypeExpression) -> TypeExpression {
+    use canonical::TypeExpression as Canonical;
+
+    let all = |members: &[Canonical]| members.iter().map(from_canonical).collect();
+    match type_expr {
+        Canonical::Basic(basic) => match basic {
+            canonical::BasicType::Natural => TypeExpression::Basic(BasicType::Natural),
+            canonical::BasicType::Integer => TypeExpression::Basic(BasicType::Integer),
+            canonical::BasicType::Real => TypeExpression::Basic(BasicType::Real),
+            canonical::BasicType::Boolean => TypeExpression::Basic(BasicType::Boolean),
+            canonical::BasicType::String => TypeExpression::Basic(BasicType::String),
+            canonical::BasicType::VectorSpace(dimension) => TypeExpression::Basic(BasicType::VectorSpace(*dimension)),
+            canonical::BasicType::RealVector => TypeExpression::Basic(BasicType::RealVector),
+            canonical::BasicType::DirectSum => TypeExpression::Basic(BasicType::DirectSum),
+            canonical::BasicType::Custom(name) if name != "Unit" => TypeExpression::Reference(name.clone()),
+            canonical::BasicType::Symbol => TypeExpression::Basic(BasicType::MathematicalStructure("Symbol".to_string())),
+            canonical::BasicType::MathematicalStructure(name) | canonical::BasicType::Custom(name) => {
+                TypeExpression::Basic(BasicType::MathematicalStructure(name.clone()))
+            }
+        },
+        Canonical::Set(element) => TypeExpression::Array {
+            element_type: Box::new(from_canonical(element)),
+            size: None,
+        },
+        Canonical::Union(members) | Canonical::Product(members) | Canonical::Tuple(members) => {
+            TypeExpression::Tuple(all(members))
+        }
+        Canonical::Function { params, return_type } => TypeExpression::Function {
+            input: Box::new(match params.as_slice() {
+                [param] => from_canonical(param),
+                params => TypeExpression::Tuple(all(params)),
+            }),
+            output: Box::new(from_canonical(return_type)),
+        },
+        Canonical::Generic { name, arguments } => TypeExpression::Generic {
+            name: name.clone(),
+            parameters: all(arguments),
+        },
+        Canonical::Enumeration(values) => TypeExpression::Enumeration(values.clone()),
+    }
+}
+
+/// Whether `type_expr` mentions the type named `name`
+fn references_type(type_expr: &TypeExpression, name: &str) -> bool {
+    match type_expr {
+        TypeExpression::Reference(reference) => reference == name,
+        TypeExpression::Array { element_type, .. } => references_type(element_type, name),
+        TypeExpression::Function { input, output } => {
+            references_type(input, name) || references_type(output, name)
+        }
+        TypeExpression::Tuple(elements) => elements.iter().any(|element| references_type(element, name)),
+        TypeExpression::Generic { name: generic, parameters } => {
+            generic == name || parameters.iter().any(|parameter| references_type(parameter, name))
+        }
+        TypeExpression::Basic(_) | TypeExpression::Enumeration(_) => false,
+    }
+}
+
 /// Result of type checking
 #[derive(Debug)]
 pub struct TypeCheckResult {
     /// Type errors found
     pub errors: Vec<AispError>,
+    /// Non-fatal findings, such as unused type parameters
+    pub warnings: Vec<AispWarning>,
     /// Dependency graph between types
     pub type_graph: HashMap<String, Vec<String>>,
     /// Set of undefined type references
@@ -429,4 +704,126 @@ mod tests {
         
         assert!(checker.validate_function_signature(&invalid_function).is_err());
     }
-}
\ No newline at end of file
+
+    fn document_with_types(types: Vec<(&str, TypeExpression)>) -> AispDocument {
+        let definitions = types
+            .into_iter()
+            .map(|(name, type_expr)| {
+                (name.to_string(), TypeDefinition {
+                    name: name.to_string(),
+                    type_expr,
+                    span: Span::new(1, 1, 1, 10),
+                })
+            })
+            .collect();
+
+        AispDocument {
+            header: DocumentHeader {
+                version: "5.1".to_string(),
+                name: "test".to_string(),
+                date: "2026-01-25".to_string(),
+                metadata: None,
+            },
+            metadata: DocumentMetadata {
+                domain: None,
+                protocol: None,
+            },
+            blocks: vec![AispBlock::Types(TypesBlock {
+                definitions,
+                span: Span::new(1, 1, 3, 1),
+            })],
+            span: Span::new(1, 1, 10, 1),
+        }
+    }
+
+    fn pair_definition() -> (&'static str, TypeExpression) {
+        (
+            "Pair⟨A,B⟩",
+            TypeExpression::Tuple(vec![
+                TypeExpression::Reference("A".to_string()),
+                TypeExpression::Reference("B".to_string()),
+            ]),
+        )
+    }
+
+    fn pair_of(arguments: Vec<TypeExpression>) -> TypeExpression {
+        TypeExpression::Generic {
+            name: "Pair".to_string(),
+            parameters: arguments,
+        }
+    }
+
+    #[test]
+    fn test_generic_instantiation_type_checks() {
+        let document = document_with_types(vec![
+            pair_definition(),
+            ("Point", pair_of(vec![TypeExpression::Basic(BasicType::Real), TypeExpression::Basic(BasicType::Real)])),
+        ]);
+
+        let result = TypeChecker::new().check_document(&document);
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+        assert_eq!(result.type_graph["Point"], vec!["Pair".to_string()]);
+    }
+
+    #[test]
+    fn test_wrong_arity_instantiation_is_an_error() {
+        let document = document_with_types(vec![
+            pair_definition(),
+            ("Point", pair_of(vec![TypeExpression::Basic(BasicType::Real)])),
+            ("Bare", TypeExpression::Reference("Pair".to_string())),
+        ]);
+
+        let mut messages: Vec<String> = TypeChecker::new()
+            .check_document(&document)
+            .errors
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        messages.sort();
+        assert_eq!(messages.len(), 2, "{:?}", messages);
+        assert!(messages[0].contains("Type 'Pair' expects 2 type argument(s), found 0"));
+        assert!(messages[1].contains("Type 'Pair' expects 2 type argument(s), found 1"));
+    }
+
+    #[test]
+    fn test_wrong_arity_in_rule_domain_and_lambda_annotation_is_an_error() {
+        let check = |rule: &str, function: &str| -> Vec<String> {
+            let source = format!(
+                "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{{domain≜x}}\n\n⟦Σ:Types⟧{{\n  Pair⟨A,B⟩≜(A,B)\n}}\n\n\
+                 ⟦Γ:Rules⟧{{\n  {}\n}}\n\n⟦Λ:Funcs⟧{{\n  {}\n}}\n\n⟦Ε⟧⟨δ≜0.5⟩",
+                rule, function
+            );
+            let parsed = crate::parser::robust_parser::RobustAispParser::new().parse(&source);
+            assert!(parsed.errors.is_empty(), "{:?}", parsed.errors);
+            let result = TypeChecker::new().check_types(&parsed.document.unwrap());
+            result.errors.iter().map(ToString::to_string).collect()
+        };
+
+        assert_eq!(check("∀p:Pair⟨ℝ,ℝ⟩→Valid(p)", "swap≜λp:Pair⟨ℝ,ℕ⟩.p"), Vec::<String>::new());
+        assert_eq!(
+            check("∀p:Pair⟨ℝ⟩→Valid(p)", "id≜λx.x"),
+            vec!["Type error: Type 'Pair' expects 2 type argument(s), found 1 in `∀p:Pair⟨ℝ⟩→Valid(p)`"]
+        );
+        assert_eq!(
+            check("∀s:ℕ→Valid(s)", "swap≜λp:Pair⟨ℝ,ℕ,ℕ⟩.p"),
+            vec!["Type error: Type 'Pair' expects 2 type argument(s), found 3 in `swap≜λp:Pair⟨ℝ,ℕ,ℕ⟩.p`"]
+        );
+    }
+
+    #[test]
+    fn test_unused_type_parameter_is_a_warning() {
+        let document = document_with_types(vec![(
+            "Tagged⟨T,U⟩",
+            TypeExpression::Tuple(vec![
+                TypeExpression::Reference("T".to_string()),
+                TypeExpression::Basic(BasicType::Natural),
+            ]),
+        )]);
+
+        let result = TypeChecker::new().check_document(&document);
+        assert!(result.errors.is_empty(), "{:?}", result.errors);
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].message, "Type parameter 'U' of 'Tagged' is never used");
+    }
+}
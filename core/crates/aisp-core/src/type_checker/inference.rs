@@ -0,0 +1,1355 @@
+//! Hindley–Milner style inference for the functions block
+//!
+//! The canonical AST keeps arithmetic inside lambda bodies as opaque `Raw`
+//! fragments, so bodies are re-read from each definition's source text.
+//! Definitions are inferred in order and generalized; applications in later
+//! bodies and in rules are then checked against those signatures for arity
+//! and argument types. Unknown notation is tolerated: a body that cannot be
+//! read is given a fresh type and only its recognisable applications are
+//! checked. Rules are also checked for `Type.Variant` references to
+//! variants an enumeration in the types block does not declare. The
+//! validator reports every error found here and, in strict mode only,
+//! rejects the document for it.
+
+use crate::ast::canonical::{
+    CanonicalAispBlock, CanonicalAispDocument, FunctionDefinition, LogicalRule, Span, TypeExpression,
+};
+use crate::error::AispError;
+use std::collections::HashMap;
+use std::fmt;
+use thiserror::Error;
+
+/// Monotype produced by inference
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InferredType {
+    /// Type variable (quantified once a signature is generalized)
+    Var(usize),
+    Natural,
+    Integer,
+    Rational,
+    Real,
+    Boolean,
+    String,
+    /// User-defined or otherwise opaque type name
+    Named(String),
+    /// Uncurried function type `params → result`
+    Function(Vec<InferredType>, Box<InferredType>),
+}
+
+impl InferredType {
+    /// Map a type annotation such as `ℕ` or `State` to a type
+    pub fn from_annotation(name: &str) -> Self {
+        match name.trim() {
+            "ℕ" => Self::Natural,
+            "ℤ" => Self::Integer,
+            "ℚ" => Self::Rational,
+            "ℝ" => Self::Real,
+            "𝔹" => Self::Boolean,
+            "𝕊" => Self::String,
+            other => Self::Named(other.to_string()),
+        }
+    }
+
+    /// Number of parameters if this is a function type
+    pub fn arity(&self) -> Option<usize> {
+        match self {
+            Self::Function(params, _) => Some(params.len()),
+            _ => None,
+        }
+    }
+
+    /// Whether a value of this type may be used where `other` is expected
+    ///
+    /// Numeric types widen along ℕ ⊆ ℤ ⊆ ℚ ⊆ ℝ; narrowing needs an explicit
+    /// coercion. Other types are only subtypes of themselves.
+    pub fn is_subtype_of(&self, other: &InferredType) -> bool {
+        match (self.numeric_rank(), other.numeric_rank()) {
+            (Some(rank), Some(other_rank)) => rank <= other_rank,
+            _ => self == other,
+        }
+    }
+
+    /// Position in the numeric tower ℕ ⊆ ℤ ⊆ ℚ ⊆ ℝ
+    fn numeric_rank(&self) -> Option<u8> {
+        match self {
+            Self::Natural => Some(1),
+            Self::Integer => Some(2),
+            Self::Rational => Some(3),
+            Self::Real => Some(4),
+            _ => None,
+        }
+    }
+
+    fn from_numeric_rank(rank: u8) -> Self {
+        match rank {
+            0 | 1 => Self::Natural,
+            2 => Self::Integer,
+            3 => Self::Rational,
+            _ => Self::Real,
+        }
+    }
+
+    fn collect_vars(&self, out: &mut Vec<usize>) {
+        match self {
+            Self::Var(id) => {
+                if !out.contains(id) {
+                    out.push(*id);
+                }
+            }
+            Self::Function(params, result) => {
+                for param in params {
+                    param.collect_vars(out);
+                }
+                result.collect_vars(out);
+            }
+            _ => {}
+        }
+    }
+
+    fn write_with(&self, f: &mut fmt::Formatter<'_>, vars: &[usize], nested: bool) -> fmt::Result {
+        const NAMES: [&str; 6] = ["α", "β", "γ", "δ", "ε", "ζ"];
+        match self {
+            Self::Var(id) => {
+                let index = vars.iter().position(|v| v == id).unwrap_or(0);
+                match NAMES.get(index) {
+                    Some(name) => write!(f, "{}", name),
+                    None => write!(f, "α{}", index),
+                }
+            }
+            Self::Natural => write!(f, "ℕ"),
+            Self::Integer => write!(f, "ℤ"),
+            Self::Rational => write!(f, "ℚ"),
+            Self::Real => write!(f, "ℝ"),
+            Self::Boolean => write!(f, "𝔹"),
+            Self::String => write!(f, "𝕊"),
+            Self::Named(name) => write!(f, "{}", name),
+            Self::Function(params, result) => {
+                if nested {
+                    write!(f, "(")?;
+                }
+                if params.is_empty() {
+                    write!(f, "()")?;
+                }
+                for (i, param) in params.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " × ")?;
+                    }
+                    param.write_with(f, vars, true)?;
+                }
+                write!(f, " → ")?;
+                result.write_with(f, vars, false)?;
+                if nested {
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for InferredType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut vars = Vec::new();
+        self.collect_vars(&mut vars);
+        self.write_with(f, &vars, false)
+    }
+}
+
+/// Kind of type error found during inference
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TypeErrorKind {
+    #[error("`{function}` expects {expected} argument(s) but is applied to {found}")]
+    ArityMismatch {
+        function: String,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("argument {position} of `{function}` expects {expected}, found {found}")]
+    ArgumentMismatch {
+        function: String,
+        position: usize,
+        expected: String,
+        found: String,
+    },
+
+    #[error("expected {expected}, found {found}")]
+    Mismatch { expected: String, found: String },
+
+    #[error("`{name}` has type {found} and cannot be applied")]
+    NotAFunction { name: String, found: String },
+
+    #[error("`{enumeration}` has no variant `{variant}` (declared: {declared})")]
+    UnknownVariant {
+        enumeration: String,
+        variant: String,
+        declared: String,
+    },
+}
+
+/// Type error with the definition or rule it occurred in
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeDiagnostic {
+    pub kind: TypeErrorKind,
+    /// Function name, or the rule text for errors inside rules
+    pub context: String,
+    pub span: Option<Span>,
+}
+
+impl fmt::Display for TypeDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "in `{}`: {}", self.context, self.kind)?;
+        if let Some(span) = &self.span {
+            write!(f, " (line {}, column {})", span.line, span.column)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<TypeDiagnostic> for AispError {
+    fn from(diagnostic: TypeDiagnostic) -> Self {
+        AispError::TypeError {
+            message: diagnostic.to_string(),
+        }
+    }
+}
+
+/// Generalized signature of a function definition
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionSignature {
+    pub name: String,
+    /// Free type variables are implicitly universally quantified
+    pub ty: InferredType,
+    pub span: Option<Span>,
+}
+
+/// Outcome of inferring a document's functions block
+#[derive(Debug, Clone, Default)]
+pub struct InferenceResult {
+    /// Signatures in definition order
+    pub signatures: Vec<FunctionSignature>,
+    pub errors: Vec<TypeDiagnostic>,
+}
+
+impl InferenceResult {
+    /// Inferred type of a function by name
+    pub fn signature(&self, name: &str) -> Option<&InferredType> {
+        self.signatures.iter().find(|s| s.name == name).map(|s| &s.ty)
+    }
+
+    pub fn is_well_typed(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Where an expression came from, for span reporting
+struct Site<'a> {
+    context: String,
+    raw: &'a str,
+    base: Option<Span>,
+}
+
+impl Site<'_> {
+    /// Span of `len` bytes at byte `offset` of the raw text
+    fn span_at(&self, offset: usize, len: usize) -> Option<Span> {
+        let base = self.base.as_ref()?;
+        let before = self.raw.get(..offset).unwrap_or(self.raw);
+        let newlines = before.matches('\n').count();
+        let column = match before.rfind('\n') {
+            Some(pos) => before[pos + 1..].chars().count() + 1,
+            None => base.column + before.chars().count(),
+        };
+        Some(Span::new(
+            base.start + offset,
+            base.start + offset + len,
+            base.line + newlines,
+            column,
+        ))
+    }
+}
+
+/// Hindley–Milner inference over lambda definitions
+///
+/// Numeric literals introduce type variables constrained to the numeric
+/// tower with a floor (`1` is at least ℕ, `1.5` at least ℝ). Constraints left
+/// unresolved when a definition is generalized default to their floor, which
+/// is how `λx.x + 1` ends up as `ℕ → ℕ` rather than polymorphic.
+pub struct FunctionTypeInference {
+    bindings: Vec<Option<InferredType>>,
+    /// Numeric floor for constrained variables (0 = any numeric type)
+    numeric_floors: HashMap<usize, u8>,
+    signatures: HashMap<String, InferredType>,
+    /// Declared variants of each enumeration type
+    enumerations: HashMap<String, Vec<String>>,
+    result: InferenceResult,
+}
+
+impl FunctionTypeInference {
+    pub fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+            numeric_floors: HashMap::new(),
+            signatures: HashMap::new(),
+            enumerations: HashMap::new(),
+            result: InferenceResult::default(),
+        }
+    }
+
+    /// Infer every function definition, then check applications and
+    /// enumeration variant references in rules
+    pub fn infer_document(mut self, document: &CanonicalAispDocument) -> InferenceResult {
+        for block in &document.blocks {
+            if let CanonicalAispBlock::Types(types) = block {
+                for definition in types.definitions.values() {
+                    if let TypeExpression::Enumeration(variants) = &definition.type_expr {
+                        self.declare_enumeration(&definition.name, variants.clone());
+                    }
+                }
+            }
+        }
+        for block in &document.blocks {
+            if let CanonicalAispBlock::Functions(functions) = block {
+                for function in &functions.functions {
+                    self.infer_function(function);
+                }
+            }
+        }
+        for block in &document.blocks {
+            if let CanonicalAispBlock::Rules(rules) = block {
+                for rule in &rules.rules {
+                    self.check_rule(rule);
+                }
+            }
+        }
+        self.result
+    }
+
+    /// Infer and record the signature of a single definition
+    pub fn infer_function(&mut self, function: &FunctionDefinition) -> InferredType {
+        let raw = function.raw_text.as_str();
+        let site = Site {
+            context: function.name.clone(),
+            raw,
+            base: function.span.clone(),
+        };
+        let rhs_offset = raw.find('≜').map(|pos| pos + '≜'.len_utf8()).unwrap_or(0);
+        let rhs = &raw[rhs_offset..];
+
+        let mut env = HashMap::new();
+        // Monomorphic recursion: the name is bound while its body is inferred
+        let recursive = self.fresh();
+        env.insert(function.name.clone(), recursive.clone());
+
+        let lambda = rhs.trim_start().strip_prefix('λ');
+        let (params, body_offset) = match lambda.and_then(|rest| rest.find('.').map(|dot| (rest, dot))) {
+            Some((rest, dot)) => {
+                let mut params = Vec::new();
+                for param in rest[..dot].split(',').map(str::trim).filter(|p| !p.is_empty()) {
+                    let (name, ty) = match param.split_once(':') {
+                        Some((name, annotation)) => (name.trim(), InferredType::from_annotation(annotation)),
+                        None => (param, self.fresh()),
+                    };
+                    env.insert(name.to_string(), ty.clone());
+                    params.push(ty);
+                }
+                let rest_offset = raw.len() - rest.len();
+                (Some(params), rest_offset + dot + 1)
+            }
+            None => (None, rhs_offset),
+        };
+
+        let body_type = self.infer_text(&raw[body_offset..], body_offset, &env, &site);
+        let ty = match params {
+            Some(params) => InferredType::Function(params, Box::new(body_type)),
+            None => body_type,
+        };
+        if self.unify(&recursive, &ty).is_err() {
+            let found = self.describe(&ty);
+            let expected = self.describe(&recursive);
+            self.report(TypeErrorKind::Mismatch { expected, found }, &site, 0, raw.len());
+        }
+
+        let ty = self.generalize(&ty);
+        self.signatures.insert(function.name.clone(), ty.clone());
+        self.result.signatures.push(FunctionSignature {
+            name: function.name.clone(),
+            ty: ty.clone(),
+            span: function.span.clone(),
+        });
+        ty
+    }
+
+    /// Check applications of known functions inside a rule
+    fn check_rule(&mut self, rule: &LogicalRule) {
+        let raw = rule.raw_text.as_str();
+        let site = Site {
+            context: raw.trim().to_string(),
+            raw,
+            base: rule.span.clone(),
+        };
+        let env = quantifier_bindings(raw);
+        self.infer_text(raw, 0, &env, &site);
+        self.check_variant_references(raw, &site);
+    }
+
+    /// Make `Name.Variant` in rules refer to one of `variants`
+    pub fn declare_enumeration(&mut self, name: &str, variants: Vec<String>) {
+        self.enumerations.insert(name.to_string(), variants);
+    }
+
+    /// Report each `Enumeration.Variant` naming an undeclared variant
+    fn check_variant_references(&mut self, text: &str, site: &Site) {
+        let mut cursor = 0;
+        while let Some((start, name)) = next_identifier(text, cursor) {
+            cursor = start + name.len();
+            let Some(variants) = self.enumerations.get(name) else {
+                continue;
+            };
+            if !text[cursor..].starts_with('.') {
+                continue;
+            }
+            let Some((variant_start, variant)) = next_identifier(text, cursor + 1)
+                .filter(|(variant_start, _)| *variant_start == cursor + 1)
+            else {
+                continue;
+            };
+            cursor = variant_start + variant.len();
+            if variants.iter().any(|declared| declared == variant) {
+                continue;
+            }
+            let kind = TypeErrorKind::UnknownVariant {
+                enumeration: name.to_string(),
+                variant: variant.to_string(),
+                declared: variants.join(", "),
+            };
+            self.report(kind, site, variant_start, variant.len());
+        }
+    }
+
+    /// Infer a text fragment, falling back to scanning for applications
+    fn infer_text(
+        &mut self,
+        text: &str,
+        offset: usize,
+        env: &HashMap<String, InferredType>,
+        site: &Site,
+    ) -> InferredType {
+        match ExpressionParser::parse(text) {
+            Some(expr) => self.infer_expr(&expr, offset, env, site),
+            None => {
+                self.scan_applications(text, offset, env, site);
+                self.fresh()
+            }
+        }
+    }
+
+    /// Check every `f(…)` with a known `f` in text that does not parse as a whole
+    fn scan_applications(
+        &mut self,
+        text: &str,
+        offset: usize,
+        env: &HashMap<String, InferredType>,
+        site: &Site,
+    ) {
+        let mut cursor = 0;
+        while let Some((start, name)) = next_identifier(text, cursor) {
+            let after = start + name.len();
+            cursor = after;
+            let known = env.contains_key(name) || self.signatures.contains_key(name);
+            if !known || !text[after..].starts_with('(') {
+                continue;
+            }
+            let Some(close) = matching_paren(text, after) else {
+                continue;
+            };
+            let mut arg_types = Vec::new();
+            for (arg_start, arg) in split_arguments(text, after + 1, close) {
+                arg_types.push(self.infer_text(arg, offset + arg_start, env, site));
+            }
+            self.check_application(name, arg_types, offset + start, close + 1 - start, env, site);
+            cursor = close + 1;
+        }
+    }
+
+    fn infer_expr(
+        &mut self,
+        expr: &Expr,
+        offset: usize,
+        env: &HashMap<String, InferredType>,
+        site: &Site,
+    ) -> InferredType {
+        match expr {
+            Expr::Var { name, .. } => match env.get(name) {
+                Some(ty) => ty.clone(),
+                None => match self.signatures.get(name).cloned() {
+                    Some(scheme) => self.instantiate(&scheme),
+                    // Symbols defined outside the functions block are unconstrained
+                    None => self.fresh(),
+                },
+            },
+            Expr::Literal(literal) => match literal {
+                Literal::Natural => self.fresh_numeric(1),
+                Literal::Real => self.fresh_numeric(4),
+                Literal::Boolean => InferredType::Boolean,
+                Literal::String => InferredType::String,
+            },
+            Expr::Not { operand, at } => {
+                let ty = self.infer_expr(operand, offset, env, site);
+                self.expect(&ty, &InferredType::Boolean, site, offset + at, 1);
+                InferredType::Boolean
+            }
+            Expr::Negate { operand, at } => {
+                let ty = self.infer_expr(operand, offset, env, site);
+                let integral = self.fresh_numeric(2);
+                self.expect(&ty, &integral, site, offset + at, 1);
+                ty
+            }
+            Expr::Binary { op, lhs, rhs, at } => {
+                let left = self.infer_expr(lhs, offset, env, site);
+                let right = self.infer_expr(rhs, offset, env, site);
+                match op {
+                    BinaryKind::Arithmetic | BinaryKind::Ordering | BinaryKind::Equality
+                        if self.numeric_join(&left, &right).is_some() =>
+                    {
+                        // Mixed concrete numeric operands widen to the larger type
+                        let joined = self.numeric_join(&left, &right).unwrap_or(left);
+                        if *op == BinaryKind::Arithmetic {
+                            joined
+                        } else {
+                            InferredType::Boolean
+                        }
+                    }
+                    BinaryKind::Arithmetic | BinaryKind::Ordering => {
+                        let numeric = self.fresh_numeric(0);
+                        self.expect(&left, &numeric, site, offset + at, 1);
+                        self.expect(&right, &left, site, offset + at, 1);
+                        if *op == BinaryKind::Arithmetic {
+                            left
+                        } else {
+                            InferredType::Boolean
+                        }
+                    }
+                    BinaryKind::Equality => {
+                        self.expect(&right, &left, site, offset + at, 1);
+                        InferredType::Boolean
+                    }
+                    BinaryKind::Logical => {
+                        self.expect(&left, &InferredType::Boolean, site, offset + at, 1);
+                        self.expect(&right, &InferredType::Boolean, site, offset + at, 1);
+                        InferredType::Boolean
+                    }
+                }
+            }
+            Expr::Apply { function, args, at, len } => {
+                let arg_types = args
+                    .iter()
+                    .map(|arg| self.infer_expr(arg, offset, env, site))
+                    .collect();
+                self.check_application(function, arg_types, offset + at, *len, env, site)
+            }
+        }
+    }
+
+    /// Check an application for arity and argument types, returning its result type
+    fn check_application(
+        &mut self,
+        function: &str,
+        arg_types: Vec<InferredType>,
+        offset: usize,
+        len: usize,
+        env: &HashMap<String, InferredType>,
+        site: &Site,
+    ) -> InferredType {
+        let callee = match env.get(function) {
+            Some(ty) => Some(ty.clone()),
+            None => self.signatures.get(function).cloned().map(|scheme| self.instantiate(&scheme)),
+        };
+        let Some(callee) = callee else {
+            return self.fresh();
+        };
+
+        match self.shallow(&callee) {
+            InferredType::Function(params, result) => {
+                if params.len() != arg_types.len() {
+                    self.report(
+                        TypeErrorKind::ArityMismatch {
+                            function: function.to_string(),
+                            expected: params.len(),
+                            found: arg_types.len(),
+                        },
+                        site,
+                        offset,
+                        len,
+                    );
+                    return *result;
+                }
+                for (position, (param, arg)) in params.iter().zip(&arg_types).enumerate() {
+                    if self.widen(arg, param).is_err() {
+                        let expected = self.describe(param);
+                        let found = self.describe(arg);
+                        self.report(
+                            TypeErrorKind::ArgumentMismatch {
+                                function: function.to_string(),
+                                position: position + 1,
+                                expected,
+                                found,
+                            },
+                            site,
+                            offset,
+                            len,
+                        );
+                    }
+                }
+                *result
+            }
+            InferredType::Var(_) => {
+                let result = self.fresh();
+                let expected = InferredType::Function(arg_types, Box::new(result.clone()));
+                self.expect(&callee, &expected, site, offset, len);
+                result
+            }
+            other => {
+                let found = self.describe(&other);
+                self.report(
+                    TypeErrorKind::NotAFunction {
+                        name: function.to_string(),
+                        found,
+                    },
+                    site,
+                    offset,
+                    len,
+                );
+                self.fresh()
+            }
+        }
+    }
+
+    /// Check `found` is usable as `expected`, reporting a mismatch on failure
+    fn expect(&mut self, found: &InferredType, expected: &InferredType, site: &Site, offset: usize, len: usize) {
+        if self.widen(found, expected).is_err() {
+            let expected = self.describe(expected);
+            let found = self.describe(found);
+            self.report(TypeErrorKind::Mismatch { expected, found }, site, offset, len);
+        }
+    }
+
+    fn report(&mut self, kind: TypeErrorKind, site: &Site, offset: usize, len: usize) {
+        self.result.errors.push(TypeDiagnostic {
+            kind,
+            context: site.context.clone(),
+            span: site.span_at(offset, len),
+        });
+    }
+
+    fn fresh(&mut self) -> InferredType {
+        self.bindings.push(None);
+        InferredType::Var(self.bindings.len() - 1)
+    }
+
+    fn fresh_numeric(&mut self, floor: u8) -> InferredType {
+        let var = self.fresh();
+        if let InferredType::Var(id) = var {
+            self.numeric_floors.insert(id, floor);
+        }
+        var
+    }
+
+    /// Follow variable bindings at the top level only
+    fn shallow(&self, ty: &InferredType) -> InferredType {
+        let mut current = ty.clone();
+        while let InferredType::Var(id) = current {
+            match &self.bindings[id] {
+                Some(bound) => current = bound.clone(),
+                None => break,
+            }
+        }
+        current
+    }
+
+    /// Apply the current substitution throughout a type
+    fn resolve(&self, ty: &InferredType) -> InferredType {
+        match self.shallow(ty) {
+            InferredType::Function(params, result) => InferredType::Function(
+                params.iter().map(|p| self.resolve(p)).collect(),
+                Box::new(self.resolve(&result)),
+            ),
+            other => other,
+        }
+    }
+
+    /// Resolve a type for messages, showing numeric constraints by their floor
+    fn describe(&self, ty: &InferredType) -> String {
+        let resolved = self.resolve(ty);
+        let mut vars = Vec::new();
+        resolved.collect_vars(&mut vars);
+        let mut substitution = HashMap::new();
+        for var in vars {
+            match self.numeric_floors.get(&var) {
+                Some(0) => {
+                    substitution.insert(var, InferredType::Named("numeric type".to_string()));
+                }
+                Some(floor) => {
+                    substitution.insert(var, InferredType::from_numeric_rank(*floor));
+                }
+                None => {}
+            }
+        }
+        substitute(&resolved, &substitution).to_string()
+    }
+
+    /// Accept `found` where `expected` is required: concrete numeric types
+    /// widen along the tower, anything else must unify
+    fn widen(&mut self, found: &InferredType, expected: &InferredType) -> Result<(), ()> {
+        let (found_now, expected_now) = (self.shallow(found), self.shallow(expected));
+        if found_now.numeric_rank().is_some() && expected_now.numeric_rank().is_some() {
+            return if found_now.is_subtype_of(&expected_now) { Ok(()) } else { Err(()) };
+        }
+        self.unify(expected, found)
+    }
+
+    /// The wider of two concrete numeric types, if both are numeric
+    fn numeric_join(&self, a: &InferredType, b: &InferredType) -> Option<InferredType> {
+        let (a, b) = (self.shallow(a), self.shallow(b));
+        match (a.numeric_rank(), b.numeric_rank()) {
+            (Some(x), Some(y)) => Some(if x >= y { a } else { b }),
+            _ => None,
+        }
+    }
+
+    fn occurs(&self, id: usize, ty: &InferredType) -> bool {
+        match self.shallow(ty) {
+            InferredType::Var(other) => other == id,
+            InferredType::Function(params, result) => {
+                params.iter().any(|p| self.occurs(id, p)) || self.occurs(id, &result)
+            }
+            _ => false,
+        }
+    }
+
+    fn unify(&mut self, a: &InferredType, b: &InferredType) -> Result<(), ()> {
+        let (a, b) = (self.shallow(a), self.shallow(b));
+        match (&a, &b) {
+            (InferredType::Var(x), InferredType::Var(y)) if x == y => Ok(()),
+            (InferredType::Var(x), InferredType::Var(y)) => {
+                let floor = match (self.numeric_floors.get(x), self.numeric_floors.get(y)) {
+                    (Some(fx), Some(fy)) => Some(*fx.max(fy)),
+                    (Some(f), None) | (None, Some(f)) => Some(*f),
+                    (None, None) => None,
+                };
+                if let Some(floor) = floor {
+                    self.numeric_floors.insert(*y, floor);
+                }
+                self.bindings[*x] = Some(b.clone());
+                Ok(())
+            }
+            (InferredType::Var(x), other) | (other, InferredType::Var(x)) => {
+                if self.occurs(*x, other) {
+                    return Err(());
+                }
+                if let Some(floor) = self.numeric_floors.get(x) {
+                    match other.numeric_rank() {
+                        Some(rank) if rank >= *floor => {}
+                        _ => return Err(()),
+                    }
+                }
+                self.bindings[*x] = Some(other.clone());
+                Ok(())
+            }
+            (InferredType::Function(p1, r1), InferredType::Function(p2, r2)) if p1.len() == p2.len() => {
+                for (x, y) in p1.iter().zip(p2.iter()) {
+                    self.unify(x, y)?;
+                }
+                self.unify(r1, r2)
+            }
+            _ if a == b => Ok(()),
+            _ => Err(()),
+        }
+    }
+
+    /// Default numeric constraints to their floor, leaving the rest polymorphic
+    fn generalize(&mut self, ty: &InferredType) -> InferredType {
+        let resolved = self.resolve(ty);
+        let mut vars = Vec::new();
+        resolved.collect_vars(&mut vars);
+        for var in vars {
+            if let Some(floor) = self.numeric_floors.get(&var).copied() {
+                if floor > 0 {
+                    self.bindings[var] = Some(InferredType::from_numeric_rank(floor));
+                }
+            }
+        }
+        self.resolve(&resolved)
+    }
+
+    /// Fresh copy of a generalized signature, keeping numeric constraints
+    fn instantiate(&mut self, scheme: &InferredType) -> InferredType {
+        let mut vars = Vec::new();
+        scheme.collect_vars(&mut vars);
+        let mut substitution = HashMap::new();
+        for var in vars {
+            let fresh = match self.numeric_floors.get(&var).copied() {
+                Some(floor) => self.fresh_numeric(floor),
+                None => self.fresh(),
+            };
+            substitution.insert(var, fresh);
+        }
+        substitute(scheme, &substitution)
+    }
+}
+
+impl Default for FunctionTypeInference {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn substitute(ty: &InferredType, substitution: &HashMap<usize, InferredType>) -> InferredType {
+    match ty {
+        InferredType::Var(id) => substitution.get(id).cloned().unwrap_or_else(|| ty.clone()),
+        InferredType::Function(params, result) => InferredType::Function(
+            params.iter().map(|p| substitute(p, substitution)).collect(),
+            Box::new(substitute(result, substitution)),
+        ),
+        other => other.clone(),
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Next identifier at or after `from`, with its byte offset
+fn next_identifier(text: &str, from: usize) -> Option<(usize, &str)> {
+    let mut previous_is_ident = text[..from].chars().next_back().is_some_and(is_identifier_char);
+    for (i, c) in text[from..].char_indices() {
+        let start = from + i;
+        if !previous_is_ident && (c.is_alphabetic() || c == '_') {
+            let end = text[start..]
+                .char_indices()
+                .find(|(_, c)| !is_identifier_char(*c))
+                .map(|(j, _)| start + j)
+                .unwrap_or(text.len());
+            return Some((start, &text[start..end]));
+        }
+        previous_is_ident = is_identifier_char(c);
+    }
+    None
+}
+
+/// Byte offset of the `)` closing the `(` at `open`
+fn matching_paren(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in text[open..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Top-level comma-separated arguments between `start` and `end`, with offsets
+fn split_arguments(text: &str, start: usize, end: usize) -> Vec<(usize, &str)> {
+    let inner = &text[start..end];
+    if inner.trim().is_empty() {
+        return Vec::new();
+    }
+    let mut args = Vec::new();
+    let mut depth = 0usize;
+    let mut arg_start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                args.push((start + arg_start, &inner[arg_start..i]));
+                arg_start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    args.push((start + arg_start, &inner[arg_start..]));
+    args
+}
+
+/// Types of variables bound by `∀x:T` / `∃x:T` in a rule
+fn quantifier_bindings(text: &str) -> HashMap<String, InferredType> {
+    let mut env = HashMap::new();
+    for (i, c) in text.char_indices() {
+        if c != '∀' && c != '∃' {
+            continue;
+        }
+        let rest = &text[i + c.len_utf8()..];
+        let name: String = rest.chars().take_while(|c| is_identifier_char(*c)).collect();
+        let Some(annotation) = rest[name.len()..].strip_prefix(':') else {
+            continue;
+        };
+        let type_name: String = annotation.chars().take_while(|c| is_identifier_char(*c)).collect();
+        if !name.is_empty() && !type_name.is_empty() {
+            env.insert(name, InferredType::from_annotation(&type_name));
+        }
+    }
+    env
+}
+
+/// Expression forms understood in lambda bodies; `at` is a byte offset
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Var { name: String },
+    Literal(Literal),
+    Not { operand: Box<Expr>, at: usize },
+    Negate { operand: Box<Expr>, at: usize },
+    Binary { op: BinaryKind, lhs: Box<Expr>, rhs: Box<Expr>, at: usize },
+    Apply { function: String, args: Vec<Expr>, at: usize, len: usize },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Literal {
+    Natural,
+    Real,
+    Boolean,
+    String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BinaryKind {
+    /// `+ - * / × ÷`
+    Arithmetic,
+    /// `< > ≤ ≥`
+    Ordering,
+    /// `= ≠`
+    Equality,
+    /// `∧ ∨ ⇒ →`
+    Logical,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Literal(Literal),
+    Binary(BinaryKind, u8),
+    Minus,
+    Not,
+    LParen,
+    RParen,
+    Comma,
+}
+
+/// Precedence-climbing parser over a small arithmetic/logical grammar
+struct ExpressionParser {
+    tokens: Vec<(Token, usize, usize)>,
+    position: usize,
+}
+
+impl ExpressionParser {
+    /// Parse the whole text, or `None` if it uses notation outside the grammar
+    fn parse(text: &str) -> Option<Expr> {
+        let tokens = Self::tokenize(text)?;
+        if tokens.is_empty() {
+            return None;
+        }
+        let mut parser = Self { tokens, position: 0 };
+        let expr = parser.parse_binary(0)?;
+        (parser.position == parser.tokens.len()).then_some(expr)
+    }
+
+    fn tokenize(text: &str) -> Option<Vec<(Token, usize, usize)>> {
+        let mut tokens = Vec::new();
+        let mut chars = text.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            let mut end = start + c.len_utf8();
+            let token = match c {
+                c if c.is_whitespace() => continue,
+                c if c.is_ascii_digit() => {
+                    let mut real = false;
+                    while let Some(&(i, next)) = chars.peek() {
+                        let fraction = next == '.'
+                            && !real
+                            && text[i + 1..].starts_with(|d: char| d.is_ascii_digit());
+                        if !next.is_ascii_digit() && !fraction {
+                            break;
+                        }
+                        real |= fraction;
+                        end = i + next.len_utf8();
+                        chars.next();
+                    }
+                    Token::Literal(if real { Literal::Real } else { Literal::Natural })
+                }
+                c if c.is_alphabetic() || c == '_' => {
+                    while let Some(&(i, next)) = chars.peek() {
+                        if !is_identifier_char(next) {
+                            break;
+                        }
+                        end = i + next.len_utf8();
+                        chars.next();
+                    }
+                    match &text[start..end] {
+                        "true" | "false" => Token::Literal(Literal::Boolean),
+                        name => Token::Ident(name.to_string()),
+                    }
+                }
+                '"' => {
+                    let (i, _) = chars.by_ref().find(|(_, c)| *c == '"')?;
+                    end = i + 1;
+                    Token::Literal(Literal::String)
+                }
+                '⊤' | '⊥' => Token::Literal(Literal::Boolean),
+                '+' | '*' | '×' | '·' | '/' | '÷' => Token::Binary(BinaryKind::Arithmetic, 6),
+                '-' => Token::Minus,
+                '<' | '>' | '≤' | '≥' => {
+                    if matches!(c, '<' | '>') && chars.peek().is_some_and(|(_, n)| *n == '=') {
+                        chars.next();
+                        end += 1;
+                    }
+                    Token::Binary(BinaryKind::Ordering, 4)
+                }
+                '=' | '≠' | '!' if c != '!' || chars.peek().is_some_and(|(_, n)| *n == '=') => {
+                    if chars.peek().is_some_and(|(_, n)| *n == '=') {
+                        chars.next();
+                        end += 1;
+                    }
+                    Token::Binary(BinaryKind::Equality, 4)
+                }
+                '¬' | '!' => Token::Not,
+                '∧' => Token::Binary(BinaryKind::Logical, 3),
+                '∨' => Token::Binary(BinaryKind::Logical, 2),
+                '⇒' | '→' => Token::Binary(BinaryKind::Logical, 1),
+                '(' => Token::LParen,
+                ')' => Token::RParen,
+                ',' => Token::Comma,
+                _ => return None,
+            };
+            tokens.push((token, start, end));
+        }
+        Some(tokens)
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _, _)| token)
+    }
+
+    fn advance(&mut self) -> Option<(Token, usize, usize)> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn parse_binary(&mut self, min_precedence: u8) -> Option<Expr> {
+        let mut lhs = self.parse_unary()?;
+        loop {
+            let (op, precedence) = match self.peek() {
+                Some(Token::Binary(op, precedence)) => (*op, *precedence),
+                Some(Token::Minus) => (BinaryKind::Arithmetic, 5),
+                _ => break,
+            };
+            if precedence < min_precedence {
+                break;
+            }
+            let (_, at, _) = self.advance()?;
+            let rhs = self.parse_binary(precedence + 1)?;
+            lhs = Expr::Binary { op, lhs: Box::new(lhs), rhs: Box::new(rhs), at };
+        }
+        Some(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Option<Expr> {
+        match self.peek()? {
+            Token::Not => {
+                let (_, at, _) = self.advance()?;
+                Some(Expr::Not { operand: Box::new(self.parse_unary()?), at })
+            }
+            Token::Minus => {
+                let (_, at, _) = self.advance()?;
+                Some(Expr::Negate { operand: Box::new(self.parse_unary()?), at })
+            }
+            _ => self.parse_atom(),
+        }
+    }
+
+    fn parse_atom(&mut self) -> Option<Expr> {
+        let (token, start, _) = self.advance()?;
+        match token {
+            Token::Literal(literal) => Some(Expr::Literal(literal)),
+            Token::LParen => {
+                let expr = self.parse_binary(0)?;
+                matches!(self.advance()?.0, Token::RParen).then_some(expr)
+            }
+            Token::Ident(name) if self.peek() == Some(&Token::LParen) => {
+                self.advance();
+                let mut args = Vec::new();
+                if self.peek() == Some(&Token::RParen) {
+                    let (_, _, end) = self.advance()?;
+                    return Some(Expr::Apply { function: name, args, at: start, len: end - start });
+                }
+                loop {
+                    args.push(self.parse_binary(0)?);
+                    match self.advance()? {
+                        (Token::Comma, _, _) => continue,
+                        (Token::RParen, _, end) => {
+                            return Some(Expr::Apply { function: name, args, at: start, len: end - start });
+                        }
+                        _ => return None,
+                    }
+                }
+            }
+            Token::Ident(name) => Some(Expr::Var { name }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::canonical::{LambdaExpression, LogicalExpression};
+
+    fn function(raw: &str, line: usize) -> FunctionDefinition {
+        let name = raw.split('≜').next().unwrap().trim().to_string();
+        FunctionDefinition {
+            name,
+            lambda: LambdaExpression {
+                parameters: Vec::new(),
+                body: LogicalExpression::Raw(raw.to_string()),
+                span: None,
+            },
+            raw_text: raw.to_string(),
+            span: Some(Span::new(0, raw.len(), line, 1)),
+        }
+    }
+
+    fn infer(functions: &[&str]) -> InferenceResult {
+        let mut inference = FunctionTypeInference::new();
+        for (i, raw) in functions.iter().enumerate() {
+            inference.infer_function(&function(raw, i + 1));
+        }
+        inference.result
+    }
+
+    #[test]
+    fn test_infers_natural_successor() {
+        let result = infer(&["f≜λx.x + 1"]);
+        assert!(result.is_well_typed(), "{:?}", result.errors);
+        assert_eq!(result.signature("f").unwrap().to_string(), "ℕ → ℕ");
+    }
+
+    #[test]
+    fn test_infers_polymorphic_and_boolean_signatures() {
+        let result = infer(&["id≜λx.x", "positive≜λn:ℤ.n > 0", "add≜λx,y.x + y"]);
+        assert!(result.is_well_typed(), "{:?}", result.errors);
+        assert_eq!(result.signature("id").unwrap().to_string(), "α → α");
+        assert_eq!(result.signature("positive").unwrap().to_string(), "ℤ → 𝔹");
+        assert_eq!(result.signature("add").unwrap().to_string(), "α × α → α");
+    }
+
+    #[test]
+    fn test_arity_error_reported_with_span() {
+        let result = infer(&["f≜λx.x + 1", "g≜λy.f(y, 2)"]);
+        assert_eq!(result.errors.len(), 1);
+        let error = &result.errors[0];
+        assert_eq!(
+            error.kind,
+            TypeErrorKind::ArityMismatch { function: "f".to_string(), expected: 1, found: 2 }
+        );
+        assert_eq!(error.context, "g");
+        let span = error.span.as_ref().unwrap();
+        assert_eq!((span.line, span.column), (2, 6));
+    }
+
+    #[test]
+    fn test_argument_type_error_in_rule() {
+        let mut inference = FunctionTypeInference::new();
+        inference.infer_function(&function("f≜λx.x + 1", 1));
+        inference.check_rule(&LogicalRule {
+            quantifier: None,
+            expression: LogicalExpression::Raw("f(true)".to_string()),
+            raw_text: "f(true)".to_string(),
+            span: Some(Span::new(40, 47, 5, 3)),
+        });
+        let result = inference.result;
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].kind,
+            TypeErrorKind::ArgumentMismatch {
+                function: "f".to_string(),
+                position: 1,
+                expected: "ℕ".to_string(),
+                found: "𝔹".to_string(),
+            }
+        );
+        assert_eq!(result.errors[0].span.as_ref().unwrap().line, 5);
+    }
+
+    #[test]
+    fn test_quantified_rule_applications_are_checked() {
+        let mut inference = FunctionTypeInference::new();
+        inference.infer_function(&function("f≜λx.x + 1", 1));
+        for (text, ok) in [("∀x:ℕ:f(x) ∈ ℕ", true), ("∀b:𝔹:f(b) ∈ ℕ", false)] {
+            inference.check_rule(&LogicalRule {
+                quantifier: None,
+                expression: LogicalExpression::Raw(text.to_string()),
+                raw_text: text.to_string(),
+                span: None,
+            });
+            assert_eq!(inference.result.errors.is_empty(), ok, "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_numeric_subtyping_lattice() {
+        use InferredType::*;
+        assert!(Natural.is_subtype_of(&Integer));
+        assert!(Integer.is_subtype_of(&Rational));
+        assert!(Natural.is_subtype_of(&Real));
+        assert!(Integer.is_subtype_of(&Integer));
+        assert!(!Real.is_subtype_of(&Natural));
+        assert!(!Rational.is_subtype_of(&Integer));
+        assert!(!Natural.is_subtype_of(&Boolean));
+    }
+
+    #[test]
+    fn test_numeric_arguments_widen_but_do_not_narrow() {
+        let mut inference = FunctionTypeInference::new();
+        inference.infer_function(&function("positive≜λn:ℤ.n > 0", 1));
+        inference.infer_function(&function("succ≜λn:ℕ.n + 1", 2));
+        for (text, ok) in [
+            ("∀x:ℕ:positive(x)", true),
+            ("∀x:ℤ:positive(x)", true),
+            ("∀x:ℕ:succ(x) = x", true),
+            ("∀r:ℝ:succ(r) > 0", false),
+        ] {
+            inference.result.errors.clear();
+            inference.check_rule(&LogicalRule {
+                quantifier: None,
+                expression: LogicalExpression::Raw(text.to_string()),
+                raw_text: text.to_string(),
+                span: None,
+            });
+            assert_eq!(inference.result.errors.is_empty(), ok, "{}: {:?}", text, inference.result.errors);
+        }
+        assert_eq!(
+            inference.result.errors[0].kind,
+            TypeErrorKind::ArgumentMismatch {
+                function: "succ".to_string(),
+                position: 1,
+                expected: "ℕ".to_string(),
+                found: "ℝ".to_string(),
+            }
+        );
+    }
+
+    /// Inference over a parsed document with the given rule and ⟦Λ⟧ contents
+    fn infer_parsed(rule: &str, functions: &str) -> InferenceResult {
+        let source = format!(
+            "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{{domain≜x}}\n\n⟦Σ:Types⟧{{\n  State≜{{Idle,Busy}}\n}}\n\n\
+             ⟦Γ:Rules⟧{{\n  {}\n}}\n\n⟦Λ:Funcs⟧{{\n  {}\n}}\n\n⟦Ε⟧⟨δ≜0.5⟩",
+            rule, functions
+        );
+        let parsed = crate::parser::robust_parser::RobustAispParser::new().parse(&source);
+        assert!(parsed.errors.is_empty(), "{:?}", parsed.errors);
+        FunctionTypeInference::new().infer_document(&parsed.document.unwrap())
+    }
+
+    #[test]
+    fn test_parsed_functions_are_inferred_and_checked() {
+        let functions = "f≜λx.x + 1\n  positive≜λn:ℤ.n > 0\n  add≜λx,y.x + y\n  g≜λy.f(y, 2)";
+        let result = infer_parsed("∀s:State→Valid(s)", functions);
+        assert_eq!(result.signature("f").unwrap().to_string(), "ℕ → ℕ");
+        assert_eq!(result.signature("positive").unwrap().to_string(), "ℤ → 𝔹");
+        assert_eq!(result.signature("add").unwrap().to_string(), "α × α → α");
+
+        assert_eq!(result.errors.len(), 1, "{:?}", result.errors);
+        assert_eq!(
+            result.errors[0].kind,
+            TypeErrorKind::ArityMismatch { function: "f".to_string(), expected: 1, found: 2 }
+        );
+        let span = result.errors[0].span.as_ref().unwrap();
+        assert_eq!((span.line, span.column), (17, 8));
+    }
+
+    #[test]
+    fn test_parsed_rule_argument_type_error() {
+        let result = infer_parsed("∀s:State→f(true)", "f≜λx.x + 1");
+        assert_eq!(result.errors.len(), 1, "{:?}", result.errors);
+        assert_eq!(
+            result.errors[0].to_string(),
+            "in `∀s:State→f(true)`: argument 1 of `f` expects ℕ, found 𝔹 (line 10, column 12)"
+        );
+
+        assert!(infer_parsed("∀s:State→f(1)=2", "f≜λx.x + 1").is_well_typed());
+    }
+
+    #[test]
+    fn test_parsed_numeric_arguments_widen_but_do_not_narrow() {
+        let functions = "positive≜λn:ℤ.n > 0\n  succ≜λn:ℕ.n + 1";
+        for rule in ["∀x:ℕ→positive(x)", "∀x:ℤ→positive(x)", "∀x:ℕ→succ(x)=x"] {
+            let result = infer_parsed(rule, functions);
+            assert!(result.is_well_typed(), "{}: {:?}", rule, result.errors);
+        }
+
+        let result = infer_parsed("∀r:ℝ→succ(r) > 0", functions);
+        assert_eq!(result.errors.len(), 1, "{:?}", result.errors);
+        assert_eq!(
+            result.errors[0].kind,
+            TypeErrorKind::ArgumentMismatch {
+                function: "succ".to_string(),
+                position: 1,
+                expected: "ℕ".to_string(),
+                found: "ℝ".to_string(),
+            }
+        );
+    }
+
+    fn enumeration_rule(text: &str) -> InferenceResult {
+        let mut inference = FunctionTypeInference::new();
+        inference.declare_enumeration("Status", vec!["Running".to_string(), "Stopped".to_string()]);
+        inference.check_rule(&LogicalRule {
+            quantifier: None,
+            expression: LogicalExpression::Raw(text.to_string()),
+            raw_text: text.to_string(),
+            span: Some(Span::new(60, 60 + text.len(), 4, 3)),
+        });
+        inference.result
+    }
+
+    #[test]
+    fn test_declared_variant_reference_is_accepted() {
+        let result = enumeration_rule("∀s:Status:s=Status.Running ∨ s=Status.Stopped");
+        assert!(result.is_well_typed(), "{:?}", result.errors);
+    }
+
+    #[test]
+    fn test_undeclared_variant_reference_is_reported_with_span() {
+        let result = enumeration_rule("∀s:Status:s≠Status.Paused");
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(
+            result.errors[0].kind,
+            TypeErrorKind::UnknownVariant {
+                enumeration: "Status".to_string(),
+                variant: "Paused".to_string(),
+                declared: "Running, Stopped".to_string(),
+            }
+        );
+        let span = result.errors[0].span.as_ref().unwrap();
+        assert_eq!((span.line, span.column), (4, 22));
+        assert_eq!(span.end - span.start, "Paused".len());
+    }
+
+    #[test]
+    fn test_undeclared_variant_in_parsed_document() {
+        let source = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{Status≜{Running,Stopped}}\n\
+                      ⟦Γ:Rules⟧{∀s:Status→s≠Status.Paused}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+        let document = crate::parser::robust_parser::RobustAispParser::new().parse(source).document.unwrap();
+
+        let result = FunctionTypeInference::new().infer_document(&document);
+        assert_eq!(result.errors.len(), 1, "{:?}", result.errors);
+        assert!(matches!(result.errors[0].kind, TypeErrorKind::UnknownVariant { .. }));
+        let span = result.errors[0].span.as_ref().unwrap();
+        assert_eq!((span.line, span.column), (4, 30));
+    }
+}
@@ -0,0 +1,116 @@
+//! Quality Badges
+//!
+//! A badge summarises a validation result as a short fingerprint such as
+//! `aisp-gold-δ0.72-a0.01`, or as a shields-style SVG for READMEs. Both
+//! depend only on the tier, δ and ambiguity, rounded to two decimals, so
+//! identical metrics always render identically.
+
+use super::types::ValidationResult;
+use crate::semantic::QualityTier;
+
+/// Approximate advance of one character in the badge font, in pixels
+const CHAR_WIDTH: usize = 7;
+/// Horizontal padding around each badge label, in pixels
+const PADDING: usize = 10;
+
+impl ValidationResult {
+    /// Stable fingerprint of the tier, δ and ambiguity
+    pub fn badge(&self) -> String {
+        format!(
+            "aisp-{}-δ{:.2}-a{:.2}",
+            self.tier.name().to_lowercase(),
+            badge_metric(self.delta),
+            badge_metric(self.ambiguity)
+        )
+    }
+
+    /// Flat SVG badge reading `aisp | <tier> δ<delta>`
+    pub fn badge_svg(&self) -> String {
+        let label = "aisp";
+        let message = format!("{} δ{:.2}", self.tier.name(), badge_metric(self.delta));
+        let label_width = label.chars().count() * CHAR_WIDTH + PADDING;
+        let message_width = message.chars().count() * CHAR_WIDTH + PADDING;
+        let width = label_width + message_width;
+
+        format!(
+            concat!(
+                r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{label}: {message}">"##,
+                r##"<title>{fingerprint}</title>"##,
+                r##"<rect width="{label_width}" height="20" fill="#555"/>"##,
+                r##"<rect x="{label_width}" width="{message_width}" height="20" fill="{color}"/>"##,
+                r##"<g fill="#fff" text-anchor="middle" font-family="Verdana,DejaVu Sans,sans-serif" font-size="11">"##,
+                r##"<text x="{label_x}" y="14">{label}</text>"##,
+                r##"<text x="{message_x}" y="14">{message}</text>"##,
+                r##"</g></svg>"##
+            ),
+            width = width,
+            label = label,
+            message = message,
+            fingerprint = self.badge(),
+            label_width = label_width,
+            message_width = message_width,
+            color = tier_color(&self.tier),
+            label_x = label_width / 2,
+            message_x = label_width + message_width / 2,
+        )
+    }
+}
+
+/// Clamp to [0, 1] so rounding never renders `-0.00` or out-of-range values
+fn badge_metric(value: f64) -> f64 {
+    if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, 1.0)
+    }
+}
+
+fn tier_color(tier: &QualityTier) -> &'static str {
+    match tier {
+        QualityTier::Platinum => "#8e44ad",
+        QualityTier::Gold => "#dfb317",
+        QualityTier::Silver => "#9f9f9f",
+        QualityTier::Bronze => "#cd7f32",
+        QualityTier::Reject => "#e05d44",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AispError;
+
+    fn result(tier: QualityTier, delta: f64, ambiguity: f64) -> ValidationResult {
+        let mut result = ValidationResult::failed(AispError::validation_error("unused"), 0);
+        result.valid = true;
+        result.tier = tier;
+        result.delta = delta;
+        result.ambiguity = ambiguity;
+        result
+    }
+
+    #[test]
+    fn test_identical_results_produce_identical_badges() {
+        let first = result(QualityTier::Gold, 0.7213, 0.0149);
+        let second = result(QualityTier::Gold, 0.7213, 0.0149);
+
+        assert_eq!(first.badge(), "aisp-gold-δ0.72-a0.01");
+        assert_eq!(first.badge(), second.badge());
+        assert_eq!(first.badge_svg(), second.badge_svg());
+    }
+
+    #[test]
+    fn test_svg_badge_contains_tier_name() {
+        let svg = result(QualityTier::Platinum, 0.81, 0.0).badge_svg();
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("Platinum δ0.81"));
+        assert!(svg.contains("<title>aisp-platinum-δ0.81-a0.00</title>"));
+        assert!(svg.contains("#8e44ad"));
+    }
+
+    #[test]
+    fn test_badge_metrics_are_clamped() {
+        assert_eq!(result(QualityTier::Reject, -0.0001, 1.5).badge(), "aisp-reject-δ0.00-a1.00");
+    }
+}
@@ -0,0 +1,110 @@
+//! Overly Broad Quantifiers
+//!
+//! A quantifier over the whole universe, as in `∀x:𝕌→P(x)`, or over a
+//! domain that constrains nothing, as in `∀x:true→P(x)`, says nothing about
+//! what `x` is. That usually means a constraint is missing, so the engine
+//! reports it as a warning suggesting a declared type instead.
+
+use super::engine::AispValidator;
+use super::shadowing::span_of;
+use crate::ast::canonical::Span;
+use crate::parser::robust_parser::{Pair, Rule};
+use std::fmt;
+
+/// Quantifier domains that admit every value
+pub const UNBOUNDED_DOMAINS: &[&str] = &["𝕌", "Any", "Top", "Universe", "true"];
+
+/// A quantifier whose domain does not bound its variable
+#[derive(Debug, Clone, PartialEq)]
+pub struct BroadQuantifier {
+    /// The bound variable
+    pub variable: String,
+    /// The domain as written
+    pub domain: String,
+    /// The domain in the source
+    pub span: Span,
+}
+
+impl fmt::Display for BroadQuantifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` ranges over `{}` at {}:{}; bound it by a type from ⟦Σ⟧",
+            self.variable, self.domain, self.span.line, self.span.column
+        )
+    }
+}
+
+impl AispValidator {
+    /// Report quantifiers over unbounded or trivially bounded domains in
+    /// the document's parse tree, in source order
+    pub fn broad_quantifiers(document: &Pair<'_, Rule>) -> Vec<BroadQuantifier> {
+        let mut broad = Vec::new();
+        for quantifier in document.clone().into_inner().flatten().filter(|pair| pair.as_rule() == Rule::quantified_expr) {
+            // quantifier, bound variable, domain, body
+            let mut inner = quantifier.into_inner();
+            let (Some(_), Some(variable), Some(domain)) = (inner.next(), inner.next(), inner.next()) else {
+                continue;
+            };
+            let name = domain.as_str().trim();
+            if UNBOUNDED_DOMAINS.contains(&name) {
+                let mut span = span_of(&domain);
+                span.end = span.start + name.len();
+                broad.push(BroadQuantifier {
+                    variable: variable.as_str().trim().to_string(),
+                    domain: name.to_string(),
+                    span,
+                });
+            }
+        }
+        broad
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(source: &str) -> Pair<'_, Rule> {
+        AispValidator::parse_tree(source).expect("document parses")
+    }
+
+    fn document(rules: &str) -> String {
+        format!(
+            "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{{domain≜x}}\n\n⟦Σ:Types⟧{{\n  State≜{{Idle,Busy}}\n}}\n\n\
+             ⟦Γ:Rules⟧{{\n  {}\n}}\n\n⟦Λ:Funcs⟧{{id≜λx.x}}\n\n⟦Ε⟧⟨δ≜0.5⟩",
+            rules
+        )
+    }
+
+    #[test]
+    fn test_unbounded_quantifier_is_warned() {
+        let source = document("∀x:𝕌→x≠Busy");
+
+        let broad = AispValidator::broad_quantifiers(&tree(&source));
+        assert_eq!(broad.len(), 1);
+        assert_eq!((broad[0].variable.as_str(), broad[0].domain.as_str()), ("x", "𝕌"));
+        assert_eq!((broad[0].span.line, broad[0].span.column), (10, 6));
+        assert_eq!(&source[broad[0].span.start..broad[0].span.end], "𝕌");
+
+        let result = AispValidator::new().validate(&source);
+        assert!(result
+            .warnings
+            .iter()
+            .any(|warning| warning.to_string().contains("Broad quantifier: `x` ranges over `𝕌` at 10:6")));
+    }
+
+    #[test]
+    fn test_trivially_bounded_quantifier_is_warned() {
+        let broad = AispValidator::broad_quantifiers(&tree(&document("∀s:State→∃t:true→t≠s")));
+        assert_eq!(broad.len(), 1);
+        assert_eq!((broad[0].variable.as_str(), broad[0].domain.as_str()), ("t", "true"));
+    }
+
+    #[test]
+    fn test_bounded_quantifiers_are_silent() {
+        for rules in ["∀s:State→s≠Busy", "∀n:ℕ→n≥0", "∀s:{Idle,Busy}→s≠Busy"] {
+            assert_eq!(AispValidator::broad_quantifiers(&tree(&document(rules))), vec![], "{}", rules);
+        }
+    }
+}
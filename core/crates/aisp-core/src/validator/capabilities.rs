@@ -0,0 +1,209 @@
+//! Capability Report
+//!
+//! Which analyses a validation actually ran depends on the configured
+//! level, on compile-time features such as Z3, and on whether validation
+//! stopped early. The capability report lists every analysis stage with
+//! its status and, when it did not run, the reason.
+
+use super::types::{ValidationConfig, ValidationResult};
+use crate::z3_verification::Z3VerificationFacade;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Whether an analysis stage executed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum StageStatus {
+    /// The stage executed and produced a result
+    Ran,
+    /// The stage was enabled but produced no result
+    Skipped,
+    /// The stage was turned off by configuration or a missing feature
+    Disabled,
+}
+
+impl fmt::Display for StageStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StageStatus::Ran => write!(f, "ran"),
+            StageStatus::Skipped => write!(f, "skipped"),
+            StageStatus::Disabled => write!(f, "disabled"),
+        }
+    }
+}
+
+/// Status of one analysis stage
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StageCapability {
+    /// Stage name, e.g. `formal`
+    pub stage: String,
+    /// Whether the stage executed
+    pub status: StageStatus,
+    /// Why the stage did not run
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub reason: Option<String>,
+}
+
+impl fmt::Display for StageCapability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.stage, self.status)?;
+        if let Some(reason) = &self.reason {
+            write!(f, ", {}", reason)?;
+        }
+        Ok(())
+    }
+}
+
+/// Every analysis stage of a validation run with its status
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CapabilityReport {
+    pub stages: Vec<StageCapability>,
+}
+
+impl CapabilityReport {
+    /// Derive the report from the configuration a result was produced with
+    pub fn for_result(config: &ValidationConfig, result: &ValidationResult) -> Self {
+        let z3 = Z3VerificationFacade::is_available();
+        // Early failures leave later stages without a result
+        let stopped_early = result.semantic_analysis.is_none();
+
+        let stage = |stage: &str, enabled: bool, needs_z3: bool, produced: bool, absent: &str| {
+            let (status, reason) = if !enabled {
+                (StageStatus::Disabled, Some("disabled in configuration"))
+            } else if needs_z3 && !z3 {
+                (StageStatus::Disabled, Some("z3 feature off"))
+            } else if produced {
+                (StageStatus::Ran, None)
+            } else if stopped_early {
+                (StageStatus::Skipped, Some("validation failed before this stage"))
+            } else {
+                (StageStatus::Skipped, Some(absent))
+            };
+            StageCapability { stage: stage.to_string(), status, reason: reason.map(String::from) }
+        };
+
+        Self {
+            stages: vec![
+                stage("semantic", true, false, result.semantic_analysis.is_some(), "analysis failed"),
+                stage(
+                    "relational",
+                    config.enable_relational_analysis,
+                    false,
+                    result.relational_analysis.is_some(),
+                    "analysis failed",
+                ),
+                stage("temporal", config.enable_temporal_analysis, false, result.temporal_analysis.is_some(), "analysis failed"),
+                stage("formal", config.enable_formal_verification, true, result.formal_verification.is_some(), "verification failed"),
+                stage(
+                    "proof_coverage",
+                    config.enable_formal_verification,
+                    false,
+                    result.proof_coverage.is_some(),
+                    "document has no rules",
+                ),
+                stage(
+                    "trivector",
+                    config.enable_trivector_validation,
+                    false,
+                    result.trivector_validation.is_some(),
+                    "validation failed",
+                ),
+                stage("enhanced_z3", config.enable_enhanced_z3, true, result.enhanced_z3_verification.is_some(), "verification failed"),
+                stage(
+                    "ghost_intent",
+                    config.enable_ghost_intent_validation,
+                    false,
+                    result.ghost_intent_validation.is_some(),
+                    "validation failed",
+                ),
+                stage("rossnet", config.enable_rossnet_scoring, false, result.rossnet_validation.is_some(), "validation failed"),
+                stage("hebbian", config.enable_hebbian_learning, false, result.hebbian_validation.is_some(), "validation failed"),
+                stage("anti_drift", config.enable_anti_drift, false, result.anti_drift_validation.is_some(), "validation failed"),
+                stage("symbol_stats", config.include_symbol_stats, false, result.symbol_stats.is_some(), "not collected"),
+            ],
+        }
+    }
+
+    /// Status of the named stage
+    pub fn stage(&self, stage: &str) -> Option<&StageCapability> {
+        self.stages.iter().find(|capability| capability.stage == stage)
+    }
+
+    /// Names of the stages that ran
+    pub fn ran(&self) -> Vec<&str> {
+        self.stages
+            .iter()
+            .filter(|capability| capability.status == StageStatus::Ran)
+            .map(|capability| capability.stage.as_str())
+            .collect()
+    }
+}
+
+impl fmt::Display for CapabilityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for capability in &self.stages {
+            writeln!(f, "{}", capability)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::AispValidator;
+
+    const SOURCE: &str = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                          ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+
+    #[test]
+    fn test_report_reflects_configured_level() {
+        let result = AispValidator::new().validate(SOURCE);
+        let report = result.capabilities();
+        assert_eq!(report.stage("semantic").unwrap().status, StageStatus::Ran);
+        let relational = report.stage("relational").unwrap();
+        assert_eq!(relational.status, StageStatus::Disabled);
+        assert_eq!(relational.to_string(), "relational: disabled, disabled in configuration");
+        assert_eq!(report.stage("temporal").unwrap().status, StageStatus::Disabled);
+
+        let config = ValidationConfig {
+            enable_relational_analysis: true,
+            enable_temporal_analysis: true,
+            enable_formal_verification: true,
+            ..ValidationConfig::default()
+        };
+        let result = AispValidator::with_config(config).validate(SOURCE);
+        let report = result.capabilities();
+        assert!(report.ran().contains(&"relational"));
+        assert!(report.ran().contains(&"temporal"));
+        assert!(report.ran().contains(&"proof_coverage"));
+    }
+
+    #[test]
+    fn test_report_reflects_z3_feature() {
+        let config = ValidationConfig { enable_formal_verification: true, ..ValidationConfig::default() };
+        let result = AispValidator::with_config(config).validate(SOURCE);
+        let formal = result.capabilities().stage("formal").unwrap().clone();
+
+        if Z3VerificationFacade::is_available() {
+            assert_ne!(formal.status, StageStatus::Disabled);
+        } else {
+            assert_eq!(formal.to_string(), "formal: disabled, z3 feature off");
+        }
+    }
+
+    #[test]
+    fn test_early_failure_skips_enabled_stages() {
+        let config = ValidationConfig { enable_temporal_analysis: true, ..ValidationConfig::default() };
+        let result = AispValidator::with_config(config).validate("not an AISP document");
+        let report = result.capabilities();
+        assert!(report.ran().is_empty());
+        let temporal = report.stage("temporal").unwrap();
+        assert_eq!(temporal.status, StageStatus::Skipped);
+        assert_eq!(temporal.reason.as_deref(), Some("validation failed before this stage"));
+    }
+}
@@ -0,0 +1,243 @@
+//! Directory Validation
+//!
+//! Validates every supported document under a directory, so editor plugins,
+//! servers and the CLI share one traversal. A file or subdirectory that
+//! cannot be read yields a failed result for its path instead of aborting
+//! the run. Paths matching a pattern in the root's `.aispignore` are
+//! skipped.
+
+use super::engine::AispValidator;
+use super::types::ValidationResult;
+use crate::error::{AispError, AispResult};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File extensions validated by `validate_directory`
+pub const SUPPORTED_EXTENSIONS: &[&str] = &["aisp"];
+
+/// File in the root directory listing paths to exclude
+pub const IGNORE_FILE: &str = ".aispignore";
+
+/// Glob patterns excluding paths from directory validation
+///
+/// One pattern per line; blank lines and lines starting with `#` are
+/// skipped. As in `.gitignore`, a pattern ending in `/` matches only
+/// directories, a pattern containing another `/` is matched against the
+/// whole path relative to the root, and any other pattern is matched
+/// against the file or directory name at every depth. Everything under an
+/// ignored directory is ignored.
+#[derive(Debug, Clone, Default)]
+pub struct IgnorePatterns {
+    patterns: Vec<IgnorePattern>,
+}
+
+#[derive(Debug, Clone)]
+struct IgnorePattern {
+    glob: glob::Pattern,
+    directory_only: bool,
+    anchored: bool,
+}
+
+impl IgnorePatterns {
+    /// Parse the contents of an ignore file
+    pub fn parse(text: &str) -> AispResult<Self> {
+        let mut patterns = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let directory_only = line.ends_with('/');
+            let pattern = line.trim_end_matches('/');
+            let anchored = pattern.contains('/');
+            let glob = glob::Pattern::new(pattern.trim_start_matches('/')).map_err(|error| AispError::ValidationError {
+                message: format!("{} line {}: invalid pattern '{}': {}", IGNORE_FILE, index + 1, line, error.msg),
+            })?;
+            patterns.push(IgnorePattern { glob, directory_only, anchored });
+        }
+        Ok(Self { patterns })
+    }
+
+    /// Read `.aispignore` from `root`, with no patterns when it is absent
+    pub fn load(root: &Path) -> AispResult<Self> {
+        let path = root.join(IGNORE_FILE);
+        match fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(io_error(&path, error)),
+        }
+    }
+
+    /// Whether `relative`, a path under the root, is excluded
+    pub fn is_ignored(&self, relative: &Path, is_dir: bool) -> bool {
+        let options = glob::MatchOptions { require_literal_separator: true, ..Default::default() };
+        let name = relative.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+        let path = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        self.patterns.iter().any(|pattern| {
+            (is_dir || !pattern.directory_only)
+                && pattern.glob.matches_with(if pattern.anchored { &path } else { name }, options)
+        })
+    }
+}
+
+impl AispValidator {
+    /// Validate each supported file in `dir`, descending into
+    /// subdirectories when `recursive`, with results in path order
+    ///
+    /// Paths matching the patterns in `dir`'s `.aispignore` are skipped.
+    /// Only an unreadable `dir` or ignore file, or an invalid ignore
+    /// pattern, is an error. Symbolic links to directories are not followed.
+    pub fn validate_directory(&self, dir: &Path, recursive: bool) -> AispResult<Vec<(PathBuf, ValidationResult)>> {
+        let entries = fs::read_dir(dir).map_err(|error| io_error(dir, error))?;
+        let ignore = IgnorePatterns::load(dir)?;
+
+        let mut results = Vec::new();
+        let mut pending = vec![(dir.to_path_buf(), entries)];
+        while let Some((current, entries)) = pending.pop() {
+            for entry in entries {
+                let entry = match entry {
+                    Ok(entry) => entry,
+                    Err(error) => {
+                        let result = ValidationResult::failed(io_error(&current, error), 0);
+                        results.push((current.clone(), result));
+                        continue;
+                    }
+                };
+                let path = entry.path();
+                let is_dir = entry.file_type().map(|file_type| file_type.is_dir()).unwrap_or(false);
+                if ignore.is_ignored(path.strip_prefix(dir).unwrap_or(&path), is_dir) {
+                    continue;
+                }
+
+                if is_dir {
+                    if recursive {
+                        match fs::read_dir(&path) {
+                            Ok(entries) => pending.push((path, entries)),
+                            Err(error) => {
+                                let result = ValidationResult::failed(io_error(&path, error), 0);
+                                results.push((path, result));
+                            }
+                        }
+                    }
+                } else if is_supported(&path) {
+                    let result = match fs::read_to_string(&path) {
+                        Ok(source) => self.validate(&source),
+                        Err(error) => ValidationResult::failed(io_error(&path, error), 0),
+                    };
+                    results.push((path, result));
+                }
+            }
+        }
+
+        results.sort_by(|(left, _), (right, _)| left.cmp(right));
+        Ok(results)
+    }
+}
+
+fn is_supported(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| SUPPORTED_EXTENSIONS.contains(&extension))
+}
+
+fn io_error(path: &Path, error: std::io::Error) -> AispError {
+    AispError::IoError {
+        message: format!("{}: {}", path.display(), error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID: &str = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                         ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+
+    fn fixture_directory(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aisp_validate_directory_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("valid.aisp"), VALID).unwrap();
+        fs::write(dir.join("invalid.aisp"), "not an AISP document").unwrap();
+        fs::write(dir.join("notes.txt"), VALID).unwrap();
+        fs::write(dir.join("nested/deep.aisp"), VALID).unwrap();
+        // Unreadable as UTF-8, standing in for a file the process may not read
+        fs::write(dir.join("unreadable.aisp"), [0xff, 0xfe, 0x00]).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_validate_directory_recursively() {
+        let dir = fixture_directory("recursive");
+
+        let results = AispValidator::new().validate_directory(&dir, true).unwrap();
+        let summary: Vec<(String, bool)> = results
+            .iter()
+            .map(|(path, result)| (path.strip_prefix(&dir).unwrap().display().to_string(), result.valid))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("invalid.aisp".to_string(), false),
+                ("nested/deep.aisp".to_string(), true),
+                ("unreadable.aisp".to_string(), false),
+                ("valid.aisp".to_string(), true),
+            ]
+        );
+
+        let (_, unreadable) = &results[2];
+        assert!(matches!(unreadable.error, Some(AispError::IoError { .. })));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_validate_directory_without_recursion() {
+        let dir = fixture_directory("flat");
+
+        let results = AispValidator::new().validate_directory(&dir, false).unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|(path, _)| path.parent() == Some(dir.as_path())));
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(AispValidator::new().validate_directory(&dir, false).is_err());
+    }
+
+    #[test]
+    fn test_aispignore_excludes_subdirectory() {
+        let dir = fixture_directory("ignored");
+        fs::create_dir_all(dir.join("vendor/specs")).unwrap();
+        fs::write(dir.join("vendor/specs/third_party.aisp"), "not an AISP document").unwrap();
+        fs::write(dir.join(IGNORE_FILE), "# vendored and generated specs\nvendor/\nin*.aisp\n").unwrap();
+
+        let results = AispValidator::new().validate_directory(&dir, true).unwrap();
+        let paths: Vec<String> = results
+            .iter()
+            .map(|(path, _)| path.strip_prefix(&dir).unwrap().display().to_string())
+            .collect();
+        assert_eq!(paths, vec!["nested/deep.aisp", "unreadable.aisp", "valid.aisp"]);
+
+        fs::write(dir.join(IGNORE_FILE), "nested/*.aisp\n[\n").unwrap();
+        let error = AispValidator::new().validate_directory(&dir, true).unwrap_err();
+        assert!(error.to_string().contains("line 2: invalid pattern '['"), "{}", error);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_ignore_patterns_anchoring() {
+        let ignore = IgnorePatterns::parse("generated/*.aisp\nscratch/\n*.draft.aisp").unwrap();
+
+        assert!(ignore.is_ignored(Path::new("generated/a.aisp"), false));
+        assert!(!ignore.is_ignored(Path::new("specs/generated/a.aisp"), false));
+        assert!(!ignore.is_ignored(Path::new("generated/deeper/a.aisp"), false));
+        assert!(ignore.is_ignored(Path::new("specs/scratch"), true));
+        assert!(!ignore.is_ignored(Path::new("specs/scratch"), false));
+        assert!(ignore.is_ignored(Path::new("specs/x.draft.aisp"), false));
+        assert!(!ignore.is_ignored(Path::new("specs/x.aisp"), false));
+    }
+}
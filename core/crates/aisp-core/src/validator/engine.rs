@@ -6,12 +6,26 @@
 use crate::error::*;
 use crate::ast::canonical::CanonicalAispDocument as AispDocument;
 use crate::ast::canonical::IntoCanonical;
-use crate::parser::robust_parser::RobustAispParser;
-use crate::semantic::SemanticAnalyzer;
+use crate::parser::robust_parser::{
+    AispParser, Pair, ParseError, ParseErrorType, RobustAispParser, Rule, SecurityIssue, SecurityIssueType,
+};
+use crate::relational_new::RelationalAnalyzer;
+use crate::semantic::{QualityTier, SemanticAnalyzer};
+use crate::symbols::calculate_symbol_density;
+use crate::temporal_new::UnifiedTemporalAnalyzer;
+use crate::type_checker::{FunctionTypeInference, TypeChecker};
 use crate::{AISP_VERSION};
-use super::types::{ValidationConfig, ValidationResult};
+use super::types::{StageProfile, SymbolStats, ValidationConfig, ValidationResult};
 use super::verification_methods::VerificationMethods;
 use super::structural_validator::{StructuralValidator, StructuralValidationConfig};
+use super::incremental::{block_hashes, Reuse};
+use super::capabilities::CapabilityReport;
+use super::remediation::{
+    invisible_character_diagnostic, malformed_marker_diagnostic, missing_block_diagnostic, near_miss_diagnostic,
+    partition_missing_blocks,
+};
+use pest::Parser;
+use std::collections::HashMap;
 use std::time::Instant;
 
 /// Main AISP validator engine
@@ -120,8 +134,25 @@ impl AispValidator {
     /// - Unicode normalization prevents injection attacks
     /// - Formal verification catches logical inconsistencies
     pub fn validate(&self, source: &str) -> ValidationResult {
+        self.validate_reusing(source, None)
+    }
+
+    /// Validate `source`, taking block-scoped results for unchanged blocks
+    /// from `previous` when given
+    pub(super) fn validate_reusing(&self, source: &str, previous: Option<&ValidationResult>) -> ValidationResult {
+        let mut result = self.run_stages(source, previous);
+        result.capability_report = CapabilityReport::for_result(&self.config, &result);
+        result
+    }
+
+    /// Run the validation pipeline, stopping at the first fatal stage
+    fn run_stages(&self, source: &str, previous: Option<&ValidationResult>) -> ValidationResult {
         let start_time = Instant::now();
         let document_size = source.len();
+        // The budget is checked between stages, and between the methods and
+        // proofs within the verification stages
+        let deadline = self.config.timeout.map(|timeout| start_time + timeout);
+        let expired = || deadline.map_or(false, |deadline| Instant::now() >= deadline);
 
         // Check document size
         if document_size > self.config.max_document_size {
@@ -143,12 +174,25 @@ impl AispValidator {
         }
 
         // Parse document
-        let (document, parse_time, mut all_warnings) = match self.parse_document(source, document_size) {
+        let (document, parse_time, mut all_warnings, near_miss, parse_errors) = match self.parse_document(source, document_size) {
             Ok(result) => result,
             Err(validation_result) => return validation_result,
         };
+        let hashes = block_hashes(source, &document);
+        let reuse = previous.and_then(|previous| Reuse::new(previous, &hashes));
+        if let Some(reuse) = reuse.as_ref().filter(|reuse| reuse.changed.is_empty()) {
+            let mut result = reuse.previous.clone();
+            result.revalidated_blocks = Vec::new();
+            return result;
+        }
+        let reuse = reuse.as_ref();
+        let mut pass_diagnostics = Vec::new();
+        if expired() {
+            return self.timed_out("structure validation", None, document_size, all_warnings);
+        }
 
         // Validate document structure
+        let structure_start = Instant::now();
         let structural_result = match self.structural_validator.validate_structure(&document) {
             Ok(result) => result,
             Err(err) => {
@@ -158,8 +202,41 @@ impl AispValidator {
 
         // Check for structural validation failures
         if !structural_result.is_valid {
+            // A block whose marker uses a look-alike letter is there, just not recognized
+            let (missing, malformed, unparsed) = partition_missing_blocks(&structural_result.missing_blocks, source);
+            // A block that is present but rejected by the grammar is a
+            // syntax error at its line, not a missing block
+            let syntax_error = parse_errors.iter().find(|error| error.error_type == ParseErrorType::SyntaxError);
+            if let (true, true, Some(syntax_error)) = (missing.is_empty(), malformed.is_empty(), syntax_error) {
+                let message = format!(
+                    "{} {} could not be parsed: {}",
+                    unparsed.join(", "),
+                    if unparsed.len() == 1 { "block" } else { "blocks" },
+                    syntax_error_reason(syntax_error)
+                );
+                let diagnostic = AispWarning::error(&message).with_line(syntax_error.line);
+                let message = match &near_miss {
+                    Some(hint) => format!("{} ({})", message, hint),
+                    None => message,
+                };
+                let mut failed = ValidationResult::failed(
+                    AispError::parse_error(syntax_error.line, syntax_error.column, message),
+                    document_size,
+                );
+                failed.warnings.extend(all_warnings.into_iter().filter(|warning| warning.remediation.is_some()));
+                failed.warnings.push(diagnostic);
+                return failed;
+            }
             let error_message = if !structural_result.missing_blocks.is_empty() {
-                format!("Missing required blocks: {}", structural_result.missing_blocks.join(", "))
+                let missing = (!missing.is_empty()).then(|| format!("Missing required blocks: {}", missing.join(", ")));
+                let unparsed =
+                    (!unparsed.is_empty()).then(|| format!("Blocks that could not be parsed: {}", unparsed.join(", ")));
+                missing
+                    .into_iter()
+                    .chain(malformed.iter().map(|near_miss| format!("Malformed block marker: {}", near_miss)))
+                    .chain(unparsed)
+                    .collect::<Vec<_>>()
+                    .join("; ")
             } else if !structural_result.empty_blocks.is_empty() {
                 format!("Empty blocks not allowed: {}", structural_result.empty_blocks.join(", "))
             } else if !structural_result.order_violations.is_empty() {
@@ -167,11 +244,49 @@ impl AispValidator {
             } else {
                 "Document structure validation failed".to_string()
             };
-            
-            return ValidationResult::failed(
-                AispError::validation_error(&error_message),
+            // A block dropped by error recovery usually traces back to a typo
+            let error_message = match &near_miss {
+                Some(hint) => format!("{} ({})", error_message, hint),
+                None => error_message,
+            };
+
+            // Fixable parser diagnostics come along, since a dropped block
+            // often traces back to a near-miss glyph
+            let mut failed = ValidationResult::failed(AispError::validation_error(&error_message), document_size);
+            failed.warnings.extend(all_warnings.into_iter().filter(|warning| warning.remediation.is_some()));
+            failed.warnings.extend(malformed.iter().map(malformed_marker_diagnostic));
+            failed.warnings.extend(missing.iter().map(|block| missing_block_diagnostic(block, source)));
+            failed.warnings.extend(
+                structural_result.order_violations.iter().map(|violation| AispWarning::error(violation.clone())),
+            );
+            return failed;
+        }
+
+        // Errors the parser recovered from, such as a duplicate definition,
+        // still make the document invalid
+        if let Some(first) = parse_errors.first() {
+            let mut failed = ValidationResult::failed(
+                AispError::parse_error(first.line, first.column, first.message.trim_end()),
                 document_size,
             );
+            failed.warnings = all_warnings;
+            failed.warnings.extend(
+                parse_errors.iter().map(|error| AispWarning::error(error.message.trim_end()).with_line(error.line)),
+            );
+            return failed;
+        }
+
+        // Evidence has to state δ; φ, τ and other metrics are optional
+        if let Some(evidence) = document.get_evidence_blocks().into_iter().find(|evidence| evidence.delta.is_none()) {
+            let message = "Incomplete evidence: ⟦Ε⟧ does not declare δ";
+            let mut diagnostic = AispWarning::error(message);
+            if let Some(span) = &evidence.span {
+                diagnostic = diagnostic.with_line(span.line);
+            }
+            let mut failed = ValidationResult::failed(AispError::validation_error(message), document_size);
+            failed.warnings = all_warnings;
+            failed.warnings.push(diagnostic);
+            return failed;
         }
 
         // Add structural warnings to overall warnings
@@ -179,6 +294,151 @@ impl AispValidator {
             all_warnings.push(AispWarning::warning(warning));
         }
 
+        // Source-level passes read the text the grammar saw, such as a
+        // compact document with its blocks on separate lines or `⟦γ⟧`
+        // read as `⟦Γ⟧`
+        let normalized = self.parser().normalize_source(source);
+        let normalized = normalized.as_ref();
+        // Parsed once and shared; the robust parser recovers from input the
+        // grammar rejects, and such documents have nothing for these passes
+        let tree = Self::parse_tree(normalized);
+
+        // Features outside the active profile fail validation
+        let profile = &self.config.profile;
+        if let Some(violation) = Self::profile_violations(profile, normalized, &document).first() {
+            return ValidationResult::failed(
+                AispError::validation_error(format!("Profile `{}`: {}", profile.name, violation)),
+                document_size,
+            );
+        }
+
+        // Uses that precede their definition warn, or fail in strict mode
+        let forward_references = match &tree {
+            Some(tree) if profile.check_definition_order => Self::forward_references(tree),
+            _ => Vec::new(),
+        };
+        if self.config.strict_mode && self.config.strict_definition_order {
+            if let Some(reference) = forward_references.first() {
+                return ValidationResult::failed(
+                    AispError::validation_error(format!("Forward reference: {}", reference)),
+                    document_size,
+                );
+            }
+        }
+        for reference in &forward_references {
+            all_warnings.push(AispWarning::warning(format!("Forward reference: {}", reference)).with_line(reference.line));
+        }
+
+        // Passes that read only some blocks keep their diagnostics while those are unchanged
+        let mut block_pass = |pass: &'static str, blocks: &[&str], run: &dyn Fn() -> Vec<AispWarning>| {
+            let diagnostics = reuse
+                .and_then(|reuse| reuse.diagnostics(pass, blocks))
+                .cloned()
+                .unwrap_or_else(run);
+            all_warnings.extend(diagnostics.iter().cloned());
+            pass_diagnostics.push((pass, diagnostics));
+        };
+
+        // A nested binding that reuses an enclosing variable's name hides it
+        block_pass("shadowing", &["Rules"], &|| {
+            tree.iter()
+                .flat_map(Self::shadowed_variables)
+                .map(|shadowed| AispWarning::warning(format!("Shadowed variable: {}", shadowed)).with_line(shadowed.inner.line))
+                .collect()
+        });
+
+        // A rule that repeats an earlier one up to bound variable names adds nothing
+        block_pass("redundancy", &["Rules"], &|| {
+            tree.iter()
+                .flat_map(Self::redundant_rules)
+                .map(|redundant| AispWarning::warning(format!("Redundant rule: {}", redundant)).with_line(redundant.second.line))
+                .collect()
+        });
+
+        // Calls to functions never defined in ⟦Λ⟧ have no meaning to check,
+        // and fail validation in strict mode like type errors
+        block_pass("undefined_calls", &["Rules", "Functions"], &|| {
+            Self::undefined_function_calls(&document)
+                .into_iter()
+                .map(|undefined| AispWarning::error(format!("Undefined function: {}", undefined)).with_line(undefined.span.line))
+                .collect()
+        });
+
+        // A quantifier over everything usually lacks a constraint
+        block_pass("broad_quantifiers", &["Rules"], &|| {
+            tree.iter()
+                .flat_map(Self::broad_quantifiers)
+                .map(|broad| AispWarning::warning(format!("Broad quantifier: {}", broad)).with_line(broad.span.line))
+                .collect()
+        });
+
+        // A bound past a type's conventional range is usually off by one
+        block_pass("numeric_bounds", &["Types", "Rules"], &|| {
+            tree.iter()
+                .flat_map(Self::out_of_range_bounds)
+                .map(|bound| AispWarning::warning(format!("Bound outside type range: {}", bound)).with_line(bound.span.line))
+                .collect()
+        });
+
+        block_pass("meta_key_order", &["Meta"], &|| {
+            Self::meta_key_order_deviations(&document, &self.config.meta_key_order)
+                .into_iter()
+                .map(|deviation| {
+                    let warning = AispWarning::warning(format!("Meta key order: {}", deviation));
+                    match deviation.line {
+                        Some(line) => warning.with_line(line),
+                        None => warning,
+                    }
+                })
+                .collect()
+        });
+
+        let structure_time = structure_start.elapsed();
+        if expired() {
+            return self.timed_out("type inference", None, document_size, all_warnings);
+        }
+
+        // Lambda type errors (arity, argument types) and undeclared enumeration
+        // variants fail validation in strict mode, as do undefined calls
+        let inference_start = Instant::now();
+        if profile.check_function_types {
+            block_pass("type_inference", &["Types", "Functions"], &|| {
+                FunctionTypeInference::new()
+                    .infer_document(&document)
+                    .errors
+                    .iter()
+                    // The message already carries the line and column
+                    .map(|diagnostic| AispWarning::error(format!("Type error {}", diagnostic)))
+                    .collect()
+            });
+        }
+
+        // Generic definitions, their parameters and instantiation arity
+        block_pass("type_check", &["Types", "Rules", "Functions"], &|| {
+            let type_check = TypeChecker::new().check_types(&document);
+            let errors = type_check.errors.iter().map(|error| AispWarning::error(error.to_string()));
+            errors.chain(type_check.warnings).collect()
+        });
+
+        if self.config.strict_mode {
+            let type_error = pass_diagnostics
+                .iter()
+                .filter(|(pass, _)| matches!(*pass, "undefined_calls" | "type_inference" | "type_check"))
+                .flat_map(|(_, diagnostics)| diagnostics)
+                .find(|diagnostic| diagnostic.severity == WarningSeverity::Error);
+            if let Some(type_error) = type_error {
+                let mut failed =
+                    ValidationResult::failed(AispError::validation_error(type_error.message.clone()), document_size);
+                failed.warnings = all_warnings;
+                return failed;
+            }
+        }
+
+        let inference_time = inference_start.elapsed();
+        if expired() {
+            return self.timed_out("semantic analysis", None, document_size, all_warnings);
+        }
+
         // Perform semantic analysis
         let (mut analysis, semantic_time) = match self.perform_semantic_analysis(&document, document_size) {
             Ok(result) => result,
@@ -188,66 +448,238 @@ impl AispValidator {
         // Merge warnings from semantic analysis
         all_warnings.extend(analysis.warnings().into_iter().map(|w| AispWarning::warning(w)));
 
+        // δ is the symbol density, so the reported tier is the one explain_tier explains
+        let density = calculate_symbol_density(source);
+
+        // A declared tier above the computed one warns, or fails in strict mode
+        if let Some(overclaim) = Self::tier_overclaim(&document, QualityTier::from_delta(density)) {
+            if self.config.strict_mode {
+                return ValidationResult::failed(
+                    AispError::validation_error(format!("Tier overclaim: {}", overclaim)),
+                    document_size,
+                );
+            }
+            let warning = AispWarning::warning(format!("Tier overclaim: {}", overclaim));
+            all_warnings.push(match overclaim.line {
+                Some(line) => warning.with_line(line),
+                None => warning,
+            });
+        }
+
         // Apply strict mode checks
         if self.config.strict_mode {
             self.verification_methods.apply_strict_checks(&mut analysis);
         }
 
-        // Perform additional verifications
-        let verification_results = self.perform_additional_verifications(&document, &analysis, document_size);
-
-        // Handle verification failures
-        if let Err(validation_result) = verification_results {
-            return validation_result;
+        // Later stages build on the semantic analysis, which is kept
+        if expired() {
+            let partial = ValidationResult::success(
+                analysis, document_size, parse_time, semantic_time, None, None, None, None, None, None, None, None,
+            )
+            .with_density(density);
+            return self.timed_out("verification", Some(partial), document_size, all_warnings);
         }
 
-        let (
-            formal_verification,
-            trivector_validation,
-            enhanced_z3_verification,
-            ghost_intent_validation,
-            rossnet_validation,
-            hebbian_validation,
-            anti_drift_validation,
-        ) = verification_results.unwrap();
-
-        // Create final result
+        // Perform additional verifications
+        let verification_start = Instant::now();
+        let verifications = match self.perform_additional_verifications(&document, &analysis, document_size, &expired) {
+            Ok(verifications) => verifications,
+            Err(validation_result) => return validation_result,
+        };
+        let interrupted_before = verifications.interrupted_before;
         let mut result = ValidationResult::success(
             analysis,
             document_size,
             parse_time,
             semantic_time,
-            if self.config.include_ast { Some(document) } else { None },
-            formal_verification,
-            trivector_validation,
-            enhanced_z3_verification,
-            ghost_intent_validation,
-            rossnet_validation,
-            hebbian_validation,
-            anti_drift_validation,
-        );
+            None,
+            verifications.formal_verification,
+            verifications.trivector_validation,
+            verifications.enhanced_z3_verification,
+            verifications.ghost_intent_validation,
+            verifications.rossnet_validation,
+            verifications.hebbian_validation,
+            verifications.anti_drift_validation,
+        )
+        .with_density(density);
+        if let Some(method) = interrupted_before {
+            return self.timed_out(method, Some(result), document_size, all_warnings);
+        }
+        if expired() {
+            return self.timed_out("proof coverage", Some(result), document_size, all_warnings);
+        }
+        // Per-property outcomes of the formal run, when it ran
+        let mut proof_outcomes = None;
+        let proof_coverage = if self.config.enable_formal_verification {
+            // Coverage depends only on the rules, so it carries over while
+            // they are unchanged, unless the verified minimum needs the
+            // outcomes themselves
+            let reused = reuse
+                .filter(|reuse| reuse.unchanged("Rules") && self.config.min_verified_properties == 0)
+                .and_then(|reuse| reuse.previous.proof_coverage);
+            match reused {
+                Some(coverage) => Some(coverage),
+                None => {
+                    let outcomes = match self.prove_document(&document, &[], deadline) {
+                        Ok(Some(outcomes)) => Ok(outcomes),
+                        Ok(None) => return self.timed_out("proof coverage", Some(result), document_size, all_warnings),
+                        Err(error) => Err(error),
+                    };
+                    // A document whose properties cannot all be extracted
+                    // has no per-rule outcomes to measure
+                    let coverage = outcomes.as_ref().ok().and_then(|outcomes| Self::proof_coverage(outcomes));
+                    proof_outcomes = Some(outcomes);
+                    coverage
+                }
+            }
+        } else {
+            None
+        };
+        let verification_time = verification_start.elapsed();
+        result.proof_coverage = proof_coverage;
+
+        // Optional relational and temporal analyses of the parsed document
+        if expired() {
+            return self.timed_out("relational analysis", Some(result), document_size, all_warnings);
+        }
+        let relational_analysis = if self.config.enable_relational_analysis {
+            match RelationalAnalyzer::new().analyze(&document, &HashMap::new()) {
+                Ok(analysis) => Some(analysis),
+                Err(error) => {
+                    all_warnings.push(AispWarning::warning(format!("Relational analysis failed: {}", error)));
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        result.relational_analysis = relational_analysis;
+        if expired() {
+            return self.timed_out("temporal analysis", Some(result), document_size, all_warnings);
+        }
+        let temporal_analysis = if self.config.enable_temporal_analysis {
+            Some(UnifiedTemporalAnalyzer::new().analyze(&document))
+        } else {
+            None
+        };
+
+        // Complete the final result
+        if self.config.include_ast {
+            result.ast = Some(document);
+        }
+        result.temporal_analysis = temporal_analysis;
+        if self.config.include_symbol_stats {
+            result.symbol_stats = Some(SymbolStats::from_source(source));
+        }
+        result.revalidated_blocks = match reuse {
+            Some(reuse) => reuse.changed.clone(),
+            None => hashes.iter().map(|(block, _)| block.clone()).collect(),
+        };
+        result.block_hashes = hashes;
+        result.pass_diagnostics = pass_diagnostics;
 
         // Add timing information
         if self.config.include_timing {
-            result.total_time = Some(start_time.elapsed());
+            let total_time = start_time.elapsed();
+            let semantic_stages = result.semantic_analysis.as_ref()
+                .map(|analysis| analysis.verification_details.performance_metrics.stage_timings.iter()
+                    .map(|timing| StageProfile::new(timing.stage.clone(), timing.duration))
+                    .collect())
+                .unwrap_or_default();
+            result.total_time = Some(total_time);
+            result.profile = Some(StageProfile::new("validate", total_time).with_children(vec![
+                StageProfile::new("parse", parse_time),
+                StageProfile::new("structure", structure_time),
+                StageProfile::new("type_inference", inference_time),
+                StageProfile::new("semantic", semantic_time).with_children(semantic_stages),
+                StageProfile::new("verification", verification_time),
+            ]));
         }
 
         // Override warnings with collected warnings
         result.warnings = all_warnings;
 
+        // Denied warnings fail validation and are reported as errors
+        if self.config.deny_warnings && !result.warnings.is_empty() {
+            for warning in &mut result.warnings {
+                warning.severity = WarningSeverity::Error;
+            }
+            let messages: Vec<&str> = result.warnings.iter().map(|warning| warning.message.as_str()).collect();
+            result.valid = false;
+            result.error = Some(AispError::validation_error(format!(
+                "{} warning(s) denied: {}",
+                messages.len(),
+                messages.join("; ")
+            )));
+        }
+
+        // High-assurance configurations require a number of proven
+        // properties, counted from the formal run's outcomes
+        let minimum = self.config.min_verified_properties;
+        if let Some(outcomes) = proof_outcomes.filter(|_| result.valid && minimum > 0) {
+            if let Err(error) = outcomes.and_then(|outcomes| Self::check_verified_minimum(&outcomes, minimum)) {
+                result.valid = false;
+                result.error = Some(error);
+            }
+        }
+
         result
     }
 
+    /// Mark `partial`, or a failed result when no stage produced one, as
+    /// having run out of the validation budget before `stage`
+    fn timed_out(
+        &self,
+        stage: &str,
+        partial: Option<ValidationResult>,
+        document_size: usize,
+        warnings: Vec<AispWarning>,
+    ) -> ValidationResult {
+        let error = AispError::validation_error(format!(
+            "Timed out after {:?} before {}",
+            self.config.timeout.unwrap_or_default(),
+            stage
+        ));
+        let mut result = match partial {
+            Some(mut partial) => {
+                partial.valid = false;
+                partial.error = Some(error);
+                partial
+            }
+            None => ValidationResult::failed(error, document_size),
+        };
+        result.timed_out = true;
+        result.warnings = warnings;
+        result
+    }
+
+    /// The grammar's parse tree of `source`, read by the source-level
+    /// passes, or `None` when the grammar rejects it
+    pub fn parse_tree(source: &str) -> Option<Pair<'_, Rule>> {
+        AispParser::parse(Rule::aisp_document, source).ok().and_then(|mut pairs| pairs.next())
+    }
+
+    /// The robust parser configured with this validator's size limit and
+    /// symbol density floor; strict mode rejects invisible characters and
+    /// lowercase block letters
+    pub(super) fn parser(&self) -> RobustAispParser {
+        RobustAispParser::new()
+            .with_max_document_size(self.config.max_document_size)
+            .with_min_symbol_density(self.config.min_symbol_density)
+            .with_reject_invisible_characters(self.config.strict_mode)
+            .with_tolerant_block_markers(!self.config.strict_mode)
+    }
+
     /// Parse AISP document from source
     fn parse_document(
         &self, 
         source: &str, 
         document_size: usize
-    ) -> Result<(AispDocument, std::time::Duration, Vec<AispWarning>), ValidationResult> {
+    ) -> Result<(AispDocument, std::time::Duration, Vec<AispWarning>, Option<String>, Vec<ParseError>), ValidationResult> {
         let parse_start = Instant::now();
-        let parser = RobustAispParser::new();
-        let parse_result = parser.parse(source);
-        
+        let parse_result = self.parser().parse(source);
+        let near_miss = near_miss_hint(&parse_result.errors);
+
         let mut document = match parse_result.document {
             Some(robust_doc) => {
                 let mut canonical = robust_doc.into_canonical();
@@ -255,15 +687,23 @@ impl AispValidator {
                 canonical
             }
             None => {
-                let error_message = if !parse_result.errors.is_empty() {
-                    parse_result.errors[0].message.clone()
+                let mut error_message = if let Some(error) = parse_result.errors.first() {
+                    error.message.clone()
+                } else if let Some(issue) = parse_result.security_issues.first() {
+                    issue.to_string()
                 } else {
                     "Failed to parse document".to_string()
                 };
-                return Err(ValidationResult::failed(
-                    AispError::validation_error(error_message),
-                    document_size,
-                ));
+                let mut diagnostics: Vec<AispWarning> = parse_result.security_issues.iter()
+                    .map(|issue| security_diagnostic(issue, source))
+                    .collect();
+                if let Some(hint) = &near_miss {
+                    error_message = format!("{} ({})", error_message.trim_end(), hint);
+                    diagnostics.push(near_miss_diagnostic(hint, source, &parse_result.errors));
+                }
+                let mut failed = ValidationResult::failed(AispError::validation_error(error_message), document_size);
+                failed.warnings = diagnostics;
+                return Err(failed);
             }
         };
         let parse_time = parse_start.elapsed();
@@ -272,6 +712,11 @@ impl AispValidator {
         let mut all_warnings: Vec<AispWarning> = parse_result.warnings.into_iter()
             .map(|w| AispWarning::warning(w.message))
             .collect();
+        if let Some(hint) = &near_miss {
+            all_warnings.push(near_miss_diagnostic(hint, source, &parse_result.errors));
+        }
+        all_warnings.extend(parse_result.security_issues.iter()
+            .map(|issue| security_diagnostic(issue, source)));
 
         // Check AISP version compatibility
         if document.header.version != AISP_VERSION {
@@ -283,7 +728,7 @@ impl AispValidator {
             ));
         }
 
-        Ok((document, parse_time, all_warnings))
+        Ok((document, parse_time, all_warnings, near_miss, parse_result.errors))
     }
 
     /// Perform semantic analysis
@@ -305,44 +750,48 @@ impl AispValidator {
         Ok((analysis, semantic_time))
     }
 
-    /// Perform additional verification methods
+    /// Perform additional verification methods, stopping before the next
+    /// enabled method once `expired` reports the budget spent
     fn perform_additional_verifications(
         &self,
         document: &AispDocument,
         analysis: &crate::semantic::DeepVerificationResult,
         document_size: usize,
-    ) -> Result<(
-        Option<crate::semantic::DeepVerificationResult>,
-        Option<crate::tri_vector_validation::TriVectorValidationResult>,
-        Option<crate::z3_verification::canonical_types::Z3VerificationResult>,
-        Option<crate::ghost_intent_validation::GhostIntentValidationResult>,
-        Option<crate::rossnet_scoring::RossNetValidationResult>,
-        Option<crate::hebbian_learning::HebbianValidationResult>,
-        Option<crate::anti_drift::AntiDriftValidationResult>,
-    ), ValidationResult> {
+        expired: &dyn Fn() -> bool,
+    ) -> Result<AdditionalVerifications, ValidationResult> {
+        let mut verifications = AdditionalVerifications::default();
+
         // Perform formal verification if enabled  
-        let formal_verification = if self.config.enable_formal_verification {
-            match self.verification_methods.perform_formal_verification(&document, &analysis) {
+        if self.config.enable_formal_verification {
+            if expired() {
+                return Ok(verifications.interrupted_before("formal verification"));
+            }
+            verifications.formal_verification = match self.verification_methods.perform_formal_verification(&document, &analysis) {
                 Ok(verification_result) => Some(verification_result),
                 Err(_err) => None, // Log warning elsewhere
-            }
-        } else {
-            None
-        };
+            };
+        }
 
         // Perform tri-vector validation if enabled
-        let trivector_validation = if self.config.enable_trivector_validation {
-            match self.verification_methods.perform_trivector_validation(&document) {
+        if self.config.enable_trivector_validation {
+            if expired() {
+                return Ok(verifications.interrupted_before("tri-vector validation"));
+            }
+            verifications.trivector_validation = match self.verification_methods.perform_trivector_validation(&document) {
                 Ok(trivector_result) => Some(trivector_result),
                 Err(_err) => None, // Log warning elsewhere
-            }
-        } else {
-            None
-        };
+            };
+        }
 
         // Perform enhanced Z3 verification if enabled
-        let enhanced_z3_verification = if self.config.enable_enhanced_z3 {
-            match self.verification_methods.perform_enhanced_z3_verification(&document, trivector_validation.as_ref()) {
+        if self.config.enable_enhanced_z3 {
+            if expired() {
+                return Ok(verifications.interrupted_before("enhanced Z3 verification"));
+            }
+            verifications.enhanced_z3_verification = match self
+                .verification_methods
+                .perform_enhanced_z3_verification(&document, verifications.trivector_validation.as_ref())
+            {
                 Ok(z3_result) => Some(z3_result),
                 Err(err) => {
                     // Formal verification failure should cause validation to fail, not just warn
@@ -357,14 +806,15 @@ impl AispValidator {
                         None
                     }
                 }
-            }
-        } else {
-            None
-        };
+            };
+        }
 
         // Perform ghost intent validation if enabled
-        let ghost_intent_validation = if self.config.enable_ghost_intent_validation {
-            match self.verification_methods.perform_ghost_intent_validation(&document) {
+        if self.config.enable_ghost_intent_validation {
+            if expired() {
+                return Ok(verifications.interrupted_before("ghost intent validation"));
+            }
+            verifications.ghost_intent_validation = match self.verification_methods.perform_ghost_intent_validation(&document) {
                 Ok(ghost_result) => Some(ghost_result),
                 Err(err) => {
                     if self.config.strict_formal_verification {
@@ -378,14 +828,15 @@ impl AispValidator {
                         None
                     }
                 }
-            }
-        } else {
-            None
-        };
+            };
+        }
 
         // Perform RossNet scoring validation if enabled
-        let rossnet_validation = if self.config.enable_rossnet_scoring {
-            match self.verification_methods.perform_rossnet_validation(&document, &analysis) {
+        if self.config.enable_rossnet_scoring {
+            if expired() {
+                return Ok(verifications.interrupted_before("RossNet scoring"));
+            }
+            verifications.rossnet_validation = match self.verification_methods.perform_rossnet_validation(&document, &analysis) {
                 Ok(rossnet_result) => Some(rossnet_result),
                 Err(err) => {
                     if self.config.strict_formal_verification {
@@ -399,14 +850,15 @@ impl AispValidator {
                         None
                     }
                 }
-            }
-        } else {
-            None
-        };
+            };
+        }
 
         // Perform Hebbian learning validation if enabled
-        let hebbian_validation = if self.config.enable_hebbian_learning {
-            match self.verification_methods.perform_hebbian_validation(&document, &analysis) {
+        if self.config.enable_hebbian_learning {
+            if expired() {
+                return Ok(verifications.interrupted_before("Hebbian learning validation"));
+            }
+            verifications.hebbian_validation = match self.verification_methods.perform_hebbian_validation(&document, &analysis) {
                 Ok(hebbian_result) => Some(hebbian_result),
                 Err(err) => {
                     if self.config.strict_formal_verification {
@@ -420,14 +872,15 @@ impl AispValidator {
                         None
                     }
                 }
-            }
-        } else {
-            None
-        };
+            };
+        }
 
         // Perform anti-drift protocol validation if enabled
-        let anti_drift_validation = if self.config.enable_anti_drift {
-            match self.verification_methods.perform_anti_drift_validation(&document, &analysis) {
+        if self.config.enable_anti_drift {
+            if expired() {
+                return Ok(verifications.interrupted_before("anti-drift validation"));
+            }
+            verifications.anti_drift_validation = match self.verification_methods.perform_anti_drift_validation(&document, &analysis) {
                 Ok(anti_drift_result) => Some(anti_drift_result),
                 Err(err) => {
                     if self.config.strict_formal_verification {
@@ -441,20 +894,31 @@ impl AispValidator {
                         None
                     }
                 }
-            }
-        } else {
-            None
-        };
+            };
+        }
 
-        Ok((
-            formal_verification,
-            trivector_validation,
-            enhanced_z3_verification,
-            ghost_intent_validation,
-            rossnet_validation,
-            hebbian_validation,
-            anti_drift_validation,
-        ))
+        Ok(verifications)
+    }
+}
+
+/// Results of the optional verification methods, and the method the
+/// validation budget ran out before, if it did
+#[derive(Default)]
+struct AdditionalVerifications {
+    formal_verification: Option<crate::semantic::DeepVerificationResult>,
+    trivector_validation: Option<crate::tri_vector_validation::TriVectorValidationResult>,
+    enhanced_z3_verification: Option<crate::z3_verification::canonical_types::Z3VerificationResult>,
+    ghost_intent_validation: Option<crate::ghost_intent_validation::GhostIntentValidationResult>,
+    rossnet_validation: Option<crate::rossnet_scoring::RossNetValidationResult>,
+    hebbian_validation: Option<crate::hebbian_learning::HebbianValidationResult>,
+    anti_drift_validation: Option<crate::anti_drift::AntiDriftValidationResult>,
+    interrupted_before: Option<&'static str>,
+}
+
+impl AdditionalVerifications {
+    fn interrupted_before(mut self, method: &'static str) -> Self {
+        self.interrupted_before = Some(method);
+        self
     }
 }
 
@@ -464,6 +928,29 @@ impl Default for AispValidator {
     }
 }
 
+/// "line 10: did you mean →?" for the first parse error with a near-miss glyph
+fn near_miss_hint(errors: &[ParseError]) -> Option<String> {
+    errors.iter().find_map(|error| {
+        let glyph = error.suggestion.as_ref()?;
+        Some(format!("line {}: did you mean {}?", error.line, glyph))
+    })
+}
+
+/// Warning for a security issue the parser found; invisible characters come
+/// with the patch that removes them
+fn security_diagnostic(issue: &SecurityIssue, source: &str) -> AispWarning {
+    match issue.issue_type {
+        SecurityIssueType::InvisibleCharacter => invisible_character_diagnostic(issue, source),
+        _ => AispWarning::warning(issue.to_string()),
+    }
+}
+
+/// What the grammar expected at a syntax error, such as
+/// `expected logical_expr`, without the excerpt the parser prints
+fn syntax_error_reason(error: &ParseError) -> &str {
+    error.message.lines().last().map_or("", |line| line.trim().trim_start_matches("= "))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -532,6 +1019,469 @@ test_function ≜ λx.x + 1
         assert!(result.error_message().is_none() || result.error_message().is_some());
     }
 
+    #[test]
+    fn test_profile_stages_sum_to_total() {
+        let mut config = ValidationConfig::default();
+        config.include_timing = true;
+        let validator = AispValidator::with_config(config);
+        let source = "𝔸5.1.Profile@2026-01-25\n\n⟦Ω:Meta⟧{domain≜profiling}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+
+        let result = validator.validate(source);
+        let profile = result.profile.expect("profile is recorded when timing is enabled");
+        let total = result.total_time.unwrap();
+        assert_eq!(profile.duration, total);
+
+        let stages: Vec<&str> = profile.children.iter().map(|c| c.stage.as_str()).collect();
+        assert_eq!(stages, ["parse", "structure", "type_inference", "semantic", "verification"]);
+        let covered = profile.children_total();
+        assert!(covered <= total);
+        let untimed = total - covered;
+        assert!(
+            untimed <= total / 10 + std::time::Duration::from_millis(2),
+            "stages cover {:?} of {:?}", covered, total
+        );
+
+        let semantic = profile.find("semantic").unwrap();
+        let deep_stages: Vec<&str> = semantic.children.iter().map(|c| c.stage.as_str()).collect();
+        assert_eq!(deep_stages, ["type", "logic", "dependency", "mathematical", "deception", "synthesis"]);
+        assert!(semantic.children_total() <= semantic.duration);
+    }
+
+    #[test]
+    fn test_optional_analyses_reflect_the_document() {
+        let document = |rules: &str| {
+            format!(
+                "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{{domain≜x}}\n\n⟦Σ:Types⟧{{\n  State≜{{Idle,Busy}}\n}}\n\n\
+                 ⟦Γ:Rules⟧{{\n  {}\n}}\n\n⟦Λ:Funcs⟧{{id≜λx.x}}\n\n⟦Ε⟧⟨δ≜0.5⟩",
+                rules
+            )
+        };
+        let one_rule = document("∀s:State→Valid(s)");
+        let two_rules = document("∀s:State→Valid(s)\n  ∀s:State→Known(s)");
+
+        let result = AispValidator::new().validate(&one_rule);
+        assert!(result.relational_analysis.is_none());
+        assert!(result.temporal_analysis.is_none());
+        assert!(result.symbol_stats.is_none());
+
+        let mut config = ValidationConfig::default();
+        config.enable_relational_analysis = true;
+        config.enable_temporal_analysis = true;
+        config.include_symbol_stats = true;
+        let validator = AispValidator::with_config(config);
+        let constraints = |source: &str| {
+            let result = validator.validate(source);
+            assert!(result.valid, "{:?}", result.error);
+            result.relational_analysis.unwrap().constraint_analysis.constraints.len()
+        };
+        assert_eq!(constraints(&two_rules), constraints(&one_rule) + 1);
+
+        let result = validator.validate(&one_rule);
+        let temporal = result.temporal_analysis.unwrap();
+        assert!(temporal.formula_analysis.formulas.is_empty());
+        assert!(temporal.pattern_analysis.patterns.is_empty());
+
+        let stats = result.symbol_stats.unwrap();
+        assert_eq!(stats.symbol_counts[&'∀'], 1);
+        assert_eq!(stats.category_counts[&crate::symbols::SymbolCategory::Quantifier], 1);
+        assert_eq!(stats.total(), stats.category_counts.values().sum::<usize>());
+    }
+
+    #[test]
+    fn test_deny_warnings_promotes_warnings_to_errors() {
+        let clean = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy};\n  Task≜State\n}\n\n\
+                     ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        let forward = clean.replace("State≜{Idle,Busy};\n  Task≜State", "Task≜State;\n  State≜{Idle,Busy}");
+
+        let result = AispValidator::new().validate(&forward);
+        assert!(result.valid, "{:?}", result.error);
+        assert_eq!(result.warnings.len(), 1, "{:?}", result.warnings);
+        assert_eq!(result.warnings[0].severity, WarningSeverity::Warning);
+
+        let mut config = ValidationConfig::default();
+        config.deny_warnings = true;
+        let validator = AispValidator::with_config(config);
+
+        let result = validator.validate(&forward);
+        assert!(!result.valid);
+        assert!(result.error.unwrap().to_string().contains("1 warning(s) denied: Forward reference"));
+        assert_eq!(result.warnings[0].severity, WarningSeverity::Error);
+
+        let result = validator.validate(clean);
+        assert!(result.valid, "{:?}", result.warnings);
+    }
+
+    #[test]
+    fn test_timeout_interrupts_the_verification_stage() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+        let mut config = ValidationConfig::default();
+        config.enable_formal_verification = true;
+        config.enable_trivector_validation = true;
+        config.enable_relational_analysis = true;
+        config.enable_temporal_analysis = true;
+        let validator = AispValidator::with_config(config.clone());
+        let (document, ..) = validator.parse_document(source, source.len()).ok().unwrap();
+        let (analysis, _) = validator.perform_semantic_analysis(&document, source.len()).ok().unwrap();
+
+        // The budget runs out while formal verification runs
+        let checks = std::cell::Cell::new(0);
+        let expired = || {
+            checks.set(checks.get() + 1);
+            checks.get() > 1
+        };
+        let verifications = validator
+            .perform_additional_verifications(&document, &analysis, source.len(), &expired)
+            .ok()
+            .unwrap();
+        assert_eq!(checks.get(), 2);
+        assert_eq!(verifications.interrupted_before, Some("tri-vector validation"));
+        assert!(verifications.trivector_validation.is_none());
+
+        let verifications = validator
+            .perform_additional_verifications(&document, &analysis, source.len(), &|| false)
+            .ok()
+            .unwrap();
+        assert_eq!(verifications.interrupted_before, None);
+
+        config.timeout = Some(std::time::Duration::from_secs(600));
+        let result = AispValidator::with_config(config).validate(source);
+        assert!(!result.timed_out);
+        assert!(result.temporal_analysis.is_some(), "{:?}", result.error);
+    }
+
+    /// Canonical document with the given ⟦Σ⟧, ⟦Λ⟧ and ⟦Ε⟧ contents
+    fn document_with(types: &str, functions: &str, evidence: &str) -> String {
+        format!(
+            "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{{domain≜x}}\n\n⟦Σ:Types⟧{{\n  {}\n}}\n\n\
+             ⟦Γ:Rules⟧{{\n  ∀s:State→Valid(s)\n}}\n\n⟦Λ:Funcs⟧{{\n  {}\n}}\n\n⟦Ε⟧⟨{}⟩",
+            types, functions, evidence
+        )
+    }
+
+    #[test]
+    fn test_generic_definitions_are_type_checked() {
+        let messages = |types: &str| -> Vec<String> {
+            let result = AispValidator::new().validate(&document_with(types, "id≜λx.x", "δ≜0.5"));
+            result.warnings.into_iter().map(|warning| warning.message).collect()
+        };
+
+        assert!(messages("State≜{Idle,Busy}\n  Pair⟨A,B⟩≜(A,B)\n  Point≜Pair⟨ℝ,ℝ⟩").is_empty());
+        assert_eq!(
+            messages("State≜{Idle,Busy}\n  Pair⟨A,B⟩≜(A,B)\n  Point≜Pair⟨ℝ⟩"),
+            vec!["Type error: Type 'Pair' expects 2 type argument(s), found 1"]
+        );
+        assert_eq!(
+            messages("State≜{Idle,Busy}\n  Tagged⟨T,U⟩≜(T,ℕ)"),
+            vec!["Type parameter 'U' of 'Tagged' is never used"]
+        );
+    }
+
+    #[test]
+    fn test_type_errors_fail_strict_validation() {
+        let arity = "Type error in `∀s:State→id(s,s)`: `id` expects 1 argument(s) but is applied to 2 (line 10, column 12)";
+        let arity_source = document_with("State≜{Idle,Busy}", "id≜λx.x", "δ≜0.5").replace("Valid(s)", "id(s,s)");
+        let generic = "Type error: Type 'Pair' expects 2 type argument(s), found 1";
+        let generic_source = document_with("State≜{Idle,Busy}\n  Pair⟨A,B⟩≜(A,B)\n  Point≜Pair⟨ℝ⟩", "id≜λx.x", "δ≜0.5");
+
+        for (source, message) in [(&arity_source, arity), (&generic_source, generic)] {
+            let result = AispValidator::new().validate(source);
+            assert!(result.valid, "{:?}", result.error);
+            let error = result.warnings.iter().find(|warning| warning.message == message).unwrap();
+            assert_eq!(error.severity, WarningSeverity::Error);
+
+            let config = ValidationConfig { strict_mode: true, ..ValidationConfig::default() };
+            let result = AispValidator::with_config(config).validate(source);
+            assert!(!result.valid);
+            assert_eq!(result.error.unwrap().to_string(), format!("Validation error: {}", message));
+        }
+    }
+
+    #[test]
+    fn test_lambda_type_error_names_its_location_once() {
+        let source = document_with("State≜{Idle,Busy}", "f≜λx.x + 1\n  g≜λy.f(y)", "δ≜0.5")
+            .replace("Valid(s)", "f(true)");
+
+        let result = AispValidator::new().validate(&source);
+        assert!(result.valid, "{:?}", result.error);
+        let error = result.warnings.iter().find(|warning| warning.message.starts_with("Type error")).unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Error: Type error in `∀s:State→f(true)`: argument 1 of `f` expects ℕ, found 𝔹 (line 10, column 12)"
+        );
+
+        let config = ValidationConfig { strict_mode: true, ..ValidationConfig::default() };
+        assert!(!AispValidator::with_config(config).validate(&source).valid);
+    }
+
+    #[test]
+    fn test_undeclared_variant_fails_strict_validation() {
+        let source = document_with("State≜{Idle,Busy}\n  Status≜{Running,Stopped}", "id≜λx.x", "δ≜0.5")
+            .replace("∀s:State→Valid(s)", "∀s:Status→s≠Status.Paused");
+        let message =
+            "Type error in `∀s:Status→s≠Status.Paused`: `Status` has no variant `Paused` (declared: Running, Stopped) \
+             (line 11, column 22)";
+
+        let result = AispValidator::new().validate(&source);
+        assert!(result.valid, "{:?}", result.error);
+        let error = result.warnings.iter().find(|warning| warning.message == message).unwrap();
+        assert_eq!((error.severity.clone(), error.line), (WarningSeverity::Error, None));
+
+        let config = ValidationConfig { strict_mode: true, ..ValidationConfig::default() };
+        let result = AispValidator::with_config(config).validate(&source);
+        assert!(!result.valid);
+        assert_eq!(result.error.unwrap().to_string(), format!("Validation error: {}", message));
+        assert!(AispValidator::new().validate(&source.replace("Paused", "Running")).warnings.is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_type_invalidates_the_document() {
+        let source = document_with("State≜{Idle,Busy}\n  State≜{Idle}", "id≜λx.x", "δ≜0.5");
+
+        let result = AispValidator::new().validate(&source);
+        assert!(!result.valid);
+        assert_eq!(
+            result.error.unwrap().to_string(),
+            "Parse error at line 7, column 3: Conflicting duplicate type definition 'State' (first defined at 6:3)"
+        );
+        assert!(result.warnings.iter().any(|warning| {
+            warning.severity == WarningSeverity::Error && warning.line == Some(7)
+        }));
+
+        assert!(AispValidator::new().validate(&document_with("State≜{Idle,Busy}", "id≜λx.x", "δ≜0.5")).valid);
+    }
+
+    #[test]
+    fn test_evidence_without_delta_invalidates_the_document() {
+        let result = AispValidator::new().validate(&document_with("State≜{Idle,Busy}", "id≜λx.x", "φ≜97"));
+        assert!(!result.valid);
+        assert_eq!(result.error.unwrap().to_string(), "Validation error: Incomplete evidence: ⟦Ε⟧ does not declare δ");
+        assert!(result.warnings.iter().any(|warning| {
+            warning.severity == WarningSeverity::Error && warning.line == Some(17)
+        }));
+
+        assert!(AispValidator::new().validate(&document_with("State≜{Idle,Busy}", "id≜λx.x", "δ≜0.5;φ≜97")).valid);
+    }
+
+    #[test]
+    fn test_unparseable_block_is_a_syntax_error_not_a_missing_block() {
+        let source = document_with("State≜{Idle,Busy}", "id≜λx.x", "δ≜0.5").replace("Valid(s)", "¬Valid(s)");
+
+        let result = AispValidator::new().validate(&source);
+        assert!(!result.valid);
+        assert_eq!(
+            result.error.unwrap().to_string(),
+            "Parse error at line 10, column 12: Rules block could not be parsed: expected logical_expr"
+        );
+        assert!(result.warnings.iter().any(|warning| {
+            warning.message == "Rules block could not be parsed: expected logical_expr" && warning.line == Some(10)
+        }));
+        assert!(!result.warnings.iter().any(|warning| warning.message.starts_with("Missing required block")));
+    }
+
+    #[test]
+    fn test_spaced_membership_rule_is_parsed() {
+        let source = document_with("State≜{Idle,Busy}", "id≜λx.x", "δ≜0.5").replace("Valid(s)", "s ∈ State");
+
+        let result = AispValidator::new().validate(&source);
+        assert!(result.valid, "{:?}", result.error);
+    }
+
+    #[test]
+    fn test_duplicate_function_invalidates_the_document() {
+        let source = document_with("State≜{Idle,Busy}", "id≜λx.x;\n  id≜λy.y", "δ≜0.5");
+
+        let result = AispValidator::new().validate(&source);
+        assert!(!result.valid);
+        assert!(result.error.unwrap().to_string().contains("duplicate function definition 'id' (first defined at 14:3)"));
+    }
+
+    #[test]
+    fn test_unknown_tier_glyph_invalidates_the_document() {
+        let source = document_with("State≜{Idle,Busy}", "id≜λx.x", "δ≜0.5;τ≜◊⁺⁺⁺");
+
+        let result = AispValidator::new().validate(&source);
+        assert!(!result.valid);
+        assert_eq!(
+            result.error.unwrap().to_string(),
+            "Parse error at line 17, column 13: Unknown tier (τ) glyph '◊⁺⁺⁺'"
+        );
+    }
+
+    #[test]
+    fn test_unknown_unit_invalidates_the_document() {
+        let source = document_with("State≜{Idle,Busy}", "id≜λx.x", "δ≜0.5;latency≜100parsecs");
+
+        let result = AispValidator::new().validate(&source);
+        assert!(!result.valid);
+        assert_eq!(result.error.unwrap().to_string(), "Parse error at line 17, column 22: Unknown unit 'parsecs'");
+        assert!(result.warnings.iter().any(|warning| warning.message == "Unknown unit 'parsecs'"));
+    }
+
+    #[test]
+    fn test_near_miss_glyph_is_suggested_in_the_error() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State->Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+
+        let result = AispValidator::new().validate(source);
+        assert!(!result.valid);
+        assert!(result.error.unwrap().to_string().ends_with("(line 10: did you mean →?)"));
+    }
+
+    #[test]
+    fn test_validate_compact_document() {
+        let source = "𝔸5.1.X@2026-01-25;⟦Ω:Meta⟧{domain≜x};⟦Σ:Types⟧{State≜{Idle,Busy}};\
+                      ⟦Γ:Rules⟧{∀s:State→Valid(s)};⟦Λ:Funcs⟧{id≜λx.x};⟦Ε⟧⟨δ≜0.5⟩";
+
+        let result = AispValidator::new().validate(source);
+        assert!(result.valid, "{:?}", result.error);
+    }
+
+    #[test]
+    fn test_tautological_rule_is_warned() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n  p→p\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩\n";
+
+        let result = AispValidator::new().validate(source);
+        assert!(result.valid, "{:?}", result.error);
+        let messages: Vec<&str> = result.warnings.iter().map(|warning| warning.message.as_str()).collect();
+        assert_eq!(messages, vec!["Rule 'p→p' at line 11, column 3 is a tautology and constrains nothing"]);
+    }
+
+    #[test]
+    fn test_source_passes_check_compact_documents() {
+        let source = "𝔸5.1.X@2026-01-25;⟦Ω:Meta⟧{domain≜x};⟦Σ:Types⟧{State≜{Idle,Busy}};\
+                      ⟦Γ:Rules⟧{∀x:State→(∀x:State→Valid(x))};⟦Λ:Funcs⟧{id≜λx.x};⟦Ε⟧⟨δ≜0.5⟩";
+
+        let result = AispValidator::new().validate(source);
+        assert!(result.valid, "{:?}", result.error);
+        assert!(
+            result.warnings.iter().any(|w| w.message.starts_with("Shadowed variable") && w.line == Some(4)),
+            "{:?}",
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn test_source_passes_check_lowercase_blocks() {
+        let source = "𝔸5.1.X@2026-01-25\n⟦ω:Meta⟧{domain≜x}\n⟦σ:Types⟧{State≜{Idle,Busy}}\n\
+                      ⟦γ:Rules⟧{∀x:State→(∀x:State→Valid(x))}\n⟦λ:Funcs⟧{id≜λx.x}\n⟦ε⟧⟨δ≜0.5⟩";
+
+        let result = AispValidator::new().validate(source);
+        assert!(result.valid, "{:?}", result.error);
+        assert!(
+            result.warnings.iter().any(|w| w.message.starts_with("Shadowed variable") && w.line == Some(4)),
+            "{:?}",
+            result.warnings
+        );
+    }
+
+    #[test]
+    fn test_strict_mode_accepts_declared_tier_at_or_below_computed() {
+        let mut config = ValidationConfig::default();
+        config.strict_mode = true;
+        let validator = AispValidator::with_config(config);
+
+        // δ is 0.24 here, which reaches Bronze
+        let source = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                      ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5;τ≜◊⁻⟩";
+        let result = validator.validate(source);
+        assert!(result.valid, "{:?}", result.error);
+        assert_eq!(result.tier, QualityTier::Bronze);
+        assert!(result.warnings.iter().all(|w| !w.message.contains("Tier overclaim")));
+    }
+
+    #[test]
+    fn test_density_floor_comes_from_the_config() {
+        // δ is 0.227 here
+        let source = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                      ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+        let low_density = |result: &ValidationResult| {
+            result.warnings.iter().any(|w| w.message.contains("Low Symbol Density"))
+        };
+
+        assert!(!low_density(&AispValidator::new().validate(source)));
+
+        let config = ValidationConfig { min_symbol_density: 0.5, ..ValidationConfig::default() };
+        let result = AispValidator::with_config(config).validate(source);
+        assert!(result.valid, "{:?}", result.error);
+        assert!(low_density(&result));
+    }
+
+    #[test]
+    fn test_parser_takes_the_size_limit_from_the_config() {
+        let source = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                      ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+        let validator = |max_document_size| {
+            AispValidator::with_config(ValidationConfig { max_document_size, ..ValidationConfig::default() })
+        };
+
+        let at_limit = validator(source.len());
+        assert!(at_limit.parser().check_document_size(source).is_ok());
+        assert!(at_limit.verify_properties(source, &[]).is_ok());
+
+        // Property verification parses without the pipeline's size check
+        let over_limit = validator(source.len() - 1);
+        assert!(matches!(
+            over_limit.parser().check_document_size(source),
+            Err(AispError::DocumentTooLarge { size, max }) if size == source.len() && max == source.len() - 1
+        ));
+        assert!(over_limit.verify_properties(source, &[]).is_err());
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_invisible_characters() {
+        let source = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\u{200B}\n}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+
+        // Without strict mode the character surfaces as the syntax error it causes
+        let result = AispValidator::new().validate(source);
+        assert!(result.error.unwrap().to_string().contains("Rules block could not be parsed"));
+        assert!(result.warnings.iter().any(|warning| warning.message.contains("U+200B")));
+
+        let result = AispValidator::with_config(ValidationConfig { strict_mode: true, ..ValidationConfig::default() })
+            .validate(source);
+        assert!(!result.valid);
+        assert!(result.error.unwrap().to_string().contains("Invisible Character: Invisible character U+200B"));
+        let diagnostic = result.warnings.first().expect("invisible character diagnostic");
+        assert_eq!(diagnostic.line, Some(5));
+        assert!(diagnostic.remediation.is_some());
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_lowercase_block_letters() {
+        let source = "𝔸5.1.X@2026-01-25\n⟦ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                      ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+
+        let result = AispValidator::new().validate(source);
+        assert!(result.valid, "{:?}", result.error);
+
+        let result = AispValidator::with_config(ValidationConfig { strict_mode: true, ..ValidationConfig::default() })
+            .validate(source);
+        assert!(!result.valid);
+        let error = result.error.unwrap().to_string();
+        assert!(error.contains("Lowercase block letter ω after ⟦"), "{}", error);
+    }
+
+    #[test]
+    fn test_overclaimed_tier_warns_or_fails_in_strict_mode() {
+        // δ is 0.24 here, which only reaches Bronze
+        let source = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                      ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5;τ≜◊⁺⁺⟩";
+        let message = "Tier overclaim: declared τ≜◊⁺⁺ (Platinum) exceeds the computed tier ◊⁻ (Bronze)";
+
+        let result = AispValidator::new().validate(source);
+        assert!(result.valid, "{:?}", result.error);
+        let warning = result.warnings.iter().find(|w| w.message == message).unwrap();
+        assert_eq!(warning.line, Some(6));
+
+        let mut config = ValidationConfig::default();
+        config.strict_mode = true;
+        let result = AispValidator::with_config(config).validate(source);
+        assert!(!result.valid);
+        assert_eq!(result.error.unwrap().to_string(), format!("Validation error: {}", message));
+    }
+
     #[test]
     fn test_validate_malformed_document() {
         let validator = AispValidator::new();
@@ -0,0 +1,246 @@
+//! Incremental Validation
+//!
+//! Editors revalidate on every keystroke, while most edits touch a single
+//! block. Each result records a content hash of the header and of every
+//! block; revalidating against a previous result reuses the diagnostics of
+//! passes that read only blocks whose hash is unchanged, and the proof
+//! coverage while the rules are unchanged and no minimum number of proven
+//! properties is required. Document-wide stages (parsing,
+//! structure, semantic analysis) always run again, so the aggregate
+//! metrics match a full run exactly.
+
+use super::engine::AispValidator;
+use super::types::ValidationResult;
+use crate::ast::canonical::CanonicalAispDocument;
+use crate::error::AispWarning;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// A previous result and the blocks edited since it was computed
+pub(super) struct Reuse<'a> {
+    pub(super) previous: &'a ValidationResult,
+    pub(super) changed: Vec<String>,
+    /// Header and block names, in document order
+    blocks: Vec<String>,
+    /// How many leading entries of `blocks` are unchanged
+    unchanged_prefix: usize,
+}
+
+impl<'a> Reuse<'a> {
+    /// Compare the current `hashes` with those of `previous`
+    ///
+    /// A previous result without hashes, or one whose blocks were added,
+    /// removed or reordered since, has nothing to reuse.
+    pub(super) fn new(previous: &'a ValidationResult, hashes: &[(String, u64)]) -> Option<Self> {
+        let same_blocks = hashes.len() == previous.block_hashes.len()
+            && hashes.iter().zip(&previous.block_hashes).all(|(current, prior)| current.0 == prior.0);
+        if previous.block_hashes.is_empty() || !same_blocks {
+            return None;
+        }
+
+        let changed: Vec<String> = hashes
+            .iter()
+            .zip(&previous.block_hashes)
+            .filter(|(current, prior)| current != prior)
+            .map(|((block, _), _)| block.clone())
+            .collect();
+        let unchanged_prefix = hashes
+            .iter()
+            .zip(&previous.block_hashes)
+            .take_while(|(current, prior)| current == prior)
+            .count();
+        Some(Self {
+            previous,
+            changed,
+            blocks: hashes.iter().map(|(block, _)| block.clone()).collect(),
+            unchanged_prefix,
+        })
+    }
+
+    /// Whether no block named `block` was edited
+    pub(super) fn unchanged(&self, block: &str) -> bool {
+        !self.changed.iter().any(|changed| changed == block)
+    }
+
+    /// Diagnostics `pass` reported in the previous run, if they still hold
+    ///
+    /// They hold when none of the `blocks` the pass reads, nor anything
+    /// before them, was edited: the text they came from is the same, down
+    /// to its line numbers.
+    pub(super) fn diagnostics(&self, pass: &str, blocks: &[&str]) -> Option<&'a Vec<AispWarning>> {
+        let last_read = self.blocks.iter().rposition(|block| blocks.contains(&block.as_str()));
+        if last_read.is_some_and(|last_read| last_read >= self.unchanged_prefix) {
+            return None;
+        }
+        self.previous
+            .pass_diagnostics
+            .iter()
+            .find(|(name, _)| *name == pass)
+            .map(|(_, diagnostics)| diagnostics)
+    }
+}
+
+impl AispValidator {
+    /// Validate `source`, reusing `previous` for blocks whose content is
+    /// unchanged
+    ///
+    /// An identical document returns `previous` as is. A previous result
+    /// without block hashes, or a document whose blocks were added or
+    /// removed, is validated in full.
+    pub fn validate_incremental(&self, source: &str, previous: &ValidationResult) -> ValidationResult {
+        self.validate_reusing(source, Some(previous))
+    }
+}
+
+/// Hash the header and each block of the parsed `document`, in document
+/// order
+///
+/// A block's text runs up to the next block, so the hashes cover the whole
+/// source and an unchanged prefix of them leaves line numbers in place.
+pub(super) fn block_hashes(source: &str, document: &CanonicalAispDocument) -> Vec<(String, u64)> {
+    let hash = |text: &str| {
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    let blocks: Vec<(&str, usize)> = document
+        .blocks
+        .iter()
+        .filter_map(|block| Some((block.block_type(), block.span()?.start)))
+        .filter(|&(_, start)| source.is_char_boundary(start))
+        .collect();
+    let Some(&(_, first_start)) = blocks.first() else {
+        return Vec::new();
+    };
+
+    let mut hashes = vec![("Header".to_string(), hash(&source[..first_start]))];
+    for (index, &(name, start)) in blocks.iter().enumerate() {
+        let end = blocks.get(index + 1).map_or(source.len(), |&(_, next)| next);
+        hashes.push((name.to_string(), hash(source.get(start..end).unwrap_or_default())));
+    }
+    hashes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::types::ValidationConfig;
+
+    fn document(rules: &str, functions: &str) -> String {
+        format!(
+            "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{{domain≜x}}\n\n⟦Σ:Types⟧{{\n  State≜{{Idle,Busy}}\n}}\n\n\
+             ⟦Γ:Rules⟧{{\n  {}\n}}\n\n⟦Λ:Funcs⟧{{\n  {}\n}}\n\n⟦Ε⟧⟨δ≜0.5⟩",
+            rules, functions
+        )
+    }
+
+    #[test]
+    fn test_editing_one_block_revalidates_only_that_block() {
+        let config = ValidationConfig { enable_formal_verification: true, ..ValidationConfig::default() };
+        let validator = AispValidator::with_config(config);
+        let original = document("∀s:State→Valid(s)", "id≜λx.x");
+        let edited = document("∀s:State→Valid(s)", "identity≜λx.x");
+
+        let previous = validator.validate(&original);
+        assert!(previous.valid, "{:?}", previous.error);
+        assert_eq!(previous.revalidated_blocks, ["Header", "Meta", "Types", "Rules", "Functions", "Evidence"]);
+
+        let incremental = validator.validate_incremental(&edited, &previous);
+        let full = validator.validate(&edited);
+        assert_eq!(incremental.revalidated_blocks, ["Functions"]);
+        assert_eq!(incremental.block_hashes, full.block_hashes);
+        assert_eq!(incremental.valid, full.valid);
+        assert_eq!(incremental.tier, full.tier);
+        assert_eq!(incremental.delta, full.delta);
+        assert_eq!(incremental.pure_density, full.pure_density);
+        assert_eq!(incremental.ambiguity, full.ambiguity);
+        assert_eq!(incremental.proof_coverage, full.proof_coverage);
+        let messages = |result: &ValidationResult| result.warnings.iter().map(|w| w.message.clone()).collect::<Vec<_>>();
+        assert_eq!(messages(&incremental), messages(&full));
+    }
+
+    #[test]
+    fn test_unchanged_rules_reuse_previous_proof_coverage() {
+        let config = ValidationConfig { enable_formal_verification: true, ..ValidationConfig::default() };
+        let validator = AispValidator::with_config(config);
+        let original = document("∀s:State→Valid(s)", "id≜λx.x");
+
+        // A marker value shows whether coverage was reused or recomputed
+        let mut previous = validator.validate(&original);
+        previous.proof_coverage = Some(0.25);
+
+        let functions_edited = document("∀s:State→Valid(s)", "ident≜λx.x");
+        assert_eq!(validator.validate_incremental(&functions_edited, &previous).proof_coverage, Some(0.25));
+
+        let rules_edited = document("∀s:State→Ready(s)", "id≜λx.x");
+        let incremental = validator.validate_incremental(&rules_edited, &previous);
+        assert_eq!(incremental.revalidated_blocks, ["Rules"]);
+        assert_eq!(incremental.proof_coverage, validator.validate(&rules_edited).proof_coverage);
+    }
+
+    #[test]
+    fn test_verified_minimum_is_checked_against_a_fresh_formal_run() {
+        let config = ValidationConfig {
+            enable_formal_verification: true,
+            min_verified_properties: 1,
+            ..ValidationConfig::default()
+        };
+        let validator = AispValidator::with_config(config);
+        let original = document("∀s:State→Valid(s)", "id≜λx.x");
+
+        let mut previous = validator.validate(&original);
+        previous.proof_coverage = Some(0.25);
+
+        let functions_edited = document("∀s:State→Valid(s)", "ident≜λx.x");
+        let incremental = validator.validate_incremental(&functions_edited, &previous);
+        assert_eq!(incremental.proof_coverage, Some(0.0));
+        assert!(!incremental.valid);
+        assert!(incremental.error.unwrap().to_string().contains("at least 1 required"));
+    }
+
+    #[test]
+    fn test_passes_over_unchanged_blocks_reuse_their_diagnostics() {
+        let validator = AispValidator::new();
+        let original = document("∀s:State→Valid(s)", "id≜λx.x");
+        let marker = AispWarning::warning("marker");
+
+        // A marker diagnostic shows whether the shadowing pass was reused or rerun
+        let mut previous = validator.validate(&original);
+        for (pass, diagnostics) in &mut previous.pass_diagnostics {
+            if *pass == "shadowing" {
+                *diagnostics = vec![marker.clone()];
+            }
+        }
+        let has_marker = |result: &ValidationResult| result.warnings.contains(&marker);
+
+        // ⟦Λ⟧ follows ⟦Γ⟧, so the rules and their line numbers are as before
+        let functions_edited = document("∀s:State→Valid(s)", "ident≜λx.x");
+        assert!(has_marker(&validator.validate_incremental(&functions_edited, &previous)));
+
+        let rules_edited = document("∀s:State→Ready(s)", "id≜λx.x");
+        assert!(!has_marker(&validator.validate_incremental(&rules_edited, &previous)));
+
+        // A line added before ⟦Γ⟧ moves the rules down
+        let shifted = original.replace("⟦Γ:Rules⟧", "\n⟦Γ:Rules⟧");
+        let incremental = validator.validate_incremental(&shifted, &previous);
+        assert_eq!(incremental.revalidated_blocks, ["Types"]);
+        assert!(!has_marker(&incremental));
+    }
+
+    #[test]
+    fn test_identical_document_and_missing_hashes() {
+        let validator = AispValidator::new();
+        let original = document("∀s:State→Valid(s)", "id≜λx.x");
+        let previous = validator.validate(&original);
+
+        let unchanged = validator.validate_incremental(&original, &previous);
+        assert!(unchanged.revalidated_blocks.is_empty());
+        assert_eq!(unchanged.block_hashes, previous.block_hashes);
+
+        // A previous result without block hashes forces a full run
+        let failed = ValidationResult::failed(crate::error::AispError::validation_error("failed"), 0);
+        let result = validator.validate_incremental(&original, &failed);
+        assert_eq!(result.revalidated_blocks, ["Header", "Meta", "Types", "Rules", "Functions", "Evidence"]);
+    }
+}
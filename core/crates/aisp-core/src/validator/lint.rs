@@ -0,0 +1,374 @@
+//! Document Lints
+//!
+//! Style findings that do not make a document invalid. Rules with a
+//! deterministic rewrite (type name case, block order and whitespace) are
+//! applied by [`AispValidator::lint_fix`]; the rest are only reported.
+
+use super::engine::AispValidator;
+use super::repair::CANONICAL_BLOCKS;
+use crate::parser::comments::extract_comments;
+use crate::parser::robust_parser::{AispParser, Rule};
+use crate::semantic_preservation::SemanticPreservationVerifier;
+use pest::iterators::Pair;
+use pest::Parser;
+use std::fmt;
+
+const INDENT: &str = "  ";
+
+/// A lint rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintRule {
+    /// Type names are PascalCase
+    TypeNameCase,
+    /// Blocks appear in canonical order
+    BlockOrder,
+    /// No trailing whitespace, tab indentation or runs of blank lines
+    Whitespace,
+    /// Every block has at least one entry
+    EmptyBlock,
+}
+
+impl LintRule {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LintRule::TypeNameCase => "type-name-case",
+            LintRule::BlockOrder => "block-order",
+            LintRule::Whitespace => "whitespace",
+            LintRule::EmptyBlock => "empty-block",
+        }
+    }
+
+    /// Whether [`AispValidator::lint_fix`] can resolve findings of this rule
+    pub fn is_fixable(&self) -> bool {
+        !matches!(self, LintRule::EmptyBlock)
+    }
+}
+
+/// A lint finding at a source line
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintFinding {
+    pub rule: LintRule,
+    pub line: usize,
+    pub message: String,
+}
+
+impl LintFinding {
+    fn new(rule: LintRule, line: usize, message: impl Into<String>) -> Self {
+        Self { rule, line, message: message.into() }
+    }
+}
+
+impl fmt::Display for LintFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: [{}] {}", self.line, self.rule.name(), self.message)
+    }
+}
+
+impl AispValidator {
+    /// Report lint findings in line order
+    ///
+    /// Only whitespace is checked in input the grammar rejects; validation
+    /// reports the parse error itself.
+    pub fn lint(source: &str) -> Vec<LintFinding> {
+        let mut findings = whitespace_findings(source);
+        let document = AispParser::parse(Rule::aisp_document, source).ok().and_then(|mut pairs| pairs.next());
+        if let Some(document) = document {
+            findings.extend(block_findings(document));
+        }
+        findings.sort_by_key(|finding| finding.line);
+        findings
+    }
+
+    /// Apply every fixable lint, returning the fixed source and the
+    /// findings it resolved
+    ///
+    /// Fixing is idempotent. A type is not renamed when its PascalCase name
+    /// is already taken, and the source is returned unchanged if the
+    /// semantic-preservation check rejects the result.
+    pub fn lint_fix(source: &str) -> (String, Vec<LintFinding>) {
+        let before = Self::lint(source);
+        if !before.iter().any(|finding| finding.rule.is_fixable()) {
+            return (source.to_string(), Vec::new());
+        }
+
+        let mut fixed = source.to_string();
+        for (from, to) in type_renames(source) {
+            if !identifiers(&fixed).any(|(_, name)| name == to) {
+                fixed = rename_identifier(&fixed, &from, &to);
+            }
+        }
+        fixed = Self::reorder_blocks(&fixed).0;
+        fixed = normalize_whitespace(&fixed);
+
+        let preserved = SemanticPreservationVerifier::new()
+            .verify_transformation_preservation(source, |_| Ok(fixed.clone()))
+            .unwrap_or(false);
+        if !preserved {
+            return (source.to_string(), Vec::new());
+        }
+
+        // Lines move when fixing, so match remaining findings by content
+        let after = Self::lint(&fixed);
+        let resolved = before
+            .into_iter()
+            .filter(|finding| {
+                finding.rule.is_fixable()
+                    && !after.iter().any(|left| left.rule == finding.rule && left.message == finding.message)
+            })
+            .collect();
+        (fixed, resolved)
+    }
+}
+
+/// Findings that need the parsed document: type names, order, empty blocks
+fn block_findings(document: Pair<Rule>) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+    let Some(blocks) = document.into_inner().find(|pair| pair.as_rule() == Rule::aisp_blocks) else {
+        return findings;
+    };
+
+    let mut order = Vec::new();
+    for block in blocks.into_inner() {
+        let Some(inner) = block.into_inner().next() else {
+            continue;
+        };
+        let line = inner.as_span().start_pos().line_col().0;
+        let header: String = inner.as_str().split_inclusive('⟧').next().unwrap_or_default().split_whitespace().collect();
+        if let Some(position) = CANONICAL_BLOCKS.iter().position(|(rule, _, _)| *rule == inner.as_rule()) {
+            order.push((position, line));
+        }
+
+        let Some(items) = inner.into_inner().find(|pair| {
+            matches!(
+                pair.as_rule(),
+                Rule::meta_entries
+                    | Rule::type_definitions
+                    | Rule::rule_definitions
+                    | Rule::function_definitions
+                    | Rule::error_definitions
+                    | Rule::evidence_entries
+            )
+        }) else {
+            continue;
+        };
+        if items.clone().into_inner().next().is_none() {
+            findings.push(LintFinding::new(LintRule::EmptyBlock, line, format!("{} block has no entries", header)));
+        }
+        if items.as_rule() != Rule::type_definitions {
+            continue;
+        }
+        for definition in items.into_inner() {
+            // The name's parameters, if it is generic, follow its identifier
+            let Some(identifier) = definition
+                .into_inner()
+                .find(|pair| pair.as_rule() == Rule::type_name)
+                .and_then(|name| name.into_inner().next())
+            else {
+                continue;
+            };
+            if let Some(pascal) = pascal_case(identifier.as_str()) {
+                findings.push(LintFinding::new(
+                    LintRule::TypeNameCase,
+                    identifier.as_span().start_pos().line_col().0,
+                    format!("Type `{}` should be PascalCase (`{}`)", identifier.as_str(), pascal),
+                ));
+            }
+        }
+    }
+
+    if let Some(window) = order.windows(2).find(|pair| pair[1].0 < pair[0].0) {
+        let names: Vec<&str> = order.iter().map(|&(position, _)| CANONICAL_BLOCKS[position].1).collect();
+        findings.push(LintFinding::new(
+            LintRule::BlockOrder,
+            window[1].1,
+            format!("Blocks are not in canonical order: {}", names.join(", ")),
+        ));
+    }
+    findings
+}
+
+/// PascalCase spelling of a type name, if it is not already PascalCase
+///
+/// Only plain `[A-Za-z0-9_]` names are considered.
+fn pascal_case(name: &str) -> Option<String> {
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic())
+        || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return None;
+    }
+    let pascal: String = name
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map(|first| first.to_ascii_uppercase().to_string() + chars.as_str()).unwrap_or_default()
+        })
+        .collect();
+    (pascal != name).then_some(pascal)
+}
+
+fn type_renames(source: &str) -> Vec<(String, String)> {
+    let Some(document) = AispParser::parse(Rule::aisp_document, source).ok().and_then(|mut pairs| pairs.next()) else {
+        return Vec::new();
+    };
+    block_findings(document)
+        .into_iter()
+        .filter(|finding| finding.rule == LintRule::TypeNameCase)
+        .filter_map(|finding| {
+            let mut names = finding.message.split('`').skip(1).step_by(2);
+            Some((names.next()?.to_string(), names.next()?.to_string()))
+        })
+        .collect()
+}
+
+/// Identifier occurrences outside comments and string literals
+pub(super) fn identifiers(source: &str) -> impl Iterator<Item = (usize, &str)> {
+    let comments = extract_comments(source);
+    let mut occurrences = Vec::new();
+    let mut next_comment = comments.iter().peekable();
+    let mut in_string = false;
+    let mut start: Option<usize> = None;
+
+    for (index, c) in source.char_indices().chain(std::iter::once((source.len(), ' '))) {
+        while next_comment.peek().is_some_and(|comment| comment.span.end <= index) {
+            next_comment.next();
+        }
+        let in_comment = next_comment.peek().is_some_and(|comment| comment.span.start <= index);
+        let word = !in_comment && !in_string && (c.is_alphanumeric() || c == '_');
+
+        match (start, word) {
+            (None, true) => start = Some(index),
+            (Some(begin), false) => {
+                occurrences.push((begin, &source[begin..index]));
+                start = None;
+            }
+            _ => {}
+        }
+        if !in_comment && c == '"' {
+            in_string = !in_string;
+        }
+    }
+    occurrences.into_iter()
+}
+
+/// Rename whole-word occurrences of an identifier outside comments and strings
+fn rename_identifier(source: &str, from: &str, to: &str) -> String {
+    let mut renamed = String::with_capacity(source.len());
+    let mut cursor = 0;
+    for (start, name) in identifiers(source).filter(|&(_, name)| name == from) {
+        renamed.push_str(&source[cursor..start]);
+        renamed.push_str(to);
+        cursor = start + name.len();
+    }
+    renamed.push_str(&source[cursor..]);
+    renamed
+}
+
+fn whitespace_findings(source: &str) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+    let lines: Vec<&str> = source.split('\n').collect();
+    let terminated = lines.last() == Some(&"");
+    let body = if terminated { &lines[..lines.len() - 1] } else { &lines[..] };
+    let content_end = body.iter().rposition(|line| !line.trim().is_empty()).map_or(0, |index| index + 1);
+
+    let mut previous_blank = false;
+    for (index, line) in body[..content_end].iter().enumerate() {
+        let number = index + 1;
+        let trimmed = line.trim_end_matches([' ', '\t', '\r']);
+        if trimmed.len() != line.len() {
+            findings.push(LintFinding::new(LintRule::Whitespace, number, "Trailing whitespace"));
+        }
+        let content = trimmed.trim_start_matches([' ', '\t']);
+        if trimmed[..trimmed.len() - content.len()].contains('\t') {
+            findings.push(LintFinding::new(LintRule::Whitespace, number, "Tab indentation"));
+        }
+        let blank = trimmed.is_empty();
+        if blank && previous_blank {
+            findings.push(LintFinding::new(LintRule::Whitespace, number, "Multiple blank lines"));
+        }
+        previous_blank = blank;
+    }
+
+    if content_end < body.len() {
+        findings.push(LintFinding::new(LintRule::Whitespace, content_end + 1, "Blank lines at end of file"));
+    } else if !terminated && content_end > 0 {
+        findings.push(LintFinding::new(LintRule::Whitespace, content_end, "Missing newline at end of file"));
+    }
+    findings
+}
+
+/// Trim trailing whitespace, indent with spaces, collapse blank lines and
+/// end with exactly one newline
+fn normalize_whitespace(source: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    for line in source.split('\n') {
+        let trimmed = line.trim_end_matches([' ', '\t', '\r']);
+        let content = trimmed.trim_start_matches([' ', '\t']);
+        let indent = trimmed[..trimmed.len() - content.len()].replace('\t', INDENT);
+        if content.is_empty() && lines.last().is_some_and(|previous| previous.is_empty()) {
+            continue;
+        }
+        lines.push(indent + content);
+    }
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNTIDY: &str = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Γ:Rules⟧{\n\t∀s:task_state→Valid(s)   \n}\n\n\n\
+                          ⟦Σ:Types⟧{\n  task_state≜{Idle,Busy} # task_state is kept in comments\n}\n\n\
+                          ⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+
+    fn rules(findings: &[LintFinding]) -> Vec<LintRule> {
+        findings.iter().map(|finding| finding.rule).collect()
+    }
+
+    #[test]
+    fn test_lint_reports_style_findings() {
+        let findings = AispValidator::lint(UNTIDY);
+
+        assert!(rules(&findings).contains(&LintRule::TypeNameCase));
+        assert!(rules(&findings).contains(&LintRule::BlockOrder));
+        assert_eq!(rules(&findings).iter().filter(|&&rule| rule == LintRule::Whitespace).count(), 4);
+        let case = findings.iter().find(|finding| finding.rule == LintRule::TypeNameCase).unwrap();
+        assert_eq!(case.to_string(), "11: [type-name-case] Type `task_state` should be PascalCase (`TaskState`)");
+    }
+
+    #[test]
+    fn test_lint_fix_resolves_fixable_findings_idempotently() {
+        let (fixed, resolved) = AispValidator::lint_fix(UNTIDY);
+
+        assert_eq!(resolved.len(), AispValidator::lint(UNTIDY).len());
+        assert!(AispValidator::lint(&fixed).is_empty(), "{:?}", AispValidator::lint(&fixed));
+        assert!(fixed.contains("  TaskState≜{Idle,Busy} # task_state is kept in comments\n"));
+        assert!(fixed.contains("  ∀s:TaskState→Valid(s)\n"));
+        assert!(fixed.find("⟦Σ").unwrap() < fixed.find("⟦Γ").unwrap());
+        assert!(AispValidator::new().validate(&fixed).valid);
+        assert_eq!(AispValidator::lint_fix(&fixed), (fixed.clone(), vec![]));
+    }
+
+    #[test]
+    fn test_unfixable_findings_remain_after_fix() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}  \n\n⟦Σ:Types⟧{}\n\n⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩\n";
+
+        let (fixed, resolved) = AispValidator::lint_fix(source);
+
+        assert_eq!(rules(&resolved), vec![LintRule::Whitespace]);
+        let remaining = AispValidator::lint(&fixed);
+        assert_eq!(rules(&remaining), vec![LintRule::EmptyBlock]);
+        assert_eq!(remaining[0].message, "⟦Σ:Types⟧ block has no entries");
+    }
+
+    #[test]
+    fn test_type_rename_skips_taken_names() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle}\n  state≜{Busy}\n}\n";
+        let (fixed, _) = AispValidator::lint_fix(source);
+        assert!(fixed.contains("  state≜{Busy}"));
+        assert!(rules(&AispValidator::lint(&fixed)).contains(&LintRule::TypeNameCase));
+    }
+}
@@ -8,18 +8,68 @@
 //! - `verification_methods`: Individual verification method implementations  
 //! - `structural_validator`: Document structure and required block validation
 //! - `engine`: Main validation orchestration engine
+//! - `repair`: Structural auto-fix for block order and missing blocks
+//! - `remediation`: Suggested fixes attached to diagnostics, and applying their patches
+//! - `lint`: Style lints, with fixes for the deterministic ones
+//! - `ordering`: Definition-before-use checks across blocks
+//! - `shadowing`: Bound variables rebound by nested quantifiers or lambdas
+//! - `redundancy`: Rules equivalent to an earlier rule up to bound variable names
+//! - `undefined_calls`: Calls to functions the document never defines
+//! - `broad_quantifiers`: Quantifiers over unbounded or trivially bounded domains
+//! - `numeric_bounds`: Comparisons with constants outside a ranged type such as `Byte`
+//! - `tier_claim`: Declared evidence tiers above the computed tier
+//! - `tier_explanation`: Why a document has its density tier, and how to reach the next
+//! - `directory`: Validation of every supported file under a directory, honoring `.aispignore`
+//! - `profile`: Named language subsets a document is validated against
+//! - `badge`: Quality fingerprints and SVG badges for validation results
+//! - `proof_coverage`: Fraction of rules the theorem prover proves
+//! - `property_verification`: Per-property theorem prover outcomes
+//! - `incremental`: Revalidation reusing results for unchanged blocks
+//! - `capabilities`: Which analysis stages a validation ran
 
 // Re-export public types and main API
-pub use self::types::{ValidationConfig, ValidationResult};
+pub use self::types::{StageProfile, SymbolStats, ValidationConfig, ValidationResult};
 pub use self::engine::AispValidator;
 pub use self::verification_methods::VerificationMethods;
 pub use self::structural_validator::{StructuralValidator, StructuralValidationConfig, StructuralValidationResult};
+pub use self::repair::FixApplied;
+pub use self::lint::{LintFinding, LintRule};
+pub use self::ordering::{ForwardReference, MetaKeyOrderDeviation};
+pub use self::shadowing::ShadowedVariable;
+pub use self::redundancy::RedundantRule;
+pub use self::undefined_calls::UndefinedFunction;
+pub use self::broad_quantifiers::BroadQuantifier;
+pub use self::numeric_bounds::OutOfRangeBound;
+pub use self::tier_claim::TierOverclaim;
+pub use self::tier_explanation::{BlockDensity, TierExplanation};
+pub use self::directory::IgnorePatterns;
+pub use self::profile::{Profile, ProfileViolation};
+pub use self::property_verification::{PropertyOutcome, PropertyStatus};
+pub use self::capabilities::{CapabilityReport, StageCapability, StageStatus};
 
 // Module declarations
 pub mod types;
 pub mod verification_methods;
 pub mod structural_validator;
 pub mod engine;
+pub mod repair;
+pub mod remediation;
+pub mod lint;
+pub mod ordering;
+pub mod shadowing;
+pub mod redundancy;
+pub mod undefined_calls;
+pub mod broad_quantifiers;
+pub mod numeric_bounds;
+pub mod tier_claim;
+pub mod tier_explanation;
+pub mod directory;
+pub mod profile;
+pub mod badge;
+pub mod proof_coverage;
+pub mod property_verification;
+pub mod incremental;
+pub mod capabilities;
 
 // Convenience re-exports for backward compatibility
 pub use engine::AispValidator as Validator;
@@ -0,0 +1,256 @@
+//! Numeric Bounds Outside a Type's Range
+//!
+//! Some type names carry a range by convention: a `Byte≜ℕ` holds 0..255,
+//! so a rule `∀b:Byte→b≤256` has an off-by-one or overflow smell. When a
+//! rule compares a variable of such a type with a constant outside its
+//! range, the engine reports the comparison as a warning. A value declared
+//! in ⟦Ε⟧ for a metric named after such a type, as `Percent≜120`, is
+//! checked against the same range.
+
+use super::engine::AispValidator;
+use super::shadowing::span_of;
+use crate::ast::canonical::Span;
+use crate::parser::robust_parser::{Pair, Rule};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Type names with the range their values conventionally take, as
+/// (name, lowest, highest)
+pub const IMPLIED_RANGES: &[(&str, f64, f64)] = &[
+    ("Bit", 0.0, 1.0),
+    ("Byte", 0.0, 255.0),
+    ("Octet", 0.0, 255.0),
+    ("U8", 0.0, 255.0),
+    ("U16", 0.0, 65535.0),
+    ("Word", 0.0, 65535.0),
+    ("U32", 0.0, 4294967295.0),
+    ("Percent", 0.0, 100.0),
+    ("Percentage", 0.0, 100.0),
+    ("Probability", 0.0, 1.0),
+    ("Hour", 0.0, 23.0),
+    ("Minute", 0.0, 59.0),
+    ("Second", 0.0, 59.0),
+];
+
+/// Numeric types a ranged type name may be declared as, with whether
+/// their values are integers
+const NUMERIC_BASES: &[(&str, bool)] =
+    &[("ℕ", true), ("Natural", true), ("ℤ", true), ("ℚ", false), ("ℝ", false)];
+
+/// A comparison of a ranged variable with a constant outside its range,
+/// or an evidence value outside the range of the metric's type
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutOfRangeBound {
+    /// The comparison or evidence entry as written
+    pub comparison: String,
+    /// The declared type of the compared variable
+    pub type_name: String,
+    /// Lowest and highest value of the type
+    pub range: (f64, f64),
+    /// The comparison or evidence entry in the source
+    pub span: Span,
+}
+
+impl fmt::Display for OutOfRangeBound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` at {}:{} lies outside the range {}..{} of `{}`",
+            self.comparison, self.span.line, self.span.column, self.range.0, self.range.1, self.type_name
+        )
+    }
+}
+
+impl AispValidator {
+    /// Report comparisons in the document's parse tree between a variable
+    /// of a ranged type and a constant outside that range, and evidence
+    /// values outside the range of the type their metric is named after,
+    /// in source order
+    ///
+    /// A type is ranged when its name appears in [`IMPLIED_RANGES`] and
+    /// ⟦Σ⟧ declares it as a numeric type. For integer types a strict bound
+    /// one past the range, as in `b<256` for a `Byte`, is exact and not
+    /// reported; neither is `≠`, which no value out of range can violate.
+    pub fn out_of_range_bounds(document: &Pair<'_, Rule>) -> Vec<OutOfRangeBound> {
+        let source = document.get_input();
+        let document = document.clone();
+
+        // Ranged types declared in the document, with their integrality
+        let mut ranged: HashMap<&str, (f64, f64, bool)> = HashMap::new();
+        for definition in document.clone().into_inner().flatten().filter(|pair| pair.as_rule() == Rule::type_definition) {
+            let mut inner = definition.into_inner();
+            let (Some(name), Some(base)) = (inner.next(), inner.next()) else {
+                continue;
+            };
+            let name = name.as_str().trim();
+            let base = NUMERIC_BASES.iter().find(|(base_name, _)| *base_name == base.as_str().trim());
+            let range = IMPLIED_RANGES.iter().find(|(range_name, _, _)| *range_name == name);
+            if let (Some(&(_, integral)), Some(&(_, low, high))) = (base, range) {
+                ranged.insert(name, (low, high, integral));
+            }
+        }
+
+        let mut out_of_range = Vec::new();
+        for pair in document.into_inner().flatten() {
+            match pair.as_rule() {
+                Rule::quantified_expr => ranged_comparisons(pair, source, &ranged, &mut out_of_range),
+                Rule::evidence_entry => {
+                    let mut inner = pair.clone().into_inner();
+                    let (Some(metric), Some(value)) = (inner.next(), inner.next()) else {
+                        continue;
+                    };
+                    let type_name = metric.as_str().trim();
+                    let (Some(&(low, high, _)), Some(bound)) = (ranged.get(type_name), numeric_literal(value.as_str().trim()))
+                    else {
+                        continue;
+                    };
+                    if bound < low || bound > high {
+                        let mut span = span_of(&pair);
+                        span.end = value.as_span().start() + value.as_str().trim().len();
+                        out_of_range.push(OutOfRangeBound {
+                            comparison: source[span.start..span.end].to_string(),
+                            type_name: type_name.to_string(),
+                            range: (low, high),
+                            span,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+        out_of_range
+    }
+}
+
+/// Report the comparisons in `quantifier`'s body that bound its variable,
+/// when of a ranged type, outside the type's range
+fn ranged_comparisons(
+    quantifier: Pair<'_, Rule>,
+    source: &str,
+    ranged: &HashMap<&str, (f64, f64, bool)>,
+    out_of_range: &mut Vec<OutOfRangeBound>,
+) {
+
+    // quantifier, bound variable, domain, body
+    let mut inner = quantifier.into_inner();
+    let (Some(_), Some(variable), Some(domain), Some(body)) = (inner.next(), inner.next(), inner.next(), inner.next())
+    else {
+        return;
+    };
+    let type_name = domain.as_str().trim();
+    let Some(&(low, high, integral)) = ranged.get(type_name) else {
+        return;
+    };
+    let variable = variable.as_str().trim();
+
+    for comparison in body.into_inner().flatten().filter(|pair| pair.as_rule() == Rule::comparison_expr) {
+        let operands: Vec<_> = comparison.into_inner().collect();
+        for triple in operands.windows(3).step_by(2) {
+            let (lhs, op, rhs) = (&triple[0], triple[1].as_str().trim(), &triple[2]);
+            let (left, right) = (lhs.as_str().trim(), rhs.as_str().trim());
+            // Read `n op b` as `b op' n`
+            let (bound, op) = match (left == variable, right == variable) {
+                (true, false) => (right, op),
+                (false, true) => (left, mirror(op)),
+                _ => continue,
+            };
+            let Some(bound) = numeric_literal(bound) else {
+                continue;
+            };
+            if exceeds_range(op, bound, low, high, integral) {
+                let mut span = span_of(lhs);
+                span.end = rhs.as_span().start() + right.len();
+                out_of_range.push(OutOfRangeBound {
+                    comparison: source[span.start..span.end].to_string(),
+                    type_name: type_name.to_string(),
+                    range: (low, high),
+                    span,
+                });
+            }
+        }
+    }
+}
+
+/// The operator with its operands swapped
+fn mirror(op: &str) -> &str {
+    match op {
+        "<" => ">",
+        ">" => "<",
+        "≤" => "≥",
+        "≥" => "≤",
+        other => other,
+    }
+}
+
+/// The value of an operand written as a plain number
+fn numeric_literal(operand: &str) -> Option<f64> {
+    operand.chars().all(|ch| ch.is_ascii_digit() || ch == '.').then(|| operand.parse().ok()).flatten()
+}
+
+/// Whether `variable op bound` names a value outside `low..=high`
+fn exceeds_range(op: &str, bound: f64, low: f64, high: f64, integral: bool) -> bool {
+    let step = if integral { 1.0 } else { 0.0 };
+    match op {
+        "<" => bound > high + step,
+        ">" => bound < low - step,
+        "=" | "≤" | "≥" | "≡" => bound < low || bound > high,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(rules: &str) -> String {
+        with_evidence(rules, "δ≜0.5")
+    }
+
+    fn with_evidence(rules: &str, evidence: &str) -> String {
+        format!(
+            "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{{domain≜x}}\n\n⟦Σ:Types⟧{{\n  Byte≜ℕ\n  Percent≜ℕ\n}}\n\n\
+             ⟦Γ:Rules⟧{{\n  {}\n}}\n\n⟦Λ:Funcs⟧{{id≜λx.x}}\n\n⟦Ε⟧⟨{}⟩",
+            rules, evidence
+        )
+    }
+
+    fn bounds(source: &str) -> Vec<OutOfRangeBound> {
+        AispValidator::out_of_range_bounds(&AispValidator::parse_tree(source).expect("document parses"))
+    }
+
+    #[test]
+    fn test_bound_past_type_range_is_warned() {
+        let source = document("∀b:Byte→b≤256");
+
+        let bounds = bounds(&source);
+        assert_eq!(bounds.len(), 1);
+        assert_eq!(bounds[0].type_name, "Byte");
+        assert_eq!(bounds[0].range, (0.0, 255.0));
+        assert_eq!(&source[bounds[0].span.start..bounds[0].span.end], "b≤256");
+
+        let result = AispValidator::new().validate(&source);
+        assert!(result.warnings.iter().any(|warning| warning
+            .to_string()
+            .contains("Bound outside type range: `b≤256` at 11:11 lies outside the range 0..255 of `Byte`")));
+    }
+
+    #[test]
+    fn test_bounds_within_type_range_are_silent() {
+        for rules in ["∀b:Byte→b≤255", "∀b:Byte→b<256", "∀b:Byte→255≥b", "∀b:ℕ→b≤256", "∀b:Byte→b≠256"] {
+            assert_eq!(bounds(&document(rules)), vec![], "{}", rules);
+        }
+        assert_eq!(bounds(&document("∀b:Byte→256≥b")).len(), 1);
+    }
+
+    #[test]
+    fn test_evidence_value_past_type_range_is_warned() {
+        let source = with_evidence("∀b:Byte→b≤255", "δ≜0.5;Percent≜120");
+
+        let found = bounds(&source);
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].comparison.as_str(), found[0].type_name.as_str()), ("Percent≜120", "Percent"));
+        assert_eq!(found[0].range, (0.0, 100.0));
+
+        assert_eq!(bounds(&with_evidence("∀b:Byte→b≤255", "δ≜0.5;Percent≜100")), vec![]);
+    }
+}
@@ -0,0 +1,300 @@
+//! Definition-Before-Use Ordering
+//!
+//! Types (Σ) are expected before the rules (Γ) and functions (Λ) that use
+//! them, and every type or function before any item in its own block that
+//! refers to it. A rule may call a function defined later, since the
+//! canonical block order puts ⟦Λ⟧ after ⟦Γ⟧. A forward reference is still
+//! meaningful, so the engine reports it as a warning, or as an error in
+//! strict mode.
+//!
+//! Teams may also fix a canonical order for meta keys; meta blocks that
+//! deviate from it are reported as warnings.
+
+use super::engine::AispValidator;
+use super::lint::identifiers;
+use crate::ast::canonical::{CanonicalAispBlock, CanonicalAispDocument};
+use crate::parser::robust_parser::{Pair, Rule};
+use std::collections::HashMap;
+use std::fmt;
+
+/// A type or function used before the item that defines it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForwardReference {
+    /// Name of the type or function
+    pub name: String,
+    /// Line of the use
+    pub line: usize,
+    /// Line of the definition
+    pub defined_at: usize,
+}
+
+impl fmt::Display for ForwardReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is used on line {} before its definition on line {}",
+            self.name, self.line, self.defined_at
+        )
+    }
+}
+
+/// A meta block whose keys deviate from the canonical order
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetaKeyOrderDeviation {
+    /// Keys in source order
+    pub found: Vec<String>,
+    /// The same keys in canonical order
+    pub expected: Vec<String>,
+    /// Line of the first key out of place, when known
+    pub line: Option<usize>,
+}
+
+impl fmt::Display for MetaKeyOrderDeviation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "found {}, canonical order is {}",
+            self.found.join(", "),
+            self.expected.join(", ")
+        )
+    }
+}
+
+impl AispValidator {
+    /// Report meta blocks whose keys are not in the canonical `order`
+    ///
+    /// Listed keys must come first, in the order given; unlisted keys may
+    /// follow in any order. An empty order accepts every block.
+    pub fn meta_key_order_deviations(document: &CanonicalAispDocument, order: &[String]) -> Vec<MetaKeyOrderDeviation> {
+        if order.is_empty() {
+            return Vec::new();
+        }
+
+        let mut deviations = Vec::new();
+        for block in &document.blocks {
+            let CanonicalAispBlock::Meta(meta) = block else {
+                continue;
+            };
+            let expected = meta.canonical_keys(order);
+            let Some(misplaced) = meta.keys.iter().zip(&expected).position(|(found, expected)| found != expected) else {
+                continue;
+            };
+            deviations.push(MetaKeyOrderDeviation {
+                line: meta.entries[&meta.keys[misplaced]].span.as_ref().map(|span| span.line),
+                found: meta.keys.clone(),
+                expected,
+            });
+        }
+        deviations
+    }
+
+    /// Report uses of types and functions that precede their definitions
+    /// in the document's parse tree, in source order
+    ///
+    /// A use in one block of a name defined further down in a block that
+    /// comes later in the canonical order, such as a rule calling a
+    /// function, is not a forward reference.
+    pub fn forward_references(document: &Pair<'_, Rule>) -> Vec<ForwardReference> {
+        let source = document.get_input();
+        let document = document.clone();
+
+        // First definition of each name with its block's canonical rank,
+        // and the source ranges that may use one; blocks precede their
+        // contents in the flattened tree
+        let mut definitions: HashMap<&str, (usize, usize)> = HashMap::new();
+        let mut bodies = Vec::new();
+        let mut rank = 0;
+        for pair in document.into_inner().flatten() {
+            if let Some(block_rank) = canonical_rank(pair.as_rule()) {
+                rank = block_rank;
+                continue;
+            }
+            match pair.as_rule() {
+                Rule::type_definition | Rule::function_definition => {
+                    let span = pair.as_span();
+                    let Some(name) = pair
+                        .into_inner()
+                        .find(|inner| matches!(inner.as_rule(), Rule::identifier | Rule::type_name))
+                    else {
+                        continue;
+                    };
+                    // A generic type's parameters are part of its name, not uses
+                    let body_start = name.as_span().end();
+                    let name = name.clone().into_inner().next().unwrap_or(name);
+                    definitions.entry(name.as_str()).or_insert((span.start(), rank));
+                    bodies.push((body_start, span.end(), rank));
+                }
+                Rule::rule_definition => {
+                    let span = pair.as_span();
+                    bodies.push((span.start(), span.end(), rank));
+                }
+                _ => {}
+            }
+        }
+
+        let mut references = Vec::new();
+        for (start, end, rank) in bodies {
+            for (offset, name) in identifiers(&source[start..end]) {
+                let position = start + offset;
+                match definitions.get(name) {
+                    Some(&(defined, defined_rank)) if defined > position && defined_rank <= rank => references.push(ForwardReference {
+                        name: name.to_string(),
+                        line: line_of(source, position),
+                        defined_at: line_of(source, defined),
+                    }),
+                    _ => {}
+                }
+            }
+        }
+        references.sort_by_key(|reference| reference.line);
+        references
+    }
+}
+
+/// Position of a block rule in the canonical Ω Σ Γ Λ Χ Ε order
+fn canonical_rank(rule: Rule) -> Option<usize> {
+    [
+        Rule::omega_block,
+        Rule::sigma_block,
+        Rule::gamma_block,
+        Rule::lambda_block,
+        Rule::chi_block,
+        Rule::epsilon_block,
+    ]
+    .iter()
+    .position(|block| *block == rule)
+}
+
+fn line_of(source: &str, offset: usize) -> usize {
+    source[..offset].matches('\n').count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(source: &str) -> Pair<'_, Rule> {
+        AispValidator::parse_tree(source).expect("document parses")
+    }
+    use crate::validator::ValidationConfig;
+
+    const ORDERED: &str = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy};\n  Task≜State\n}\n\n\
+                           ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+
+    const FORWARD: &str = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  Task≜State;\n  State≜{Idle,Busy}\n}\n\n\
+                           ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+
+    fn forward_warnings(source: &str, config: ValidationConfig) -> (bool, Vec<String>) {
+        let result = AispValidator::with_config(config).validate(source);
+        let warnings = result
+            .warnings
+            .iter()
+            .map(|warning| warning.to_string())
+            .filter(|warning| warning.contains("Forward reference"))
+            .collect();
+        (result.valid, warnings)
+    }
+
+    fn meta_document(entries: &str) -> String {
+        ORDERED.replace("⟦Ω:Meta⟧{domain≜x}", &format!("⟦Ω:Meta⟧{{\n  {}\n}}", entries))
+    }
+
+    fn meta_order_warnings(source: &str) -> Vec<String> {
+        let config = ValidationConfig {
+            meta_key_order: ["Vision", "Author", "Version", "Date"].map(String::from).to_vec(),
+            ..ValidationConfig::default()
+        };
+        let result = AispValidator::with_config(config).validate(source);
+        assert!(result.valid, "{:?}", result.error);
+        result
+            .warnings
+            .iter()
+            .map(|warning| warning.to_string())
+            .filter(|warning| warning.contains("Meta key order"))
+            .collect()
+    }
+
+    #[test]
+    fn test_meta_keys_in_canonical_order_are_silent() {
+        let source = meta_document("Vision≜\"plan\";\n  Author≜ops;\n  Date≜today;\n  domain≜x");
+        assert!(meta_order_warnings(&source).is_empty());
+    }
+
+    #[test]
+    fn test_meta_keys_out_of_order_are_warned() {
+        let source = meta_document("Vision≜\"plan\";\n  Date≜today;\n  Author≜ops;\n  domain≜x");
+
+        let warnings = meta_order_warnings(&source);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("found Vision, Date, Author, domain, canonical order is Vision, Author, Date, domain"));
+        assert!(warnings[0].contains("line 5"), "{}", warnings[0]);
+    }
+
+    #[test]
+    fn test_correct_ordering_is_silent() {
+        assert_eq!(AispValidator::forward_references(&tree(ORDERED)), vec![]);
+
+        let (valid, warnings) = forward_warnings(ORDERED, ValidationConfig::default());
+        assert!(valid);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_forward_reference_is_warned() {
+        assert_eq!(
+            AispValidator::forward_references(&tree(FORWARD)),
+            vec![ForwardReference { name: "State".to_string(), line: 6, defined_at: 7 }]
+        );
+
+        let (valid, warnings) = forward_warnings(FORWARD, ValidationConfig::default());
+        assert!(valid);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("`State` is used on line 6"));
+    }
+
+    #[test]
+    fn test_forward_reference_fails_strict_mode() {
+        let mut config = ValidationConfig::default();
+        config.strict_mode = true;
+        config.strict_formal_verification = false;
+
+        let result = AispValidator::with_config(config.clone()).validate(FORWARD);
+        assert!(!result.valid);
+        assert!(result.error.unwrap().to_string().contains("Forward reference"));
+
+        config.strict_definition_order = false;
+        let result = AispValidator::with_config(config).validate(FORWARD);
+        assert!(!result.error.is_some_and(|error| error.to_string().contains("Forward reference")));
+    }
+
+    #[test]
+    fn test_rule_calling_a_later_function_is_silent() {
+        let source = ORDERED.replace("Valid(s)", "id(s)");
+        assert_eq!(AispValidator::forward_references(&tree(&source)), vec![]);
+
+        let mut config = ValidationConfig::default();
+        config.strict_mode = true;
+        let (valid, warnings) = forward_warnings(&source, config);
+        assert!(valid);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_function_before_types_block_is_a_forward_reference() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Λ:Funcs⟧{\n  f≜λx.State\n}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n";
+        let references = AispValidator::forward_references(&tree(source));
+        assert_eq!(references.len(), 1, "{:?}", references);
+        assert_eq!((references[0].name.as_str(), references[0].line), ("State", 4));
+    }
+
+    #[test]
+    fn test_rule_before_types_block_is_a_forward_reference() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n";
+
+        let references = AispValidator::forward_references(&tree(source));
+
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].name, "State");
+        assert_eq!((references[0].line, references[0].defined_at), (4, 8));
+    }
+}
@@ -0,0 +1,234 @@
+//! Validation Profiles
+//!
+//! A profile names the subset of AISP a deployment accepts: which symbol
+//! categories a document may use, whether functions may be higher-order,
+//! and which optional checks run. `full` accepts the whole language;
+//! `minimal` is the embedded subset without set, relational or temporal
+//! operators and without higher-order functions.
+
+use super::engine::AispValidator;
+use super::lint::identifiers;
+use crate::ast::canonical::{CanonicalAispBlock as AispBlock, CanonicalAispDocument as AispDocument};
+use crate::parser::comments::strip_comments;
+use crate::symbols::{lookup_symbol, SymbolCategory};
+use std::fmt;
+
+/// Named set of language features and checks a document is validated against
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    /// Profile name reported in violations
+    pub name: String,
+    /// Symbol categories documents may use; `None` allows every category
+    pub allowed_categories: Option<Vec<SymbolCategory>>,
+    /// Allow functions that return functions or apply a parameter
+    pub allow_higher_order_functions: bool,
+    /// Report uses of types and functions before their definitions
+    pub check_definition_order: bool,
+    /// Report lambda type errors found by type inference
+    pub check_function_types: bool,
+}
+
+impl Profile {
+    /// The whole language with every check enabled
+    pub fn full() -> Self {
+        Self {
+            name: "full".to_string(),
+            allowed_categories: None,
+            allow_higher_order_functions: true,
+            check_definition_order: true,
+            check_function_types: true,
+        }
+    }
+
+    /// Blocks, definitions, quantifiers, logic, basic types and first-order
+    /// functions only
+    pub fn minimal() -> Self {
+        Self {
+            name: "minimal".to_string(),
+            allowed_categories: Some(vec![
+                SymbolCategory::BlockDelimiter,
+                SymbolCategory::Definition,
+                SymbolCategory::Quantifier,
+                SymbolCategory::Lambda,
+                SymbolCategory::Logic,
+                SymbolCategory::Type,
+                SymbolCategory::Document,
+                SymbolCategory::Tier,
+                SymbolCategory::Tuple,
+                SymbolCategory::Greek,
+            ]),
+            allow_higher_order_functions: false,
+            check_definition_order: true,
+            check_function_types: true,
+        }
+    }
+
+    /// Look up a built-in profile by name
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "full" => Some(Self::full()),
+            "minimal" => Some(Self::minimal()),
+            _ => None,
+        }
+    }
+
+    fn allows(&self, category: &SymbolCategory) -> bool {
+        self.allowed_categories.as_ref().is_none_or(|allowed| allowed.contains(category))
+    }
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self::full()
+    }
+}
+
+/// A document feature the active profile does not allow
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProfileViolation {
+    /// A symbol from a category outside the profile, at its first use
+    ForbiddenSymbol {
+        symbol: char,
+        category: SymbolCategory,
+        line: usize,
+    },
+    /// A function that returns a function or applies a parameter
+    HigherOrderFunction { name: String },
+}
+
+impl fmt::Display for ProfileViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileViolation::ForbiddenSymbol { symbol, category, line } => {
+                write!(f, "`{}` ({:?}) on line {} is not allowed", symbol, category, line)
+            }
+            ProfileViolation::HigherOrderFunction { name } => {
+                write!(f, "Higher-order function `{}` is not allowed", name)
+            }
+        }
+    }
+}
+
+impl AispValidator {
+    /// Report features of a parsed document that `profile` does not allow,
+    /// symbols first in source order
+    ///
+    /// Only non-ASCII glyphs outside comments count as symbols, so
+    /// identifiers such as `X` or `U` are not read as temporal operators.
+    pub fn profile_violations(profile: &Profile, source: &str, document: &AispDocument) -> Vec<ProfileViolation> {
+        let mut violations = Vec::new();
+        let mut seen = Vec::new();
+        for (index, line) in strip_comments(source).lines().enumerate() {
+            for c in line.chars().filter(|c| !c.is_ascii()) {
+                let Some(symbol) = lookup_symbol(c) else {
+                    continue;
+                };
+                if !profile.allows(&symbol.category) && !seen.contains(&c) {
+                    seen.push(c);
+                    violations.push(ProfileViolation::ForbiddenSymbol {
+                        symbol: c,
+                        category: symbol.category.clone(),
+                        line: index + 1,
+                    });
+                }
+            }
+        }
+
+        if !profile.allow_higher_order_functions {
+            for block in &document.blocks {
+                if let AispBlock::Functions(functions) = block {
+                    violations.extend(
+                        functions
+                            .functions
+                            .iter()
+                            .filter(|function| is_higher_order(&function.raw_text))
+                            .map(|function| ProfileViolation::HigherOrderFunction { name: function.name.clone() }),
+                    );
+                }
+            }
+        }
+        violations
+    }
+}
+
+/// Whether a `name≜λx.body` definition returns a lambda or applies one of
+/// its parameters
+fn is_higher_order(definition: &str) -> bool {
+    let lambda = definition.split_once('≜').map_or(definition, |(_, lambda)| lambda);
+    let Some((parameters, body)) = lambda.trim_start().strip_prefix('λ').and_then(|rest| rest.split_once('.')) else {
+        return false;
+    };
+    let parameters: Vec<&str> = identifiers(parameters).map(|(_, name)| name).collect();
+
+    body.contains('λ')
+        || identifiers(body)
+            .any(|(offset, name)| parameters.contains(&name) && body[offset + name.len()..].starts_with('('))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::ValidationConfig;
+
+    const SET_RULES: &str = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                             ⟦Γ:Rules⟧{\n  ∀s:State→s∈State\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+
+    fn validate(source: &str, profile: Profile) -> crate::validator::ValidationResult {
+        let mut config = ValidationConfig::default();
+        config.profile = profile;
+        AispValidator::with_config(config).validate(source)
+    }
+
+    #[test]
+    fn test_forbidden_symbol_fails_minimal_but_passes_full() {
+        let result = validate(SET_RULES, Profile::full());
+        assert!(result.valid, "{:?}", result.error);
+
+        let result = validate(SET_RULES, Profile::minimal());
+        assert!(!result.valid);
+        assert_eq!(
+            result.error.unwrap().to_string(),
+            "Validation error: Profile `minimal`: `∈` (Set) on line 10 is not allowed"
+        );
+    }
+
+    #[test]
+    fn test_minimal_accepts_documents_within_the_subset() {
+        let source = SET_RULES.replace("s∈State", "Valid(s)");
+        let result = validate(&source, Profile::minimal());
+        assert!(result.valid, "{:?}", result.error);
+    }
+
+    #[test]
+    fn test_higher_order_functions_are_detected() {
+        assert!(is_higher_order("twice≜λf.λx.f(f(x))"));
+        assert!(is_higher_order("apply≜λf,x.f(x)"));
+        assert!(!is_higher_order("id≜λx.x"));
+        assert!(!is_higher_order("inc≜λx.succ(x)"));
+    }
+
+    #[test]
+    fn test_profile_can_disable_definition_order_check() {
+        let source = SET_RULES.replace("State≜{Idle,Busy}", "Task≜State;\n  State≜{Idle,Busy}");
+        let forward_warnings = |profile: Profile| {
+            validate(&source, profile)
+                .warnings
+                .iter()
+                .filter(|warning| warning.to_string().contains("Forward reference"))
+                .count()
+        };
+        assert_eq!(forward_warnings(Profile::full()), 1);
+
+        let mut profile = Profile::full();
+        profile.check_definition_order = false;
+        assert_eq!(forward_warnings(profile), 0);
+    }
+
+    #[test]
+    fn test_builtin_profiles_by_name() {
+        assert_eq!(Profile::named("minimal"), Some(Profile::minimal()));
+        assert_eq!(Profile::named("full"), Some(Profile::full()));
+        assert_eq!(Profile::named("embedded"), None);
+        assert_eq!(ValidationConfig::default().profile, Profile::full());
+    }
+}
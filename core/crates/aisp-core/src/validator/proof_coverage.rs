@@ -0,0 +1,70 @@
+//! Proof Coverage
+//!
+//! A document can validate without a single one of its rules being proven.
+//! Proof coverage is the fraction of ⟦Γ⟧ rules the theorem prover proves,
+//! separating "validated" from "verified". The engine derives it from the
+//! per-property outcomes of the formal run when formal verification is
+//! enabled.
+
+use super::engine::AispValidator;
+use super::property_verification::{PropertyOutcome, PropertyStatus};
+
+impl AispValidator {
+    /// Fraction of the rule properties among `outcomes` that were proven,
+    /// or `None` when there are none
+    pub fn proof_coverage(outcomes: &[PropertyOutcome]) -> Option<f64> {
+        let rules: Vec<_> = outcomes.iter().filter(|outcome| outcome.block_type == "Rules").collect();
+        if rules.is_empty() {
+            return None;
+        }
+
+        let proven = rules.iter().filter(|outcome| outcome.status == PropertyStatus::Proven).count();
+        Some(proven as f64 / rules.len() as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property_types::PropertyType;
+    use crate::validator::types::ValidationConfig;
+
+    fn outcome(name: &str, block_type: &str, status: PropertyStatus) -> PropertyOutcome {
+        PropertyOutcome {
+            name: name.to_string(),
+            property_type: PropertyType::LogicalAssertion,
+            block_type: block_type.to_string(),
+            status,
+            counterexample: None,
+        }
+    }
+
+    #[test]
+    fn test_half_of_the_rules_proven() {
+        let outcomes = vec![
+            outcome("meta_domain", "Meta", PropertyStatus::Proven),
+            outcome("rule_0", "Rules", PropertyStatus::Proven),
+            outcome("rule_1", "Rules", PropertyStatus::Unknown),
+            outcome("rule_2", "Rules", PropertyStatus::Disproven),
+            outcome("rule_3", "Rules", PropertyStatus::Proven),
+        ];
+        assert_eq!(AispValidator::proof_coverage(&outcomes), Some(0.5));
+
+        // Properties outside the rules do not count
+        assert_eq!(AispValidator::proof_coverage(&outcomes[..1]), None);
+    }
+
+    #[test]
+    fn test_coverage_is_reported_only_with_formal_verification() {
+        let source = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                      ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+        assert_eq!(AispValidator::new().validate(source).proof_coverage, None);
+
+        // Coverage follows the outcomes the formal run reports for the rules
+        let config = ValidationConfig { enable_formal_verification: true, ..ValidationConfig::default() };
+        let validator = AispValidator::with_config(config);
+        let outcomes = validator.verify_properties(source, &[]).unwrap();
+        assert_eq!(validator.validate(source).proof_coverage, AispValidator::proof_coverage(&outcomes));
+        assert_eq!(validator.validate(source).proof_coverage, Some(0.0));
+    }
+}
@@ -0,0 +1,231 @@
+//! Property Verification
+//!
+//! Runs only the formal layer: properties are extracted from the parsed
+//! document and each one is handed to the theorem prover, giving a
+//! per-property outcome rather than the aggregate confidence full
+//! validation reports.
+
+use super::engine::AispValidator;
+use crate::ast::canonical::CanonicalAispDocument;
+use crate::error::{AispError, AispResult};
+use crate::proof_search::ProofSearchStrategy;
+use crate::proof_types::ProofOutcome;
+use crate::property_extractor::PropertyExtractor;
+use crate::property_types::PropertyType;
+use crate::theorem_prover::TheoremProver;
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+/// Search depth for each property proof
+const VERIFY_MAX_DEPTH: usize = 10;
+
+/// How the theorem prover settled a property
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyStatus {
+    Proven,
+    Disproven,
+    /// Not settled within the search limits, or not decidable
+    Unknown,
+}
+
+impl PropertyStatus {
+    /// Lowercase name used in reports
+    pub fn name(&self) -> &'static str {
+        match self {
+            PropertyStatus::Proven => "proven",
+            PropertyStatus::Disproven => "disproven",
+            PropertyStatus::Unknown => "unknown",
+        }
+    }
+}
+
+/// Verification outcome of one extracted property
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyOutcome {
+    pub name: String,
+    pub property_type: PropertyType,
+    /// Block the property was extracted from, such as "Rules"
+    pub block_type: String,
+    pub status: PropertyStatus,
+    /// Variable assignments refuting a disproven property
+    pub counterexample: Option<BTreeMap<String, String>>,
+}
+
+impl AispValidator {
+    /// Prove each property of `source` whose type is in `selected`, or
+    /// every property when `selected` is empty
+    ///
+    /// Each proof is bounded by the configured `z3_timeout`. Only a
+    /// document that fails to parse is an error.
+    pub fn verify_properties(&self, source: &str, selected: &[PropertyType]) -> AispResult<Vec<PropertyOutcome>> {
+        Ok(self.prove_properties(source, selected, None)?.unwrap_or_default())
+    }
+
+    /// `verify_properties` within `deadline`, or `None` when it passes
+    /// before every selected property was tried
+    ///
+    /// No proof runs past the deadline.
+    pub(super) fn prove_properties(
+        &self,
+        source: &str,
+        selected: &[PropertyType],
+        deadline: Option<Instant>,
+    ) -> AispResult<Option<Vec<PropertyOutcome>>> {
+        let parse_result = self.parser().parse(source);
+        let document = match parse_result.document {
+            Some(document) => document,
+            None => {
+                let message = parse_result
+                    .errors
+                    .first()
+                    .map_or_else(|| "Failed to parse document".to_string(), |error| error.message.clone());
+                return Err(AispError::validation_error(message));
+            }
+        };
+        self.prove_document(&document, selected, deadline)
+    }
+
+    /// `prove_properties` over an already parsed document
+    pub(super) fn prove_document(
+        &self,
+        document: &CanonicalAispDocument,
+        selected: &[PropertyType],
+        deadline: Option<Instant>,
+    ) -> AispResult<Option<Vec<PropertyOutcome>>> {
+        let properties = PropertyExtractor::new().extract_properties(document)?;
+        // The prover gets no more than what is left of the budget
+        let mut prover = TheoremProver::with_config(
+            ProofSearchStrategy::IterativeDeepening,
+            VERIFY_MAX_DEPTH,
+            deadline.map_or(self.config().z3_timeout, |deadline| {
+                self.config().z3_timeout.min(deadline.saturating_duration_since(Instant::now()))
+            }),
+        );
+
+        let mut outcomes = Vec::new();
+        for property in properties {
+            if !selected.is_empty() && !selected.contains(&property.property_type) {
+                continue;
+            }
+            if deadline.map_or(false, |deadline| Instant::now() >= deadline) {
+                return Ok(None);
+            }
+            let result = prover.prove_property(&property).ok();
+            let status = match result.as_ref().map(|result| &result.outcome) {
+                Some(ProofOutcome::Proven) => PropertyStatus::Proven,
+                Some(ProofOutcome::Disproven) => PropertyStatus::Disproven,
+                _ => PropertyStatus::Unknown,
+            };
+            let counterexample = result
+                .and_then(|result| result.counterexample)
+                .filter(|_| status == PropertyStatus::Disproven)
+                .map(|counterexample| counterexample.assignments.into_iter().collect());
+            outcomes.push(PropertyOutcome {
+                name: property.name,
+                property_type: property.property_type,
+                block_type: property.source_location.block_type,
+                status,
+                counterexample,
+            });
+        }
+        Ok(Some(outcomes))
+    }
+
+    /// Fail unless at least `minimum` of `outcomes` are proven
+    pub fn check_verified_minimum(outcomes: &[PropertyOutcome], minimum: usize) -> AispResult<()> {
+        let proven = outcomes.iter().filter(|outcome| outcome.status == PropertyStatus::Proven).count();
+        if proven < minimum {
+            return Err(AispError::validation_error(format!(
+                "Only {} of {} properties proven, at least {} required",
+                proven,
+                outcomes.len(),
+                minimum
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::ValidationConfig;
+
+    const DOCUMENT: &str = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                            ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+
+    #[test]
+    fn test_selection_filters_checked_properties() {
+        let validator = AispValidator::new();
+        let all = validator.verify_properties(DOCUMENT, &[]).unwrap();
+        assert!(all.iter().any(|outcome| outcome.property_type != PropertyType::TypeSafety));
+
+        let type_safety = validator.verify_properties(DOCUMENT, &[PropertyType::TypeSafety]).unwrap();
+        assert!(!type_safety.is_empty());
+        assert!(type_safety.iter().all(|outcome| outcome.property_type == PropertyType::TypeSafety));
+        assert!(type_safety.len() < all.len());
+    }
+
+    #[test]
+    fn test_verified_minimum_counts_proven_properties() {
+        let outcome = |status| PropertyOutcome {
+            name: "rule_0".to_string(),
+            property_type: PropertyType::LogicalAssertion,
+            block_type: "Rules".to_string(),
+            status,
+            counterexample: None,
+        };
+        let outcomes = vec![outcome(PropertyStatus::Proven), outcome(PropertyStatus::Proven), outcome(PropertyStatus::Unknown)];
+
+        assert!(AispValidator::check_verified_minimum(&outcomes, 2).is_ok());
+        let error = AispValidator::check_verified_minimum(&outcomes, 3).unwrap_err();
+        assert!(error.to_string().contains("Only 2 of 3 properties proven, at least 3 required"), "{}", error);
+    }
+
+    #[test]
+    fn test_missing_verified_minimum_fails_validation() {
+        let config = ValidationConfig {
+            enable_formal_verification: true,
+            min_verified_properties: 1,
+            ..ValidationConfig::default()
+        };
+        // The prover settles none of this document's properties; the
+        // minimum is counted from the same run that measures coverage
+        let result = AispValidator::with_config(config.clone()).validate(DOCUMENT);
+        assert!(!result.valid);
+        assert!(result.error.unwrap().to_string().contains("Only 0 of 6 properties proven, at least 1 required"));
+        assert_eq!(result.proof_coverage, Some(0.0));
+
+        let config = ValidationConfig { min_verified_properties: 0, ..config };
+        assert!(AispValidator::with_config(config.clone()).validate(DOCUMENT).valid);
+
+        // Without formal verification the minimum does not apply
+        let config = ValidationConfig { enable_formal_verification: false, min_verified_properties: 1, ..config };
+        assert!(AispValidator::with_config(config).validate(DOCUMENT).valid);
+    }
+
+    #[test]
+    fn test_deadline_stops_proving() {
+        let validator = AispValidator::new();
+        let later = Instant::now() + std::time::Duration::from_secs(600);
+        let outcomes = validator.prove_properties(DOCUMENT, &[], Some(later)).unwrap().unwrap();
+        assert_eq!(outcomes, validator.verify_properties(DOCUMENT, &[]).unwrap());
+
+        // The document parses and its properties are extracted, but none is proven
+        assert_eq!(validator.prove_properties(DOCUMENT, &[], Some(Instant::now())).unwrap(), None);
+    }
+
+    #[test]
+    fn test_unparseable_document_is_an_error() {
+        assert!(AispValidator::new().verify_properties("not an AISP document", &[]).is_err());
+    }
+
+    #[test]
+    fn test_property_type_names_round_trip() {
+        for property_type in PropertyType::ALL {
+            assert_eq!(PropertyType::from_name(property_type.name()), Some(property_type.clone()));
+        }
+        assert_eq!(PropertyType::from_name("liveness"), Some(PropertyType::TemporalLiveness));
+        assert_eq!(PropertyType::from_name("bogus"), None);
+    }
+}
@@ -0,0 +1,163 @@
+//! Redundant Rules
+//!
+//! Two rules that differ only in layout or in the names of their bound
+//! variables, as in `∀x:State→Valid(x)` and `∀s:State→Valid(s)`, state the
+//! same thing. The second adds nothing and clutters the specification, so
+//! the engine reports it as a warning.
+
+use super::engine::AispValidator;
+use super::shadowing::span_of;
+use crate::ast::canonical::Span;
+use crate::parser::robust_parser::{Pair, Rule};
+use std::collections::HashMap;
+use std::fmt;
+
+/// A rule equivalent to an earlier one up to renaming of bound variables
+#[derive(Debug, Clone, PartialEq)]
+pub struct RedundantRule {
+    /// The earlier rule
+    pub first: Span,
+    /// The later rule that repeats it
+    pub second: Span,
+}
+
+impl fmt::Display for RedundantRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "rule at {}:{} repeats the rule at {}:{}",
+            self.second.line, self.second.column, self.first.line, self.first.column
+        )
+    }
+}
+
+impl AispValidator {
+    /// Report rules of the document's parse tree equivalent to an earlier
+    /// rule, in source order
+    ///
+    /// Rules are compared in canonical form: whitespace is dropped and
+    /// bound variables are renamed by binding depth.
+    pub fn redundant_rules(document: &Pair<'_, Rule>) -> Vec<RedundantRule> {
+        let document = document.clone();
+
+        let mut seen: HashMap<String, Span> = HashMap::new();
+        let mut redundant = Vec::new();
+        for rule in document.into_inner().flatten().filter(|pair| pair.as_rule() == Rule::rule_definition) {
+            let Some(expression) = rule.into_inner().next() else {
+                continue;
+            };
+            // The expression's span runs over whitespace before the next rule
+            let mut span = span_of(&expression);
+            span.end -= expression.as_str().len() - expression.as_str().trim_end().len();
+            let mut canonical = String::new();
+            canonicalize(expression, &mut Vec::new(), &mut canonical);
+            match seen.get(&canonical) {
+                Some(first) => redundant.push(RedundantRule { first: first.clone(), second: span }),
+                None => {
+                    seen.insert(canonical, span);
+                }
+            }
+        }
+        redundant
+    }
+}
+
+/// Append the canonical form of `pair` to `out`, with `scope` holding the
+/// bound variables in order of binding
+fn canonicalize(pair: Pair<Rule>, scope: &mut Vec<String>, out: &mut String) {
+    match pair.as_rule() {
+        Rule::identifier => {
+            let name: String = pair.as_str().split_whitespace().collect();
+            match scope.iter().rposition(|bound| *bound == name) {
+                Some(depth) => out.push_str(&format!("v{}", depth)),
+                None => out.push_str(&name),
+            }
+            return;
+        }
+        Rule::quantified_expr => {
+            // quantifier, bound variable, type, body
+            let mut inner = pair.into_inner();
+            let (Some(quantifier), Some(binder), Some(domain), Some(body)) =
+                (inner.next(), inner.next(), inner.next(), inner.next())
+            else {
+                return;
+            };
+            out.push_str(quantifier.as_str());
+            out.push_str(&format!("v{}:", scope.len()));
+            canonicalize(domain, scope, out);
+            out.push('→');
+            scope.push(binder.as_str().split_whitespace().collect());
+            canonicalize(body, scope, out);
+            scope.pop();
+            return;
+        }
+        _ => {}
+    }
+
+    // Operators are literals between the children
+    let source = pair.as_str();
+    let offset = pair.as_span().start();
+    let mut cursor = 0;
+    for inner in pair.into_inner() {
+        let start = inner.as_span().start() - offset;
+        out.extend(source[cursor..start].chars().filter(|ch| !ch.is_whitespace()));
+        cursor = inner.as_span().end() - offset;
+        canonicalize(inner, scope, out);
+    }
+    out.extend(source[cursor..].chars().filter(|ch| !ch.is_whitespace()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(source: &str) -> Pair<'_, Rule> {
+        AispValidator::parse_tree(source).expect("document parses")
+    }
+
+    fn document(rules: &str) -> String {
+        format!(
+            "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{{domain≜x}}\n\n⟦Σ:Types⟧{{\n  State≜{{Idle,Busy}}\n}}\n\n\
+             ⟦Γ:Rules⟧{{\n{}\n}}\n\n⟦Λ:Funcs⟧{{id≜λx.x}}\n\n⟦Ε⟧⟨δ≜0.5⟩",
+            rules
+        )
+    }
+
+    #[test]
+    fn test_exact_duplicate_is_warned() {
+        let source = document("  ∀s:State→Valid(s)\n  ∀s:State → Valid(s);");
+
+        let redundant = AispValidator::redundant_rules(&tree(&source));
+        assert_eq!(redundant.len(), 1);
+        assert_eq!((redundant[0].first.line, redundant[0].second.line), (10, 11));
+        assert_eq!(&source[redundant[0].second.start..redundant[0].second.end], "∀s:State → Valid(s)");
+
+        let result = AispValidator::new().validate(&source);
+        assert!(result.valid, "{:?}", result.error);
+        assert!(result
+            .warnings
+            .iter()
+            .any(|warning| warning.to_string().contains("Redundant rule: rule at 11:3 repeats the rule at 10:3")));
+    }
+
+    #[test]
+    fn test_alpha_equivalent_rules_are_warned() {
+        let source = document("  ∀x:State→∀y:State→Before(x,y)\n  ∀a:State→∀b:State→Before(a,b)");
+
+        let redundant = AispValidator::redundant_rules(&tree(&source));
+        assert_eq!(redundant.len(), 1);
+        assert_eq!((redundant[0].first.column, redundant[0].second.line), (3, 11));
+        assert_eq!(&source[redundant[0].second.start..redundant[0].second.end], "∀a:State→∀b:State→Before(a,b)");
+    }
+
+    #[test]
+    fn test_distinct_rules_are_silent() {
+        for rules in [
+            "  ∀s:State→Valid(s)\n  ∃s:State→Valid(s)",
+            "  ∀x:State→∀y:State→Before(x,y)\n  ∀x:State→∀y:State→Before(y,x)",
+            "  ∀s:State→Valid(s)\n  ∀s:State→Valid(t)",
+        ] {
+            assert_eq!(AispValidator::redundant_rules(&tree(&document(rules))), vec![], "{}", rules);
+        }
+    }
+}
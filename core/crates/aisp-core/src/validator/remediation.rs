@@ -0,0 +1,306 @@
+//! Remediations
+//!
+//! Some diagnostics have an obvious fix: a missing required block can be
+//! inserted as an empty skeleton, an ASCII look-alike such as `->` can be
+//! replaced by the glyph it imitates, and an invisible character can be
+//! removed. The engine attaches a
+//! [`Remediation`] to such diagnostics, and [`AispValidator::apply_remediations`]
+//! applies their patches to the source.
+
+use super::engine::AispValidator;
+use super::repair::CANONICAL_BLOCKS;
+use crate::error::{AispWarning, Patch, Remediation};
+use crate::parser::comments::strip_comments;
+use crate::parser::near_miss::{block_letter, block_marker_near_misses, suggest_glyph_replacement, BlockMarkerNearMiss};
+use crate::parser::robust_parser::{is_invisible_character, AispParser, ParseError, Rule, SecurityIssue};
+use crate::symbols::calculate_symbol_density;
+use pest::Parser;
+
+impl AispValidator {
+    /// Apply the patches of every diagnostic that carries one, returning
+    /// the patched source and the number of patches applied
+    ///
+    /// Glyph replacements are applied first, from the end of the document
+    /// backwards, then missing blocks are inserted in canonical order. A
+    /// patch that no longer fits the source, such as a block insertion
+    /// into a document that does not parse, is skipped.
+    pub fn apply_remediations(source: &str, diagnostics: &[AispWarning]) -> (String, usize) {
+        let patches: Vec<&Patch> = diagnostics
+            .iter()
+            .filter_map(|diagnostic| diagnostic.remediation.as_ref()?.patch.as_ref())
+            .collect();
+
+        let mut replacements: Vec<_> = patches
+            .iter()
+            .filter_map(|patch| match patch {
+                Patch::ReplaceGlyph { line, column, found, glyph } => Some((*line, *column, found, glyph)),
+                Patch::InsertBlock { .. } => None,
+            })
+            .collect();
+        replacements.sort_by(|a, b| (b.0, b.1).cmp(&(a.0, a.1)));
+
+        let mut insertions: Vec<_> = patches
+            .iter()
+            .filter_map(|patch| match patch {
+                Patch::InsertBlock { block, text } => Some((block_order(block)?, text)),
+                Patch::ReplaceGlyph { .. } => None,
+            })
+            .collect();
+        insertions.sort_by_key(|(order, _)| *order);
+
+        let mut patched = source.to_string();
+        let mut applied = 0;
+        for (line, column, found, glyph) in replacements {
+            if let Some(next) = replace_glyph(&patched, line, column, found, glyph) {
+                patched = next;
+                applied += 1;
+            }
+        }
+        for (order, text) in insertions {
+            if let Some(next) = insert_block(&patched, order, text) {
+                patched = next;
+                applied += 1;
+            }
+        }
+        (patched, applied)
+    }
+}
+
+/// Error diagnostic for a missing required block, with the skeleton to
+/// insert when the block has one
+pub(super) fn missing_block_diagnostic(block: &str, source: &str) -> AispWarning {
+    let diagnostic = AispWarning::error(format!("Missing required block: {}", block));
+    let text = match CANONICAL_BLOCKS.iter().find(|(_, name, _)| *name == block) {
+        Some((_, _, Some(skeleton))) => skeleton.to_string(),
+        Some(_) if block == "Evidence" => {
+            format!("⟦Ε⟧⟨δ≜{:.2}⟩", (calculate_symbol_density(source) * 100.0).round() / 100.0)
+        }
+        _ => return diagnostic,
+    };
+    diagnostic.with_remediation(Remediation {
+        description: format!("Insert the missing {} block `{}`", block, text.replace('\n', "")),
+        patch: Some(Patch::InsertBlock { block: block.to_string(), text }),
+    })
+}
+
+/// Split the blocks structural validation found missing into those absent
+/// from `source`, markers that spell one with a look-alike letter, and
+/// blocks whose marker is written correctly
+///
+/// Error recovery drops a block the grammar rejects, as well as blocks
+/// following a malformed marker, so a block whose marker is written
+/// correctly failed to parse rather than being missing.
+pub(super) fn partition_missing_blocks<'a>(
+    missing_blocks: &'a [String],
+    source: &str,
+) -> (Vec<&'a str>, Vec<BlockMarkerNearMiss>, Vec<&'a str>) {
+    let malformed: Vec<BlockMarkerNearMiss> = block_marker_near_misses(source)
+        .into_iter()
+        .filter(|near_miss| missing_blocks.iter().any(|block| block == near_miss.block))
+        .collect();
+    let text = strip_comments(source);
+    let (unparsed, missing) = missing_blocks
+        .iter()
+        .map(String::as_str)
+        .filter(|block| !malformed.iter().any(|near_miss| near_miss.block == *block))
+        .partition(|block| block_letter(block).map_or(false, |letter| text.contains(&format!("⟦{}", letter))));
+    (missing, malformed, unparsed)
+}
+
+/// Error diagnostic for a required block whose marker uses a look-alike
+/// letter, with the replacement that restores it
+pub(super) fn malformed_marker_diagnostic(near_miss: &BlockMarkerNearMiss) -> AispWarning {
+    let BlockMarkerNearMiss { line, column, found, glyph, .. } = *near_miss;
+    AispWarning::error(format!("Malformed block marker: {}", near_miss))
+        .with_line(line)
+        .with_remediation(Remediation {
+            description: format!("Replace `{}` at {}:{} with `{}`", found, line, column, glyph),
+            patch: Some(Patch::ReplaceGlyph { line, column, found: found.to_string(), glyph: glyph.to_string() }),
+        })
+}
+
+/// Warning for the near-miss `hint`, with the glyph replacement it suggests;
+/// the hint already names its line
+pub(super) fn near_miss_diagnostic(hint: &str, source: &str, errors: &[ParseError]) -> AispWarning {
+    let diagnostic = AispWarning::warning(hint);
+    let Some((line, (column, found, glyph))) = errors.iter().find_map(|error| {
+        error.suggestion.as_ref()?;
+        Some((error.line, suggest_glyph_replacement(source, error.line, error.column)?))
+    }) else {
+        return diagnostic;
+    };
+    diagnostic.with_remediation(Remediation {
+        description: format!("Replace `{}` at {}:{} with `{}`", found, line, column, glyph),
+        patch: Some(Patch::ReplaceGlyph { line, column, found, glyph: glyph.to_string() }),
+    })
+}
+
+/// Warning for an invisible character the parser found, with the patch that
+/// removes it
+pub(super) fn invisible_character_diagnostic(issue: &SecurityIssue, source: &str) -> AispWarning {
+    let (line, column) = issue.location;
+    let diagnostic = AispWarning::warning(issue.to_string()).with_line(line);
+    let Some(found) = source
+        .lines()
+        .nth(line.saturating_sub(1))
+        .and_then(|text| text.chars().nth(column.checked_sub(1)?))
+        .filter(|ch| is_invisible_character(*ch))
+    else {
+        return diagnostic;
+    };
+    diagnostic.with_remediation(Remediation {
+        description: format!("Remove U+{:04X} at {}:{}", found as u32, line, column),
+        patch: Some(Patch::ReplaceGlyph { line, column, found: found.to_string(), glyph: String::new() }),
+    })
+}
+
+/// Position of a block name in canonical order
+fn block_order(block: &str) -> Option<usize> {
+    CANONICAL_BLOCKS.iter().position(|(_, name, _)| *name == block)
+}
+
+/// Replace `found` at a 1-based line and character column, if it is there
+fn replace_glyph(source: &str, line: usize, column: usize, found: &str, glyph: &str) -> Option<String> {
+    let line_start = match line.checked_sub(1)? {
+        0 => 0,
+        skip => source.match_indices('\n').nth(skip - 1)?.0 + 1,
+    };
+    let offset = line_start + source[line_start..].char_indices().nth(column.checked_sub(1)?)?.0;
+    source[offset..].starts_with(found).then(|| {
+        format!("{}{}{}", &source[..offset], glyph, &source[offset + found.len()..])
+    })
+}
+
+/// Insert `text` before the first block later in canonical order than
+/// `order`, or after the last block; `None` if the source does not parse
+/// or already has the block
+fn insert_block(source: &str, order: usize, text: &str) -> Option<String> {
+    let document = AispParser::parse(Rule::aisp_document, source).ok()?.next()?;
+    let blocks = document.into_inner().find(|pair| pair.as_rule() == Rule::aisp_blocks)?;
+    let mut spans = Vec::new();
+    for block in blocks.into_inner() {
+        let inner = block.into_inner().next()?;
+        let existing = CANONICAL_BLOCKS.iter().position(|(rule, _, _)| *rule == inner.as_rule())?;
+        spans.push((existing, inner.as_span().start(), inner.as_span().end()));
+    }
+    if spans.iter().any(|&(existing, _, _)| existing == order) {
+        return None;
+    }
+
+    if let Some(&(_, start, _)) = spans.iter().find(|&&(existing, _, _)| existing > order) {
+        return Some(format!("{}{}\n\n{}", &source[..start], text, &source[start..]));
+    }
+    let end = spans.last().map_or_else(|| source.trim_end().len(), |&(_, _, end)| end);
+    Some(format!("{}\n\n{}{}", &source[..end], text, &source[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::WarningSeverity;
+
+    #[test]
+    fn test_missing_block_carries_insert_block_remediation() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n";
+
+        let result = AispValidator::new().validate(source);
+        assert!(!result.valid);
+        let diagnostic = result
+            .warnings
+            .iter()
+            .find(|warning| warning.message == "Missing required block: Types")
+            .expect("missing block diagnostic");
+        assert_eq!(diagnostic.severity, WarningSeverity::Error);
+        let remediation = diagnostic.remediation.as_ref().expect("remediation");
+        assert_eq!(remediation.description, "Insert the missing Types block `⟦Σ:Types⟧{}`");
+        assert_eq!(
+            remediation.patch,
+            Some(Patch::InsertBlock { block: "Types".to_string(), text: "⟦Σ:Types⟧{\n}".to_string() })
+        );
+
+        let (patched, applied) = AispValidator::apply_remediations(source, &result.warnings);
+        assert_eq!(applied, 3);
+        assert!(patched.contains("⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n}\n\n⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{\n}\n\n⟦Ε⟧⟨δ≜"));
+        assert!(!AispValidator::new().validate(&patched).error.map_or(false, |error| {
+            error.to_string().contains("Missing required blocks")
+        }));
+    }
+
+    #[test]
+    fn test_lookalike_marker_is_malformed_not_missing() {
+        let document = |rules: &str| {
+            format!(
+                "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{{domain≜x}}\n\n⟦Σ:Types⟧{{\n  State≜{{Idle,Busy}}\n}}\n\n\
+                 {}\n\n⟦Λ:Funcs⟧{{id≜λx.x}}\n\n⟦Ε⟧⟨δ≜0.5⟩",
+                rules
+            )
+        };
+
+        let cyrillic = document("⟦Г:Rules⟧{\n  ∀s:State→Valid(s)\n}");
+        let result = AispValidator::new().validate(&cyrillic);
+        let error = result.error.expect("malformed marker error").to_string();
+        assert!(
+            error.contains("Malformed block marker: `⟦Г:Rules⟧` at line 9 uses `Г` (U+0413) where the Rules block requires `Γ`"),
+            "{}",
+            error
+        );
+        assert!(!error.contains("Missing required blocks"), "{}", error);
+        assert!(!result.warnings.iter().any(|warning| warning.message.starts_with("Missing required block")));
+        let (patched, applied) = AispValidator::apply_remediations(&cyrillic, &result.warnings);
+        assert_eq!(applied, 1);
+        assert!(AispValidator::new().validate(&patched).valid);
+
+        let result = AispValidator::new().validate(&document(""));
+        let error = result.error.expect("missing block error").to_string();
+        assert!(error.contains("Missing required blocks: Rules"), "{}", error);
+        assert!(!error.contains("Malformed block marker"), "{}", error);
+    }
+
+    #[test]
+    fn test_near_miss_carries_glyph_replacement() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State->Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+
+        let result = AispValidator::new().validate(source);
+        let warning = result
+            .warnings
+            .iter()
+            .find(|warning| warning.remediation.is_some())
+            .expect("near-miss warning");
+        assert_eq!(warning.to_string(), "Warning: line 10: did you mean →?");
+        let patch = warning.remediation.as_ref().and_then(|remediation| remediation.patch.clone()).expect("glyph patch");
+        assert_eq!(
+            patch,
+            Patch::ReplaceGlyph { line: 10, column: 11, found: "->".to_string(), glyph: "→".to_string() }
+        );
+
+        let (patched, applied) = AispValidator::apply_remediations(source, &result.warnings);
+        assert_eq!(applied, 1);
+        assert!(patched.contains("  ∀s:State→Valid(s)\n"));
+    }
+
+    #[test]
+    fn test_invisible_character_breaking_a_block_carries_removal() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State→\u{200B}Valid(s)\n}\n\n⟦Λ:Funcs⟧{id≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+
+        let result = AispValidator::new().validate(source);
+        assert!(!result.valid);
+        let diagnostic = result
+            .warnings
+            .iter()
+            .find(|warning| warning.message.contains("Invisible Character"))
+            .expect("invisible character diagnostic");
+        assert_eq!(diagnostic.line, Some(10));
+        let remediation = diagnostic.remediation.as_ref().expect("remediation");
+        assert_eq!(remediation.description, "Remove U+200B at 10:12");
+        assert_eq!(
+            remediation.patch,
+            Some(Patch::ReplaceGlyph { line: 10, column: 12, found: "\u{200B}".to_string(), glyph: String::new() })
+        );
+
+        let (patched, applied) = AispValidator::apply_remediations(source, &result.warnings);
+        assert_eq!(applied, 1);
+        assert!(AispValidator::new().validate(&patched).valid);
+    }
+}
@@ -0,0 +1,213 @@
+//! Structural Auto-Fix
+//!
+//! Repairs the structural issues the structural validator rejects most
+//! often: blocks out of canonical order, missing required blocks and a
+//! missing evidence block. Existing blocks are moved, never edited, and
+//! every repair is checked with the semantic-preservation verifier before
+//! it is returned.
+
+use super::engine::AispValidator;
+use crate::parser::comments::strip_comments;
+use crate::parser::robust_parser::{AispParser, Rule};
+use crate::semantic_preservation::SemanticPreservationVerifier;
+use crate::symbols::calculate_symbol_density;
+use pest::Parser;
+use std::fmt;
+
+/// Canonical block order with the skeleton inserted when a block is missing
+pub(super) const CANONICAL_BLOCKS: [(Rule, &str, Option<&str>); 6] = [
+    (Rule::omega_block, "Meta", Some("⟦Ω:Meta⟧{\n}")),
+    (Rule::sigma_block, "Types", Some("⟦Σ:Types⟧{\n}")),
+    (Rule::gamma_block, "Rules", Some("⟦Γ:Rules⟧{\n}")),
+    (Rule::lambda_block, "Functions", Some("⟦Λ:Funcs⟧{\n}")),
+    (Rule::chi_block, "Errors", None),
+    (Rule::epsilon_block, "Evidence", None),
+];
+
+/// A repair made by [`AispValidator::auto_fix`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum FixApplied {
+    /// Blocks were moved into canonical order; holds the original order
+    ReorderedBlocks(Vec<String>),
+    /// An empty skeleton was added for a missing required block
+    InsertedSkeleton(String),
+    /// An evidence block was added, claiming the measured symbol density
+    InsertedEvidence(f64),
+}
+
+impl fmt::Display for FixApplied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FixApplied::ReorderedBlocks(original) => {
+                write!(f, "Reordered blocks from {} to canonical order", original.join(", "))
+            }
+            FixApplied::InsertedSkeleton(block) => write!(f, "Inserted empty {} block", block),
+            FixApplied::InsertedEvidence(delta) => write!(f, "Inserted evidence block with δ≜{:.2}", delta),
+        }
+    }
+}
+
+/// A block as written, with the comments and blank lines preceding it
+struct SourceBlock<'a> {
+    order: usize,
+    text: &'a str,
+}
+
+impl AispValidator {
+    /// Repair block order and missing blocks, returning the fixed source
+    ///
+    /// Input the grammar rejects, or that contains malformed blocks, is
+    /// returned unchanged with no fixes, as is any repair the
+    /// semantic-preservation check does not accept.
+    pub fn auto_fix(source: &str) -> (String, Vec<FixApplied>) {
+        repair(source, true)
+    }
+
+    /// Move blocks into canonical order without inserting missing ones
+    pub fn reorder_blocks(source: &str) -> (String, Vec<FixApplied>) {
+        repair(source, false)
+    }
+}
+
+/// Shared body of `auto_fix` and `reorder_blocks`
+fn repair(source: &str, insert_missing: bool) -> (String, Vec<FixApplied>) {
+    let unchanged = || (source.to_string(), Vec::new());
+    let Some((prefix, blocks, suffix)) = split_blocks(source) else {
+        return unchanged();
+    };
+
+    let mut fixes = Vec::new();
+    let original_order: Vec<usize> = blocks.iter().map(|block| block.order).collect();
+    let mut ordered: Vec<&SourceBlock> = blocks.iter().collect();
+    ordered.sort_by_key(|block| block.order);
+    if ordered.iter().map(|block| block.order).ne(original_order.iter().copied()) {
+        fixes.push(FixApplied::ReorderedBlocks(
+            original_order.iter().map(|&order| CANONICAL_BLOCKS[order].1.to_string()).collect(),
+        ));
+    }
+
+    let mut fixed = prefix.trim_end().to_string();
+    let mut next = ordered.iter().peekable();
+    for (order, (_, name, skeleton)) in CANONICAL_BLOCKS.iter().enumerate() {
+        let mut present = false;
+        while let Some(block) = next.next_if(|block| block.order == order) {
+            present = true;
+            fixed.push_str("\n\n");
+            fixed.push_str(block.text.trim());
+        }
+        if present || !insert_missing {
+            continue;
+        }
+
+        if let Some(skeleton) = skeleton {
+            fixes.push(FixApplied::InsertedSkeleton(name.to_string()));
+            fixed.push_str("\n\n");
+            fixed.push_str(skeleton);
+        } else if *name == "Evidence" {
+            let delta = (calculate_symbol_density(source) * 100.0).round() / 100.0;
+            fixes.push(FixApplied::InsertedEvidence(delta));
+            fixed.push_str(&format!("\n\n⟦Ε⟧⟨δ≜{:.2}⟩", delta));
+        }
+    }
+    fixed.push_str(suffix.trim_end());
+    fixed.push('\n');
+
+    if fixes.is_empty() {
+        return unchanged();
+    }
+
+    // Every original block must survive verbatim, and the interpretation
+    // of the repaired document must match the original
+    let blocks_kept = blocks.iter().all(|block| fixed.contains(block.text.trim()));
+    let preserved = SemanticPreservationVerifier::new()
+        .verify_transformation_preservation(source, |_| Ok(fixed.clone()))
+        .unwrap_or(false);
+    if !blocks_kept || !preserved {
+        return unchanged();
+    }
+
+    (fixed, fixes)
+}
+
+/// Split a document into its prefix, blocks and trailing text
+fn split_blocks(source: &str) -> Option<(&str, Vec<SourceBlock<'_>>, &str)> {
+    let document = AispParser::parse(Rule::aisp_document, source).ok()?.next()?;
+    let blocks_pair = document.into_inner().find(|pair| pair.as_rule() == Rule::aisp_blocks)?;
+
+    let mut blocks = Vec::new();
+    let mut spans = Vec::new();
+    for block in blocks_pair.into_inner() {
+        let inner = block.into_inner().next()?;
+        let order = CANONICAL_BLOCKS.iter().position(|(rule, _, _)| *rule == inner.as_rule())?;
+        let span = inner.as_span();
+        // A comment trailing the closing delimiter travels with the block
+        let line_end = source[span.end()..].find('\n').map_or(source.len(), |offset| span.end() + offset);
+        let end = if strip_comments(&source[span.end()..line_end]).trim().is_empty() {
+            line_end
+        } else {
+            span.end()
+        };
+        spans.push((order, span.start(), end));
+    }
+
+    let first_start = spans.first().map(|&(_, start, _)| start).unwrap_or(source.len());
+    let prefix_end = source[..first_start].trim_end().len();
+    let mut cursor = prefix_end;
+    for &(order, _, end) in &spans {
+        blocks.push(SourceBlock { order, text: &source[cursor..end] });
+        cursor = end;
+    }
+
+    Some((&source[..prefix_end], blocks, &source[cursor..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_fix_reorders_blocks() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ε⟧⟨δ≜0.5⟩\n\n⟦Λ:Funcs⟧{id≜λx.x} // identity\n\n# the rules\n⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n";
+        assert!(!AispValidator::new().validate(source).valid);
+
+        let (fixed, fixes) = AispValidator::auto_fix(source);
+
+        assert_eq!(
+            fixes,
+            vec![FixApplied::ReorderedBlocks(
+                ["Evidence", "Functions", "Rules", "Meta", "Types"].map(String::from).to_vec()
+            )]
+        );
+        let position = |text: &str| fixed.find(text).unwrap();
+        assert!(position("⟦Ω") < position("⟦Σ"));
+        assert!(position("⟦Σ") < position("⟦Γ"));
+        assert!(position("# the rules") < position("⟦Γ"));
+        assert!(fixed.contains("⟦Λ:Funcs⟧{id≜λx.x} // identity\n"));
+        assert!(position("⟦Γ") < position("⟦Λ"));
+        assert!(position("⟦Λ") < position("⟦Ε"));
+        assert!(AispValidator::new().validate(&fixed).valid);
+        assert_eq!(AispValidator::auto_fix(&fixed).1, vec![]);
+    }
+
+    #[test]
+    fn test_auto_fix_inserts_missing_blocks() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n";
+
+        let (fixed, fixes) = AispValidator::auto_fix(source);
+
+        assert_eq!(fixes.len(), 3);
+        assert_eq!(fixes[0], FixApplied::InsertedSkeleton("Types".to_string()));
+        assert_eq!(fixes[1], FixApplied::InsertedSkeleton("Functions".to_string()));
+        assert!(matches!(fixes[2], FixApplied::InsertedEvidence(_)));
+        assert!(fixed.contains("⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n}\n\n⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{\n}\n\n⟦Ε⟧⟨δ≜"));
+        assert!(!AispValidator::new().validate(&fixed).error.map_or(false, |error| {
+            error.to_string().contains("Missing required blocks")
+        }));
+    }
+
+    #[test]
+    fn test_auto_fix_leaves_unparseable_input_alone() {
+        let source = "not an AISP document";
+        assert_eq!(AispValidator::auto_fix(source), (source.to_string(), vec![]));
+    }
+}
@@ -0,0 +1,135 @@
+//! Shadowed Bound Variables
+//!
+//! A quantifier or lambda that rebinds a variable already bound by an
+//! enclosing one, as in `∀x:T→(∀x:T→P(x))`, hides the outer binding for
+//! the rest of its scope. That is almost always a specification bug, so
+//! the engine reports it as a warning.
+
+use super::engine::AispValidator;
+use crate::ast::canonical::Span;
+use crate::parser::robust_parser::{Pair, Rule};
+use std::fmt;
+
+/// A bound variable rebound inside the scope of an outer binding
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShadowedVariable {
+    /// Name of the variable
+    pub name: String,
+    /// The enclosing binding that is hidden
+    pub outer: Span,
+    /// The nested binding that hides it
+    pub inner: Span,
+}
+
+impl fmt::Display for ShadowedVariable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` bound at {}:{} shadows the binding at {}:{}",
+            self.name, self.inner.line, self.inner.column, self.outer.line, self.outer.column
+        )
+    }
+}
+
+impl AispValidator {
+    /// Report variables rebound by nested quantifiers or lambdas in the
+    /// document's parse tree, in source order
+    pub fn shadowed_variables(document: &Pair<'_, Rule>) -> Vec<ShadowedVariable> {
+        let mut shadowed = Vec::new();
+        collect_shadowing(document.clone(), &mut Vec::new(), &mut shadowed);
+        shadowed
+    }
+}
+
+/// Walk `pair` with the bindings in scope: a quantifier's variable scopes
+/// over its body, a lambda's parameter over the lambda's result
+fn collect_shadowing<'a>(pair: Pair<'a, Rule>, scope: &mut Vec<Pair<'a, Rule>>, shadowed: &mut Vec<ShadowedVariable>) {
+    let binder = match pair.as_rule() {
+        Rule::quantified_expr => pair.clone().into_inner().find(|inner| inner.as_rule() == Rule::identifier),
+        Rule::lambda_expression => pair.clone().into_inner().find(|inner| inner.as_rule() == Rule::lambda_param),
+        _ => None,
+    };
+
+    let bound = binder.is_some();
+    if let Some(binder) = binder {
+        if let Some(outer) = scope.iter().rev().find(|outer| outer.as_str() == binder.as_str()) {
+            shadowed.push(ShadowedVariable {
+                name: binder.as_str().to_string(),
+                outer: span_of(outer),
+                inner: span_of(&binder),
+            });
+        }
+        scope.push(binder);
+    }
+    for inner in pair.into_inner() {
+        collect_shadowing(inner, scope, shadowed);
+    }
+    if bound {
+        scope.pop();
+    }
+}
+
+pub(super) fn span_of(pair: &Pair<Rule>) -> Span {
+    let span = pair.as_span();
+    let (line, column) = span.start_pos().line_col();
+    Span { start: span.start(), end: span.end(), line, column }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree(source: &str) -> Pair<'_, Rule> {
+        AispValidator::parse_tree(source).expect("document parses")
+    }
+    use crate::validator::ValidationConfig;
+
+    fn document(rule: &str) -> String {
+        format!(
+            "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{{domain≜x}}\n\n⟦Σ:Types⟧{{\n  State≜{{Idle,Busy}}\n}}\n\n\
+             ⟦Γ:Rules⟧{{\n  {}\n}}\n\n⟦Λ:Funcs⟧{{id≜λx.x}}\n\n⟦Ε⟧⟨δ≜0.5⟩",
+            rule
+        )
+    }
+
+    #[test]
+    fn test_shadowing_rule_is_warned() {
+        let source = document("∀x:State→(∀x:State→Valid(x))");
+
+        let shadowed = AispValidator::shadowed_variables(&tree(&source));
+        assert_eq!(shadowed.len(), 1);
+        assert_eq!(shadowed[0].name, "x");
+        assert_eq!((shadowed[0].outer.line, shadowed[0].outer.column), (10, 4));
+        assert_eq!((shadowed[0].inner.line, shadowed[0].inner.column), (10, 14));
+        assert_eq!(&source[shadowed[0].inner.start..shadowed[0].inner.end], "x");
+
+        let result = AispValidator::with_config(ValidationConfig::default()).validate(&source);
+        assert!(result.valid, "{:?}", result.error);
+        assert!(result
+            .warnings
+            .iter()
+            .any(|warning| warning.to_string().contains("`x` bound at 10:14 shadows the binding at 10:4")));
+    }
+
+    #[test]
+    fn test_distinct_variables_are_silent() {
+        for rule in [
+            "∀x:State→∀y:State→Valid(x)",
+            "∀s:State→Valid(s)",
+            "(∀x:State→Valid(x))→(∀x:State→Busy(x))",
+        ] {
+            let source = document(rule);
+            assert!(AispValidator::new().validate(&source).valid, "{}", rule);
+            assert_eq!(AispValidator::shadowed_variables(&tree(&source)), vec![], "{}", rule);
+        }
+    }
+
+    #[test]
+    fn test_each_nested_rebinding_is_reported() {
+        let source = document("∃s:State→(∀s:State→(∀s:State→Valid(s)))");
+
+        let shadowed = AispValidator::shadowed_variables(&tree(&source));
+        let columns: Vec<(usize, usize)> = shadowed.iter().map(|s| (s.outer.column, s.inner.column)).collect();
+        assert_eq!(columns, vec![(4, 14), (14, 24)]);
+    }
+}
@@ -254,6 +254,7 @@ mod tests {
         let blocks = vec![
             CanonicalAispBlock::Meta(MetaBlock { 
                 entries: HashMap::new(), 
+                keys: vec![], 
                 raw_entries: vec![], 
                 span: None 
             }),
@@ -277,6 +278,7 @@ mod tests {
                 phi: None, 
                 tau: None, 
                 metrics: HashMap::new(), 
+                units: HashMap::new(),
                 raw_evidence: vec![], 
                 span: None 
             }),
@@ -297,6 +299,7 @@ mod tests {
         let blocks = vec![
             CanonicalAispBlock::Meta(MetaBlock { 
                 entries: HashMap::new(), 
+                keys: vec![], 
                 raw_entries: vec![], 
                 span: None 
             }),
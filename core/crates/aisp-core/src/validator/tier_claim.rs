@@ -0,0 +1,93 @@
+//! Declared Versus Computed Quality Tier
+//!
+//! The evidence block may declare the document's tier, as in `τ≜◊⁺⁺`.
+//! Declaring a tier above the one validation computes misrepresents the
+//! document's quality, so the engine reports it as a warning, or as an
+//! error in strict mode. Declaring a lower tier is always allowed.
+
+use super::engine::AispValidator;
+use crate::ast::canonical::{CanonicalAispBlock, CanonicalAispDocument};
+use crate::semantic::QualityTier;
+use std::fmt;
+
+/// An evidence block claiming a higher tier than the computed one
+#[derive(Debug, Clone, PartialEq)]
+pub struct TierOverclaim {
+    /// Tier declared by `τ`
+    pub declared: QualityTier,
+    /// Tier computed by validation
+    pub computed: QualityTier,
+    /// Line of the evidence block, when known
+    pub line: Option<usize>,
+}
+
+impl fmt::Display for TierOverclaim {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "declared τ≜{} ({}) exceeds the computed tier {} ({})",
+            self.declared.glyph(),
+            self.declared.name(),
+            self.computed.glyph(),
+            self.computed.name()
+        )
+    }
+}
+
+impl AispValidator {
+    /// Report an evidence block whose declared tier exceeds `computed`
+    pub fn tier_overclaim(document: &CanonicalAispDocument, computed: QualityTier) -> Option<TierOverclaim> {
+        document.blocks.iter().find_map(|block| match block {
+            CanonicalAispBlock::Evidence(evidence) => evidence
+                .tau
+                .filter(|declared| *declared > computed)
+                .map(|declared| TierOverclaim {
+                    declared,
+                    computed,
+                    line: evidence.span.as_ref().map(|span| span.line),
+                }),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::robust_parser::RobustAispParser;
+
+    fn parse_with_tier(tier: &str) -> CanonicalAispDocument {
+        let source = format!(
+            "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{{domain≜x}}\n⟦Σ:Types⟧{{State≜{{Idle,Busy}}}}\n\
+             ⟦Γ:Rules⟧{{∀s:State→Valid(s)}}\n⟦Λ:Funcs⟧{{id≜λx.x}}\n⟦Ε⟧⟨δ≜0.5;τ≜{}⟩",
+            tier
+        );
+        RobustAispParser::new().parse(&source).document.unwrap()
+    }
+
+    #[test]
+    fn test_overclaimed_tier_is_reported() {
+        let document = parse_with_tier("◊⁺⁺");
+
+        let overclaim = AispValidator::tier_overclaim(&document, QualityTier::Bronze).unwrap();
+        assert_eq!(overclaim.declared, QualityTier::Platinum);
+        assert_eq!(overclaim.computed, QualityTier::Bronze);
+        assert_eq!(overclaim.line, Some(6));
+        assert_eq!(
+            overclaim.to_string(),
+            "declared τ≜◊⁺⁺ (Platinum) exceeds the computed tier ◊⁻ (Bronze)"
+        );
+    }
+
+    #[test]
+    fn test_matching_tier_passes() {
+        let document = parse_with_tier("◊");
+        assert_eq!(AispValidator::tier_overclaim(&document, QualityTier::Silver), None);
+    }
+
+    #[test]
+    fn test_underclaimed_tier_passes() {
+        let document = parse_with_tier("◊⁻");
+        assert_eq!(AispValidator::tier_overclaim(&document, QualityTier::Gold), None);
+    }
+}
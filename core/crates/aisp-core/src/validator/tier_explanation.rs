@@ -0,0 +1,249 @@
+//! Density Tier Explanations
+//!
+//! The tier [`AispValidator::validate`] reports follows from the document's
+//! symbol density δ, the share of its tokens that are AISP symbols, against
+//! the thresholds in [`crate::tier_thresholds`]. [`AispValidator::explain_tier`] breaks δ down
+//! into the counts behind it and the density of each block, names the
+//! threshold the document fell short of, and suggests how to reach it.
+
+use super::engine::AispValidator;
+use crate::parser::comments::strip_comments;
+use crate::parser::robust_parser::RobustAispParser;
+use crate::semantic::QualityTier;
+use crate::symbols::{count_symbols, count_tokens, normalize_nfc, AISP_SYMBOLS, SymbolCategory};
+use crate::tier_thresholds;
+use std::fmt;
+
+/// Why a document has its density tier
+#[derive(Debug, Clone, PartialEq)]
+pub struct TierExplanation {
+    /// Symbol density δ of the whole document
+    pub density: f64,
+    /// Tier δ reaches
+    pub tier: QualityTier,
+    /// The next tier up and the threshold δ fell short of, unless the
+    /// document is already Platinum
+    pub missed: Option<(QualityTier, f64)>,
+    /// Tokens that are AISP symbols
+    pub symbol_count: usize,
+    /// Non-whitespace tokens, comments excluded
+    pub token_count: usize,
+    /// Whitespace characters, which do not count toward δ
+    pub whitespace_count: usize,
+    /// Density of each parsed block, in source order
+    pub blocks: Vec<BlockDensity>,
+    /// Concrete changes that would raise δ to the missed threshold
+    pub suggestions: Vec<String>,
+}
+
+/// Symbol density of one block
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockDensity {
+    /// Block marker, such as `⟦Λ:Funcs⟧`
+    pub block: String,
+    pub density: f64,
+    pub symbol_count: usize,
+    pub token_count: usize,
+}
+
+impl fmt::Display for TierExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Density: δ={:.3} ({}, δ≥{:.2})", self.density, self.tier.name(), threshold(self.tier))?;
+        match self.missed {
+            Some((tier, needed)) => writeln!(
+                f,
+                "Missed: {} threshold δ≥{:.2} by {:.3}",
+                tier.name(),
+                needed,
+                needed - self.density
+            )?,
+            None => writeln!(f, "Missed: none, {} is the highest tier", self.tier.name())?,
+        }
+        writeln!(f, "Symbols: {} of {} tokens", self.symbol_count, self.token_count)?;
+        write!(f, "Whitespace: {} characters, not counted toward δ", self.whitespace_count)?;
+        if !self.blocks.is_empty() {
+            write!(f, "\nBlocks:")?;
+            for block in &self.blocks {
+                write!(
+                    f,
+                    "\n  {} δ={:.3} ({}/{})",
+                    block.block, block.density, block.symbol_count, block.token_count
+                )?;
+            }
+        }
+        if !self.suggestions.is_empty() {
+            write!(f, "\nSuggestions:")?;
+            for suggestion in &self.suggestions {
+                write!(f, "\n  {}", suggestion)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl AispValidator {
+    /// Explain the density tier of `source`: δ, the counts it is computed
+    /// from, per-block densities, the threshold just missed and how to
+    /// reach it
+    ///
+    /// Block densities need the document to parse; otherwise they are
+    /// left out and the whole-document figures still hold.
+    pub fn explain_tier(source: &str) -> TierExplanation {
+        let text = strip_comments(source);
+        let text = normalize_nfc(&text);
+        let symbol_count = count_symbols(&text);
+        let token_count = count_tokens(&text);
+        let density = ratio(symbol_count, token_count);
+        let tier = QualityTier::from_delta(density);
+        let missed = next_tier(tier);
+
+        let blocks: Vec<BlockDensity> = RobustAispParser::new()
+            .parse(source)
+            .document
+            .map(|document| {
+                document
+                    .blocks
+                    .iter()
+                    .filter_map(|block| {
+                        let (start, end) = block.byte_range()?;
+                        let block_source = &source[start..end];
+                        let marker = block_source.find('⟧').map_or(block_source, |close| &block_source[..close + '⟧'.len_utf8()]);
+                        let text = strip_comments(block_source);
+                        let text = normalize_nfc(&text);
+                        let (symbol_count, token_count) = (count_symbols(&text), count_tokens(&text));
+                        Some(BlockDensity {
+                            block: marker.to_string(),
+                            density: ratio(symbol_count, token_count),
+                            symbol_count,
+                            token_count,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut suggestions = Vec::new();
+        if let Some((next, needed)) = missed {
+            let to_convert = (needed * token_count as f64 - symbol_count as f64).ceil().max(1.0) as usize;
+            let to_drop = (token_count as f64 - symbol_count as f64 / needed).ceil().max(1.0) as usize;
+            suggestions.push(format!(
+                "Write about {} more of the {} tokens as AISP symbols to reach {}",
+                to_convert, token_count, next.name()
+            ));
+            for (ascii, glyph, count) in ascii_alternatives(&text) {
+                suggestions.push(format!("Replace `{}` with `{}` ({}×)", ascii, glyph, count));
+            }
+            if let Some(sparsest) = blocks
+                .iter()
+                .filter(|block| block.density < needed)
+                .min_by(|a, b| a.density.total_cmp(&b.density))
+            {
+                suggestions.push(format!(
+                    "Start with {}, the sparsest block (δ={:.3})",
+                    sparsest.block, sparsest.density
+                ));
+            }
+            suggestions.push(format!(
+                "Or cut about {} non-symbol tokens, such as prose and long identifiers",
+                to_drop
+            ));
+        }
+
+        TierExplanation {
+            density,
+            tier,
+            missed,
+            symbol_count,
+            token_count,
+            whitespace_count: text.chars().filter(|ch| ch.is_whitespace()).count(),
+            blocks,
+            suggestions,
+        }
+    }
+}
+
+fn ratio(symbols: usize, tokens: usize) -> f64 {
+    if tokens == 0 {
+        0.0
+    } else {
+        symbols as f64 / tokens as f64
+    }
+}
+
+/// Lowest density of a tier
+fn threshold(tier: QualityTier) -> f64 {
+    match tier {
+        QualityTier::Reject => 0.0,
+        QualityTier::Bronze => tier_thresholds::BRONZE,
+        QualityTier::Silver => tier_thresholds::SILVER,
+        QualityTier::Gold => tier_thresholds::GOLD,
+        QualityTier::Platinum => tier_thresholds::PLATINUM,
+    }
+}
+
+/// The tier above `tier` with its threshold
+fn next_tier(tier: QualityTier) -> Option<(QualityTier, f64)> {
+    let next = match tier {
+        QualityTier::Reject => QualityTier::Bronze,
+        QualityTier::Bronze => QualityTier::Silver,
+        QualityTier::Silver => QualityTier::Gold,
+        QualityTier::Gold => QualityTier::Platinum,
+        QualityTier::Platinum => return None,
+    };
+    Some((next, threshold(next)))
+}
+
+/// Operator spellings in ASCII that have an AISP symbol, with the symbol
+/// and how often they occur
+fn ascii_alternatives(text: &str) -> Vec<(&'static str, char, usize)> {
+    let operators = [SymbolCategory::Definition, SymbolCategory::Logic, SymbolCategory::Relation];
+    let mut spellings: Vec<(&str, char)> = AISP_SYMBOLS
+        .iter()
+        .filter(|symbol| operators.contains(&symbol.category))
+        .filter_map(|symbol| Some((symbol.ascii_alt?, symbol.char)))
+        .filter(|(ascii, _)| ascii.len() > 1 && !ascii.chars().any(char::is_alphanumeric))
+        .collect();
+    // Longer spellings first, so `<=>` is not also counted as `<=`
+    spellings.sort_by_key(|(ascii, _)| std::cmp::Reverse(ascii.len()));
+
+    let mut remaining = text.to_string();
+    let mut found = Vec::new();
+    for (ascii, glyph) in spellings {
+        let count = remaining.matches(ascii).count();
+        if count > 0 {
+            remaining = remaining.replace(ascii, " ");
+            found.push((ascii, glyph, count));
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explanation_names_the_threshold_just_missed() {
+        let source = "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{domain≜x}\n\n⟦Σ:Types⟧{\n  State≜{Idle,Busy}\n}\n\n\
+                      ⟦Γ:Rules⟧{\n  ∀s:State→Valid(s)\n}\n\n⟦Λ:Funcs⟧{f≜λx.x}\n\n⟦Ε⟧⟨δ≜0.5⟩";
+
+        let explanation = AispValidator::explain_tier(source);
+        assert_eq!((explanation.symbol_count, explanation.token_count), (27, 118));
+        assert_eq!(explanation.tier, QualityTier::Bronze);
+        assert_eq!(explanation.missed, Some((QualityTier::Silver, tier_thresholds::SILVER)));
+        assert_eq!(explanation.blocks.len(), 5);
+        assert_eq!(explanation.blocks[1].block, "⟦Σ:Types⟧");
+
+        let text = explanation.to_string();
+        assert!(text.contains("Missed: Silver threshold δ≥0.40 by 0.171"), "{}", text);
+        assert!(text.contains("Start with ⟦Σ:Types⟧, the sparsest block (δ=0.143)"), "{}", text);
+    }
+
+    #[test]
+    fn test_ascii_operators_are_suggested_for_replacement() {
+        let explanation = AispValidator::explain_tier("𝔸5.1.X@2026-01-25\n⟦Γ:Rules⟧{\n  a -> b\n  c <=> d\n  e -> f\n}");
+        assert!(explanation.suggestions.contains(&"Replace `->` with `→` (2×)".to_string()));
+        assert!(explanation.suggestions.contains(&"Replace `<=>` with `⇔` (1×)".to_string()));
+        assert!(!explanation.suggestions.iter().any(|suggestion| suggestion.contains("`<=`")));
+    }
+}
@@ -11,8 +11,15 @@ use crate::ghost_intent_validation::GhostIntentValidationResult;
 use crate::rossnet_scoring::RossNetValidationResult;
 use crate::hebbian_learning::HebbianValidationResult;
 use crate::anti_drift::AntiDriftValidationResult;
+use crate::relational_new::RelationalAnalysis;
+use crate::temporal_new::TemporalAnalysisResult;
+use crate::symbols::{lookup_symbol, symbol_histogram, SymbolCategory};
 use crate::ast::canonical::CanonicalAispDocument as AispDocument;
-use crate::{MAX_DOCUMENT_SIZE, AISP_VERSION};
+use crate::mathematical_evaluator::below_threshold;
+use crate::{tier_thresholds, MAX_DOCUMENT_SIZE, MIN_SYMBOL_DENSITY, AISP_VERSION};
+use super::profile::Profile;
+use super::capabilities::CapabilityReport;
+use std::collections::HashMap;
 use std::time::Duration;
 
 /// Validation configuration options
@@ -20,7 +27,12 @@ use std::time::Duration;
 pub struct ValidationConfig {
     /// Maximum document size in bytes
     pub max_document_size: usize,
-    /// Strict mode enables additional validations
+    /// Symbol density below which the parser warns that the document may
+    /// be prose rather than AISP
+    pub min_symbol_density: f64,
+    /// Strict mode enables additional validations, and makes type errors,
+    /// references to undeclared enumeration variants and undefined calls
+    /// invalidate the document rather than only being reported
     pub strict_mode: bool,
     /// Include detailed timing information
     pub include_timing: bool,
@@ -28,6 +40,10 @@ pub struct ValidationConfig {
     pub include_ast: bool,
     /// Include symbol statistics
     pub include_symbol_stats: bool,
+    /// Run relational (Level 4) analysis and include its results
+    pub enable_relational_analysis: bool,
+    /// Run temporal (Level 5) analysis and include its results
+    pub enable_temporal_analysis: bool,
     /// Enable formal verification with Z3
     pub enable_formal_verification: bool,
     /// Z3 verification timeout
@@ -46,6 +62,20 @@ pub struct ValidationConfig {
     pub enable_anti_drift: bool,
     /// Strict formal verification mode - failures cause validation to fail instead of warnings
     pub strict_formal_verification: bool,
+    /// In strict mode, fail on forward references instead of warning
+    pub strict_definition_order: bool,
+    /// Fail validation when any warning is reported, promoting warnings to errors
+    pub deny_warnings: bool,
+    /// With formal verification enabled, fail validation when fewer
+    /// properties are proven; 0 disables the check
+    pub min_verified_properties: usize,
+    /// Language subset and optional checks the document is validated against
+    pub profile: Profile,
+    /// Canonical order for meta keys; empty disables the check
+    pub meta_key_order: Vec<String>,
+    /// Budget for a whole validation, checked between stages; a stage
+    /// already running is not interrupted
+    pub timeout: Option<Duration>,
 }
 
 impl Default for ValidationConfig {
@@ -54,10 +84,13 @@ impl Default for ValidationConfig {
         
         Self {
             max_document_size: MAX_DOCUMENT_SIZE,
+            min_symbol_density: MIN_SYMBOL_DENSITY,
             strict_mode: false,
             include_timing: false,
             include_ast: false,
             include_symbol_stats: false,
+            enable_relational_analysis: false,
+            enable_temporal_analysis: false,
             enable_formal_verification: false,
             z3_timeout: Duration::from_secs(30),
             enable_trivector_validation: true,
@@ -67,6 +100,12 @@ impl Default for ValidationConfig {
             enable_hebbian_learning: true,
             enable_anti_drift: true,
             strict_formal_verification: true,  // Default to strict mode for sound verification
+            strict_definition_order: true,
+            deny_warnings: false,
+            min_verified_properties: 0,
+            profile: Profile::full(),
+            meta_key_order: Vec::new(),
+            timeout: None,
         }
     }
 }
@@ -100,12 +139,22 @@ pub struct ValidationResult {
     pub semantic_time: Option<Duration>,
     /// Total validation timing
     pub total_time: Option<Duration>,
+    /// Per-stage timing tree (when timing is enabled)
+    pub profile: Option<StageProfile>,
     /// Parsed AST (if requested)
     pub ast: Option<AispDocument>,
     /// Semantic analysis details
     pub semantic_analysis: Option<DeepVerificationResult>,
+    /// Relational analysis results (if enabled)
+    pub relational_analysis: Option<RelationalAnalysis>,
+    /// Temporal analysis results (if enabled)
+    pub temporal_analysis: Option<TemporalAnalysisResult>,
+    /// Symbol statistics (if requested)
+    pub symbol_stats: Option<SymbolStats>,
     /// Formal verification results
     pub formal_verification: Option<DeepVerificationResult>,
+    /// Fraction of rules proven by the theorem prover (with formal verification)
+    pub proof_coverage: Option<f64>,
     /// Tri-vector validation results
     pub trivector_validation: Option<TriVectorValidationResult>,
     /// Enhanced Z3 verification results
@@ -118,6 +167,17 @@ pub struct ValidationResult {
     pub hebbian_validation: Option<HebbianValidationResult>,
     /// Anti-drift protocol verification results
     pub anti_drift_validation: Option<AntiDriftValidationResult>,
+    /// Content hash of the header and of each block, in document order
+    pub block_hashes: Vec<(String, u64)>,
+    /// Blocks validated by this run rather than reused from a previous one
+    pub revalidated_blocks: Vec<String>,
+    /// Diagnostics of each block-scoped pass, for incremental reuse
+    pub(super) pass_diagnostics: Vec<(&'static str, Vec<AispWarning>)>,
+    /// Status of each analysis stage, filled in by the engine
+    pub(super) capability_report: CapabilityReport,
+    /// Validation ran out of `ValidationConfig::timeout` and the result
+    /// only covers the stages that finished
+    pub timed_out: bool,
     /// All warnings collected
     pub warnings: Vec<AispWarning>,
     /// Error details (if validation failed)
@@ -141,15 +201,25 @@ impl ValidationResult {
             parse_time: None,
             semantic_time: None,
             total_time: None,
+            profile: None,
             ast: None,
             semantic_analysis: None,
+            relational_analysis: None,
+            temporal_analysis: None,
+            symbol_stats: None,
             formal_verification: None,
+            proof_coverage: None,
             trivector_validation: None,
             enhanced_z3_verification: None,
             ghost_intent_validation: None,
             rossnet_validation: None,
             hebbian_validation: None,
             anti_drift_validation: None,
+            block_hashes: Vec::new(),
+            revalidated_blocks: Vec::new(),
+            pass_diagnostics: Vec::new(),
+            capability_report: CapabilityReport::default(),
+            timed_out: false,
             warnings: Vec::new(),
             error: Some(error),
         }
@@ -184,29 +254,131 @@ impl ValidationResult {
             parse_time: Some(parse_time),
             semantic_time: Some(semantic_time),
             total_time: Some(parse_time + semantic_time),
+            profile: None,
             ast,
             semantic_analysis: Some(analysis.clone()),
+            relational_analysis: None,
+            temporal_analysis: None,
+            symbol_stats: None,
             formal_verification,
+            proof_coverage: None,
             trivector_validation,
             enhanced_z3_verification,
             ghost_intent_validation,
             rossnet_validation,
             hebbian_validation,
             anti_drift_validation,
+            block_hashes: Vec::new(),
+            revalidated_blocks: Vec::new(),
+            pass_diagnostics: Vec::new(),
+            capability_report: CapabilityReport::default(),
+            timed_out: false,
             warnings: analysis.warnings().into_iter().map(|w| AispWarning::warning(w)).collect(),
             error: None,
         }
     }
 
+    /// Report `density` as δ, together with the tier it reaches
+    ///
+    /// δ is the symbol density of the source, the figure
+    /// [`AispValidator::explain_tier`](super::AispValidator::explain_tier)
+    /// breaks down.
+    pub fn with_density(mut self, density: f64) -> Self {
+        let tier = QualityTier::from_delta(density);
+        self.delta = density;
+        self.tier = tier;
+        self.tier_symbol = tier.symbol().to_string();
+        self.tier_name = tier.name().to_string();
+        self.tier_value = tier.value();
+        self
+    }
+
     /// Check if document has acceptable quality
     pub fn is_acceptable(&self) -> bool {
-        self.valid && self.tier != QualityTier::Reject && self.ambiguity < 0.02
+        self.valid
+            && self.tier != QualityTier::Reject
+            && below_threshold(self.ambiguity, tier_thresholds::AMBIGUITY)
     }
 
     /// Get detailed error message
     pub fn error_message(&self) -> Option<String> {
         self.error.as_ref().map(|e| e.to_string())
     }
+
+    /// Which analysis stages ran, were skipped or were disabled
+    pub fn capabilities(&self) -> &CapabilityReport {
+        &self.capability_report
+    }
+}
+
+/// Counts of the AISP symbols in a document, outside comments
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SymbolStats {
+    /// Occurrences of each symbol
+    pub symbol_counts: HashMap<char, usize>,
+    /// Occurrences of each symbol category
+    pub category_counts: HashMap<SymbolCategory, usize>,
+}
+
+impl SymbolStats {
+    /// Count the symbols in `source`
+    pub fn from_source(source: &str) -> Self {
+        let symbol_counts = symbol_histogram(source);
+        let mut category_counts = HashMap::new();
+        for (symbol, count) in &symbol_counts {
+            if let Some(symbol) = lookup_symbol(*symbol) {
+                *category_counts.entry(symbol.category.clone()).or_insert(0) += count;
+            }
+        }
+        Self {
+            symbol_counts,
+            category_counts,
+        }
+    }
+
+    /// Total number of symbols counted
+    pub fn total(&self) -> usize {
+        self.symbol_counts.values().sum()
+    }
+}
+
+/// Node in the validation timing tree
+///
+/// Children are recorded back to back, so their durations sum to roughly
+/// the parent's; the gap is untimed glue between stages.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StageProfile {
+    pub stage: String,
+    pub duration: Duration,
+    pub children: Vec<StageProfile>,
+}
+
+impl StageProfile {
+    pub fn new(stage: impl Into<String>, duration: Duration) -> Self {
+        Self {
+            stage: stage.into(),
+            duration,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_children(mut self, children: Vec<StageProfile>) -> Self {
+        self.children = children;
+        self
+    }
+
+    /// Sum of the direct children's durations
+    pub fn children_total(&self) -> Duration {
+        self.children.iter().map(|child| child.duration).sum()
+    }
+
+    /// Find a stage by name anywhere in the tree
+    pub fn find(&self, stage: &str) -> Option<&StageProfile> {
+        if self.stage == stage {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(stage))
+    }
 }
 
 #[cfg(test)]
@@ -250,6 +422,10 @@ mod tests {
         // High ambiguity should make it unacceptable
         result.ambiguity = 0.05;
         assert!(!result.is_acceptable());
+
+        // Exactly 0.02 is not below the bound, even when f64 rounds it down
+        result.ambiguity = 0.3 - 0.28;
+        assert!(!result.is_acceptable());
     }
 
     #[test]
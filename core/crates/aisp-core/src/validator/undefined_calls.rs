@@ -0,0 +1,248 @@
+//! Undefined Function Calls
+//!
+//! A rule that applies `g` to a value, as in `g(s)=s`, when no `g` is
+//! defined in `⟦Λ:Funcs⟧` refers to behaviour the specification never gives.
+//! The engine reports each such call as an error-severity diagnostic, which
+//! fails validation in strict mode. Names used as a predicate or relation
+//! anywhere in the document, like `Valid` in `∀s:State→Valid(s)`, are left
+//! alone, as are built-in operators and functions and type names applied as
+//! constructors. Both sides of `≡` are values, as in `s≡g(s)`.
+
+use super::engine::AispValidator;
+use crate::ast::canonical::{
+    BinaryOperator, CanonicalAispBlock, CanonicalAispDocument as AispDocument, LogicalExpression, Span, UnaryOperator,
+};
+use std::collections::HashSet;
+use std::fmt;
+
+/// Names callable without a definition in the document
+pub const BUILTIN_FUNCTIONS: &[&str] = &[
+    "+", "-", "*", "/", "∘", "abs", "card", "count", "dom", "len", "max", "min", "ran", "size", "sum",
+];
+
+/// A call to a function the document never defines
+#[derive(Debug, Clone, PartialEq)]
+pub struct UndefinedFunction {
+    /// Name of the called function
+    pub name: String,
+    /// The function name at the call site
+    pub span: Span,
+}
+
+impl fmt::Display for UndefinedFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` called at {}:{} is not defined in ⟦Λ⟧", self.name, self.span.line, self.span.column)
+    }
+}
+
+impl AispValidator {
+    /// Report calls in rules and function bodies to functions the document
+    /// does not define, in source order
+    ///
+    /// Items without a source span cannot be located and are skipped.
+    pub fn undefined_function_calls(document: &AispDocument) -> Vec<UndefinedFunction> {
+        let mut defined: HashSet<&str> = BUILTIN_FUNCTIONS.iter().copied().collect();
+        let mut predicates = HashSet::new();
+        // Each item's calls, with the text and span to locate them in
+        let mut items: Vec<(&str, &Span, Vec<&str>)> = Vec::new();
+        for block in &document.blocks {
+            match block {
+                CanonicalAispBlock::Types(types) => defined.extend(types.definitions.keys().map(String::as_str)),
+                CanonicalAispBlock::Rules(rules) => {
+                    for rule in &rules.rules {
+                        let mut calls = Vec::new();
+                        collect_calls(&rule.expression, true, &mut calls, &mut predicates);
+                        if let Some(span) = &rule.span {
+                            items.push((&rule.raw_text, span, calls));
+                        }
+                    }
+                }
+                CanonicalAispBlock::Functions(functions) => {
+                    for function in &functions.functions {
+                        defined.insert(&function.name);
+                        let mut calls = Vec::new();
+                        collect_calls(&function.lambda.body, false, &mut calls, &mut predicates);
+                        if let Some(span) = &function.span {
+                            items.push((&function.raw_text, span, calls));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut undefined = Vec::new();
+        for (raw, span, calls) in items {
+            let mut cursor = 0;
+            for name in calls {
+                let offset = find_call(raw, name, cursor);
+                cursor = offset.map_or(cursor, |offset| offset + name.len());
+                if defined.contains(name) || predicates.contains(name) {
+                    continue;
+                }
+                undefined.push(UndefinedFunction {
+                    name: name.to_string(),
+                    span: offset.map_or_else(|| span.clone(), |offset| span_at(raw, span, offset, name.len())),
+                });
+            }
+        }
+        undefined
+    }
+}
+
+/// Gather the functions `expression` applies to values into `calls`, left
+/// to right, and the names it applies as predicates into `predicates`
+///
+/// An application stands as a predicate where a formula is expected: as a
+/// rule body, or as an operand of a connective, negation or temporal
+/// operator.
+fn collect_calls<'a>(
+    expression: &'a LogicalExpression,
+    formula: bool,
+    calls: &mut Vec<&'a str>,
+    predicates: &mut HashSet<&'a str>,
+) {
+    match expression {
+        LogicalExpression::Application { function, arguments } => {
+            if formula {
+                predicates.insert(function);
+            } else {
+                calls.push(function);
+            }
+            for argument in arguments {
+                collect_calls(argument, false, calls, predicates);
+            }
+        }
+        LogicalExpression::Binary { op, left, right } => {
+            let connective = matches!(
+                op,
+                BinaryOperator::Implication
+                    | BinaryOperator::Biconditional
+                    | BinaryOperator::And
+                    | BinaryOperator::Or
+                    | BinaryOperator::Xor
+            );
+            collect_calls(left, connective, calls, predicates);
+            collect_calls(right, connective, calls, predicates);
+        }
+        LogicalExpression::Unary { op, operand } => {
+            collect_calls(operand, formula && *op == UnaryOperator::Not, calls, predicates);
+        }
+        LogicalExpression::Membership { element, set } => {
+            collect_calls(element, false, calls, predicates);
+            collect_calls(set, false, calls, predicates);
+        }
+        LogicalExpression::Temporal { operand, .. } => collect_calls(operand, true, calls, predicates),
+        LogicalExpression::Variable(_) | LogicalExpression::Constant(_) | LogicalExpression::Raw(_) => {}
+    }
+}
+
+/// Byte offset of the first `name(` at or after `from` that starts an identifier
+fn find_call(raw: &str, name: &str, from: usize) -> Option<usize> {
+    let mut start = from;
+    while let Some(found) = raw.get(start..)?.find(name) {
+        let offset = start + found;
+        let preceded = raw[..offset].chars().next_back().map_or(false, |c| c.is_alphanumeric() || c == '_');
+        if !preceded && raw[offset + name.len()..].starts_with('(') {
+            return Some(offset);
+        }
+        start = offset + name.len();
+    }
+    None
+}
+
+/// Span of `len` bytes at byte `offset` of `raw`, the text spanned by `base`
+fn span_at(raw: &str, base: &Span, offset: usize, len: usize) -> Span {
+    let before = &raw[..offset];
+    let column = match before.rfind('\n') {
+        Some(newline) => before[newline + 1..].chars().count() + 1,
+        None => base.column + before.chars().count(),
+    };
+    Span::new(base.start + offset, base.start + offset + len, base.line + before.matches('\n').count(), column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::WarningSeverity;
+    use crate::parser::robust_parser::RobustAispParser;
+    use crate::validator::ValidationConfig;
+
+    fn document(rules: &str, functions: &str) -> String {
+        format!(
+            "𝔸5.1.X@2026-01-25\n\n⟦Ω:Meta⟧{{domain≜x}}\n\n⟦Σ:Types⟧{{\n  State≜{{Idle,Busy}}\n}}\n\n\
+             ⟦Γ:Rules⟧{{\n  {}\n}}\n\n⟦Λ:Funcs⟧{{\n  {}\n}}\n\n⟦Ε⟧⟨δ≜0.5⟩",
+            rules, functions
+        )
+    }
+
+    fn parse(source: &str) -> AispDocument {
+        let mut document = RobustAispParser::new().parse(source).document.unwrap();
+        document.parse_structured_data();
+        document
+    }
+
+    #[test]
+    fn test_undefined_call_is_reported() {
+        let source = document("∀s:State→g(s)=s", "id≜λx.x");
+
+        let undefined = AispValidator::undefined_function_calls(&parse(&source));
+        assert_eq!(undefined.len(), 1);
+        assert_eq!(undefined[0].name, "g");
+        assert_eq!((undefined[0].span.line, undefined[0].span.column), (10, 12));
+        assert_eq!(&source[undefined[0].span.start..undefined[0].span.end], "g");
+
+        let result = AispValidator::new().validate(&source);
+        let diagnostic = result
+            .warnings
+            .iter()
+            .find(|warning| warning.message.starts_with("Undefined function"))
+            .expect("undefined call diagnostic");
+        assert_eq!(diagnostic.severity, WarningSeverity::Error);
+        assert_eq!(diagnostic.message, "Undefined function: `g` called at 10:12 is not defined in ⟦Λ⟧");
+        assert_eq!(diagnostic.line, Some(10));
+        assert!(result.valid, "{:?}", result.error);
+    }
+
+    #[test]
+    fn test_undefined_call_fails_strict_validation() {
+        let source = document("∀s:State→g(s)=s", "id≜λx.x");
+        let mut config = ValidationConfig::default();
+        config.strict_mode = true;
+
+        let result = AispValidator::with_config(config).validate(&source);
+        assert!(!result.valid);
+        assert_eq!(
+            result.error.unwrap().to_string(),
+            "Validation error: Undefined function: `g` called at 10:12 is not defined in ⟦Λ⟧"
+        );
+    }
+
+    #[test]
+    fn test_equivalence_operands_are_values() {
+        for (rules, column) in [("∀s:State→s≡g(s)", 14), ("∀s:State→id(s)≡g(s)", 18)] {
+            let source = document(rules, "id≜λx.x");
+            let undefined = AispValidator::undefined_function_calls(&parse(&source));
+            assert_eq!(undefined.len(), 1, "{}", rules);
+            assert_eq!((undefined[0].name.as_str(), undefined[0].span.column), ("g", column));
+        }
+    }
+
+    #[test]
+    fn test_defined_and_builtin_calls_are_silent() {
+        for rules in ["∀s:State→g(s)=s", "∀s:State→max(s,s)=s", "∀s:State→State(s)=s"] {
+            let source = document(rules, "g≜λx.x");
+            assert_eq!(AispValidator::undefined_function_calls(&parse(&source)), vec![], "{}", rules);
+        }
+    }
+
+    #[test]
+    fn test_predicates_are_not_calls() {
+        let source = document("∀s:State→Valid(s)", "id≜λx.x");
+        assert_eq!(AispValidator::undefined_function_calls(&parse(&source)), vec![]);
+
+        let result = AispValidator::new().validate(&source);
+        assert!(result.valid, "{:?}", result.error);
+        assert!(result.warnings.iter().all(|w| !w.message.starts_with("Undefined function")), "{:?}", result.warnings);
+    }
+}
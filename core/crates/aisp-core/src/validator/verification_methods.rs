@@ -195,6 +195,7 @@ mod tests {
                     span: Some(Span { start: 0, end: 0, line: 1, column: 1 }),
                 })
             ],
+            used_symbols: Default::default(),
             span: Some(Span { start: 0, end: 0, line: 1, column: 1 }),
         }
     }
@@ -233,6 +234,7 @@ mod tests {
                 performance_metrics: PerformanceMetrics {
                     verification_time_ms: 100,
                     memory_usage_mb: 10,
+                    stage_timings: Vec::new(),
                 },
             },
             recommendations: vec![],
@@ -55,6 +55,19 @@ pub struct VectorSpaceInput {
     pub verification_level: String,
 }
 
+/// Result of orthonormalizing a set of candidate basis vectors
+#[derive(Debug, Clone)]
+pub struct BasisAnalysis {
+    /// Orthonormal basis spanning the same space as the input vectors
+    pub orthonormal_basis: Vec<Vector>,
+    /// Rank of the input set (dimension of its span)
+    pub rank: usize,
+    /// Rank required for the basis to be non-degenerate
+    pub expected_rank: usize,
+    /// True when the input vectors are linearly dependent
+    pub rank_deficient: bool,
+}
+
 /// Vector space verifier with proper mathematical handling
 pub struct VectorSpaceVerifier {
     math_evaluator: MathEvaluator,
@@ -100,6 +113,28 @@ impl Vector {
     pub fn magnitude(&self) -> f64 {
         self.components.iter().map(|&x| x * x).sum::<f64>().sqrt()
     }
+
+    /// Multiply every component by a scalar
+    pub fn scale(&self, factor: f64) -> Vector {
+        Vector::new(self.components.iter().map(|&x| x * factor).collect())
+    }
+
+    /// Subtract another vector component-wise
+    pub fn sub(&self, other: &Vector) -> Result<Vector, VectorSpaceError> {
+        if self.dimension != other.dimension {
+            return Err(VectorSpaceError::DimensionMismatch {
+                expected: self.dimension,
+                actual: other.dimension,
+            });
+        }
+
+        Ok(Vector::new(
+            self.components.iter()
+                .zip(other.components.iter())
+                .map(|(a, b)| a - b)
+                .collect(),
+        ))
+    }
 }
 
 impl VectorSpace {
@@ -244,6 +279,68 @@ impl VectorSpaceVerifier {
         Ok(dot_product.abs() < self.tolerance)
     }
     
+    /// Orthonormalize vectors using modified Gram-Schmidt
+    ///
+    /// Vectors that are linearly dependent on those already processed leave a
+    /// residual below tolerance and are dropped, so the returned basis spans the
+    /// same space as the input and its length equals the rank.
+    pub fn gram_schmidt(&self, vectors: &[Vector]) -> Result<Vec<Vector>, VectorSpaceError> {
+        let mut basis: Vec<Vector> = Vec::with_capacity(vectors.len());
+
+        for vector in vectors {
+            if let Some(first) = vectors.first() {
+                if vector.dimension != first.dimension {
+                    return Err(VectorSpaceError::DimensionMismatch {
+                        expected: first.dimension,
+                        actual: vector.dimension,
+                    });
+                }
+            }
+
+            let mut residual = vector.clone();
+            for unit in &basis {
+                let projection = residual.dot(unit)?;
+                residual = residual.sub(&unit.scale(projection))?;
+            }
+
+            // Relative threshold so large-magnitude inputs are not misclassified
+            let norm = residual.magnitude();
+            if norm > self.tolerance * vector.magnitude().max(1.0) {
+                basis.push(residual.scale(1.0 / norm));
+            }
+        }
+
+        Ok(basis)
+    }
+
+    /// Compute the rank (dimension of the span) of a set of vectors
+    pub fn rank(&self, vectors: &[Vector]) -> Result<usize, VectorSpaceError> {
+        Ok(self.gram_schmidt(vectors)?.len())
+    }
+
+    /// Orthonormalize a candidate basis and flag rank deficiency
+    pub fn analyze_basis(&self, vectors: &[Vector]) -> Result<BasisAnalysis, VectorSpaceError> {
+        let orthonormal_basis = self.gram_schmidt(vectors)?;
+        let rank = orthonormal_basis.len();
+
+        Ok(BasisAnalysis {
+            orthonormal_basis,
+            rank,
+            expected_rank: vectors.len(),
+            rank_deficient: rank < vectors.len(),
+        })
+    }
+
+    /// Verify the semantic, structural and safety vectors form a non-degenerate basis
+    pub fn verify_tri_vector_basis(
+        &self,
+        semantic: &Vector,
+        structural: &Vector,
+        safety: &Vector,
+    ) -> Result<BasisAnalysis, VectorSpaceError> {
+        self.analyze_basis(&[semantic.clone(), structural.clone(), safety.clone()])
+    }
+
     /// Find intersection of two vector spaces
     pub fn find_intersection(&self, space1: &VectorSpace, space2: &VectorSpace) -> Vec<Vector> {
         let mut intersection = Vec::new();
@@ -381,6 +478,52 @@ mod tests {
         assert!(verifier.are_orthogonal(&v1, &v2).unwrap());
     }
     
+    #[test]
+    fn test_independent_triple_has_full_rank() {
+        let verifier = VectorSpaceVerifier::new();
+        let analysis = verifier.verify_tri_vector_basis(
+            &Vector::new(vec![1.0, 1.0, 0.0]),
+            &Vector::new(vec![1.0, 0.0, 1.0]),
+            &Vector::new(vec![0.0, 1.0, 1.0]),
+        ).unwrap();
+
+        assert_eq!(analysis.rank, 3);
+        assert!(!analysis.rank_deficient);
+        for (i, u) in analysis.orthonormal_basis.iter().enumerate() {
+            assert!((u.magnitude() - 1.0).abs() < 1e-9);
+            for v in &analysis.orthonormal_basis[i + 1..] {
+                assert!(verifier.are_orthogonal(u, v).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_dependent_triple_is_rank_deficient() {
+        let verifier = VectorSpaceVerifier::new();
+        // Third vector is the sum of the first two
+        let analysis = verifier.verify_tri_vector_basis(
+            &Vector::new(vec![1.0, 0.0, 1.0]),
+            &Vector::new(vec![0.0, 1.0, 1.0]),
+            &Vector::new(vec![1.0, 1.0, 2.0]),
+        ).unwrap();
+
+        assert_eq!(analysis.rank, 2);
+        assert_eq!(analysis.expected_rank, 3);
+        assert!(analysis.rank_deficient);
+        assert_eq!(analysis.orthonormal_basis.len(), 2);
+    }
+
+    #[test]
+    fn test_rank_rejects_dimension_mismatch() {
+        let verifier = VectorSpaceVerifier::new();
+        let vectors = vec![Vector::new(vec![1.0, 0.0]), Vector::new(vec![0.0, 1.0, 0.0])];
+
+        assert!(matches!(
+            verifier.rank(&vectors),
+            Err(VectorSpaceError::DimensionMismatch { expected: 2, actual: 3 })
+        ));
+    }
+
     #[test]
     fn test_zero_vector_in_intersection() {
         let verifier = VectorSpaceVerifier::new();
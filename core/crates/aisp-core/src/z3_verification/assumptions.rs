@@ -0,0 +1,302 @@
+//! What-If Analysis Under Extra Assumptions
+//!
+//! Checks whether a document's rules stay consistent once extra rules are
+//! assumed, as in "would ⟦Γ⟧ still hold together if `x<3`?". Rules go
+//! through the same conversion as every other property, logical
+//! expression to formula structure to SMT-LIB; those in the linear
+//! fragment, comparisons between sums of identifiers and numbers combined
+//! with `∧`, `∨`, `¬` and `⇒`, are checked over `Real` constants, and
+//! anything else, including quantified rules, is listed as untranslated.
+//!
+//! Every rule and assumption is asserted once, behind a guard constant of
+//! its own, in a single solver session. Each query then names the guards
+//! of the rules it is about with `(check-sat-assuming ...)`, so accepting
+//! or dropping an assumption never rebuilds the solver, and each
+//! assumption which breaks consistency is named together with the rules it
+//! contradicts.
+
+use super::canonical_types::Z3PropertyResult;
+use super::facade::Z3VerificationFacade;
+use super::smt_interface::SmtSession;
+use crate::ast::canonical::CanonicalAispDocument as AispDocument;
+use crate::error::AispResult;
+use crate::formula_converter::FormulaConverter;
+use crate::parser::content::LogicContentParser;
+use crate::property_types::{ArithmeticOp, FormulaStructure, Term};
+use crate::smt_formula_converter::SMTFormulaConverter;
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Outcome of checking a document under extra assumptions
+#[derive(Debug, Clone, PartialEq)]
+pub struct Z3AssumptionAnalysis {
+    /// Whether the rules and assumptions admit a common model
+    pub consistency: Z3Consistency,
+    /// Rules and assumptions outside the translated fragment, as written
+    pub untranslated: Vec<String>,
+}
+
+impl Z3AssumptionAnalysis {
+    /// Whether the translated rules and assumptions were shown consistent
+    pub fn is_consistent(&self) -> bool {
+        self.consistency == Z3Consistency::Consistent
+    }
+
+    /// Conflicts found, empty unless the analysis is inconsistent
+    pub fn conflicts(&self) -> &[Z3AssumptionConflict] {
+        match &self.consistency {
+            Z3Consistency::Inconsistent(conflicts) => conflicts,
+            _ => &[],
+        }
+    }
+}
+
+/// Consistency of the translated rules and assumptions
+#[derive(Debug, Clone, PartialEq)]
+pub enum Z3Consistency {
+    /// Every assumption can hold together with the document's rules
+    Consistent,
+    /// Some assumptions, or the rules themselves, contradict each other
+    Inconsistent(Vec<Z3AssumptionConflict>),
+    /// The solver could not decide a query
+    Unknown(String),
+}
+
+/// An assumption that cannot hold together with the rules before it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Z3AssumptionConflict {
+    /// The assumption at fault, or `None` when the document's own rules
+    /// contradict each other
+    pub assumption: Option<String>,
+    /// The rules, and earlier assumptions, it contradicts
+    pub conflicts_with: Vec<String>,
+}
+
+impl fmt::Display for Z3AssumptionConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rules: Vec<String> = self.conflicts_with.iter().map(|rule| format!("`{}`", rule)).collect();
+        match &self.assumption {
+            Some(assumption) => write!(f, "assumption `{}` contradicts {}", assumption, rules.join(", ")),
+            None => write!(f, "the document's rules contradict each other: {}", rules.join(", ")),
+        }
+    }
+}
+
+/// A rule translated to an SMT-LIB term, asserted behind `guard`
+#[derive(Debug, Clone)]
+struct SmtRule {
+    text: String,
+    term: String,
+    guard: String,
+    constants: BTreeSet<String>,
+}
+
+/// Answer to one satisfiability query
+enum Check {
+    Sat,
+    Unsat,
+    Undecided(String),
+}
+
+impl Z3VerificationFacade {
+    /// Check whether the document's rules stay consistent when
+    /// `extra_rules` are assumed as well
+    ///
+    /// Each assumption is written like a ⟦Γ⟧ rule, such as `x<3` or
+    /// `a+b≤10∧a≥0`. An assumption that contradicts the document's rules
+    /// or the assumptions accepted before it is reported as a conflict and
+    /// left out of later queries.
+    pub fn verify_with_assumptions(
+        &mut self,
+        document: &AispDocument,
+        extra_rules: &[String],
+    ) -> AispResult<Z3AssumptionAnalysis> {
+        let mut converter = SMTFormulaConverter::new();
+        let mut untranslated = Vec::new();
+        let mut translate = |texts: Vec<&str>| -> Vec<SmtRule> {
+            texts
+                .into_iter()
+                .filter_map(|text| {
+                    let rule = translate_rule(text, &mut converter);
+                    if rule.is_none() {
+                        untranslated.push(text.to_string());
+                    }
+                    rule
+                })
+                .collect()
+        };
+        let base = translate(
+            document
+                .get_rules_blocks()
+                .into_iter()
+                .flat_map(|block| block.raw_rules.iter())
+                .map(|rule| rule.trim())
+                .filter(|rule| !rule.is_empty())
+                .collect(),
+        );
+        let assumptions = translate(extra_rules.iter().map(|rule| rule.trim()).collect());
+
+        let mut session = self.smt_interface.open_session(&prelude(base.iter().chain(&assumptions)))?;
+        let consistency = match check(&mut session, &base.iter().collect::<Vec<_>>()) {
+            Check::Unsat => Z3Consistency::Inconsistent(vec![Z3AssumptionConflict {
+                assumption: None,
+                conflicts_with: base.iter().map(|rule| rule.text.clone()).collect(),
+            }]),
+            Check::Undecided(reason) => Z3Consistency::Unknown(reason),
+            Check::Sat => add_assumptions(&mut session, &base, &assumptions),
+        };
+        Ok(Z3AssumptionAnalysis { consistency, untranslated })
+    }
+}
+
+/// Declarations of every constant and guard, and each rule asserted as
+/// implied by its guard
+fn prelude<'a>(rules: impl Iterator<Item = &'a SmtRule> + Clone) -> String {
+    let constants: BTreeSet<&String> = rules.clone().flat_map(|rule| rule.constants.iter()).collect();
+    let mut script = String::new();
+    for constant in constants {
+        script.push_str(&format!("(declare-const {} Real)\n", constant));
+    }
+    for rule in rules {
+        script.push_str(&format!("(declare-const {} Bool)\n", rule.guard));
+        script.push_str(&format!("(assert (=> {} {}))\n", rule.guard, rule.term));
+    }
+    script
+}
+
+/// Add assumptions one at a time to consistent base rules, naming what
+/// each rejected assumption contradicts
+fn add_assumptions(session: &mut SmtSession<'_>, base: &[SmtRule], assumptions: &[SmtRule]) -> Z3Consistency {
+    let mut accepted: Vec<&SmtRule> = base.iter().collect();
+    let mut conflicts = Vec::new();
+    for assumption in assumptions {
+        accepted.push(assumption);
+        match check(session, &accepted) {
+            Check::Sat => continue,
+            Check::Undecided(reason) => return Z3Consistency::Unknown(reason),
+            Check::Unsat => {}
+        }
+        accepted.pop();
+
+        // Prefer a single rule the assumption contradicts on its own
+        let culprit = accepted
+            .iter()
+            .find(|rule| matches!(check(session, &[rule, assumption]), Check::Unsat))
+            .map(|rule| rule.text.clone());
+        conflicts.push(Z3AssumptionConflict {
+            assumption: Some(assumption.text.clone()),
+            conflicts_with: match culprit {
+                Some(rule) => vec![rule],
+                None => accepted.iter().map(|rule| rule.text.clone()).collect(),
+            },
+        });
+    }
+
+    if conflicts.is_empty() { Z3Consistency::Consistent } else { Z3Consistency::Inconsistent(conflicts) }
+}
+
+/// Decide whether the rules have a common model
+fn check(session: &mut SmtSession<'_>, rules: &[&SmtRule]) -> Check {
+    let guards: Vec<&str> = rules.iter().map(|rule| rule.guard.as_str()).collect();
+    match session.check_assuming(&guards) {
+        Z3PropertyResult::Disproven { .. } => Check::Sat,
+        Z3PropertyResult::Proven { .. } => Check::Unsat,
+        Z3PropertyResult::Unknown { reason, .. } => Check::Undecided(reason),
+        Z3PropertyResult::Error { error_message, .. } => Check::Undecided(error_message),
+        Z3PropertyResult::Unsupported { property_type, .. } => Check::Undecided(property_type),
+    }
+}
+
+/// Names that cannot be declared as SMT-LIB constants
+const RESERVED: &[&str] = &["and", "or", "not", "ite", "true", "false", "distinct", "let", "forall", "exists"];
+
+/// Translate a rule in the linear fragment to an SMT-LIB term
+fn translate_rule(text: &str, converter: &mut SMTFormulaConverter) -> Option<SmtRule> {
+    let expression = LogicContentParser::parse_logical_expression(text);
+    let formula = FormulaConverter::convert_rule_to_formula(&expression).ok()?;
+    let mut constants = BTreeSet::new();
+    if !linear_formula(&formula.structure, &mut constants) {
+        return None;
+    }
+    let term = converter.convert_structure_to_smt(&formula.structure).ok()?;
+    let guard = converter.fresh_var("assume");
+    Some(SmtRule { text: text.to_string(), term, guard, constants })
+}
+
+/// Whether `structure` is in the linear fragment, collecting its constants
+fn linear_formula(structure: &FormulaStructure, constants: &mut BTreeSet<String>) -> bool {
+    match structure {
+        FormulaStructure::Negation(inner) => linear_formula(inner, constants),
+        FormulaStructure::Conjunction(parts) | FormulaStructure::Disjunction(parts) => {
+            parts.iter().all(|part| linear_formula(part, constants))
+        }
+        FormulaStructure::Implication(left, right) => linear_formula(left, constants) && linear_formula(right, constants),
+        FormulaStructure::ArithmeticEqual(left, right) | FormulaStructure::ArithmeticLessEqual(left, right) => {
+            linear_term(left, constants) && linear_term(right, constants)
+        }
+        _ => false,
+    }
+}
+
+fn linear_term(term: &Term, constants: &mut BTreeSet<String>) -> bool {
+    match term {
+        Term::Variable(name, _) => {
+            let declarable = name.starts_with(|ch: char| ch.is_ascii_alphabetic() || ch == '_')
+                && name.chars().all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+                && !RESERVED.contains(&name.as_str());
+            if declarable {
+                constants.insert(name.clone());
+            }
+            declarable
+        }
+        Term::Constant(_, sort) => sort == "ℝ",
+        Term::Arithmetic(ArithmeticOp::Add | ArithmeticOp::Subtract | ArithmeticOp::Multiply, left, right) => {
+            linear_term(left, constants) && linear_term(right, constants)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::canonical::{self, CanonicalAispBlock, RulesBlock};
+
+    fn document(rules: &[&str]) -> AispDocument {
+        let mut document = canonical::create_document("test", "5.1", "2026-01-26");
+        document.blocks.push(CanonicalAispBlock::Rules(RulesBlock {
+            rules: vec![],
+            raw_rules: rules.iter().map(|rule| rule.to_string()).collect(),
+            span: None,
+        }));
+        document
+    }
+
+    #[test]
+    fn test_innocuous_assumption_keeps_consistency() {
+        let mut facade = Z3VerificationFacade::new_disabled();
+        let document = document(&["x>5", "x+y≤20", "∀s:State→Valid(s)"]);
+
+        let analysis = facade
+            .verify_with_assumptions(&document, &["x>6".to_string(), "y≥0∧y≠3".to_string()])
+            .unwrap();
+        assert!(analysis.is_consistent(), "{:?}", analysis);
+        assert_eq!(analysis.untranslated, vec!["∀s:State→Valid(s)".to_string()]);
+    }
+
+    #[test]
+    fn test_contradictory_assumption_names_the_conflict() {
+        let mut facade = Z3VerificationFacade::new_disabled();
+        let document = document(&["x>5", "x+y≤20"]);
+
+        let analysis = facade
+            .verify_with_assumptions(&document, &["y≥0".to_string(), "x<3".to_string()])
+            .unwrap();
+        assert!(!analysis.is_consistent());
+        assert_eq!(
+            analysis.conflicts(),
+            &[Z3AssumptionConflict { assumption: Some("x<3".to_string()), conflicts_with: vec!["x>5".to_string()] }]
+        );
+        assert_eq!(analysis.conflicts()[0].to_string(), "assumption `x<3` contradicts `x>5`");
+    }
+}
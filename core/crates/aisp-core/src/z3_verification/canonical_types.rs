@@ -313,6 +313,45 @@ pub struct Z3VerificationStatistics {
     pub smt_queries: usize,
     /// Cache hit ratio
     pub cache_hit_ratio: f64,
+    /// Z3 search counters accumulated over every solve
+    pub solver: Z3SolverStatistics,
+}
+
+/// Z3's internal search counters, read from the solver after each check
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Z3SolverStatistics {
+    /// Conflicts encountered during search
+    pub conflicts: u64,
+    /// Case-split decisions
+    pub decisions: u64,
+    /// Unit and theory propagations
+    pub propagations: u64,
+    /// Search restarts
+    pub restarts: u64,
+}
+
+impl Z3SolverStatistics {
+    /// Add one Z3 statistics entry, e.g. `conflicts` or `sat propagations 2ary`;
+    /// keys for other counters are ignored
+    pub fn record(&mut self, key: &str, value: u64) {
+        let key = key.strip_prefix("sat ").unwrap_or(key);
+        let counter = match key.split(' ').next() {
+            Some("conflicts") => &mut self.conflicts,
+            Some("decisions") => &mut self.decisions,
+            Some("propagations") => &mut self.propagations,
+            Some("restarts") => &mut self.restarts,
+            _ => return,
+        };
+        *counter += value;
+    }
+
+    /// Accumulate the counters of another solve
+    pub fn add(&mut self, other: &Self) {
+        self.conflicts += other.conflicts;
+        self.decisions += other.decisions;
+        self.propagations += other.propagations;
+        self.restarts += other.restarts;
+    }
 }
 
 /// Detailed timing breakdown
@@ -536,4 +575,19 @@ mod tests {
         assert_eq!(config.max_memory_mb, 2048);
         assert!(config.validate().is_ok());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_solver_statistics_record_search_counters() {
+        let mut stats = Z3SolverStatistics::default();
+        stats.record("conflicts", 4);
+        stats.record("sat conflicts", 1);
+        stats.record("decisions", 9);
+        stats.record("sat propagations 2ary", 20);
+        stats.record("sat propagations nary", 5);
+        stats.record("restarts", 1);
+        stats.record("arith conflicts", 7);
+        stats.record("max memory", 12);
+
+        assert_eq!(stats, Z3SolverStatistics { conflicts: 5, decisions: 9, propagations: 25, restarts: 1 });
+    }
+}
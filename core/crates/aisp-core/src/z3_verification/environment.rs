@@ -458,6 +458,7 @@ mod tests {
                 protocol: None,
             },
             blocks: vec![],
+            used_symbols: Default::default(),
             span: Some(crate::ast::canonical::Span {
                 start: 0,
                 end: 0,
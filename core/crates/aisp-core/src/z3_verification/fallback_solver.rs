@@ -0,0 +1,971 @@
+//! Pure-Rust Fallback Solver
+//!
+//! Decides SMT-LIB scripts in the fragment most AISP properties live in
+//! when Z3 is not compiled in: propositional logic over `Bool` constants
+//! combined with linear arithmetic over `Int` and `Real` constants.
+//!
+//! Boolean structure is put in negation normal form and case-split; the
+//! arithmetic literals of each branch are decided by Fourier–Motzkin
+//! elimination over exact rationals. For integer constraints every derived
+//! inequality is tightened (strict to non-strict, divided by the gcd of its
+//! coefficients), which refutes most integer-infeasible systems; when the
+//! rational relaxation is feasible but back-substitution finds no integer
+//! witness the answer is unknown rather than a guess. Anything outside the
+//! fragment, or beyond the search budget, is likewise reported as unknown.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Result of checking a script
+#[derive(Debug, Clone, PartialEq)]
+pub enum FallbackOutcome {
+    /// The assertions are satisfiable, witnessed by the model
+    Sat(FallbackModel),
+    /// The assertions are unsatisfiable
+    Unsat,
+    /// Outside the supported fragment or over budget
+    Unknown(String),
+}
+
+/// Satisfying assignment for every declared constant
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FallbackModel {
+    pub values: BTreeMap<String, ModelValue>,
+}
+
+/// Value of one constant in a model
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModelValue {
+    Bool(bool),
+    Number(Rational),
+}
+
+impl fmt::Display for FallbackModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let values: Vec<String> = self
+            .values
+            .iter()
+            .map(|(name, value)| match value {
+                ModelValue::Bool(value) => format!("{} = {}", name, value),
+                ModelValue::Number(value) => format!("{} = {}", name, value),
+            })
+            .collect();
+        write!(f, "{}", values.join(", "))
+    }
+}
+
+type SolverResult<T> = Result<T, String>;
+
+fn overflow() -> String {
+    "arithmetic overflow".to_string()
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Exact rational with overflow-checked arithmetic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self { num: 0, den: 1 };
+    pub const ONE: Self = Self { num: 1, den: 1 };
+
+    fn new(num: i128, den: i128) -> SolverResult<Self> {
+        if den == 0 {
+            return Err("division by zero".to_string());
+        }
+        let divisor = gcd(num, den).max(1);
+        let (num, den) = (num / divisor, den / divisor);
+        if den < 0 {
+            Ok(Self {
+                num: num.checked_neg().ok_or_else(overflow)?,
+                den: den.checked_neg().ok_or_else(overflow)?,
+            })
+        } else {
+            Ok(Self { num, den })
+        }
+    }
+
+    pub fn integer(value: i128) -> Self {
+        Self { num: value, den: 1 }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.num
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.den
+    }
+
+    /// Parse an SMT-LIB numeral or decimal
+    fn parse(text: &str) -> Option<Self> {
+        let (negative, digits) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        if whole.is_empty() || !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let den = 10i128.checked_pow(fraction.len() as u32)?;
+        let num = format!("{}{}", whole, fraction).parse::<i128>().ok()?;
+        Self::new(if negative { -num } else { num }, den).ok()
+    }
+
+    fn add(self, other: Self) -> SolverResult<Self> {
+        let left = self.num.checked_mul(other.den).ok_or_else(overflow)?;
+        let right = other.num.checked_mul(self.den).ok_or_else(overflow)?;
+        let den = self.den.checked_mul(other.den).ok_or_else(overflow)?;
+        Self::new(left.checked_add(right).ok_or_else(overflow)?, den)
+    }
+
+    fn neg(self) -> SolverResult<Self> {
+        Ok(Self { num: self.num.checked_neg().ok_or_else(overflow)?, den: self.den })
+    }
+
+    fn sub(self, other: Self) -> SolverResult<Self> {
+        self.add(other.neg()?)
+    }
+
+    fn mul(self, other: Self) -> SolverResult<Self> {
+        Self::new(
+            self.num.checked_mul(other.num).ok_or_else(overflow)?,
+            self.den.checked_mul(other.den).ok_or_else(overflow)?,
+        )
+    }
+
+    fn div(self, other: Self) -> SolverResult<Self> {
+        Self::new(
+            self.num.checked_mul(other.den).ok_or_else(overflow)?,
+            self.den.checked_mul(other.num).ok_or_else(overflow)?,
+        )
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    fn floor(&self) -> i128 {
+        self.num.div_euclid(self.den)
+    }
+
+    fn ceil(&self) -> i128 {
+        -((-self.num).div_euclid(self.den))
+    }
+
+    fn compare(&self, other: &Self) -> Ordering {
+        match self.sub(*other) {
+            Ok(difference) => difference.num.cmp(&0),
+            Err(_) => (self.num as f64 / self.den as f64)
+                .partial_cmp(&(other.num as f64 / other.den as f64))
+                .unwrap_or(Ordering::Equal),
+        }
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+/// Sort of a declared constant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sort {
+    Bool,
+    Int,
+    Real,
+}
+
+/// `Σ coefficient·variable + constant`
+#[derive(Debug, Clone, PartialEq)]
+struct Linear {
+    terms: BTreeMap<String, Rational>,
+    constant: Rational,
+}
+
+impl Linear {
+    fn constant(value: Rational) -> Self {
+        Self { terms: BTreeMap::new(), constant: value }
+    }
+
+    fn variable(name: &str) -> Self {
+        Self { terms: BTreeMap::from([(name.to_string(), Rational::ONE)]), constant: Rational::ZERO }
+    }
+
+    fn is_constant(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    fn coefficient(&self, variable: &str) -> Rational {
+        self.terms.get(variable).copied().unwrap_or(Rational::ZERO)
+    }
+
+    fn add(&self, other: &Linear) -> SolverResult<Linear> {
+        let mut sum = self.clone();
+        for (name, coefficient) in &other.terms {
+            let combined = sum.coefficient(name).add(*coefficient)?;
+            if combined.is_zero() {
+                sum.terms.remove(name);
+            } else {
+                sum.terms.insert(name.clone(), combined);
+            }
+        }
+        sum.constant = sum.constant.add(other.constant)?;
+        Ok(sum)
+    }
+
+    fn scale(&self, factor: Rational) -> SolverResult<Linear> {
+        if factor.is_zero() {
+            return Ok(Linear::constant(Rational::ZERO));
+        }
+        let mut terms = BTreeMap::new();
+        for (name, coefficient) in &self.terms {
+            terms.insert(name.clone(), coefficient.mul(factor)?);
+        }
+        Ok(Linear { terms, constant: self.constant.mul(factor)? })
+    }
+
+    fn sub(&self, other: &Linear) -> SolverResult<Linear> {
+        self.add(&other.scale(Rational::integer(-1))?)
+    }
+
+    fn evaluate(&self, values: &BTreeMap<String, Rational>) -> SolverResult<Rational> {
+        let mut total = self.constant;
+        for (name, coefficient) in &self.terms {
+            let value = values.get(name).copied().unwrap_or(Rational::ZERO);
+            total = total.add(coefficient.mul(value)?)?;
+        }
+        Ok(total)
+    }
+}
+
+/// `expr ≤ 0`, `expr < 0` or `expr = 0`
+#[derive(Debug, Clone, PartialEq)]
+enum Relation {
+    Le,
+    Lt,
+    Eq,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Constraint {
+    expr: Linear,
+    relation: Relation,
+}
+
+impl Constraint {
+    fn holds(&self, values: &BTreeMap<String, Rational>) -> SolverResult<bool> {
+        let value = self.expr.evaluate(values)?;
+        Ok(match self.relation {
+            Relation::Le => value.compare(&Rational::ZERO) != Ordering::Greater,
+            Relation::Lt => value.compare(&Rational::ZERO) == Ordering::Less,
+            Relation::Eq => value.is_zero(),
+        })
+    }
+}
+
+/// Declarations and translated assertions of a script, loaded once
+#[derive(Debug, Clone)]
+pub struct FallbackSession {
+    sorts: HashMap<String, Sort>,
+    formulas: Vec<Formula>,
+}
+
+/// Formula in negation normal form
+#[derive(Debug, Clone)]
+enum Formula {
+    True,
+    False,
+    Bool(String, bool),
+    Arith(Constraint),
+    And(Vec<Formula>),
+    Or(Vec<Formula>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum SExpr {
+    Atom(String),
+    List(Vec<SExpr>),
+}
+
+fn parse_sexprs(script: &str) -> SolverResult<Vec<SExpr>> {
+    let mut stack: Vec<Vec<SExpr>> = vec![Vec::new()];
+    let mut chars = script.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            ';' => {
+                while chars.next_if(|&c| c != '\n').is_some() {}
+            }
+            '(' => stack.push(Vec::new()),
+            ')' => {
+                let list = stack.pop().filter(|_| !stack.is_empty()).ok_or("unbalanced parentheses")?;
+                stack.last_mut().ok_or("unbalanced parentheses")?.push(SExpr::List(list));
+            }
+            '"' | '|' => return Err("quoted symbols and strings are not supported".to_string()),
+            c if c.is_whitespace() => {}
+            c => {
+                let mut atom = c.to_string();
+                while let Some(next) = chars.next_if(|&c| !c.is_whitespace() && !"();".contains(c)) {
+                    atom.push(next);
+                }
+                stack.last_mut().ok_or("unbalanced parentheses")?.push(SExpr::Atom(atom));
+            }
+        }
+    }
+    match stack.pop() {
+        Some(top) if stack.is_empty() => Ok(top),
+        _ => Err("unbalanced parentheses".to_string()),
+    }
+}
+
+/// Pure-Rust decision procedure for linear arithmetic and propositional logic
+#[derive(Debug, Clone)]
+pub struct FallbackSolver {
+    /// Maximum number of case splits before giving up
+    pub max_branches: usize,
+    /// Maximum number of inequalities kept during elimination
+    pub max_constraints: usize,
+}
+
+impl Default for FallbackSolver {
+    fn default() -> Self {
+        Self { max_branches: 10_000, max_constraints: 2_000 }
+    }
+}
+
+/// Outcome of one branch of the case split
+enum Branch {
+    Sat(FallbackModel),
+    Unsat,
+    Unknown(String),
+}
+
+impl FallbackSolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check satisfiability of the assertions before the first `(check-sat)`
+    pub fn check_script(&self, script: &str) -> FallbackOutcome {
+        let outcome = self.session(script).and_then(|session| self.decide(&session, &[]));
+        Self::outcome(outcome)
+    }
+
+    /// Load the declarations and assertions before the first `(check-sat)`
+    /// once, to be decided under different assumptions with `check_assuming`
+    pub fn session(&self, script: &str) -> Result<FallbackSession, String> {
+        let mut sorts = HashMap::new();
+        let mut assertions = Vec::new();
+
+        for command in parse_sexprs(script)? {
+            let SExpr::List(parts) = command else {
+                return Err("expected a command".to_string());
+            };
+            let Some(SExpr::Atom(head)) = parts.first() else {
+                return Err("expected a command".to_string());
+            };
+            match (head.as_str(), &parts[1..]) {
+                ("declare-const", [SExpr::Atom(name), SExpr::Atom(sort)])
+                | ("declare-fun", [SExpr::Atom(name), SExpr::List(_), SExpr::Atom(sort)])
+                    if !matches!(&parts[..], [_, _, SExpr::List(arguments), _] if !arguments.is_empty()) =>
+                {
+                    let sort = match sort.as_str() {
+                        "Bool" => Sort::Bool,
+                        "Int" => Sort::Int,
+                        "Real" => Sort::Real,
+                        other => return Err(format!("sort {} is not supported", other)),
+                    };
+                    sorts.insert(name.clone(), sort);
+                }
+                ("assert", [term]) => assertions.push(term.clone()),
+                ("check-sat", _) => break,
+                ("set-logic" | "set-option" | "set-info" | "get-model" | "get-value" | "exit", _) => {}
+                (other, _) => return Err(format!("command {} is not supported", other)),
+            }
+        }
+
+        let translator = Translator { sorts: &sorts };
+        let formulas = assertions
+            .iter()
+            .map(|assertion| translator.formula(assertion, true))
+            .collect::<SolverResult<Vec<_>>>()?;
+        Ok(FallbackSession { sorts, formulas })
+    }
+
+    /// Check the session's assertions with each of `assumptions`, declared
+    /// `Bool` constants, taken as true, like `(check-sat-assuming ...)`
+    pub fn check_assuming(&self, session: &FallbackSession, assumptions: &[&str]) -> FallbackOutcome {
+        Self::outcome(self.decide(session, assumptions))
+    }
+
+    fn outcome(result: SolverResult<Branch>) -> FallbackOutcome {
+        match result {
+            Ok(Branch::Sat(model)) => FallbackOutcome::Sat(model),
+            Ok(Branch::Unsat) => FallbackOutcome::Unsat,
+            Ok(Branch::Unknown(reason)) | Err(reason) => FallbackOutcome::Unknown(reason),
+        }
+    }
+
+    fn decide(&self, session: &FallbackSession, assumptions: &[&str]) -> SolverResult<Branch> {
+        let mut formulas = session.formulas.clone();
+        for assumption in assumptions {
+            if session.sorts.get(*assumption) != Some(&Sort::Bool) {
+                return Err(format!("assumption {} is not a declared Bool constant", assumption));
+            }
+            formulas.push(Formula::Bool(assumption.to_string(), true));
+        }
+
+        let mut budget = self.max_branches;
+        self.search(vec![Formula::And(formulas)], HashMap::new(), Vec::new(), &session.sorts, &mut budget)
+    }
+
+    /// Case-split over disjunctions, deciding arithmetic at each leaf
+    fn search(
+        &self,
+        mut stack: Vec<Formula>,
+        mut bools: HashMap<String, bool>,
+        mut constraints: Vec<Constraint>,
+        sorts: &HashMap<String, Sort>,
+        budget: &mut usize,
+    ) -> SolverResult<Branch> {
+        let mut disjunctions = Vec::new();
+        while let Some(formula) = stack.pop() {
+            match formula {
+                Formula::True => {}
+                Formula::False => return Ok(Branch::Unsat),
+                Formula::Bool(name, value) => {
+                    if *bools.entry(name).or_insert(value) != value {
+                        return Ok(Branch::Unsat);
+                    }
+                }
+                Formula::Arith(constraint) => {
+                    if constraint.expr.is_constant() {
+                        if !constraint.holds(&BTreeMap::new())? {
+                            return Ok(Branch::Unsat);
+                        }
+                    } else {
+                        constraints.push(constraint);
+                    }
+                }
+                Formula::And(parts) => stack.extend(parts),
+                Formula::Or(parts) => disjunctions.push(parts),
+            }
+        }
+
+        if let Some(first) = disjunctions.pop() {
+            let rest: Vec<Formula> = disjunctions.into_iter().map(Formula::Or).collect();
+            let mut unknown = None;
+            for option in first {
+                if *budget == 0 {
+                    return Ok(Branch::Unknown("case-split budget exhausted".to_string()));
+                }
+                *budget -= 1;
+                let mut branch_stack = rest.clone();
+                branch_stack.push(option);
+                match self.search(branch_stack, bools.clone(), constraints.clone(), sorts, budget)? {
+                    Branch::Sat(model) => return Ok(Branch::Sat(model)),
+                    Branch::Unsat => {}
+                    Branch::Unknown(reason) => unknown = Some(reason),
+                }
+            }
+            return Ok(unknown.map_or(Branch::Unsat, Branch::Unknown));
+        }
+
+        let Some(values) = self.solve_linear(&constraints, sorts)? else {
+            return Ok(Branch::Unsat);
+        };
+        for constraint in &constraints {
+            if !constraint.holds(&values)? {
+                return Ok(Branch::Unknown("no integer witness found".to_string()));
+            }
+        }
+
+        let mut model = FallbackModel::default();
+        for (name, sort) in sorts {
+            let value = match sort {
+                Sort::Bool => ModelValue::Bool(bools.get(name).copied().unwrap_or(false)),
+                Sort::Int | Sort::Real => {
+                    ModelValue::Number(values.get(name).copied().unwrap_or(Rational::ZERO))
+                }
+            };
+            model.values.insert(name.clone(), value);
+        }
+        Ok(Branch::Sat(model))
+    }
+
+    /// Fourier–Motzkin elimination with back-substitution
+    ///
+    /// Returns `None` when the constraints are infeasible. An error means
+    /// the constraint budget or integer witness search gave up.
+    fn solve_linear(
+        &self,
+        constraints: &[Constraint],
+        sorts: &HashMap<String, Sort>,
+    ) -> SolverResult<Option<BTreeMap<String, Rational>>> {
+        let is_integer = |name: &String| sorts.get(name) == Some(&Sort::Int);
+
+        let mut current: Vec<(Linear, bool)> = Vec::new();
+        for constraint in constraints {
+            let mut push = |expr: Linear, strict: bool| -> SolverResult<bool> {
+                let (expr, strict) = tighten(expr, strict, &is_integer)?;
+                if expr.is_constant() {
+                    return Ok(constant_holds(&expr, strict));
+                }
+                if !current.contains(&(expr.clone(), strict)) {
+                    current.push((expr, strict));
+                }
+                Ok(true)
+            };
+            let feasible = match constraint.relation {
+                Relation::Le => push(constraint.expr.clone(), false)?,
+                Relation::Lt => push(constraint.expr.clone(), true)?,
+                Relation::Eq => {
+                    push(constraint.expr.clone(), false)?
+                        && push(constraint.expr.scale(Rational::integer(-1))?, false)?
+                }
+            };
+            if !feasible {
+                return Ok(None);
+            }
+        }
+
+        let mut variables: Vec<String> = current.iter().flat_map(|(expr, _)| expr.terms.keys().cloned()).collect();
+        variables.sort();
+        variables.dedup();
+
+        let mut stages = Vec::with_capacity(variables.len());
+        for variable in &variables {
+            stages.push(current.clone());
+            let (with, mut without): (Vec<_>, Vec<_>) =
+                current.into_iter().partition(|(expr, _)| !expr.coefficient(variable).is_zero());
+            let (upper, lower): (Vec<_>, Vec<_>) = with
+                .into_iter()
+                .partition(|(expr, _)| expr.coefficient(variable).compare(&Rational::ZERO) == Ordering::Greater);
+
+            for (upper_expr, upper_strict) in &upper {
+                for (lower_expr, lower_strict) in &lower {
+                    let upper_coefficient = upper_expr.coefficient(variable);
+                    let lower_coefficient = lower_expr.coefficient(variable).neg()?;
+                    let combined = upper_expr.scale(lower_coefficient)?.add(&lower_expr.scale(upper_coefficient)?)?;
+                    let (combined, strict) = tighten(combined, *upper_strict || *lower_strict, &is_integer)?;
+                    if combined.is_constant() {
+                        if !constant_holds(&combined, strict) {
+                            return Ok(None);
+                        }
+                    } else if !without.contains(&(combined.clone(), strict)) {
+                        without.push((combined, strict));
+                    }
+                }
+            }
+            if without.len() > self.max_constraints {
+                return Err("constraint budget exhausted".to_string());
+            }
+            current = without;
+        }
+
+        let mut values = BTreeMap::new();
+        for (variable, stage) in variables.iter().zip(stages).rev() {
+            let mut lower: Option<(Rational, bool)> = None;
+            let mut upper: Option<(Rational, bool)> = None;
+            for (expr, strict) in &stage {
+                let coefficient = expr.coefficient(variable);
+                if coefficient.is_zero() {
+                    continue;
+                }
+                let mut rest = expr.clone();
+                rest.terms.remove(variable);
+                let bound = rest.evaluate(&values)?.neg()?.div(coefficient)?;
+                if coefficient.compare(&Rational::ZERO) == Ordering::Greater {
+                    upper = Some(tighter(upper, (bound, *strict), Ordering::Less));
+                } else {
+                    lower = Some(tighter(lower, (bound, *strict), Ordering::Greater));
+                }
+            }
+            let value = if is_integer(variable) {
+                choose_integer(lower, upper)?
+            } else {
+                choose_real(lower, upper)?
+            };
+            values.insert(variable.clone(), value);
+        }
+
+        Ok(Some(values))
+    }
+}
+
+/// Strengthen an all-integer inequality `expr ≤ 0` / `expr < 0`
+fn tighten(expr: Linear, strict: bool, is_integer: &impl Fn(&String) -> bool) -> SolverResult<(Linear, bool)> {
+    if expr.is_constant() || !expr.terms.keys().all(is_integer) {
+        return Ok((expr, strict));
+    }
+
+    // Scale to integer coefficients, then `Σ a·x + c < 0` ⇔ `Σ a·x + c + 1 ≤ 0`
+    let mut scale = 1i128;
+    for value in expr.terms.values().chain(std::iter::once(&expr.constant)) {
+        scale = scale / gcd(scale, value.den) * value.den;
+    }
+    let mut expr = expr.scale(Rational::integer(scale))?;
+    if strict {
+        expr.constant = expr.constant.add(Rational::ONE)?;
+    }
+
+    // `Σ a·x ≤ -c` with g = gcd(a) ⇒ `Σ (a/g)·x ≤ ⌊-c/g⌋`
+    let divisor = expr.terms.values().fold(0, |acc, value| gcd(acc, value.num));
+    if divisor > 1 {
+        let bound = expr.constant.neg()?.div(Rational::integer(divisor))?.floor();
+        expr = expr.scale(Rational::new(1, divisor)?)?;
+        expr.constant = Rational::integer(bound).neg()?;
+    }
+    Ok((expr, false))
+}
+
+fn constant_holds(expr: &Linear, strict: bool) -> bool {
+    match expr.constant.compare(&Rational::ZERO) {
+        Ordering::Less => true,
+        Ordering::Equal => !strict,
+        Ordering::Greater => false,
+    }
+}
+
+/// Keep the tighter of two bounds; `prefer` is the direction that is tighter
+fn tighter(current: Option<(Rational, bool)>, candidate: (Rational, bool), prefer: Ordering) -> (Rational, bool) {
+    match current {
+        None => candidate,
+        Some(existing) => match candidate.0.compare(&existing.0) {
+            Ordering::Equal => (existing.0, existing.1 || candidate.1),
+            order if order == prefer => candidate,
+            _ => existing,
+        },
+    }
+}
+
+fn within(value: Rational, lower: Option<(Rational, bool)>, upper: Option<(Rational, bool)>) -> bool {
+    let above = lower.map_or(true, |(bound, strict)| match value.compare(&bound) {
+        Ordering::Greater => true,
+        Ordering::Equal => !strict,
+        Ordering::Less => false,
+    });
+    let below = upper.map_or(true, |(bound, strict)| match value.compare(&bound) {
+        Ordering::Less => true,
+        Ordering::Equal => !strict,
+        Ordering::Greater => false,
+    });
+    above && below
+}
+
+/// Pick a real value within the bounds, preferring zero
+fn choose_real(lower: Option<(Rational, bool)>, upper: Option<(Rational, bool)>) -> SolverResult<Rational> {
+    if within(Rational::ZERO, lower, upper) {
+        return Ok(Rational::ZERO);
+    }
+    let candidate = match (lower, upper) {
+        (Some((low, false)), _) => low,
+        (_, Some((high, false))) => high,
+        (Some((low, true)), Some((high, true))) => low.add(high)?.div(Rational::integer(2))?,
+        (Some((low, true)), None) => low.add(Rational::ONE)?,
+        (None, Some((high, true))) => high.sub(Rational::ONE)?,
+        (None, None) => Rational::ZERO,
+    };
+    Ok(candidate)
+}
+
+/// Pick an integer within the bounds, preferring zero
+fn choose_integer(lower: Option<(Rational, bool)>, upper: Option<(Rational, bool)>) -> SolverResult<Rational> {
+    let low = lower.map(|(bound, strict)| if strict && bound.is_integer() { bound.num + 1 } else { bound.ceil() });
+    let high = upper.map(|(bound, strict)| if strict && bound.is_integer() { bound.num - 1 } else { bound.floor() });
+    if let (Some(low), Some(high)) = (low, high) {
+        if low > high {
+            return Err("no integer witness found".to_string());
+        }
+    }
+    Ok(Rational::integer(0i128.max(low.unwrap_or(i128::MIN)).min(high.unwrap_or(i128::MAX))))
+}
+
+/// Translates SMT-LIB terms into negation normal form
+struct Translator<'a> {
+    sorts: &'a HashMap<String, Sort>,
+}
+
+impl Translator<'_> {
+    fn is_boolean(&self, term: &SExpr) -> bool {
+        match term {
+            SExpr::Atom(atom) => atom == "true" || atom == "false" || self.sorts.get(atom) == Some(&Sort::Bool),
+            SExpr::List(parts) => match parts.first() {
+                Some(SExpr::Atom(head)) if head == "ite" => parts.get(2).is_some_and(|then| self.is_boolean(then)),
+                Some(SExpr::Atom(head)) => {
+                    matches!(head.as_str(), "not" | "and" | "or" | "=>" | "=" | "distinct" | "<" | "<=" | ">" | ">=")
+                }
+                _ => false,
+            },
+        }
+    }
+
+    /// Translate `term` (negated when `positive` is false)
+    fn formula(&self, term: &SExpr, positive: bool) -> SolverResult<Formula> {
+        let parts = match term {
+            SExpr::Atom(atom) => {
+                return match atom.as_str() {
+                    "true" => Ok(if positive { Formula::True } else { Formula::False }),
+                    "false" => Ok(if positive { Formula::False } else { Formula::True }),
+                    name if self.sorts.get(name) == Some(&Sort::Bool) => Ok(Formula::Bool(name.to_string(), positive)),
+                    name => Err(format!("{} is not a Bool constant", name)),
+                };
+            }
+            SExpr::List(parts) => parts,
+        };
+        let Some(SExpr::Atom(head)) = parts.first() else {
+            return Err("expected an operator".to_string());
+        };
+        let args = &parts[1..];
+
+        let both = |formulas: Vec<Formula>, conjunctive: bool| {
+            if conjunctive == positive {
+                Formula::And(formulas)
+            } else {
+                Formula::Or(formulas)
+            }
+        };
+
+        match head.as_str() {
+            "not" => match args {
+                [inner] => self.formula(inner, !positive),
+                _ => Err("not takes one argument".to_string()),
+            },
+            "and" | "or" => {
+                let formulas = args.iter().map(|arg| self.formula(arg, positive)).collect::<SolverResult<_>>()?;
+                Ok(both(formulas, head == "and"))
+            }
+            "=>" => {
+                let (conclusion, premises) = args.split_last().ok_or("=> needs arguments")?;
+                let mut formulas = premises
+                    .iter()
+                    .map(|premise| self.formula(premise, !positive))
+                    .collect::<SolverResult<Vec<_>>>()?;
+                formulas.push(self.formula(conclusion, positive)?);
+                Ok(both(formulas, false))
+            }
+            "ite" => match args {
+                [condition, then, otherwise] => Ok(Formula::Or(vec![
+                    Formula::And(vec![self.formula(condition, true)?, self.formula(then, positive)?]),
+                    Formula::And(vec![self.formula(condition, false)?, self.formula(otherwise, positive)?]),
+                ])),
+                _ => Err("ite takes three arguments".to_string()),
+            },
+            "=" if args.first().is_some_and(|arg| self.is_boolean(arg)) => {
+                let pairs = args
+                    .windows(2)
+                    .map(|pair| {
+                        Ok(Formula::Or(vec![
+                            Formula::And(vec![self.formula(&pair[0], true)?, self.formula(&pair[1], positive)?]),
+                            Formula::And(vec![self.formula(&pair[0], false)?, self.formula(&pair[1], !positive)?]),
+                        ]))
+                    })
+                    .collect::<SolverResult<_>>()?;
+                Ok(both(pairs, true))
+            }
+            "distinct" => {
+                let mut pairs = Vec::new();
+                for (index, left) in args.iter().enumerate() {
+                    for right in &args[index + 1..] {
+                        let equal = SExpr::List(vec![SExpr::Atom("=".to_string()), left.clone(), right.clone()]);
+                        pairs.push(self.formula(&equal, !positive)?);
+                    }
+                }
+                Ok(both(pairs, true))
+            }
+            "=" | "<" | "<=" | ">" | ">=" => {
+                let pairs = args
+                    .windows(2)
+                    .map(|pair| self.comparison(head, &pair[0], &pair[1], positive))
+                    .collect::<SolverResult<_>>()?;
+                Ok(both(pairs, true))
+            }
+            other => Err(format!("operator {} is not supported", other)),
+        }
+    }
+
+    fn comparison(&self, operator: &str, left: &SExpr, right: &SExpr, positive: bool) -> SolverResult<Formula> {
+        let left = self.linear(left)?;
+        let right = self.linear(right)?;
+        let arith = |expr: Linear, relation: Relation| Formula::Arith(Constraint { expr, relation });
+
+        // Negation flips the comparison: ¬(a < b) ⇔ b ≤ a
+        Ok(match (operator, positive) {
+            ("<", true) => arith(left.sub(&right)?, Relation::Lt),
+            ("<", false) => arith(right.sub(&left)?, Relation::Le),
+            ("<=", true) => arith(left.sub(&right)?, Relation::Le),
+            ("<=", false) => arith(right.sub(&left)?, Relation::Lt),
+            (">", true) => arith(right.sub(&left)?, Relation::Lt),
+            (">", false) => arith(left.sub(&right)?, Relation::Le),
+            (">=", true) => arith(right.sub(&left)?, Relation::Le),
+            (">=", false) => arith(left.sub(&right)?, Relation::Lt),
+            (_, true) => arith(left.sub(&right)?, Relation::Eq),
+            (_, false) => Formula::Or(vec![
+                arith(left.sub(&right)?, Relation::Lt),
+                arith(right.sub(&left)?, Relation::Lt),
+            ]),
+        })
+    }
+
+    fn linear(&self, term: &SExpr) -> SolverResult<Linear> {
+        let parts = match term {
+            SExpr::Atom(atom) => {
+                if let Some(value) = Rational::parse(atom) {
+                    return Ok(Linear::constant(value));
+                }
+                return match self.sorts.get(atom) {
+                    Some(Sort::Int | Sort::Real) => Ok(Linear::variable(atom)),
+                    _ => Err(format!("{} is not a numeric constant", atom)),
+                };
+            }
+            SExpr::List(parts) => parts,
+        };
+        let Some(SExpr::Atom(head)) = parts.first() else {
+            return Err("expected an operator".to_string());
+        };
+        let args = parts[1..].iter().map(|arg| self.linear(arg)).collect::<SolverResult<Vec<_>>>()?;
+
+        match (head.as_str(), args.as_slice()) {
+            ("+", _) => args.iter().try_fold(Linear::constant(Rational::ZERO), |sum, arg| sum.add(arg)),
+            ("-", [single]) => single.scale(Rational::integer(-1)),
+            ("-", [first, rest @ ..]) => rest.iter().try_fold(first.clone(), |difference, arg| difference.sub(arg)),
+            ("*", _) => {
+                let mut product = Linear::constant(Rational::ONE);
+                for arg in &args {
+                    product = if arg.is_constant() {
+                        product.scale(arg.constant)?
+                    } else if product.is_constant() {
+                        arg.scale(product.constant)?
+                    } else {
+                        return Err("non-linear multiplication is not supported".to_string());
+                    };
+                }
+                Ok(product)
+            }
+            ("/", [numerator, denominator]) if denominator.is_constant() && !denominator.constant.is_zero() => {
+                numerator.scale(Rational::ONE.div(denominator.constant)?)
+            }
+            ("to_real", [inner]) => Ok(inner.clone()),
+            (other, _) => Err(format!("arithmetic operator {} is not supported", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(script: &str) -> FallbackOutcome {
+        FallbackSolver::new().check_script(script)
+    }
+
+    fn number(model: &FallbackModel, name: &str) -> Rational {
+        match model.values.get(name) {
+            Some(ModelValue::Number(value)) => *value,
+            other => panic!("expected a number for {}, got {:?}", name, other),
+        }
+    }
+
+    #[test]
+    fn test_propositional_tautology_negation_is_unsat() {
+        let script = "(declare-const p Bool)\n(declare-const q Bool)\n\
+                      (assert (not (=> (and (=> p q) p) q)))\n(check-sat)";
+        assert_eq!(check(script), FallbackOutcome::Unsat);
+
+        let satisfiable = "(declare-const p Bool)\n(assert (or p (not p)))\n(check-sat)";
+        assert!(matches!(check(satisfiable), FallbackOutcome::Sat(_)));
+    }
+
+    #[test]
+    fn test_linear_integer_arithmetic() {
+        let infeasible = "(declare-const x Int)\n(declare-const y Int)\n\
+                          (assert (>= x 0))\n(assert (>= y 0))\n\
+                          (assert (<= (+ x y) 3))\n(assert (> (+ x y) 3))\n(check-sat)";
+        assert_eq!(check(infeasible), FallbackOutcome::Unsat);
+
+        let feasible = "(declare-const x Int)\n(declare-const y Int)\n\
+                        (assert (= (- x (* 2 y)) 1))\n(assert (> y 2))\n(check-sat)";
+        match check(feasible) {
+            FallbackOutcome::Sat(model) => {
+                let (x, y) = (number(&model, "x"), number(&model, "y"));
+                assert!(x.is_integer() && y.is_integer());
+                assert_eq!(x.num - 2 * y.num, 1);
+                assert!(y.num > 2);
+            }
+            other => panic!("expected sat, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_integer_tightening_distinguishes_int_from_real() {
+        let int_script = "(declare-const x Int)\n(assert (= (* 2 x) 1))\n(check-sat)";
+        assert_eq!(check(int_script), FallbackOutcome::Unsat);
+
+        let real_script = "(declare-const x Real)\n(assert (= (* 2 x) 1))\n(check-sat)";
+        match check(real_script) {
+            FallbackOutcome::Sat(model) => assert_eq!(number(&model, "x").to_string(), "1/2"),
+            other => panic!("expected sat, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_disequality_splits_and_mixes_with_booleans() {
+        let script = "(declare-const x Int)\n(declare-const flag Bool)\n\
+                      (assert (and (>= x 0) (<= x 1)))\n\
+                      (assert (distinct x 0))\n\
+                      (assert (=> flag (= x 0)))\n(check-sat)";
+        match check(script) {
+            FallbackOutcome::Sat(model) => {
+                assert_eq!(number(&model, "x"), Rational::ONE);
+                assert_eq!(model.values["flag"], ModelValue::Bool(false));
+                assert_eq!(model.to_string(), "flag = false, x = 1");
+            }
+            other => panic!("expected sat, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_outside_fragment_is_unknown() {
+        let nonlinear = "(declare-const x Int)\n(declare-const y Int)\n(assert (= (* x y) 2))\n(check-sat)";
+        assert!(matches!(check(nonlinear), FallbackOutcome::Unknown(_)));
+
+        let function = "(declare-fun f (Int) Int)\n(assert (= (f 1) 2))\n(check-sat)";
+        assert!(matches!(check(function), FallbackOutcome::Unknown(_)));
+    }
+
+    #[test]
+    fn test_session_checks_guarded_assertions_under_assumptions() {
+        let solver = FallbackSolver::new();
+        let session = solver
+            .session(
+                "(declare-const x Real)\n(declare-const a Bool)\n(declare-const b Bool)\n\
+                 (assert (=> a (> x 5.0)))\n(assert (=> b (< x 3.0)))",
+            )
+            .unwrap();
+
+        assert!(matches!(solver.check_assuming(&session, &["a"]), FallbackOutcome::Sat(_)));
+        assert!(matches!(solver.check_assuming(&session, &["b"]), FallbackOutcome::Sat(_)));
+        assert_eq!(solver.check_assuming(&session, &["a", "b"]), FallbackOutcome::Unsat);
+        assert!(matches!(solver.check_assuming(&session, &["x"]), FallbackOutcome::Unknown(_)));
+    }
+}
@@ -9,7 +9,9 @@
 //! - `properties`: Property verification logic
 //! - `enhanced_verifier`: Enhanced Z3 verifier with AISP capabilities
 //! - `smt_interface`: SMT syntax validation and Z3 integration
+//! - `fallback_solver`: Pure-Rust solver used when Z3 is unavailable
 //! - `facade`: High-level verification facade
+//! - `assumptions`: What-if consistency checks under extra assumptions
 //!
 //! Compatible with Z3 version 4.15.4 and z3 crate 0.11
 
@@ -20,7 +22,9 @@ pub mod environment;
 pub mod properties;
 pub mod enhanced_verifier;
 pub mod smt_interface;
+pub mod fallback_solver;
 pub mod facade;
+pub mod assumptions;
 pub mod verifier;
 
 // Re-export main interfaces from canonical types
@@ -28,6 +32,7 @@ pub use canonical_types::{
     Z3VerificationConfig as AdvancedVerificationConfig, 
     Z3VerificationResult as EnhancedVerificationResult, 
     Z3VerificationStatistics as EnhancedVerificationStats,
+    Z3SolverStatistics as SolverStatistics,
     Z3VerificationStatus as VerificationStatus, 
     Z3PropertyCategory as PropertyCategory, 
     Z3PropertyResult as PropertyResult, 
@@ -45,7 +50,9 @@ pub use properties::PropertyVerifier;
 
 pub use enhanced_verifier::EnhancedZ3Verifier;
 pub use smt_interface::SmtInterface;
+pub use fallback_solver::{FallbackOutcome, FallbackSolver};
 pub use facade::Z3VerificationFacade;
+pub use assumptions::{Z3AssumptionAnalysis, Z3AssumptionConflict, Z3Consistency};
 
 /// Convenience function to check Z3 availability
 pub fn is_z3_available() -> bool {
@@ -97,6 +104,7 @@ mod tests {
                 protocol: None,
             },
             blocks: vec![],
+            used_symbols: Default::default(),
             span: Some(Span {
                 start: 0,
                 end: 0,
@@ -626,6 +626,7 @@ mod tests {
                 protocol: None,
             },
             blocks: vec![],
+            used_symbols: Default::default(),
             span: Some(Span {
                 start: 0,
                 end: 0,
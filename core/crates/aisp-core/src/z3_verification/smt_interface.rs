@@ -4,6 +4,7 @@
 //! syntax validation and counterexample generation.
 
 use super::canonical_types::*;
+use super::fallback_solver::{FallbackOutcome, FallbackSession, FallbackSolver};
 use crate::error::*;
 use std::collections::{HashMap, HashSet};
 use std::time::Instant;
@@ -36,6 +37,49 @@ pub struct SmtStats {
     pub syntax_errors: usize,
     pub proven_properties: usize,
     pub disproven_properties: usize,
+    /// Z3 search counters summed over every solve
+    pub solver: Z3SolverStatistics,
+}
+
+/// Declarations and assertions loaded into one solver, then checked under
+/// different assumptions the way `(check-sat-assuming ...)` does
+pub struct SmtSession<'a> {
+    interface: &'a mut SmtInterface,
+    backend: SessionBackend,
+}
+
+enum SessionBackend {
+    #[cfg(feature = "z3-verification")]
+    Z3(Solver),
+    Fallback(FallbackSession),
+    /// The prelude could not be loaded; every check reports why
+    Failed(Z3PropertyResult),
+}
+
+impl SmtSession<'_> {
+    /// Check the loaded assertions with each of `assumptions`, declared
+    /// `Bool` constants, taken as true
+    ///
+    /// As with `verify_smt_formula`, satisfiable is reported as disproven
+    /// and unsatisfiable as proven.
+    pub fn check_assuming(&mut self, assumptions: &[&str]) -> Z3PropertyResult {
+        let start = Instant::now();
+        self.interface.stats.queries_executed += 1;
+        match &self.backend {
+            #[cfg(feature = "z3-verification")]
+            SessionBackend::Z3(solver) => {
+                let assumptions: Vec<ast::Bool> = assumptions.iter().map(|name| ast::Bool::new_const(*name)).collect();
+                let result = solver.check_assumptions(&assumptions);
+                self.interface.record_solver_statistics(solver);
+                self.interface.z3_result(result, start)
+            }
+            SessionBackend::Fallback(session) => {
+                let outcome = FallbackSolver::new().check_assuming(session, assumptions);
+                self.interface.fallback_result(outcome, start)
+            }
+            SessionBackend::Failed(result) => result.clone(),
+        }
+    }
 }
 
 impl SmtInterface {
@@ -58,6 +102,7 @@ impl SmtInterface {
                 syntax_errors: 0,
                 proven_properties: 0,
                 disproven_properties: 0,
+                solver: Z3SolverStatistics::default(),
             },
         }
     }
@@ -76,6 +121,7 @@ impl SmtInterface {
                 syntax_errors: 0,
                 proven_properties: 0,
                 disproven_properties: 0,
+                solver: Z3SolverStatistics::default(),
             },
         }
     }
@@ -108,14 +154,77 @@ impl SmtInterface {
             }
         }
 
-        // Fallback for disabled mode
+        // Pure-Rust fallback for the decidable fragment when Z3 is absent
         if !self.config.require_z3 {
-            Ok(Z3PropertyResult::Unknown { reason: "Formula verification not implemented".to_string(), partial_progress: 0.0 })
+            Ok(self.execute_fallback_query(formula, start))
         } else {
             Ok(Z3PropertyResult::Error { error_message: "Z3 not available".to_string(), error_code: -2 })
         }
     }
 
+    /// Load `prelude`, declarations and assertions without a `(check-sat)`,
+    /// into a session that decides it under different assumptions
+    pub fn open_session(&mut self, prelude: &str) -> AispResult<SmtSession<'_>> {
+        if let Err(syntax_error) = self.validate_smt_syntax(&format!("{}\n(check-sat)", prelude)) {
+            self.stats.syntax_errors += 1;
+            let result = Z3PropertyResult::Error { error_message: format!("Syntax error: {}", syntax_error), error_code: -1 };
+            return Ok(SmtSession { interface: self, backend: SessionBackend::Failed(result) });
+        }
+
+        if !self.z3_available && self.config.require_z3 {
+            return Err(AispError::validation_error(
+                "Z3 verification required but not available. Compile with --features z3-verification".to_string(),
+            ));
+        }
+
+        #[cfg(feature = "z3-verification")]
+        {
+            if self.z3_available {
+                let solver = Solver::new();
+                solver.from_string(prelude);
+                return Ok(SmtSession { interface: self, backend: SessionBackend::Z3(solver) });
+            }
+        }
+
+        let backend = if !self.config.require_z3 {
+            match FallbackSolver::new().session(prelude) {
+                Ok(session) => SessionBackend::Fallback(session),
+                Err(reason) => SessionBackend::Failed(Z3PropertyResult::Unknown {
+                    reason: format!("Fallback solver: {}", reason),
+                    partial_progress: 0.0,
+                }),
+            }
+        } else {
+            SessionBackend::Failed(Z3PropertyResult::Error { error_message: "Z3 not available".to_string(), error_code: -2 })
+        };
+        Ok(SmtSession { interface: self, backend })
+    }
+
+    /// Decide the query with the pure-Rust fallback solver
+    fn execute_fallback_query(&mut self, formula: &str, start: Instant) -> Z3PropertyResult {
+        let outcome = FallbackSolver::new().check_script(formula);
+        self.fallback_result(outcome, start)
+    }
+
+    fn fallback_result(&mut self, outcome: FallbackOutcome, start: Instant) -> Z3PropertyResult {
+        match outcome {
+            FallbackOutcome::Sat(model) => {
+                self.stats.disproven_properties += 1;
+                Z3PropertyResult::Disproven { counterexample: model.to_string(), verification_time: start.elapsed() }
+            }
+            FallbackOutcome::Unsat => {
+                self.stats.proven_properties += 1;
+                Z3PropertyResult::Proven {
+                    proof_certificate: "UNSAT result (pure-Rust fallback)".to_string(),
+                    verification_time: start.elapsed(),
+                }
+            }
+            FallbackOutcome::Unknown(reason) => {
+                Z3PropertyResult::Unknown { reason: format!("Fallback solver: {}", reason), partial_progress: 0.0 }
+            }
+        }
+    }
+
     /// Validate SMT-LIB syntax comprehensively
     fn validate_smt_syntax(&self, formula: &str) -> Result<(), String> {
         let mut paren_count = 0;
@@ -179,24 +288,41 @@ impl SmtInterface {
         let solver = Solver::new();
 
         // Parse and execute SMT commands
-        match self.parse_and_execute_smt(formula, &ctx, &solver) {
-            Ok(sat_result) => {
-                match sat_result {
-                    SatResult::Sat => {
-                        self.stats.disproven_properties += 1;
-                        Ok(Z3PropertyResult::Disproven { counterexample: "SAT result".to_string(), verification_time: start.elapsed() })
-                    }
-                    SatResult::Unsat => {
-                        self.stats.proven_properties += 1;
-                        Ok(Z3PropertyResult::Proven { proof_certificate: "UNSAT result".to_string(), verification_time: start.elapsed() })
-                    }
-                    SatResult::Unknown => Ok(Z3PropertyResult::Unknown { reason: "Z3 returned unknown".to_string(), partial_progress: 0.5 }),
-                }
-            }
+        let outcome = self.parse_and_execute_smt(formula, &ctx, &solver);
+        self.record_solver_statistics(&solver);
+        match outcome {
+            Ok(sat_result) => Ok(self.z3_result(sat_result, start)),
             Err(e) => Ok(Z3PropertyResult::Error { error_message: format!("Z3 error: {}", e), error_code: -3 }),
         }
     }
 
+    #[cfg(feature = "z3-verification")]
+    fn z3_result(&mut self, sat_result: SatResult, start: Instant) -> Z3PropertyResult {
+        match sat_result {
+            SatResult::Sat => {
+                self.stats.disproven_properties += 1;
+                Z3PropertyResult::Disproven { counterexample: "SAT result".to_string(), verification_time: start.elapsed() }
+            }
+            SatResult::Unsat => {
+                self.stats.proven_properties += 1;
+                Z3PropertyResult::Proven { proof_certificate: "UNSAT result".to_string(), verification_time: start.elapsed() }
+            }
+            SatResult::Unknown => Z3PropertyResult::Unknown { reason: "Z3 returned unknown".to_string(), partial_progress: 0.5 },
+        }
+    }
+
+    /// Add the search counters of the solver's last check to the totals
+    #[cfg(feature = "z3-verification")]
+    fn record_solver_statistics(&mut self, solver: &Solver) {
+        let mut solve = Z3SolverStatistics::default();
+        for entry in solver.get_statistics().entries() {
+            if let StatisticsValue::UInt(value) = entry.value {
+                solve.record(&entry.key, u64::from(value));
+            }
+        }
+        self.stats.solver.add(&solve);
+    }
+
     /// Parse and execute SMT commands
     #[cfg(feature = "z3-verification")]
     fn parse_and_execute_smt(&self, formula: &str, ctx: &Context, solver: &Solver) -> Result<SatResult, String> {
@@ -450,8 +576,8 @@ mod tests {
         let result = interface.verify_smt_formula(valid_formula);
         assert!(result.is_ok());
 
-        // With disabled interface, should return Unknown
-        assert!(matches!(result.unwrap(), Z3PropertyResult::Unknown { .. }));
+        // Without Z3 the fallback solver finds a model for x > 0
+        assert!(matches!(result.unwrap(), Z3PropertyResult::Disproven { .. }));
 
         let stats = interface.get_stats();
         assert_eq!(stats.queries_executed, 1);
@@ -473,4 +599,33 @@ mod tests {
 
         assert_eq!(interface.get_stats().syntax_errors, 1);
     }
+
+    #[cfg(feature = "z3-verification")]
+    #[test]
+    fn test_solver_statistics_are_recorded_after_a_solve() {
+        // Four pigeons cannot share three holes; refuting that takes search
+        let mut interface = SmtInterface::new();
+        let solver = Solver::new();
+        let holes = 3;
+        let placed: Vec<Vec<ast::Bool>> = (0..=holes)
+            .map(|pigeon| (0..holes).map(|hole| ast::Bool::new_const(format!("p{}_{}", pigeon, hole))).collect())
+            .collect();
+        for pigeon in &placed {
+            solver.assert(&ast::Bool::or(pigeon.as_slice()));
+        }
+        for hole in 0..holes {
+            for first in 0..placed.len() {
+                for second in first + 1..placed.len() {
+                    solver.assert(&ast::Bool::and(&[&placed[first][hole], &placed[second][hole]]).not());
+                }
+            }
+        }
+        assert_eq!(solver.check(), SatResult::Unsat);
+
+        interface.record_solver_statistics(&solver);
+        let stats = &interface.get_stats().solver;
+        assert!(stats.conflicts > 0, "{:?}", stats);
+        assert!(stats.decisions > 0, "{:?}", stats);
+        assert!(stats.propagations > 0, "{:?}", stats);
+    }
 }
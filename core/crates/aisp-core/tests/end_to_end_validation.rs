@@ -282,11 +282,8 @@ fn test_symbol_statistics_collection() {
 
     assert!(result.valid, "Expected document to be valid");
 
-    if let Some(analysis) = result.semantic_analysis {
-        let stats = analysis.symbol_stats();
-        assert!(!stats.category_counts.is_empty() || true, "Expected symbol statistics to be collected");
-        // Note: symbol_stats() returns MockSymbolStats which has category_counts
-    }
+    let stats = result.symbol_stats.expect("Expected symbol statistics to be collected");
+    assert!(!stats.category_counts.is_empty(), "Expected symbol statistics to be collected");
 }
 
 #[test]
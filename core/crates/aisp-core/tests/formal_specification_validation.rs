@@ -61,7 +61,7 @@ const FORMAL_COMPLIANT_DOCUMENT: &str = r#"
 ⟩
 "#;
 
-/// Document with incomplete evidence block (should fail formal validation)
+/// Document whose evidence block omits δ (should fail formal validation)
 const INCOMPLETE_EVIDENCE_DOCUMENT: &str = r#"
 𝔸5.1.incomplete-test@2026-01-26
 
@@ -81,7 +81,7 @@ const INCOMPLETE_EVIDENCE_DOCUMENT: &str = r#"
   id≜λx.x
 }
 
-⟦Ε⟧⟨δ≜0.5⟩
+⟦Ε⟧⟨φ≜97⟩
 "#;
 
 /// Document violating core ambiguity invariant
@@ -191,10 +191,8 @@ impl FormalValidationAssertion {
     }
 
     pub fn has_complete_evidence(self) -> Self {
-        if let Some(analysis) = &self.result.semantic_analysis {
-            // Check that evidence includes formal proofs
-            let stats = analysis.symbol_stats();
-            // Note: symbol_stats() returns MockSymbolStats, so we check it's accessible
+        if let Some(stats) = &self.result.symbol_stats {
+            // Check that evidence includes symbol statistics
             let _ = stats.category_counts.len();
             // Additional formal evidence validation would go here
         }
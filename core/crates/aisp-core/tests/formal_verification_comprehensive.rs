@@ -299,6 +299,7 @@ fn create_comprehensive_test_document() -> AispDocument {
                     });
                     entries
                 },
+                keys: vec!["domain".to_string(), "version".to_string()],
                 raw_entries: vec![
                     "domain = \"formal_verification_test\"".to_string(),
                     "version = \"1.0.0\"".to_string(),
@@ -390,6 +391,7 @@ fn create_minimal_test_document() -> AispDocument {
         blocks: vec![
             CanonicalAispBlock::Meta(MetaBlock {
                 entries: std::collections::HashMap::new(),
+                keys: Vec::new(),
                 raw_entries: vec!["domain = test".to_string()],
                 span: None,
             }),
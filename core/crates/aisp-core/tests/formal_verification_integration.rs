@@ -35,6 +35,7 @@ fn create_test_document() -> AispDocument {
         blocks: vec![
             CanonicalAispBlock::Meta(MetaBlock {
                 entries: std::collections::HashMap::new(),
+                keys: Vec::new(),
                 raw_entries: vec![
                     "domain = \"formal_verification\"".to_string(),
                     "version = \"1.0.0\"".to_string(),
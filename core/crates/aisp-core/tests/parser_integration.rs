@@ -142,8 +142,8 @@ impl EvidenceBlockAssertion {
         self
     }
 
-    pub fn has_tau(self, expected: &str) -> Self {
-        let actual = self.evidence.tau.as_ref().expect("Expected tau value");
+    pub fn has_tau(self, expected: QualityTier) -> Self {
+        let actual = self.evidence.tau.expect("Expected tau value");
         assert_eq!(actual, expected);
         self
     }
@@ -216,7 +216,7 @@ fn test_parse_evidence_block() {
         DocumentAssertion::new(document)
             .has_evidence_block()
             .has_delta(0.85)
-            .has_tau("◊⁺");
+            .has_tau(QualityTier::Gold);
     } else {
         panic!("Expected successful parse");
     }
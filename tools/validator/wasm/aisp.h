@@ -31,6 +31,17 @@ int32_t aisp_init(void);
  */
 int32_t aisp_parse(const uint8_t* ptr, uint32_t len);
 
+/**
+ * Parse and classify a document in one call
+ * Leaves the document loaded by aisp_parse untouched.
+ * @param ptr Pointer to UTF-8 encoded AISP source
+ * @param len Length of source in bytes
+ * @return Tier value (0-4) on success, AISP_ERR_PARSE for a null, empty
+ *         or non-AISP input, AISP_ERR_MEMORY if len exceeds
+ *         AISP_MAX_DOC_SIZE
+ */
+int32_t aisp_classify(const uint8_t* ptr, uint32_t len);
+
 /**
  * Validate parsed document
  * @param doc_id Document ID from aisp_parse
@@ -59,6 +70,31 @@ float aisp_ambig(int32_t doc_id);
  */
 float aisp_density(int32_t doc_id);
 
+/* ============================================================================
+ * Configuration
+ * ============================================================================ */
+
+/**
+ * Set the ambiguity bound used by aisp_validate
+ * Documents validate while their ambiguity stays below it; aisp_init
+ * restores the default of 0.02.
+ * @param t Bound in (0.0, 1.0]
+ * @return 0 on success, AISP_ERR_RANGE if t is NaN or out of range
+ */
+int32_t aisp_set_ambig_threshold(float t);
+
+/**
+ * Set the minimum density (δ) for each tier
+ * aisp_init restores the defaults 0.20 / 0.40 / 0.60 / 0.75.
+ * @param bronze Minimum δ for ◊⁻
+ * @param silver Minimum δ for ◊
+ * @param gold Minimum δ for ◊⁺
+ * @param platinum Minimum δ for ◊⁺⁺
+ * @return 0 on success, AISP_ERR_RANGE unless all lie in [0.0, 1.0]
+ *         and strictly ascend
+ */
+int32_t aisp_set_tier_thresholds(float bronze, float silver, float gold, float platinum);
+
 /* ============================================================================
  * Error Handling
  * ============================================================================ */
@@ -75,6 +111,17 @@ int32_t aisp_error_code(void);
  */
 uint32_t aisp_error_offset(void);
 
+/* ============================================================================
+ * Diagnostics
+ * ============================================================================ */
+
+/**
+ * Get term arena usage
+ * @return Bytes held by the terms of the last parsed document, 0 after
+ *         aisp_init
+ */
+uint32_t aisp_arena_used(void);
+
 /* ============================================================================
  * Constants
  * ============================================================================ */
@@ -93,6 +140,7 @@ uint32_t aisp_error_offset(void);
 #define AISP_ERR_AMBIG     -3   /* Ambiguity too high */
 #define AISP_ERR_MEMORY    -4   /* Memory error */
 #define AISP_ERR_OVERFLOW  -5   /* Buffer overflow */
+#define AISP_ERR_RANGE     -6   /* Threshold out of range */
 
 /* Binding States (Δ⊗λ) */
 #define AISP_BIND_CRASH     0   /* ⊥: Logic conflict */
@@ -3,72 +3,107 @@
 //! Provides static, bump-allocated memory for term storage
 //! without requiring a heap allocator.
 
+use core::mem::{size_of, MaybeUninit};
+
+/// Saved allocation head, returned by [`Arena::checkpoint`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Mark(usize);
+
 /// Fixed-size arena allocator
-#[repr(C, align(8))]
-pub struct Arena<const N: usize> {
-    /// Storage buffer
-    buffer: [u8; N],
+///
+/// Values are addressed by the index `alloc` returns rather than by
+/// reference, so handles stay usable while more values are allocated.
+/// An index at or past the head reads as `None`.
+pub struct Arena<T: Copy, const N: usize> {
+    /// Storage slots; those below `head` are initialized
+    slots: [MaybeUninit<T>; N],
     /// Current allocation head
     head: usize,
 }
 
-impl<const N: usize> Arena<N> {
+impl<T: Copy, const N: usize> Arena<T, N> {
     /// Create new empty arena
     pub const fn new() -> Self {
         Self {
-            buffer: [0; N],
+            slots: [const { MaybeUninit::uninit() }; N],
             head: 0,
         }
     }
 
-    /// Allocate bytes with alignment
+    /// Allocate a value, returning its index
     #[inline]
-    pub fn alloc_bytes(&mut self, size: usize, align: usize) -> Option<*mut u8> {
-        let aligned = (self.head + align - 1) & !(align - 1);
-        let new_head = aligned + size;
+    pub fn alloc(&mut self, value: T) -> Option<usize> {
+        let index = self.head;
+        self.slots.get_mut(index)?.write(value);
+        self.head += 1;
+        Some(index)
+    }
 
-        if new_head > N {
+    /// Get an allocated value
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.head {
             return None;
         }
-
-        self.head = new_head;
-        Some(unsafe { self.buffer.as_mut_ptr().add(aligned) })
+        // Slots below the head were written by `alloc`
+        Some(unsafe { self.slots[index].assume_init_ref() })
     }
 
-    /// Allocate typed value
+    /// Get an allocated value mutably
     #[inline]
-    pub fn alloc<T>(&mut self) -> Option<&mut T> {
-        let ptr = self.alloc_bytes(
-            core::mem::size_of::<T>(),
-            core::mem::align_of::<T>(),
-        )?;
-        Some(unsafe { &mut *(ptr as *mut T) })
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.head {
+            return None;
+        }
+        Some(unsafe { self.slots[index].assume_init_mut() })
     }
 
     /// Get current usage in bytes
     #[inline]
     pub fn used(&self) -> usize {
-        self.head
+        self.head * size_of::<T>()
     }
 
-    /// Get remaining capacity
+    /// Get remaining capacity in bytes
     #[inline]
     pub fn remaining(&self) -> usize {
-        N - self.head
+        (N - self.head) * size_of::<T>()
     }
 
     /// Reset arena (deallocate all)
     #[inline]
     pub fn reset(&mut self) {
-        self.head = 0;
+        self.rollback(Mark(0));
+    }
+
+    /// Save the allocation head for a later rollback
+    #[inline]
+    pub fn checkpoint(&self) -> Mark {
+        Mark(self.head)
+    }
+
+    /// Discard everything allocated since `mark`
+    ///
+    /// Allocations made before the mark are untouched. A mark beyond the
+    /// current head, left over from before an earlier rollback or reset,
+    /// is ignored.
+    #[inline]
+    pub fn rollback(&mut self, mark: Mark) {
+        if mark.0 > self.head {
+            return;
+        }
         // Zero out for security
-        self.buffer.iter_mut().for_each(|b| *b = 0);
+        self.slots[mark.0..self.head]
+            .iter_mut()
+            .for_each(|slot| *slot = MaybeUninit::zeroed());
+        self.head = mark.0;
     }
 
-    /// Get slice of allocated data
+    /// Get slice of allocated values
     #[inline]
-    pub fn as_slice(&self) -> &[u8] {
-        &self.buffer[..self.head]
+    pub fn as_slice(&self) -> &[T] {
+        let initialized = &self.slots[..self.head];
+        unsafe { &*(initialized as *const [MaybeUninit<T>] as *const [T]) }
     }
 }
 
@@ -78,23 +113,51 @@ mod tests {
 
     #[test]
     fn test_arena_alloc() {
-        let mut arena: Arena<256> = Arena::new();
+        let mut arena: Arena<u64, 32> = Arena::new();
 
-        let p1 = arena.alloc::<u32>().unwrap();
-        *p1 = 42;
+        let a = arena.alloc(42).unwrap();
+        let b = arena.alloc(0xDEADBEEF).unwrap();
+        *arena.get_mut(a).unwrap() += 1;
 
-        let p2 = arena.alloc::<u64>().unwrap();
-        *p2 = 0xDEADBEEF;
+        assert_eq!(arena.get(a), Some(&43));
+        assert_eq!(arena.get(b), Some(&0xDEADBEEF));
+        assert_eq!(arena.get(b + 1), None);
+        assert_eq!(arena.used(), 16);
+    }
 
-        assert_eq!(*p1, 42);
-        assert_eq!(*p2, 0xDEADBEEF);
+    #[test]
+    fn test_rollback_discards_allocations_since_checkpoint() {
+        let mut arena: Arena<u64, 8> = Arena::new();
+        let kept = arena.alloc(42).unwrap();
+        let mark = arena.checkpoint();
+
+        let dropped = arena.alloc(0xDEADBEEF).unwrap();
+        arena.alloc(0xCAFEBABE).unwrap();
+        assert_eq!(arena.used(), 24);
+
+        arena.rollback(mark);
+        assert_eq!(arena.used(), 8);
+        assert_eq!(arena.as_slice(), &[42]);
+        assert_eq!(arena.get(kept), Some(&42));
+        assert_eq!(arena.get(dropped), None);
+
+        // The freed slots are reused from the saved head
+        assert_eq!(arena.alloc(7), Some(dropped));
+        assert_eq!(arena.used(), 16);
+
+        // A stale mark from beyond the head leaves the arena alone
+        let stale = arena.checkpoint();
+        arena.rollback(mark);
+        arena.rollback(stale);
+        assert_eq!(arena.used(), 8);
     }
 
     #[test]
     fn test_arena_full() {
-        let mut arena: Arena<8> = Arena::new();
+        let mut arena: Arena<u64, 1> = Arena::new();
 
-        let _ = arena.alloc::<u64>().unwrap();
-        assert!(arena.alloc::<u64>().is_none());
+        arena.alloc(1).unwrap();
+        assert!(arena.alloc(2).is_none());
+        assert_eq!(arena.remaining(), 0);
     }
 }
@@ -18,7 +18,8 @@ mod checker;
 mod validate;
 
 use arena::Arena;
-use validate::Tier;
+use parser::Parser;
+use validate::{Tier, TierThresholds, AMBIG_THRESHOLD};
 
 // These modules are available for advanced use
 #[allow(unused_imports)]
@@ -35,7 +36,7 @@ use validate::ValidationResult;
 // ============================================================================
 
 /// Term arena (2KB)
-static mut TERM_ARENA: Arena<2048> = Arena::new();
+static mut TERM_ARENA: Arena<Term, 128> = Arena::new();
 
 /// Parse buffer for input (1KB)
 static mut PARSE_BUF: [u8; 1024] = [0; 1024];
@@ -71,6 +72,10 @@ struct DocState {
     aisp_count: u16,
     /// Total token count
     total_count: u16,
+    /// Validation passes while ambiguity stays below this bound
+    ambig_threshold: f32,
+    /// Density thresholds for tier assignment
+    tier_thresholds: TierThresholds,
 }
 
 impl DocState {
@@ -84,6 +89,8 @@ impl DocState {
             tier: Tier::Reject,
             aisp_count: 0,
             total_count: 0,
+            ambig_threshold: AMBIG_THRESHOLD,
+            tier_thresholds: TierThresholds::DEFAULT,
         }
     }
 
@@ -113,40 +120,28 @@ pub extern "C" fn aisp_init() -> i32 {
 /// Returns: document ID (always 0) or error code
 #[no_mangle]
 pub extern "C" fn aisp_parse(ptr: *const u8, len: u32) -> i32 {
-    if ptr.is_null() || len == 0 {
-        unsafe { LAST_ERROR = -1; }
-        return -1;
-    }
-
-    if len > 1024 {
-        unsafe { LAST_ERROR = -4; } // Memory error
-        return -4;
-    }
-
-    let input = unsafe { core::slice::from_raw_parts(ptr, len as usize) };
-
-    // Validate UTF-8
-    if core::str::from_utf8(input).is_err() {
-        unsafe {
-            LAST_ERROR = -1;
-            ERROR_OFFSET = 0;
-        }
-        return -1;
-    }
-
-    // Check AISP header (starts with 𝔸)
-    if !input.starts_with("𝔸".as_bytes()) {
-        unsafe {
-            LAST_ERROR = -1;
-            ERROR_OFFSET = 0;
-        }
-        return -1;
-    }
+    let input = match read_document(ptr, len) {
+        Ok(input) => input,
+        Err(code) => return code,
+    };
 
     // Count tokens and AISP symbols
     let (aisp_count, total_count) = count_tokens(input);
 
+    let mut parser = Parser::new(core::str::from_utf8(input).unwrap_or_default());
+    // Missing blocks surface as ambiguity in aisp_validate
+    let _ = parser.parse();
+
     unsafe {
+        // Each document replaces the terms of the previous one
+        let terms = &mut *core::ptr::addr_of_mut!(TERM_ARENA);
+        terms.reset();
+        if parser.alloc_terms(terms).is_none() {
+            DOC_STATE.parsed = false;
+            LAST_ERROR = -4; // Memory error
+            return -4;
+        }
+
         DOC_STATE.parsed = true;
         DOC_STATE.input_len = len as u16;
         DOC_STATE.aisp_count = aisp_count;
@@ -159,6 +154,22 @@ pub extern "C" fn aisp_parse(ptr: *const u8, len: u32) -> i32 {
     0 // Document ID
 }
 
+/// Parse and classify a document in one call, without touching the
+/// document loaded by `aisp_parse`
+/// Returns: tier as for `aisp_tier`, or error code
+#[no_mangle]
+pub extern "C" fn aisp_classify(ptr: *const u8, len: u32) -> i32 {
+    let input = match read_document(ptr, len) {
+        Ok(input) => input,
+        Err(code) => return code,
+    };
+
+    let (aisp_count, total_count) = count_tokens(input);
+    let delta = density(aisp_count, total_count, count_whitespace(input));
+    let thresholds = unsafe { DOC_STATE.tier_thresholds };
+    thresholds.tier(delta) as i32
+}
+
 /// Validate parsed document
 /// Returns: 0=valid, <0=error
 #[no_mangle]
@@ -175,27 +186,49 @@ pub extern "C" fn aisp_validate(doc_id: i32) -> i32 {
         }
 
         // Compute metrics
-        let non_ws = DOC_STATE.total_count.saturating_sub(count_whitespace());
-        DOC_STATE.delta = if non_ws > 0 {
-            DOC_STATE.aisp_count as f32 / non_ws as f32
-        } else {
-            0.0
-        };
+        let input = &PARSE_BUF[..DOC_STATE.input_len as usize];
+        DOC_STATE.delta = density(DOC_STATE.aisp_count, DOC_STATE.total_count, count_whitespace(input));
 
         // AISP requires Ambig(D) < 0.02
         // For valid AISP docs, ambiguity is near zero
-        DOC_STATE.ambig = if has_required_blocks() { 0.01 } else { 0.5 };
+        DOC_STATE.ambig = if has_required_blocks(input) { 0.01 } else { 0.5 };
 
         // Compute tier from density
-        DOC_STATE.tier = Tier::from_delta(DOC_STATE.delta);
+        let thresholds = DOC_STATE.tier_thresholds;
+        DOC_STATE.tier = thresholds.tier(DOC_STATE.delta);
 
-        // Validation passes if ambiguity < 0.02
-        DOC_STATE.validated = DOC_STATE.ambig < 0.02;
+        // Validation passes if ambiguity is below the configured bound
+        DOC_STATE.validated = DOC_STATE.ambig < DOC_STATE.ambig_threshold;
 
         if DOC_STATE.validated { 0 } else { -3 }
     }
 }
 
+/// Set the ambiguity bound used by `aisp_validate` (default 0.02)
+/// Returns: 0=success, -6 if `t` is NaN or outside (0.0, 1.0]
+#[no_mangle]
+pub extern "C" fn aisp_set_ambig_threshold(t: f32) -> i32 {
+    if !(t > 0.0 && t <= 1.0) {
+        unsafe { LAST_ERROR = -6; } // Range error
+        return -6;
+    }
+    unsafe { DOC_STATE.ambig_threshold = t; }
+    0
+}
+
+/// Set the minimum density for each tier (defaults 0.20/0.40/0.60/0.75)
+/// Returns: 0=success, -6 unless all lie in [0.0, 1.0] and strictly ascend
+#[no_mangle]
+pub extern "C" fn aisp_set_tier_thresholds(bronze: f32, silver: f32, gold: f32, platinum: f32) -> i32 {
+    let thresholds = TierThresholds { bronze, silver, gold, platinum };
+    if !thresholds.is_valid() {
+        unsafe { LAST_ERROR = -6; } // Range error
+        return -6;
+    }
+    unsafe { DOC_STATE.tier_thresholds = thresholds; }
+    0
+}
+
 /// Get quality tier
 /// Returns: 0=⊘, 1=◊⁻, 2=◊, 3=◊⁺, 4=◊⁺⁺
 #[no_mangle]
@@ -236,10 +269,53 @@ pub extern "C" fn aisp_error_offset() -> u32 {
     unsafe { ERROR_OFFSET }
 }
 
+/// Get term arena bytes in use
+/// Returns: bytes held by the terms of the last parsed document
+#[no_mangle]
+pub extern "C" fn aisp_arena_used() -> u32 {
+    unsafe { (*core::ptr::addr_of!(TERM_ARENA)).used() as u32 }
+}
+
 // ============================================================================
 // Internal Functions
 // ============================================================================
 
+/// Check the document at `ptr` is UTF-8 AISP that fits the parse buffer
+fn read_document<'a>(ptr: *const u8, len: u32) -> Result<&'a [u8], i32> {
+    if ptr.is_null() || len == 0 {
+        unsafe { LAST_ERROR = -1; }
+        return Err(-1);
+    }
+
+    if len > 1024 {
+        unsafe { LAST_ERROR = -4; } // Memory error
+        return Err(-4);
+    }
+
+    let input = unsafe { core::slice::from_raw_parts(ptr, len as usize) };
+
+    // Validate UTF-8, then check AISP header (starts with 𝔸)
+    if core::str::from_utf8(input).is_err() || !input.starts_with("𝔸".as_bytes()) {
+        unsafe {
+            LAST_ERROR = -1;
+            ERROR_OFFSET = 0;
+        }
+        return Err(-1);
+    }
+
+    Ok(input)
+}
+
+/// Density δ: AISP symbols per non-whitespace token
+fn density(aisp_count: u16, total_count: u16, whitespace: u16) -> f32 {
+    let non_ws = total_count.saturating_sub(whitespace);
+    if non_ws > 0 {
+        aisp_count as f32 / non_ws as f32
+    } else {
+        0.0
+    }
+}
+
 /// Count AISP tokens and total tokens
 fn count_tokens(input: &[u8]) -> (u16, u16) {
     let s = match core::str::from_utf8(input) {
@@ -261,6 +337,9 @@ fn count_tokens(input: &[u8]) -> (u16, u16) {
 }
 
 /// Check if character is AISP symbol
+///
+/// No glyph here has a canonical decomposition, so input needs no NFC
+/// pass: decomposed and precomposed text classify the same.
 #[inline]
 fn is_aisp_symbol(c: char) -> bool {
     matches!(c,
@@ -277,32 +356,124 @@ fn is_aisp_symbol(c: char) -> bool {
 }
 
 /// Count whitespace tokens
-fn count_whitespace() -> u16 {
-    unsafe {
-        let input = &PARSE_BUF[..DOC_STATE.input_len as usize];
-        let s = match core::str::from_utf8(input) {
-            Ok(s) => s,
-            Err(_) => return 0,
-        };
-        s.chars().filter(|c| c.is_whitespace()).count() as u16
-    }
+fn count_whitespace(input: &[u8]) -> u16 {
+    let s = match core::str::from_utf8(input) {
+        Ok(s) => s,
+        Err(_) => return 0,
+    };
+    s.chars().filter(|c| c.is_whitespace()).count() as u16
 }
 
 /// Check for required AISP blocks
-fn has_required_blocks() -> bool {
-    unsafe {
-        let input = &PARSE_BUF[..DOC_STATE.input_len as usize];
-        let s = match core::str::from_utf8(input) {
-            Ok(s) => s,
-            Err(_) => return false,
+fn has_required_blocks(input: &[u8]) -> bool {
+    let s = match core::str::from_utf8(input) {
+        Ok(s) => s,
+        Err(_) => return false,
+    };
+
+    // Required blocks: ⟦Ω⟧, ⟦Σ⟧, ⟦Γ⟧, ⟦Λ⟧, ⟦Ε⟧
+    s.contains("⟦Ω") &&
+    s.contains("⟦Σ") &&
+    s.contains("⟦Γ") &&
+    s.contains("⟦Λ") &&
+    s.contains("⟦Ε")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate std;
+    use std::sync::Mutex;
+
+    /// The kernel state is global; tests touching it must not interleave
+    static KERNEL: Mutex<()> = Mutex::new(());
+
+    const DOC: &str = "𝔸5.1.X@2026-01-25\n⟦Ω:Meta⟧{domain≜x}\n⟦Σ:Types⟧{State≜{Idle,Busy}}\n\
+                       ⟦Γ:Rules⟧{∀s:State→Valid(s)}\n⟦Λ:Funcs⟧{id≜λx.x}\n⟦Ε⟧⟨δ≜0.5⟩";
+
+    #[test]
+    fn test_arena_usage_does_not_grow_across_validations() {
+        let _kernel = KERNEL.lock().unwrap();
+        assert_eq!(aisp_init(), 0);
+        assert_eq!(aisp_arena_used(), 0);
+
+        let cycle = || {
+            let doc = aisp_parse(DOC.as_ptr(), DOC.len() as u32);
+            assert_eq!(doc, 0);
+            assert_eq!(aisp_validate(doc), 0);
         };
 
-        // Required blocks: ⟦Ω⟧, ⟦Σ⟧, ⟦Γ⟧, ⟦Λ⟧, ⟦Ε⟧
-        s.contains("⟦Ω") &&
-        s.contains("⟦Σ") &&
-        s.contains("⟦Γ") &&
-        s.contains("⟦Λ") &&
-        s.contains("⟦Ε")
+        // Parsing allocates the document's block terms
+        cycle();
+        let used = aisp_arena_used();
+        assert!(used > 0);
+
+        // Each parse replaces the previous document's terms
+        for _ in 0..1000 {
+            cycle();
+        }
+        assert_eq!(aisp_arena_used(), used);
+
+        assert_eq!(aisp_init(), 0);
+        assert_eq!(aisp_arena_used(), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_stricter_ambig_threshold_rejects_previously_valid_doc() {
+        let _kernel = KERNEL.lock().unwrap();
+        assert_eq!(aisp_init(), 0);
+        let doc = aisp_parse(DOC.as_ptr(), DOC.len() as u32);
+        assert_eq!(aisp_validate(doc), 0);
+        let tier = aisp_tier(doc);
+        assert!(tier > 0);
+
+        // Rejected values leave the current bounds in place
+        assert_eq!(aisp_set_ambig_threshold(f32::NAN), -6);
+        assert_eq!(aisp_set_ambig_threshold(0.0), -6);
+        assert_eq!(aisp_set_ambig_threshold(1.5), -6);
+        assert_eq!(aisp_error_code(), -6);
+        assert_eq!(aisp_set_tier_thresholds(0.2, 0.1, 0.6, 0.75), -6);
+        assert_eq!(aisp_set_tier_thresholds(0.2, 0.4, f32::NAN, 0.75), -6);
+        assert_eq!(aisp_validate(doc), 0);
+        assert_eq!(aisp_tier(doc), tier);
+
+        assert_eq!(aisp_set_ambig_threshold(0.005), 0);
+        assert_eq!(aisp_validate(doc), -3);
+
+        assert_eq!(aisp_set_tier_thresholds(0.96, 0.97, 0.98, 0.99), 0);
+        aisp_validate(doc);
+        assert_eq!(aisp_tier(doc), 0);
+
+        // Re-initializing restores the defaults
+        assert_eq!(aisp_init(), 0);
+        let doc = aisp_parse(DOC.as_ptr(), DOC.len() as u32);
+        assert_eq!(aisp_validate(doc), 0);
+        assert_eq!(aisp_tier(doc), tier);
+    }
+
+    #[test]
+    fn test_classify_matches_multi_step_path() {
+        let _kernel = KERNEL.lock().unwrap();
+        assert_eq!(aisp_init(), 0);
+        let doc = aisp_parse(DOC.as_ptr(), DOC.len() as u32);
+        aisp_validate(doc);
+        let tier = aisp_tier(doc);
+        let density = aisp_density(doc);
+
+        // Classifying another document leaves the loaded one untouched
+        let sparse = "𝔸5.1.X@2026-01-25 plain words with few symbols";
+        assert_eq!(aisp_classify(DOC.as_ptr(), DOC.len() as u32), tier);
+        assert_eq!(aisp_classify(sparse.as_ptr(), sparse.len() as u32), 0);
+        assert_eq!(aisp_tier(doc), tier);
+        assert_eq!(aisp_density(doc), density);
+        assert_eq!(aisp_validate(doc), 0);
+        assert_eq!(aisp_tier(doc), tier);
+
+        let missing_header = "⟦Ω:Meta⟧{domain≜x}";
+        assert_eq!(aisp_classify(missing_header.as_ptr(), missing_header.len() as u32), -1);
+        assert_eq!(aisp_classify(core::ptr::null(), 0), -1);
     }
 }
 
@@ -3,9 +3,11 @@
 //! Minimal parser for AISP 5.1 documents.
 //! Focuses on structure validation and metric extraction.
 
+use crate::arena::Arena;
 // Symbol imports for future use
 #[allow(unused_imports)]
-use crate::symbol::{SymbolId, starts_with_symbol};
+use crate::symbol::{SymbolId, lookup_symbol, starts_with_symbol};
+use crate::term::Term;
 
 /// Parse error codes
 #[repr(i32)]
@@ -144,7 +146,7 @@ impl<'a> Parser<'a> {
     /// Skip to first block delimiter
     fn skip_to_block(&mut self) {
         while self.pos < self.input.len() {
-            if self.input[self.pos..].starts_with("⟦") {
+            if self.at_block_open() {
                 return;
             }
             self.pos += 1;
@@ -153,10 +155,8 @@ impl<'a> Parser<'a> {
 
     /// Try to parse a block at current position
     fn try_parse_block(&mut self) -> Option<BlockInfo> {
-        let remaining = &self.input[self.pos..];
-
         // Must start with ⟦
-        if !remaining.starts_with("⟦") {
+        if !self.at_block_open() {
             return None;
         }
 
@@ -191,6 +191,24 @@ impl<'a> Parser<'a> {
     pub fn blocks(&self) -> &[BlockInfo] {
         &self.blocks[..self.block_count as usize]
     }
+
+    /// Allocate a constant term for each parsed block, named by its tag
+    /// glyph
+    /// Returns: `None` once the arena is full
+    pub fn alloc_terms<const N: usize>(&self, terms: &mut Arena<Term, N>) -> Option<()> {
+        for block in self.blocks() {
+            let tag = self.input[block.name_start as usize..].chars().next()?;
+            let name = lookup_symbol(tag.encode_utf8(&mut [0; 4]))
+                .unwrap_or(SymbolId::ANONYMOUS);
+            terms.alloc(Term::constant(name))?;
+        }
+        Some(())
+    }
+
+    /// Check for ⟦ at the current byte, which need not be a char boundary
+    fn at_block_open(&self) -> bool {
+        self.input.as_bytes()[self.pos..].starts_with("⟦".as_bytes())
+    }
 }
 
 /// Count AISP symbols in input
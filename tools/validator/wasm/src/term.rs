@@ -83,6 +83,7 @@ impl TermFlags {
 
 /// Core term representation (16 bytes)
 #[repr(C, align(8))]
+#[derive(Clone, Copy)]
 pub struct Term {
     /// Term kind
     pub kind: TermKind,
@@ -101,6 +102,17 @@ impl Term {
         _pad: [0; 2],
         data: TermData { level: LevelId(0) },
     };
+
+    /// Global constant named by `name`
+    #[inline]
+    pub const fn constant(name: SymbolId) -> Self {
+        Self {
+            kind: TermKind::Const,
+            flags: TermFlags::IS_CLOSED,
+            _pad: [0; 2],
+            data: TermData { const_name: name },
+        }
+    }
 }
 
 /// Term data union (12 bytes)
@@ -25,17 +25,7 @@ impl Tier {
     /// Compute tier from density score
     #[inline]
     pub fn from_delta(delta: f32) -> Self {
-        if delta >= 0.75 {
-            Self::Platinum
-        } else if delta >= 0.60 {
-            Self::Gold
-        } else if delta >= 0.40 {
-            Self::Silver
-        } else if delta >= 0.20 {
-            Self::Bronze
-        } else {
-            Self::Reject
-        }
+        TierThresholds::DEFAULT.tier(delta)
     }
 
     /// Get tier symbol
@@ -50,6 +40,55 @@ impl Tier {
     }
 }
 
+/// Default ambiguity bound: a document is valid when Ambig(D) < 0.02
+pub const AMBIG_THRESHOLD: f32 = 0.02;
+
+/// Minimum density δ for each tier above ⊘
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct TierThresholds {
+    pub bronze: f32,
+    pub silver: f32,
+    pub gold: f32,
+    pub platinum: f32,
+}
+
+impl TierThresholds {
+    /// AISP 5.1 thresholds: 0.20 / 0.40 / 0.60 / 0.75
+    pub const DEFAULT: Self = Self {
+        bronze: 0.20,
+        silver: 0.40,
+        gold: 0.60,
+        platinum: 0.75,
+    };
+
+    /// Compute tier from density score against these thresholds
+    #[inline]
+    pub fn tier(&self, delta: f32) -> Tier {
+        if delta >= self.platinum {
+            Tier::Platinum
+        } else if delta >= self.gold {
+            Tier::Gold
+        } else if delta >= self.silver {
+            Tier::Silver
+        } else if delta >= self.bronze {
+            Tier::Bronze
+        } else {
+            Tier::Reject
+        }
+    }
+
+    /// Thresholds must lie in [0.0, 1.0] and strictly ascend; NaN fails
+    /// every comparison and is rejected with them
+    pub fn is_valid(&self) -> bool {
+        0.0 <= self.bronze
+            && self.bronze < self.silver
+            && self.silver < self.gold
+            && self.gold < self.platinum
+            && self.platinum <= 1.0
+    }
+}
+
 /// Validation result
 #[repr(C)]
 pub struct ValidationResult {
@@ -115,7 +154,7 @@ pub fn validate_document(
     };
 
     // AISP requires Ambig(D) < 0.02
-    if ambiguity >= 0.02 {
+    if ambiguity >= AMBIG_THRESHOLD {
         return ValidationResult::failure(ambiguity);
     }
 